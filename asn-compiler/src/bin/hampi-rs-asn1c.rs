@@ -34,6 +34,21 @@ struct Cli {
     /// Generate code for these derive macros during code generation.
     #[arg(long)]
     derive: Vec<Derive>,
+
+    /// Preserve the extensions not known to us, so that these are re-encoded (by the PER codecs)
+    /// exactly as they were received.
+    #[arg(long)]
+    preserve_unknown_extensions: bool,
+
+    /// Generate a Rust module for each of the ASN.1 modules, so that the definitions with the same
+    /// name in different ASN.1 modules do not conflict.
+    #[arg(long)]
+    module_per_asn1_module: bool,
+
+    /// Check the Inner Type Constraints (`WITH COMPONENT(S)`) on the decoded values as well. These
+    /// are always checked on the values being encoded.
+    #[arg(long)]
+    validate_on_decode: bool,
 }
 
 fn main() -> io::Result<()> {
@@ -77,6 +92,9 @@ fn main() -> io::Result<()> {
         cli.codec.clone(),
         derives.clone(),
     );
+    compiler.set_preserve_unknown_extensions(cli.preserve_unknown_extensions);
+    compiler.set_module_per_asn1_module(cli.module_per_asn1_module);
+    compiler.set_validate_on_decode(cli.validate_on_decode);
     compiler.compile_files(&cli.files)?;
 
     Ok(())
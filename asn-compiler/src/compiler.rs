@@ -12,7 +12,7 @@ use topological_sort::TopologicalSort;
 
 use crate::error::Error;
 
-use crate::parser::asn::structs::module::Asn1Module;
+use crate::parser::asn::structs::module::{Asn1Module, Asn1ModuleName};
 
 use crate::generator::{Codec, Derive, Generator, Visibility};
 use crate::resolver::Resolver;
@@ -61,6 +61,35 @@ impl Asn1Compiler {
         }
     }
 
+    /// Generate the code for preserving the extensions that are not known to us.
+    ///
+    /// When set, the extensible `SEQUENCE`s get an `unknown_extensions` field and the extensible
+    /// `CHOICE`s and the `OPEN` types get an `Unknown(i128, Vec<u8>)` variant. These hold the
+    /// encodings of the extension additions, alternatives and values not known to us, so that the
+    /// PER Codecs encode them again exactly as they were received.
+    pub fn set_preserve_unknown_extensions(&mut self, preserve: bool) {
+        self.generator.preserve_unknown_extensions = preserve;
+    }
+
+    /// Generate a Rust module for each of the ASN.1 modules.
+    ///
+    /// When set, the code for the definitions in an ASN.1 module is generated inside a Rust module
+    /// named after the ASN.1 module (eg. `ngap_ies` for `NGAP-IEs`). The definitions from the other
+    /// ASN.1 modules are brought in scope with `use`s. This allows the definitions with the same
+    /// name in different ASN.1 modules (eg. when compiling more than one protocol together).
+    pub fn set_module_per_asn1_module(&mut self, module_per_asn1_module: bool) {
+        self.generator.module_per_asn1_module = module_per_asn1_module;
+    }
+
+    /// Check the Inner Type Constraints (`WITH COMPONENT` and `WITH COMPONENTS`) when decoding.
+    ///
+    /// A `validate` method is generated for the types with these constraints, which is always
+    /// called by the generated codecs when encoding a value. When set, it is also called after a
+    /// value is decoded and a value not satisfying the constraint is a decode error.
+    pub fn set_validate_on_decode(&mut self, validate_on_decode: bool) {
+        self.generator.validate_on_decode = validate_on_decode;
+    }
+
     /// Add a module to the list of known modules.
     ///
     /// If the module alredy exists, returns `false` else returns `true`.
@@ -73,12 +102,15 @@ impl Asn1Compiler {
 
     /// Resolve Modules order and definitions within those modules.
     ///
-    /// First Makes sure that all the modules that have IMPORTs are indeed added to us. Then
+    /// First Makes sure that all the modules that have IMPORTs are indeed added to us and that the
+    /// imported definitions are defined and exported by those modules. Then
     /// definitions in each of the modules are 'resolved'. Calls the `Resolver` functions to do
     /// that. Modules are Topologically Sorted before they are resolved and definitions within
     /// modules are topologically sorted as well. This makes Error handling for undefined
     /// definitions much easier.
-    // FIXME: Support the case where module is imported by a name different from it's actual name.
+    ///
+    /// Modules are imported by their Object Identifiers when those are given in the `IMPORTS`,
+    /// otherwise by their names.
     pub fn resolve_modules(&mut self) -> Result<(), Error> {
         log::info!("Resolving imports from all modules.");
         self.resolve_imports()?;
@@ -143,6 +175,7 @@ impl Asn1Compiler {
             }
             let module = module.unwrap();
             log::debug!("Adding module : {}", module.get_module_name());
+            self.check_module_conflicts(&module)?;
             self.add_module(module);
         }
         Ok(())
@@ -180,18 +213,72 @@ impl Asn1Compiler {
         }
     }
 
-    fn resolve_imports(&self) -> Result<(), Error> {
+    // Resolves the modules from which the definitions are imported.
+    //
+    // A module is looked up by it's Object Identifier, if one is given in the `IMPORTS` and falls
+    // back to the name of the module. A module imported by a name different from it's actual name
+    // (eg. a module that was renamed in a later release of a spec) is thus found by it's Object
+    // Identifier. The imports are updated with the actual name of the module, so that rest of the
+    // compilation need to deal with only those names.
+    fn resolve_imports(&mut self) -> Result<(), Error> {
         log::debug!("Resolving imports.");
-        for (_, module) in self.modules.iter() {
+        let mut renamed = vec![];
+        for (name, module) in self.modules.iter() {
             for (import, module_name) in module.get_imported_defs() {
-                let target = self.modules.get(module_name.name_as_str());
+                let location = match module.get_import_location(import) {
+                    Some(location) => format!(" ({})", location),
+                    None => String::new(),
+                };
+                let target = self.imported_module(module_name);
                 if target.is_none() {
+                    let oid = match module_name.oid() {
+                        Some(oid) => format!(" {{ {} }}", oid),
+                        None => String::new(),
+                    };
                     return Err(resolve_error!(
-                        "Module '{}', corresponding to definition '{}' not found!",
+                        "Module '{}'{}, corresponding to definition '{}' not found!",
                         module_name.name_as_str(),
+                        oid,
                         import
                     ));
                 }
+                let target = target.unwrap();
+                if !target.defines_symbol(import) {
+                    return Err(resolve_error!(
+                        "Definition '{}' imported in Module '{}'{} is not defined in Module '{}'!",
+                        import,
+                        module.get_module_name(),
+                        location,
+                        target.get_module_name()
+                    ));
+                }
+                if !target.get_exports().exports(import) {
+                    return Err(resolve_error!(
+                        "Definition '{}' imported in Module '{}'{} is not exported by Module '{}'!",
+                        import,
+                        module.get_module_name(),
+                        location,
+                        target.get_module_name()
+                    ));
+                }
+                if target.get_module_name() != module_name.name() {
+                    log::debug!(
+                        "Module '{}' imported as '{}' in Module '{}'.",
+                        target.get_module_name(),
+                        module_name.name_as_str(),
+                        module.get_module_name()
+                    );
+                    renamed.push((name.clone(), import.clone(), target.get_module_name()));
+                }
+            }
+        }
+
+        for (name, import, target) in renamed {
+            let module = self.modules.get_mut(&name).unwrap();
+            for (i, module_name) in module.get_imported_defs_mut() {
+                if *i == import {
+                    *module_name = Asn1ModuleName::new(target.clone(), module_name.oid());
+                }
             }
         }
 
@@ -199,6 +286,52 @@ impl Asn1Compiler {
         Ok(())
     }
 
+    // Finds the module, from which definitions are imported. See `resolve_imports`.
+    fn imported_module(&self, module_name: &Asn1ModuleName) -> Option<&Asn1Module> {
+        if let Some(ref oid) = module_name.oid() {
+            let module = self
+                .modules
+                .values()
+                .find(|m| m.get_module_oid() == Some(oid));
+            if module.is_some() {
+                return module;
+            }
+        }
+        self.modules.get(module_name.name_as_str())
+    }
+
+    // Two different modules cannot have the same name or the same Object Identifier.
+    fn check_module_conflicts(&self, module: &Asn1Module) -> Result<(), Error> {
+        if let Some(existing) = self.modules.get(&module.get_module_name()) {
+            if existing.get_module_oid() != module.get_module_oid() {
+                let oid = |m: &Asn1Module| match m.get_module_oid() {
+                    Some(oid) => format!("{{ {} }}", oid),
+                    None => "NONE".to_string(),
+                };
+                return Err(resolve_error!(
+                    "Module '{}' is defined with different Object Identifiers: {} and {}!",
+                    module.get_module_name(),
+                    oid(existing),
+                    oid(module)
+                ));
+            }
+        }
+        if let Some(oid) = module.get_module_oid() {
+            let existing = self.modules.values().find(|m| {
+                m.get_module_oid() == Some(oid) && m.get_module_name() != module.get_module_name()
+            });
+            if let Some(existing) = existing {
+                return Err(resolve_error!(
+                    "Modules '{}' and '{}' have the same Object Identifier {{ {} }}!",
+                    existing.get_module_name(),
+                    module.get_module_name(),
+                    oid
+                ));
+            }
+        }
+        Ok(())
+    }
+
     fn sorted_modules(&self) -> Vec<String> {
         log::trace!("Topologically sorting modules.");
         let mut ts = TopologicalSort::<String>::new();
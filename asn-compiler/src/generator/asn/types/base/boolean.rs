@@ -15,12 +15,14 @@ impl Asn1ResolvedBoolean {
     ) -> Result<TokenStream, Error> {
         let type_name = generator.to_type_ident(name);
 
+        let asn1_name = generator.generate_asn1_type_attr_tokens(name, "BOOLEAN");
+
         let vis = generator.get_visibility_tokens();
         let dir = generator.generate_derive_tokens();
 
         Ok(quote! {
             #dir
-            #[asn(type = "BOOLEAN")]
+            #[asn(type = "BOOLEAN" #asn1_name)]
             #vis struct #type_name(#vis bool);
         })
     }
@@ -25,6 +25,13 @@ impl Asn1ResolvedCharacterString {
             ty_attributes.extend(sz_attributes);
         }
 
+        if let Some(alphabet) = self.alphabet.as_ref() {
+            let alphabet = alphabet.iter().collect::<String>();
+            ty_attributes.extend(quote! { , alphabet = #alphabet });
+        }
+
+        ty_attributes.extend(generator.generate_asn1_type_attr_tokens(name, &self.str_type));
+
         let vis = generator.get_visibility_tokens();
         let dir = generator.generate_derive_tokens();
 
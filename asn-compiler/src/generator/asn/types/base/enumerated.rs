@@ -27,6 +27,18 @@ impl Asn1ResolvedEnumerated {
         ty_attributes.extend(quote! { , lb = "0" });
         ty_attributes.extend(quote! { , ub =  #ub  });
 
+        if generator.needs_asn1_names() {
+            let named_values = self
+                .named_values()
+                .iter()
+                .map(|(name, value)| format!("{}({})", name, value))
+                .collect::<Vec<_>>()
+                .join(", ");
+            ty_attributes.extend(quote! { , named_values = #named_values });
+        }
+
+        ty_attributes.extend(generator.generate_asn1_type_attr_tokens(name, "ENUMERATED"));
+
         let vis = generator.get_visibility_tokens();
         let dir = generator.generate_derive_tokens();
 
@@ -45,7 +57,7 @@ impl Asn1ResolvedEnumerated {
 
     fn generate_named_values(&self, generator: &Generator) -> Result<TokenStream, Error> {
         let mut tokens = TokenStream::new();
-        for (name, value) in &self.named_root_values {
+        for (name, value) in &self.named_values() {
             let const_name = generator.to_const_ident(name);
             let value_literal = generator.to_suffixed_literal(self.bits, self.signed, *value);
             let ty = generator.to_inner_type(self.bits, self.signed);
@@ -61,6 +73,21 @@ impl Asn1ResolvedEnumerated {
         Ok(tokens)
     }
 
+    // The values of the extension additions follow the values of the root (the PER Codecs encode
+    // the index of the value within the extension additions).
+    fn named_values(&self) -> Vec<(String, i128)> {
+        let root_count = self.named_root_values.len() as i128;
+        self.named_root_values
+            .iter()
+            .cloned()
+            .chain(
+                self.named_ext_values
+                    .iter()
+                    .map(|(name, idx)| (name.clone(), root_count + idx)),
+            )
+            .collect()
+    }
+
     pub(crate) fn generate_ident_and_aux_type(
         &self,
         generator: &mut Generator,
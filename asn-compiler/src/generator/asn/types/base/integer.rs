@@ -6,7 +6,7 @@ use quote::quote;
 use crate::error::Error;
 
 use crate::generator::Generator;
-use crate::resolver::asn::structs::types::base::Asn1ResolvedInteger;
+use crate::resolver::asn::structs::{defs::DefinitionKey, types::base::Asn1ResolvedInteger};
 
 impl Asn1ResolvedInteger {
     pub(crate) fn generate(
@@ -52,6 +52,24 @@ impl Asn1ResolvedInteger {
             });
         }
 
+        ty_tokens.extend(generator.generate_asn1_type_attr_tokens(name, "INTEGER"));
+
+        // The allowed values of a Value Set definition.
+        let key = DefinitionKey::new(&generator.module, name);
+        let value_set_impl = match generator.value_sets.get(&key).cloned() {
+            Some(values) => {
+                let (validate_attrs, value_set_impl) = values.generate_value_set_impl(
+                    name,
+                    &inner_type,
+                    self.get_inner_type_min_max(),
+                    generator,
+                );
+                ty_tokens.extend(validate_attrs);
+                value_set_impl
+            }
+            None => TokenStream::new(),
+        };
+
         let vis = generator.get_visibility_tokens();
         let dir = generator.generate_derive_tokens();
 
@@ -59,6 +77,8 @@ impl Asn1ResolvedInteger {
             #dir
             #[asn(#ty_tokens)]
             #vis struct #struct_name(#vis #inner_type);
+
+            #value_set_impl
         };
 
         Ok(struct_tokens)
@@ -81,6 +101,19 @@ impl Asn1ResolvedInteger {
         Ok(generator.to_type_ident(&unique_name))
     }
 
+    // The smallest and the largest values of the generated inner type.
+    fn get_inner_type_min_max(&self) -> (i128, i128) {
+        let bits = match self.bits {
+            8 | 16 | 32 | 64 => self.bits as u32,
+            _ => 64,
+        };
+        if self.signed {
+            (-(1i128 << (bits - 1)), (1i128 << (bits - 1)) - 1)
+        } else {
+            (0, (1i128 << bits) - 1)
+        }
+    }
+
     fn get_min_max_constraints(&self) -> (Option<i128>, Option<i128>) {
         if self.resolved_constraints.is_none() {
             (None, None)
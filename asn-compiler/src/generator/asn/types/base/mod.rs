@@ -16,6 +16,10 @@ mod null;
 
 mod oid;
 
+mod time;
+
+mod real;
+
 use proc_macro2::{Ident, TokenStream};
 
 use crate::error::Error;
@@ -33,10 +37,12 @@ impl ResolvedBaseType {
             ResolvedBaseType::Enum(ref e) => e.generate(name, generator),
             ResolvedBaseType::BitString(ref b) => b.generate(name, generator),
             ResolvedBaseType::Boolean(ref b) => b.generate(name, generator),
+            ResolvedBaseType::Real(ref r) => r.generate(name, generator),
             ResolvedBaseType::OctetString(ref o) => o.generate(name, generator),
             ResolvedBaseType::CharacterString(ref c) => c.generate(name, generator),
             ResolvedBaseType::Null(ref n) => n.generate(name, generator),
             ResolvedBaseType::ObjectIdentifier(ref o) => o.generate(name, generator),
+            ResolvedBaseType::Time(ref t) => t.generate(name, generator),
         }
     }
 
@@ -50,6 +56,7 @@ impl ResolvedBaseType {
             ResolvedBaseType::Enum(ref e) => e.generate_ident_and_aux_type(generator, input),
             ResolvedBaseType::BitString(ref b) => b.generate_ident_and_aux_type(generator, input),
             ResolvedBaseType::Boolean(ref b) => b.generate_ident_and_aux_type(generator, input),
+            ResolvedBaseType::Real(ref r) => r.generate_ident_and_aux_type(generator, input),
             ResolvedBaseType::OctetString(ref o) => o.generate_ident_and_aux_type(generator, input),
             ResolvedBaseType::CharacterString(ref c) => {
                 c.generate_ident_and_aux_type(generator, input)
@@ -58,6 +65,7 @@ impl ResolvedBaseType {
             ResolvedBaseType::ObjectIdentifier(ref o) => {
                 o.generate_ident_and_aux_type(generator, input)
             }
+            ResolvedBaseType::Time(ref t) => t.generate_ident_and_aux_type(generator, input),
         }
     }
 }
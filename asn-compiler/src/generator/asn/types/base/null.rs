@@ -15,12 +15,14 @@ impl Asn1ResolvedNull {
     ) -> Result<TokenStream, Error> {
         let type_name = generator.to_type_ident(name);
 
+        let asn1_name = generator.generate_asn1_type_attr_tokens(name, "NULL");
+
         let vis = generator.get_visibility_tokens();
         let dir = generator.generate_derive_tokens();
 
         Ok(quote! {
             #dir
-            #[asn(type = "NULL")]
+            #[asn(type = "NULL" #asn1_name)]
             #vis struct #type_name;
         })
     }
@@ -23,13 +23,22 @@ impl Asn1ResolvedOctetString {
             ty_attributes.extend(sz_attributes);
         }
 
+        ty_attributes.extend(generator.generate_asn1_type_attr_tokens(name, "OCTET_STRING"));
+
         let vis = generator.get_visibility_tokens();
         let dir = generator.generate_derive_tokens();
 
+        let contents_tokens = match self.contents {
+            Some(ref contents) => contents.generate_contents_impl(&struct_name, false, generator),
+            None => TokenStream::new(),
+        };
+
         let struct_tokens = quote! {
             #dir
             #[asn(#ty_attributes)]
             #vis struct #struct_name(#vis Vec<u8>);
+
+            #contents_tokens
         };
 
         Ok(struct_tokens)
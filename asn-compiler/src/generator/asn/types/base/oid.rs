@@ -1,4 +1,4 @@
-//! Generator code for 'Asn1ResolvedBoolean'.
+//! Generator code for 'Asn1ResolvedObjectIdentifier'.
 
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
@@ -15,13 +15,36 @@ impl Asn1ResolvedObjectIdentifier {
     ) -> Result<TokenStream, Error> {
         let type_name = generator.to_type_ident(name);
 
+        let (ty, builtin) = if self.relative {
+            ("RELATIVE-OID", "RELATIVE_OID")
+        } else {
+            ("OBJECT-IDENTIFIER", "OBJECT_IDENTIFIER")
+        };
+        let asn1_name = generator.generate_asn1_type_attr_tokens(name, builtin);
+
         let vis = generator.get_visibility_tokens();
         let dir = generator.generate_derive_tokens();
 
+        // The value holds the arcs, and is displayed as (and parsed from) the dotted notation.
         Ok(quote! {
             #dir
-            #[asn(type = "OBJECT-IDENTIFIER")]
-            #vis struct #type_name;
+            #[asn(type = #ty #asn1_name)]
+            #vis struct #type_name(#vis Vec<u128>);
+
+            impl std::fmt::Display for #type_name {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    let arcs = self.0.iter().map(|arc| arc.to_string()).collect::<Vec<_>>();
+                    write!(f, "{}", arcs.join("."))
+                }
+            }
+
+            impl std::str::FromStr for #type_name {
+                type Err = std::num::ParseIntError;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    s.split('.').map(|arc| arc.parse()).collect::<Result<_, _>>().map(Self)
+                }
+            }
         })
     }
 
@@ -31,7 +54,11 @@ impl Asn1ResolvedObjectIdentifier {
         input: Option<&String>,
     ) -> Result<Ident, Error> {
         let unique_name = if input.is_none() {
-            generator.get_unique_name("OBJECT IDENTIFIER")
+            generator.get_unique_name(if self.relative {
+                "RELATIVE-OID"
+            } else {
+                "OBJECT IDENTIFIER"
+            })
         } else {
             input.unwrap().to_string()
         };
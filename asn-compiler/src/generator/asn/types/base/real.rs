@@ -0,0 +1,65 @@
+//! Generator code for 'Asn1ResolvedReal'.
+
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+use crate::error::Error;
+use crate::generator::{Derive, Generator};
+use crate::resolver::asn::structs::types::base::Asn1ResolvedReal;
+
+impl Asn1ResolvedReal {
+    pub(crate) fn generate(
+        &self,
+        name: &str,
+        generator: &mut Generator,
+    ) -> Result<TokenStream, Error> {
+        let type_name = generator.to_type_ident(name);
+
+        let asn1_name = generator.generate_asn1_type_attr_tokens(name, "REAL");
+
+        let vis = generator.get_visibility_tokens();
+        let dir = generator.generate_derive_tokens_except(&[Derive::EqPartialEq]);
+
+        // `f64` is not `Eq`, so the values are compared by their bits. This makes a `NaN` equal to
+        // itself, which is what we want for the values of the structures containing a `REAL`.
+        let eq_impls = if generator.derives.contains(&Derive::EqPartialEq)
+            || generator.derives.contains(&Derive::All)
+        {
+            quote! {
+                impl PartialEq for #type_name {
+                    fn eq(&self, other: &Self) -> bool {
+                        self.0.to_bits() == other.0.to_bits()
+                    }
+                }
+
+                impl Eq for #type_name {}
+            }
+        } else {
+            TokenStream::new()
+        };
+
+        Ok(quote! {
+            #dir
+            #[asn(type = "REAL" #asn1_name)]
+            #vis struct #type_name(#vis f64);
+
+            #eq_impls
+        })
+    }
+
+    pub(crate) fn generate_ident_and_aux_type(
+        &self,
+        generator: &mut Generator,
+        input: Option<&String>,
+    ) -> Result<Ident, Error> {
+        let unique_name = match input {
+            Some(name) => name.to_string(),
+            None => generator.get_unique_name("REAL"),
+        };
+
+        let item = self.generate(&unique_name, generator)?;
+        generator.aux_items.push(item);
+
+        Ok(generator.to_type_ident(&unique_name))
+    }
+}
@@ -0,0 +1,106 @@
+//! Generator code for 'Asn1ResolvedTime'.
+
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+use crate::error::Error;
+use crate::generator::Generator;
+use crate::resolver::asn::structs::types::base::Asn1ResolvedTime;
+
+impl Asn1ResolvedTime {
+    pub(crate) fn generate(
+        &self,
+        name: &str,
+        generator: &mut Generator,
+    ) -> Result<TokenStream, Error> {
+        let type_name = generator.to_type_ident(name);
+        let ty = self.time_type.as_str();
+        let asn1_name = generator.generate_asn1_type_attr_tokens(name, ty);
+
+        // The broken-down type for the value and the conversions from and to the lexical form.
+        let (broken_down, from_lexical, to_lexical) = match ty {
+            "UTCTime" => (
+                quote!(asn1_codecs::time::DateTime),
+                quote!(asn1_codecs::time::DateTime::from_utc_time),
+                quote!(value.to_utc_time()?),
+            ),
+            "GeneralizedTime" => (
+                quote!(asn1_codecs::time::DateTime),
+                quote!(asn1_codecs::time::DateTime::from_generalized_time),
+                quote!(value.to_generalized_time()),
+            ),
+            "DATE-TIME" => (
+                quote!(asn1_codecs::time::DateTime),
+                quote!(asn1_codecs::time::DateTime::from_date_time),
+                quote!(value.to_date_time()),
+            ),
+            "DATE" => (
+                quote!(asn1_codecs::time::Date),
+                quote!(<asn1_codecs::time::Date as std::str::FromStr>::from_str),
+                quote!(value.to_string()),
+            ),
+            "TIME-OF-DAY" => (
+                quote!(asn1_codecs::time::TimeOfDay),
+                quote!(<asn1_codecs::time::TimeOfDay as std::str::FromStr>::from_str),
+                quote!(value.to_string()),
+            ),
+            "DURATION" => (
+                quote!(asn1_codecs::time::Duration),
+                quote!(<asn1_codecs::time::Duration as std::str::FromStr>::from_str),
+                quote!(value.to_string()),
+            ),
+            _ => {
+                return Err(code_generate_error!(
+                    "Unsupported Time Type '{}' for '{}'.",
+                    ty,
+                    name
+                ))
+            }
+        };
+
+        let vis = generator.get_visibility_tokens();
+        let dir = generator.generate_derive_tokens();
+
+        // The value holds the lexical form, which is validated when converted from or to the
+        // broken-down form.
+        Ok(quote! {
+            #dir
+            #[asn(type = #ty #asn1_name)]
+            #vis struct #type_name(#vis String);
+
+            impl std::convert::TryFrom<&#type_name> for #broken_down {
+                type Error = asn1_codecs::TimeError;
+
+                fn try_from(value: &#type_name) -> Result<Self, Self::Error> {
+                    #from_lexical(&value.0)
+                }
+            }
+
+            impl std::convert::TryFrom<#broken_down> for #type_name {
+                type Error = asn1_codecs::TimeError;
+
+                fn try_from(value: #broken_down) -> Result<Self, Self::Error> {
+                    let lexical = #to_lexical;
+                    #from_lexical(&lexical)?;
+                    Ok(Self(lexical))
+                }
+            }
+        })
+    }
+
+    pub(crate) fn generate_ident_and_aux_type(
+        &self,
+        generator: &mut Generator,
+        input: Option<&String>,
+    ) -> Result<Ident, Error> {
+        let unique_name = match input {
+            Some(name) => name.to_string(),
+            None => generator.get_unique_name(&self.time_type),
+        };
+
+        let item = self.generate(&unique_name, generator)?;
+        generator.aux_items.push(item);
+
+        Ok(generator.to_type_ident(&unique_name))
+    }
+}
@@ -1,9 +1,17 @@
 //! Utilities related to genrating Type/Field attributes based on Type Constraints.
 
-use proc_macro2::TokenStream;
-use quote::quote;
+use proc_macro2::{Ident, Literal, TokenStream};
+use quote::{format_ident, quote};
 
-use crate::resolver::asn::structs::types::constraints::Asn1ConstraintValueSet;
+use crate::generator::{Codec, Generator};
+use crate::parser::asn::structs::types::constraints::ComponentPresence;
+use crate::resolver::asn::structs::{
+    defs::DefinitionKey,
+    types::constraints::{
+        Asn1ConstraintValueSet, Asn1ResolvedContents, ConstraintValues,
+        ResolvedInnerTypeConstraint, ResolvedValueConstraint,
+    },
+};
 
 impl Asn1ConstraintValueSet {
     pub(crate) fn get_ty_size_constraints_attrs(&self) -> TokenStream {
@@ -25,4 +33,469 @@ impl Asn1ConstraintValueSet {
 
         ty_attributes
     }
+
+    // Generates the consts with the values of the `INTEGER` Value Set type `name` (values of
+    // which are `inner_type`) and `is_allowed` checking that a value is one of those. The values
+    // that cannot be held by the `inner_type` are left out. Like the other constraints, only a
+    // Value Set that is not extensible is validated by the codecs. Returns the type attributes
+    // for that and the generated `impl`.
+    pub(crate) fn generate_value_set_impl(
+        &self,
+        name: &str,
+        inner_type: &TokenStream,
+        (min, max): (i128, i128),
+        generator: &mut Generator,
+    ) -> (TokenStream, TokenStream) {
+        let ty_ident = generator.to_type_ident(name);
+        let bound = |value: i128| {
+            if value == min {
+                quote! { #inner_type::MIN }
+            } else if value == max {
+                quote! { #inner_type::MAX }
+            } else {
+                let value = Literal::i128_unsuffixed(value);
+                quote! { #value }
+            }
+        };
+        let ranges = |values: &ConstraintValues| {
+            values
+                .intersection(&ConstraintValues::from_range(min, max))
+                .ranges()
+                .iter()
+                .map(|r| {
+                    let (start, end) = (bound(*r.start()), bound(*r.end()));
+                    quote! { #start..=#end }
+                })
+                .collect::<Vec<TokenStream>>()
+        };
+        let root_values = ranges(&self.root_values);
+        let additional_values = ranges(&self.additional_values.clone().unwrap_or_default());
+
+        let vis = generator.get_visibility_tokens();
+        let (validate_attrs, validate_fn) = if self.has_extension() {
+            (TokenStream::new(), TokenStream::new())
+        } else {
+            let message = format!("Value of '{}' is not in the Value Set.", name);
+            (
+                validate_attrs(generator),
+                quote! {
+                    #vis fn validate(&self) -> Result<(), String> {
+                        if Self::is_allowed(self.0) {
+                            Ok(())
+                        } else {
+                            Err(#message.to_string())
+                        }
+                    }
+                },
+            )
+        };
+
+        let value_set_impl = quote! {
+            impl #ty_ident {
+                #vis const ROOT_VALUES: &'static [std::ops::RangeInclusive<#inner_type>] = &[#(#root_values),*];
+                #vis const ADDITIONAL_VALUES: &'static [std::ops::RangeInclusive<#inner_type>] = &[#(#additional_values),*];
+
+                #vis fn is_allowed(value: #inner_type) -> bool {
+                    Self::ROOT_VALUES
+                        .iter()
+                        .chain(Self::ADDITIONAL_VALUES)
+                        .any(|r| r.contains(&value))
+                }
+
+                #validate_fn
+            }
+        };
+
+        (validate_attrs, value_set_impl)
+    }
+}
+
+impl Asn1ResolvedContents {
+    // Generates the methods of the `OCTET STRING` or the `BIT STRING` type `ty_ident`, for
+    // decoding the contained type from it's value and encoding the contained type into a value of
+    // it (eg. `aper_decode_contents` and `aper_encode_contents`). The methods are generated for
+    // the Encoding Rules given by `ENCODED BY`, or for all the binary codecs being generated, if
+    // it is absent. The value itself continues to hold the encoded bytes.
+    pub(crate) fn generate_contents_impl(
+        &self,
+        ty_ident: &Ident,
+        bit_string: bool,
+        generator: &mut Generator,
+    ) -> TokenStream {
+        let containing = match self.containing {
+            Some(ref c) if generator.type_names.contains(c) => c,
+            Some(ref c) => {
+                log::warn!(
+                    "Contained type '{}' of '{}' not found. Not generating the methods for it's contents.",
+                    c,
+                    ty_ident
+                );
+                return TokenStream::new();
+            }
+            None => return TokenStream::new(),
+        };
+
+        let codecs = match self.encoded_by {
+            Some(ref oid) => match Self::codec_for_encoding(oid) {
+                Some(codec) if generator.codecs.contains(&codec) => vec![codec],
+                _ => vec![],
+            },
+            None => generator
+                .codecs
+                .iter()
+                .filter(|c| !matches!(c, Codec::Jer | Codec::Xer))
+                .cloned()
+                .collect(),
+        };
+        if codecs.is_empty() {
+            return TokenStream::new();
+        }
+
+        let contained = generator.reference_type_ident(containing);
+        let (bytes, value) = if bit_string {
+            (
+                quote! { self.0.as_raw_slice() },
+                quote! { Self(bitvec::vec::BitVec::from_vec(data.into_bytes())) },
+            )
+        } else {
+            (quote! { &self.0 }, quote! { Self(data.into_bytes()) })
+        };
+
+        let vis = generator.get_visibility_tokens();
+        let mut fns = vec![];
+        for codec in codecs {
+            let (prefix, data_ty, err_ty, decode_data, encode_data, codec_trait) = match codec {
+                Codec::Aper => (
+                    "aper",
+                    quote!(PerCodecData),
+                    quote!(PerCodecError),
+                    quote!(from_slice_aper),
+                    quote!(new_aper),
+                    quote!(asn1_codecs::aper::AperCodec),
+                ),
+                Codec::Uper => (
+                    "uper",
+                    quote!(PerCodecData),
+                    quote!(PerCodecError),
+                    quote!(from_slice_uper),
+                    quote!(new_uper),
+                    quote!(asn1_codecs::uper::UperCodec),
+                ),
+                Codec::Ber => (
+                    "ber",
+                    quote!(BerCodecData),
+                    quote!(BerCodecError),
+                    quote!(from_slice),
+                    quote!(new),
+                    quote!(asn1_codecs::ber::BerCodec),
+                ),
+                Codec::Der => (
+                    "der",
+                    quote!(BerCodecData),
+                    quote!(BerCodecError),
+                    quote!(from_slice_der),
+                    quote!(new),
+                    quote!(asn1_codecs::ber::der::DerCodec),
+                ),
+                Codec::Oer => (
+                    "oer",
+                    quote!(OerCodecData),
+                    quote!(OerCodecError),
+                    quote!(from_slice),
+                    quote!(new),
+                    quote!(asn1_codecs::oer::OerCodec),
+                ),
+                Codec::Jer | Codec::Xer => unreachable!(),
+            };
+            let decode_fn = format_ident!("{}_decode", prefix);
+            let encode_fn = format_ident!("{}_encode", prefix);
+            let decode_contents_fn = format_ident!("{}_decode_contents", prefix);
+            let encode_contents_fn = format_ident!("{}_encode_contents", prefix);
+
+            fns.push(quote! {
+                #vis fn #decode_contents_fn(&self) -> Result<#contained, asn1_codecs::#err_ty> {
+                    let mut data = asn1_codecs::#data_ty::#decode_data(#bytes);
+                    <#contained as #codec_trait>::#decode_fn(&mut data)
+                }
+
+                #vis fn #encode_contents_fn(contents: &#contained) -> Result<Self, asn1_codecs::#err_ty> {
+                    let mut data = asn1_codecs::#data_ty::#encode_data();
+                    #codec_trait::#encode_fn(contents, &mut data)?;
+                    Ok(#value)
+                }
+            });
+        }
+
+        quote! {
+            impl #ty_ident {
+                #(#fns)*
+            }
+        }
+    }
+
+    // The codec for the Encoding Rules identified by the Object Identifier of `ENCODED BY`.
+    fn codec_for_encoding(oid: &[u32]) -> Option<Codec> {
+        match oid {
+            // { joint-iso-itu-t asn1(1) basic-encoding(1) }
+            [2, 1, 1] => Some(Codec::Ber),
+            // { joint-iso-itu-t asn1(1) ber-derived(2) distinguished-encoding(1) }
+            [2, 1, 2, 1] => Some(Codec::Der),
+            // { joint-iso-itu-t asn1(1) packed-encoding(3) basic(0) aligned(0) }
+            [2, 1, 3, 0, 0] => Some(Codec::Aper),
+            // { joint-iso-itu-t asn1(1) packed-encoding(3) basic(0) unaligned(1) }
+            [2, 1, 3, 0, 1] => Some(Codec::Uper),
+            _ => None,
+        }
+    }
+}
+
+impl ResolvedValueConstraint {
+    // Generates the `validate` method of the type with the name `name`, checking the constraint
+    // on it's values. Also returns the type attributes, for the codecs to call it when encoding
+    // (and when decoding, if so configured) the values.
+    pub(crate) fn generate_validate_impl(
+        &self,
+        name: &str,
+        generator: &mut Generator,
+    ) -> (TokenStream, TokenStream) {
+        let ty_ident = generator.to_type_ident(name);
+        let check = self
+            .generate_check(&quote!(self), name, &quote!(#ty_ident), generator)
+            .expr;
+        let message = format!("Value of '{}' does not satisfy the constraint.", name);
+
+        let vis = generator.get_visibility_tokens();
+        let validate_impl = quote! {
+            impl #ty_ident {
+                #vis fn validate(&self) -> Result<(), String> {
+                    if #check {
+                        Ok(())
+                    } else {
+                        Err(#message.to_string())
+                    }
+                }
+            }
+        };
+
+        (validate_attrs(generator), validate_impl)
+    }
+
+    // Returns the expression checking that the `value` (a value of the type `ty_ident` generated
+    // with the name `name`) satisfies the constraint. The names of the types of the components
+    // defined inline, are those given to them by the generator.
+    fn generate_check(
+        &self,
+        value: &TokenStream,
+        name: &str,
+        ty_ident: &TokenStream,
+        generator: &mut Generator,
+    ) -> Check {
+        match self {
+            Self::Union(ref constraints) => Check::any(
+                constraints
+                    .iter()
+                    .map(|c| c.generate_check(value, name, ty_ident, generator))
+                    .collect(),
+            ),
+            Self::Intersection(ref constraints) => Check::all(
+                constraints
+                    .iter()
+                    .map(|c| c.generate_check(value, name, ty_ident, generator))
+                    .collect(),
+            ),
+            Self::Integer(ref values) => {
+                Self::generate_values_check(values, &quote! { (#value.0 as i128) })
+            }
+            Self::Size { ref values, chars } => {
+                let size = if *chars {
+                    quote! { (#value.0.chars().count() as i128) }
+                } else {
+                    quote! { (#value.0.len() as i128) }
+                };
+                Self::generate_values_check(values, &size)
+            }
+            Self::Inner(ref inner) => inner.generate_check(value, name, ty_ident, generator),
+        }
+    }
+
+    fn generate_values_check(values: &ConstraintValues, v: &TokenStream) -> Check {
+        let mut checks = vec![];
+        for range in values.ranges() {
+            let start = Literal::i128_unsuffixed(*range.start());
+            let end = Literal::i128_unsuffixed(*range.end());
+            let check = match (*range.start(), *range.end()) {
+                (i128::MIN, i128::MAX) => quote! { true },
+                (i128::MIN, _) => quote! { #v <= #end },
+                (_, i128::MAX) => quote! { #v >= #start },
+                (s, e) if s == e => quote! { #v == #start },
+                _ => quote! { (#start..=#end).contains(&#v) },
+            };
+            checks.push(Check::from(check));
+        }
+        Check::any(checks)
+    }
+}
+
+impl ResolvedInnerTypeConstraint {
+    fn generate_check(
+        &self,
+        value: &TokenStream,
+        name: &str,
+        ty_ident: &TokenStream,
+        generator: &mut Generator,
+    ) -> Check {
+        let mut checks = vec![];
+        match self {
+            Self::Component {
+                ref reference,
+                ref constraint,
+            } => {
+                let (element_name, element_ident) =
+                    Self::component_type(reference, format!("{}_Entry", name), generator);
+                let check = constraint
+                    .generate_check(&quote!(v), &element_name, &element_ident, generator)
+                    .expr;
+                checks.push(Check::from(quote! { #value.0.iter().all(|v| #check) }));
+            }
+            Self::Components(ref components) => {
+                for c in components {
+                    let field = generator.to_value_ident(&c.id);
+                    match c.presence {
+                        Some(ComponentPresence::Present) => {
+                            checks.push(Check::from(quote! { #value.#field.is_some() }))
+                        }
+                        Some(ComponentPresence::Absent) => {
+                            checks.push(Check::from(quote! { #value.#field.is_none() }))
+                        }
+                        _ => {}
+                    }
+                    if let Some(ref constraint) = c.value {
+                        let inline_name = format!("{}{}", name, generator.to_type_ident(&c.id));
+                        let (comp_name, comp_ident) =
+                            Self::component_type(&c.reference, inline_name, generator);
+                        if c.optional {
+                            let check = constraint
+                                .generate_check(&quote!(v), &comp_name, &comp_ident, generator)
+                                .expr;
+                            checks
+                                .push(Check::from(quote! { #value.#field.iter().all(|v| #check) }));
+                        } else {
+                            checks.push(constraint.generate_check(
+                                &quote!(#value.#field),
+                                &comp_name,
+                                &comp_ident,
+                                generator,
+                            ));
+                        }
+                    }
+                }
+            }
+            Self::Alternatives(ref alternatives) => {
+                for c in alternatives {
+                    let variant = generator.to_type_ident(&c.id);
+                    match c.presence {
+                        Some(ComponentPresence::Present) => checks.push(Check::from(
+                            quote! { matches!(#value, #ty_ident::#variant(..)) },
+                        )),
+                        Some(ComponentPresence::Absent) => checks.push(Check::from(
+                            quote! { !matches!(#value, #ty_ident::#variant(..)) },
+                        )),
+                        _ => {}
+                    }
+                    if let Some(ref constraint) = c.value {
+                        let inline_name = format!("{}_{}", name, c.id);
+                        let (alt_name, alt_ident) =
+                            Self::component_type(&c.reference, inline_name, generator);
+                        let check = constraint
+                            .generate_check(&quote!(v), &alt_name, &alt_ident, generator)
+                            .expr;
+                        checks.push(Check::from(quote! {
+                            match &#value {
+                                #ty_ident::#variant(v) => #check,
+                                _ => true,
+                            }
+                        }));
+                    }
+                }
+            }
+        }
+        Check::all(checks)
+    }
+
+    // The name and the identifier of the type of a component. For a component defined inline,
+    // that is the name given to the type by the generator.
+    fn component_type(
+        reference: &Option<DefinitionKey>,
+        inline_name: String,
+        generator: &mut Generator,
+    ) -> (String, TokenStream) {
+        match reference {
+            Some(ref key) => {
+                let ident = generator.reference_type_ident(key);
+                (key.name.clone(), quote!(#ident))
+            }
+            None => {
+                let ident = generator.to_type_ident(&inline_name);
+                (inline_name, quote!(#ident))
+            }
+        }
+    }
+}
+
+// The type attributes for the codecs to call the `validate` method when encoding (and when
+// decoding, if so configured) the values.
+fn validate_attrs(generator: &Generator) -> TokenStream {
+    if generator.validate_on_decode {
+        quote! { , validate = true, validate_decode = true }
+    } else {
+        quote! { , validate = true }
+    }
+}
+
+// A generated boolean expression. The expressions are combined without parentheses, except
+// around the disjunctions (`||`) in a conjunction (`&&`).
+struct Check {
+    expr: TokenStream,
+    disjunction: bool,
+}
+
+impl Check {
+    fn from(expr: TokenStream) -> Self {
+        Check {
+            expr,
+            disjunction: false,
+        }
+    }
+
+    fn any(mut checks: Vec<Check>) -> Self {
+        match checks.len() {
+            0 => Check::from(quote! { false }),
+            1 => checks.pop().unwrap(),
+            _ => {
+                let exprs = checks.into_iter().map(|c| c.expr);
+                Check {
+                    expr: quote! { #(#exprs)||* },
+                    disjunction: true,
+                }
+            }
+        }
+    }
+
+    fn all(mut checks: Vec<Check>) -> Self {
+        match checks.len() {
+            0 => Check::from(quote! { true }),
+            1 => checks.pop().unwrap(),
+            _ => {
+                let exprs = checks.into_iter().map(|c| {
+                    let expr = c.expr;
+                    if c.disjunction {
+                        quote! { (#expr) }
+                    } else {
+                        expr
+                    }
+                });
+                Check::from(quote! { #(#exprs)&&* })
+            }
+        }
+    }
 }
@@ -15,6 +15,8 @@ struct ChoiceComponentToken {
     variant: Ident,
     ty: Ident,
     key: i128,
+    name: Option<String>,
+    tag: Option<TokenStream>,
 }
 
 impl ResolvedConstructedType {
@@ -26,6 +28,7 @@ impl ResolvedConstructedType {
         if let ResolvedConstructedType::Choice {
             ref root_components,
             ref additions,
+            ref constraint,
             ..
         } = self
         {
@@ -50,16 +53,30 @@ impl ResolvedConstructedType {
 
             let vis = generator.get_visibility_tokens();
             let dir = generator.generate_derive_tokens();
+            let mut ty_attrs = generator.generate_asn1_type_attr_tokens(name, "CHOICE");
+
+            let validate_impl = match constraint {
+                Some(ref constraint) => {
+                    let (validate_attrs, validate_impl) =
+                        constraint.generate_validate_impl(name, generator);
+                    ty_attrs.extend(validate_attrs);
+                    validate_impl
+                }
+                None => TokenStream::new(),
+            };
             let struct_tokens =
                 ResolvedConstructedType::generate_struct_tokens_for_asn_choice_type(
                     &type_name,
                     &root_tokens,
                     &addition_tokens,
+                    generator.preserve_unknown_extensions,
                     vis,
                     dir,
+                    ty_attrs,
                 )?;
 
             choice_tokens.extend(struct_tokens);
+            choice_tokens.extend(validate_impl);
 
             Ok(choice_tokens)
         } else {
@@ -71,8 +88,10 @@ impl ResolvedConstructedType {
         type_name: &Ident,
         root_tokens: &[ChoiceComponentToken],
         addition_tokens: &Option<Vec<ChoiceComponentToken>>,
+        preserve_unknown: bool,
         vis: TokenStream,
         dir: TokenStream,
+        ty_attrs: TokenStream,
     ) -> Result<TokenStream, Error> {
         let mut root_comp_tokens = TokenStream::new();
         for token in root_tokens {
@@ -80,7 +99,11 @@ impl ResolvedConstructedType {
             let ty_ident = token.ty.clone();
             let key_token: TokenStream = format!("{}", token.key).parse().unwrap();
             let extension_token = quote! { false };
-            let field_attributes = quote! { #[asn(key = #key_token, extended = #extension_token)] };
+            let name_token = token.name.as_ref().map(|name| quote! { , name = #name });
+            let tag_token = token.tag.as_ref().map(|tag| quote! { , #tag });
+            let field_attributes = quote! {
+                #[asn(key = #key_token, extended = #extension_token #name_token #tag_token)]
+            };
             let comp_token = quote! {
                 #field_attributes
                 #variant_ident(#ty_ident),
@@ -99,14 +122,24 @@ impl ResolvedConstructedType {
                 let ty_ident = token.ty.clone();
                 let key_token: TokenStream = format!("{}", token.key).parse().unwrap();
                 let extension_token = quote! { true };
-                let field_attributes =
-                    quote! { #[asn(key = #key_token, extended = #extension_token)] };
+                let name_token = token.name.as_ref().map(|name| quote! { , name = #name });
+                let tag_token = token.tag.as_ref().map(|tag| quote! { , #tag });
+                let field_attributes = quote! {
+                    #[asn(key = #key_token, extended = #extension_token #name_token #tag_token)]
+                };
                 let comp_token = quote! {
                     #field_attributes
                     #variant_ident(#ty_ident),
                 };
                 addition_comp_tokens.extend(comp_token);
             }
+
+            if preserve_unknown {
+                addition_comp_tokens.extend(quote! {
+                    #[asn(unknown = true)]
+                    Unknown(i128, Vec<u8>),
+                });
+            }
         }
 
         // Attributes Tokens (Let's not Get rid of them as yet, they will be required if we decide
@@ -121,7 +154,8 @@ impl ResolvedConstructedType {
         };
         let additions = quote! { extensible = #additions };
 
-        let ty_attributes = quote! { #[asn(type = "CHOICE", #lb_token, #ub_token, #additions)] };
+        let ty_attributes =
+            quote! { #[asn(type = "CHOICE", #lb_token, #ub_token, #additions #ty_attrs)] };
 
         Ok(quote! {
             #dir
@@ -152,6 +186,8 @@ impl ResolvedConstructedType {
                 variant: comp_variant_ident,
                 ty: comp_variant_ty_ident,
                 key: i as i128,
+                name: generator.needs_asn1_names().then(|| c.id.clone()),
+                tag: generator.generate_asn1_tag_tokens(&c.tagging),
             });
         }
         Ok(out_components)
@@ -24,7 +24,9 @@ impl ResolvedConstructedType {
         generator: &mut Generator,
     ) -> Result<TokenStream, Error> {
         match self {
-            ResolvedConstructedType::Sequence { .. } => self.generate_sequence(name, generator),
+            ResolvedConstructedType::Sequence { .. } | ResolvedConstructedType::Set { .. } => {
+                self.generate_sequence(name, generator)
+            }
             ResolvedConstructedType::Choice { .. } => self.generate_choice(name, generator),
             ResolvedConstructedType::SequenceOf { .. } => {
                 self.generate_sequence_of(name, generator)
@@ -58,7 +60,8 @@ impl ResolvedConstructedType {
         input: Option<&String>,
     ) -> Result<Ident, Error> {
         let unique_name = match self {
-            ResolvedConstructedType::Sequence { name, .. } => match input {
+            ResolvedConstructedType::Sequence { name, .. }
+            | ResolvedConstructedType::Set { name, .. } => match input {
                 Some(ref inp) => inp.to_string(),
                 None => match name {
                     Some(ref n) => n.to_string(),
@@ -1,4 +1,4 @@
-//! Implementation of Code Generation for ASN.1 `SEQUENCE` Type.
+//! Implementation of Code Generation for ASN.1 `SEQUENCE` and `SET` Types.
 
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -15,25 +15,124 @@ impl ResolvedConstructedType {
         name: &str,
         generator: &mut Generator,
     ) -> Result<TokenStream, Error> {
-        if let ResolvedConstructedType::Sequence {
-            ref components,
-            ref extensible,
-            ..
-        } = self
-        {
-            let type_name = generator.to_type_ident(name);
-
-            let extensible = if *extensible {
-                quote! { true }
+        let (components, additions, extensible, constraint, asn_type) = match self {
+            ResolvedConstructedType::Sequence {
+                ref components,
+                ref additions,
+                ref extensible,
+                ref constraint,
+                ..
+            } => (components, additions, extensible, constraint, "SEQUENCE"),
+            // The components of a resolved `SET` are already in the canonical order, so the
+            // codecs encode them in the order of the fields.
+            ResolvedConstructedType::Set {
+                ref components,
+                ref additions,
+                ref extensible,
+                ref constraint,
+                ..
+            } => (components, additions, extensible, constraint, "SET"),
+            _ => return Ok(TokenStream::new()),
+        };
+
+        let type_name = generator.to_type_ident(name);
+
+        let is_extensible = *extensible;
+        let extensible = if *extensible {
+            quote! { true }
+        } else {
+            quote! { false }
+        };
+
+        let vis = generator.get_visibility_tokens();
+
+        let mut comp_tokens = TokenStream::new();
+        let mut default_fn_tokens = TokenStream::new();
+        let mut optional_fields = 0;
+        for c in components {
+            let comp_field_ident = generator.to_value_ident(&c.component.id);
+            let comp_ty_suffix = generator.to_type_ident(&c.component.id);
+            let input_comp_ty_ident = format!("{}{}", name, comp_ty_suffix);
+            let comp_ty_ident = Asn1ResolvedType::generate_name_maybe_aux_type(
+                &c.component.ty,
+                generator,
+                Some(&input_comp_ty_ident),
+            )?;
+            let mut fld_attrs = vec![];
+
+            let fld_tokens = if c.optional {
+                let idx: proc_macro2::TokenStream = format!("{}", optional_fields).parse().unwrap();
+                fld_attrs.push(quote! { optional_idx = #idx });
+
+                optional_fields += 1;
+
+                quote! { #vis #comp_field_ident: Option<#comp_ty_ident>, }
+            } else {
+                quote! { #vis #comp_field_ident: #comp_ty_ident, }
+            };
+
+            // The `DEFAULT` value is returned by a function of the `SEQUENCE` type, which the
+            // codecs use for the component that is absent. Like a `DEFAULT` value that is not
+            // resolved, one that cannot be generated leaves the component just `OPTIONAL`.
+            let default = match c.default {
+                Some(ref default) => {
+                    match default.generate_value_expression(Some(&comp_ty_ident), generator) {
+                        Ok(value) => Some(value),
+                        Err(e) => {
+                            eprintln!(
+                                "Warning!! DEFAULT value of the component '{}' not generated: {}",
+                                c.component.id, e
+                            );
+                            None
+                        }
+                    }
+                }
+                None => None,
+            };
+            if let Some(value) = default {
+                let default_fn_ident =
+                    generator.to_value_ident(&format!("default_{}", comp_field_ident));
+                default_fn_tokens.extend(quote! {
+                    #vis fn #default_fn_ident() -> #comp_ty_ident {
+                        #value
+                    }
+                });
+
+                let default_fn_path = format!("{}::{}", type_name, default_fn_ident);
+                fld_attrs.push(quote! { default = #default_fn_path });
+            }
+
+            if c.key_field {
+                fld_attrs.push(quote! { key_field = true })
+            }
+
+            if generator.needs_asn1_names() {
+                let comp_name = &c.component.id;
+                fld_attrs.push(quote! { name = #comp_name })
+            }
+
+            if let Some(tag) = generator.generate_asn1_tag_tokens(&c.component.tagging) {
+                fld_attrs.push(tag)
+            }
+
+            let fld_attr_tokens = if !fld_attrs.is_empty() {
+                quote! { #[asn(#(#fld_attrs),*)] }
             } else {
-                quote! { false }
+                quote! {}
             };
 
-            let vis = generator.get_visibility_tokens();
+            comp_tokens.extend(quote! {
+                #fld_attr_tokens #fld_tokens
+            });
+        }
 
-            let mut comp_tokens = TokenStream::new();
-            let mut optional_fields = 0;
-            for c in components {
+        // The extension additions are always `Option`s, since they are absent in the values
+        // encoded by the peers knowing only the earlier versions. The `OPTIONAL` components of
+        // an extension addition group are indexed in the group's own bitmap.
+        for (idx, addition) in additions.iter().enumerate() {
+            let ext_idx: proc_macro2::TokenStream = format!("{}", idx).parse().unwrap();
+            let mut group_optional_fields = 0;
+            for c in &addition.components {
                 let comp_field_ident = generator.to_value_ident(&c.component.id);
                 let comp_ty_suffix = generator.to_type_ident(&c.component.id);
                 let input_comp_ty_ident = format!("{}{}", name, comp_ty_suffix);
@@ -42,53 +141,78 @@ impl ResolvedConstructedType {
                     generator,
                     Some(&input_comp_ty_ident),
                 )?;
-                let mut fld_attrs = vec![];
 
-                let fld_tokens = if c.optional {
-                    let idx: proc_macro2::TokenStream =
-                        format!("{}", optional_fields).parse().unwrap();
-                    fld_attrs.push(quote! { optional_idx = #idx,  });
-
-                    optional_fields += 1;
-
-                    quote! { #vis #comp_field_ident: Option<#comp_ty_ident>, }
-                } else {
-                    quote! { #vis #comp_field_ident: #comp_ty_ident, }
-                };
+                let mut fld_attrs = vec![quote! { extension_idx = #ext_idx }];
+                if addition.is_group && c.optional {
+                    let optidx: proc_macro2::TokenStream =
+                        format!("{}", group_optional_fields).parse().unwrap();
+                    fld_attrs.push(quote! { optional_idx = #optidx });
+                    group_optional_fields += 1;
+                }
 
-                if c.key_field {
-                    fld_attrs.push(quote! { key_field = true })
+                if generator.needs_asn1_names() {
+                    let comp_name = &c.component.id;
+                    fld_attrs.push(quote! { name = #comp_name })
                 }
 
-                let fld_attr_tokens = if !fld_attrs.is_empty() {
-                    quote! { #[asn(#(#fld_attrs),*)] }
-                } else {
-                    quote! {}
-                };
+                if let Some(tag) = generator.generate_asn1_tag_tokens(&c.component.tagging) {
+                    fld_attrs.push(tag)
+                }
 
                 comp_tokens.extend(quote! {
-                    #fld_attr_tokens #fld_tokens
+                    #[asn(#(#fld_attrs),*)]
+                    #vis #comp_field_ident: Option<#comp_ty_ident>,
                 });
             }
+        }
 
-            let mut ty_tokens = quote! { type = "SEQUENCE", extensible = #extensible };
+        if generator.preserve_unknown_extensions && is_extensible {
+            comp_tokens.extend(quote! {
+                #[asn(unknown = true)]
+                #vis unknown_extensions: Vec<Option<Vec<u8>>>,
+            });
+        }
 
-            if optional_fields > 0 {
-                let optflds: proc_macro2::TokenStream =
-                    format!("{}", optional_fields).parse().unwrap();
-                ty_tokens.extend(quote! { , optional_fields = #optflds });
+        let mut ty_tokens = quote! { type = #asn_type, extensible = #extensible };
+
+        if optional_fields > 0 {
+            let optflds: proc_macro2::TokenStream = format!("{}", optional_fields).parse().unwrap();
+            ty_tokens.extend(quote! { , optional_fields = #optflds });
+        }
+
+        ty_tokens.extend(generator.generate_asn1_type_attr_tokens(name, asn_type));
+
+        let validate_impl = match constraint {
+            Some(ref constraint) => {
+                let (validate_attrs, validate_impl) =
+                    constraint.generate_validate_impl(name, generator);
+                ty_tokens.extend(validate_attrs);
+                validate_impl
             }
+            None => TokenStream::new(),
+        };
 
-            let dir = generator.generate_derive_tokens();
-            Ok(quote! {
-                #dir
-                #[asn(#ty_tokens)]
-                #vis struct #type_name {
-                    #comp_tokens
-                }
-            })
+        let default_fns = if default_fn_tokens.is_empty() {
+            TokenStream::new()
         } else {
-            Ok(TokenStream::new())
-        }
+            quote! {
+                impl #type_name {
+                    #default_fn_tokens
+                }
+            }
+        };
+
+        let dir = generator.generate_derive_tokens();
+        Ok(quote! {
+            #dir
+            #[asn(#ty_tokens)]
+            #vis struct #type_name {
+                #comp_tokens
+            }
+
+            #default_fns
+
+            #validate_impl
+        })
     }
 }
@@ -18,6 +18,7 @@ impl ResolvedConstructedType {
         if let ResolvedConstructedType::SequenceOf {
             ref ty,
             ref size_values,
+            ref constraint,
             ..
         } = self
         {
@@ -34,6 +35,18 @@ impl ResolvedConstructedType {
                 )
             }
 
+            ty_attrs.extend(generator.generate_asn1_type_attr_tokens(name, "SEQUENCE_OF"));
+
+            let validate_impl = match constraint {
+                Some(ref constraint) => {
+                    let (validate_attrs, validate_impl) =
+                        constraint.generate_validate_impl(name, generator);
+                    ty_attrs.extend(validate_attrs);
+                    validate_impl
+                }
+                None => TokenStream::new(),
+            };
+
             let seq_of_type = Asn1ResolvedType::generate_name_maybe_aux_type(
                 ty,
                 generator,
@@ -47,6 +60,8 @@ impl ResolvedConstructedType {
                 #dir
                 #[asn(#ty_attrs)]
                 #vis struct #seq_of_type_ident(#vis Vec<#seq_of_type>);
+
+                #validate_impl
             })
         } else {
             Ok(TokenStream::new())
@@ -6,7 +6,10 @@ use quote::quote;
 
 use crate::error::Error;
 use crate::generator::Generator;
-use crate::resolver::asn::structs::types::{Asn1ResolvedType, ResolvedSetType};
+use crate::resolver::asn::structs::{
+    defs::DefinitionKey,
+    types::{Asn1ResolvedType, ResolvedSetType},
+};
 
 impl Asn1ResolvedType {
     pub(crate) fn generate_for_type(
@@ -46,19 +49,19 @@ impl Asn1ResolvedType {
     }
 
     pub(crate) fn generate_ident_for_reference(
-        reference: &str,
+        reference: &DefinitionKey,
         gen: &mut Generator,
     ) -> Result<Ident, Error> {
-        Ok(gen.to_type_ident(reference))
+        Ok(gen.reference_type_ident(reference))
     }
 
     fn generate_type_alias_for_reference(
         name: &str,
         gen: &mut Generator,
-        reference: &str,
+        reference: &DefinitionKey,
     ) -> Result<TokenStream, Error> {
         let referring = gen.to_type_ident(name);
-        let reference = gen.to_type_ident(reference);
+        let reference = gen.reference_type_ident(reference);
 
         let vis = gen.get_visibility_tokens();
 
@@ -103,9 +106,20 @@ impl ResolvedSetType {
         let vis = generator.get_visibility_tokens();
         let dir = generator.generate_derive_tokens();
 
+        let ty_elements = if generator.preserve_unknown_extensions {
+            quote! {
+                #ty_elements
+                #[asn(unknown = true)]
+                Unknown(i128, Vec<u8>),
+            }
+        } else {
+            ty_elements
+        };
+
+        let asn1_name = generator.generate_asn1_type_attr_tokens(&ty_ident.to_string(), "OPEN");
         let set_ty = quote! {
             #dir
-            #[asn(type = "OPEN")]
+            #[asn(type = "OPEN" #asn1_name)]
             #vis enum #ty_ident {
                 #ty_elements
             }
@@ -123,8 +137,18 @@ impl ResolvedSetType {
             let ty_ident =
                 Asn1ResolvedType::generate_name_maybe_aux_type(&ty.1, generator, Some(&name.0))?;
             let key: proc_macro2::TokenStream = ty.0.to_string().parse().unwrap();
-            let key_tokens = quote! {
-                #[asn(key = #key)]
+            // The value of an open type is encoded by XER inside an element with the name of the
+            // actual type. The auxiliary types already carry the name of the builtin type.
+            let key_tokens = match ty.1 {
+                Asn1ResolvedType::Reference(ref reference) if generator.needs_asn1_names() => {
+                    let reference = &reference.name;
+                    quote! {
+                        #[asn(key = #key, name = #reference)]
+                    }
+                }
+                _ => quote! {
+                    #[asn(key = #key)]
+                },
             };
 
             let variant_token = quote! {
@@ -1,13 +1,13 @@
 //! Code generation related utilities for `Asn1ResolvedValue`
 
-use proc_macro2::{Literal, TokenStream};
+use proc_macro2::{Ident, Literal, TokenStream};
 use quote::quote;
 
 use crate::error::Error;
 use crate::generator::Generator;
 use crate::resolver::asn::structs::{
-    types::{base::ResolvedBaseType, Asn1ResolvedType},
-    values::{Asn1ResolvedValue, ResolvedBaseValue},
+    types::{base::ResolvedBaseType, constructed::ResolvedConstructedType, Asn1ResolvedType},
+    values::{Asn1ResolvedValue, ResolvedBaseValue, ResolvedConstructedValue},
 };
 
 impl Asn1ResolvedValue {
@@ -60,4 +60,190 @@ impl Asn1ResolvedValue {
         };
         Ok(None)
     }
+
+    // Generates an expression for this value, of the generated type `ty_ident`. For example, a
+    // value `5` of a type `Priority ::= INTEGER (0..7)` would become `Priority(5u8)`.
+    //
+    // Since a Referenced Type is generated as a type alias, which cannot be used for constructing
+    // a value, the innermost referenced type is used as the type. `ty_ident` is required only when
+    // the value is not of a referenced type (eg. for a value of an 'inline' type of a component).
+    // The types of the components (or the elements) of a constructed value, that are 'inline'
+    // types, are named after `ty_ident` the way those are generated.
+    pub(crate) fn generate_value_expression(
+        &self,
+        ty_ident: Option<&Ident>,
+        gen: &mut Generator,
+    ) -> Result<TokenStream, Error> {
+        let ty_ident = match self {
+            Asn1ResolvedValue::ReferencedType { typeref, value } => {
+                let ty_ident = gen.reference_type_ident(typeref);
+                return value.generate_value_expression(Some(&ty_ident), gen);
+            }
+            Asn1ResolvedValue::Reference(ref r) => {
+                return Err(code_generate_error!(
+                    "Value Reference '{}' cannot be generated as a Value expression.",
+                    r
+                ));
+            }
+            _ => ty_ident.ok_or_else(|| {
+                code_generate_error!("Type of the Value '{:#?}' is not known.", self)
+            })?,
+        };
+
+        match self {
+            Asn1ResolvedValue::Base(ref b) => {
+                let inner = Self::generate_base_value_inner_expression(b, ty_ident, gen);
+                Ok(quote! { #ty_ident #inner })
+            }
+            Asn1ResolvedValue::Constructed(ResolvedConstructedValue::SequenceOf {
+                ref values,
+                ..
+            }) => {
+                let element_ty_ident = gen.to_type_ident(&format!("{}_Entry", ty_ident));
+                let mut elements = vec![];
+                for value in values {
+                    elements.push(value.generate_value_expression(Some(&element_ty_ident), gen)?);
+                }
+                Ok(quote! { #ty_ident(vec![#(#elements),*]) })
+            }
+            Asn1ResolvedValue::Constructed(ResolvedConstructedValue::Sequence {
+                ref typeref,
+                ref components,
+            }) => Self::generate_sequence_value_expression(typeref, components, ty_ident, gen),
+            Asn1ResolvedValue::Constructed(ResolvedConstructedValue::Choice {
+                ref identifier,
+                ref value,
+                ..
+            }) => {
+                let variant_ident = gen.to_type_ident(identifier);
+                let alternative_ty_ident =
+                    gen.to_type_ident(&format!("{}_{}", ty_ident, identifier));
+                let value = value.generate_value_expression(Some(&alternative_ty_ident), gen)?;
+                Ok(quote! { #ty_ident::#variant_ident(#value) })
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    // The value of a `SEQUENCE` or a `SET` is a struct expression with all the fields. The absent
+    // `OPTIONAL` components and extension additions are `None`.
+    fn generate_sequence_value_expression(
+        typeref: &Asn1ResolvedType,
+        values: &[(String, Asn1ResolvedValue)],
+        ty_ident: &Ident,
+        gen: &mut Generator,
+    ) -> Result<TokenStream, Error> {
+        let (components, additions, extensible) = match typeref {
+            Asn1ResolvedType::Constructed(ResolvedConstructedType::Sequence {
+                ref components,
+                ref additions,
+                extensible,
+                ..
+            })
+            | Asn1ResolvedType::Constructed(ResolvedConstructedType::Set {
+                ref components,
+                ref additions,
+                extensible,
+                ..
+            }) => (components, additions, *extensible),
+            _ => {
+                return Err(code_generate_error!(
+                    "Type of the Value '{:#?}' is not a SEQUENCE or a SET.",
+                    values
+                ))
+            }
+        };
+
+        let all_components = components.iter().map(|c| (c, c.optional)).chain(
+            additions
+                .iter()
+                .flat_map(|a| a.components.iter().map(|c| (c, true))),
+        );
+        let mut fields = vec![];
+        for (c, optional) in all_components {
+            let field_ident = gen.to_value_ident(&c.component.id);
+            let comp_ty_suffix = gen.to_type_ident(&c.component.id);
+            let comp_ty_ident = gen.to_type_ident(&format!("{}{}", ty_ident, comp_ty_suffix));
+            let value = match values.iter().find(|(id, _)| id == &c.component.id) {
+                Some((_, value)) => {
+                    let value = value.generate_value_expression(Some(&comp_ty_ident), gen)?;
+                    if optional {
+                        quote! { Some(#value) }
+                    } else {
+                        value
+                    }
+                }
+                None => quote! { None },
+            };
+            fields.push(quote! { #field_ident: #value });
+        }
+
+        if gen.preserve_unknown_extensions && extensible {
+            fields.push(quote! { unknown_extensions: vec![] });
+        }
+
+        Ok(quote! { #ty_ident { #(#fields),* } })
+    }
+
+    // The expression that follows the type in the value expression (eg. `(true)` for a
+    // `BOOLEAN`).
+    fn generate_base_value_inner_expression(
+        base: &ResolvedBaseValue,
+        ty_ident: &Ident,
+        gen: &Generator,
+    ) -> TokenStream {
+        match base {
+            ResolvedBaseValue::Integer(ref i) => {
+                let val = match i.typeref {
+                    Asn1ResolvedType::Base(ResolvedBaseType::Integer(ref typ)) => {
+                        gen.to_suffixed_literal(typ.bits, typ.signed, i.value)
+                    }
+                    _ => Literal::i128_unsuffixed(i.value),
+                };
+                quote! { (#val) }
+            }
+            ResolvedBaseValue::Enum(ref e) => {
+                let const_id = gen.to_const_ident(&e.identifier);
+                quote! { (#ty_ident::#const_id) }
+            }
+            ResolvedBaseValue::Boolean(ref b) => {
+                let val = b.value;
+                quote! { (#val) }
+            }
+            ResolvedBaseValue::Real(ref r) => {
+                let val = if r.value.is_nan() {
+                    quote! { f64::NAN }
+                } else if r.value == f64::INFINITY {
+                    quote! { f64::INFINITY }
+                } else if r.value == f64::NEG_INFINITY {
+                    quote! { f64::NEG_INFINITY }
+                } else {
+                    let val = Literal::f64_suffixed(r.value);
+                    quote! { #val }
+                };
+                quote! { (#val) }
+            }
+            ResolvedBaseValue::CharString(ref c) => {
+                let val = Literal::string(&c.value);
+                quote! { (#val.to_string()) }
+            }
+            ResolvedBaseValue::OctetString(ref o) => {
+                let bytes = o.value.iter().map(|b| Literal::u8_unsuffixed(*b));
+                quote! { (vec![#(#bytes),*]) }
+            }
+            ResolvedBaseValue::BitString(ref b) => {
+                if b.value.is_empty() {
+                    quote! { (bitvec::vec::BitVec::new()) }
+                } else {
+                    let bits = b.value.iter().map(|b| Literal::u8_unsuffixed(*b as u8));
+                    quote! { (bitvec::bitvec![u8, bitvec::order::Msb0; #(#bits),*]) }
+                }
+            }
+            ResolvedBaseValue::ObjectIdentifier(ref o) => {
+                let arcs = o.value.iter().map(|arc| Literal::u32_unsuffixed(*arc));
+                quote! { (vec![#(#arcs),*]) }
+            }
+            ResolvedBaseValue::Null => quote! {},
+        }
+    }
 }
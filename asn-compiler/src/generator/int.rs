@@ -1,6 +1,6 @@
 //! Code Generation module
 
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap, HashSet};
 
 use heck::{ToShoutySnakeCase, ToSnakeCase};
 use proc_macro2::{Ident, Literal, Span, TokenStream};
@@ -12,7 +12,11 @@ use lazy_static::lazy_static;
 use crate::error::Error;
 use crate::resolver::Resolver;
 
-use crate::resolver::asn::structs::{types::Asn1ResolvedType, values::Asn1ResolvedValue};
+use crate::resolver::asn::structs::{
+    defs::DefinitionKey,
+    types::{constraints::Asn1ConstraintValueSet, tags::ResolvedTagging, Asn1ResolvedType},
+    values::Asn1ResolvedValue,
+};
 
 /// Supported Codecs
 #[derive(clap::ValueEnum, Clone, Debug, PartialEq, Eq, Hash)]
@@ -22,6 +26,21 @@ pub enum Codec {
 
     /// Generate code for ASN.1 UPER Codec
     Uper,
+
+    /// Generate code for ASN.1 BER Codec
+    Ber,
+
+    /// Generate code for ASN.1 DER Codec
+    Der,
+
+    /// Generate code for ASN.1 OER Codec
+    Oer,
+
+    /// Generate code for ASN.1 JER Codec
+    Jer,
+
+    /// Generate code for ASN.1 XER Codec
+    Xer,
 }
 
 /// Supported Derive Macros
@@ -63,6 +82,11 @@ lazy_static! {
         let mut m = HashMap::new();
         m.insert(Codec::Aper, "asn1_codecs_derive::AperCodec".to_string());
         m.insert(Codec::Uper, "asn1_codecs_derive::UperCodec".to_string());
+        m.insert(Codec::Ber, "asn1_codecs_derive::BerCodec".to_string());
+        m.insert(Codec::Der, "asn1_codecs_derive::DerCodec".to_string());
+        m.insert(Codec::Oer, "asn1_codecs_derive::OerCodec".to_string());
+        m.insert(Codec::Jer, "asn1_codecs_derive::JerCodec".to_string());
+        m.insert(Codec::Xer, "asn1_codecs_derive::XerCodec".to_string());
         m
     };
     static ref DERIVE_TOKENS: HashMap<Derive, String> = {
@@ -95,6 +119,33 @@ pub(crate) struct Generator {
 
     // Derives
     pub(crate) derives: Vec<Derive>,
+
+    // The types defined in the ASN.1 modules.
+    pub(crate) type_names: HashSet<DefinitionKey>,
+
+    // Taggings of the types defined in the ASN.1 modules.
+    pub(crate) type_taggings: HashMap<DefinitionKey, Vec<ResolvedTagging>>,
+
+    // Values of the `INTEGER` Value Set definitions.
+    pub(crate) value_sets: HashMap<DefinitionKey, Asn1ConstraintValueSet>,
+
+    // Whether to generate the fields and variants for holding the extensions not known to us.
+    pub(crate) preserve_unknown_extensions: bool,
+
+    // Whether to generate a Rust module for each of the ASN.1 modules.
+    pub(crate) module_per_asn1_module: bool,
+
+    // Whether the generated codecs check the Inner Type Constraints on the values when decoding.
+    // These are always checked when encoding.
+    pub(crate) validate_on_decode: bool,
+
+    // The ASN.1 module, whose definitions are being generated.
+    pub(crate) module: String,
+
+    // The definitions from the other ASN.1 modules, that are referred to by the definitions of
+    // the module being generated. These are brought in scope with `use`s, when a Rust module is
+    // generated for each of the ASN.1 modules.
+    pub(crate) uses: BTreeSet<DefinitionKey>,
 }
 
 impl Generator {
@@ -106,6 +157,14 @@ impl Generator {
             visibility: visibility.clone(),
             codecs,
             derives,
+            type_names: HashSet::new(),
+            type_taggings: HashMap::new(),
+            value_sets: HashMap::new(),
+            preserve_unknown_extensions: false,
+            module_per_asn1_module: false,
+            validate_on_decode: false,
+            module: String::new(),
+            uses: BTreeSet::new(),
         }
     }
 
@@ -115,35 +174,140 @@ impl Generator {
         // FIXME: Not sure how to make sure the crates defined here are a dependency.
         // May be can just do with documenting it.
 
-        // First Get the 'consts' for builtin values.
+        self.type_names = resolver
+            .get_resolved_types()
+            .into_iter()
+            .map(|(k, _)| k.clone())
+            .collect();
+        self.type_taggings = resolver.type_taggings.clone();
+        self.value_sets = resolver.value_sets.clone();
+
+        if self.module_per_asn1_module {
+            let modules = resolver
+                .resolved_defs
+                .keys()
+                .map(|k| k.module.clone())
+                .collect::<BTreeSet<String>>();
+            for module in modules {
+                let values = resolver
+                    .get_resolved_values()
+                    .into_iter()
+                    .filter(|(k, _)| k.module == module)
+                    .collect::<Vec<_>>();
+                let types = resolver
+                    .get_resolved_types()
+                    .into_iter()
+                    .filter(|(k, _)| k.module == module)
+                    .collect::<Vec<_>>();
+                self.uses.clear();
+                let items = self.generate_items(&values, &types)?;
+                let item = self.generate_rust_module(&module, items);
+                self.items.push(item);
+            }
+        } else {
+            let unique = Self::unique_definitions(resolver)?;
+
+            // Without the Rust modules, the items are generated in the order of their names.
+            let mut values = resolver
+                .get_resolved_values()
+                .into_iter()
+                .filter(|(k, _)| unique.contains(k))
+                .collect::<Vec<_>>();
+            values.sort_by(|(a, _), (b, _)| a.name.cmp(&b.name));
+            let mut types = resolver
+                .get_resolved_types()
+                .into_iter()
+                .filter(|(k, _)| unique.contains(k))
+                .collect::<Vec<_>>();
+            types.sort_by(|(a, _), (b, _)| a.name.cmp(&b.name));
+            let items = self.generate_items(&values, &types)?;
+            self.items.extend(items);
+        }
+
+        Ok(self
+            .items
+            .iter()
+            .map(|t| t.to_string())
+            .collect::<Vec<String>>()
+            .join("\n\n"))
+    }
+
+    // Generates the items for the given values and types: the 'consts' for the builtin values
+    // first, then the types, followed by the auxiliary types required by those.
+    fn generate_items(
+        &mut self,
+        values: &[(&DefinitionKey, &Asn1ResolvedValue)],
+        types: &[(&DefinitionKey, &Asn1ResolvedType)],
+    ) -> Result<Vec<TokenStream>, Error> {
         let mut items = vec![];
-        for (k, v) in resolver.get_resolved_values() {
-            let item = Asn1ResolvedValue::generate_const_for_base_value(k, v, self)?;
+        for (k, v) in values {
+            self.module = k.module.clone();
+            let item = Asn1ResolvedValue::generate_const_for_base_value(&k.name, v, self)?;
             if let Some(it) = item {
                 items.push(it)
             }
         }
 
-        // Now get the types
-        for (k, t) in resolver.get_resolved_types() {
-            let item = Asn1ResolvedType::generate_for_type(k, t, self)?;
+        for (k, t) in types {
+            self.module = k.module.clone();
+            let item = Asn1ResolvedType::generate_for_type(&k.name, t, self)?;
             if let Some(it) = item {
                 items.push(it)
             }
         }
 
-        for aux in &self.aux_items {
-            items.push(aux.clone())
-        }
+        items.append(&mut self.aux_items);
 
-        self.items.extend(items);
+        Ok(items)
+    }
 
-        Ok(self
-            .items
-            .iter()
-            .map(|t| t.to_string())
-            .collect::<Vec<String>>()
-            .join("\n\n"))
+    // Generates a Rust module for an ASN.1 module, with the `use`s for the definitions from the
+    // other modules, that it refers to.
+    fn generate_rust_module(&self, module: &str, items: Vec<TokenStream>) -> TokenStream {
+        let vis = self.get_visibility_tokens();
+        let module_ident = self.to_module_ident(module);
+        let uses = self.uses.iter().map(|key| {
+            let module_ident = self.to_module_ident(&key.module);
+            let ty_ident = self.to_type_ident(&key.name);
+            quote! { use super::#module_ident::#ty_ident; }
+        });
+
+        quote! {
+            #vis mod #module_ident {
+                #(#uses)*
+
+                #(#items)*
+            }
+        }
+    }
+
+    // Without a Rust module for each of the ASN.1 modules, the definitions with the same name in
+    // different ASN.1 modules are generated as the same item. That's fine only when the
+    // definitions are the same (eg. a type copied in another module, referring to the copies of
+    // the other definitions), so only one of those is generated. Returns the definitions to be
+    // generated.
+    fn unique_definitions(resolver: &Resolver) -> Result<HashSet<&DefinitionKey>, Error> {
+        let mut defined = HashMap::new();
+        let mut unique = HashSet::new();
+        for (key, def) in &resolver.resolved_defs {
+            match defined.get(&key.name) {
+                Some((other, other_def)) => {
+                    if def != *other_def {
+                        return Err(code_generate_error!(
+                            "Definition '{}' is defined differently in the modules '{}' and '{}'! A Rust module should be generated for each ASN.1 module.",
+                            key.name,
+                            other,
+                            key.module
+                        ));
+                    }
+                }
+                None => {
+                    defined.insert(&key.name, (&key.module, def));
+                    unique.insert(key);
+                }
+            }
+        }
+        Ok(unique)
     }
 
     pub(crate) fn to_type_ident(&self, name: &str) -> Ident {
@@ -153,6 +317,22 @@ impl Generator {
         )
     }
 
+    // The identifier of a type defined in an ASN.1 module, referred to from the module being
+    // generated. A type from another module is brought in scope with a `use` (See `uses`).
+    pub(crate) fn reference_type_ident(&mut self, reference: &DefinitionKey) -> Ident {
+        if self.module_per_asn1_module && reference.module != self.module {
+            self.uses.insert(reference.clone());
+        }
+        self.to_type_ident(&reference.name)
+    }
+
+    pub(crate) fn to_module_ident(&self, name: &str) -> Ident {
+        Ident::new(
+            &name.to_lowercase().replace(['-', ' '], "_"),
+            Span::call_site(),
+        )
+    }
+
     pub(crate) fn to_const_ident(&self, name: &str) -> Ident {
         Ident::new(&name.to_shouty_snake_case(), Span::call_site())
     }
@@ -219,7 +399,69 @@ impl Generator {
         }
     }
 
+    // The identifiers of the components, alternatives and named values from the ASN.1 definitions
+    // are required only by the codecs that encode them (eg. JER).
+    pub(crate) fn needs_asn1_names(&self) -> bool {
+        self.codecs.contains(&Codec::Jer) || self.codecs.contains(&Codec::Xer)
+    }
+
+    // The tags are required only by the codecs that encode them (BER and DER).
+    pub(crate) fn needs_asn1_tags(&self) -> bool {
+        self.codecs.contains(&Codec::Ber) || self.codecs.contains(&Codec::Der)
+    }
+
+    // The attributes of a type, that are required only by some of the codecs.
+    //
+    // The name of the type is required only by the XER codec. For the types defined in the ASN.1
+    // modules, it is the name from the definition. The auxiliary types generated for the types
+    // used inside other types use the name of the `builtin` type instead. Only the types defined in
+    // the ASN.1 modules can be tagged.
+    pub(crate) fn generate_asn1_type_attr_tokens(&self, name: &str, builtin: &str) -> TokenStream {
+        let mut tokens = TokenStream::new();
+        let key = DefinitionKey::new(&self.module, name);
+        if self.codecs.contains(&Codec::Xer) {
+            let name = if self.type_names.contains(&key) {
+                name
+            } else {
+                builtin
+            };
+            tokens.extend(quote! { , name = #name });
+        }
+
+        if let Some(tagging) = self.type_taggings.get(&key) {
+            if let Some(tag) = self.generate_asn1_tag_tokens(tagging) {
+                tokens.extend(quote! { , #tag });
+            }
+        }
+
+        tokens
+    }
+
+    // The taggings of a type or a component as a `tag` attribute, eg.
+    // `tag = "[0] EXPLICIT [APPLICATION 1] IMPLICIT"`. An empty value denotes an untagged component.
+    pub(crate) fn generate_asn1_tag_tokens(
+        &self,
+        tagging: &[ResolvedTagging],
+    ) -> Option<TokenStream> {
+        if !self.needs_asn1_tags() {
+            return None;
+        }
+
+        let tag = tagging
+            .iter()
+            .map(|t| t.to_string())
+            .collect::<Vec<String>>()
+            .join(" ");
+        Some(quote! { tag = #tag })
+    }
+
     pub(crate) fn generate_derive_tokens(&self) -> TokenStream {
+        self.generate_derive_tokens_except(&[])
+    }
+
+    // Generates the derive tokens without the given `Derive`s. Used for the types that cannot
+    // derive some of these, like the `f64` backed `REAL` type for the `Eq`.
+    pub(crate) fn generate_derive_tokens_except(&self, except: &[Derive]) -> TokenStream {
         let mut tokens = vec![];
         for codec in &self.codecs {
             let codec_token = CODEC_TOKENS.get(codec).unwrap();
@@ -228,10 +470,12 @@ impl Generator {
 
         for derive in &self.derives {
             if derive == &Derive::All {
-                for derive_token in DERIVE_TOKENS.values() {
-                    tokens.push(derive_token.to_string());
+                for (derive, derive_token) in DERIVE_TOKENS.iter() {
+                    if !except.contains(derive) {
+                        tokens.push(derive_token.to_string());
+                    }
                 }
-            } else {
+            } else if !except.contains(derive) {
                 let derive_token = DERIVE_TOKENS.get(derive).unwrap();
                 tokens.push(derive_token.to_string());
             }
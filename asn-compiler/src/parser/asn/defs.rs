@@ -1,14 +1,15 @@
 //! Top level handling of definitions
 
 use crate::error::Error;
-use crate::tokenizer::Token;
+use crate::tokenizer::{types::TokenType, Token};
 
 use crate::parser::{
     asn::structs::{
         defs::{
             Asn1AssignmentKind, Asn1Definition, Asn1ObjectAssignment, Asn1ObjectClassAssignment,
-            Asn1ObjectSetAssignment, Asn1TypeAssignment, Asn1ValueAssignment, DefinitionParam,
-            DefinitionParams, DummyReferenceKind, GovernerKind, ParamDummyReference, ParamGoverner,
+            Asn1ObjectSetAssignment, Asn1TypeAssignment, Asn1ValueAssignment,
+            Asn1ValueSetAssignment, DefinitionParam, DefinitionParams, DummyReferenceKind,
+            GovernerKind, ParamDummyReference, ParamGoverner,
         },
         types::{
             ioc::{Asn1Object, Asn1ObjectSet, Asn1ObjectValue},
@@ -22,7 +23,7 @@ use crate::parser::{
 
 use super::types::{
     ioc::{parse_class, parse_object_from_class, parse_object_set, parse_object_set_from_class},
-    parse_type,
+    parse_type, parse_value_set,
 };
 use super::values::parse_value;
 
@@ -73,14 +74,17 @@ impl Asn1Definition {
         }
 
         for (idx, actual) in actual_params.iter().enumerate() {
-            let replace = actual.param_string();
             let source = &params.ordered[idx].dummyref.name;
 
-            for token in params.type_tokens.iter_mut() {
+            let mut type_tokens = vec![];
+            for token in params.type_tokens {
                 if &token.text == source {
-                    token.text = replace.clone()
+                    type_tokens.extend(actual.param_tokens(&token));
+                } else {
+                    type_tokens.push(token);
                 }
             }
+            params.type_tokens = type_tokens;
         }
         let (ty, _) = parse_type(&params.type_tokens)?;
         Ok(ty)
@@ -220,7 +224,8 @@ fn parse_value_assignment(tokens: &[Token]) -> Result<(Asn1Definition, usize), E
     ))
 }
 
-// Parse A `TypeAssignment`, a `ObjectClassAssignement or `ObjectSetAssignment`
+// Parse A `TypeAssignment`, a `ObjectClassAssignement`, `ObjectSetAssignment` or
+// `ValueSetTypeAssignment`
 //
 // All the above assignments start with a lowe-case letter and will have to be parsed into their
 // respective 'values'. Returns the corresponding variant of the `Asn1Definition` and  the number
@@ -242,6 +247,11 @@ fn parse_typeish_definition(tokens: &[Token]) -> Result<(Asn1Definition, usize),
         return Ok(x);
     }
 
+    if let Ok(x) = parse_value_set_assignment(tokens) {
+        log::trace!("Parsed Value Set Assignment.");
+        return Ok(x);
+    }
+
     Err(parse_error_log!(
         "Failed to parse a definition at Token: {:?}",
         tokens[0]
@@ -368,6 +378,67 @@ fn parse_object_set_assignment(tokens: &[Token]) -> Result<(Asn1Definition, usiz
     ))
 }
 
+// Parse a Value Set Assignment
+//
+// Identifier [{Params}] Type ::= { ElementSetSpecs }
+//
+// For a Parameterized Value Set, the tokens of the Type are followed by those of the element set
+// as a constraint, so that the actual params are applied to a constrained Type.
+fn parse_value_set_assignment(tokens: &[Token]) -> Result<(Asn1Definition, usize), Error> {
+    let mut consumed = 0;
+
+    if !expect_token(&tokens[consumed..], Token::is_type_reference)? {
+        return Err(unexpected_token!("Type Reference", tokens[consumed]));
+    }
+    let id = tokens[consumed].text.clone();
+    consumed += 1;
+
+    // Parse Optional Params
+    let (params, params_consumed) = match parse_params(&tokens[consumed..]) {
+        Ok(result) => (Some(result.0), result.1),
+        Err(_) => (None, 0),
+    };
+    consumed += params_consumed;
+
+    let type_start = consumed;
+    let (typeref, typeref_consumed) = parse_type(&tokens[consumed..])?;
+    consumed += typeref_consumed;
+
+    if !expect_token(&tokens[consumed..], Token::is_assignment)? {
+        return Err(unexpected_token!("::=", tokens[consumed]));
+    }
+    consumed += 1;
+
+    let set_start = consumed;
+    let (set, set_consumed) = parse_value_set(&tokens[consumed..])?;
+    consumed += set_consumed;
+
+    let params = match params {
+        Some(mut pars) => {
+            let mut type_tokens = tokens[type_start..type_start + typeref_consumed].to_vec();
+            let mut set_tokens = tokens[set_start..consumed].to_vec();
+            let last = set_tokens.len() - 1;
+            set_tokens[0].r#type = TokenType::RoundBegin;
+            set_tokens[0].text = "(".to_string();
+            set_tokens[last].r#type = TokenType::RoundEnd;
+            set_tokens[last].text = ")".to_string();
+            type_tokens.extend(set_tokens);
+            pars.type_tokens = type_tokens;
+            Some(pars)
+        }
+        None => None,
+    };
+
+    Ok((
+        Asn1Definition {
+            kind: Asn1AssignmentKind::ValueSet(Asn1ValueSetAssignment { id, typeref, set }),
+            params,
+            resolved: false,
+        },
+        consumed,
+    ))
+}
+
 fn parse_params(tokens: &[Token]) -> Result<(DefinitionParams, usize), Error> {
     let mut consumed = 0;
 
@@ -547,6 +618,52 @@ UE-RadioAccessCapability-LaterNonCriticalExtensions ::= SEQUENCE {
 "#,
                 success: true,
             },
+            ParseDefinitionTestCase {
+                input: r#"
+RANfunction-Item ::= SEQUENCE {
+    ranFunctionOID      OBJECT IDENTIFIER,
+    ranFunctionRelOID   RELATIVE-OID    OPTIONAL,
+    ...
+}
+"#,
+                success: true,
+            },
+            ParseDefinitionTestCase {
+                input: "RANfunction-RelOID ::= RELATIVE-OID",
+                success: true,
+            },
+            ParseDefinitionTestCase {
+                input: "Msisdn ::= NumericString (SIZE (1..15))",
+                success: true,
+            },
+            ParseDefinitionTestCase {
+                input: "Names ::= SEQUENCE { bmp BMPString, t61 T61String OPTIONAL, gen GeneralString, univ UniversalString }",
+                success: true,
+            },
+            ParseDefinitionTestCase {
+                input: "Times ::= SEQUENCE { utc UTCTime, gt GeneralizedTime, d DATE, t TIME-OF-DAY, dt DATE-TIME, dur DURATION }",
+                success: true,
+            },
+            ParseDefinitionTestCase {
+                input: "Measurement ::= SEQUENCE { value REAL, accuracy REAL OPTIONAL }",
+                success: true,
+            },
+            ParseDefinitionTestCase {
+                input: "Config ::= SEQUENCE { enabled BOOLEAN DEFAULT TRUE, ratio REAL DEFAULT -0.5, name IA5String DEFAULT \"none\", flags BIT STRING DEFAULT '0101'B }",
+                success: true,
+            },
+            ParseDefinitionTestCase {
+                input: "MyCodes INTEGER ::= { 1 | 2 | 5..9, ... }",
+                success: true,
+            },
+            ParseDefinitionTestCase {
+                input: "Ranged {INTEGER : upper} INTEGER (0..255) ::= { 1..upper }",
+                success: true,
+            },
+            ParseDefinitionTestCase {
+                input: "MyCodes INTEGER ::= { }",
+                success: false,
+            },
         ];
 
         for tc in test_cases {
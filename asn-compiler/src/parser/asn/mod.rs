@@ -14,7 +14,7 @@ pub(crate) mod types;
 pub(crate) mod values;
 
 /// Implementation of Object Identifier.
-mod oid;
+pub(crate) mod oid;
 
 /// Output Types of the Parsers.
 pub(crate) mod structs;
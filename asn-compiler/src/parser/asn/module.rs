@@ -2,16 +2,16 @@
 use std::collections::HashMap;
 
 use crate::error::Error;
-use crate::tokenizer::Token;
+use crate::tokenizer::{LineColumn, Token};
 
-use crate::parser::utils::{expect_keyword, expect_one_of_keywords, expect_token};
+use crate::parser::utils::{expect_keyword, expect_one_of_keywords, expect_token, expect_tokens};
 
 use super::{
     defs::parse_definition,
     oid::parse_object_identifier,
     structs::{
         defs::Asn1Definition,
-        module::{Asn1Module, Asn1ModuleName, Asn1ModuleTag},
+        module::{Asn1Module, Asn1ModuleExports, Asn1ModuleName, Asn1ModuleTag},
         oid::ObjectIdentifier,
     },
 };
@@ -84,12 +84,12 @@ where
         return Err(unexpected_token!("BEGIN", tokens[consumed]));
     }
 
-    // Parse but ignore exports if any (by default everything is exported).
-    let (_, exports_consumed) = parse_module_maybe_exports(&tokens[consumed..])?;
+    let (exports, exports_consumed) = parse_module_maybe_exports(&tokens[consumed..])?;
     consumed += exports_consumed;
     log::trace!("Parsed EXPORTS. Consumed {} tokens.", consumed);
 
-    let (imports, imports_consumed) = parse_module_imports(&tokens[consumed..])?;
+    let ((imports, import_locations), imports_consumed) =
+        parse_module_imports(&tokens[consumed..])?;
     consumed += imports_consumed;
     log::trace!(
         "Parsed IMPORTS. Consumed {} tokens. Parsing Definitions Now",
@@ -116,38 +116,65 @@ where
         .name(name)
         .tags(tags)
         .imports(imports)
+        .import_locations(import_locations)
+        .exports(exports)
         .definitions(definitions);
     Ok((module, consumed))
 }
 
-fn parse_module_maybe_exports(tokens: &[Token]) -> Result<((), usize), Error> {
+// Parses the `EXPORTS` clause if any. The exported symbols are recorded by their names, so
+// `Foo{}` for a Parameterized Type is recorded as `Foo`.
+fn parse_module_maybe_exports(tokens: &[Token]) -> Result<(Asn1ModuleExports, usize), Error> {
     let mut consumed = 0;
-    if expect_keyword(&tokens[consumed..], "EXPORTS")? {
+    if !expect_keyword(&tokens[consumed..], "EXPORTS")? {
+        return Ok((Asn1ModuleExports::Absent, consumed));
+    }
+    consumed += 1;
+
+    if expect_keyword(&tokens[consumed..], "ALL")? {
         consumed += 1;
-        loop {
-            if expect_token(&tokens[consumed..], Token::is_semicolon)? {
-                consumed += 1;
-                break;
-            }
+        if !expect_token(&tokens[consumed..], Token::is_semicolon)? {
+            return Err(unexpected_token!(";", tokens[consumed]));
+        }
+        consumed += 1;
+        return Ok((Asn1ModuleExports::All, consumed));
+    }
 
-            if expect_token(&tokens[consumed..], Token::is_identifier)? {
-                consumed += 1;
-            }
+    let mut symbols = vec![];
+    loop {
+        if expect_token(&tokens[consumed..], Token::is_semicolon)? {
+            consumed += 1;
+            break;
+        }
 
-            if expect_token(&tokens[consumed..], Token::is_comma)? {
-                consumed += 1;
-            }
+        if expect_token(&tokens[consumed..], Token::is_identifier)? {
+            symbols.push(tokens[consumed].text.clone());
+            consumed += 1;
+        } else {
+            return Err(unexpected_token!("IDENTIFIER", tokens[consumed]));
+        }
+
+        if expect_tokens(
+            &tokens[consumed..],
+            &[&[Token::is_curly_begin], &[Token::is_curly_end]],
+        )? {
+            consumed += 2;
+        }
+
+        if expect_token(&tokens[consumed..], Token::is_comma)? {
+            consumed += 1;
         }
     }
-    Ok(((), consumed))
+    Ok((Asn1ModuleExports::Symbols(symbols), consumed))
 }
 
-fn parse_module_imports(
-    tokens: &[Token],
-) -> Result<(HashMap<String, Asn1ModuleName>, usize), Error> {
+type ParsedImports = (HashMap<String, Asn1ModuleName>, HashMap<String, LineColumn>);
+
+fn parse_module_imports(tokens: &[Token]) -> Result<(ParsedImports, usize), Error> {
     let mut consumed = 0;
 
     let mut imports = HashMap::new();
+    let mut import_locations = HashMap::new();
     if expect_keyword(&tokens[consumed..], "IMPORTS")? {
         consumed += 1;
 
@@ -156,7 +183,7 @@ fn parse_module_imports(
             while !expect_keyword(&tokens[consumed..], "FROM")? {
                 if expect_token(&tokens[consumed..], Token::is_identifier)? {
                     let definition = tokens[consumed].text.clone();
-                    imported_defs.push(definition);
+                    imported_defs.push((definition, tokens[consumed].span.start()));
                 }
                 consumed += 1;
                 if expect_token(&tokens[consumed..], Token::is_comma)? {
@@ -167,10 +194,11 @@ fn parse_module_imports(
             let (module_name, module_name_consumed) = parse_module_name(&tokens[consumed..])?;
             consumed += module_name_consumed;
 
-            for d in imported_defs {
+            for (d, location) in imported_defs {
                 if imports.contains_key(&d) {
                     return Err(parse_error!("Definition '{}' is imported twice", d));
                 }
+                let _ = import_locations.insert(d.clone(), location);
                 let _ = imports.insert(d, module_name.clone());
             }
 
@@ -181,7 +209,7 @@ fn parse_module_imports(
         }
     }
 
-    Ok((imports, consumed))
+    Ok(((imports, import_locations), consumed))
 }
 
 fn maybe_parse_header_tags(tokens: &[Token]) -> Result<(Asn1ModuleTag, usize), Error> {
@@ -275,8 +303,35 @@ mod tests {
 
         assert!(module.definitions.is_empty());
         assert!(module.imports.is_empty());
-        assert_eq!(module._tags, Asn1ModuleTag::Explicit);
+        assert_eq!(module.tags, Asn1ModuleTag::Explicit);
     }
+    #[test]
+    fn parse_module_exports() {
+        let test_cases = vec![
+            ("", Asn1ModuleExports::Absent, 0),
+            ("EXPORTS ALL;", Asn1ModuleExports::All, 3),
+            ("EXPORTS;", Asn1ModuleExports::Symbols(vec![]), 2),
+            (
+                "EXPORTS Foo, bar, Baz{};",
+                Asn1ModuleExports::Symbols(vec!["Foo".into(), "bar".into(), "Baz".into()]),
+                9,
+            ),
+        ];
+
+        for (input, expected, expected_consumed) in test_cases {
+            let input = format!("{} END", input);
+            let reader = std::io::BufReader::new(std::io::Cursor::new(input.clone()));
+            let tokens = tokenize(reader).unwrap();
+
+            let result = parse_module_maybe_exports(&tokens);
+            assert!(result.is_ok(), "{}: {:#?}", input, result.err().unwrap());
+
+            let (exports, consumed) = result.unwrap();
+            assert_eq!(exports, expected, "{}", input);
+            assert_eq!(consumed, expected_consumed, "{}", input);
+        }
+    }
+
     // TODO: Test Cases for imports (count), Tags (type), Definitions (count))
     // TODO: Test Cases for missing BEGIN, END, DEFINITIONS, ::=
 
@@ -28,6 +28,11 @@ lazy_static! {
     };
 }
 
+// The number of a well known name of an OID component (eg. `1` for `iso`).
+pub(crate) fn well_known_oid_number(name: &str) -> Option<u32> {
+    WELL_KNOWN_OID_NAMES.get(name).copied()
+}
+
 // Parses a named OID component
 //
 // Parses named OID components of the form `iso` or `iso(1)`
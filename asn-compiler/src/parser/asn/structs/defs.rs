@@ -2,9 +2,11 @@
 use crate::tokenizer::Token;
 
 use super::types::{
+    constraints::{Asn1Constraint, ElementSet},
     ioc::{Asn1Object, Asn1ObjectClass, Asn1ObjectSet},
     Asn1Type,
 };
+use super::values::Asn1Value;
 
 /// Struct representing an Object Class Assignment
 #[derive(Debug, Clone)]
@@ -77,14 +79,50 @@ pub(crate) struct Asn1ValueAssignment {
     /// Type Reference
     pub(crate) typeref: Asn1Type,
 
-    /// Value Text
-    pub(crate) value: String,
+    /// Value
+    pub(crate) value: Asn1Value,
+}
+
+/// A Value Set Assignment in ASN.1 Module
+///
+/// A Value Set Assignment in ASN.1 module looks like -
+///
+/// ```asn
+///
+///     MyCodes INTEGER ::= { 1 | 2 | 5..9, ... }
+///
+/// ```
+///
+/// The Value Set is a subtype of the Type, given by the element set. It is resolved like the Type
+/// with the element set as a constraint (See [`Asn1ValueSetAssignment::constrained_type`]).
+#[derive(Debug, Clone)]
+pub(crate) struct Asn1ValueSetAssignment {
+    /// Identifier for the value set
+    pub(crate) id: String,
+
+    /// Type of the values in the set
+    pub(crate) typeref: Asn1Type,
+
+    /// Values in the set
+    pub(crate) set: ElementSet,
+}
+
+impl Asn1ValueSetAssignment {
+    // The Type constrained by the element set of the Value Set.
+    pub(crate) fn constrained_type(&self) -> Asn1Type {
+        let mut ty = self.typeref.clone();
+        ty.constraints
+            .get_or_insert_with(Vec::new)
+            .push(Asn1Constraint::Subtype(self.set.clone()));
+        ty
+    }
 }
 
 #[derive(Debug, Clone)]
 pub(crate) enum Asn1AssignmentKind {
     Value(Asn1ValueAssignment),
     Type(Asn1TypeAssignment),
+    ValueSet(Asn1ValueSetAssignment),
     Class(Asn1ObjectClassAssignment),
     ObjectSet(Asn1ObjectSetAssignment),
     Object(Asn1ObjectAssignment),
@@ -95,6 +133,7 @@ impl Asn1AssignmentKind {
         match self {
             Self::Value(ref v) => v.id.clone(),
             Self::Type(ref t) => t.id.clone(),
+            Self::ValueSet(ref s) => s.id.clone(),
             Self::Class(ref c) => c.id.clone(),
             Self::ObjectSet(ref s) => s.id.clone(),
             Self::Object(ref o) => o.id.clone(),
@@ -103,8 +142,13 @@ impl Asn1AssignmentKind {
 
     pub fn dependent_references(&self) -> Vec<String> {
         match self {
-            Self::Value(ref v) => v.typeref.dependent_references(),
+            Self::Value(ref v) => {
+                let mut references = v.typeref.dependent_references();
+                references.extend(v.value.dependent_references());
+                references
+            }
             Self::Type(ref t) => t.typeref.dependent_references(),
+            Self::ValueSet(ref s) => s.constrained_type().dependent_references(),
             Self::Object(ref o) => vec![o.object.class.clone()],
             Self::ObjectSet(ref s) => s.dependent_references(),
             Self::Class(ref c) => c.dependent_references(),
@@ -157,6 +201,7 @@ macro_rules! is_assignment_kind {
 is_assignment_kind! {
     (is_value_assignment, Asn1AssignmentKind::Value),
     (is_type_assignment, Asn1AssignmentKind::Type),
+    (is_value_set_assignment, Asn1AssignmentKind::ValueSet),
     (is_class_assignment, Asn1AssignmentKind::Class),
     (is_object_set_assignment, Asn1AssignmentKind::ObjectSet),
     (is_object_assignment, Asn1AssignmentKind::Object),
@@ -187,6 +232,7 @@ macro_rules! get_inner {
 get_inner! {
     (get_inner_value, Asn1AssignmentKind::Value, Asn1ValueAssignment),
     (get_inner_type, Asn1AssignmentKind::Type, Asn1TypeAssignment),
+    (get_inner_value_set, Asn1AssignmentKind::ValueSet, Asn1ValueSetAssignment),
     (get_inner_class, Asn1AssignmentKind::Class, Asn1ObjectClassAssignment),
     (get_inner_object_set, Asn1AssignmentKind::ObjectSet, Asn1ObjectSetAssignment),
     (get_inner_object, Asn1AssignmentKind::Object, Asn1ObjectAssignment),
@@ -7,3 +7,5 @@ pub mod oid;
 pub mod defs;
 
 pub mod types;
+
+pub mod values;
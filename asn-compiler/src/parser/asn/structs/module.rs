@@ -1,12 +1,16 @@
 //! ASN.1 Module Level Structures and other functionality
 
-use std::collections::{hash_map::Iter, HashMap};
+use std::collections::{
+    hash_map::{Iter, IterMut},
+    HashMap,
+};
 
 use topological_sort::TopologicalSort;
 
 use crate::parser::asn::structs::{defs::Asn1Definition, oid::ObjectIdentifier};
+use crate::tokenizer::LineColumn;
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Asn1ModuleTag {
     Explicit,
     Implicit,
@@ -43,6 +47,28 @@ impl Asn1ModuleName {
     }
 }
 
+/// Symbols exported by an ASN Module.
+///
+/// When the `EXPORTS` clause is absent or is `EXPORTS ALL`, all the symbols defined in (or imported
+/// into) the module are exported. Otherwise only the listed symbols are exported.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum Asn1ModuleExports {
+    #[default]
+    Absent,
+    All,
+    Symbols(Vec<String>),
+}
+
+impl Asn1ModuleExports {
+    /// Whether the given symbol is exported.
+    pub fn exports(&self, symbol: &str) -> bool {
+        match self {
+            Self::Absent | Self::All => true,
+            Self::Symbols(ref symbols) => symbols.iter().any(|s| s == symbol),
+        }
+    }
+}
+
 /// Definition of a 'parsed' ASN Module
 ///
 /// When an ASN module is successfully parsed, it contains a set of definitions that result from
@@ -54,11 +80,11 @@ impl Asn1ModuleName {
 #[derive(Debug, Default)]
 pub struct Asn1Module {
     pub(in crate::parser) imports: HashMap<String, Asn1ModuleName>,
-    pub(in crate::parser) _exports: Option<Vec<Asn1Definition>>,
+    pub(in crate::parser) import_locations: HashMap<String, LineColumn>,
+    pub(in crate::parser) exports: Asn1ModuleExports,
     pub(in crate::parser) name: Asn1ModuleName,
-    pub(in crate::parser) _tags: Asn1ModuleTag,
+    pub(in crate::parser) tags: Asn1ModuleTag,
     pub(in crate::parser) definitions: HashMap<String, Asn1Definition>,
-    pub(in crate::parser) _exports_all: bool,
 }
 
 impl Asn1Module {
@@ -66,14 +92,25 @@ impl Asn1Module {
         Self { name, ..self }
     }
 
-    pub fn tags(self, _tags: Asn1ModuleTag) -> Self {
-        Self { _tags, ..self }
+    pub fn tags(self, tags: Asn1ModuleTag) -> Self {
+        Self { tags, ..self }
     }
 
     pub fn imports(self, imports: HashMap<String, Asn1ModuleName>) -> Self {
         Self { imports, ..self }
     }
 
+    pub(crate) fn import_locations(self, import_locations: HashMap<String, LineColumn>) -> Self {
+        Self {
+            import_locations,
+            ..self
+        }
+    }
+
+    pub(crate) fn exports(self, exports: Asn1ModuleExports) -> Self {
+        Self { exports, ..self }
+    }
+
     pub(crate) fn definitions(self, definitions: HashMap<String, Asn1Definition>) -> Self {
         Self {
             definitions,
@@ -117,6 +154,16 @@ impl Asn1Module {
         self.name.name.clone()
     }
 
+    #[inline(always)]
+    pub(crate) fn get_module_oid(&self) -> Option<&ObjectIdentifier> {
+        self.name.oid.as_ref()
+    }
+
+    #[inline(always)]
+    pub(crate) fn get_module_tags(&self) -> Asn1ModuleTag {
+        self.tags
+    }
+
     // FIXME: Add filtering criteria
     #[inline(always)]
     pub(crate) fn get_definitions(&self) -> &HashMap<String, Asn1Definition> {
@@ -132,4 +179,24 @@ impl Asn1Module {
     pub(crate) fn get_imported_defs(&self) -> Iter<'_, String, Asn1ModuleName> {
         self.imports.iter()
     }
+
+    #[inline(always)]
+    pub(crate) fn get_imported_defs_mut(&mut self) -> IterMut<'_, String, Asn1ModuleName> {
+        self.imports.iter_mut()
+    }
+
+    #[inline(always)]
+    pub(crate) fn get_import_location(&self, import: &str) -> Option<LineColumn> {
+        self.import_locations.get(import).copied()
+    }
+
+    #[inline(always)]
+    pub(crate) fn get_exports(&self) -> &Asn1ModuleExports {
+        &self.exports
+    }
+
+    // Whether the symbol is defined in (or imported into) the module.
+    pub(crate) fn defines_symbol(&self, symbol: &str) -> bool {
+        self.definitions.contains_key(symbol) || self.imports.contains_key(symbol)
+    }
 }
@@ -40,8 +40,28 @@ impl ObjectIdentifier {
     pub fn len(self) -> usize {
         self.components.len()
     }
+
+    /// The numbers of the components of the Object Identifier.
+    pub fn numbers(&self) -> Vec<u32> {
+        self.components.iter().map(|c| c.number).collect()
+    }
+}
+
+// Two Object Identifiers are the same if the numbers of their components are the same, the names
+// of the components do not matter. (eg. `{ iso org(3) }` and `{ 1 3 }` are the same.)
+impl PartialEq for ObjectIdentifier {
+    fn eq(&self, other: &Self) -> bool {
+        self.components.len() == other.components.len()
+            && self
+                .components
+                .iter()
+                .zip(other.components.iter())
+                .all(|(a, b)| a.number == b.number)
+    }
 }
 
+impl Eq for ObjectIdentifier {}
+
 impl std::fmt::Display for ObjectIdentifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if let Some((first, rest)) = self.components.split_first() {
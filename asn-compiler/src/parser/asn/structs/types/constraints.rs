@@ -1,29 +1,63 @@
 //! Related to handling of ASN.1 Constraints
 
-use crate::parser::asn::structs::oid::ObjectIdentifier;
+use crate::parser::asn::structs::{oid::ObjectIdentifier, values::Asn1Value};
 
 use super::Asn1Type;
 
 #[derive(Debug, Clone)]
 pub(crate) enum SubtypeElements {
     SingleValue {
-        value: String,
+        value: Asn1Value,
     },
     ConstrainedSubtype(Asn1Type),
+    // The `lower` is `None` for `MIN` and the `upper` is `None` for `MAX`.
     ValueRange {
-        lower: String,
+        lower: Option<Asn1Value>,
         lower_inclusive: bool,
-        upper: String,
+        upper: Option<Asn1Value>,
         upper_inclusive: bool,
     },
     SizeConstraint(ElementSet),
     PermittedAlphabet(ElementSet),
+    InnerType(InnerTypeConstraint),
+}
+
+// An Inner Type Constraint. `WITH COMPONENT` constrains every element of a `SEQUENCE OF` or a
+// `SET OF` and `WITH COMPONENTS` constrains the components of a `SEQUENCE`, a `SET` or a `CHOICE`.
+#[derive(Debug, Clone)]
+pub(crate) enum InnerTypeConstraint {
+    SingleComponent(ElementSet),
+    MultipleComponents {
+        partial: bool, // Partial Specification (starts with `...`)
+        components: Vec<NamedConstraint>,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct NamedConstraint {
+    pub(crate) id: String,
+    pub(crate) value: Option<ElementSet>,
+    pub(crate) presence: Option<ComponentPresence>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ComponentPresence {
+    Present,
+    Absent,
+    Optional,
 }
 
 #[derive(Debug, Clone)]
 pub(crate) enum Elements {
     Subtype(SubtypeElements),
     Set(ElementSet),
+    // `Elements EXCEPT Elements`
+    Except {
+        elements: Box<Elements>,
+        exclusions: Box<Elements>,
+    },
+    // `ALL EXCEPT Elements`, which is always the whole of the root or the additional elements.
+    AllExcept(Box<Elements>),
 }
 #[derive(Debug, Clone)]
 pub(crate) struct IntersectionSet {
@@ -61,7 +95,7 @@ pub(crate) enum Asn1Constraint {
     Subtype(ElementSet),
     Table(TableConstraint),
     Contents {
-        _containing: String,                   // Reference to referenced type
-        _encoded_by: Option<ObjectIdentifier>, // Encoding Object Identifier (Right now always None)
+        containing: Option<String>,           // Reference to the contained type
+        encoded_by: Option<ObjectIdentifier>, // Object Identifier of the Encoding Rules
     },
 }
@@ -1,5 +1,7 @@
 //! Structures Representing Constructed Types
 
+use crate::parser::asn::structs::values::Asn1Value;
+
 use super::constraints::Asn1Constraint;
 use super::Asn1Type;
 
@@ -19,12 +21,18 @@ impl Component {
 pub(crate) struct SeqComponent {
     pub(crate) component: Component,
     pub(crate) optional: bool,
-    pub(crate) default: Option<String>, // FIXME: Should be Asn1Value later on
+    pub(crate) default: Option<Asn1Value>,
 }
 
 impl SeqComponent {
+    // The references in the `DEFAULT` value are included, so that the values are resolved before
+    // the `SEQUENCE` using them.
     pub(crate) fn dependent_references(&self) -> Vec<String> {
-        self.component.dependent_references()
+        let mut references = self.component.dependent_references();
+        if let Some(ref default) = self.default {
+            references.extend(default.dependent_references());
+        }
+        references
     }
 }
 
@@ -32,6 +40,8 @@ impl SeqComponent {
 pub(crate) struct SeqAdditionGroup {
     pub(crate) _version: Option<String>,
     pub(crate) components: Vec<SeqComponent>,
+    // A lone extension addition is also represented as a group with a single component.
+    pub(crate) is_group: bool,
 }
 
 impl SeqAdditionGroup {
@@ -107,6 +117,10 @@ pub(crate) struct Asn1TypeSequenceOf {
 
 impl Asn1TypeSequenceOf {
     pub(crate) fn dependent_references(&self) -> Vec<String> {
-        self.ty.dependent_references()
+        let mut references = self.ty.dependent_references();
+        if let Some(ref size) = self.size {
+            references.extend(size.dependent_references());
+        }
+        references
     }
 }
@@ -2,6 +2,8 @@
 
 use std::collections::HashMap;
 
+use crate::parser::asn::structs::values::Asn1Value;
+
 use super::Asn1Type;
 
 #[derive(Debug, Clone)]
@@ -18,7 +20,7 @@ pub(crate) enum ObjectClassFieldSpec {
 
         field_type: Asn1Type,
         unique: bool,
-        default: Option<String>,
+        default: Option<Asn1Value>,
         optional: bool,
         with_syntax: Option<String>,
         _resolved: bool,
@@ -104,7 +106,7 @@ pub(crate) enum Asn1ObjectFieldSpec {
     },
     FixedTypeValue {
         typeref: Asn1Type,
-        value: Option<String>,
+        value: Option<Asn1Value>,
     },
 }
 
@@ -1,5 +1,7 @@
 //! Structures related to ASN.1 Type
 
+use crate::tokenizer::{types::TokenType, Token};
+
 pub(crate) mod base;
 use base::{Asn1TypeBitString, Asn1TypeEnumerated, Asn1TypeInteger};
 
@@ -19,19 +21,22 @@ pub(crate) enum Asn1BuiltinType {
     BitString(Asn1TypeBitString),
     Boolean,
     Null,
+    Real,
     OctetString,
     ObjectIdentifier,
     RelativeOid,
 
     // Consumes a lot of String Types.
     CharacterString { str_type: String },
+
+    // `UTCTime`, `GeneralizedTime` and the Time Types of X.680 like `DATE`.
+    Time { time_type: String },
 }
 
 #[derive(Debug, Clone)]
 pub(crate) struct Asn1Type {
     pub(crate) kind: Asn1TypeKind,
     pub(crate) constraints: Option<Vec<Asn1Constraint>>,
-    #[allow(dead_code)]
     pub(crate) tag: Option<Asn1Tag>,
 }
 
@@ -73,6 +78,8 @@ impl Default for Asn1TypeKind {
 pub(crate) enum ActualParam {
     Set(String),
     Single(String),
+    // A Value Set given inline, eg. `{ 1 | 2 | 5..9 }`. The tokens are those inside the braces.
+    ValueSet(Vec<Token>),
 }
 
 impl ActualParam {
@@ -80,12 +87,40 @@ impl ActualParam {
         match self {
             Self::Set(ref s) => vec![s.clone()],
             Self::Single(ref s) => vec![s.clone()],
+            Self::ValueSet(ref tokens) => tokens
+                .iter()
+                .filter(|t| t.is_identifier())
+                .map(|t| t.text.clone())
+                .collect(),
         }
     }
 
-    pub(crate) fn param_string(&self) -> String {
+    // The tokens replacing the `dummy` reference in the definition of the parameterized type. An
+    // inline Value Set replaces a reference in a constraint, so it becomes a nested element set
+    // eg. `INTEGER (Dummy)` becomes `INTEGER (( 1 | 2 ))`.
+    pub(crate) fn param_tokens(&self, dummy: &Token) -> Vec<Token> {
+        let replaced = |r#type: TokenType, text: &str| {
+            let mut token = dummy.clone();
+            token.r#type = r#type;
+            token.text = text.to_string();
+            token
+        };
         match self {
-            Self::Set(ref r) | Self::Single(ref r) => r.clone(),
+            // A number (eg. the `1` of `Container {1}`) replaces the dummy reference as a number.
+            Self::Set(ref r) | Self::Single(ref r) => {
+                let r#type = if r.parse::<i128>().is_ok() {
+                    TokenType::NumberInt
+                } else {
+                    dummy.r#type.clone()
+                };
+                vec![replaced(r#type, r)]
+            }
+            Self::ValueSet(ref tokens) => {
+                let mut replacement = vec![replaced(TokenType::RoundBegin, "(")];
+                replacement.extend(tokens.iter().cloned());
+                replacement.push(replaced(TokenType::RoundEnd, ")"));
+                replacement
+            }
         }
     }
 }
@@ -125,7 +160,7 @@ pub(crate) enum Asn1ConstructedType {
     Choice(Asn1TypeChoice),
     Sequence(Asn1TypeSequence),
     SequenceOf(Asn1TypeSequenceOf),
-    Set,
+    Set(Asn1TypeSequence),
     SetOf,
 }
 
@@ -133,20 +168,20 @@ impl Asn1ConstructedType {
     pub(crate) fn dependent_references(&self) -> Vec<String> {
         match self {
             Self::Choice(ref c) => c.dependent_references(),
-            Self::Sequence(ref s) => s.dependent_references(),
+            Self::Sequence(ref s) | Self::Set(ref s) => s.dependent_references(),
             Self::SequenceOf(ref so) => so.dependent_references(),
             _ => vec![],
         }
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum Asn1TagMode {
     Explicit,
     Implicit,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub(crate) enum Asn1TagClass {
     Universal,
     Application,
@@ -154,7 +189,6 @@ pub(crate) enum Asn1TagClass {
     Private,
 }
 
-#[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub(crate) struct Asn1Tag {
     pub(crate) class: Asn1TagClass,
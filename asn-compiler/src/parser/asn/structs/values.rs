@@ -0,0 +1,167 @@
+//! Structures representing ASN.1 Values
+
+use crate::parser::asn::oid::well_known_oid_number;
+
+/// A Value in the ASN.1 Value Notation
+///
+/// The value is parsed without knowing it's type, so the same notation may mean different values
+/// depending upon the governing type. For example, `{ a 1, b 2 }` may be a `SEQUENCE` value or an
+/// `OBJECT IDENTIFIER` value and an identifier may be a reference to a value, an `ENUMERATED`
+/// value or a named number. The value is interpreted when it is 'resolved' against it's type.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Asn1Value {
+    Boolean(bool),
+    Null,
+    Integer(i128),
+    // A `REAL` value in the decimal notation (eg. `-1.25`) or one of the special values.
+    Real(f64),
+    // The bits of a `'0101'B` value.
+    BitString(String),
+    // The hex digits of a `'5A'H` value.
+    HexString(String),
+    // A character string value, without the quotes (and `""` replaced by `"`).
+    CharString(String),
+    // A reference to a value, or an identifier, whose meaning depends upon the type.
+    Reference(String),
+    // A `CHOICE` value like `alt : 5`.
+    Choice {
+        identifier: String,
+        value: Box<Asn1Value>,
+    },
+    // The values in the `{ ... }` notation like `{ a 1, b TRUE }` or `{ 1, 2 }`. It is used for
+    // the values of `SEQUENCE`, `SET`, `SEQUENCE OF` and `SET OF`, the named bits of a
+    // `BIT STRING` and the `{ mantissa, base, exponent }` values of a `REAL`.
+    List(Vec<NamedValue>),
+    // An `OBJECT IDENTIFIER` value like `{ iso member-body(2) 1 }`, that cannot be a `List`.
+    ObjectIdentifier(Vec<ObjIdComponent>),
+}
+
+/// An element of a value in the `{ ... }` notation, with the identifier if present.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct NamedValue {
+    pub(crate) identifier: Option<String>,
+    pub(crate) value: Asn1Value,
+}
+
+/// A Component of an `OBJECT IDENTIFIER` value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ObjIdComponent {
+    // A well known name (like `iso`) or a reference to a value.
+    Name(String),
+    Number(u32),
+    NameAndNumber(String, u32),
+}
+
+impl Asn1Value {
+    // The references to the values within this value. The identifiers that are not known to be
+    // references (eg. the `ENUMERATED` values) are also included. So is the identifier of a
+    // `List` that may be an `OBJECT IDENTIFIER` value, like the `id-base` of `{ id-base 5 }`.
+    pub(crate) fn dependent_references(&self) -> Vec<String> {
+        match self {
+            Self::Reference(ref r) => vec![r.clone()],
+            Self::Choice { ref value, .. } => value.dependent_references(),
+            Self::List(ref elements) => {
+                let mut references = elements
+                    .iter()
+                    .flat_map(|e| e.value.dependent_references())
+                    .collect::<Vec<String>>();
+                if let Some(components) = self.object_identifier_components() {
+                    for name in Self::object_identifier_references(&components) {
+                        if !references.contains(&name) {
+                            references.push(name);
+                        }
+                    }
+                }
+                references
+            }
+            Self::ObjectIdentifier(ref components) => {
+                Self::object_identifier_references(components)
+            }
+            _ => vec![],
+        }
+    }
+
+    fn object_identifier_references(components: &[ObjIdComponent]) -> Vec<String> {
+        components
+            .iter()
+            .filter_map(|c| match c {
+                ObjIdComponent::Name(ref name) if well_known_oid_number(name).is_none() => {
+                    Some(name.clone())
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    // The components, if this value can be an `OBJECT IDENTIFIER` value. A value like `{ a 1 }`
+    // or `{ 1 }` is parsed as a `List`, since it's not known (while parsing) which one it is.
+    pub(crate) fn object_identifier_components(&self) -> Option<Vec<ObjIdComponent>> {
+        match self {
+            Self::ObjectIdentifier(ref components) => Some(components.clone()),
+            Self::List(ref elements) if elements.len() == 1 => {
+                let mut components = vec![];
+                if let Some(ref identifier) = elements[0].identifier {
+                    components.push(ObjIdComponent::Name(identifier.clone()));
+                }
+                components.push(match elements[0].value {
+                    Self::Integer(n) if (0..=u32::MAX as i128).contains(&n) => {
+                        ObjIdComponent::Number(n as u32)
+                    }
+                    Self::Reference(ref r) => ObjIdComponent::Name(r.clone()),
+                    _ => return None,
+                });
+                Some(components)
+            }
+            _ => None,
+        }
+    }
+}
+
+// The value is displayed in the ASN.1 Value Notation.
+impl std::fmt::Display for Asn1Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Boolean(true) => write!(f, "TRUE"),
+            Self::Boolean(false) => write!(f, "FALSE"),
+            Self::Null => write!(f, "NULL"),
+            Self::Integer(i) => write!(f, "{}", i),
+            Self::Real(r) if r.is_nan() => write!(f, "NOT-A-NUMBER"),
+            Self::Real(r) if *r == f64::INFINITY => write!(f, "PLUS-INFINITY"),
+            Self::Real(r) if *r == f64::NEG_INFINITY => write!(f, "MINUS-INFINITY"),
+            Self::Real(r) => write!(f, "{:?}", r),
+            Self::BitString(ref b) => write!(f, "'{}'B", b),
+            Self::HexString(ref h) => write!(f, "'{}'H", h),
+            Self::CharString(ref c) => write!(f, "\"{}\"", c.replace('"', "\"\"")),
+            Self::Reference(ref r) => write!(f, "{}", r),
+            Self::Choice {
+                ref identifier,
+                ref value,
+            } => write!(f, "{} : {}", identifier, value),
+            Self::List(ref elements) => {
+                let elements = elements
+                    .iter()
+                    .map(|e| match e.identifier {
+                        Some(ref id) => format!("{} {}", id, e.value),
+                        None => e.value.to_string(),
+                    })
+                    .collect::<Vec<String>>();
+                if elements.is_empty() {
+                    write!(f, "{{ }}")
+                } else {
+                    write!(f, "{{ {} }}", elements.join(", "))
+                }
+            }
+            Self::ObjectIdentifier(ref components) => {
+                let components = components
+                    .iter()
+                    .map(|c| match c {
+                        ObjIdComponent::Name(ref name) => name.clone(),
+                        ObjIdComponent::Number(n) => n.to_string(),
+                        ObjIdComponent::NameAndNumber(ref name, n) => format!("{}({})", name, n),
+                    })
+                    .collect::<Vec<String>>();
+                write!(f, "{{ {} }}", components.join(" "))
+            }
+        }
+    }
+}
@@ -4,7 +4,7 @@ use crate::error::Error;
 use crate::tokenizer::Token;
 
 use crate::parser::{
-    asn::values::parse_value,
+    asn::{oid::parse_object_identifier, values::parse_value},
     utils::{
         expect_keyword, expect_one_of_keywords, expect_one_of_tokens, expect_token, expect_tokens,
     },
@@ -113,6 +113,48 @@ fn parse_element_set(tokens: &[Token]) -> Result<(ElementSet, usize), Error> {
         consumed += 1;
     }
 
+    let (element_set, element_set_consumed) = parse_element_set_specs(&tokens[consumed..])?;
+    consumed += element_set_consumed;
+
+    if round_begin {
+        if !expect_token(&tokens[consumed..], Token::is_round_end)? {
+            return Err(unexpected_token!("')'", tokens[consumed]));
+        }
+        consumed += 1;
+    } else {
+        // For #47
+        if !expect_keyword(&tokens[consumed..], "OF")? {
+            return Err(unexpected_token!("'OF'", tokens[consumed]));
+        }
+    }
+
+    Ok((element_set, consumed))
+}
+
+// Parse a Value Set, which is an element set in curly braces. eg. `{ 1 | 2 | 5..9, ... }`.
+pub(crate) fn parse_value_set(tokens: &[Token]) -> Result<(ElementSet, usize), Error> {
+    let mut consumed = 0;
+
+    if !expect_token(&tokens[consumed..], Token::is_curly_begin)? {
+        return Err(unexpected_token!("'{'", tokens[consumed]));
+    }
+    consumed += 1;
+
+    let (element_set, element_set_consumed) = parse_element_set_specs(&tokens[consumed..])?;
+    consumed += element_set_consumed;
+
+    if !expect_token(&tokens[consumed..], Token::is_curly_end)? {
+        return Err(unexpected_token!("'}'", tokens[consumed]));
+    }
+    consumed += 1;
+
+    Ok((element_set, consumed))
+}
+
+// Parse the root elements and the optional extension marker followed by the additional elements.
+fn parse_element_set_specs(tokens: &[Token]) -> Result<(ElementSet, usize), Error> {
+    let mut consumed = 0;
+
     let (root_elements, root_consumed) = parse_union_set(&tokens[consumed..])?;
     consumed += root_consumed;
 
@@ -141,18 +183,6 @@ fn parse_element_set(tokens: &[Token]) -> Result<(ElementSet, usize), Error> {
         }
     }
 
-    if round_begin {
-        if !expect_token(&tokens[consumed..], Token::is_round_end)? {
-            return Err(unexpected_token!("')'", tokens[consumed]));
-        }
-        consumed += 1;
-    } else {
-        // For #47
-        if !expect_keyword(&tokens[consumed..], "OF")? {
-            return Err(unexpected_token!("'OF'", tokens[consumed]));
-        }
-    }
-
     Ok((
         ElementSet {
             root_elements,
@@ -165,6 +195,23 @@ fn parse_element_set(tokens: &[Token]) -> Result<(ElementSet, usize), Error> {
 fn parse_union_set(tokens: &[Token]) -> Result<(UnionSet, usize), Error> {
     let mut consumed = 0;
 
+    // `ALL EXCEPT Elements`
+    if expect_keyword(&tokens[consumed..], "ALL")? {
+        consumed += 1;
+        if !expect_keyword(&tokens[consumed..], "EXCEPT")? {
+            return Err(unexpected_token!("'EXCEPT'", tokens[consumed]));
+        }
+        consumed += 1;
+
+        let (exclusions, exclusions_consumed) = parse_intersection_set(&tokens[consumed..])?;
+        consumed += exclusions_consumed;
+
+        let elements = vec![IntersectionSet {
+            elements: vec![Elements::AllExcept(Box::new(exclusions))],
+        }];
+        return Ok((UnionSet { elements }, consumed));
+    }
+
     let mut elements = vec![];
     // UnionSet Loop
     // TODO: May be error when stuck in loop?
@@ -175,8 +222,19 @@ fn parse_union_set(tokens: &[Token]) -> Result<(UnionSet, usize), Error> {
         loop {
             match parse_intersection_set(&tokens[consumed..]) {
                 Ok(result) => {
-                    iset_elements.push(result.0);
                     consumed += result.1;
+                    if expect_keyword(&tokens[consumed..], "EXCEPT")? {
+                        consumed += 1;
+                        let (exclusions, exclusions_consumed) =
+                            parse_intersection_set(&tokens[consumed..])?;
+                        consumed += exclusions_consumed;
+                        iset_elements.push(Elements::Except {
+                            elements: Box::new(result.0),
+                            exclusions: Box::new(exclusions),
+                        });
+                    } else {
+                        iset_elements.push(result.0);
+                    }
                 }
                 Err(_) => {
                     if expecting_iset {
@@ -215,6 +273,17 @@ fn parse_union_set(tokens: &[Token]) -> Result<(UnionSet, usize), Error> {
 fn parse_intersection_set(tokens: &[Token]) -> Result<(Elements, usize), Error> {
     let mut consumed = 0;
 
+    // Inner Type Constraints
+    if expect_keyword(&tokens[consumed..], "WITH")? {
+        let (inner_type, inner_type_consumed) = parse_inner_type_constraint(&tokens[consumed..])?;
+        consumed += inner_type_consumed;
+
+        return Ok((
+            Elements::Subtype(SubtypeElements::InnerType(inner_type)),
+            consumed,
+        ));
+    }
+
     // First try to Parse a Size
     if expect_one_of_keywords(&tokens[consumed..], &["SIZE", "FROM"])? {
         // If we come inside, following is guaranteed. to succeed.
@@ -275,6 +344,119 @@ fn parse_intersection_set(tokens: &[Token]) -> Result<(Elements, usize), Error>
     Err(parse_error!("parse_intersection_set: Not Implmented"))
 }
 
+// Parses an Inner Type Constraint (X.680 51.8) `WITH COMPONENT (Constraint)` or
+// `WITH COMPONENTS { [..., ] id [(Constraint)] [PRESENT | ABSENT | OPTIONAL], ... }`.
+fn parse_inner_type_constraint(tokens: &[Token]) -> Result<(InnerTypeConstraint, usize), Error> {
+    let mut consumed = 0;
+
+    if !expect_keyword(&tokens[consumed..], "WITH")? {
+        return Err(unexpected_token!("'WITH'", tokens[consumed]));
+    }
+    consumed += 1;
+
+    if expect_keyword(&tokens[consumed..], "COMPONENT")? {
+        consumed += 1;
+        if !expect_token(&tokens[consumed..], Token::is_round_begin)? {
+            return Err(unexpected_token!("'('", tokens[consumed]));
+        }
+        let (element_set, element_set_consumed) = parse_element_set(&tokens[consumed..])?;
+        consumed += element_set_consumed;
+
+        return Ok((InnerTypeConstraint::SingleComponent(element_set), consumed));
+    }
+
+    if !expect_keyword(&tokens[consumed..], "COMPONENTS")? {
+        return Err(unexpected_token!(
+            "'COMPONENT' or 'COMPONENTS'",
+            tokens[consumed]
+        ));
+    }
+    consumed += 1;
+
+    if !expect_token(&tokens[consumed..], Token::is_curly_begin)? {
+        return Err(unexpected_token!("'{'", tokens[consumed]));
+    }
+    consumed += 1;
+
+    let partial = if expect_token(&tokens[consumed..], Token::is_extension)? {
+        consumed += 1;
+        if !expect_token(&tokens[consumed..], Token::is_comma)? {
+            return Err(unexpected_token!("','", tokens[consumed]));
+        }
+        consumed += 1;
+        true
+    } else {
+        false
+    };
+
+    let mut components = vec![];
+    loop {
+        let (component, component_consumed) = parse_named_constraint(&tokens[consumed..])?;
+        components.push(component);
+        consumed += component_consumed;
+
+        if expect_token(&tokens[consumed..], Token::is_comma)? {
+            consumed += 1;
+        } else {
+            break;
+        }
+    }
+
+    if !expect_token(&tokens[consumed..], Token::is_curly_end)? {
+        return Err(unexpected_token!("'}'", tokens[consumed]));
+    }
+    consumed += 1;
+
+    Ok((
+        InnerTypeConstraint::MultipleComponents {
+            partial,
+            components,
+        },
+        consumed,
+    ))
+}
+
+// Parses a component of `WITH COMPONENTS` ie. `id [(Constraint)] [PRESENT | ABSENT | OPTIONAL]`.
+fn parse_named_constraint(tokens: &[Token]) -> Result<(NamedConstraint, usize), Error> {
+    let mut consumed = 0;
+
+    if !expect_token(&tokens[consumed..], Token::is_value_reference)? {
+        return Err(unexpected_token!("'IDENTIFIER'", tokens[consumed]));
+    }
+    let id = tokens[consumed].text.clone();
+    consumed += 1;
+
+    let value = if expect_token(&tokens[consumed..], Token::is_round_begin)? {
+        let (element_set, element_set_consumed) = parse_element_set(&tokens[consumed..])?;
+        consumed += element_set_consumed;
+        Some(element_set)
+    } else {
+        None
+    };
+
+    let presence =
+        if expect_one_of_keywords(&tokens[consumed..], &["PRESENT", "ABSENT", "OPTIONAL"])? {
+            let presence = match tokens[consumed].text.as_str() {
+                "PRESENT" => ComponentPresence::Present,
+                "ABSENT" => ComponentPresence::Absent,
+                _ => ComponentPresence::Optional,
+            };
+            consumed += 1;
+            Some(presence)
+        } else {
+            None
+        };
+
+    Ok((
+        NamedConstraint {
+            id,
+            value,
+            presence,
+        },
+        consumed,
+    ))
+}
+
 // Parses a Range Value, supports all possible formats.
 //
 // If parsing fails (tokens of not adequate length or tokens don't match) returns an Error. The
@@ -283,22 +465,13 @@ fn parse_intersection_set(tokens: &[Token]) -> Result<(Elements, usize), Error>
 fn parse_range_elements(tokens: &[Token]) -> Result<(SubtypeElements, usize), Error> {
     let mut consumed = 0;
 
-    fn is_min_max_keyword(token: &Token) -> bool {
-        ["MIN", "MAX"]
-            .iter()
-            .any(|k| Token::is_given_keyword(token, k))
-    }
-
     let (lower, lower_consumed) = match parse_value(&tokens[consumed..]) {
-        Ok(result) => (result.0, result.1),
+        Ok(result) => (Some(result.0), result.1),
         Err(_) => {
-            if expect_token(&tokens[consumed..], is_min_max_keyword)? {
-                (tokens[consumed].text.clone(), 1)
+            if expect_keyword(&tokens[consumed..], "MIN")? {
+                (None, 1)
             } else {
-                return Err(unexpected_token!(
-                    "'MIN', 'MAX' or 'Value'",
-                    tokens[consumed]
-                ));
+                return Err(unexpected_token!("'MIN' or 'Value'", tokens[consumed]));
             }
         }
     };
@@ -327,15 +500,12 @@ fn parse_range_elements(tokens: &[Token]) -> Result<(SubtypeElements, usize), Er
     };
 
     let (upper, upper_consumed) = match parse_value(&tokens[consumed..]) {
-        Ok(result) => (result.0, result.1),
+        Ok(result) => (Some(result.0), result.1),
         Err(_) => {
-            if expect_token(&tokens[consumed..], is_min_max_keyword)? {
-                (tokens[consumed].text.clone(), 1)
+            if expect_keyword(&tokens[consumed..], "MAX")? {
+                (None, 1)
             } else {
-                return Err(unexpected_token!(
-                    "'MIN', 'MAX' or 'Value'",
-                    tokens[consumed]
-                ));
+                return Err(unexpected_token!("'MAX' or 'Value'", tokens[consumed]));
             }
         }
     };
@@ -352,6 +522,9 @@ fn parse_range_elements(tokens: &[Token]) -> Result<(SubtypeElements, usize), Er
     ))
 }
 
+// Parses a Contents Constraint (X.682) of one of the following forms
+// `(CONTAINING Type)`, `(ENCODED BY Value)` or `(CONTAINING Type ENCODED BY Value)`. Only a
+// reference to a type and an Object Identifier value of the encoding rules are supported.
 fn parse_contents_constraint(tokens: &[Token]) -> Result<(Asn1Constraint, usize), Error> {
     let mut consumed = 0;
 
@@ -360,28 +533,47 @@ fn parse_contents_constraint(tokens: &[Token]) -> Result<(Asn1Constraint, usize)
     }
     consumed += 1;
 
-    if !expect_keyword(&tokens[consumed..], "CONTAINING")? {
-        return Err(unexpected_token!("'CONTAINING'", tokens[consumed]));
-    }
-    consumed += 1;
+    let containing = if expect_keyword(&tokens[consumed..], "CONTAINING")? {
+        consumed += 1;
+        if expect_token(&tokens[consumed..], Token::is_type_reference)? {
+            consumed += 1;
+            Some(tokens[consumed - 1].text.clone())
+        } else {
+            return Err(unexpected_token!("'TYPE Reference'", tokens[consumed]));
+        }
+    } else {
+        None
+    };
 
-    let _containing = if expect_token(&tokens[consumed..], Token::is_type_reference)? {
-        tokens[consumed].text.clone()
+    let encoded_by = if expect_keyword(&tokens[consumed..], "ENCODED")? {
+        consumed += 1;
+        if !expect_keyword(&tokens[consumed..], "BY")? {
+            return Err(unexpected_token!("'BY'", tokens[consumed]));
+        }
+        consumed += 1;
+        let (oid, oid_consumed) = parse_object_identifier(&tokens[consumed..])?;
+        consumed += oid_consumed;
+        Some(oid)
     } else {
-        return Err(unexpected_token!("'TYPE Reference'", tokens[consumed]));
+        None
     };
-    consumed += 1;
+
+    if containing.is_none() && encoded_by.is_none() {
+        return Err(unexpected_token!(
+            "'CONTAINING' or 'ENCODED BY'",
+            tokens[consumed]
+        ));
+    }
 
     if !expect_token(&tokens[consumed..], Token::is_round_end)? {
         return Err(unexpected_token!("')'", tokens[consumed]));
     }
     consumed += 1;
 
-    let _encoded_by = None;
     Ok((
         Asn1Constraint::Contents {
-            _containing,
-            _encoded_by,
+            containing,
+            encoded_by,
         },
         consumed,
     ))
@@ -440,6 +632,41 @@ mod tests {
                 additional_elements_present: true,
                 additional_elements_count: 1,
             },
+            ParseConstraintTestCase {
+                input: "(1..10 EXCEPT 5)",
+                success: true,
+                root_elements_count: 1,
+                additional_elements_present: false,
+                additional_elements_count: 0,
+            },
+            ParseConstraintTestCase {
+                input: "(1..10 ^ 5..MAX EXCEPT (7 | 8) | 30)",
+                success: true,
+                root_elements_count: 2,
+                additional_elements_present: false,
+                additional_elements_count: 0,
+            },
+            ParseConstraintTestCase {
+                input: "((1..10, ...) | 20, ..., ALL EXCEPT 5)",
+                success: true,
+                root_elements_count: 2,
+                additional_elements_present: true,
+                additional_elements_count: 1,
+            },
+            ParseConstraintTestCase {
+                input: "(ALL EXCEPT MIN..0)",
+                success: true,
+                root_elements_count: 1,
+                additional_elements_present: false,
+                additional_elements_count: 0,
+            },
+            ParseConstraintTestCase {
+                input: "(ALL 5)",
+                success: false,
+                root_elements_count: 0,
+                additional_elements_present: false,
+                additional_elements_count: 0,
+            },
             // FIXME: Add more test cases for subtype constraints
         ];
         for tc in test_cases {
@@ -501,4 +728,117 @@ mod tests {
         // FIXME: Add test cases
         assert!(true);
     }
+
+    #[test]
+    fn parse_contents_constraint_testcases() {
+        let test_cases = vec![
+            ("(CONTAINING Inner)", true, Some("Inner"), None),
+            (
+                "(ENCODED BY { joint-iso-itu-t asn1(1) packed-encoding(3) basic(0) aligned(0) })",
+                true,
+                None,
+                Some(vec![2, 1, 3, 0, 0]),
+            ),
+            (
+                "(CONTAINING Inner ENCODED BY { 2 1 1 })",
+                true,
+                Some("Inner"),
+                Some(vec![2, 1, 1]),
+            ),
+            ("(CONTAINING)", false, None, None),
+            ("(CONTAINING Inner ENCODED BY)", false, None, None),
+        ];
+
+        for (input, success, containing_expected, encoded_by_expected) in test_cases {
+            let reader = std::io::BufReader::new(std::io::Cursor::new(input));
+            let tokens = tokenize(reader).unwrap();
+            let constraint = parse_constraint(&tokens);
+            assert_eq!(constraint.is_ok(), success, "{}", input);
+            if let Ok((constraint, consumed)) = constraint {
+                assert_eq!(consumed, tokens.len(), "{}", input);
+                if let Asn1Constraint::Contents {
+                    containing,
+                    encoded_by,
+                } = constraint
+                {
+                    assert_eq!(containing.as_deref(), containing_expected, "{}", input);
+                    assert_eq!(
+                        encoded_by.map(|oid| oid.numbers()),
+                        encoded_by_expected,
+                        "{}",
+                        input
+                    );
+                } else {
+                    panic!("Expected Contents Constraint, Found {:#?}", constraint);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn parse_inner_type_constraint_testcases() {
+        // Input, Success, Partial Specification, (Component, Has Value Constraint, Presence)
+        let test_cases = vec![
+            ("(WITH COMPONENT (1..10))", true, None, vec![]),
+            (
+                "(WITH COMPONENTS { a PRESENT, b (1..4) ABSENT, c (SIZE (2)) })",
+                true,
+                Some(false),
+                vec![
+                    ("a", false, Some(ComponentPresence::Present)),
+                    ("b", true, Some(ComponentPresence::Absent)),
+                    ("c", true, None),
+                ],
+            ),
+            (
+                "(WITH COMPONENTS { ..., a OPTIONAL })",
+                true,
+                Some(true),
+                vec![("a", false, Some(ComponentPresence::Optional))],
+            ),
+            ("(WITH COMPONENTS { ..., })", false, None, vec![]),
+            ("(WITH COMPONENT 1..10)", false, None, vec![]),
+        ];
+
+        for (input, success, partial_expected, components_expected) in test_cases {
+            let reader = std::io::BufReader::new(std::io::Cursor::new(input));
+            let tokens = tokenize(reader).unwrap();
+            let constraint = parse_constraint(&tokens);
+            assert_eq!(
+                constraint
+                    .as_ref()
+                    .map(|c| c.1 == tokens.len())
+                    .unwrap_or(false),
+                success,
+                "{}",
+                input
+            );
+            if !success {
+                continue;
+            }
+            let (constraint, _) = constraint.unwrap();
+            let inner = match constraint {
+                Asn1Constraint::Subtype(ref e) => match e.root_elements.elements[0].elements[0] {
+                    Elements::Subtype(SubtypeElements::InnerType(ref inner)) => inner.clone(),
+                    _ => panic!("Expected Inner Type Constraint, Found {:#?}", constraint),
+                },
+                _ => panic!("Expected Subtype Constraint, Found {:#?}", constraint),
+            };
+            match inner {
+                InnerTypeConstraint::SingleComponent(_) => assert!(partial_expected.is_none()),
+                InnerTypeConstraint::MultipleComponents {
+                    partial,
+                    components,
+                } => {
+                    assert_eq!(Some(partial), partial_expected, "{}", input);
+                    assert_eq!(components.len(), components_expected.len(), "{}", input);
+                    for (c, (id, value, presence)) in components.iter().zip(components_expected) {
+                        assert_eq!(c.id, id, "{}", input);
+                        assert_eq!(c.value.is_some(), value, "{}", input);
+                        assert_eq!(c.presence, presence, "{}", input);
+                    }
+                }
+            }
+        }
+    }
 }
@@ -11,9 +11,9 @@ use super::utils::parse_component;
 
 // Parse a Choice Type
 //
-// The current implementation supports a very simple choice definition, where, everything is dumped
-// into 'root' components. Additional extension components or version groups etc. are not supported
-// for now. May be supported later if needed.
+// The alternatives before the extension marker are the 'root' components, the ones after the
+// extension marker (either in the version groups or on their own) are the 'additions' in the order
+// in which they appear.
 pub(crate) fn parse_choice_type(tokens: &[Token]) -> Result<(Asn1TypeChoice, usize), Error> {
     let mut consumed = 0;
 
@@ -29,7 +29,6 @@ pub(crate) fn parse_choice_type(tokens: &[Token]) -> Result<(Asn1TypeChoice, usi
 
     let mut root_components = vec![];
     let mut additions = vec![];
-    let mut no_ver_additions_components = vec![];
     let mut extension_markers = 0;
     let mut loop_count = 1;
     loop {
@@ -40,11 +39,14 @@ pub(crate) fn parse_choice_type(tokens: &[Token]) -> Result<(Asn1TypeChoice, usi
         };
         if let Some(c) = component {
             // We have a component that needs to be added in the `additions` list and not in the
-            // `root_components`. All such components that are not part of `ChoiceAdditionGroup`
-            // (ie. one without `version`, are pulled into one `ChoiceAdditionGroup` with version
-            // as `None`. And are added separately.
+            // `root_components`. Such a component that is not part of a `ChoiceAdditionGroup` is
+            // added as a `ChoiceAdditionGroup` of it's own with version as `None`, so that the
+            // order of the alternatives (and hence their indices) in the extension is retained.
             if extension_markers > 0 {
-                no_ver_additions_components.push(c);
+                additions.push(ChoiceAdditionGroup {
+                    _version: None,
+                    components: vec![c],
+                });
             } else {
                 root_components.push(c);
             }
@@ -101,13 +103,6 @@ pub(crate) fn parse_choice_type(tokens: &[Token]) -> Result<(Asn1TypeChoice, usi
     }
 
     let additions = if extension_markers > 0 {
-        if !no_ver_additions_components.is_empty() {
-            let new_addition = ChoiceAdditionGroup {
-                _version: None,
-                components: no_ver_additions_components,
-            };
-            additions.push(new_addition);
-        }
         Some(additions)
     } else {
         None
@@ -240,6 +235,14 @@ mod tests {
                 addition_components_count: 1,
                 tokens_consumed: 19,
             },
+            ParseChoiceTestCase {
+                input: "CHOICE { a INTEGER, ..., b Enum, [[ c INTEGER, d NULL ]] }",
+                success: true,
+                components_count: 1,
+                extensions_present: true,
+                addition_components_count: 2,
+                tokens_consumed: 18,
+            },
             ParseChoiceTestCase {
                 input: "CHOICE { a INTEGER , b Enum, [[ c CHOICE { d INTEGER } ]] }",
                 success: false,
@@ -51,8 +51,18 @@ fn parse_sequence_type(tokens: &[Token]) -> Result<(Asn1TypeKind, usize), Error>
             Err(_) => (None, 0),
         };
 
-        if let Some(root_comp) = component {
-            root_components.push(root_comp);
+        // Components between the first and the second extension markers are the extension
+        // additions.
+        if let Some(comp) = component {
+            if ext_marker_found == 1 {
+                additions.push(SeqAdditionGroup {
+                    _version: None,
+                    components: vec![comp],
+                    is_group: false,
+                });
+            } else {
+                root_components.push(comp);
+            }
         }
         consumed += component_consumed;
 
@@ -105,14 +115,20 @@ fn parse_sequence_type(tokens: &[Token]) -> Result<(Asn1TypeKind, usize), Error>
     }
 
     let extensible = ext_marker_found > 0;
-    Ok((
-        Asn1TypeKind::Constructed(Asn1ConstructedType::Sequence(Asn1TypeSequence {
-            root_components,
-            additions,
-            extensible,
-        })),
-        consumed,
-    ))
+    let sequence = Asn1TypeSequence {
+        root_components,
+        additions,
+        extensible,
+    };
+
+    // A `SET` has the same components as a `SEQUENCE`, but it is kept distinct because the
+    // components of a `SET` are encoded in the canonical order of their tags.
+    let kind = if tokens[0].text == "SET" {
+        Asn1ConstructedType::Set(sequence)
+    } else {
+        Asn1ConstructedType::Sequence(sequence)
+    };
+    Ok((Asn1TypeKind::Constructed(kind), consumed))
 }
 
 fn parse_sequence_of_type(tokens: &[Token]) -> Result<(Asn1TypeKind, usize), Error> {
@@ -237,6 +253,7 @@ fn parse_seq_addition_group(tokens: &[Token]) -> Result<(SeqAdditionGroup, usize
             SeqAdditionGroup {
                 _version,
                 components,
+                is_group: true,
             },
             consumed,
         ))
@@ -293,8 +310,8 @@ mod tests {
             ParseSequenceTestCase {
                 input: " SEQUENCE { a INTEGER, b BOOLEAN OPTIONAL, ..., c CHOICE { d INTEGER, e Enum}} ",
                 success: true,
-                root_components_count: 3,
-                additional_components_count: 0,
+                root_components_count: 2,
+                additional_components_count: 1,
                 consumed_tokens: 21,
             },
             ParseSequenceTestCase {
@@ -339,6 +356,13 @@ mod tests {
                 additional_components_count: 0,
                 consumed_tokens: 0,
             },
+            ParseSequenceTestCase {
+                input: " SET { a [1] INTEGER, b [0] BOOLEAN } ",
+                success: true,
+                root_components_count: 2,
+                additional_components_count: 0,
+                consumed_tokens: 14,
+            },
             ParseSequenceTestCase {
                 input: " SEQUENCE (SIZE(1..maxnoofeNBX2TLAs)) OF TransportLayerAddress",
                 success: true,
@@ -369,7 +393,10 @@ mod tests {
 
             if tc.success {
                 let (seq, seq_consumed) = sequence.unwrap();
-                if let Asn1TypeKind::Constructed(Asn1ConstructedType::Sequence(seq)) = seq {
+                if let Asn1TypeKind::Constructed(
+                    Asn1ConstructedType::Sequence(seq) | Asn1ConstructedType::Set(seq),
+                ) = seq
+                {
                     assert_eq!(seq_consumed, tc.consumed_tokens, "{}", tc.input);
                     assert_eq!(
                         seq.root_components.len(),
@@ -12,7 +12,7 @@ use crate::parser::utils::{
 
 use super::{
     base::{parse_bitstring_type, parse_enumerated_type, parse_integer_type},
-    constraints::parse_constraints,
+    constraints::{parse_constraints, parse_value_set},
     constructed::{parse_choice_type, parse_seq_or_seq_of_type},
 };
 
@@ -103,8 +103,13 @@ pub(crate) fn parse_type(tokens: &[Token]) -> Result<(Asn1Type, usize), Error> {
             (Asn1TypeKind::Builtin(Asn1BuiltinType::Null), 1)
         }
 
-        "VisibleString" | "UTF8String" | "IA5String" | "PrintableString" | "UTCTime"
-        | "GeneralizedTime" => {
+        "REAL" => {
+            log::trace!("Parsing `REAL` type.");
+            (Asn1TypeKind::Builtin(Asn1BuiltinType::Real), 1)
+        }
+
+        "VisibleString" | "UTF8String" | "IA5String" | "PrintableString" | "NumericString"
+        | "BMPString" | "UniversalString" | "TeletexString" | "T61String" | "GeneralString" => {
             log::trace!("Parsing `String` type.");
             (
                 Asn1TypeKind::Builtin(Asn1BuiltinType::CharacterString {
@@ -114,6 +119,16 @@ pub(crate) fn parse_type(tokens: &[Token]) -> Result<(Asn1Type, usize), Error> {
             )
         }
 
+        "UTCTime" | "GeneralizedTime" | "DATE" | "TIME-OF-DAY" | "DATE-TIME" | "DURATION" => {
+            log::trace!("Parsing `Time` type.");
+            (
+                Asn1TypeKind::Builtin(Asn1BuiltinType::Time {
+                    time_type: typestr.to_string(),
+                }),
+                1,
+            )
+        }
+
         "CHOICE" => {
             log::trace!("Parsing `CHOICE` type.");
             let (choice_type, choice_type_consumed) = parse_choice_type(&tokens[consumed..])?;
@@ -234,24 +249,23 @@ fn parse_actual_params(tokens: &[Token]) -> Result<(Vec<ActualParam>, usize), Er
 
     let mut params = vec![];
     loop {
-        if expect_token(&tokens[consumed..], Token::is_curly_begin)? {
-            consumed += 1;
-            let param = if expect_one_of_tokens(
-                &tokens[consumed..],
+        if expect_tokens(
+            &tokens[consumed..],
+            &[
+                &[Token::is_curly_begin],
                 &[Token::is_numeric, Token::is_identifier],
-            )? {
-                let param = tokens[consumed].text.clone();
-                consumed += 1;
-                param
-            } else {
-                return Err(unexpected_token!("'IDENTIFIER'", tokens[consumed]));
-            };
-
-            if !expect_token(&tokens[consumed..], Token::is_curly_end)? {
-                return Err(unexpected_token!("'}'", tokens[consumed]));
-            };
-            consumed += 1;
-            params.push(ActualParam::Set(param));
+                &[Token::is_curly_end],
+            ],
+        )? {
+            params.push(ActualParam::Set(tokens[consumed + 1].text.clone()));
+            consumed += 3;
+        } else if expect_token(&tokens[consumed..], Token::is_curly_begin)? {
+            // A Value Set given inline. eg. `{ 1 | 2 | 5..9 }`
+            let (_, set_consumed) = parse_value_set(&tokens[consumed..])?;
+            params.push(ActualParam::ValueSet(
+                tokens[consumed + 1..consumed + set_consumed - 1].to_vec(),
+            ));
+            consumed += set_consumed;
         }
 
         if expect_one_of_tokens(
@@ -382,6 +396,11 @@ mod tests {
                 success: true,
                 consumed: 6,
             },
+            ParseTypeTestCase {
+                input: "Container {{ 3 | 4, ... }, {Set}}",
+                success: true,
+                consumed: 14,
+            },
             ParseTypeTestCase {
                 input: "[PRIVATE bla] INTEGER",
                 success: false,
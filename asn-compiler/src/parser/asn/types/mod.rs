@@ -6,6 +6,7 @@ mod base;
 mod constructed;
 
 mod constraints;
+pub(crate) use constraints::parse_value_set;
 
 mod int;
 pub(crate) use int::parse_type;
@@ -3,12 +3,67 @@
 use crate::error::Error;
 use crate::tokenizer::{types::TokenType, Token};
 
-use crate::parser::utils::{expect_one_of_tokens, parse_set_ish_value};
+use crate::parser::utils::{
+    expect_one_of_keywords, expect_one_of_tokens, expect_token, expect_tokens,
+};
+
+use super::structs::values::{Asn1Value, NamedValue, ObjIdComponent};
+
+// Keywords that are values by themselves.
+const VALUE_KEYWORDS: &[&str] = &[
+    "TRUE",
+    "FALSE",
+    "NULL",
+    "PLUS-INFINITY",
+    "MINUS-INFINITY",
+    "NOT-A-NUMBER",
+];
+
+// Parses a given set of 'tokens' as a value in the ASN.1 Value Notation. Since the type of the
+// value is not known, the value is interpreted when it is resolved against it's type. (See
+// `Asn1Value`.)
+pub(crate) fn parse_value(tokens: &[Token]) -> Result<(Asn1Value, usize), Error> {
+    if expect_one_of_keywords(tokens, VALUE_KEYWORDS)? {
+        let value = match tokens[0].text.as_str() {
+            "TRUE" => Asn1Value::Boolean(true),
+            "FALSE" => Asn1Value::Boolean(false),
+            "NULL" => Asn1Value::Null,
+            "PLUS-INFINITY" => Asn1Value::Real(f64::INFINITY),
+            "MINUS-INFINITY" => Asn1Value::Real(f64::NEG_INFINITY),
+            _ => Asn1Value::Real(f64::NAN),
+        };
+        return Ok((value, 1));
+    }
+
+    // A `REAL` value in decimal notation (eg. `-1.25`) is tokenized as a number, a '.' and a
+    // number.
+    if tokens.len() >= 3
+        && expect_tokens(
+            tokens,
+            &[&[Token::is_numeric], &[Token::is_dot], &[Token::is_numeric]],
+        )?
+        && !tokens[2].text.starts_with('-')
+    {
+        let value = format!("{}.{}", tokens[0].text, tokens[2].text)
+            .parse::<f64>()
+            .map_err(|_| invalid_token!(tokens[0]))?;
+        return Ok((Asn1Value::Real(value), 3));
+    }
+
+    // A `CHOICE` value (eg. `alt : 5`)
+    if tokens.len() >= 2
+        && expect_tokens(tokens, &[&[Token::is_value_reference], &[Token::is_colon]])?
+    {
+        let (value, value_consumed) = parse_value(&tokens[2..])?;
+        return Ok((
+            Asn1Value::Choice {
+                identifier: tokens[0].text.clone(),
+                value: Box::new(value),
+            },
+            value_consumed + 2,
+        ));
+    }
 
-// Parses a given set of 'tokens' as a value and returns a string corresponding to one that would
-// be generated by concatenating those tokens. Note: It should be possible to regenerate, original
-// tokens, by 'tokenize'ing the string.
-pub(crate) fn parse_value(tokens: &[Token]) -> Result<(String, usize), Error> {
     if !expect_one_of_tokens(
         tokens,
         &[
@@ -18,24 +73,259 @@ pub(crate) fn parse_value(tokens: &[Token]) -> Result<(String, usize), Error> {
             Token::is_hexstring,
             Token::is_tstring,
             Token::is_curly_begin,
-            Token::is_round_begin,
         ],
     )? {
         Err(unexpected_token!(
-            "'IDENTIFIER', 'NUMBER', 'Bit String', 'Hex String', 'String', '{', '('",
+            "'IDENTIFIER', 'NUMBER', 'Bit String', 'Hex String', 'String', '{', 'TRUE', 'FALSE', 'NULL'",
             tokens[0]
         ))
     } else {
         let token = &tokens[0];
-        match token.r#type {
-            TokenType::Identifier
-            | TokenType::NumberInt
-            | TokenType::BitString
-            | TokenType::HexString
-            | TokenType::TString => Ok((token.text.clone(), 1)),
-            _ => parse_set_ish_value(tokens),
+        let value = match token.r#type {
+            TokenType::Identifier => Asn1Value::Reference(token.text.clone()),
+            TokenType::NumberInt => Asn1Value::Integer(
+                token
+                    .text
+                    .parse::<i128>()
+                    .map_err(|_| invalid_token!(token))?,
+            ),
+            // A `"` within the string is written as `""`.
+            TokenType::TString => {
+                Asn1Value::CharString(token.text[1..token.text.len() - 1].replace("\"\"", "\""))
+            }
+            // The text of the token is the bits or the hex digits within the quotes.
+            TokenType::BitString => Asn1Value::BitString(token.text.trim_matches('\'').to_string()),
+            TokenType::HexString => Asn1Value::HexString(token.text.trim_matches('\'').to_string()),
+            _ => return parse_braced_value(tokens),
+        };
+        Ok((value, 1))
+    }
+}
+
+// Parses a value in the `{ ... }` notation. It's either a list of values (possibly with the
+// identifiers) or the components of an `OBJECT IDENTIFIER` value (eg. `{ iso member-body(2) }`).
+fn parse_braced_value(tokens: &[Token]) -> Result<(Asn1Value, usize), Error> {
+    if let Ok((elements, consumed)) = parse_value_list(tokens) {
+        return Ok((Asn1Value::List(elements), consumed));
+    }
+
+    let (components, consumed) = parse_object_identifier_value(tokens)?;
+    Ok((Asn1Value::ObjectIdentifier(components), consumed))
+}
+
+// Parses the comma separated values like `{ a 1, b TRUE }`, `{ 1, 2 }` or `{ }`.
+fn parse_value_list(tokens: &[Token]) -> Result<(Vec<NamedValue>, usize), Error> {
+    let mut consumed = 0;
+
+    if !expect_token(&tokens[consumed..], Token::is_curly_begin)? {
+        return Err(unexpected_token!("'{'", tokens[consumed]));
+    }
+    consumed += 1;
+
+    let mut elements = vec![];
+    if expect_token(&tokens[consumed..], Token::is_curly_end)? {
+        return Ok((elements, consumed + 1));
+    }
+
+    loop {
+        // An identifier followed by a value is the identifier of the element. (An identifier
+        // followed by a ':' is a `CHOICE` value.)
+        let identifier = if tokens.len() > consumed + 1
+            && tokens[consumed].is_value_reference()
+            && !(tokens[consumed + 1].is_comma()
+                || tokens[consumed + 1].is_curly_end()
+                || tokens[consumed + 1].is_colon())
+        {
+            consumed += 1;
+            Some(tokens[consumed - 1].text.clone())
+        } else {
+            None
+        };
+
+        let (value, value_consumed) = parse_value(&tokens[consumed..])?;
+        consumed += value_consumed;
+        elements.push(NamedValue { identifier, value });
+
+        if expect_token(&tokens[consumed..], Token::is_curly_end)? {
+            consumed += 1;
+            break;
         }
+        if !expect_token(&tokens[consumed..], Token::is_comma)? {
+            return Err(unexpected_token!("',' or '}'", tokens[consumed]));
+        }
+        consumed += 1;
+    }
+
+    Ok((elements, consumed))
+}
+
+// Parses the components of an `OBJECT IDENTIFIER` value of the form `{ iso member-body(2) 840 }`.
+// The name of a component may be a well known name or a reference to a value.
+fn parse_object_identifier_value(tokens: &[Token]) -> Result<(Vec<ObjIdComponent>, usize), Error> {
+    let mut consumed = 0;
+
+    if !expect_token(&tokens[consumed..], Token::is_curly_begin)? {
+        return Err(unexpected_token!("'{'", tokens[consumed]));
+    }
+    consumed += 1;
+
+    let mut components = vec![];
+    while !expect_token(&tokens[consumed..], Token::is_curly_end)? {
+        let named_number = matches!(
+            expect_tokens(
+                &tokens[consumed..],
+                &[
+                    &[Token::is_value_reference],
+                    &[Token::is_round_begin],
+                    &[Token::is_numeric],
+                    &[Token::is_round_end],
+                ],
+            ),
+            Ok(true)
+        );
+        let component = if named_number {
+            let number_token = &tokens[consumed + 2];
+            let number = number_token
+                .text
+                .parse::<u32>()
+                .map_err(|_| invalid_token!(number_token))?;
+            let name = tokens[consumed].text.clone();
+            consumed += 4;
+            ObjIdComponent::NameAndNumber(name, number)
+        } else if expect_token(&tokens[consumed..], Token::is_value_reference)? {
+            consumed += 1;
+            ObjIdComponent::Name(tokens[consumed - 1].text.clone())
+        } else if expect_token(&tokens[consumed..], Token::is_numeric)? {
+            let number_token = &tokens[consumed];
+            let number = number_token
+                .text
+                .parse::<u32>()
+                .map_err(|_| invalid_token!(number_token))?;
+            consumed += 1;
+            ObjIdComponent::Number(number)
+        } else {
+            return Err(unexpected_token!(
+                "'IDENTIFIER', 'NUMBER' or '}'",
+                tokens[consumed]
+            ));
+        };
+        components.push(component);
+    }
+    consumed += 1;
+
+    if components.is_empty() {
+        return Err(parse_error!("An OBJECT IDENTIFIER value cannot be empty!"));
     }
+
+    Ok((components, consumed))
 }
 
-// TODO: Add Test cases
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::tokenizer::tokenize_string;
+
+    fn parse_values(input: &str) -> Vec<Asn1Value> {
+        let tokens = tokenize_string(input).unwrap();
+        let mut consumed = 0;
+        let mut values = vec![];
+        while consumed < tokens.len() {
+            let (value, value_consumed) = parse_value(&tokens[consumed..]).unwrap();
+            values.push(value);
+            consumed += value_consumed;
+        }
+        values
+    }
+
+    fn named(identifier: Option<&str>, value: Asn1Value) -> NamedValue {
+        NamedValue {
+            identifier: identifier.map(|id| id.to_string()),
+            value,
+        }
+    }
+
+    #[test]
+    fn parse_value_keywords_and_real() {
+        let values = parse_values("TRUE -1.25 PLUS-INFINITY 'FF'H NULL '0101'B \"a \"\"b\"\"\"");
+        assert_eq!(
+            values,
+            vec![
+                Asn1Value::Boolean(true),
+                Asn1Value::Real(-1.25),
+                Asn1Value::Real(f64::INFINITY),
+                Asn1Value::HexString("FF".to_string()),
+                Asn1Value::Null,
+                Asn1Value::BitString("0101".to_string()),
+                Asn1Value::CharString("a \"b\"".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_value_braced_and_choice() {
+        let values = parse_values(
+            "{ } { red, \"a b\", 3 } { mantissa 5, base 10, exponent -1 } alt : { a 1, b TRUE }",
+        );
+        assert_eq!(
+            values,
+            vec![
+                Asn1Value::List(vec![]),
+                Asn1Value::List(vec![
+                    named(None, Asn1Value::Reference("red".to_string())),
+                    named(None, Asn1Value::CharString("a b".to_string())),
+                    named(None, Asn1Value::Integer(3)),
+                ]),
+                Asn1Value::List(vec![
+                    named(Some("mantissa"), Asn1Value::Integer(5)),
+                    named(Some("base"), Asn1Value::Integer(10)),
+                    named(Some("exponent"), Asn1Value::Integer(-1)),
+                ]),
+                Asn1Value::Choice {
+                    identifier: "alt".to_string(),
+                    value: Box::new(Asn1Value::List(vec![
+                        named(Some("a"), Asn1Value::Integer(1)),
+                        named(Some("b"), Asn1Value::Boolean(true)),
+                    ])),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_value_object_identifier() {
+        let values = parse_values("{ iso member-body(2) 840 } { 1 3 6 } { id-ce 5 }");
+        assert_eq!(
+            values,
+            vec![
+                Asn1Value::ObjectIdentifier(vec![
+                    ObjIdComponent::Name("iso".to_string()),
+                    ObjIdComponent::NameAndNumber("member-body".to_string(), 2),
+                    ObjIdComponent::Number(840),
+                ]),
+                Asn1Value::ObjectIdentifier(vec![
+                    ObjIdComponent::Number(1),
+                    ObjIdComponent::Number(3),
+                    ObjIdComponent::Number(6),
+                ]),
+                // Parsed as a `List`, but it may be an `OBJECT IDENTIFIER` value as well.
+                Asn1Value::List(vec![named(Some("id-ce"), Asn1Value::Integer(5))]),
+            ]
+        );
+        assert_eq!(
+            values[2].object_identifier_components(),
+            Some(vec![
+                ObjIdComponent::Name("id-ce".to_string()),
+                ObjIdComponent::Number(5),
+            ])
+        );
+    }
+
+    #[test]
+    fn parse_value_failures() {
+        for input in ["{ 1 2, 3 }", "{ a 1 b, 2 }", "{ 1, }", "( 1 )", "alt :"] {
+            let tokens = tokenize_string(input).unwrap();
+            assert!(parse_value(&tokens).is_err(), "{}", input);
+        }
+    }
+}
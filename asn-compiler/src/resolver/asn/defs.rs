@@ -4,12 +4,19 @@ use crate::error::Error;
 
 use crate::parser::asn::structs::defs::{
     Asn1AssignmentKind, Asn1Definition, Asn1ObjectAssignment, Asn1ObjectSetAssignment,
-    Asn1TypeAssignment, Asn1ValueAssignment,
+    Asn1TypeAssignment, Asn1ValueAssignment, Asn1ValueSetAssignment,
+};
+use crate::resolver::{
+    asn::structs::{
+        defs::Asn1ResolvedDefinition,
+        types::{base::ResolvedBaseType, Asn1ResolvedType},
+    },
+    Resolver,
 };
-use crate::resolver::{asn::structs::defs::Asn1ResolvedDefinition, Resolver};
 
 use super::types::ioc::{resolve_object, resolve_object_set};
-use super::types::resolve_type;
+use super::types::tags::{resolve_generated_type_tags, resolve_type_tags};
+use super::types::{constraints::governing_type, resolve_type};
 use super::values::resolve_value;
 
 // Resolve a given Parsed Definition to a Resolved Definition
@@ -22,6 +29,7 @@ pub(crate) fn resolve_definition(
     match definition.kind {
         Asn1AssignmentKind::Value(ref v) => resolve_value_definition(v, resolver),
         Asn1AssignmentKind::Type(ref t) => resolve_type_definition(t, resolver),
+        Asn1AssignmentKind::ValueSet(ref s) => resolve_value_set_definition(s, resolver),
         Asn1AssignmentKind::ObjectSet(ref objset) => {
             resolve_object_set_definition(objset, resolver)
         }
@@ -38,9 +46,51 @@ pub(crate) fn resolve_type_definition(
     resolver: &mut Resolver,
 ) -> Result<Asn1ResolvedDefinition, Error> {
     let typeref = resolve_type(&def.typeref, resolver)?;
+
+    // The tags of a type are required only by the types referring to it (eg. for ordering the
+    // components of a `SET`), so it's not an error if those cannot be determined.
+    if let Ok(tags) = resolve_type_tags(&def.typeref, resolver) {
+        // A Referenced Type is generated as an alias, which cannot be tagged. The components
+        // referring to such a type carry it's tags instead.
+        if !matches!(typeref, Asn1ResolvedType::Reference(..)) {
+            let tagging = tags.tagging_over(&resolve_generated_type_tags(&typeref, resolver));
+            if !tagging.is_empty() {
+                let key = resolver.current_module_key(&def.id);
+                resolver.type_taggings.insert(key, tagging);
+            }
+        }
+        let key = resolver.current_module_key(&def.id);
+        resolver.type_tags.insert(key, tags);
+    }
+
     Ok(Asn1ResolvedDefinition::Type(typeref))
 }
 
+// A Value Set is resolved as it's Type constrained by the element set. For an `INTEGER`, the
+// values in the set are also kept, to generate the allowed values.
+fn resolve_value_set_definition(
+    def: &Asn1ValueSetAssignment,
+    resolver: &mut Resolver,
+) -> Result<Asn1ResolvedDefinition, Error> {
+    let typedef = Asn1TypeAssignment {
+        id: def.id.clone(),
+        typeref: def.constrained_type(),
+    };
+    let resolved = resolve_type_definition(&typedef, resolver)?;
+
+    if let Asn1ResolvedDefinition::Type(ref ty) = resolved {
+        if let Asn1ResolvedType::Base(ResolvedBaseType::Integer(..)) = governing_type(ty, resolver)?
+        {
+            if let Some(values) = def.set.get_values(resolver)? {
+                let key = resolver.current_module_key(&def.id);
+                resolver.value_sets.insert(key, values);
+            }
+        }
+    }
+
+    Ok(resolved)
+}
+
 fn resolve_value_definition(
     value: &Asn1ValueAssignment,
     resolver: &mut Resolver,
@@ -2,10 +2,48 @@ use super::types::ioc::{Asn1ResolvedObject, Asn1ResolvedObjectSet};
 use super::types::Asn1ResolvedType;
 use super::values::Asn1ResolvedValue;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub(crate) enum Asn1ResolvedDefinition {
     Type(Asn1ResolvedType),
     Value(Asn1ResolvedValue),
     ObjectSet(Asn1ResolvedObjectSet),
     Object(Asn1ResolvedObject),
 }
+
+// A definition is identified by the name of the module, in which it is defined, and it's name.
+// The same name may be defined in more than one module.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct DefinitionKey {
+    pub(crate) module: String,
+    pub(crate) name: String,
+}
+
+impl DefinitionKey {
+    pub(crate) fn new(module: &str, name: &str) -> Self {
+        Self {
+            module: module.to_string(),
+            name: name.to_string(),
+        }
+    }
+
+    // The resolved definitions refer to other definitions by their names, when they are compared.
+    // A definition in one module is then the same as a copy of it in another module, which refers
+    // to the copies of the same definitions.
+    pub(crate) fn same_name(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+
+    pub(crate) fn same_names(this: &Option<Self>, other: &Option<Self>) -> bool {
+        match (this, other) {
+            (Some(this), Some(other)) => this.same_name(other),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl std::fmt::Display for DefinitionKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.module, self.name)
+    }
+}
@@ -1,17 +1,21 @@
 //! Structs for the resolved Base Types
 use std::collections::{BTreeSet, HashMap};
 
-use crate::resolver::asn::structs::types::constraints::Asn1ConstraintValueSet;
+use crate::resolver::asn::structs::types::constraints::{
+    Asn1ConstraintValueSet, Asn1ResolvedContents,
+};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub(crate) enum ResolvedBaseType {
     Integer(Asn1ResolvedInteger),
     Enum(Asn1ResolvedEnumerated),
     BitString(Asn1ResolvedBitString),
     Boolean(Asn1ResolvedBoolean),
+    Real(Asn1ResolvedReal),
     OctetString(Asn1ResolvedOctetString),
     CharacterString(Asn1ResolvedCharacterString),
     ObjectIdentifier(Asn1ResolvedObjectIdentifier),
+    Time(Asn1ResolvedTime),
     Null(Asn1ResolvedNull),
 }
 
@@ -19,7 +23,7 @@ pub(crate) enum ResolvedBaseType {
 //
 // This structure is obtained when all the 'Constraints' in a give definition are applied.
 // Information from this structure can be directly used for code generation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub(crate) struct Asn1ResolvedInteger {
     pub(crate) bits: u8,
     pub(crate) signed: bool,
@@ -38,7 +42,7 @@ impl Default for Asn1ResolvedInteger {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub(crate) struct Asn1ResolvedEnumerated {
     pub(crate) bits: u8,
     pub(crate) signed: bool,
@@ -66,36 +70,58 @@ impl Default for Asn1ResolvedEnumerated {
 }
 
 // A Resolved `BIT STRING` representation. Normally only the `SIZE` Constraint needs to be
-// resolved. If optional, `named_bits` are present, we maintain those in the map below.
-#[derive(Debug, Default, Clone)]
+// resolved. If optional, `named_bits` are present, we maintain those in the map below. The
+// `CONTAINING` Constraint, if any, is resolved as `contents`.
+#[derive(Debug, Default, Clone, PartialEq)]
 pub(crate) struct Asn1ResolvedBitString {
     pub(crate) size: Option<Asn1ConstraintValueSet>,
 
+    pub(crate) contents: Option<Asn1ResolvedContents>,
+
     // We support only up to 128 named bits, if more than that is required, change this to appropriate. value
     pub(crate) named_values: HashMap<String, u8>,
 }
 
 // Just an empty structure for Resolved `BOOLEAN` type.
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub(crate) struct Asn1ResolvedBoolean;
 
+// Just an empty structure for Resolved `REAL` type. The constraints are not PER-visible and are
+// not resolved.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(crate) struct Asn1ResolvedReal;
+
 // Just an empty structure for Resolved `NULL` type.
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub(crate) struct Asn1ResolvedNull;
 
-// A structure representing a Resolved `OCTET STRING`. `SIZE` Constraint is resolved as well. The
-// `CONTAINING` Constraint is not resolved.
-#[derive(Debug, Default, Clone)]
+// A structure representing a Resolved `OCTET STRING`. `SIZE` and `CONTAINING` Constraints are
+// resolved as well.
+#[derive(Debug, Default, Clone, PartialEq)]
 pub(crate) struct Asn1ResolvedOctetString {
     pub(crate) size: Option<Asn1ConstraintValueSet>,
+    pub(crate) contents: Option<Asn1ResolvedContents>,
 }
 
 // A structure representing a Resolved `CharacterString`. `SIZE` Constraint is resolved as well. The
-#[derive(Debug, Default, Clone)]
+// `alphabet` has the characters permitted by the `FROM` (PermittedAlphabet) constraints.
+#[derive(Debug, Default, Clone, PartialEq)]
 pub(crate) struct Asn1ResolvedCharacterString {
     pub(crate) str_type: String,
     pub(crate) size: Option<Asn1ConstraintValueSet>,
+    pub(crate) alphabet: Option<BTreeSet<char>>,
 }
 
-#[derive(Debug, Default, Clone)]
-pub(crate) struct Asn1ResolvedObjectIdentifier;
+// A structure representing a Resolved `OBJECT IDENTIFIER` or `RELATIVE-OID` (when `relative` is
+// `true`).
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(crate) struct Asn1ResolvedObjectIdentifier {
+    pub(crate) relative: bool,
+}
+
+// A structure representing a Resolved Time Type (`UTCTime`, `GeneralizedTime`, `DATE`,
+// `TIME-OF-DAY`, `DATE-TIME` or `DURATION`). The constraints are not resolved.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(crate) struct Asn1ResolvedTime {
+    pub(crate) time_type: String,
+}
@@ -1,62 +1,344 @@
 //! Structs Related to Resolved Constraints
 
-use std::ops::Range;
+use std::ops::RangeInclusive;
 
-#[derive(Debug, Clone)]
+use crate::parser::asn::structs::types::constraints::ComponentPresence;
+use crate::resolver::asn::structs::defs::DefinitionKey;
+
+// A set of integer values, as sorted, disjoint and non-adjacent ranges. A range starting at
+// `i128::MIN` (or ending at `i128::MAX`) is not bounded, ie. it is `MIN..` (or `..MAX`).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub(crate) struct ConstraintValues {
-    pub(crate) ranges: Vec<Range<i128>>,
-    pub(crate) values: Vec<i128>,
+    ranges: Vec<RangeInclusive<i128>>,
 }
 
 impl ConstraintValues {
+    // The empty set.
     pub(crate) fn new() -> Self {
-        ConstraintValues {
-            ranges: vec![],
-            values: vec![],
+        ConstraintValues { ranges: vec![] }
+    }
+
+    // All the values ie. `MIN..MAX`.
+    pub(crate) fn all() -> Self {
+        Self::from_range(i128::MIN, i128::MAX)
+    }
+
+    pub(crate) fn from_value(value: i128) -> Self {
+        Self::from_range(value, value)
+    }
+
+    // The values `lower..upper`, empty if `lower` is greater than `upper`.
+    pub(crate) fn from_range(lower: i128, upper: i128) -> Self {
+        Self::from_ranges(vec![lower..=upper])
+    }
+
+    fn from_ranges(mut ranges: Vec<RangeInclusive<i128>>) -> Self {
+        ranges.retain(|r| !r.is_empty());
+        ranges.sort_by_key(|r| *r.start());
+
+        let mut merged: Vec<RangeInclusive<i128>> = vec![];
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if *range.start() <= last.end().saturating_add(1) => {
+                    if range.end() > last.end() {
+                        *last = *last.start()..=*range.end();
+                    }
+                }
+                _ => merged.push(range),
+            }
         }
+        ConstraintValues { ranges: merged }
     }
 
-    pub(crate) fn append(&mut self, other: &Self) {
-        self.ranges.extend(other.ranges.clone());
-        self.values.extend(other.values.clone());
+    pub(crate) fn ranges(&self) -> &[RangeInclusive<i128>] {
+        &self.ranges
     }
 
     pub(crate) fn is_empty(&self) -> bool {
-        self.values.is_empty() && self.ranges.is_empty()
+        self.ranges.is_empty()
     }
 
-    pub(crate) fn min(&self) -> Option<i128> {
-        let values_min = self.values.iter().min();
-        let ranges_min = self.ranges.iter().map(|r| r.start).min();
-        match values_min {
-            None => ranges_min,
-            Some(vals_min) => match ranges_min {
-                None => Some(*vals_min),
-                Some(rnges_min) => Some(std::cmp::min(rnges_min, *vals_min)),
-            },
+    pub(crate) fn union(&self, other: &Self) -> Self {
+        Self::from_ranges(self.ranges.iter().chain(&other.ranges).cloned().collect())
+    }
+
+    pub(crate) fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        for r in &self.ranges {
+            for o in &other.ranges {
+                ranges.push(*r.start().max(o.start())..=*r.end().min(o.end()));
+            }
         }
+        Self::from_ranges(ranges)
     }
 
-    pub(crate) fn max(&self) -> Option<i128> {
-        let values_max = self.values.iter().max();
-        let ranges_max = self.ranges.iter().map(|r| r.end - 1).max();
-        match values_max {
-            None => ranges_max,
-            Some(vals_max) => match ranges_max {
-                None => Some(*vals_max),
-                Some(rnges_max) => Some(std::cmp::min(rnges_max, *vals_max)), // FIXME: This should be std::cmp::max?
-            },
+    // The values that are not in the set (`ALL EXCEPT`).
+    pub(crate) fn complement(&self) -> Self {
+        let mut ranges = vec![];
+        let mut next = Some(i128::MIN);
+        for r in &self.ranges {
+            if let Some(start) = next {
+                if *r.start() > start {
+                    ranges.push(start..=*r.start() - 1);
+                }
+            }
+            next = r.end().checked_add(1);
         }
+        if let Some(start) = next {
+            ranges.push(start..=i128::MAX);
+        }
+        ConstraintValues { ranges }
+    }
+
+    // The values of the set, that are not in the `other` set (`EXCEPT`).
+    pub(crate) fn except(&self, other: &Self) -> Self {
+        self.intersection(&other.complement())
+    }
+
+    // The smallest value, `None` if the set is empty or not bounded below.
+    pub(crate) fn min(&self) -> Option<i128> {
+        self.ranges
+            .first()
+            .map(|r| *r.start())
+            .filter(|min| *min != i128::MIN)
+    }
+
+    // The largest value, `None` if the set is empty or not bounded above.
+    pub(crate) fn max(&self) -> Option<i128> {
+        self.ranges
+            .last()
+            .map(|r| *r.end())
+            .filter(|max| *max != i128::MAX)
     }
 }
-#[derive(Debug, Clone)]
+
+// The values of a constraint. An extensible constraint has `additional_values`, the values that
+// are not in the root, which may be empty.
+#[derive(Debug, Clone, PartialEq)]
 pub(crate) struct Asn1ConstraintValueSet {
     pub(crate) root_values: ConstraintValues,
     pub(crate) additional_values: Option<ConstraintValues>,
 }
 
 impl Asn1ConstraintValueSet {
+    pub(crate) fn new(root_values: ConstraintValues) -> Self {
+        Asn1ConstraintValueSet {
+            root_values,
+            additional_values: None,
+        }
+    }
+
     pub(crate) fn has_extension(&self) -> bool {
         self.additional_values.is_some()
     }
+
+    // All the values, including the additional values.
+    pub(crate) fn all_values(&self) -> ConstraintValues {
+        match self.additional_values {
+            Some(ref additional) => self.root_values.union(additional),
+            None => self.root_values.clone(),
+        }
+    }
+
+    // The result of the set arithmetic on the roots and on all the values. The result is
+    // extensible as given by `extensible`.
+    fn combine<F>(&self, other: &Self, extensible: bool, op: F) -> Self
+    where
+        F: Fn(&ConstraintValues, &ConstraintValues) -> ConstraintValues,
+    {
+        let root_values = op(&self.root_values, &other.root_values);
+        let additional_values = if extensible {
+            Some(op(&self.all_values(), &other.all_values()).except(&root_values))
+        } else {
+            None
+        };
+        Asn1ConstraintValueSet {
+            root_values,
+            additional_values,
+        }
+    }
+
+    // Extensible if either of the sets is extensible.
+    pub(crate) fn union(&self, other: &Self) -> Self {
+        let extensible = self.has_extension() || other.has_extension();
+        self.combine(other, extensible, ConstraintValues::union)
+    }
+
+    // Extensible only if both the sets are extensible.
+    pub(crate) fn intersection(&self, other: &Self) -> Self {
+        let extensible = self.has_extension() && other.has_extension();
+        self.combine(other, extensible, ConstraintValues::intersection)
+    }
+
+    // Extensible if this set is extensible.
+    pub(crate) fn except(&self, other: &Self) -> Self {
+        let extensible = self.has_extension();
+        self.combine(other, extensible, ConstraintValues::except)
+    }
+
+    // The values of the type with the values of this set, when the `constraint` is (serially)
+    // applied to it. The extensibility is that of the last constraint applied (X.691 10.3).
+    pub(crate) fn constrained_by(&self, constraint: &Self) -> Self {
+        let extensible = constraint.has_extension();
+        self.combine(constraint, extensible, ConstraintValues::intersection)
+    }
+}
+
+// A Resolved Contents Constraint (`CONTAINING` and/or `ENCODED BY`) of an `OCTET STRING` or a
+// `BIT STRING`. `encoded_by` has the numbers of the Object Identifier of the Encoding Rules. When
+// it is absent, the contained type is encoded using the same Encoding Rules as the outer type.
+#[derive(Debug, Clone)]
+pub(crate) struct Asn1ResolvedContents {
+    pub(crate) containing: Option<DefinitionKey>,
+    pub(crate) encoded_by: Option<Vec<u32>>,
+}
+
+// The references are compared by the names of the definitions (See `DefinitionKey::same_name`).
+impl PartialEq for Asn1ResolvedContents {
+    fn eq(&self, other: &Self) -> bool {
+        DefinitionKey::same_names(&self.containing, &other.containing)
+            && self.encoded_by == other.encoded_by
+    }
+}
+
+// A constraint on the values of a type that is checked by the generated `validate` function. This
+// is obtained for the constraints that have an Inner Type Constraint (`WITH COMPONENT` or
+// `WITH COMPONENTS`), including the constraints on the values of the components.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum ResolvedValueConstraint {
+    // Satisfied when any of the constraints is satisfied.
+    Union(Vec<ResolvedValueConstraint>),
+    // Satisfied when all the constraints are satisfied.
+    Intersection(Vec<ResolvedValueConstraint>),
+    // The values of an `INTEGER`.
+    Integer(ConstraintValues),
+    // The size of a string or a `SEQUENCE OF` value. `chars` is `true` when the size is the number
+    // of characters of a character string.
+    Size {
+        values: ConstraintValues,
+        chars: bool,
+    },
+    Inner(ResolvedInnerTypeConstraint),
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum ResolvedInnerTypeConstraint {
+    // `WITH COMPONENT` constraint on every element of a `SEQUENCE OF`. `reference` is the type of
+    // the elements, when that is not defined inline.
+    Component {
+        reference: Option<DefinitionKey>,
+        constraint: Box<ResolvedValueConstraint>,
+    },
+    // `WITH COMPONENTS` constraint on the components of a `SEQUENCE` or a `SET`.
+    Components(Vec<ResolvedComponentConstraint>),
+    // `WITH COMPONENTS` constraint on the alternatives of a `CHOICE`.
+    Alternatives(Vec<ResolvedComponentConstraint>),
+}
+
+impl PartialEq for ResolvedInnerTypeConstraint {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                Self::Component {
+                    reference,
+                    constraint,
+                },
+                Self::Component {
+                    reference: other_reference,
+                    constraint: other_constraint,
+                },
+            ) => {
+                DefinitionKey::same_names(reference, other_reference)
+                    && constraint == other_constraint
+            }
+            (Self::Components(this), Self::Components(other)) => this == other,
+            (Self::Alternatives(this), Self::Alternatives(other)) => this == other,
+            _ => false,
+        }
+    }
+}
+
+// The constraint on a component. For the full specification, the (optional) components that are
+// not listed are `ABSENT`.
+#[derive(Debug, Clone)]
+pub(crate) struct ResolvedComponentConstraint {
+    pub(crate) id: String,
+    // Whether the component is an optional component (or an extension addition) of a `SEQUENCE`.
+    pub(crate) optional: bool,
+    pub(crate) presence: Option<ComponentPresence>,
+    pub(crate) value: Option<ResolvedValueConstraint>,
+    // The type of the component, when that is not defined inline.
+    pub(crate) reference: Option<DefinitionKey>,
+}
+
+impl PartialEq for ResolvedComponentConstraint {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.optional == other.optional
+            && self.presence == other.presence
+            && self.value == other.value
+            && DefinitionKey::same_names(&self.reference, &other.reference)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constraint_values_arithmetic() {
+        let low = ConstraintValues::from_range(1, 10);
+        let high = ConstraintValues::from_range(5, 20);
+
+        let union = low.union(&high).union(&ConstraintValues::from_value(21));
+        assert_eq!(union.ranges(), &[1..=21]);
+        assert_eq!((union.min(), union.max()), (Some(1), Some(21)));
+
+        let intersection = low.intersection(&high);
+        assert_eq!(intersection.ranges(), &[5..=10]);
+
+        let except = low.except(&ConstraintValues::from_value(5));
+        assert_eq!(except.ranges(), &[1..=4, 6..=10]);
+
+        assert!(low
+            .intersection(&ConstraintValues::from_value(11))
+            .is_empty());
+        assert!(ConstraintValues::from_range(10, 1).is_empty());
+
+        let all_except = ConstraintValues::from_value(0).complement();
+        assert_eq!(all_except.ranges(), &[i128::MIN..=-1, 1..=i128::MAX]);
+        assert_eq!((all_except.min(), all_except.max()), (None, None));
+        assert_eq!(all_except.complement(), ConstraintValues::from_value(0));
+        assert!(ConstraintValues::all().complement().is_empty());
+
+        let unbounded = ConstraintValues::from_range(0, i128::MAX);
+        assert_eq!((unbounded.min(), unbounded.max()), (Some(0), None));
+    }
+
+    #[test]
+    fn constraint_value_set_extensibility() {
+        let root = Asn1ConstraintValueSet::new(ConstraintValues::from_range(1, 10));
+        let extensible = Asn1ConstraintValueSet {
+            root_values: ConstraintValues::from_range(5, 20),
+            additional_values: Some(ConstraintValues::from_value(30)),
+        };
+
+        let union = root.union(&extensible);
+        assert!(union.has_extension());
+        assert_eq!(union.root_values.ranges(), &[1..=20]);
+        assert_eq!(
+            union.additional_values,
+            Some(ConstraintValues::from_value(30))
+        );
+
+        assert!(!root.intersection(&extensible).has_extension());
+        assert!(extensible.intersection(&extensible).has_extension());
+        assert!(!root.except(&extensible).has_extension());
+
+        // Serially applied constraints are extensible only when the last one is.
+        assert!(!extensible.constrained_by(&root).has_extension());
+        let constrained = root.constrained_by(&extensible);
+        assert!(constrained.has_extension());
+        assert_eq!(constrained.root_values.ranges(), &[5..=10]);
+    }
 }
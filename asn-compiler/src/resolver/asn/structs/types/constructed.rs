@@ -1,44 +1,76 @@
 //! Structs for the resolved Base Types
 
-use crate::resolver::asn::structs::types::{constraints::Asn1ConstraintValueSet, Asn1ResolvedType};
+use crate::resolver::asn::structs::{
+    types::{
+        constraints::{Asn1ConstraintValueSet, ResolvedValueConstraint},
+        tags::{ResolvedTagging, ResolvedTypeTags},
+        Asn1ResolvedType,
+    },
+    values::Asn1ResolvedValue,
+};
 
-#[derive(Debug, Clone)]
+// The `constraint` is the Inner Type Constraint on the values of the type, that is checked by the
+// generated code.
+#[derive(Debug, Clone, PartialEq)]
 pub(crate) enum ResolvedConstructedType {
     Choice {
         name: Option<String>,
         root_components: Vec<ResolvedComponent>,
         additions: Option<Vec<ResolvedComponent>>,
+        constraint: Option<ResolvedValueConstraint>,
     },
     Sequence {
         name: Option<String>,
         extensible: bool,
         components: Vec<ResolvedSeqComponent>,
+        additions: Vec<ResolvedSeqAdditionGroup>,
+        constraint: Option<ResolvedValueConstraint>,
+    },
+    // Same as a `Sequence`, except the root components are in the canonical order of their tags.
+    Set {
+        name: Option<String>,
+        extensible: bool,
+        components: Vec<ResolvedSeqComponent>,
+        additions: Vec<ResolvedSeqAdditionGroup>,
+        constraint: Option<ResolvedValueConstraint>,
     },
     SequenceOf {
         name: Option<String>,
         ty: Box<Asn1ResolvedType>,
         size_values: Option<Asn1ConstraintValueSet>,
+        constraint: Option<ResolvedValueConstraint>,
     },
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub(crate) enum ClassFieldComponentType {
     FixedTypeValue,
     Type,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub(crate) struct ResolvedComponent {
     pub(crate) id: String,
     pub(crate) ty: Asn1ResolvedType,
+    // The effective tags of the component and the taggings to be applied on the tags of the type
+    // generated for the component, to get those.
+    pub(crate) tags: ResolvedTypeTags,
+    pub(crate) tagging: Vec<ResolvedTagging>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub(crate) struct ResolvedSeqComponent {
     pub(crate) component: ResolvedComponent,
     pub(crate) optional: bool,
     pub(crate) class_field_type: Option<ClassFieldComponentType>,
     pub(crate) key_field: bool,
-    // FIXME : Handle default
-    // pub(crate) default: Option<Asn1ResolvedType>
+    // The `DEFAULT` value (resolved against the type of the component). A component with a
+    // `DEFAULT` value is also `optional`.
+    pub(crate) default: Option<Asn1ResolvedValue>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ResolvedSeqAdditionGroup {
+    pub(crate) components: Vec<ResolvedSeqComponent>,
+    pub(crate) is_group: bool,
 }
@@ -10,9 +10,13 @@ pub(crate) mod ioc;
 
 pub(crate) mod constraints;
 
+pub(crate) mod tags;
+
+use super::defs::DefinitionKey;
+
 pub(crate) type ResolvedSetTypeMap = BTreeMap<(String, String), (String, Asn1ResolvedType)>;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub(crate) struct ResolvedSetType {
     pub(crate) setref: String,
     pub(crate) types: ResolvedSetTypeMap,
@@ -27,8 +31,21 @@ pub(crate) enum Asn1ResolvedType {
     Constructed(ResolvedConstructedType),
 
     // A reference to a Resolved Type
-    Reference(String),
+    Reference(DefinitionKey),
 
     // A Set of Resolved Types. This is true if the type is obtained from Object Sets or Value Sets
     Set(ResolvedSetType),
 }
+
+// The references are compared by the names of the definitions (See `DefinitionKey::same_name`).
+impl PartialEq for Asn1ResolvedType {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Base(this), Self::Base(other)) => this == other,
+            (Self::Constructed(this), Self::Constructed(other)) => this == other,
+            (Self::Reference(this), Self::Reference(other)) => this.same_name(other),
+            (Self::Set(this), Self::Set(other)) => this == other,
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,108 @@
+//! Structs for the resolved Tags
+
+use std::collections::BTreeSet;
+
+// The order of the classes is the canonical order of the tags (X.680 8.6).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) enum ResolvedTagClass {
+    Universal,
+    Application,
+    ContextSpecific,
+    Private,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct ResolvedTag {
+    pub(crate) class: ResolvedTagClass,
+    pub(crate) number: u32,
+}
+
+impl ResolvedTag {
+    pub(crate) fn universal(number: u32) -> Self {
+        Self {
+            class: ResolvedTagClass::Universal,
+            number,
+        }
+    }
+}
+
+impl std::fmt::Display for ResolvedTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.class {
+            ResolvedTagClass::Universal => write!(f, "[UNIVERSAL {}]", self.number),
+            ResolvedTagClass::Application => write!(f, "[APPLICATION {}]", self.number),
+            ResolvedTagClass::ContextSpecific => write!(f, "[{}]", self.number),
+            ResolvedTagClass::Private => write!(f, "[PRIVATE {}]", self.number),
+        }
+    }
+}
+
+// A tag applied on the tags of a type, as in `[APPLICATION 1] IMPLICIT` or `[0] EXPLICIT`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ResolvedTagging {
+    pub(crate) tag: ResolvedTag,
+    pub(crate) explicit: bool,
+}
+
+impl std::fmt::Display for ResolvedTagging {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.explicit {
+            write!(f, "{} EXPLICIT", self.tag)
+        } else {
+            write!(f, "{} IMPLICIT", self.tag)
+        }
+    }
+}
+
+// The effective tags of a type, the outermost tag first.
+//
+// An untagged `CHOICE` does not have a tag of it's own. The tags of such a type are the outermost
+// tags of it's alternatives (`choice_tags`). An Open Type has neither of these.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct ResolvedTypeTags {
+    pub(crate) tags: Vec<ResolvedTag>,
+    pub(crate) choice_tags: BTreeSet<ResolvedTag>,
+}
+
+impl ResolvedTypeTags {
+    // The tag used for sorting the components of a `SET`. For an untagged `CHOICE`, this is the
+    // smallest of the tags of it's alternatives (X.680 8.6).
+    pub(crate) fn canonical_tag(&self) -> Option<ResolvedTag> {
+        self.tags
+            .first()
+            .or_else(|| self.choice_tags.iter().next())
+            .copied()
+    }
+
+    // The tags with which an encoding of the type may begin. For an untagged `CHOICE`, these are
+    // the tags of all it's alternatives.
+    pub(crate) fn outermost_tags(&self) -> BTreeSet<ResolvedTag> {
+        match self.tags.first() {
+            Some(tag) => BTreeSet::from([*tag]),
+            None => self.choice_tags.clone(),
+        }
+    }
+
+    // The taggings to be applied on the tags of the `base` type to get these tags. The outer tags
+    // are applied explicitly and the one replacing the outermost tag of the `base` type (if it is
+    // different) implicitly.
+    pub(crate) fn tagging_over(&self, base: &ResolvedTypeTags) -> Vec<ResolvedTagging> {
+        let explicit_count = self.tags.len().saturating_sub(base.tags.len());
+        let mut taggings = self.tags[..explicit_count]
+            .iter()
+            .map(|tag| ResolvedTagging {
+                tag: *tag,
+                explicit: true,
+            })
+            .collect::<Vec<ResolvedTagging>>();
+        if let (Some(tag), Some(base_tag)) = (self.tags.get(explicit_count), base.tags.first()) {
+            if tag != base_tag {
+                taggings.push(ResolvedTagging {
+                    tag: *tag,
+                    explicit: false,
+                });
+            }
+        }
+        taggings
+    }
+}
@@ -1,3 +1,4 @@
+use super::defs::DefinitionKey;
 use super::types::Asn1ResolvedType;
 
 /// An INTEGER value will be represented by a BaseInteger type when 'Resolved'.
@@ -9,31 +10,107 @@ pub(crate) type BaseEnum = i64;
 /// A BOOLEAN Value will be represented by a a BaseBoolean type when 'Resolved'.
 pub(crate) type BaseBoolean = bool;
 
+/// A REAL Value will be represented by a BaseReal type when 'Resolved'.
+pub(crate) type BaseReal = f64;
+
 /// Any CharacterString value will be represented by a BaseCharString type when 'Resolved'.
-pub(crate) type BaseCharString = bool;
+pub(crate) type BaseCharString = String;
 
 /// Any OCTET STRING value will be represente by a BaseOctetString type when 'Resolved'.
-pub(crate) type BaseOctetSTring = Vec<u8>;
+pub(crate) type BaseOctetString = Vec<u8>;
 
-#[derive(Debug, Clone)]
+/// A BIT STRING value will be represented by a BaseBitString type (one `bool` per bit) when
+/// 'Resolved'.
+pub(crate) type BaseBitString = Vec<bool>;
+
+/// An OBJECT IDENTIFIER (or a RELATIVE-OID) value will be represented by a BaseObjectIdentifier
+/// type (the arcs) when 'Resolved'.
+pub(crate) type BaseObjectIdentifier = Vec<u32>;
+
+#[derive(Debug, Clone, PartialEq)]
 pub(crate) struct Asn1ResolvedIntegerValue {
     pub(crate) typeref: Asn1ResolvedType,
     pub(crate) value: BaseInteger,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub(crate) struct Asn1ResolvedEnumValue {
     pub(crate) typeref: Asn1ResolvedType,
+    pub(crate) identifier: String,
     pub(crate) value: BaseEnum,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Asn1ResolvedBooleanValue {
+    pub(crate) typeref: Asn1ResolvedType,
+    pub(crate) value: BaseBoolean,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Asn1ResolvedRealValue {
+    pub(crate) typeref: Asn1ResolvedType,
+    pub(crate) value: BaseReal,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Asn1ResolvedCharStringValue {
+    pub(crate) typeref: Asn1ResolvedType,
+    pub(crate) value: BaseCharString,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Asn1ResolvedOctetStringValue {
+    pub(crate) typeref: Asn1ResolvedType,
+    pub(crate) value: BaseOctetString,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Asn1ResolvedBitStringValue {
+    pub(crate) typeref: Asn1ResolvedType,
+    pub(crate) value: BaseBitString,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Asn1ResolvedObjectIdentifierValue {
+    pub(crate) typeref: Asn1ResolvedType,
+    pub(crate) value: BaseObjectIdentifier,
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub(crate) enum ResolvedBaseValue {
     Integer(Asn1ResolvedIntegerValue),
     Enum(Asn1ResolvedEnumValue),
+    Boolean(Asn1ResolvedBooleanValue),
+    Real(Asn1ResolvedRealValue),
+    CharString(Asn1ResolvedCharStringValue),
+    OctetString(Asn1ResolvedOctetStringValue),
+    BitString(Asn1ResolvedBitStringValue),
+    ObjectIdentifier(Asn1ResolvedObjectIdentifierValue),
+    Null,
 }
 
-#[derive(Debug, Clone)]
+// A Resolved value of a Constructed Type. The values of the elements are resolved against the type
+// of the elements.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum ResolvedConstructedValue {
+    SequenceOf {
+        typeref: Asn1ResolvedType,
+        values: Vec<Asn1ResolvedValue>,
+    },
+    // A value of a `SEQUENCE` or a `SET`. The components present in the value are in the order of
+    // the components of the type.
+    Sequence {
+        typeref: Asn1ResolvedType,
+        components: Vec<(String, Asn1ResolvedValue)>,
+    },
+    Choice {
+        typeref: Asn1ResolvedType,
+        identifier: String,
+        value: Box<Asn1ResolvedValue>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub(crate) struct ResolvedRefValue {
     pub(crate) reference: String,
     pub(crate) value: Box<Asn1ResolvedValue>,
@@ -41,12 +118,32 @@ pub(crate) struct ResolvedRefValue {
 
 #[derive(Debug, Clone)]
 pub(crate) enum Asn1ResolvedValue {
-    Reference(String),
+    Reference(DefinitionKey),
     ReferencedType {
-        typeref: String,
+        typeref: DefinitionKey,
         value: Box<Asn1ResolvedValue>,
     },
     Base(ResolvedBaseValue),
+    Constructed(ResolvedConstructedValue),
+}
+
+// The references are compared by the names of the definitions (See `DefinitionKey::same_name`).
+impl PartialEq for Asn1ResolvedValue {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Reference(this), Self::Reference(other)) => this.same_name(other),
+            (
+                Self::ReferencedType { typeref, value },
+                Self::ReferencedType {
+                    typeref: other_typeref,
+                    value: other_value,
+                },
+            ) => typeref.same_name(other_typeref) && value == other_value,
+            (Self::Base(this), Self::Base(other)) => this == other,
+            (Self::Constructed(this), Self::Constructed(other)) => this == other,
+            _ => false,
+        }
+    }
 }
 
 impl Asn1ResolvedValue {
@@ -57,4 +154,23 @@ impl Asn1ResolvedValue {
             _ => None,
         }
     }
+
+    // Returns the value of the base type, if this is a value of a base type or a reference to one.
+    pub(crate) fn get_base_value(&self) -> Option<&ResolvedBaseValue> {
+        match self {
+            Self::Base(ref b) => Some(b),
+            Self::ReferencedType { value, .. } => value.get_base_value(),
+            _ => None,
+        }
+    }
+
+    // Returns the value of the constructed type, if this is a value of a constructed type or a
+    // reference to one.
+    pub(crate) fn get_constructed_value(&self) -> Option<&ResolvedConstructedValue> {
+        match self {
+            Self::Constructed(ref c) => Some(c),
+            Self::ReferencedType { value, .. } => value.get_constructed_value(),
+            _ => None,
+        }
+    }
 }
@@ -9,6 +9,7 @@ use crate::parser::asn::structs::types::{
     Asn1Type,
 };
 use crate::resolver::asn::structs::types::base::Asn1ResolvedBitString;
+use crate::resolver::asn::types::constraints::serially_constrained;
 use crate::resolver::Resolver;
 
 impl Asn1ResolvedBitString {
@@ -21,16 +22,9 @@ impl Asn1ResolvedBitString {
 
         if ty.constraints.is_some() {
             let constraints = ty.constraints.as_ref().unwrap();
-            if !constraints.is_empty() {
-                let constraint = &constraints[0];
+            base.size = serially_constrained(None, constraints, |c| c.get_size_valueset(resolver))?;
 
-                if constraint.is_size_constraint() {
-                    let value_set = constraint.get_size_valueset(resolver)?;
-                    let _ = base.size.replace(value_set);
-                } else {
-                    // The constraint is a 'CONTAINING' Constraint, TODO: Handle this.
-                }
-            }
+            base.contents = constraints.iter().find_map(|c| c.get_contents(resolver));
         }
 
         if b.named_bits.is_some() {
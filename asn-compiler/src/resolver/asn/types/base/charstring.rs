@@ -3,7 +3,9 @@
 use crate::error::Error;
 use crate::resolver::Resolver;
 
-use crate::parser::asn::structs::types::{Asn1BuiltinType, Asn1Type, Asn1TypeKind};
+use crate::parser::asn::structs::types::{
+    constraints::Asn1Constraint, Asn1BuiltinType, Asn1Type, Asn1TypeKind,
+};
 use crate::resolver::asn::structs::types::base::Asn1ResolvedCharacterString;
 
 impl Asn1ResolvedCharacterString {
@@ -16,22 +18,38 @@ impl Asn1ResolvedCharacterString {
         if let Asn1TypeKind::Builtin(Asn1BuiltinType::CharacterString { str_type }) = &ty.kind {
             base.str_type = str_type.clone();
         }
-        if ty.constraints.is_some() {
-            let constraints = ty.constraints.as_ref().unwrap();
-            if !constraints.is_empty() {
-                let constraint = &constraints[0];
+        if let Some(constraints) = ty.constraints.as_ref() {
+            base.constrain(constraints, resolver)?;
+        }
+        Ok(base)
+    }
 
-                if constraint.is_size_constraint() {
-                    let value_set = constraint.get_size_valueset(resolver)?;
-                    let _ = base.size.replace(value_set);
-                } else {
-                    return Err(constraint_error!(
-                        "Expected a Size Constraint, Found '{:#?}'",
-                        constraint
-                    ));
-                }
+    // Applies the `SIZE` and the `FROM` constraints to the values of the type. Returns whether
+    // any of the constraints has these.
+    pub(crate) fn constrain(
+        &mut self,
+        constraints: &[Asn1Constraint],
+        resolver: &Resolver,
+    ) -> Result<bool, Error> {
+        let mut constrained = false;
+        // Serial constraints like `(FROM ("0".."9")) (SIZE (1..8))` each apply to the type.
+        for constraint in constraints {
+            let (size, alphabet) = constraint.get_character_string_constraints(resolver)?;
+            if let Some(size) = size {
+                self.size = Some(match self.size.take() {
+                    Some(current) => current.constrained_by(&size),
+                    None => size,
+                });
+                constrained = true;
+            }
+            if let Some(alphabet) = alphabet {
+                self.alphabet = Some(match self.alphabet.take() {
+                    Some(current) => current.intersection(&alphabet).cloned().collect(),
+                    None => alphabet,
+                });
+                constrained = true;
             }
         }
-        Ok(base)
+        Ok(constrained)
     }
 }
@@ -1,9 +1,16 @@
 //! Functionality for handling Resolved ASN.1 INTEGER Types
 
+use std::collections::HashMap;
+
 use crate::error::Error;
 
-use crate::parser::asn::structs::types::{base::Asn1TypeInteger, Asn1Type};
-use crate::resolver::asn::structs::types::base::Asn1ResolvedInteger;
+use crate::parser::asn::structs::types::{
+    base::{Asn1TypeInteger, NamedValue},
+    Asn1Type,
+};
+use crate::resolver::asn::structs::types::{
+    base::Asn1ResolvedInteger, constraints::Asn1ConstraintValueSet,
+};
 use crate::resolver::Resolver;
 
 impl Asn1ResolvedInteger {
@@ -15,17 +22,37 @@ impl Asn1ResolvedInteger {
     ) -> Result<Asn1ResolvedInteger, Error> {
         let mut base = Asn1ResolvedInteger::default();
 
-        if ty.constraints.is_none() {
-            return Ok(base);
-        } else {
-            let constraints = ty.constraints.as_ref().unwrap();
-            if constraints.is_empty() {
-                return Ok(base);
+        let constrained = matches!(ty.constraints, Some(ref c) if !c.is_empty());
+
+        // Get the Values that are expected
+        if constrained {
+            if let Some(value_set) = ty.get_integer_valueset_from_constraint(resolver)? {
+                base = Asn1ResolvedInteger::from_valueset(value_set);
             }
         }
 
-        // Get the Values that are expected
-        let value_set = ty.get_integer_valueset_from_constraint(resolver)?;
+        // The named numbers are used for resolving the values of this type (eg. `DEFAULT high`).
+        if let Some(ref named_values) = i.named_values {
+            let mut named = HashMap::new();
+            for (name, value) in named_values {
+                if let NamedValue::Number(ref v) = value {
+                    let parsed = v.parse::<i128>().map_err(|_| {
+                        resolve_error!("Named number '{}({})' is not a valid INTEGER!", name, v)
+                    })?;
+                    named.insert(name.clone(), parsed);
+                }
+            }
+            base.named_values = Some(named);
+        }
+
+        Ok(base)
+    }
+
+    // An integer with the given PER-visible values, with the bit width and the signedness needed
+    // for the values. A value set that is not bounded is given the default (64 bits) width.
+    pub(crate) fn from_valueset(value_set: Asn1ConstraintValueSet) -> Asn1ResolvedInteger {
+        let mut base = Asn1ResolvedInteger::default();
+
         if let Some(x) = value_set.root_values.min() {
             if x < 0 {
                 base.signed = true
@@ -34,17 +61,21 @@ impl Asn1ResolvedInteger {
             }
         }
 
-        let bit_width = if base.signed {
-            let min = value_set.root_values.min().unwrap();
-            let max = value_set.root_values.max().unwrap();
-            let bits_needed_max = 128 - max.abs().leading_zeros();
-            let bits_needed_min = 128 - min.abs().leading_zeros();
-            std::cmp::max(bits_needed_min, bits_needed_max)
-        } else if value_set.root_values.min().is_none() {
-            8_u32
-        } else {
-            let max = value_set.root_values.max().unwrap();
-            128 - max.leading_zeros()
+        // Bits needed for a value of a signed type, including the sign bit.
+        fn signed_bits(v: i128) -> u32 {
+            if v < 0 {
+                129 - (!v).leading_zeros()
+            } else {
+                129 - v.leading_zeros()
+            }
+        }
+
+        let bit_width = match (value_set.root_values.min(), value_set.root_values.max()) {
+            (Some(min), Some(max)) if base.signed => {
+                std::cmp::max(signed_bits(min), signed_bits(max))
+            }
+            (Some(_), Some(max)) => 128 - max.leading_zeros(),
+            _ => u32::from(base.bits),
         };
 
         base.bits = if bit_width <= 8 {
@@ -59,10 +90,7 @@ impl Asn1ResolvedInteger {
             128
         };
 
-        // TODO: If we have named values, They should be added to Global list of resolved definitions.
-        if i.named_values.is_some() {}
-
         let _ = base.resolved_constraints.replace(value_set);
-        Ok(base)
+        base
     }
 }
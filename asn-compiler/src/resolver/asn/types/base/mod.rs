@@ -18,7 +18,8 @@ use crate::resolver::{
     asn::structs::types::base::{
         Asn1ResolvedBitString, Asn1ResolvedBoolean, Asn1ResolvedCharacterString,
         Asn1ResolvedEnumerated, Asn1ResolvedInteger, Asn1ResolvedNull,
-        Asn1ResolvedObjectIdentifier, Asn1ResolvedOctetString, ResolvedBaseType,
+        Asn1ResolvedObjectIdentifier, Asn1ResolvedOctetString, Asn1ResolvedReal, Asn1ResolvedTime,
+        ResolvedBaseType,
     },
     Resolver,
 };
@@ -41,6 +42,7 @@ pub(crate) fn resolve_base_type(
             Asn1BuiltinType::Boolean => {
                 Ok(ResolvedBaseType::Boolean(Asn1ResolvedBoolean::default()))
             }
+            Asn1BuiltinType::Real => Ok(ResolvedBaseType::Real(Asn1ResolvedReal)),
             Asn1BuiltinType::OctetString => Ok(ResolvedBaseType::OctetString(
                 Asn1ResolvedOctetString::resolve_octet_string(ty, resolver)?,
             )),
@@ -50,11 +52,15 @@ pub(crate) fn resolve_base_type(
             Asn1BuiltinType::ObjectIdentifier => Ok(ResolvedBaseType::ObjectIdentifier(
                 Asn1ResolvedObjectIdentifier::default(),
             )),
-            Asn1BuiltinType::Null => Ok(ResolvedBaseType::Null(Asn1ResolvedNull::default())),
-            _ => Err(resolve_error!(
-                "parse_base_type: Not Implemented! {:#?}",
-                ty
+            Asn1BuiltinType::RelativeOid => Ok(ResolvedBaseType::ObjectIdentifier(
+                Asn1ResolvedObjectIdentifier { relative: true },
             )),
+            Asn1BuiltinType::Time { ref time_type } => {
+                Ok(ResolvedBaseType::Time(Asn1ResolvedTime {
+                    time_type: time_type.clone(),
+                }))
+            }
+            Asn1BuiltinType::Null => Ok(ResolvedBaseType::Null(Asn1ResolvedNull::default())),
         }
     } else {
         Err(resolve_error!("Expected Base Type. Found '{:#?}'", ty))
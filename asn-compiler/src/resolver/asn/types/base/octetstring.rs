@@ -5,6 +5,7 @@ use crate::resolver::Resolver;
 
 use crate::parser::asn::structs::types::Asn1Type;
 use crate::resolver::asn::structs::types::base::Asn1ResolvedOctetString;
+use crate::resolver::asn::types::constraints::serially_constrained;
 
 impl Asn1ResolvedOctetString {
     pub(crate) fn resolve_octet_string(
@@ -15,16 +16,9 @@ impl Asn1ResolvedOctetString {
 
         if ty.constraints.is_some() {
             let constraints = ty.constraints.as_ref().unwrap();
-            if !constraints.is_empty() {
-                let constraint = &constraints[0];
+            base.size = serially_constrained(None, constraints, |c| c.get_size_valueset(resolver))?;
 
-                if constraint.is_size_constraint() {
-                    let value_set = constraint.get_size_valueset(resolver)?;
-                    let _ = base.size.replace(value_set);
-                } else {
-                    // The constraint is a 'CONTAINING' Constraint, TODO: Handle this.
-                }
-            }
+            base.contents = constraints.iter().find_map(|c| c.get_contents(resolver));
         }
         Ok(base)
     }
@@ -1,17 +1,34 @@
 //! Constraint Resolution Implementation
-use std::ops::Range;
+use std::collections::BTreeSet;
 
 use crate::error::Error;
 
-use crate::parser::asn::structs::types::constraints::*;
+use crate::parser::asn::structs::{types::constraints::*, values::Asn1Value};
 
 use crate::resolver::asn::structs::{
-    defs::Asn1ResolvedDefinition,
-    types::constraints::{Asn1ConstraintValueSet, ConstraintValues},
+    defs::{Asn1ResolvedDefinition, DefinitionKey},
+    types::{
+        base::ResolvedBaseType,
+        constraints::{
+            Asn1ConstraintValueSet, Asn1ResolvedContents, ConstraintValues,
+            ResolvedComponentConstraint, ResolvedInnerTypeConstraint, ResolvedValueConstraint,
+        },
+        constructed::{ResolvedComponent, ResolvedConstructedType},
+        Asn1ResolvedType,
+    },
     values::{Asn1ResolvedValue, ResolvedBaseValue},
 };
 use crate::resolver::Resolver;
 
+// What is obtained from a constraint: the values of an `INTEGER` or the values of the `SIZE` of a
+// string or a `SEQUENCE OF`, and whether the exclusions of `EXCEPT` are applied. For the PER-visible
+// values (X.691 10.3), that the codecs use, the exclusions are ignored.
+#[derive(Debug, Clone, Copy)]
+struct ValueSetQuery {
+    size: bool,
+    except: bool,
+}
+
 impl Asn1Constraint {
     /// Returns 'value' `String` for the constraint
     ///
@@ -80,18 +97,47 @@ impl Asn1Constraint {
         }
     }
 
+    // Returns the PER-visible values of an `INTEGER` given by the constraint, `None` if the
+    // constraint is not PER-visible.
     pub(crate) fn get_integer_valueset(
         &self,
         resolver: &Resolver,
-    ) -> Result<Asn1ConstraintValueSet, Error> {
+    ) -> Result<Option<Asn1ConstraintValueSet>, Error> {
+        self.get_valueset(
+            ValueSetQuery {
+                size: false,
+                except: false,
+            },
+            resolver,
+        )
+    }
+
+    // Returns the PER-visible values of the `SIZE` of a string or a `SEQUENCE OF` given by the
+    // constraint, `None` if the constraint has no PER-visible `SIZE` constraint.
+    pub(crate) fn get_size_valueset(
+        &self,
+        resolver: &Resolver,
+    ) -> Result<Option<Asn1ConstraintValueSet>, Error> {
+        self.get_valueset(
+            ValueSetQuery {
+                size: true,
+                except: false,
+            },
+            resolver,
+        )
+    }
+
+    fn get_valueset(
+        &self,
+        query: ValueSetQuery,
+        resolver: &Resolver,
+    ) -> Result<Option<Asn1ConstraintValueSet>, Error> {
         match self {
-            Self::Subtype(ref e) => e.get_integer_valueset(resolver),
-            _ => Err(constraint_error!(
-                "Integer Values not supported for the constraint '{:#?}'",
-                self
-            )),
+            Self::Subtype(ref e) => e.get_valueset(query, resolver),
+            _ => Ok(None),
         }
     }
+
     // Returns whether this constraint is a Subtype Constraint
     fn is_subtype(&self) -> bool {
         true
@@ -107,53 +153,81 @@ impl Asn1Constraint {
         true
     }
 
-    // Returns whether the constraint is a 'size' constraint.
-    pub(crate) fn is_size_constraint(&self) -> bool {
+    // Returns whether the constraint has an Inner Type Constraint (`WITH COMPONENT` or
+    // `WITH COMPONENTS`).
+    pub(crate) fn has_inner_type_constraint(&self) -> bool {
         if let Self::Subtype(ref e) = self {
-            let iset = e.get_inner_elements();
-            if iset.len() != 1 {
-                false
-            } else {
-                matches!(
-                    iset[0].elements[0],
-                    Elements::Subtype(SubtypeElements::SizeConstraint(..))
-                )
-            }
+            e.has_inner_type_constraint()
         } else {
             false
         }
     }
 
-    pub(crate) fn get_size_valueset(
+    // Returns the constraint to be checked on the values of the type `ty`, if the constraint has
+    // an Inner Type Constraint. The other constraints are PER-visible and are taken care of by the
+    // codecs.
+    pub(crate) fn get_inner_type_constraint(
         &self,
+        ty: &Asn1ResolvedType,
         resolver: &Resolver,
-    ) -> Result<Asn1ConstraintValueSet, Error> {
+    ) -> Result<Option<ResolvedValueConstraint>, Error> {
+        match self {
+            Self::Subtype(ref e) if e.has_inner_type_constraint() => {
+                e.get_value_constraint(ty, resolver)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    // Returns the resolved Contents Constraint, if the constraint is one. The contained type is
+    // not required to be resolved yet (it may even be defined later), only the definition it
+    // refers to is determined.
+    pub(crate) fn get_contents(&self, resolver: &Resolver) -> Option<Asn1ResolvedContents> {
+        if let Self::Contents {
+            containing,
+            encoded_by,
+        } = self
+        {
+            Some(Asn1ResolvedContents {
+                containing: containing.as_ref().map(|c| resolver.definition_key(c)),
+                encoded_by: encoded_by.as_ref().map(|oid| oid.numbers()),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the `SIZE` and the `FROM` (PermittedAlphabet) constraints of a character string type.
+    ///
+    /// The two may be given as an intersection like `(SIZE (1..8) ^ FROM ("0".."9"))`. A
+    /// PermittedAlphabet constraint with an extension marker is not PER-visible and is ignored.
+    pub(crate) fn get_character_string_constraints(
+        &self,
+        resolver: &Resolver,
+    ) -> Result<(Option<Asn1ConstraintValueSet>, Option<BTreeSet<char>>), Error> {
+        let size = self.get_size_valueset(resolver)?;
+        let mut alphabet: Option<BTreeSet<char>> = None;
         if let Self::Subtype(ref e) = self {
             let iset = e.get_inner_elements();
             if iset.len() == 1 {
-                match iset[0].elements[0] {
-                    Elements::Subtype(ref s) => match s {
-                        SubtypeElements::SizeConstraint(ref elems) => {
-                            elems.get_integer_valueset(resolver)
+                for element in &iset[0].elements {
+                    if let Elements::Subtype(SubtypeElements::PermittedAlphabet(ref elems)) =
+                        element
+                    {
+                        if elems.additional_elements.is_none() {
+                            let permitted = elems.get_permitted_alphabet()?;
+                            alphabet = Some(match alphabet.take() {
+                                Some(current) => {
+                                    current.intersection(&permitted).cloned().collect()
+                                }
+                                None => permitted,
+                            });
                         }
-                        _ => Err(constraint_error!(
-                            "The Constraint for the Type is not a Size Constraint."
-                        )),
-                    },
-                    _ => Err(constraint_error!(
-                        "The Constraint for the type is not a size Constraint."
-                    )),
+                    }
                 }
-            } else {
-                Err(constraint_error!(
-                    "The Constraint for the type is not a size Constraint."
-                ))
             }
-        } else {
-            Err(constraint_error!(
-                "The Constraint for the type is not a size Constraint."
-            ))
         }
+        Ok((size, alphabet))
     }
 }
 
@@ -176,29 +250,100 @@ impl ElementSet {
         }
     }
 
-    fn get_integer_valueset(&self, resolver: &Resolver) -> Result<Asn1ConstraintValueSet, Error> {
-        let mut root_values = ConstraintValues::new();
-        for element in self.get_inner_elements() {
-            let element_values = element.get_integer_valueset(resolver)?;
-            root_values.append(&element_values);
+    // The values of an `INTEGER` given by the element set of a Value Set, including the
+    // exclusions of `EXCEPT`. `None` if the values cannot be obtained.
+    pub(crate) fn get_values(
+        &self,
+        resolver: &Resolver,
+    ) -> Result<Option<Asn1ConstraintValueSet>, Error> {
+        let query = ValueSetQuery {
+            size: false,
+            except: true,
+        };
+        self.get_valueset(query, resolver)
+    }
+
+    // The values of the element set. The additional elements, if present, make the values
+    // extensible. `None` if the root is not PER-visible.
+    fn get_valueset(
+        &self,
+        query: ValueSetQuery,
+        resolver: &Resolver,
+    ) -> Result<Option<Asn1ConstraintValueSet>, Error> {
+        let mut values = match self.root_elements.get_valueset(query, resolver)? {
+            Some(values) => values,
+            None => return Ok(None),
+        };
+
+        if let Some(ref additional_elements) = self.additional_elements {
+            let additional_values = match additional_elements.get_valueset(query, resolver)? {
+                Some(additional) => values.all_values().union(&additional.all_values()),
+                None => values.all_values(),
+            };
+            values.additional_values = Some(additional_values.except(&values.root_values));
+        }
+        Ok(Some(values))
+    }
+
+    fn has_inner_type_constraint(&self) -> bool {
+        self.get_inner_elements().iter().any(|iset| {
+            iset.elements
+                .iter()
+                .any(|element| element.has_inner_type_constraint())
+        })
+    }
+
+    // The constraint on the values of the type `ty` given by the root of the element set. The
+    // parts of the constraint that cannot be checked are left out of an intersection, while a
+    // union with such a part is not checked at all. An extensible constraint is not checked.
+    fn get_value_constraint(
+        &self,
+        ty: &Asn1ResolvedType,
+        resolver: &Resolver,
+    ) -> Result<Option<ResolvedValueConstraint>, Error> {
+        if self.additional_elements.is_some() {
+            return Ok(None);
+        }
+
+        // The values of an `INTEGER` are obtained with the set arithmetic, including `EXCEPT`.
+        if let Asn1ResolvedType::Base(ResolvedBaseType::Integer(..)) = governing_type(ty, resolver)?
+        {
+            let query = ValueSetQuery {
+                size: false,
+                except: true,
+            };
+            return Ok(self
+                .get_valueset(query, resolver)?
+                .map(|values| ResolvedValueConstraint::Integer(values.root_values)));
         }
 
-        let additional_values = if self.additional_elements.is_some() {
-            let additional_elements = self.additional_elements.as_ref().unwrap();
-            let mut additional_values = ConstraintValues::new();
-            for element in &additional_elements.elements {
-                let element_values = element.get_integer_valueset(resolver)?;
-                additional_values.append(&element_values);
+        let mut union = vec![];
+        for iset in self.get_inner_elements() {
+            let mut intersection = vec![];
+            for element in &iset.elements {
+                intersection.extend(element.get_value_constraint(ty, resolver)?);
+            }
+            match intersection.len() {
+                0 => return Ok(None),
+                1 => union.push(intersection.pop().unwrap()),
+                _ => union.push(ResolvedValueConstraint::Intersection(intersection)),
             }
-            Some(additional_values)
+        }
+
+        if union.len() > 1 {
+            Ok(Some(ResolvedValueConstraint::Union(union)))
         } else {
-            None
-        };
+            Ok(union.pop())
+        }
+    }
 
-        Ok(Asn1ConstraintValueSet {
-            root_values,
-            additional_values,
-        })
+    // Characters permitted by the root of the element set of a PermittedAlphabet constraint.
+    fn get_permitted_alphabet(&self) -> Result<BTreeSet<char>, Error> {
+        let mut alphabet = BTreeSet::new();
+        for element in self.get_inner_elements() {
+            alphabet.extend(element.get_permitted_alphabet()?);
+        }
+        Ok(alphabet)
     }
 
     fn dependent_references(self) -> Vec<String> {
@@ -222,7 +367,7 @@ impl IntersectionSet {
         if self.elements.len() == 1 {
             let element = &self.elements[0];
             if let Elements::Subtype(SubtypeElements::SingleValue { value }) = element {
-                Ok(value.clone())
+                Ok(value.to_string())
             } else {
                 Err(constraint_error!(
                     "The Element is not a SingleValue Subtype Element!"
@@ -236,27 +381,35 @@ impl IntersectionSet {
         }
     }
 
-    fn get_integer_valueset(&self, resolver: &Resolver) -> Result<ConstraintValues, Error> {
-        let mut value_set = ConstraintValues::new();
+    // The values common to the elements that are PER-visible, ignoring the others. `None` if none
+    // of the elements is PER-visible.
+    fn get_valueset(
+        &self,
+        query: ValueSetQuery,
+        resolver: &Resolver,
+    ) -> Result<Option<Asn1ConstraintValueSet>, Error> {
+        let mut values: Option<Asn1ConstraintValueSet> = None;
         for element in &self.elements {
-            let element_set = element.get_integer_valueset(resolver)?;
-            if value_set.is_empty() {
-                // If Empty Set, it's okay to union, but not otherwise
-                value_set.append(&element_set);
-            } else {
-                value_set.values = value_set
-                    .values
-                    .into_iter()
-                    .filter(|e| element_set.values.contains(e))
-                    .collect::<Vec<i128>>();
-                value_set.ranges = value_set
-                    .ranges
-                    .into_iter()
-                    .filter(|e| element_set.ranges.contains(e))
-                    .collect::<Vec<Range<i128>>>();
+            if let Some(element_values) = element.get_valueset(query, resolver)? {
+                values = Some(match values {
+                    Some(current) => current.intersection(&element_values),
+                    None => element_values,
+                });
             }
         }
-        Ok(value_set)
+        Ok(values)
+    }
+
+    fn get_permitted_alphabet(&self) -> Result<BTreeSet<char>, Error> {
+        let mut alphabet: Option<BTreeSet<char>> = None;
+        for element in &self.elements {
+            let element_alphabet = element.get_permitted_alphabet()?;
+            alphabet = Some(match alphabet.take() {
+                Some(current) => current.intersection(&element_alphabet).cloned().collect(),
+                None => element_alphabet,
+            });
+        }
+        Ok(alphabet.unwrap_or_default())
     }
 
     fn dependent_references(&self) -> Vec<String> {
@@ -279,11 +432,85 @@ impl TableConstraint {
 }
 
 impl Elements {
-    fn get_integer_valueset(&self, resolver: &Resolver) -> Result<ConstraintValues, Error> {
+    // The values of the elements. For the PER-visible values, the `EXCEPT` is ignored
+    // (X.691 10.3). `None` if the elements are not PER-visible, or if the values of the
+    // exclusions can't be obtained.
+    fn get_valueset(
+        &self,
+        query: ValueSetQuery,
+        resolver: &Resolver,
+    ) -> Result<Option<Asn1ConstraintValueSet>, Error> {
         match self {
-            Self::Subtype(ref s) => s.get_integer_valueset(resolver),
-            Self::Set(ref _s) => Err(constraint_error!(
-                "get_integer_valueset: Set Variant: Not Supported!"
+            Self::Subtype(ref s) => s.get_valueset(query, resolver),
+            Self::Set(ref e) => e.get_valueset(query, resolver),
+            Self::Except {
+                ref elements,
+                ref exclusions,
+            } => {
+                let values = elements.get_valueset(query, resolver)?;
+                if !query.except {
+                    return Ok(values);
+                }
+                match (values, exclusions.get_valueset(query, resolver)?) {
+                    (Some(values), Some(excluded)) => Ok(Some(values.except(&excluded))),
+                    _ => Ok(None),
+                }
+            }
+            Self::AllExcept(ref exclusions) => {
+                let all = Asn1ConstraintValueSet::new(ConstraintValues::all());
+                if !query.except {
+                    return Ok(Some(all));
+                }
+                Ok(exclusions
+                    .get_valueset(query, resolver)?
+                    .map(|excluded| all.except(&excluded)))
+            }
+        }
+    }
+
+    fn has_inner_type_constraint(&self) -> bool {
+        match self {
+            Self::Subtype(SubtypeElements::InnerType(..)) => true,
+            Self::Subtype(..) | Self::AllExcept(..) => false,
+            Self::Set(ref e) => e.has_inner_type_constraint(),
+            Self::Except { ref elements, .. } => elements.has_inner_type_constraint(),
+        }
+    }
+
+    fn get_value_constraint(
+        &self,
+        ty: &Asn1ResolvedType,
+        resolver: &Resolver,
+    ) -> Result<Option<ResolvedValueConstraint>, Error> {
+        match self {
+            Self::Subtype(ref s) => s.get_value_constraint(ty, resolver),
+            Self::Set(ref e) => e.get_value_constraint(ty, resolver),
+            Self::Except { ref elements, .. } => {
+                eprintln!(
+                    "Warning!! Exclusions in the Constraint '{:?}' are not checked.",
+                    self
+                );
+                elements.get_value_constraint(ty, resolver)
+            }
+            Self::AllExcept(..) => {
+                eprintln!(
+                    "Warning!! Constraint '{:?}' on the values is not checked.",
+                    self
+                );
+                Ok(None)
+            }
+        }
+    }
+
+    // The exclusions of `EXCEPT` are not PER-visible and are ignored.
+    fn get_permitted_alphabet(&self) -> Result<BTreeSet<char>, Error> {
+        match self {
+            Self::Subtype(ref s) => s.get_permitted_alphabet(),
+            Self::Set(ref e) => e.get_permitted_alphabet(),
+            Self::Except { ref elements, .. } => elements.get_permitted_alphabet(),
+            Self::AllExcept(..) => Err(constraint_error!(
+                "Unsupported PermittedAlphabet Constraint '{:#?}'",
+                self
             )),
         }
     }
@@ -292,66 +519,255 @@ impl Elements {
         match self {
             Self::Subtype(ref s) => s.dependent_references(),
             Self::Set(ref e) => e.clone().dependent_references(),
+            Self::Except {
+                ref elements,
+                ref exclusions,
+            } => {
+                let mut output = elements.dependent_references();
+                output.extend(exclusions.dependent_references());
+                output
+            }
+            Self::AllExcept(ref exclusions) => exclusions.dependent_references(),
         }
     }
 }
 
 impl SubtypeElements {
-    fn get_integer_valueset(&self, resolver: &Resolver) -> Result<ConstraintValues, Error> {
-        let mut value_set = ConstraintValues::new();
+    // The values given by the element. `None` if the element does not constrain the values (or
+    // the `SIZE`) or is not PER-visible.
+    fn get_valueset(
+        &self,
+        query: ValueSetQuery,
+        resolver: &Resolver,
+    ) -> Result<Option<Asn1ConstraintValueSet>, Error> {
+        if query.size {
+            return match self {
+                Self::SizeConstraint(ref elems) => elems.get_valueset(
+                    ValueSetQuery {
+                        size: false,
+                        ..query
+                    },
+                    resolver,
+                ),
+                _ => Ok(None),
+            };
+        }
+
+        let value_set = match self {
+            Self::SingleValue { value } => Self::resolve_value_set(value, resolver)?,
+            Self::ConstrainedSubtype(ref ty) => {
+                // We only care about Root Elements
+                let values = match ty.get_integer_valueset_from_constraint(resolver)? {
+                    Some(values) => values.root_values,
+                    None => ConstraintValues::all(),
+                };
+                Asn1ConstraintValueSet::new(values)
+            }
+            Self::ValueRange {
+                lower,
+                lower_inclusive,
+                upper,
+                upper_inclusive,
+            } => {
+                let lower_value = match lower {
+                    None => i128::MIN,
+                    Some(ref lower) => {
+                        let lower_value = Self::parse_or_resolve_value(lower, resolver)?;
+                        if *lower_inclusive {
+                            lower_value
+                        } else {
+                            lower_value + 1
+                        }
+                    }
+                };
+                let upper_value = match upper {
+                    None => i128::MAX,
+                    Some(ref upper) => {
+                        let upper_value = Self::parse_or_resolve_value(upper, resolver)?;
+                        if *upper_inclusive {
+                            upper_value
+                        } else {
+                            upper_value - 1
+                        }
+                    }
+                };
+                Asn1ConstraintValueSet::new(ConstraintValues::from_range(lower_value, upper_value))
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(value_set))
+    }
+
+    // The constraint on the values of the type `ty`. Only the `SIZE` of the strings and
+    // `SEQUENCE OF`s and the Inner Type Constraints are checked. The values of an `INTEGER` are
+    // obtained for the whole of the element set.
+    fn get_value_constraint(
+        &self,
+        ty: &Asn1ResolvedType,
+        resolver: &Resolver,
+    ) -> Result<Option<ResolvedValueConstraint>, Error> {
+        let governing = governing_type(ty, resolver)?;
         match self {
-            Self::SingleValue { value } => {
-                value_set
-                    .values
-                    .push(Self::parse_or_resolve_value(value, resolver)?);
+            Self::InnerType(ref inner) => inner.get_value_constraint(governing, resolver),
+            Self::SizeConstraint(ref elems) => {
+                let chars = match governing {
+                    Asn1ResolvedType::Base(ResolvedBaseType::CharacterString(..)) => true,
+                    Asn1ResolvedType::Base(ResolvedBaseType::OctetString(..))
+                    | Asn1ResolvedType::Base(ResolvedBaseType::BitString(..))
+                    | Asn1ResolvedType::Constructed(ResolvedConstructedType::SequenceOf {
+                        ..
+                    }) => false,
+                    _ => {
+                        return Err(constraint_error!(
+                            "SIZE Constraint on a type that is not a string or a SEQUENCE OF!"
+                        ))
+                    }
+                };
+                if elems.additional_elements.is_some() {
+                    return Ok(None);
+                }
+                let query = ValueSetQuery {
+                    size: false,
+                    except: true,
+                };
+                Ok(elems.get_valueset(query, resolver)?.map(|values| {
+                    ResolvedValueConstraint::Size {
+                        values: values.root_values,
+                        chars,
+                    }
+                }))
             }
-            Self::ConstrainedSubtype(ref ty) => {
-                let all_values_set = ty.get_integer_valueset_from_constraint(resolver)?;
-                value_set.append(&all_values_set.root_values); // We only care about Root Elements
+            _ => {
+                eprintln!(
+                    "Warning!! Constraint '{:?}' on the values is not checked.",
+                    self
+                );
+                Ok(None)
             }
+        }
+    }
+
+    fn get_permitted_alphabet(&self) -> Result<BTreeSet<char>, Error> {
+        match self {
+            Self::SingleValue { value } => Ok(Self::parse_string_value(value)?.chars().collect()),
             Self::ValueRange {
                 lower,
                 lower_inclusive,
                 upper,
                 upper_inclusive,
             } => {
-                let mut lower_value = Self::parse_or_resolve_value(lower, resolver)?;
+                let (lower, upper) = match (lower, upper) {
+                    (Some(ref lower), Some(ref upper)) => (lower, upper),
+                    _ => {
+                        return Err(constraint_error!(
+                            "MIN or MAX not supported in a PermittedAlphabet Constraint!"
+                        ))
+                    }
+                };
+                let mut lower_value = Self::parse_char_value(lower)? as u32;
                 if !lower_inclusive {
                     lower_value += 1;
                 }
-                let mut upper_value = Self::parse_or_resolve_value(upper, resolver)?;
+                let mut upper_value = Self::parse_char_value(upper)? as u32;
                 if !upper_inclusive {
                     upper_value -= 1;
                 }
-                value_set.ranges.push(Range {
-                    start: lower_value,
-                    end: upper_value + 1,
-                });
+                Ok((lower_value..=upper_value)
+                    .filter_map(char::from_u32)
+                    .collect())
             }
-            Self::SizeConstraint(ref elems) => {
-                let size_elements = elems.get_integer_valueset(resolver)?;
-                value_set.append(&size_elements.root_values);
-                if size_elements.additional_values.is_some() {
-                    value_set.append(&size_elements.additional_values.unwrap());
+            _ => Err(constraint_error!(
+                "Unsupported PermittedAlphabet Constraint '{:#?}'",
+                self
+            )),
+        }
+    }
+
+    // The characters of a `cstring` value like `"a ""b"""`. References to the values are not
+    // supported.
+    fn parse_string_value(value: &Asn1Value) -> Result<String, Error> {
+        if let Asn1Value::CharString(ref s) = value {
+            Ok(s.clone())
+        } else {
+            Err(constraint_error!(
+                "Expected a character string value, Found '{}'.",
+                value
+            ))
+        }
+    }
+
+    // Parses a single character given as a `cstring` like `"a"`, or as a `Quadruple` or a `Tuple`
+    // like `{0, 0, 3, 112}` or `{3, 0}`.
+    fn parse_char_value(value: &Asn1Value) -> Result<char, Error> {
+        let c = if let Asn1Value::List(ref elements) = value {
+            let numbers = elements
+                .iter()
+                .map(|e| match e.value {
+                    Asn1Value::Integer(n)
+                        if e.identifier.is_none() && (0..=u32::MAX as i128).contains(&n) =>
+                    {
+                        Some(n as u32)
+                    }
+                    _ => None,
+                })
+                .collect::<Option<Vec<u32>>>()
+                .ok_or_else(|| constraint_error!("Invalid character value '{}'.", value))?;
+            match numbers[..] {
+                [group, plane, row, cell] => {
+                    char::from_u32(group << 24 | plane << 16 | row << 8 | cell)
                 }
+                [column, row] => char::from_u32(column << 4 | row),
+                _ => None,
             }
-            _ => {
-                return Err(constraint_error!(
-                    "Unexpected Constraint Type '{:#?}'",
-                    self
-                ));
+        } else {
+            let s = Self::parse_string_value(value)?;
+            let mut chars = s.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Some(c),
+                _ => None,
             }
+        };
+
+        c.ok_or_else(|| constraint_error!("Expected a single character, Found '{}'.", value))
+    }
+
+    // A value (or a reference to a value) or a reference to an `INTEGER` type, whose values are
+    // the values of the set.
+    fn resolve_value_set(
+        value: &Asn1Value,
+        resolver: &Resolver,
+    ) -> Result<Asn1ConstraintValueSet, Error> {
+        let referenced = match value {
+            Asn1Value::Reference(ref reference) => resolver.get_resolved_def(reference),
+            _ => None,
+        };
+        if let Some(Asn1ResolvedDefinition::Type(ref ty)) = referenced {
+            match governing_type(ty, resolver)? {
+                Asn1ResolvedType::Base(ResolvedBaseType::Integer(ref i)) => {
+                    let values = match i.resolved_constraints {
+                        Some(ref constraints) => constraints.root_values.clone(),
+                        None => ConstraintValues::all(),
+                    };
+                    Ok(Asn1ConstraintValueSet::new(values))
+                }
+                _ => Err(constraint_error!(
+                    "Referenced Type '{}' in the Constraint is not an INTEGER!",
+                    value
+                )),
+            }
+        } else {
+            let value = Self::parse_or_resolve_value(value, resolver)?;
+            Ok(Asn1ConstraintValueSet::new(ConstraintValues::from_value(
+                value,
+            )))
         }
-        Ok(value_set)
     }
 
-    fn parse_or_resolve_value(value: &str, resolver: &Resolver) -> Result<i128, Error> {
-        // FIXME : do the 'resolve part'
-        let parsed = value.parse::<i128>();
-        match parsed {
-            Ok(x) => Ok(x),
-            Err(_) => {
-                let resolved = resolver.resolved_defs.get(value);
+    fn parse_or_resolve_value(value: &Asn1Value, resolver: &Resolver) -> Result<i128, Error> {
+        match value {
+            Asn1Value::Integer(x) => Ok(*x),
+            Asn1Value::Reference(ref reference) => {
+                let resolved = resolver.get_resolved_def(reference);
                 match resolved {
                     None => Err(constraint_error!(
                         "Unable To Resolve '{}'. Not Found!",
@@ -372,38 +788,242 @@ impl SubtypeElements {
                     }
                 }
             }
+            _ => Err(constraint_error!(
+                "Expected an INTEGER value, Found '{}'.",
+                value
+            )),
         }
     }
 
     fn dependent_references(&self) -> Vec<String> {
         match self {
-            Self::SingleValue { value } => match value.parse::<i128>() {
-                Ok(_) => vec![],
-                Err(_) => vec![value
-                    .trim_matches(|c| matches!(c, '{' | '}'))
-                    .trim()
-                    .to_string()],
-            },
+            Self::SingleValue { value } => value.dependent_references(),
             Self::ConstrainedSubtype(ref t) => t.dependent_references(),
-            Self::ValueRange { lower, upper, .. } => {
-                let mut output = vec![];
-                match lower.parse::<i128>() {
-                    Ok(_) => {}
-                    Err(_) => output.extend(vec![lower.trim().to_string()]),
+            Self::ValueRange { lower, upper, .. } => lower
+                .iter()
+                .chain(upper.iter())
+                .flat_map(|v| v.dependent_references())
+                .collect(),
+            Self::SizeConstraint(ref s) => s.clone().dependent_references(),
+            Self::PermittedAlphabet(ref _p) => vec![], // FIXME: Should we?
+            Self::InnerType(ref i) => i.dependent_references(),
+        }
+    }
+}
+
+impl InnerTypeConstraint {
+    fn get_value_constraint(
+        &self,
+        ty: &Asn1ResolvedType,
+        resolver: &Resolver,
+    ) -> Result<Option<ResolvedValueConstraint>, Error> {
+        match self {
+            Self::SingleComponent(ref elems) => {
+                if let Asn1ResolvedType::Constructed(ResolvedConstructedType::SequenceOf {
+                    ty: ref element,
+                    ..
+                }) = ty
+                {
+                    Ok(elems.get_value_constraint(element, resolver)?.map(|c| {
+                        ResolvedValueConstraint::Inner(ResolvedInnerTypeConstraint::Component {
+                            reference: type_reference(element),
+                            constraint: Box::new(c),
+                        })
+                    }))
+                } else {
+                    Err(constraint_error!(
+                        "'WITH COMPONENT' Constraint on a type that is not a SEQUENCE OF!"
+                    ))
+                }
+            }
+            Self::MultipleComponents {
+                partial,
+                components,
+            } => {
+                // All the components along with whether they are optional. Every alternative of a
+                // `CHOICE` is optional.
+                let (alternatives, all): (bool, Vec<(&ResolvedComponent, bool)>) = match ty {
+                    Asn1ResolvedType::Constructed(ResolvedConstructedType::Sequence {
+                        components,
+                        additions,
+                        ..
+                    })
+                    | Asn1ResolvedType::Constructed(ResolvedConstructedType::Set {
+                        components,
+                        additions,
+                        ..
+                    }) => (
+                        false,
+                        components
+                            .iter()
+                            .map(|c| (&c.component, c.optional))
+                            .chain(
+                                additions
+                                    .iter()
+                                    .flat_map(|a| a.components.iter().map(|c| (&c.component, true))),
+                            )
+                            .collect(),
+                    ),
+                    Asn1ResolvedType::Constructed(ResolvedConstructedType::Choice {
+                        root_components,
+                        additions,
+                        ..
+                    }) => (
+                        true,
+                        root_components
+                            .iter()
+                            .chain(additions.iter().flatten())
+                            .map(|c| (c, true))
+                            .collect(),
+                    ),
+                    _ => {
+                        return Err(constraint_error!(
+                            "'WITH COMPONENTS' Constraint on a type that is not a SEQUENCE, SET or CHOICE!"
+                        ))
+                    }
                 };
-                match upper.parse::<i128>() {
-                    Ok(_) => {}
-                    Err(_) => output.extend(vec![upper.trim().to_string()]),
+
+                let mut constraints = vec![];
+                for named in components {
+                    let (component, optional) =
+                        all.iter().find(|(c, _)| c.id == named.id).ok_or_else(|| {
+                            constraint_error!(
+                                "Component '{}' in the 'WITH COMPONENTS' Constraint not found!",
+                                named.id
+                            )
+                        })?;
+                    if !optional
+                        && matches!(
+                            named.presence,
+                            Some(ComponentPresence::Present) | Some(ComponentPresence::Absent)
+                        )
+                    {
+                        return Err(constraint_error!(
+                            "Presence Constraint on the component '{}' that is not OPTIONAL!",
+                            named.id
+                        ));
+                    }
+                    let value = match named.value {
+                        Some(ref elems) => elems.get_value_constraint(&component.ty, resolver)?,
+                        None => None,
+                    };
+                    constraints.push(ResolvedComponentConstraint {
+                        id: named.id.clone(),
+                        optional: *optional,
+                        presence: named.presence,
+                        value,
+                        reference: type_reference(&component.ty),
+                    });
+                }
+
+                // For the Full Specification, the optional components not listed are `ABSENT`.
+                if !partial {
+                    for (component, optional) in &all {
+                        if *optional && !components.iter().any(|c| c.id == component.id) {
+                            constraints.push(ResolvedComponentConstraint {
+                                id: component.id.clone(),
+                                optional: true,
+                                presence: Some(ComponentPresence::Absent),
+                                value: None,
+                                reference: type_reference(&component.ty),
+                            });
+                        }
+                    }
+                }
+
+                constraints.retain(|c| {
+                    c.value.is_some()
+                        || c.presence.is_some_and(|p| p != ComponentPresence::Optional)
+                });
+
+                let inner = if alternatives {
+                    ResolvedInnerTypeConstraint::Alternatives(constraints)
+                } else {
+                    ResolvedInnerTypeConstraint::Components(constraints)
                 };
-                output
+                Ok(Some(ResolvedValueConstraint::Inner(inner)))
             }
-            Self::SizeConstraint(ref s) => s.clone().dependent_references(),
-            Self::PermittedAlphabet(ref _p) => vec![], // FIXME: Should we?
         }
     }
+
+    fn dependent_references(&self) -> Vec<String> {
+        match self {
+            Self::SingleComponent(ref e) => e.clone().dependent_references(),
+            Self::MultipleComponents { components, .. } => components
+                .iter()
+                .filter_map(|c| c.value.clone())
+                .flat_map(|e| e.dependent_references())
+                .collect(),
+        }
+    }
+}
+
+// The type, following the references to the other types.
+pub(crate) fn governing_type<'a>(
+    ty: &'a Asn1ResolvedType,
+    resolver: &'a Resolver,
+) -> Result<&'a Asn1ResolvedType, Error> {
+    if let Asn1ResolvedType::Reference(ref key) = ty {
+        match resolver.resolved_defs.get(key) {
+            Some(Asn1ResolvedDefinition::Type(ref t)) => governing_type(t, resolver),
+            _ => Err(constraint_error!(
+                "Referenced Type '{}' not resolved yet!",
+                key
+            )),
+        }
+    } else {
+        Ok(ty)
+    }
+}
+
+// Serially applies the `constraints` to a type with the values `parent`, using the PER-visible
+// values of the constraints given by `valueset`. `None` if there are no values for the parent type
+// and none of the constraints is PER-visible.
+pub(crate) fn serially_constrained<F>(
+    parent: Option<Asn1ConstraintValueSet>,
+    constraints: &[Asn1Constraint],
+    valueset: F,
+) -> Result<Option<Asn1ConstraintValueSet>, Error>
+where
+    F: Fn(&Asn1Constraint) -> Result<Option<Asn1ConstraintValueSet>, Error>,
+{
+    let mut values = parent;
+    for constraint in constraints {
+        if let Some(constraint_values) = valueset(constraint)? {
+            values = Some(match values {
+                Some(current) => current.constrained_by(&constraint_values),
+                None => constraint_values,
+            });
+        }
+    }
+    Ok(values)
+}
+
+fn type_reference(ty: &Asn1ResolvedType) -> Option<DefinitionKey> {
+    if let Asn1ResolvedType::Reference(ref key) = ty {
+        Some(key.clone())
+    } else {
+        None
+    }
 }
 
 impl UnionSet {
+    // The values of any of the intersections. `None` if any of them is not PER-visible.
+    fn get_valueset(
+        &self,
+        query: ValueSetQuery,
+        resolver: &Resolver,
+    ) -> Result<Option<Asn1ConstraintValueSet>, Error> {
+        let mut values = Asn1ConstraintValueSet::new(ConstraintValues::new());
+        for iset in &self.elements {
+            match iset.get_valueset(query, resolver)? {
+                Some(iset_values) => values = values.union(&iset_values),
+                None => return Ok(None),
+            }
+        }
+        Ok(Some(values))
+    }
+
     fn dependent_references(&self) -> Vec<String> {
         let mut output = vec![];
         for element in &self.elements {
@@ -3,6 +3,7 @@ use std::collections::BTreeMap;
 use crate::error::Error;
 
 use crate::parser::asn::structs::types::{
+    constraints::Asn1Constraint,
     constructed::{Asn1TypeChoice, Asn1TypeSequence, Asn1TypeSequenceOf, Component, SeqComponent},
     Asn1ConstructedType, Asn1Type, Asn1TypeKind, Asn1TypeReference,
 };
@@ -12,15 +13,21 @@ use crate::resolver::{
         structs::{
             defs::Asn1ResolvedDefinition,
             types::{
+                constraints::ResolvedValueConstraint,
                 constructed::{
                     ClassFieldComponentType, ResolvedComponent, ResolvedConstructedType,
-                    ResolvedSeqComponent,
+                    ResolvedSeqAdditionGroup, ResolvedSeqComponent,
                 },
                 ioc::{ResolvedFieldSpec, ResolvedObjectSet, ResolvedObjectSetElement},
+                tags::{ResolvedTag, ResolvedTagging, ResolvedTypeTags},
                 Asn1ResolvedType, ResolvedSetType, ResolvedSetTypeMap,
             },
         },
-        types::resolve_type,
+        types::{
+            resolve_type,
+            tags::{resolve_component_tags, resolve_generated_type_tags},
+        },
+        values::resolve_typed_value,
     },
     Resolver,
 };
@@ -30,14 +37,19 @@ pub(crate) fn resolve_constructed_type(
     resolver: &mut Resolver,
 ) -> Result<Asn1ResolvedType, Error> {
     if let Asn1TypeKind::Constructed(ref kind) = ty.kind {
-        match kind {
+        let resolved = match kind {
             Asn1ConstructedType::Choice(ref c) => resolve_choice_type(c, resolver),
             Asn1ConstructedType::Sequence(ref s) => resolve_sequence_type(s, resolver),
             Asn1ConstructedType::SequenceOf(ref so) => resolve_sequence_of_type(so, resolver),
+            Asn1ConstructedType::Set(ref s) => resolve_set_type(s, resolver),
             _ => {
                 eprintln!("ConstructedType: {:#?}", ty);
                 Err(resolve_error!("resolve_constructed_Type: Not Implemented!"))
             }
+        }?;
+        match ty.constraints {
+            Some(ref constraints) => apply_inner_type_constraints(resolved, constraints, resolver),
+            None => Ok(resolved),
         }
     } else {
         Err(resolve_error!(
@@ -47,18 +59,74 @@ pub(crate) fn resolve_constructed_type(
     }
 }
 
+// Adds the Inner Type Constraints among the `constraints` to the constraint of the resolved
+// constructed type.
+pub(crate) fn apply_inner_type_constraints(
+    resolved: Asn1ResolvedType,
+    constraints: &[Asn1Constraint],
+    resolver: &Resolver,
+) -> Result<Asn1ResolvedType, Error> {
+    let mut checked = vec![];
+    for constraint in constraints {
+        checked.extend(constraint.get_inner_type_constraint(&resolved, resolver)?);
+    }
+    if checked.is_empty() {
+        return Ok(resolved);
+    }
+
+    if let Asn1ResolvedType::Constructed(mut constructed) = resolved {
+        match constructed {
+            ResolvedConstructedType::Choice {
+                ref mut constraint, ..
+            }
+            | ResolvedConstructedType::Sequence {
+                ref mut constraint, ..
+            }
+            | ResolvedConstructedType::Set {
+                ref mut constraint, ..
+            }
+            | ResolvedConstructedType::SequenceOf {
+                ref mut constraint, ..
+            } => {
+                checked.extend(constraint.take());
+                *constraint = if checked.len() > 1 {
+                    Some(ResolvedValueConstraint::Intersection(checked))
+                } else {
+                    checked.pop()
+                };
+            }
+        }
+        Ok(Asn1ResolvedType::Constructed(constructed))
+    } else {
+        Err(resolve_error!(
+            "Inner Type Constraint on a type that is not a Constructed Type!"
+        ))
+    }
+}
+
 fn resolve_choice_type(
     choice: &Asn1TypeChoice,
     resolver: &mut Resolver,
 ) -> Result<Asn1ResolvedType, Error> {
+    let mut alternatives = choice
+        .root_components
+        .iter()
+        .map(|c| &c.ty)
+        .collect::<Vec<&Asn1Type>>();
+    if let Some(ref additions) = choice.additions {
+        alternatives.extend(
+            additions
+                .iter()
+                .flat_map(|a| a.components.iter().map(|c| &c.ty)),
+        );
+    }
+    let mut tags = resolve_component_tags(&alternatives, resolver).into_iter();
+
     let mut root_components = vec![];
     for c in &choice.root_components {
         let ty = resolve_type(&c.ty, resolver)?;
-        let component = ResolvedComponent {
-            id: c.id.clone(),
-            ty,
-        };
-        root_components.push(component);
+        let component_tags = tags.next().unwrap()?;
+        root_components.push(resolved_component(&c.id, ty, component_tags, resolver));
     }
 
     let additions = if choice.additions.is_some() {
@@ -66,11 +134,8 @@ fn resolve_choice_type(
         for addition in choice.additions.as_ref().unwrap() {
             for c in &addition.components {
                 let ty = resolve_type(&c.ty, resolver)?;
-                let component = ResolvedComponent {
-                    id: c.id.clone(),
-                    ty,
-                };
-                components.push(component);
+                let component_tags = tags.next().unwrap()?;
+                components.push(resolved_component(&c.id, ty, component_tags, resolver));
             }
         }
         Some(components)
@@ -78,11 +143,25 @@ fn resolve_choice_type(
         None
     };
 
+    check_distinct_tags(
+        "CHOICE",
+        root_components.iter().chain(additions.iter().flatten()),
+    )?;
+
+    // The alternatives are indexed in the canonical order of their tags (X.691 23.5). The extension
+    // additions are sorted separately.
+    root_components.sort_by_key(|c| c.tags.canonical_tag());
+    let additions = additions.map(|mut components| {
+        components.sort_by_key(|c| c.tags.canonical_tag());
+        components
+    });
+
     Ok(Asn1ResolvedType::Constructed(
         ResolvedConstructedType::Choice {
             name: None,
             root_components,
             additions,
+            constraint: None,
         },
     ))
 }
@@ -91,56 +170,229 @@ fn resolve_sequence_type(
     sequence: &Asn1TypeSequence,
     resolver: &mut Resolver,
 ) -> Result<Asn1ResolvedType, Error> {
+    // The tags of a component that cannot be determined are left empty. A `SEQUENCE` can still be
+    // encoded with PER.
+    let component_types = sequence
+        .root_components
+        .iter()
+        .chain(sequence.additions.iter().flat_map(|a| a.components.iter()))
+        .map(|c| &c.component.ty)
+        .collect::<Vec<&Asn1Type>>();
+    let mut tags = resolve_component_tags(&component_types, resolver)
+        .into_iter()
+        .map(|tags| tags.unwrap_or_default());
+
     let mut components = vec![];
-    // FIXME: implement for additional_components too
     for c in &sequence.root_components {
-        let ty = match resolve_type(&c.component.ty, resolver) {
-            Ok(ty) => ty,
+        match resolve_seq_component(c, tags.next().unwrap(), resolver) {
+            Ok(seq_component) => components.push(seq_component),
             Err(_e) => {
                 return resolve_sequence_classfield_components(sequence, resolver);
             }
-        };
-        let component = ResolvedComponent {
-            id: c.component.id.clone(),
-            ty,
-        };
-        let seq_component = ResolvedSeqComponent {
-            component,
-            optional: c.optional || c.default.is_some(),
-            class_field_type: None,
-            key_field: false,
-        };
-        components.push(seq_component);
+        }
+    }
+
+    let mut additions = vec![];
+    for addition in &sequence.additions {
+        let mut addition_components = vec![];
+        for c in &addition.components {
+            match resolve_seq_component(c, tags.next().unwrap(), resolver) {
+                Ok(seq_component) => addition_components.push(seq_component),
+                Err(_e) => {
+                    return resolve_sequence_classfield_components(sequence, resolver);
+                }
+            }
+        }
+        additions.push(ResolvedSeqAdditionGroup {
+            components: addition_components,
+            is_group: addition.is_group,
+        });
     }
 
+    check_sequence_tags(&components)?;
+
     Ok(Asn1ResolvedType::Constructed(
         ResolvedConstructedType::Sequence {
             components,
+            additions,
             extensible: sequence.extensible,
             name: None,
+            constraint: None,
         },
     ))
 }
 
+// A `SET` is resolved like a `SEQUENCE` and then the root components are sorted in the canonical
+// order of their tags (X.691 9.2). The extension additions remain in the textual order.
+fn resolve_set_type(
+    set: &Asn1TypeSequence,
+    resolver: &mut Resolver,
+) -> Result<Asn1ResolvedType, Error> {
+    if let Asn1ResolvedType::Constructed(ResolvedConstructedType::Sequence {
+        name,
+        extensible,
+        mut components,
+        additions,
+        constraint,
+    }) = resolve_sequence_type(set, resolver)?
+    {
+        check_distinct_tags(
+            "SET",
+            components
+                .iter()
+                .chain(additions.iter().flat_map(|a| a.components.iter()))
+                .map(|c| &c.component),
+        )?;
+
+        components.sort_by_key(|c| c.component.tags.canonical_tag());
+        Ok(Asn1ResolvedType::Constructed(
+            ResolvedConstructedType::Set {
+                name,
+                extensible,
+                components,
+                additions,
+                constraint,
+            },
+        ))
+    } else {
+        Err(resolve_error!("Expected a Resolved SET Type!"))
+    }
+}
+
+// The outermost tags of all the components of a `SET` or of all the alternatives of a `CHOICE`
+// should be known and distinct (X.680 27.3 and 29.3).
+fn check_distinct_tags<'a>(
+    kind: &str,
+    components: impl Iterator<Item = &'a ResolvedComponent>,
+) -> Result<(), Error> {
+    let mut seen: Vec<(&str, ResolvedTag)> = vec![];
+    for c in components {
+        let tags = c.tags.outermost_tags();
+        if tags.is_empty() {
+            return Err(resolve_error!(
+                "Tag of the {} component '{}' cannot be determined!",
+                kind,
+                c.id
+            ));
+        }
+        for tag in tags {
+            if let Some((other, _)) = seen.iter().find(|(_, t)| *t == tag) {
+                return Err(resolve_error!(
+                    "{} components '{}' and '{}' have the same tag {}!",
+                    kind,
+                    other,
+                    c.id,
+                    tag
+                ));
+            }
+            seen.push((&c.id, tag));
+        }
+    }
+    Ok(())
+}
+
+// The tags of the components in a series of consecutive `OPTIONAL` (or `DEFAULT`) components and
+// of the component that follows them, should be distinct (X.680 25.5). Components whose tags are
+// not known are not checked.
+fn check_sequence_tags(components: &[ResolvedSeqComponent]) -> Result<(), Error> {
+    let mut series: Vec<(&str, ResolvedTag)> = vec![];
+    for c in components {
+        let tags = c.component.tags.outermost_tags();
+        for tag in &tags {
+            if let Some((other, _)) = series.iter().find(|(_, t)| t == tag) {
+                return Err(resolve_error!(
+                    "SEQUENCE components '{}' and '{}' have the same tag {}!",
+                    other,
+                    c.component.id,
+                    tag
+                ));
+            }
+        }
+        if c.optional {
+            series.extend(tags.into_iter().map(|tag| (c.component.id.as_str(), tag)));
+        } else {
+            series.clear();
+        }
+    }
+    Ok(())
+}
+
+// The taggings to be applied on the type generated for a component, to get the effective `tags` of
+// the component. The outermost tag is always present (unless the component is untagged), even when
+// it is the same as that of the generated type.
+fn resolved_component(
+    id: &str,
+    ty: Asn1ResolvedType,
+    tags: ResolvedTypeTags,
+    resolver: &Resolver,
+) -> ResolvedComponent {
+    let mut tagging = tags.tagging_over(&resolve_generated_type_tags(&ty, resolver));
+    if tagging.is_empty() {
+        if let Some(tag) = tags.tags.first() {
+            tagging.push(ResolvedTagging {
+                tag: *tag,
+                explicit: false,
+            });
+        }
+    }
+    ResolvedComponent {
+        id: id.to_string(),
+        ty,
+        tags,
+        tagging,
+    }
+}
+
+fn resolve_seq_component(
+    c: &SeqComponent,
+    tags: ResolvedTypeTags,
+    resolver: &mut Resolver,
+) -> Result<ResolvedSeqComponent, Error> {
+    let ty = resolve_type(&c.component.ty, resolver)?;
+
+    // A `DEFAULT` value that cannot be resolved (yet), makes the component just an `OPTIONAL`
+    // component.
+    let default = match c.default {
+        Some(ref value) => match resolve_typed_value(value, &ty, resolver) {
+            Ok(resolved) => Some(resolved),
+            Err(e) => {
+                eprintln!(
+                    "Warning!! DEFAULT value '{}' of the component '{}' not resolved: {}",
+                    value, c.component.id, e
+                );
+                None
+            }
+        },
+        None => None,
+    };
+
+    Ok(ResolvedSeqComponent {
+        component: resolved_component(&c.component.id, ty, tags, resolver),
+        optional: c.optional || c.default.is_some(),
+        class_field_type: None,
+        key_field: false,
+        default,
+    })
+}
+
 fn resolve_sequence_of_type(
     sequence_of: &Asn1TypeSequenceOf,
     resolver: &mut Resolver,
 ) -> Result<Asn1ResolvedType, Error> {
     let resolved = resolve_type(&sequence_of.ty, resolver)?;
-    let size_values = if sequence_of.size.is_some() {
-        let size = sequence_of.size.as_ref().unwrap();
-        Some(size.get_size_valueset(resolver)?)
-    } else {
-        None
+    // A `WITH COMPONENT` constraint is given in place of (or along with) the `SIZE` constraint.
+    let size_values = match sequence_of.size {
+        Some(ref size) => size.get_size_valueset(resolver)?,
+        None => None,
     };
 
-    Ok(Asn1ResolvedType::Constructed(
-        ResolvedConstructedType::SequenceOf {
-            ty: Box::new(resolved),
-            name: None,
-            size_values,
-        },
-    ))
+    let resolved = Asn1ResolvedType::Constructed(ResolvedConstructedType::SequenceOf {
+        ty: Box::new(resolved),
+        name: None,
+        size_values,
+        constraint: None,
+    });
+    apply_inner_type_constraints(resolved, sequence_of.size.as_slice(), resolver)
 }
 
 fn resolve_sequence_classfield_components(
@@ -160,6 +412,16 @@ fn resolve_sequence_classfield_components(
         .iter()
         .map(|c| c.component.clone())
         .collect::<Vec<Component>>();
+    let all_tags = resolve_component_tags(
+        &all_components
+            .iter()
+            .map(|c| &c.ty)
+            .collect::<Vec<&Asn1Type>>(),
+        resolver,
+    )
+    .into_iter()
+    .map(|tags| tags.unwrap_or_default())
+    .collect::<Vec<ResolvedTypeTags>>();
 
     if all_components.is_empty() {
         // It's an Error to try to resolve Empty components with Class Field Ref
@@ -173,7 +435,7 @@ fn resolve_sequence_classfield_components(
 
     let set_reference = constraint.get_set_reference()?;
 
-    let objects = resolver.resolved_defs.get(&set_reference);
+    let objects = resolver.get_resolved_def(&set_reference);
     if objects.is_none() {
         return Err(resolve_error!(
             "Object Set '{}' not resolved yet!",
@@ -182,13 +444,20 @@ fn resolve_sequence_classfield_components(
     }
     if let Some(Asn1ResolvedDefinition::ObjectSet(ref set)) = objects {
         let objects = &set.objects;
-        let components =
-            resolve_seq_components_for_objects(&all_components, &set_reference, objects)?;
+        let components = resolve_seq_components_for_objects(
+            &all_components,
+            all_tags,
+            &set_reference,
+            objects,
+            resolver,
+        )?;
         Ok(Asn1ResolvedType::Constructed(
             ResolvedConstructedType::Sequence {
                 name: None,
                 extensible: seq.extensible,
                 components,
+                additions: vec![],
+                constraint: None,
             },
         ))
     } else {
@@ -201,15 +470,17 @@ fn resolve_sequence_classfield_components(
 
 fn resolve_seq_components_for_objects(
     input_components: &[Component],
+    input_tags: Vec<ResolvedTypeTags>,
     set_reference: &str,
     objects: &ResolvedObjectSet,
+    resolver: &Resolver,
 ) -> Result<Vec<ResolvedSeqComponent>, Error> {
     if objects.elements.is_empty() {
         return Ok(vec![]);
     }
     let first = &objects.elements[0];
     let mut result = vec![];
-    for component in input_components {
+    for (component, tags) in input_components.iter().zip(input_tags) {
         if let Asn1TypeKind::Reference(Asn1TypeReference::ClassField { fieldref, .. }) =
             &component.ty.kind
         {
@@ -218,15 +489,14 @@ fn resolve_seq_components_for_objects(
                 if let Some(ResolvedFieldSpec::FixedTypeValue { typeref, .. }) = spec {
                     let constraint = &component.ty.constraints.as_ref().unwrap()[0];
                     let comp_spec = constraint.get_comp_reference();
-                    let component = ResolvedComponent {
-                        id: component.id.clone(),
-                        ty: typeref.clone(),
-                    };
+                    let component =
+                        resolved_component(&component.id, typeref.clone(), tags, resolver);
                     let seq_component = ResolvedSeqComponent {
                         component,
                         optional: false, // FIXME:
                         class_field_type: Some(ClassFieldComponentType::FixedTypeValue),
                         key_field: comp_spec.is_none(),
+                        default: None,
                     };
                     result.push(seq_component);
                 } else {
@@ -235,15 +505,18 @@ fn resolve_seq_components_for_objects(
                         setref: set_reference.to_string(),
                         types,
                     };
-                    let component = ResolvedComponent {
-                        id: component.id.clone(),
-                        ty: Asn1ResolvedType::Set(ty),
-                    };
+                    let component = resolved_component(
+                        &component.id,
+                        Asn1ResolvedType::Set(ty),
+                        tags,
+                        resolver,
+                    );
                     let seq_component = ResolvedSeqComponent {
                         component,
                         optional: false, // FIXME:
                         class_field_type: Some(ClassFieldComponentType::Type),
                         key_field: false,
+                        default: None,
                     };
                     result.push(seq_component);
                 }
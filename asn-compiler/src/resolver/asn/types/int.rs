@@ -8,38 +8,61 @@ use crate::resolver::{
     asn::{
         structs::{
             defs::Asn1ResolvedDefinition,
-            types::{constraints::Asn1ConstraintValueSet, Asn1ResolvedType},
+            types::{
+                base::{
+                    Asn1ResolvedBitString, Asn1ResolvedInteger, Asn1ResolvedOctetString,
+                    ResolvedBaseType,
+                },
+                constraints::Asn1ConstraintValueSet,
+                constructed::ResolvedConstructedType,
+                Asn1ResolvedType,
+            },
+        },
+        types::{
+            base::resolve_base_type,
+            constraints::{governing_type, serially_constrained},
+            constructed::{apply_inner_type_constraints, resolve_constructed_type},
         },
-        types::{base::resolve_base_type, constructed::resolve_constructed_type},
     },
     Resolver,
 };
 
 impl Asn1Type {
-    // Returns the Integer ValueSet for a given Type.
+    // Returns the PER-visible Integer ValueSet for a given Type, `None` if it is not constrained.
     //
     // If the type is a `Base` type, it should be INTEGER or it's an Error. If the `Type` is a
-    // Referenced Type, it should be possible to 'resolve' the Reference to a proper
-    // `Asn1ConstraintValueSet` else it's an error, we'll try to 'recursively' 'resolve' till we
-    // get to a `Base` Type.
+    // Referenced Type, it should refer to an INTEGER type that is already resolved. The
+    // constraints of the type are serially applied to the values of the referenced type.
     pub(crate) fn get_integer_valueset_from_constraint(
         &self,
         resolver: &Resolver,
-    ) -> Result<Asn1ConstraintValueSet, Error> {
-        let kind = &self.kind;
-        match kind {
-            Asn1TypeKind::Builtin(Asn1BuiltinType::Integer(..)) => {
-                let constraint = &self.constraints.as_ref().unwrap()[0];
-                constraint.get_integer_valueset(resolver)
+    ) -> Result<Option<Asn1ConstraintValueSet>, Error> {
+        let parent = match self.kind {
+            Asn1TypeKind::Builtin(Asn1BuiltinType::Integer(..)) => None,
+            Asn1TypeKind::Reference(Asn1TypeReference::Reference(ref r)) => {
+                let referenced = Asn1ResolvedType::Reference(resolver.definition_key(r));
+                match governing_type(&referenced, resolver)? {
+                    Asn1ResolvedType::Base(ResolvedBaseType::Integer(ref i)) => {
+                        i.resolved_constraints.clone()
+                    }
+                    _ => {
+                        return Err(constraint_error!(
+                            "The Referenced Type '{}' is not an INTEGER!",
+                            r
+                        ))
+                    }
+                }
             }
-            Asn1TypeKind::Reference(Asn1TypeReference::Reference(ref _r)) => {
-                Err(constraint_error!("Not Implemented!"))
+            _ => {
+                return Err(constraint_error!(
+                    "The Type '{:#?}' is not of a BuiltIn Or a Referenced Kind!",
+                    self,
+                ))
             }
-            _ => Err(constraint_error!(
-                "The Type '{:#?}' is not of a BuiltIn Or a Referenced Kind!",
-                self,
-            )),
-        }
+        };
+
+        let constraints = self.constraints.as_deref().unwrap_or_default();
+        serially_constrained(parent, constraints, |c| c.get_integer_valueset(resolver))
     }
 }
 
@@ -62,12 +85,15 @@ fn resolve_reference_type(
     if let Asn1TypeKind::Reference(ref reference) = ty.kind {
         match reference {
             Asn1TypeReference::Reference(ref r) => {
-                let resolved = resolver.resolved_defs.get(r);
+                let key = resolver.definition_key(r);
+                let resolved = resolver.resolved_defs.get(&key);
                 match resolved {
                     Some(res) => match res {
-                        Asn1ResolvedDefinition::Type(..) => {
-                            Ok(Asn1ResolvedType::Reference(r.to_string()))
-                        }
+                        Asn1ResolvedDefinition::Type(..) => constrain_referenced_type(
+                            Asn1ResolvedType::Reference(key),
+                            ty,
+                            resolver,
+                        ),
                         _ => Err(resolve_error!(
                             "Expected a Resolved Type, found {:#?}",
                             resolved
@@ -80,11 +106,17 @@ fn resolve_reference_type(
                 }
             }
             Asn1TypeReference::Parameterized { typeref, params } => {
-                let def = resolver.parameterized_defs.get(typeref);
+                let key = resolver.definition_key(typeref);
+                let def = resolver.parameterized_defs.get(&key);
                 match def {
                     Some(d) => {
+                        // The names in the definition of the Parameterized Type are those of the
+                        // module defining it.
                         let params_resolved_type = d.clone().apply_params(params)?;
-                        resolve_type(&params_resolved_type, resolver)
+                        resolver.push_scope(&key.module);
+                        let resolved = resolve_type(&params_resolved_type, resolver);
+                        resolver.pop_scope();
+                        resolved
                     }
                     None => Err(resolve_error!(
                         "Parameterized Type for '{:#?}' Not found!",
@@ -100,3 +132,87 @@ fn resolve_reference_type(
         Err(resolve_error!("Expected Reference Type. Found '{:#?}'", ty))
     }
 }
+
+// A reference to a type with an Inner Type Constraint is resolved to a copy of the referenced
+// (constructed) type with the constraint. A separate type checking the constraint is generated.
+//
+// Similarly, a reference to an `INTEGER`, a string or a `SEQUENCE OF` type with PER-visible
+// constraints is resolved to a copy of the referenced type, with the constraints serially applied
+// to it's values, so that the generated type has the right bounds (and permitted alphabet).
+fn constrain_referenced_type(
+    resolved: Asn1ResolvedType,
+    ty: &Asn1Type,
+    resolver: &Resolver,
+) -> Result<Asn1ResolvedType, Error> {
+    let constraints = match ty.constraints {
+        Some(ref constraints) if !constraints.is_empty() => constraints,
+        _ => return Ok(resolved),
+    };
+
+    let referenced = governing_type(&resolved, resolver)?.clone();
+    if constraints.iter().any(|c| c.has_inner_type_constraint()) {
+        return apply_inner_type_constraints(referenced, constraints, resolver);
+    }
+
+    // The values of the referenced type with the values `parent`, when constrained.
+    let constrain =
+        |parent: &Option<Asn1ConstraintValueSet>, values: Asn1ConstraintValueSet| match parent {
+            Some(ref parent) => parent.constrained_by(&values),
+            None => values,
+        };
+    let size = |parent: &Option<Asn1ConstraintValueSet>| {
+        serially_constrained(None, constraints, |c| c.get_size_valueset(resolver))
+            .map(|size| size.map(|size| constrain(parent, size)))
+    };
+
+    let constrained = match referenced {
+        Asn1ResolvedType::Base(ResolvedBaseType::Integer(ref i)) => {
+            serially_constrained(None, constraints, |c| c.get_integer_valueset(resolver))?.map(
+                |values| {
+                    let values = constrain(&i.resolved_constraints, values);
+                    Asn1ResolvedType::Base(ResolvedBaseType::Integer(
+                        Asn1ResolvedInteger::from_valueset(values),
+                    ))
+                },
+            )
+        }
+        Asn1ResolvedType::Base(ResolvedBaseType::OctetString(ref o)) => {
+            size(&o.size)?.map(|size| {
+                Asn1ResolvedType::Base(ResolvedBaseType::OctetString(Asn1ResolvedOctetString {
+                    size: Some(size),
+                    ..o.clone()
+                }))
+            })
+        }
+        Asn1ResolvedType::Base(ResolvedBaseType::BitString(ref b)) => size(&b.size)?.map(|size| {
+            Asn1ResolvedType::Base(ResolvedBaseType::BitString(Asn1ResolvedBitString {
+                size: Some(size),
+                ..b.clone()
+            }))
+        }),
+        Asn1ResolvedType::Base(ResolvedBaseType::CharacterString(ref c)) => {
+            let mut c = c.clone();
+            if c.constrain(constraints, resolver)? {
+                Some(Asn1ResolvedType::Base(ResolvedBaseType::CharacterString(c)))
+            } else {
+                None
+            }
+        }
+        Asn1ResolvedType::Constructed(ResolvedConstructedType::SequenceOf {
+            ref ty,
+            ref size_values,
+            ref constraint,
+            ..
+        }) => size(size_values)?.map(|size| {
+            Asn1ResolvedType::Constructed(ResolvedConstructedType::SequenceOf {
+                name: None,
+                ty: ty.clone(),
+                size_values: Some(size),
+                constraint: constraint.clone(),
+            })
+        }),
+        _ => None,
+    };
+
+    Ok(constrained.unwrap_or(resolved))
+}
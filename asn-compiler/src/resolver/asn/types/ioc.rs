@@ -28,7 +28,9 @@ pub(crate) fn resolve_object_set(
     name: &str,
     resolver: &mut Resolver,
 ) -> Result<Asn1ResolvedObjectSet, Error> {
-    let class = resolver.classes.get(&objectset.class);
+    let class = resolver
+        .classes
+        .get(&resolver.definition_key(&objectset.class));
     match class {
         None => Err(resolve_error!(
             "Class '{}' definition not found to resolve object set!",
@@ -54,7 +56,7 @@ pub(crate) fn resolve_object_set(
             for (i, object) in input_elements.iter().enumerate() {
                 match object {
                     ObjectSetElement::ObjectSetReference(ref r) => {
-                        let resolved = resolver.resolved_defs.get(r);
+                        let resolved = resolver.get_resolved_def(r);
                         match resolved {
                             None => {
                                 return Err(resolve_error!(
@@ -78,7 +80,7 @@ pub(crate) fn resolve_object_set(
                         }
                     }
                     ObjectSetElement::ObjectReference(ref r) => {
-                        let resolved = resolver.resolved_defs.get(r);
+                        let resolved = resolver.get_resolved_def(r);
                         match resolved {
                             None => {
                                 return Err(resolve_error!(
@@ -118,7 +120,7 @@ pub(crate) fn resolve_object_set(
                                                             v.get_base_integer_value().unwrap()
                                                         );
                                                         lookup_table
-                                                            .insert((v, s.clone()), element);
+                                                            .insert((v, s.name.clone()), element);
                                                     }
                                                 }
                                             }
@@ -158,7 +160,7 @@ pub(crate) fn resolve_object_set(
                                             // decoder.
                                             let v =
                                                 format!("{}", v.get_base_integer_value().unwrap());
-                                            lookup_table.insert((v, s.clone()), element);
+                                            lookup_table.insert((v, s.name.clone()), element);
                                         }
                                     }
                                 }
@@ -2,6 +2,7 @@ pub(crate) mod base;
 pub(crate) mod constraints;
 pub(crate) mod constructed;
 pub(crate) mod ioc;
+pub(crate) mod tags;
 
 mod int;
 pub(crate) use int::resolve_type;
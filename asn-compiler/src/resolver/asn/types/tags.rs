@@ -0,0 +1,296 @@
+//! Resolution of the effective Tags of the Types
+
+use std::collections::BTreeSet;
+
+use crate::error::Error;
+
+use crate::parser::asn::structs::{
+    defs::Asn1AssignmentKind,
+    module::Asn1ModuleTag,
+    types::{
+        Asn1BuiltinType, Asn1ConstructedType, Asn1Tag, Asn1TagClass, Asn1TagMode, Asn1Type,
+        Asn1TypeKind, Asn1TypeReference,
+    },
+};
+
+use crate::resolver::{
+    asn::structs::{
+        defs::Asn1ResolvedDefinition,
+        types::{
+            base::ResolvedBaseType,
+            constructed::ResolvedConstructedType,
+            tags::{ResolvedTag, ResolvedTagClass, ResolvedTypeTags},
+            Asn1ResolvedType,
+        },
+    },
+    Resolver,
+};
+
+// Resolves the effective tags of a type.
+//
+// The tags of the referenced types should already be resolved. The tags of an Open Type (a Class
+// Field Reference) are not known, so those are empty.
+pub(crate) fn resolve_type_tags(
+    ty: &Asn1Type,
+    resolver: &mut Resolver,
+) -> Result<ResolvedTypeTags, Error> {
+    let inner = match ty.kind {
+        Asn1TypeKind::Builtin(ref b) => ResolvedTypeTags {
+            tags: vec![ResolvedTag::universal(builtin_tag_number(b))],
+            ..Default::default()
+        },
+        Asn1TypeKind::Constructed(ref c) => match c {
+            Asn1ConstructedType::Choice(ref choice) => {
+                let mut alternatives = choice
+                    .root_components
+                    .iter()
+                    .map(|c| &c.ty)
+                    .collect::<Vec<&Asn1Type>>();
+                if let Some(ref additions) = choice.additions {
+                    alternatives.extend(
+                        additions
+                            .iter()
+                            .flat_map(|a| a.components.iter().map(|c| &c.ty)),
+                    );
+                }
+
+                let mut choice_tags = BTreeSet::new();
+                for tags in resolve_component_tags(&alternatives, resolver) {
+                    choice_tags.extend(tags?.outermost_tags());
+                }
+                ResolvedTypeTags {
+                    tags: vec![],
+                    choice_tags,
+                }
+            }
+            Asn1ConstructedType::Sequence(..) | Asn1ConstructedType::SequenceOf(..) => {
+                ResolvedTypeTags {
+                    tags: vec![ResolvedTag::universal(16)],
+                    ..Default::default()
+                }
+            }
+            Asn1ConstructedType::Set(..) | Asn1ConstructedType::SetOf => ResolvedTypeTags {
+                tags: vec![ResolvedTag::universal(17)],
+                ..Default::default()
+            },
+        },
+        Asn1TypeKind::Reference(ref r) => match r {
+            Asn1TypeReference::Reference(ref r) => match resolver
+                .type_tags
+                .get(&resolver.definition_key(r))
+            {
+                Some(tags) => tags.clone(),
+                None => return Err(resolve_error!("Tags of the Type '{}' not resolved yet!", r)),
+            },
+            Asn1TypeReference::Parameterized { ref typeref, .. } => {
+                let key = resolver.definition_key(typeref);
+                let ty = match resolver.parameterized_defs.get(&key).map(|d| &d.kind) {
+                    Some(Asn1AssignmentKind::Type(ref t)) => Some(t.typeref.clone()),
+                    Some(Asn1AssignmentKind::ValueSet(ref s)) => Some(s.typeref.clone()),
+                    _ => None,
+                };
+                match ty {
+                    Some(ty) => {
+                        resolver.push_scope(&key.module);
+                        let tags = resolve_type_tags(&ty, resolver);
+                        resolver.pop_scope();
+                        tags?
+                    }
+                    None => {
+                        return Err(resolve_error!(
+                            "Parameterized Type '{}' not found!",
+                            typeref
+                        ))
+                    }
+                }
+            }
+            Asn1TypeReference::ClassField { .. } => ResolvedTypeTags::default(),
+        },
+    };
+
+    match ty.tag {
+        Some(ref tag) => {
+            let mode = match (tag.mode, resolver.module_tags) {
+                (Some(mode), _) => mode,
+                (None, Asn1ModuleTag::Explicit) => Asn1TagMode::Explicit,
+                (None, _) => Asn1TagMode::Implicit,
+            };
+            Ok(apply_tag(resolved_tag(tag), mode, inner))
+        }
+        None => Ok(inner),
+    }
+}
+
+// Resolves the effective tags of the components of a `SEQUENCE`, `SET` or a `CHOICE`.
+//
+// The components are the root components followed by the extension additions. In an `AUTOMATIC
+// TAGS` module, if none of the components is tagged, the components are tagged automatically
+// (X.680 25.3).
+//
+// The tags are resolved for each component separately, so that a component whose tags cannot be
+// determined, does not affect the others.
+pub(crate) fn resolve_component_tags(
+    components: &[&Asn1Type],
+    resolver: &mut Resolver,
+) -> Vec<Result<ResolvedTypeTags, Error>> {
+    let automatic = resolver.module_tags == Asn1ModuleTag::Automatic
+        && components.iter().all(|ty| ty.tag.is_none());
+
+    components
+        .iter()
+        .enumerate()
+        .map(|(idx, ty)| {
+            if automatic {
+                // The outermost tag of an automatically tagged component is known, even when the
+                // tags of it's type are not (eg. a Dummy Reference in a Parameterized Type).
+                let tags = resolve_type_tags(ty, resolver).unwrap_or_default();
+                let tag = ResolvedTag {
+                    class: ResolvedTagClass::ContextSpecific,
+                    number: idx as u32,
+                };
+                Ok(apply_tag(tag, Asn1TagMode::Implicit, tags))
+            } else {
+                resolve_type_tags(ty, resolver)
+            }
+        })
+        .collect()
+}
+
+// An `IMPLICIT` tag replaces the outermost tag of the type. An untagged type (an untagged
+// `CHOICE` or an Open Type) has no tag to replace, so that is always tagged explicitly.
+fn apply_tag(tag: ResolvedTag, mode: Asn1TagMode, inner: ResolvedTypeTags) -> ResolvedTypeTags {
+    let skip = match mode {
+        Asn1TagMode::Implicit => 1,
+        Asn1TagMode::Explicit => 0,
+    };
+    let mut tags = vec![tag];
+    tags.extend(inner.tags.into_iter().skip(skip));
+    ResolvedTypeTags {
+        tags,
+        ..Default::default()
+    }
+}
+
+fn resolved_tag(tag: &Asn1Tag) -> ResolvedTag {
+    let class = match tag.class {
+        Asn1TagClass::Universal => ResolvedTagClass::Universal,
+        Asn1TagClass::Application => ResolvedTagClass::Application,
+        Asn1TagClass::ContextSpecific => ResolvedTagClass::ContextSpecific,
+        Asn1TagClass::Private => ResolvedTagClass::Private,
+    };
+    ResolvedTag {
+        class,
+        number: tag.number,
+    }
+}
+
+// Resolves the tags of the type that is generated for a resolved type. These are the tags, on which
+// the tags of a component are applied.
+//
+// A Referenced Type is generated as an alias of the referenced type, so the tags are those of the
+// type generated for the definition, that is finally referred to. The types generated for the
+// other types are not tagged.
+pub(crate) fn resolve_generated_type_tags(
+    ty: &Asn1ResolvedType,
+    resolver: &Resolver,
+) -> ResolvedTypeTags {
+    let universal = |number| ResolvedTypeTags {
+        tags: vec![ResolvedTag::universal(number)],
+        ..Default::default()
+    };
+    match ty {
+        Asn1ResolvedType::Base(ref b) => universal(resolved_base_tag_number(b)),
+        Asn1ResolvedType::Constructed(ref c) => match c {
+            ResolvedConstructedType::Choice {
+                ref root_components,
+                ref additions,
+                ..
+            } => ResolvedTypeTags {
+                tags: vec![],
+                choice_tags: root_components
+                    .iter()
+                    .chain(additions.iter().flatten())
+                    .flat_map(|c| c.tags.outermost_tags())
+                    .collect(),
+            },
+            ResolvedConstructedType::Sequence { .. }
+            | ResolvedConstructedType::SequenceOf { .. } => universal(16),
+            ResolvedConstructedType::Set { .. } => universal(17),
+        },
+        Asn1ResolvedType::Reference(ref r) => {
+            let mut reference = r;
+            while let Some(Asn1ResolvedDefinition::Type(Asn1ResolvedType::Reference(ref r))) =
+                resolver.resolved_defs.get(reference)
+            {
+                reference = r;
+            }
+            resolver
+                .type_tags
+                .get(reference)
+                .cloned()
+                .unwrap_or_default()
+        }
+        Asn1ResolvedType::Set(..) => ResolvedTypeTags::default(),
+    }
+}
+
+// Tag numbers of the `UNIVERSAL` class for the Builtin Types (X.680 Table 1).
+fn builtin_tag_number(builtin: &Asn1BuiltinType) -> u32 {
+    match builtin {
+        Asn1BuiltinType::Boolean => 1,
+        Asn1BuiltinType::Integer(..) => 2,
+        Asn1BuiltinType::BitString(..) => 3,
+        Asn1BuiltinType::OctetString => 4,
+        Asn1BuiltinType::Null => 5,
+        Asn1BuiltinType::ObjectIdentifier => 6,
+        Asn1BuiltinType::Real => 9,
+        Asn1BuiltinType::Enumerated(..) => 10,
+        Asn1BuiltinType::RelativeOid => 13,
+        Asn1BuiltinType::CharacterString { ref str_type } => string_tag_number(str_type),
+        Asn1BuiltinType::Time { ref time_type } => time_tag_number(time_type),
+    }
+}
+
+fn resolved_base_tag_number(base: &ResolvedBaseType) -> u32 {
+    match base {
+        ResolvedBaseType::Boolean(..) => 1,
+        ResolvedBaseType::Integer(..) => 2,
+        ResolvedBaseType::BitString(..) => 3,
+        ResolvedBaseType::OctetString(..) => 4,
+        ResolvedBaseType::Null(..) => 5,
+        ResolvedBaseType::ObjectIdentifier(ref o) if o.relative => 13,
+        ResolvedBaseType::ObjectIdentifier(..) => 6,
+        ResolvedBaseType::Real(..) => 9,
+        ResolvedBaseType::Enum(..) => 10,
+        ResolvedBaseType::CharacterString(ref c) => string_tag_number(&c.str_type),
+        ResolvedBaseType::Time(ref t) => time_tag_number(&t.time_type),
+    }
+}
+
+fn string_tag_number(str_type: &str) -> u32 {
+    match str_type {
+        "UTF8String" => 12,
+        "NumericString" => 18,
+        "PrintableString" => 19,
+        "TeletexString" | "T61String" => 20,
+        "VideotexString" => 21,
+        "IA5String" => 22,
+        "GraphicString" => 25,
+        "VisibleString" => 26,
+        "GeneralString" => 27,
+        "UniversalString" => 28,
+        "CHARACTER-STRING" => 29,
+        _ => 30, // BMPString
+    }
+}
+
+fn time_tag_number(time_type: &str) -> u32 {
+    match time_type {
+        "UTCTime" => 23,
+        "GeneralizedTime" => 24,
+        "DATE" => 31,
+        "TIME-OF-DAY" => 32,
+        "DATE-TIME" => 33,
+        _ => 34, // DURATION
+    }
+}
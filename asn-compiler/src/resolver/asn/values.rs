@@ -1,84 +1,499 @@
 //! Resolved 'values' implementation
 
+use std::convert::TryFrom;
+
 use crate::error::Error;
 
+use crate::parser::asn::{
+    oid::well_known_oid_number,
+    structs::values::{Asn1Value, ObjIdComponent},
+};
 use crate::resolver::{
     asn::structs::{
         defs::Asn1ResolvedDefinition,
-        types::{base::ResolvedBaseType, Asn1ResolvedType},
+        types::{
+            base::ResolvedBaseType,
+            constructed::{ResolvedConstructedType, ResolvedSeqComponent},
+            Asn1ResolvedType,
+        },
         values::{
-            Asn1ResolvedEnumValue, Asn1ResolvedIntegerValue, Asn1ResolvedValue, BaseInteger,
-            ResolvedBaseValue,
+            Asn1ResolvedBitStringValue, Asn1ResolvedBooleanValue, Asn1ResolvedCharStringValue,
+            Asn1ResolvedEnumValue, Asn1ResolvedIntegerValue, Asn1ResolvedObjectIdentifierValue,
+            Asn1ResolvedOctetStringValue, Asn1ResolvedRealValue, Asn1ResolvedValue, BaseBitString,
+            BaseEnum, BaseObjectIdentifier, BaseOctetString, BaseReal, ResolvedBaseValue,
+            ResolvedConstructedValue,
         },
     },
     Resolver,
 };
 
 pub(crate) fn resolve_value(
-    value: &str,
+    value: &Asn1Value,
     typeref: &Asn1ResolvedType,
     resolver: &mut Resolver,
 ) -> Result<Asn1ResolvedValue, Error> {
-    let referenced_value = resolver.resolved_defs.get(value);
-    match referenced_value {
-        None => {
-            match typeref {
-                Asn1ResolvedType::Base(ref b) => match b {
-                    ResolvedBaseType::Integer(ref _i) => {
-                        let value = value.parse::<BaseInteger>().unwrap();
-                        Ok(Asn1ResolvedValue::Base(ResolvedBaseValue::Integer(
-                            Asn1ResolvedIntegerValue {
-                                typeref: typeref.clone(),
-                                value,
-                            },
-                        )))
-                    }
-                    ResolvedBaseType::Enum(ref _e) => {
-                        // FIXME: When Enum is resolved we'd get it right
-                        // let value = value.parse::<BaseEnum>().unwrap();
-                        let value = 0;
-                        Ok(Asn1ResolvedValue::Base(ResolvedBaseValue::Enum(
-                            Asn1ResolvedEnumValue {
-                                typeref: typeref.clone(),
-                                value,
-                            },
-                        )))
+    if let Asn1Value::Reference(ref reference) = value {
+        let key = resolver.definition_key(reference);
+        if let Some(ref_value) = resolver.resolved_defs.get(&key) {
+            return match ref_value {
+                Asn1ResolvedDefinition::Value(ref _v) => Ok(Asn1ResolvedValue::Reference(key)),
+                _ => Err(resolve_error!("{} Not a Referenved Value!", value)),
+            };
+        }
+    }
+    resolve_value_of_type(value, typeref, resolver)
+}
+
+// Resolves a value like `resolve_value`, except that a reference to a value defined in a module
+// is replaced by the referenced value (of the given type). This is used where the values are to be
+// generated as the values of the given type (eg. `DEFAULT` values of the `SEQUENCE` components).
+pub(crate) fn resolve_typed_value(
+    value: &Asn1Value,
+    typeref: &Asn1ResolvedType,
+    resolver: &mut Resolver,
+) -> Result<Asn1ResolvedValue, Error> {
+    resolve_value_of_type(value, typeref, resolver)
+}
+
+fn resolve_value_of_type(
+    value: &Asn1Value,
+    typeref: &Asn1ResolvedType,
+    resolver: &mut Resolver,
+) -> Result<Asn1ResolvedValue, Error> {
+    match typeref {
+        Asn1ResolvedType::Base(ref b) => Ok(Asn1ResolvedValue::Base(resolve_base_value(
+            value, typeref, b, resolver,
+        )?)),
+        Asn1ResolvedType::Reference(ref r) => {
+            let typedef = resolver.resolved_defs.get(r);
+            match typedef {
+                None => Err(resolve_error!(
+                    "Definition for Reference '{}' not found or not Resolved yet!",
+                    r
+                )),
+                Some(def) => match def {
+                    Asn1ResolvedDefinition::Type(ref t) => {
+                        let v = resolve_value_of_type(value, &t.clone(), resolver)?;
+                        Ok(Asn1ResolvedValue::ReferencedType {
+                            value: Box::new(v),
+                            typeref: r.clone(),
+                        })
                     }
                     _ => Err(resolve_error!(
-                        "resolve_value: Not Supported Yet! {:#?}",
-                        typeref
+                        "Resolved Definition '{:#?}' is not a Type definition!",
+                        typedef
                     )),
                 },
-                Asn1ResolvedType::Reference(ref r) => {
-                    let typedef = resolver.resolved_defs.get(r);
-                    match typedef {
-                        None => Err(resolve_error!(
-                            "Definition for Reference '{}' not found or not Resolved yet!",
-                            r
-                        )),
-                        Some(def) => match def {
-                            Asn1ResolvedDefinition::Type(ref t) => {
-                                let v = resolve_value(value, &t.clone(), resolver)?;
-                                Ok(Asn1ResolvedValue::ReferencedType {
-                                    value: Box::new(v),
-                                    typeref: r.clone(),
-                                })
-                            }
-                            _ => Err(resolve_error!(
-                                "Resolved Definition '{:#?}' is not a Type definition!",
-                                typedef
-                            )),
-                        },
-                    }
+            }
+        }
+        Asn1ResolvedType::Constructed(ref c) => {
+            let referenced = referenced_value(value, resolver);
+            if let Some(constructed) = referenced.as_ref().and_then(|v| v.get_constructed_value()) {
+                return Ok(Asn1ResolvedValue::Constructed(constructed.clone()));
+            }
+            Ok(Asn1ResolvedValue::Constructed(resolve_constructed_value(
+                value, typeref, c, resolver,
+            )?))
+        }
+        _ => Err(resolve_error!("resolve_value: Not Implemented!")),
+    }
+}
+
+fn resolve_base_value(
+    value: &Asn1Value,
+    typeref: &Asn1ResolvedType,
+    base: &ResolvedBaseType,
+    resolver: &Resolver,
+) -> Result<ResolvedBaseValue, Error> {
+    let referenced = referenced_value(value, resolver).and_then(|v| v.get_base_value().cloned());
+    let identifier = match value {
+        Asn1Value::Reference(ref r) => Some(r.as_str()),
+        _ => None,
+    };
+    let invalid = |ty: &str| resolve_error!("Value '{}' is not a valid '{}' value!", value, ty);
+
+    let resolved = match base {
+        ResolvedBaseType::Integer(ref i) => {
+            let named = identifier.and_then(|id| i.named_values.as_ref()?.get(id));
+            let value = match (value, named, referenced) {
+                (Asn1Value::Integer(v), _, _) => *v,
+                (_, Some(v), _) => *v,
+                (_, _, Some(ResolvedBaseValue::Integer(i))) => i.value,
+                _ => return Err(invalid("INTEGER")),
+            };
+            ResolvedBaseValue::Integer(Asn1ResolvedIntegerValue {
+                typeref: typeref.clone(),
+                value,
+            })
+        }
+        ResolvedBaseType::Enum(ref e) => {
+            // The values of the extension additions follow the values of the root.
+            let root_count = e.named_root_values.len() as i128;
+            let named = identifier.and_then(|id| {
+                e.named_root_values
+                    .iter()
+                    .find(|(name, _)| name == id)
+                    .map(|(_, v)| *v)
+                    .or_else(|| {
+                        e.named_ext_values
+                            .iter()
+                            .find(|(name, _)| name == id)
+                            .map(|(_, idx)| root_count + idx)
+                    })
+                    .map(|v| (id.to_string(), v as BaseEnum))
+            });
+            let (identifier, value) = match (named, referenced) {
+                (Some(named), _) => named,
+                (_, Some(ResolvedBaseValue::Enum(e))) => (e.identifier, e.value),
+                _ => return Err(invalid("ENUMERATED")),
+            };
+            ResolvedBaseValue::Enum(Asn1ResolvedEnumValue {
+                typeref: typeref.clone(),
+                identifier,
+                value,
+            })
+        }
+        ResolvedBaseType::Boolean(_) => {
+            let value = match (value, referenced) {
+                (Asn1Value::Boolean(b), _) => *b,
+                (_, Some(ResolvedBaseValue::Boolean(b))) => b.value,
+                _ => return Err(invalid("BOOLEAN")),
+            };
+            ResolvedBaseValue::Boolean(Asn1ResolvedBooleanValue {
+                typeref: typeref.clone(),
+                value,
+            })
+        }
+        ResolvedBaseType::Real(_) => {
+            let value = match (parse_real_value(value), referenced) {
+                (Some(v), _) => v,
+                (_, Some(ResolvedBaseValue::Real(r))) => r.value,
+                _ => return Err(invalid("REAL")),
+            };
+            ResolvedBaseValue::Real(Asn1ResolvedRealValue {
+                typeref: typeref.clone(),
+                value,
+            })
+        }
+        ResolvedBaseType::CharacterString(_) | ResolvedBaseType::Time(_) => {
+            let value = match (value, referenced) {
+                (Asn1Value::CharString(c), _) => c.clone(),
+                (_, Some(ResolvedBaseValue::CharString(c))) => c.value,
+                _ => return Err(invalid("Character String")),
+            };
+            ResolvedBaseValue::CharString(Asn1ResolvedCharStringValue {
+                typeref: typeref.clone(),
+                value,
+            })
+        }
+        ResolvedBaseType::OctetString(_) => {
+            let value = match (parse_bstring_or_hstring_value(value), referenced) {
+                (Some(bits), _) => bits_to_bytes(&bits),
+                (_, Some(ResolvedBaseValue::OctetString(o))) => o.value,
+                _ => return Err(invalid("OCTET STRING")),
+            };
+            ResolvedBaseValue::OctetString(Asn1ResolvedOctetStringValue {
+                typeref: typeref.clone(),
+                value,
+            })
+        }
+        ResolvedBaseType::BitString(ref b) => {
+            let named_bits = parse_named_bits_value(value, &b.named_values);
+            let value = match (
+                parse_bstring_or_hstring_value(value),
+                named_bits,
+                referenced,
+            ) {
+                (Some(bits), _, _) => bits,
+                (_, Some(bits), _) => bits,
+                (_, _, Some(ResolvedBaseValue::BitString(b))) => b.value,
+                _ => return Err(invalid("BIT STRING")),
+            };
+            ResolvedBaseValue::BitString(Asn1ResolvedBitStringValue {
+                typeref: typeref.clone(),
+                value,
+            })
+        }
+        ResolvedBaseType::Null(_) => match value {
+            Asn1Value::Null => ResolvedBaseValue::Null,
+            _ => return Err(invalid("NULL")),
+        },
+        ResolvedBaseType::ObjectIdentifier(_) => {
+            let value = match (value.object_identifier_components(), referenced) {
+                (Some(components), _) => {
+                    resolve_object_identifier_components(&components, resolver)?
+                }
+                (_, Some(ResolvedBaseValue::ObjectIdentifier(o))) => o.value,
+                _ => return Err(invalid("OBJECT IDENTIFIER")),
+            };
+            ResolvedBaseValue::ObjectIdentifier(Asn1ResolvedObjectIdentifierValue {
+                typeref: typeref.clone(),
+                value,
+            })
+        }
+    };
+
+    Ok(resolved)
+}
+
+// Resolves a value of a `SEQUENCE`, a `SET`, a `CHOICE` or a `SEQUENCE OF` type. The values of
+// the components (or the elements) are resolved against their types.
+fn resolve_constructed_value(
+    value: &Asn1Value,
+    typeref: &Asn1ResolvedType,
+    constructed: &ResolvedConstructedType,
+    resolver: &mut Resolver,
+) -> Result<ResolvedConstructedValue, Error> {
+    let invalid = |ty: &str| resolve_error!("Value '{}' is not a valid '{}' value!", value, ty);
+
+    match constructed {
+        ResolvedConstructedType::SequenceOf { ref ty, .. } => {
+            let elements = match value {
+                Asn1Value::List(ref elements) => elements,
+                _ => return Err(invalid("SEQUENCE OF")),
+            };
+            let mut values = vec![];
+            for element in elements {
+                if element.identifier.is_some() {
+                    return Err(invalid("SEQUENCE OF"));
                 }
-                _ => Err(resolve_error!("resolve_value: Not Implemented!")),
+                values.push(resolve_value_of_type(&element.value, ty, resolver)?);
             }
+            Ok(ResolvedConstructedValue::SequenceOf {
+                typeref: typeref.clone(),
+                values,
+            })
         }
-        Some(ref_value) => match ref_value {
-            Asn1ResolvedDefinition::Value(ref _v) => {
-                Ok(Asn1ResolvedValue::Reference(value.to_string()))
+        ResolvedConstructedType::Sequence {
+            ref components,
+            ref additions,
+            ..
+        }
+        | ResolvedConstructedType::Set {
+            ref components,
+            ref additions,
+            ..
+        } => {
+            // The components of a `SEQUENCE` value are in the order of the components of the type,
+            // the components of a `SET` value may be in any order.
+            let (ty, ordered) = match constructed {
+                ResolvedConstructedType::Sequence { .. } => ("SEQUENCE", true),
+                _ => ("SET", false),
+            };
+            let elements = match value {
+                Asn1Value::List(ref elements) => elements,
+                _ => return Err(invalid(ty)),
+            };
+
+            let all_components = components
+                .iter()
+                .chain(additions.iter().flat_map(|a| a.components.iter()))
+                .collect::<Vec<&ResolvedSeqComponent>>();
+            let mut resolved = vec![];
+            let mut next = 0;
+            for element in elements {
+                let id = element.identifier.as_ref().ok_or_else(|| invalid(ty))?;
+                let idx = all_components
+                    .iter()
+                    .position(|c| &c.component.id == id)
+                    .ok_or_else(|| {
+                        resolve_error!("Component '{}' of the value '{}' not found!", id, value)
+                    })?;
+                if resolved.iter().any(|(i, _, _)| i == &idx) || (ordered && idx < next) {
+                    return Err(resolve_error!(
+                        "Component '{}' of the value '{}' is repeated or out of order!",
+                        id,
+                        value
+                    ));
+                }
+                next = idx + 1;
+                let component_value = resolve_value_of_type(
+                    &element.value,
+                    &all_components[idx].component.ty,
+                    resolver,
+                )?;
+                resolved.push((idx, id.clone(), component_value));
             }
-            _ => Err(resolve_error!("{} Not a Referenved Value!", value)),
+
+            // Only the `OPTIONAL` (or `DEFAULT`) components and the extension additions may be
+            // absent.
+            for (idx, c) in components.iter().enumerate() {
+                if !c.optional && !resolved.iter().any(|(i, _, _)| *i == idx) {
+                    return Err(resolve_error!(
+                        "Mandatory Component '{}' is absent in the value '{}'!",
+                        c.component.id,
+                        value
+                    ));
+                }
+            }
+
+            resolved.sort_by_key(|(idx, _, _)| *idx);
+            Ok(ResolvedConstructedValue::Sequence {
+                typeref: typeref.clone(),
+                components: resolved
+                    .into_iter()
+                    .map(|(_, id, value)| (id, value))
+                    .collect(),
+            })
+        }
+        ResolvedConstructedType::Choice {
+            ref root_components,
+            ref additions,
+            ..
+        } => {
+            let (identifier, alternative_value) = match value {
+                Asn1Value::Choice {
+                    ref identifier,
+                    ref value,
+                } => (identifier, value),
+                _ => return Err(invalid("CHOICE")),
+            };
+            let alternative = root_components
+                .iter()
+                .chain(additions.iter().flatten())
+                .find(|c| &c.id == identifier)
+                .ok_or_else(|| {
+                    resolve_error!(
+                        "Alternative '{}' of the value '{}' not found!",
+                        identifier,
+                        value
+                    )
+                })?;
+            let resolved = resolve_value_of_type(alternative_value, &alternative.ty, resolver)?;
+            Ok(ResolvedConstructedValue::Choice {
+                typeref: typeref.clone(),
+                identifier: identifier.clone(),
+                value: Box::new(resolved),
+            })
+        }
+    }
+}
+
+// Returns the referenced value, if `value` is a reference to a value defined in a module. A
+// reference to a value, that is itself a reference to another value, is followed.
+fn referenced_value(value: &Asn1Value, resolver: &Resolver) -> Option<Asn1ResolvedValue> {
+    let mut referenced = match value {
+        Asn1Value::Reference(ref reference) => match resolver.get_resolved_def(reference) {
+            Some(Asn1ResolvedDefinition::Value(ref v)) => v.clone(),
+            _ => return None,
         },
+        _ => return None,
+    };
+    while let Asn1ResolvedValue::Reference(ref key) = referenced {
+        referenced = match resolver.resolved_defs.get(key) {
+            Some(Asn1ResolvedDefinition::Value(ref v)) => v.clone(),
+            _ => return None,
+        };
     }
+    Some(referenced)
+}
+
+// Resolves the arcs of an `OBJECT IDENTIFIER` (or a `RELATIVE-OID`) value. A name of a component
+// is a reference to an `OBJECT IDENTIFIER` (or a `RELATIVE-OID`) value, whose arcs are included, a
+// reference to an `INTEGER` value or a well known name (like `iso`).
+fn resolve_object_identifier_components(
+    components: &[ObjIdComponent],
+    resolver: &Resolver,
+) -> Result<BaseObjectIdentifier, Error> {
+    let mut arcs = vec![];
+    for component in components {
+        match component {
+            ObjIdComponent::Number(n) | ObjIdComponent::NameAndNumber(_, n) => arcs.push(*n),
+            ObjIdComponent::Name(ref name) => {
+                let referenced = referenced_value(&Asn1Value::Reference(name.clone()), resolver);
+                match referenced.as_ref().and_then(|v| v.get_base_value()) {
+                    Some(ResolvedBaseValue::ObjectIdentifier(ref o)) => arcs.extend(&o.value),
+                    Some(ResolvedBaseValue::Integer(ref i))
+                        if (0..=u32::MAX as i128).contains(&i.value) =>
+                    {
+                        arcs.push(i.value as u32)
+                    }
+                    _ => arcs.push(well_known_oid_number(name).ok_or_else(|| {
+                        resolve_error!("OBJECT IDENTIFIER component '{}' not found!", name)
+                    })?),
+                }
+            }
+        }
+    }
+    Ok(arcs)
+}
+
+// Parses a `REAL` value in the decimal notation (eg. `-1.25`), the `{ mantissa, base, exponent }`
+// notation or one of the special values.
+fn parse_real_value(value: &Asn1Value) -> Option<BaseReal> {
+    let elements = match value {
+        Asn1Value::Real(r) => return Some(*r),
+        Asn1Value::Integer(i) => return Some(*i as BaseReal),
+        Asn1Value::List(ref elements) => elements,
+        _ => return None,
+    };
+
+    let mut components = [None; 3];
+    for element in elements {
+        let idx = ["mantissa", "base", "exponent"]
+            .iter()
+            .position(|&id| Some(id) == element.identifier.as_deref())?;
+        components[idx] = match element.value {
+            Asn1Value::Integer(v) => Some(i32::try_from(v).ok()?),
+            _ => return None,
+        };
+    }
+    match components {
+        [Some(mantissa), Some(base @ (2 | 10)), Some(exponent)] => {
+            Some(mantissa as BaseReal * (base as BaseReal).powi(exponent))
+        }
+        _ => None,
+    }
+}
+
+// Parses a value of the form `'0101'B` or `'5A'H` as bits.
+fn parse_bstring_or_hstring_value(value: &Asn1Value) -> Option<BaseBitString> {
+    match value {
+        Asn1Value::BitString(ref bits) => Some(bits.chars().map(|c| c == '1').collect()),
+        Asn1Value::HexString(ref hex) => {
+            let mut bits = vec![];
+            for c in hex.chars() {
+                let nibble = c.to_digit(16)?;
+                bits.extend((0..4).rev().map(|i| nibble & (1 << i) != 0));
+            }
+            Some(bits)
+        }
+        _ => None,
+    }
+}
+
+// Parses a `BIT STRING` value of the form `{ bit1, bit3 }`, where the named bits are set. The
+// trailing `0` bits are not present in the value.
+fn parse_named_bits_value(
+    value: &Asn1Value,
+    named_values: &std::collections::HashMap<String, u8>,
+) -> Option<BaseBitString> {
+    let elements = match value {
+        Asn1Value::List(ref elements) => elements,
+        _ => return None,
+    };
+    let mut bits = vec![];
+    for element in elements {
+        let bit = match (&element.identifier, &element.value) {
+            (None, Asn1Value::Reference(ref name)) => *named_values.get(name)? as usize,
+            _ => return None,
+        };
+        if bit >= bits.len() {
+            bits.resize(bit + 1, false);
+        }
+        bits[bit] = true;
+    }
+    Some(bits)
+}
+
+// Packs the bits into octets. The last octet is padded with `0` bits.
+fn bits_to_bytes(bits: &[bool]) -> BaseOctetString {
+    bits.chunks(8)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .enumerate()
+                .fold(0u8, |acc, (i, &bit)| acc | ((bit as u8) << (7 - i)))
+        })
+        .collect()
 }
@@ -1,27 +1,64 @@
 //! Resolver Struct and it's implementation
 
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 use crate::error::Error;
 
-use crate::parser::asn::structs::{defs::Asn1Definition, module::Asn1Module};
+use crate::parser::asn::structs::{
+    defs::Asn1Definition,
+    module::{Asn1Module, Asn1ModuleTag},
+};
 
 use crate::resolver::asn::structs::{
-    defs::Asn1ResolvedDefinition, types::Asn1ResolvedType, values::Asn1ResolvedValue,
+    defs::{Asn1ResolvedDefinition, DefinitionKey},
+    types::{
+        constraints::Asn1ConstraintValueSet,
+        tags::{ResolvedTagging, ResolvedTypeTags},
+        Asn1ResolvedType,
+    },
+    values::Asn1ResolvedValue,
 };
 
 use crate::resolver::asn::defs::resolve_definition;
 
+// Symbols visible in a module: the names defined in the module and the names imported into the
+// module along with the names of the modules from which those are imported.
+#[derive(Debug, Clone, Default)]
+struct ModuleSymbols {
+    definitions: HashSet<String>,
+    imports: HashMap<String, String>,
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct Resolver {
     // Resolved definitions
-    pub(crate) resolved_defs: BTreeMap<String, Asn1ResolvedDefinition>,
+    pub(crate) resolved_defs: BTreeMap<DefinitionKey, Asn1ResolvedDefinition>,
 
     // Unresolved Parameterized Definitions used by individual 'Type's to resolve themselves.
-    pub(crate) parameterized_defs: HashMap<String, Asn1Definition>,
+    pub(crate) parameterized_defs: HashMap<DefinitionKey, Asn1Definition>,
 
     // Object Classes: Used by Objects and Object Sets to resolves themselves.
-    pub(crate) classes: HashMap<String, Asn1Definition>,
+    pub(crate) classes: HashMap<DefinitionKey, Asn1Definition>,
+
+    // Tagging environment of the module being resolved.
+    pub(crate) module_tags: Asn1ModuleTag,
+
+    // Effective tags of the resolved Type definitions. Used for the tags of the referenced types.
+    pub(crate) type_tags: HashMap<DefinitionKey, ResolvedTypeTags>,
+
+    // Taggings to be applied on the types generated for the Type definitions.
+    pub(crate) type_taggings: HashMap<DefinitionKey, Vec<ResolvedTagging>>,
+
+    // Values of the `INTEGER` Value Set definitions, for which the allowed values are generated.
+    pub(crate) value_sets: HashMap<DefinitionKey, Asn1ConstraintValueSet>,
+
+    // Symbols of all the modules seen so far.
+    module_symbols: HashMap<String, ModuleSymbols>,
+
+    // Modules in which the names are looked up. The first is the module being resolved. While
+    // resolving a Parameterized Type, the module defining the Parameterized Type is pushed, so that
+    // the names used in it's definition are looked up in that module first.
+    scopes: Vec<String>,
 }
 
 impl Resolver {
@@ -30,7 +67,103 @@ impl Resolver {
             resolved_defs: BTreeMap::new(),
             parameterized_defs: HashMap::new(),
             classes: HashMap::new(),
+            module_tags: Asn1ModuleTag::default(),
+            type_tags: HashMap::new(),
+            type_taggings: HashMap::new(),
+            value_sets: HashMap::new(),
+            module_symbols: HashMap::new(),
+            scopes: vec![],
+        }
+    }
+
+    // The name of the module being resolved.
+    pub(crate) fn current_module(&self) -> &str {
+        self.scopes.first().map(|s| s.as_str()).unwrap_or_default()
+    }
+
+    // The key of a definition with the given name in the module being resolved.
+    pub(crate) fn current_module_key(&self, name: &str) -> DefinitionKey {
+        DefinitionKey::new(self.current_module(), name)
+    }
+
+    // The key of the definition referred to by `name` in the current scope.
+    //
+    // The name is looked up in the modules of the scope, innermost first. In a module, the name is
+    // either defined in the module or imported into it (possibly through a chain of modules that
+    // import it). A name not visible in any of these, refers to the only definition of that name
+    // in any of the modules, if there is one, else to the only definition of that name in the
+    // modules the scope imports from (directly or indirectly). Some specifications do not import
+    // every definition they refer to (eg. the types of the fields of a Class used in Objects).
+    pub(crate) fn definition_key(&self, name: &str) -> DefinitionKey {
+        for module in self.scopes.iter().rev() {
+            if let Some(key) = self.lookup_in_module(module, name) {
+                return key;
+            }
+        }
+
+        let defining = self
+            .module_symbols
+            .iter()
+            .filter(|(_, symbols)| symbols.definitions.contains(name))
+            .map(|(module, _)| module)
+            .collect::<Vec<_>>();
+        if let [module] = defining[..] {
+            return DefinitionKey::new(module, name);
         }
+
+        let imported = self.imported_modules();
+        let defining = defining
+            .into_iter()
+            .filter(|module| imported.contains(module.as_str()))
+            .collect::<Vec<_>>();
+        match defining[..] {
+            [module] => DefinitionKey::new(module, name),
+            _ => self.current_module_key(name),
+        }
+    }
+
+    // The modules, from which the modules of the scope import, directly or indirectly.
+    fn imported_modules(&self) -> HashSet<&str> {
+        let mut imported = HashSet::new();
+        let mut pending = self.scopes.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+        while let Some(module) = pending.pop() {
+            if let Some(symbols) = self.module_symbols.get(module) {
+                for from in symbols.imports.values() {
+                    if imported.insert(from.as_str()) {
+                        pending.push(from);
+                    }
+                }
+            }
+        }
+        imported
+    }
+
+    fn lookup_in_module(&self, module: &str, name: &str) -> Option<DefinitionKey> {
+        let mut module = module;
+        // A chain of imports cannot be longer than the number of the modules.
+        for _ in 0..=self.module_symbols.len() {
+            let symbols = self.module_symbols.get(module)?;
+            if symbols.definitions.contains(name) {
+                return Some(DefinitionKey::new(module, name));
+            }
+            module = symbols.imports.get(name)?;
+        }
+        None
+    }
+
+    // The resolved definition referred to by `name` in the current scope.
+    pub(crate) fn get_resolved_def(&self, name: &str) -> Option<&Asn1ResolvedDefinition> {
+        self.resolved_defs.get(&self.definition_key(name))
+    }
+
+    // Looks up the names in the given module first (See `definition_key`), till the scope is
+    // popped.
+    pub(crate) fn push_scope(&mut self, module: &str) {
+        self.scopes.push(module.to_string());
+    }
+
+    pub(crate) fn pop_scope(&mut self) {
+        self.scopes.pop();
     }
 
     // Resolve Definitions: Algorithm
@@ -48,10 +181,23 @@ impl Resolver {
     // After that we resolve definitions in a Topologically sorted order. Fairly straight forward.
     // We do not need to do any `Pending` definitions, as we were doing before.
     pub(crate) fn resolve_definitions(&mut self, module: &mut Asn1Module) -> Result<(), Error> {
+        self.module_tags = module.get_module_tags();
+
+        let module_name = module.get_module_name();
+        let symbols = ModuleSymbols {
+            definitions: module.get_definitions().keys().cloned().collect(),
+            imports: module
+                .get_imported_defs()
+                .map(|(name, m)| (name.clone(), m.name()))
+                .collect(),
+        };
+        self.module_symbols.insert(module_name.clone(), symbols);
+        self.scopes = vec![module_name];
+
         // We need to first get Classes in the current module - resolved
         self.resolve_classes_in_current_module(module);
 
-        module.resolve_object_classes(&self.classes)?;
+        module.resolve_object_classes(&self.visible_classes())?;
 
         let definitions_sorted = module.definitions_sorted();
         for k in definitions_sorted {
@@ -65,14 +211,14 @@ impl Resolver {
                 continue;
             }
             let parsed_def = parsed_def.unwrap();
+            let key = self.current_module_key(&k);
             if parsed_def.params.is_some() {
-                self.parameterized_defs
-                    .insert(k.to_string(), parsed_def.clone());
+                self.parameterized_defs.insert(key, parsed_def.clone());
             } else if parsed_def.is_class_assignment() {
-                self.classes.insert(k.to_string(), parsed_def.clone());
+                self.classes.insert(key, parsed_def.clone());
             } else {
                 let resolved_def = resolve_definition(parsed_def, self)?;
-                self.resolved_defs.insert(k.clone(), resolved_def);
+                self.resolved_defs.insert(key, resolved_def);
             }
             parsed_def.resolved = true;
         }
@@ -99,31 +245,40 @@ impl Resolver {
         Ok(())
     }
 
-    pub(crate) fn get_resolved_types(&self) -> Vec<(&String, &Asn1ResolvedType)> {
+    pub(crate) fn get_resolved_types(&self) -> Vec<(&DefinitionKey, &Asn1ResolvedType)> {
         self.resolved_defs
             .iter()
             .filter_map(|(k, v)| match v {
                 Asn1ResolvedDefinition::Type(ref t) => Some((k, t)),
                 _ => None,
             })
-            .collect::<Vec<(&String, &Asn1ResolvedType)>>()
+            .collect::<Vec<(&DefinitionKey, &Asn1ResolvedType)>>()
     }
 
-    pub(crate) fn get_resolved_values(&self) -> Vec<(&String, &Asn1ResolvedValue)> {
+    pub(crate) fn get_resolved_values(&self) -> Vec<(&DefinitionKey, &Asn1ResolvedValue)> {
         self.resolved_defs
             .iter()
             .filter_map(|(k, v)| match v {
                 Asn1ResolvedDefinition::Value(ref v) => Some((k, v)),
                 _ => None,
             })
-            .collect::<Vec<(&String, &Asn1ResolvedValue)>>()
+            .collect::<Vec<(&DefinitionKey, &Asn1ResolvedValue)>>()
     }
 
     fn resolve_classes_in_current_module(&mut self, module: &Asn1Module) {
         for (k, def) in module.get_definitions() {
             if def.is_class_assignment() {
-                self.classes.insert(k.clone(), def.clone());
+                self.classes.insert(self.current_module_key(k), def.clone());
             }
         }
     }
+
+    // The classes visible in the module being resolved by their names.
+    fn visible_classes(&self) -> HashMap<String, Asn1Definition> {
+        self.classes
+            .iter()
+            .filter(|(key, _)| self.definition_key(&key.name) == **key)
+            .map(|(key, def)| (key.name.clone(), def.clone()))
+            .collect()
+    }
 }
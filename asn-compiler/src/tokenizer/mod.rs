@@ -27,8 +27,11 @@ const KEYWORDS: &[&str] = &[
     "COMPONENTS",
     "CONSTRAINED",
     "CONTAINING",
+    "DATE",
+    "DATE-TIME",
     "DEFAULT",
     "DEFINITIONS",
+    "DURATION",
     "EMBEDDED",
     "ENCODED",
     "END",
@@ -56,6 +59,7 @@ const KEYWORDS: &[&str] = &[
     "MAX",
     "MIN",
     "MINUS-INFINITY",
+    "NOT-A-NUMBER",
     "NULL",
     "NumericString",
     "OBJECT",
@@ -65,7 +69,7 @@ const KEYWORDS: &[&str] = &[
     "OPTIONAL",
     "PATTERN",
     "PDV",
-    "Plus-Infinity",
+    "PLUS-INFINITY",
     "PRESENT",
     "PrintableString",
     "PRIVATE",
@@ -79,6 +83,7 @@ const KEYWORDS: &[&str] = &[
     "T61String",
     "TAGS",
     "TeletexString",
+    "TIME-OF-DAY",
     "TRUE",
     "TYPE-IDENTIFIER",
     "UNION",
@@ -98,12 +103,24 @@ const BASE_TYPES: &[&str] = &[
     "BOOLEAN",
     "ENUMERATED",
     "NULL",
+    "REAL",
     "UTF8String",
     "IA5String",
     "PrintableString",
     "VisibleString",
+    "NumericString",
+    "BMPString",
+    "UniversalString",
+    "TeletexString",
+    "T61String",
+    "GeneralString",
     "UTCTime",
     "GeneralizedTime",
+    "DATE",
+    "TIME-OF-DAY",
+    "DATE-TIME",
+    "DURATION",
+    "RELATIVE-OID",
     // Spliced types (Note: actual ASN.1 Type names are different.
     "OBJECT",
     "OCTET",
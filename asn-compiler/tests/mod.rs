@@ -81,34 +81,543 @@ mod tests {
         let module_header = super::get_module_header(module_name, test_no);
 
         let definitions = r#"
-PersonnelRecord ::=  SET {
+PersonnelRecord ::= [APPLICATION 0] IMPLICIT SET {
         name Name,
-        title VisibleString,
+        title [0] VisibleString,
         number EmployeeNumber,
-        dateOfHire Date,
-        nameOfSpouse Name,
-        children SEQUENCE OF ChildInformation DEFAULT {}
+        dateOfHire [1] Date,
+        nameOfSpouse [2] Name,
+        children [3] IMPLICIT SEQUENCE OF ChildInformation DEFAULT {}
 }
 ChildInformation ::= SET {
     name Name,
-    dateOfBirth Date
+    dateOfBirth [0] Date
 }
 
-Name ::= SEQUENCE {
+Name ::= [APPLICATION 1] IMPLICIT SEQUENCE {
     givenName VisibleString,
     initial VisibleString,
     familyName VisibleString
 }
-EmployeeNumber ::= INTEGER
-Date ::= VisibleString -- YYYYMMDD"#;
+EmployeeNumber ::= [APPLICATION 2] IMPLICIT INTEGER
+Date ::= [APPLICATION 3] IMPLICIT VisibleString -- YYYYMMDD"#;
 
         let definitions = super::get_module_definitions(definitions);
 
         let module_str = format!("{} {}", module_header, definitions);
 
-        let mut compiler = get_dev_null_compiler();
+        let output = std::env::temp_dir().join("compile_example_from_x691_spec.rs");
+        let mut compiler = Asn1Compiler::new(
+            output.to_str().unwrap(),
+            &Visibility::Public,
+            vec![Codec::Aper],
+            vec![Derive::Debug],
+        );
         let result = compiler.compile_string(&module_str);
 
         assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+        // The components of the `SET` are generated in the canonical order of their tags.
+        let generated = std::fs::read_to_string(&output).unwrap();
+        let expected = r#"pub struct PersonnelRecord {
+    pub name: Name,
+    pub number: EmployeeNumber,
+    pub title: PersonnelRecordTitle,
+    pub date_of_hire: Date,
+    pub name_of_spouse: Name,
+    #[asn(optional_idx = 0, default = "PersonnelRecord::default_children")]
+    pub children: Option<PersonnelRecordChildren>,
+}"#;
+        assert!(generated.contains(expected), "{}", generated);
+    }
+
+    #[test]
+    fn failing_set_components_same_tag() {
+        let module_name = "SetComponentsSameTag";
+        let test_no = 4;
+        let module_header = super::get_module_header(module_name, test_no);
+
+        let definitions = "Record ::= SET { name VisibleString, title VisibleString }";
+        let definitions = super::get_module_definitions(definitions);
+
+        let module_str = format!("{} {}", module_header, definitions);
+
+        let mut compiler = get_dev_null_compiler();
+        let result = compiler.compile_string(&module_str);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn failing_choice_alternatives_same_tag() {
+        let module_name = "ChoiceAlternativesSameTag";
+        let test_no = 5;
+        let module_header = super::get_module_header(module_name, test_no);
+
+        let definitions =
+            "Contact ::= CHOICE { phone [0] VisibleString, email [0] IMPLICIT IA5String }";
+        let definitions = super::get_module_definitions(definitions);
+
+        let module_str = format!("{} {}", module_header, definitions);
+
+        let mut compiler = get_dev_null_compiler();
+        let result = compiler.compile_string(&module_str);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn failing_imports_not_exported_or_not_defined() {
+        let test_cases = vec![
+            ("EXPORTS ALL;", "Exported", true),
+            ("", "NotExported", true),
+            ("EXPORTS Exported;", "Exported", true),
+            ("EXPORTS Exported;", "NotExported", false),
+            ("EXPORTS ALL;", "Undefined", false),
+        ];
+
+        for (exports, import, success) in test_cases {
+            let exporting_header = super::get_module_header("ExportingModule", 6);
+            let exporting_definitions = super::get_module_definitions(&format!(
+                "{} Exported ::= INTEGER NotExported ::= BOOLEAN",
+                exports
+            ));
+
+            let importing_header = super::get_module_header("ImportingModule", 7);
+            let importing_definitions = super::get_module_definitions(&format!(
+                "IMPORTS {} FROM ExportingModule; Record ::= SEQUENCE {{ a {} }}",
+                import, import
+            ));
+
+            let module_str = format!(
+                "{} {} {} {}",
+                exporting_header, exporting_definitions, importing_header, importing_definitions
+            );
+
+            let mut compiler = get_dev_null_compiler();
+            let result = compiler.compile_string(&module_str);
+
+            assert_eq!(result.is_ok(), success, "{}: {:?}", exports, result);
+            if let Err(e) = result {
+                let e = format!("{}", e);
+                assert!(e.contains(import) && e.contains("ExportingModule"), "{}", e);
+                assert!(e.contains("Line: "), "{}", e);
+            }
+        }
+    }
+
+    #[test]
+    fn import_module_by_oid_and_by_name() {
+        let exporting_definitions = super::get_module_definitions("Exported ::= INTEGER");
+
+        // The name in the `IMPORTS` is used only when the module is not found by it's OID.
+        let test_cases = vec![
+            (super::get_module_header("OldModuleName", 8), true),
+            ("ExportingModule".to_string(), true),
+            (super::get_module_header("ExportingModule", 9), true),
+            (super::get_module_header("OldModuleName", 9), false),
+        ];
+
+        for (imported_module, success) in test_cases {
+            let exporting_header = super::get_module_header("ExportingModule", 8);
+            let importing_header = super::get_module_header("ImportingModule", 10);
+            let importing_definitions = super::get_module_definitions(&format!(
+                "IMPORTS Exported FROM {}; Record ::= SEQUENCE {{ a Exported }}",
+                imported_module
+            ));
+
+            let module_str = format!(
+                "{} {} {} {}",
+                exporting_header, exporting_definitions, importing_header, importing_definitions
+            );
+
+            let mut compiler = get_dev_null_compiler();
+            let result = compiler.compile_string(&module_str);
+
+            assert_eq!(result.is_ok(), success, "{}: {:?}", imported_module, result);
+        }
+    }
+
+    #[test]
+    fn failing_modules_conflicting_names_or_oids() {
+        let definitions = super::get_module_definitions("Exported ::= INTEGER");
+
+        let test_cases = vec![
+            (("SameName", 11), ("SameName", 12)),
+            (("FirstName", 13), ("SecondName", 13)),
+        ];
+
+        for ((first_name, first_no), (second_name, second_no)) in test_cases {
+            let module_str = format!(
+                "{} {} {} {}",
+                super::get_module_header(first_name, first_no),
+                definitions,
+                super::get_module_header(second_name, second_no),
+                definitions
+            );
+
+            let mut compiler = get_dev_null_compiler();
+            let result = compiler.compile_string(&module_str);
+
+            assert!(result.is_err(), "{} {}", first_name, second_name);
+        }
+    }
+
+    #[test]
+    fn same_definition_names_in_modules() {
+        let first = format!(
+            "{} {}",
+            super::get_module_header("FirstModule", 14),
+            super::get_module_definitions("Criticality ::= ENUMERATED { reject, ignore }")
+        );
+        let second = format!(
+            "{} {}",
+            super::get_module_header("SecondModule", 15),
+            super::get_module_definitions(
+                "Criticality ::= ENUMERATED { reject, ignore, notify } \
+                 Record ::= SEQUENCE { criticality Criticality }"
+            )
+        );
+        let importing = format!(
+            "{} {}",
+            super::get_module_header("ImportingModule", 16),
+            super::get_module_definitions(
+                "IMPORTS Criticality FROM FirstModule; \
+                 Message ::= SEQUENCE { criticality Criticality }"
+            )
+        );
+        let module_str = format!("{} {} {}", first, second, importing);
+
+        // The different definitions of 'Criticality' can only be generated in separate modules.
+        for (module_per_asn1_module, success) in [(false, false), (true, true)] {
+            let mut compiler = get_dev_null_compiler();
+            compiler.set_module_per_asn1_module(module_per_asn1_module);
+            let result = compiler.compile_string(&module_str);
+
+            assert_eq!(result.is_ok(), success, "{:?}", result);
+        }
+
+        // Each ASN.1 module is generated as a Rust module, importing the definitions it uses.
+        let output = std::env::temp_dir().join("same_definition_names_in_modules.rs");
+        let mut compiler = Asn1Compiler::new(
+            output.to_str().unwrap(),
+            &Visibility::Public,
+            vec![Codec::Aper],
+            vec![Derive::Debug],
+        );
+        compiler.set_module_per_asn1_module(true);
+        let result = compiler.compile_string(&module_str);
+        assert!(result.is_ok(), "{:?}", result);
+
+        let generated = std::fs::read_to_string(&output).unwrap();
+        for expected in [
+            "pub mod firstmodule {\n",
+            "pub mod secondmodule {\n",
+            "pub mod importingmodule {\n    use super::firstmodule::Criticality;\n",
+        ] {
+            assert!(generated.contains(expected), "{}: {}", expected, generated);
+        }
+        assert!(
+            !generated.contains("use super::secondmodule::"),
+            "{}",
+            generated
+        );
+
+        // The same definitions, referring to their copies in each of the modules, are fine.
+        let copies = ["FirstCopy", "SecondCopy"]
+            .iter()
+            .zip([21, 22])
+            .map(|(name, test_no)| {
+                format!(
+                    "{} {}",
+                    super::get_module_header(name, test_no),
+                    super::get_module_definitions(
+                        "Criticality ::= ENUMERATED { reject, ignore } \
+                         Record ::= SEQUENCE { criticality Criticality }"
+                    )
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(" ");
+        let mut compiler = get_dev_null_compiler();
+        let result = compiler.compile_string(&copies);
+        assert!(result.is_ok(), "{:?}", result);
+    }
+
+    #[test]
+    fn inner_type_constraints() {
+        let base = "Base ::= SEQUENCE { a INTEGER (0..255), b BOOLEAN OPTIONAL, ... } \
+                    Alt ::= CHOICE { x INTEGER (0..7), y BOOLEAN } \
+                    Numbers ::= SEQUENCE OF INTEGER (0..100) ";
+
+        let test_cases = vec![
+            ("Restricted ::= Base (WITH COMPONENTS { ..., a (1..10), b PRESENT })", true),
+            ("Restricted ::= Base (WITH COMPONENTS { a, b ABSENT })", true),
+            ("Restricted ::= Alt (WITH COMPONENTS { x (1..3) PRESENT })", true),
+            ("Restricted ::= Numbers (WITH COMPONENT (1..10))", true),
+            ("Restricted ::= SEQUENCE (WITH COMPONENT (WITH COMPONENTS { ..., b ABSENT })) OF Base", true),
+            ("Restricted ::= Base (WITH COMPONENTS { ..., c PRESENT })", false),
+            ("Restricted ::= Base (WITH COMPONENTS { ..., a ABSENT })", false),
+            ("Restricted ::= Base (WITH COMPONENT (1..10))", false),
+        ];
+
+        for (definition, success) in test_cases {
+            let module_str = format!(
+                "{} {}",
+                super::get_module_header("InnerTypeConstraints", 17),
+                super::get_module_definitions(&format!("{} {}", base, definition))
+            );
+
+            let mut compiler = get_dev_null_compiler();
+            let result = compiler.compile_string(&module_str);
+
+            assert_eq!(result.is_ok(), success, "{}: {:?}", definition, result);
+        }
+    }
+
+    #[test]
+    fn constraint_set_arithmetic() {
+        let test_cases = vec![
+            (
+                "maxValue INTEGER ::= 30 Values ::= INTEGER (1..10 | 20..maxValue)",
+                r#"type = "INTEGER", lb = "1", ub = "30""#,
+            ),
+            (
+                "Values ::= INTEGER (1..30 | 40 | 181, ...)",
+                r#"type = "INTEGER", lb = "1", ub = "181", extensible = true"#,
+            ),
+            (
+                "Values ::= INTEGER ((1..10, ...) ^ 5..20)",
+                r#"type = "INTEGER", lb = "5", ub = "10""#,
+            ),
+            (
+                "Values ::= INTEGER (1..100 EXCEPT 50..100)",
+                r#"type = "INTEGER", lb = "1", ub = "100""#,
+            ),
+            (
+                "Values ::= INTEGER (0..MAX)",
+                r#"type = "INTEGER", lb = "0""#,
+            ),
+            ("Values ::= INTEGER (ALL EXCEPT 0)", r#"type = "INTEGER""#),
+            (
+                "Small ::= INTEGER (1..4) Values ::= INTEGER (Small | 8)",
+                r#"type = "INTEGER", lb = "1", ub = "8""#,
+            ),
+            (
+                "Values ::= INTEGER (0..10) (2..4, ...)",
+                r#"type = "INTEGER", lb = "2", ub = "4", extensible = true"#,
+            ),
+            (
+                "Base ::= INTEGER (0..255, ...) Values ::= Base (10..20)",
+                r#"type = "INTEGER", lb = "10", ub = "20""#,
+            ),
+            (
+                "Values ::= OCTET STRING (SIZE (1..8) | SIZE (16), ...)",
+                r#"type = "OCTET-STRING", sz_extensible = true, sz_lb = "1", sz_ub = "16""#,
+            ),
+        ];
+
+        let output = std::env::temp_dir().join("constraint_set_arithmetic.rs");
+        for (definitions, attributes) in test_cases {
+            let module_str = format!(
+                "{} {}",
+                super::get_module_header("ConstraintSetArithmetic", 18),
+                super::get_module_definitions(definitions)
+            );
+
+            let mut compiler = Asn1Compiler::new(
+                output.to_str().unwrap(),
+                &Visibility::Public,
+                vec![Codec::Aper],
+                vec![Derive::Debug],
+            );
+            let result = compiler.compile_string(&module_str);
+            assert!(result.is_ok(), "{}: {:?}", definitions, result);
+
+            let generated = std::fs::read_to_string(&output).unwrap();
+            let expected = format!("#[asn({})]\npub struct Values(", attributes);
+            assert!(
+                generated.contains(&expected),
+                "{}: {}",
+                definitions,
+                generated
+            );
+        }
+    }
+
+    #[test]
+    fn value_set_assignments() {
+        let base = "maxCode INTEGER ::= 20 \
+                    Codes INTEGER ::= { 1 | 3 | 10..maxCode } \
+                    Container {INTEGER : Allowed} ::= SEQUENCE { code INTEGER (Allowed) } \
+                    Ranged {INTEGER : upper} INTEGER ::= { 1..upper } ";
+
+        let test_cases = vec![
+            (
+                "Values INTEGER ::= { 1 | 2 | 5..9, ... }",
+                vec![
+                    r#"#[asn(type = "INTEGER", lb = "1", ub = "9", extensible = true)]"#,
+                    "pub const ROOT_VALUES: &'static [std::ops::RangeInclusive<u8>] = &[1..=2, 5..=9];",
+                    "pub fn is_allowed(value: u8) -> bool",
+                ],
+            ),
+            (
+                "Values INTEGER (0..100) ::= { Codes EXCEPT 3 }",
+                vec![
+                    r#"#[asn(type = "INTEGER", lb = "1", ub = "20", validate = true)]"#,
+                    "pub const ROOT_VALUES: &'static [std::ops::RangeInclusive<u8>] = &[1..=1, 10..=20];",
+                    "pub fn validate(&self) -> Result<(), String>",
+                ],
+            ),
+            (
+                "Values INTEGER ::= { -5..-1 | 10..MAX }",
+                vec!["pub const ROOT_VALUES: &'static [std::ops::RangeInclusive<i64>] = &[-5..=-1, 10..=i64::MAX];"],
+            ),
+            (
+                "Values ::= SEQUENCE { a Container {Codes}, b Container {{ 3 | 4 }}, c Ranged {maxCode} }",
+                vec![
+                    r#"#[asn(type = "INTEGER", lb = "1", ub = "20")]
+pub struct ValuesACode("#,
+                    r#"#[asn(type = "INTEGER", lb = "3", ub = "4")]
+pub struct ValuesBCode("#,
+                    r#"#[asn(type = "INTEGER", lb = "1", ub = "20")]
+pub struct ValuesC("#,
+                ],
+            ),
+        ];
+
+        let output = std::env::temp_dir().join("value_set_assignments.rs");
+        for (definition, expected) in test_cases {
+            let module_str = format!(
+                "{} {}",
+                super::get_module_header("ValueSetAssignments", 19),
+                super::get_module_definitions(&format!("{} {}", base, definition))
+            );
+
+            let mut compiler = Asn1Compiler::new(
+                output.to_str().unwrap(),
+                &Visibility::Public,
+                vec![Codec::Aper],
+                vec![Derive::Debug],
+            );
+            let result = compiler.compile_string(&module_str);
+            assert!(result.is_ok(), "{}: {:?}", definition, result);
+
+            let generated = std::fs::read_to_string(&output).unwrap();
+            for expected in expected {
+                assert!(
+                    generated.contains(expected),
+                    "{}: {}",
+                    definition,
+                    generated
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn value_notation() {
+        let base = "id-base OBJECT IDENTIFIER ::= { iso member-body(2) 840 } \
+                    id-sub OBJECT IDENTIFIER ::= { id-base 5 } \
+                    Point ::= SEQUENCE { x INTEGER (0..255), y INTEGER (0..255) OPTIONAL, \
+                                         flag BOOLEAN DEFAULT TRUE } \
+                    Shape ::= CHOICE { point Point, radius INTEGER (0..255) } \
+                    origin Point ::= { x 0, y 0 } ";
+
+        let test_cases = vec![
+            (
+                "Values ::= SEQUENCE { oid OBJECT IDENTIFIER DEFAULT { id-sub 7 } }",
+                vec!["ValuesOid(vec![1, 2, 840, 5, 7])"],
+            ),
+            (
+                "Values ::= SEQUENCE { p Point DEFAULT { x 1, flag FALSE } }",
+                vec![
+                    r#"pub fn default_p() -> Point {
+        Point {
+            x: PointX(1u8),
+            y: None,
+            flag: Some(PointFlag(false)),
+        }
+    }"#,
+                ],
+            ),
+            (
+                "Values ::= SEQUENCE { s Shape DEFAULT point : origin }",
+                vec![
+                    r#"Shape::Point(Point {
+            x: PointX(0u8),
+            y: Some(PointY(0u8)),
+            flag: None,
+        })"#,
+                ],
+            ),
+            (
+                "Values ::= SEQUENCE { l SEQUENCE OF INTEGER (0..7) DEFAULT { 1, 2 } }",
+                vec!["ValuesL(vec![ValuesL_Entry(1u8), ValuesL_Entry(2u8)])"],
+            ),
+            (
+                "Values ::= SEQUENCE { q SEQUENCE { a INTEGER (0..3), b CHOICE { c BOOLEAN, d NULL } } \
+                                       DEFAULT { a 2, b d : NULL } }",
+                vec!["b: ValuesQB::D(ValuesQB_d),"],
+            ),
+            (
+                "Values ::= SEQUENCE { p INTEGER { low(0), high(7) } (0..7) DEFAULT high }",
+                vec!["ValuesP(7u8)"],
+            ),
+        ];
+
+        let output = std::env::temp_dir().join("value_notation.rs");
+        for (definition, expected) in test_cases {
+            let module_str = format!(
+                "{} {}",
+                super::get_module_header("ValueNotation", 20),
+                super::get_module_definitions(&format!("{} {}", base, definition))
+            );
+
+            let mut compiler = Asn1Compiler::new(
+                output.to_str().unwrap(),
+                &Visibility::Public,
+                vec![Codec::Aper],
+                vec![Derive::Debug],
+            );
+            let result = compiler.compile_string(&module_str);
+            assert!(result.is_ok(), "{}: {:?}", definition, result);
+
+            let generated = std::fs::read_to_string(&output).unwrap();
+            for expected in expected {
+                assert!(
+                    generated.contains(expected),
+                    "{}: {}",
+                    definition,
+                    generated
+                );
+            }
+        }
+
+        // Values that do not match their types.
+        let failures = vec![
+            "bad Point ::= { y 1 }",
+            "bad Point ::= { flag FALSE, x 1 }",
+            "bad Point ::= { x 1, z 2 }",
+            "bad Shape ::= circle : 1",
+            "bad BOOLEAN ::= 1",
+            "bad OBJECT IDENTIFIER ::= { unknown-arc 1 }",
+        ];
+        for definition in failures {
+            let module_str = format!(
+                "{} {}",
+                super::get_module_header("ValueNotation", 20),
+                super::get_module_definitions(&format!("{} {}", base, definition))
+            );
+
+            let mut compiler = Asn1Compiler::new(
+                output.to_str().unwrap(),
+                &Visibility::Public,
+                vec![Codec::Aper],
+                vec![Derive::Debug],
+            );
+            let result = compiler.compile_string(&module_str);
+            assert!(result.is_err(), "{}", definition);
+        }
     }
 }
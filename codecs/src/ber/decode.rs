@@ -0,0 +1,735 @@
+//! Decode APIs for BER Codec
+
+use bitvec::prelude::*;
+
+use crate::ber::{BerCodecData, BerCodecError, Tag, TagClass, Tagging};
+
+use super::encode::check_time_value;
+
+/// Decode the Identifier octets
+///
+/// Returns the decoded Tag and whether the encoding is a 'constructed' encoding.
+pub fn decode_tag(data: &mut BerCodecData) -> Result<(Tag, bool), BerCodecError> {
+    let first = data.get_byte()?;
+
+    let class = match first >> 6 {
+        0 => TagClass::Universal,
+        1 => TagClass::Application,
+        2 => TagClass::ContextSpecific,
+        _ => TagClass::Private,
+    };
+    let constructed = first & 0x20 == 0x20;
+
+    let number = if first & 0x1f != 0x1f {
+        (first & 0x1f) as u32
+    } else {
+        let mut number: u32 = 0;
+        loop {
+            let octet = data.get_byte()?;
+            if number == 0 && octet == 0x80 {
+                return Err(BerCodecError::new(
+                    "BerCodec:DecodeError:Tag number has leading zero octets.",
+                ));
+            }
+            if number > (u32::MAX >> 7) {
+                return Err(BerCodecError::new(
+                    "BerCodec:DecodeError:Tag number too large.",
+                ));
+            }
+            number = (number << 7) | (octet & 0x7f) as u32;
+            if octet & 0x80 == 0 {
+                break;
+            }
+        }
+        if data.der && number < 31 {
+            return Err(BerCodecError::new(format!(
+                "DerCodec:DecodeError:High Tag number form used for Tag number {}.",
+                number
+            )));
+        }
+        number
+    };
+
+    let tag = Tag::new(class, number);
+    log::trace!("decode_tag: tag: {}, constructed: {}", tag, constructed);
+
+    Ok((tag, constructed))
+}
+
+/// Peek the Identifier octets without advancing the decode offset.
+///
+/// Returns `None` if there is no more data to be decoded. This is useful for decoding `OPTIONAL`
+/// components of a `SEQUENCE` or the alternatives of a `CHOICE`.
+pub fn peek_tag(data: &mut BerCodecData) -> Result<Option<(Tag, bool)>, BerCodecError> {
+    if data.is_empty() {
+        return Ok(None);
+    }
+
+    let offset = data.decode_offset;
+    let result = decode_tag(data);
+    data.decode_offset = offset;
+
+    result.map(Some)
+}
+
+/// Decode the Length octets
+///
+/// Returns `None` for the indefinite form of the length.
+pub fn decode_length(data: &mut BerCodecData) -> Result<Option<usize>, BerCodecError> {
+    let first = data.get_byte()?;
+
+    let length = if first < 0x80 {
+        Some(first as usize)
+    } else if first == 0x80 {
+        None
+    } else if first == 0xff {
+        return Err(BerCodecError::new(
+            "BerCodec:DecodeError:Reserved value 0xFF for the Length octet.",
+        ));
+    } else {
+        let count = (first & 0x7f) as usize;
+        let octets = data.get_bytes(count)?;
+        if data.der && (octets[0] == 0 || (count == 1 && octets[0] < 0x80)) {
+            return Err(BerCodecError::new(
+                "DerCodec:DecodeError:Length not encoded in the minimum number of octets.",
+            ));
+        }
+        let mut length: usize = 0;
+        for octet in octets {
+            if length > (usize::MAX >> 8) {
+                return Err(BerCodecError::new("BerCodec:DecodeError:Length too large."));
+            }
+            length = (length << 8) | octet as usize;
+        }
+        Some(length)
+    };
+    log::trace!("decode_length: length: {:?}", length);
+
+    Ok(length)
+}
+
+/// Decode a Tag, Length and Value triplet.
+///
+/// Returns the decoded Tag, whether the encoding is 'constructed' and the contents octets as a
+/// `BerCodecData` that can be used for further decoding of the contents. The 'key' of the input
+/// `data` is carried to the contents.
+pub fn decode_tlv(data: &mut BerCodecData) -> Result<(Tag, bool, BerCodecData), BerCodecError> {
+    let (tag, constructed) = decode_tag(data)?;
+
+    let start = data.decode_offset;
+    let (length, skip) = match decode_length(data)? {
+        Some(length) => (length, 0),
+        None => {
+            if data.der {
+                return Err(BerCodecError::new(format!(
+                    "DerCodec:DecodeError:Indefinite Length used for Tag {}.",
+                    tag
+                )));
+            }
+            if !constructed {
+                return Err(BerCodecError::new(format!(
+                    "BerCodec:DecodeError:Indefinite Length for primitive encoding of Tag {}.",
+                    tag
+                )));
+            }
+            let contents_start = data.decode_offset;
+            loop {
+                if data.remaining() >= 2
+                    && data.bytes[data.decode_offset] == 0
+                    && data.bytes[data.decode_offset + 1] == 0
+                {
+                    break;
+                }
+                let _ = decode_tlv(data)?;
+            }
+            let length = data.decode_offset - contents_start;
+            data.decode_offset = contents_start;
+            (length, 2)
+        }
+    };
+
+    let mut contents = BerCodecData::from_slice_internal(&data.get_bytes(length)?, data.der);
+    contents.key = data.key;
+    data.decode_offset += skip;
+
+    log::trace!(
+        "decode_tlv: tag: {}, constructed: {}, encoded length: {}",
+        tag,
+        constructed,
+        data.decode_offset - start
+    );
+
+    Ok((tag, constructed, contents))
+}
+
+/// Skip a Tag, Length and Value triplet
+///
+/// This is useful for ignoring the unknown extensions during decoding.
+pub fn skip_tlv(data: &mut BerCodecData) -> Result<(), BerCodecError> {
+    let _ = decode_tlv(data)?;
+    Ok(())
+}
+
+/// Decode a Constructed encoding with the given Tag.
+///
+/// Returns the contents as a `BerCodecData` which is used to decode the components of a
+/// constructed type or the inner type of an 'explicitly' tagged type.
+pub fn decode_constructed(
+    data: &mut BerCodecData,
+    tag: Tag,
+) -> Result<BerCodecData, BerCodecError> {
+    let (decoded_tag, constructed, contents) = decode_tlv(data)?;
+    check_tag(tag, decoded_tag)?;
+
+    if !constructed {
+        return Err(BerCodecError::new(format!(
+            "BerCodec:DecodeError:Expected Constructed encoding for Tag {}.",
+            tag
+        )));
+    }
+
+    Ok(contents)
+}
+
+/// Decode a value of a Tagged Type
+///
+/// The constructed encodings for the 'explicit' `taggings` are decoded first and the value is
+/// decoded from their contents using `decode`. The tag of the last 'implicit' tagging (if any) is
+/// passed to `decode` to replace the tag of the type.
+pub fn decode_with_tagging<T, F>(
+    data: &mut BerCodecData,
+    taggings: &[Tagging],
+    decode: F,
+) -> Result<T, BerCodecError>
+where
+    F: FnOnce(&mut BerCodecData, Option<Tag>) -> Result<T, BerCodecError>,
+{
+    match taggings.split_first() {
+        None => decode(data, None),
+        Some((tagging, _)) if !tagging.explicit => decode(data, Some(tagging.tag)),
+        Some((tagging, rest)) => {
+            let mut contents = decode_constructed(data, tagging.tag)?;
+            let decoded = decode_with_tagging(&mut contents, rest, decode)?;
+            if !contents.is_empty() {
+                return Err(BerCodecError::new(format!(
+                    "BerCodec:DecodeError:{} bytes remaining after decoding the contents of Tag {}.",
+                    contents.remaining(),
+                    tagging.tag
+                )));
+            }
+            Ok(decoded)
+        }
+    }
+}
+
+/// Decode a Primitive encoding with the given Tag and return the contents octets.
+pub fn decode_primitive(data: &mut BerCodecData, tag: Tag) -> Result<Vec<u8>, BerCodecError> {
+    let (decoded_tag, constructed, contents) = decode_tlv(data)?;
+    check_tag(tag, decoded_tag)?;
+
+    if constructed {
+        return Err(BerCodecError::new(format!(
+            "BerCodec:DecodeError:Expected Primitive encoding for Tag {}.",
+            tag
+        )));
+    }
+
+    Ok(contents.into_bytes())
+}
+
+/// Decode an INTEGER
+///
+/// Note: The maximum (and minimum) value to be decoded is limited to an `i128` value.
+pub fn decode_integer(data: &mut BerCodecData, tag: Option<Tag>) -> Result<i128, BerCodecError> {
+    log::trace!("decode_integer: tag: {:?}", tag);
+
+    let contents = decode_primitive(data, tag.unwrap_or(Tag::INTEGER))?;
+    integer_from_contents_octets(&contents, data.der)
+}
+
+/// Decode an ENUMERATED Value
+pub fn decode_enumerated(data: &mut BerCodecData, tag: Option<Tag>) -> Result<i128, BerCodecError> {
+    log::trace!("decode_enumerated: tag: {:?}", tag);
+
+    let contents = decode_primitive(data, tag.unwrap_or(Tag::ENUMERATED))?;
+    integer_from_contents_octets(&contents, data.der)
+}
+
+/// Decode a BOOLEAN Value
+///
+/// Any non-zero value of the contents octet is decoded as `true`. For DER, only `0xFF` is a valid
+/// encoding of `true`.
+pub fn decode_bool(data: &mut BerCodecData, tag: Option<Tag>) -> Result<bool, BerCodecError> {
+    log::trace!("decode_bool: tag: {:?}", tag);
+
+    let contents = decode_primitive(data, tag.unwrap_or(Tag::BOOLEAN))?;
+    if contents.len() != 1 {
+        return Err(BerCodecError::new(format!(
+            "BerCodec:DecodeError:Invalid Length {} for a BOOLEAN.",
+            contents.len()
+        )));
+    }
+
+    if data.der && contents[0] != 0 && contents[0] != 0xFF {
+        return Err(BerCodecError::new(format!(
+            "DerCodec:DecodeError:Invalid value 0x{:02x} for a BOOLEAN.",
+            contents[0]
+        )));
+    }
+
+    Ok(contents[0] != 0)
+}
+
+/// Decode a NULL Value
+pub fn decode_null(data: &mut BerCodecData, tag: Option<Tag>) -> Result<(), BerCodecError> {
+    log::trace!("decode_null: tag: {:?}", tag);
+
+    let contents = decode_primitive(data, tag.unwrap_or(Tag::NULL))?;
+    if !contents.is_empty() {
+        return Err(BerCodecError::new(format!(
+            "BerCodec:DecodeError:Invalid Length {} for a NULL.",
+            contents.len()
+        )));
+    }
+
+    Ok(())
+}
+
+/// Decode a BIT STRING
+///
+/// Both the Primitive and the Constructed encodings are supported. For DER, only the Primitive
+/// encoding with the unused bits set to zero is supported.
+pub fn decode_bitstring(
+    data: &mut BerCodecData,
+    tag: Option<Tag>,
+) -> Result<BitVec<u8, Msb0>, BerCodecError> {
+    log::trace!("decode_bitstring: tag: {:?}", tag);
+
+    let (decoded_tag, constructed, mut contents) = decode_tlv(data)?;
+    check_tag(tag.unwrap_or(Tag::BIT_STRING), decoded_tag)?;
+
+    if !constructed {
+        return bitstring_from_contents_octets(&contents.into_bytes(), data.der);
+    }
+    if data.der {
+        return Err(BerCodecError::new(
+            "DerCodec:DecodeError:Constructed encoding used for a BIT STRING.",
+        ));
+    }
+
+    let mut bits = BitVec::new();
+    while !contents.is_empty() {
+        if bits.len() % 8 != 0 {
+            return Err(BerCodecError::new(
+                "BerCodec:DecodeError:Unused bits in a segment other than the final segment.",
+            ));
+        }
+        bits.extend(decode_bitstring(&mut contents, None)?);
+    }
+
+    Ok(bits)
+}
+
+/// Decode an OCTET STRING
+///
+/// Both the Primitive and the Constructed encodings are supported. For DER, only the Primitive
+/// encoding is supported.
+pub fn decode_octetstring(
+    data: &mut BerCodecData,
+    tag: Option<Tag>,
+) -> Result<Vec<u8>, BerCodecError> {
+    log::trace!("decode_octetstring: tag: {:?}", tag);
+
+    decode_string_octets(data, tag.unwrap_or(Tag::OCTET_STRING))
+}
+
+/// Decode a UTF8String
+pub fn decode_utf8_string(
+    data: &mut BerCodecData,
+    tag: Option<Tag>,
+) -> Result<String, BerCodecError> {
+    log::trace!("decode_utf8_string: tag: {:?}", tag);
+
+    let octets = decode_string_octets(data, tag.unwrap_or(Tag::UTF8_STRING))?;
+    String::from_utf8(octets)
+        .map_err(|e| BerCodecError::new(format!("BerCodec:DecodeError:Invalid UTF8String: {}", e)))
+}
+
+/// Decode a PrintableString
+pub fn decode_printable_string(
+    data: &mut BerCodecData,
+    tag: Option<Tag>,
+) -> Result<String, BerCodecError> {
+    log::trace!("decode_printable_string: tag: {:?}", tag);
+
+    let octets = decode_string_octets(data, tag.unwrap_or(Tag::PRINTABLE_STRING))?;
+    if let Some(c) = octets
+        .iter()
+        .find(|c| !super::encode::is_printable_char(**c as char))
+    {
+        return Err(BerCodecError::new(format!(
+            "BerCodec:DecodeError:Invalid character 0x{:02x} in a PrintableString.",
+            c
+        )));
+    }
+
+    Ok(octets.into_iter().map(|c| c as char).collect())
+}
+
+/// Decode a VisibleString
+pub fn decode_visible_string(
+    data: &mut BerCodecData,
+    tag: Option<Tag>,
+) -> Result<String, BerCodecError> {
+    log::trace!("decode_visible_string: tag: {:?}", tag);
+
+    let octets = decode_string_octets(data, tag.unwrap_or(Tag::VISIBLE_STRING))?;
+    if let Some(c) = octets.iter().find(|c| !(0x20..=0x7e).contains(*c)) {
+        return Err(BerCodecError::new(format!(
+            "BerCodec:DecodeError:Invalid character 0x{:02x} in a VisibleString.",
+            c
+        )));
+    }
+
+    Ok(octets.into_iter().map(|c| c as char).collect())
+}
+
+/// Decode an IA5String
+pub fn decode_ia5_string(
+    data: &mut BerCodecData,
+    tag: Option<Tag>,
+) -> Result<String, BerCodecError> {
+    log::trace!("decode_ia5_string: tag: {:?}", tag);
+
+    let octets = decode_string_octets(data, tag.unwrap_or(Tag::IA5_STRING))?;
+    string_from_contents_octets(&octets, 1, "IA5String", |c| c.is_ascii())
+}
+
+/// Decode a NumericString
+pub fn decode_numeric_string(
+    data: &mut BerCodecData,
+    tag: Option<Tag>,
+) -> Result<String, BerCodecError> {
+    log::trace!("decode_numeric_string: tag: {:?}", tag);
+
+    let octets = decode_string_octets(data, tag.unwrap_or(Tag::NUMERIC_STRING))?;
+    string_from_contents_octets(&octets, 1, "NumericString", |c| {
+        c == ' ' || c.is_ascii_digit()
+    })
+}
+
+/// Decode a BMPString
+pub fn decode_bmp_string(
+    data: &mut BerCodecData,
+    tag: Option<Tag>,
+) -> Result<String, BerCodecError> {
+    log::trace!("decode_bmp_string: tag: {:?}", tag);
+
+    let octets = decode_string_octets(data, tag.unwrap_or(Tag::BMP_STRING))?;
+    string_from_contents_octets(&octets, 2, "BMPString", |_| true)
+}
+
+/// Decode a UniversalString
+pub fn decode_universal_string(
+    data: &mut BerCodecData,
+    tag: Option<Tag>,
+) -> Result<String, BerCodecError> {
+    log::trace!("decode_universal_string: tag: {:?}", tag);
+
+    let octets = decode_string_octets(data, tag.unwrap_or(Tag::UNIVERSAL_STRING))?;
+    string_from_contents_octets(&octets, 4, "UniversalString", |_| true)
+}
+
+/// Decode a TeletexString
+pub fn decode_teletex_string(
+    data: &mut BerCodecData,
+    tag: Option<Tag>,
+) -> Result<String, BerCodecError> {
+    log::trace!("decode_teletex_string: tag: {:?}", tag);
+
+    let octets = decode_string_octets(data, tag.unwrap_or(Tag::TELETEX_STRING))?;
+    Ok(octets.into_iter().map(|c| c as char).collect())
+}
+
+/// Decode a GeneralString
+pub fn decode_general_string(
+    data: &mut BerCodecData,
+    tag: Option<Tag>,
+) -> Result<String, BerCodecError> {
+    log::trace!("decode_general_string: tag: {:?}", tag);
+
+    let octets = decode_string_octets(data, tag.unwrap_or(Tag::GENERAL_STRING))?;
+    Ok(octets.into_iter().map(|c| c as char).collect())
+}
+
+/// Decode a UTCTime
+pub fn decode_utc_time(data: &mut BerCodecData, tag: Option<Tag>) -> Result<String, BerCodecError> {
+    log::trace!("decode_utc_time: tag: {:?}", tag);
+
+    let octets = decode_string_octets(data, tag.unwrap_or(Tag::UTC_TIME))?;
+    let value = String::from_utf8(octets)
+        .map_err(|_| BerCodecError::new("BerCodec:DecodeError:Invalid characters in UTCTime."))?;
+    check_time_value(&value, Tag::UTC_TIME, data.der)?;
+
+    Ok(value)
+}
+
+/// Decode a GeneralizedTime
+pub fn decode_generalized_time(
+    data: &mut BerCodecData,
+    tag: Option<Tag>,
+) -> Result<String, BerCodecError> {
+    log::trace!("decode_generalized_time: tag: {:?}", tag);
+
+    let octets = decode_string_octets(data, tag.unwrap_or(Tag::GENERALIZED_TIME))?;
+    let value = String::from_utf8(octets).map_err(|_| {
+        BerCodecError::new("BerCodec:DecodeError:Invalid characters in GeneralizedTime.")
+    })?;
+    check_time_value(&value, Tag::GENERALIZED_TIME, data.der)?;
+
+    Ok(value)
+}
+
+/// Decode a DATE
+pub fn decode_date(data: &mut BerCodecData, tag: Option<Tag>) -> Result<String, BerCodecError> {
+    log::trace!("decode_date: tag: {:?}", tag);
+
+    let octets = decode_string_octets(data, tag.unwrap_or(Tag::DATE))?;
+    let value = String::from_utf8(octets)
+        .map_err(|_| BerCodecError::new("BerCodec:DecodeError:Invalid characters in DATE."))?;
+    check_time_value(&value, Tag::DATE, data.der)?;
+
+    Ok(value)
+}
+
+/// Decode a TIME-OF-DAY
+pub fn decode_time_of_day(
+    data: &mut BerCodecData,
+    tag: Option<Tag>,
+) -> Result<String, BerCodecError> {
+    log::trace!("decode_time_of_day: tag: {:?}", tag);
+
+    let octets = decode_string_octets(data, tag.unwrap_or(Tag::TIME_OF_DAY))?;
+    let value = String::from_utf8(octets).map_err(|_| {
+        BerCodecError::new("BerCodec:DecodeError:Invalid characters in TIME-OF-DAY.")
+    })?;
+    check_time_value(&value, Tag::TIME_OF_DAY, data.der)?;
+
+    Ok(value)
+}
+
+/// Decode a DATE-TIME
+pub fn decode_date_time(
+    data: &mut BerCodecData,
+    tag: Option<Tag>,
+) -> Result<String, BerCodecError> {
+    log::trace!("decode_date_time: tag: {:?}", tag);
+
+    let octets = decode_string_octets(data, tag.unwrap_or(Tag::DATE_TIME))?;
+    let value = String::from_utf8(octets)
+        .map_err(|_| BerCodecError::new("BerCodec:DecodeError:Invalid characters in DATE-TIME."))?;
+    check_time_value(&value, Tag::DATE_TIME, data.der)?;
+
+    Ok(value)
+}
+
+/// Decode a DURATION
+pub fn decode_duration(data: &mut BerCodecData, tag: Option<Tag>) -> Result<String, BerCodecError> {
+    log::trace!("decode_duration: tag: {:?}", tag);
+
+    let octets = decode_string_octets(data, tag.unwrap_or(Tag::DURATION))?;
+    let value = String::from_utf8(octets)
+        .map_err(|_| BerCodecError::new("BerCodec:DecodeError:Invalid characters in DURATION."))?;
+    check_time_value(&value, Tag::DURATION, data.der)?;
+
+    Ok(value)
+}
+
+// Decodes the characters of a character string type with each of the characters encoded in
+// `octets_per_char` octets.
+fn string_from_contents_octets(
+    octets: &[u8],
+    octets_per_char: usize,
+    type_name: &str,
+    is_valid: fn(char) -> bool,
+) -> Result<String, BerCodecError> {
+    let chars = octets.chunks_exact(octets_per_char);
+    if !chars.remainder().is_empty() {
+        return Err(BerCodecError::new(format!(
+            "BerCodec:DecodeError:Length {} is not a multiple of {} for a {}.",
+            octets.len(),
+            octets_per_char,
+            type_name
+        )));
+    }
+
+    chars
+        .map(|c| {
+            let value = c
+                .iter()
+                .fold(0_u32, |value, octet| (value << 8) | *octet as u32);
+            char::from_u32(value)
+                .filter(|c| is_valid(*c))
+                .ok_or_else(|| {
+                    BerCodecError::new(format!(
+                        "BerCodec:DecodeError:Invalid character 0x{:02x} in a {}.",
+                        value, type_name
+                    ))
+                })
+        })
+        .collect()
+}
+
+// Decodes the contents of the OCTET STRING and the restricted character string types. For the
+// constructed encoding, each of the segments is an OCTET STRING encoding (X.690 8.7.3 and 8.23.6)
+fn decode_string_octets(data: &mut BerCodecData, tag: Tag) -> Result<Vec<u8>, BerCodecError> {
+    let (decoded_tag, constructed, mut contents) = decode_tlv(data)?;
+    check_tag(tag, decoded_tag)?;
+
+    if !constructed {
+        return Ok(contents.into_bytes());
+    }
+    if data.der {
+        return Err(BerCodecError::new(format!(
+            "DerCodec:DecodeError:Constructed encoding used for Tag {}.",
+            tag
+        )));
+    }
+
+    let mut octets = vec![];
+    while !contents.is_empty() {
+        octets.extend(decode_string_octets(&mut contents, Tag::OCTET_STRING)?);
+    }
+
+    Ok(octets)
+}
+
+/// Checks the order of the elements of a `SET OF` in the strict DER mode.
+///
+/// The elements should be sorted in the ascending order of their encodings. Does nothing if the
+/// `contents` are not decoded using the strict DER rules.
+pub fn check_set_of_order(contents: &BerCodecData) -> Result<(), BerCodecError> {
+    if !contents.der {
+        return Ok(());
+    }
+
+    let encodings = tlv_encodings(contents)?;
+    for pair in encodings.windows(2) {
+        if super::encode::compare_padded(&pair[0], &pair[1]) == std::cmp::Ordering::Greater {
+            return Err(BerCodecError::new(
+                "DerCodec:DecodeError:Elements of a SET OF are not sorted.",
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks the order of the components of a `SET` in the strict DER mode.
+///
+/// The components should be in the canonical order of their tags. Does nothing if the `contents`
+/// are not decoded using the strict DER rules.
+pub fn check_set_order(contents: &BerCodecData) -> Result<(), BerCodecError> {
+    if !contents.der {
+        return Ok(());
+    }
+
+    let mut tags = vec![];
+    for encoding in tlv_encodings(contents)? {
+        let (tag, _) = decode_tag(&mut BerCodecData::from_slice(&encoding))?;
+        tags.push(tag);
+    }
+    for pair in tags.windows(2) {
+        if pair[0] >= pair[1] {
+            return Err(BerCodecError::new(format!(
+                "DerCodec:DecodeError:Components of a SET not in canonical order. Tag {} before Tag {}.",
+                pair[0], pair[1]
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+// Returns the complete encodings of all the remaining TLVs in the `data` without consuming them.
+fn tlv_encodings(data: &BerCodecData) -> Result<Vec<Vec<u8>>, BerCodecError> {
+    let mut data = BerCodecData::from_slice_internal(&data.bytes[data.decode_offset..], data.der);
+
+    let mut encodings = vec![];
+    while !data.is_empty() {
+        let start = data.decode_offset;
+        skip_tlv(&mut data)?;
+        encodings.push(data.bytes[start..data.decode_offset].to_vec());
+    }
+
+    Ok(encodings)
+}
+
+fn check_tag(expected: Tag, decoded: Tag) -> Result<(), BerCodecError> {
+    if expected != decoded {
+        Err(BerCodecError::new(format!(
+            "BerCodec:DecodeError:Expected Tag {}, Found Tag {}.",
+            expected, decoded
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+pub(crate) fn integer_from_contents_octets(
+    contents: &[u8],
+    strict: bool,
+) -> Result<i128, BerCodecError> {
+    if contents.is_empty() || contents.len() > 16 {
+        return Err(BerCodecError::new(format!(
+            "BerCodec:DecodeError:Unsupported Length {} for an INTEGER.",
+            contents.len()
+        )));
+    }
+
+    if strict
+        && contents.len() > 1
+        && ((contents[0] == 0x00 && contents[1] & 0x80 == 0)
+            || (contents[0] == 0xFF && contents[1] & 0x80 != 0))
+    {
+        return Err(BerCodecError::new(
+            "DerCodec:DecodeError:INTEGER not encoded in the minimum number of octets.",
+        ));
+    }
+
+    let mut value: i128 = if contents[0] & 0x80 == 0x80 { -1 } else { 0 };
+    for octet in contents {
+        value = (value << 8) | *octet as i128;
+    }
+
+    Ok(value)
+}
+
+pub(crate) fn bitstring_from_contents_octets(
+    contents: &[u8],
+    strict: bool,
+) -> Result<BitVec<u8, Msb0>, BerCodecError> {
+    if contents.is_empty() {
+        return Err(BerCodecError::new(
+            "BerCodec:DecodeError:Missing initial octet for a BIT STRING.",
+        ));
+    }
+
+    let unused = contents[0] as usize;
+    if unused > 7 || (contents.len() == 1 && unused != 0) {
+        return Err(BerCodecError::new(format!(
+            "BerCodec:DecodeError:Invalid number of unused bits {} for a BIT STRING.",
+            unused
+        )));
+    }
+
+    let mut bits = BitVec::<u8, Msb0>::from_slice(&contents[1..]);
+    if strict && bits[bits.len() - unused..].any() {
+        return Err(BerCodecError::new(
+            "DerCodec:DecodeError:Unused bits of a BIT STRING are not zero.",
+        ));
+    }
+    bits.truncate(bits.len() - unused);
+
+    Ok(bits)
+}
@@ -0,0 +1,131 @@
+//! ASN.1 DER Codec
+//!
+//! The Distinguished Encoding Rules (X.690 Section 10 and 11) are a restricted form of BER, where
+//! there is exactly one encoding for any given value. The encoder uses the definite form of
+//! length in the minimum number of octets, the primitive form for the string types, the
+//! components of a `SET` in the canonical order of their tags and the elements of a `SET OF`
+//! sorted by their encodings.
+//!
+//! A `BerCodecData` created using [`BerCodecData::from_slice_der`] decodes in a strict mode and
+//! any encoding that violates these rules is rejected.
+
+use crate::ber::{BerCodecData, BerCodecError, Tag};
+
+/// Trait representing a 'DER Codec'.
+///
+/// This 'trait' is to be derived by any `struct` or `enum` representing an ASN.1 Type. See
+/// [`crate::ber::BerCodec`] for the meaning of the `tag` parameter.
+pub trait DerCodec {
+    type Output;
+
+    fn der_decode_tagged(
+        data: &mut BerCodecData,
+        tag: Option<Tag>,
+    ) -> Result<Self::Output, BerCodecError>;
+
+    fn der_encode_tagged(
+        &self,
+        data: &mut BerCodecData,
+        tag: Option<Tag>,
+    ) -> Result<(), BerCodecError>;
+
+    fn der_decode(data: &mut BerCodecData) -> Result<Self::Output, BerCodecError> {
+        Self::der_decode_tagged(data, None)
+    }
+
+    fn der_encode(&self, data: &mut BerCodecData) -> Result<(), BerCodecError> {
+        self.der_encode_tagged(data, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ber::{decode, encode, BerCodecData, Tag};
+
+    #[test]
+    fn strict_length() {
+        // Long form for a length less than 128.
+        let mut d = BerCodecData::from_slice(&[0x02, 0x81, 0x01, 0x05]);
+        assert_eq!(decode::decode_integer(&mut d, None).unwrap(), 5);
+        let mut d = BerCodecData::from_slice_der(&[0x02, 0x81, 0x01, 0x05]);
+        assert!(decode::decode_integer(&mut d, None).is_err());
+
+        // Indefinite length
+        let encoded = [0x30, 0x80, 0x02, 0x01, 0x05, 0x00, 0x00];
+        let mut d = BerCodecData::from_slice(&encoded);
+        assert!(decode::decode_constructed(&mut d, Tag::SEQUENCE).is_ok());
+        let mut d = BerCodecData::from_slice_der(&encoded);
+        assert!(decode::decode_constructed(&mut d, Tag::SEQUENCE).is_err());
+    }
+
+    #[test]
+    fn strict_primitives() {
+        let mut d = BerCodecData::from_slice_der(&[0x01, 0x01, 0x01]);
+        assert!(decode::decode_bool(&mut d, None).is_err());
+
+        let mut d = BerCodecData::from_slice_der(&[0x02, 0x02, 0x00, 0x05]);
+        assert!(decode::decode_integer(&mut d, None).is_err());
+
+        let mut d = BerCodecData::from_slice_der(&[0x03, 0x02, 0x04, 0xbf]);
+        assert!(decode::decode_bitstring(&mut d, None).is_err());
+
+        let mut d = BerCodecData::from_slice_der(&[0x24, 0x03, 0x04, 0x01, 0x01]);
+        assert!(decode::decode_octetstring(&mut d, None).is_err());
+    }
+
+    #[test]
+    fn set_of_sorted() {
+        let mut elements = vec![];
+        for value in [256, 3, -1] {
+            let mut element = BerCodecData::new();
+            encode::encode_integer(&mut element, None, value).unwrap();
+            elements.push(element);
+        }
+        let mut d = BerCodecData::new();
+        encode::encode_set_of_sorted(&mut d, Tag::SET, elements).unwrap();
+        let encoded = d.into_bytes();
+        assert_eq!(
+            encoded,
+            vec![0x31, 0x0a, 0x02, 0x01, 0x03, 0x02, 0x01, 0xff, 0x02, 0x02, 0x01, 0x00]
+        );
+
+        let mut d = BerCodecData::from_slice_der(&encoded);
+        let contents = decode::decode_constructed(&mut d, Tag::SET).unwrap();
+        assert!(decode::check_set_of_order(&contents).is_ok());
+
+        let unsorted = [0x31, 0x06, 0x02, 0x01, 0xff, 0x02, 0x01, 0x03];
+        let mut d = BerCodecData::from_slice_der(&unsorted);
+        let contents = decode::decode_constructed(&mut d, Tag::SET).unwrap();
+        assert!(decode::check_set_of_order(&contents).is_err());
+    }
+
+    #[test]
+    fn set_canonical_order() {
+        let mut components = vec![];
+        for (tag, value) in [
+            (Tag::context(2), 1),
+            (Tag::INTEGER, 2),
+            (Tag::context(0), 3),
+        ] {
+            let mut component = BerCodecData::new();
+            encode::encode_integer(&mut component, Some(tag), value).unwrap();
+            components.push(component);
+        }
+        let mut d = BerCodecData::new();
+        encode::encode_set_canonical(&mut d, Tag::SET, components).unwrap();
+        let encoded = d.into_bytes();
+        assert_eq!(
+            encoded,
+            vec![0x31, 0x09, 0x02, 0x01, 0x02, 0x80, 0x01, 0x03, 0x82, 0x01, 0x01]
+        );
+
+        let mut d = BerCodecData::from_slice_der(&encoded);
+        let contents = decode::decode_constructed(&mut d, Tag::SET).unwrap();
+        assert!(decode::check_set_order(&contents).is_ok());
+
+        let unsorted = [0x31, 0x06, 0x82, 0x01, 0x01, 0x80, 0x01, 0x03];
+        let mut d = BerCodecData::from_slice_der(&unsorted);
+        let contents = decode::decode_constructed(&mut d, Tag::SET).unwrap();
+        assert!(decode::check_set_order(&contents).is_err());
+    }
+}
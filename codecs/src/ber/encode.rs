@@ -0,0 +1,541 @@
+//! Encode APIs for BER Codec
+
+use bitvec::prelude::*;
+
+use crate::ber::{BerCodecData, BerCodecError, Tag, Tagging};
+use crate::time::{Date, DateTime, Duration, TimeOfDay};
+
+/// Encode the Identifier octets for a given Tag
+///
+/// Tag numbers less than 31 are encoded in a single octet, larger Tag numbers use the 'high tag
+/// number' form where the number is encoded in base 128 in the subsequent octets.
+pub fn encode_tag(data: &mut BerCodecData, tag: Tag, constructed: bool) {
+    log::trace!("encode_tag: tag: {}, constructed: {}", tag, constructed);
+
+    let mut first = (tag.class as u8) << 6;
+    if constructed {
+        first |= 0x20;
+    }
+
+    if tag.number < 31 {
+        data.append_bytes(&[first | tag.number as u8]);
+    } else {
+        let mut octets = vec![(tag.number & 0x7f) as u8];
+        let mut number = tag.number >> 7;
+        while number > 0 {
+            octets.push((number & 0x7f) as u8 | 0x80);
+            number >>= 7;
+        }
+        octets.push(first | 0x1f);
+        octets.reverse();
+        data.append_bytes(&octets);
+    }
+}
+
+/// Encode the Length octets
+///
+/// The definite form is always used for encoding. The short form is used for lengths less than
+/// 128 and the long form in the minimum number of octets for the others.
+pub fn encode_length(data: &mut BerCodecData, length: usize) {
+    log::trace!("encode_length: length: {}", length);
+
+    if length < 128 {
+        data.append_bytes(&[length as u8]);
+    } else {
+        let bytes = length.to_be_bytes();
+        let first = bytes.iter().position(|b| *b != 0).unwrap();
+        let count = bytes.len() - first;
+        data.append_bytes(&[0x80 | count as u8]);
+        data.append_bytes(&bytes[first..]);
+    }
+}
+
+/// Encode a Primitive encoding with given contents octets.
+pub fn encode_primitive(
+    data: &mut BerCodecData,
+    tag: Tag,
+    contents: &[u8],
+) -> Result<(), BerCodecError> {
+    encode_tag(data, tag, false);
+    encode_length(data, contents.len());
+    data.append_bytes(contents);
+
+    Ok(())
+}
+
+/// Encode a Constructed encoding
+///
+/// The `contents` are the encodings of the components of a constructed type (eg. `SEQUENCE`,
+/// `SEQUENCE OF`) or the encoding of the inner type for 'explicitly' tagged types.
+pub fn encode_constructed(
+    data: &mut BerCodecData,
+    tag: Tag,
+    contents: &BerCodecData,
+) -> Result<(), BerCodecError> {
+    log::trace!(
+        "encode_constructed: tag: {}, length: {}",
+        tag,
+        contents.length_in_bytes()
+    );
+
+    encode_tag(data, tag, true);
+    encode_length(data, contents.length_in_bytes());
+    data.append_bytes(&contents.bytes);
+
+    Ok(())
+}
+
+/// Encode a value of a Tagged Type
+///
+/// The value is encoded using `encode` inside the constructed encodings for the 'explicit'
+/// `taggings`. The tag of the last 'implicit' tagging (if any) is passed to `encode` to replace
+/// the tag of the type.
+pub fn encode_with_tagging<F>(
+    data: &mut BerCodecData,
+    taggings: &[Tagging],
+    encode: F,
+) -> Result<(), BerCodecError>
+where
+    F: FnOnce(&mut BerCodecData, Option<Tag>) -> Result<(), BerCodecError>,
+{
+    match taggings.split_first() {
+        None => encode(data, None),
+        Some((tagging, _)) if !tagging.explicit => encode(data, Some(tagging.tag)),
+        Some((tagging, rest)) => {
+            let mut contents = BerCodecData::new();
+            encode_with_tagging(&mut contents, rest, encode)?;
+            encode_constructed(data, tagging.tag, &contents)
+        }
+    }
+}
+
+/// Encode the elements of a `SET OF` sorted as required by DER
+///
+/// The `elements` are the individual encodings of each of the elements. These are sorted in the
+/// ascending order of their encodings (X.690 11.6), where a shorter encoding is treated as if it
+/// were padded at the end with zero octets.
+pub fn encode_set_of_sorted(
+    data: &mut BerCodecData,
+    tag: Tag,
+    mut elements: Vec<BerCodecData>,
+) -> Result<(), BerCodecError> {
+    log::trace!(
+        "encode_set_of_sorted: tag: {}, elements: {}",
+        tag,
+        elements.len()
+    );
+
+    elements.sort_by(|a, b| compare_padded(&a.bytes, &b.bytes));
+
+    let mut contents = BerCodecData::new();
+    for element in elements {
+        contents.append_bytes(&element.bytes);
+    }
+    encode_constructed(data, tag, &contents)
+}
+
+/// Encode the components of a `SET` in the canonical order as required by DER
+///
+/// The `components` are the individual encodings of each of the components, which are sorted in
+/// the canonical order of their tags (X.680 8.6). ie. `UNIVERSAL`, `APPLICATION`, Context
+/// Specific and `PRIVATE` and within each class in the ascending order of the Tag number.
+pub fn encode_set_canonical(
+    data: &mut BerCodecData,
+    tag: Tag,
+    components: Vec<BerCodecData>,
+) -> Result<(), BerCodecError> {
+    log::trace!(
+        "encode_set_canonical: tag: {}, components: {}",
+        tag,
+        components.len()
+    );
+
+    let mut tagged = vec![];
+    for component in components {
+        let (component_tag, _) =
+            crate::ber::decode::decode_tag(&mut BerCodecData::from_slice(&component.bytes))?;
+        tagged.push((component_tag, component));
+    }
+    tagged.sort_by_key(|(tag, _)| *tag);
+
+    let mut contents = BerCodecData::new();
+    for (_, component) in tagged {
+        contents.append_bytes(&component.bytes);
+    }
+    encode_constructed(data, tag, &contents)
+}
+
+/// Encode an INTEGER
+///
+/// The value is encoded as a two's complement binary number in the minimum number of octets.
+/// This API is also used by other `encode` functions to encode an integer value.
+pub fn encode_integer(
+    data: &mut BerCodecData,
+    tag: Option<Tag>,
+    value: i128,
+) -> Result<(), BerCodecError> {
+    log::trace!("encode_integer: tag: {:?}, value: {}", tag, value);
+
+    encode_primitive(
+        data,
+        tag.unwrap_or(Tag::INTEGER),
+        &integer_contents_octets(value),
+    )
+}
+
+/// Encode an ENUMERATED Value
+pub fn encode_enumerated(
+    data: &mut BerCodecData,
+    tag: Option<Tag>,
+    value: i128,
+) -> Result<(), BerCodecError> {
+    log::trace!("encode_enumerated: tag: {:?}, value: {}", tag, value);
+
+    encode_primitive(
+        data,
+        tag.unwrap_or(Tag::ENUMERATED),
+        &integer_contents_octets(value),
+    )
+}
+
+/// Encode a BOOLEAN Value
+pub fn encode_bool(
+    data: &mut BerCodecData,
+    tag: Option<Tag>,
+    value: bool,
+) -> Result<(), BerCodecError> {
+    log::trace!("encode_bool: tag: {:?}, value: {}", tag, value);
+
+    let octet = if value { 0xFF } else { 0x00 };
+    encode_primitive(data, tag.unwrap_or(Tag::BOOLEAN), &[octet])
+}
+
+/// Encode a NULL Value
+pub fn encode_null(data: &mut BerCodecData, tag: Option<Tag>) -> Result<(), BerCodecError> {
+    log::trace!("encode_null: tag: {:?}", tag);
+
+    encode_primitive(data, tag.unwrap_or(Tag::NULL), &[])
+}
+
+/// Encode a BIT STRING
+///
+/// The first contents octet is the number of unused bits in the final octet, the unused bits are
+/// set to zero.
+pub fn encode_bitstring(
+    data: &mut BerCodecData,
+    tag: Option<Tag>,
+    bits: &BitSlice<u8, Msb0>,
+) -> Result<(), BerCodecError> {
+    log::trace!("encode_bitstring: tag: {:?}, length: {}", tag, bits.len());
+
+    encode_primitive(
+        data,
+        tag.unwrap_or(Tag::BIT_STRING),
+        &bitstring_contents_octets(bits),
+    )
+}
+
+/// Encode an OCTET STRING
+pub fn encode_octetstring(
+    data: &mut BerCodecData,
+    tag: Option<Tag>,
+    octets: &[u8],
+) -> Result<(), BerCodecError> {
+    log::trace!(
+        "encode_octetstring: tag: {:?}, length: {}",
+        tag,
+        octets.len()
+    );
+
+    encode_primitive(data, tag.unwrap_or(Tag::OCTET_STRING), octets)
+}
+
+/// Encode a UTF8String
+pub fn encode_utf8_string(
+    data: &mut BerCodecData,
+    tag: Option<Tag>,
+    value: &str,
+) -> Result<(), BerCodecError> {
+    log::trace!("encode_utf8_string: tag: {:?}, value: {}", tag, value);
+
+    encode_primitive(data, tag.unwrap_or(Tag::UTF8_STRING), value.as_bytes())
+}
+
+/// Encode a PrintableString
+pub fn encode_printable_string(
+    data: &mut BerCodecData,
+    tag: Option<Tag>,
+    value: &str,
+) -> Result<(), BerCodecError> {
+    log::trace!("encode_printable_string: tag: {:?}, value: {}", tag, value);
+
+    if let Some(c) = value.chars().find(|c| !is_printable_char(*c)) {
+        return Err(BerCodecError::new(format!(
+            "Character '{}' is not valid for a PrintableString.",
+            c
+        )));
+    }
+
+    encode_primitive(data, tag.unwrap_or(Tag::PRINTABLE_STRING), value.as_bytes())
+}
+
+/// Encode a VisibleString
+pub fn encode_visible_string(
+    data: &mut BerCodecData,
+    tag: Option<Tag>,
+    value: &str,
+) -> Result<(), BerCodecError> {
+    log::trace!("encode_visible_string: tag: {:?}, value: {}", tag, value);
+
+    if let Some(c) = value.chars().find(|c| !(' '..='~').contains(c)) {
+        return Err(BerCodecError::new(format!(
+            "Character '{}' is not valid for a VisibleString.",
+            c
+        )));
+    }
+
+    encode_primitive(data, tag.unwrap_or(Tag::VISIBLE_STRING), value.as_bytes())
+}
+
+/// Encode an IA5String
+pub fn encode_ia5_string(
+    data: &mut BerCodecData,
+    tag: Option<Tag>,
+    value: &str,
+) -> Result<(), BerCodecError> {
+    log::trace!("encode_ia5_string: tag: {:?}, value: {}", tag, value);
+
+    let octets = string_contents_octets(value, 1, "IA5String", |c| c.is_ascii())?;
+    encode_primitive(data, tag.unwrap_or(Tag::IA5_STRING), &octets)
+}
+
+/// Encode a NumericString
+pub fn encode_numeric_string(
+    data: &mut BerCodecData,
+    tag: Option<Tag>,
+    value: &str,
+) -> Result<(), BerCodecError> {
+    log::trace!("encode_numeric_string: tag: {:?}, value: {}", tag, value);
+
+    let octets = string_contents_octets(value, 1, "NumericString", |c| {
+        c == ' ' || c.is_ascii_digit()
+    })?;
+    encode_primitive(data, tag.unwrap_or(Tag::NUMERIC_STRING), &octets)
+}
+
+/// Encode a BMPString
+///
+/// Each of the characters is encoded in two octets.
+pub fn encode_bmp_string(
+    data: &mut BerCodecData,
+    tag: Option<Tag>,
+    value: &str,
+) -> Result<(), BerCodecError> {
+    log::trace!("encode_bmp_string: tag: {:?}, value: {}", tag, value);
+
+    let octets = string_contents_octets(value, 2, "BMPString", |c| (c as u32) <= 0xffff)?;
+    encode_primitive(data, tag.unwrap_or(Tag::BMP_STRING), &octets)
+}
+
+/// Encode a UniversalString
+///
+/// Each of the characters is encoded in four octets.
+pub fn encode_universal_string(
+    data: &mut BerCodecData,
+    tag: Option<Tag>,
+    value: &str,
+) -> Result<(), BerCodecError> {
+    log::trace!("encode_universal_string: tag: {:?}, value: {}", tag, value);
+
+    let octets = string_contents_octets(value, 4, "UniversalString", |_| true)?;
+    encode_primitive(data, tag.unwrap_or(Tag::UNIVERSAL_STRING), &octets)
+}
+
+/// Encode a TeletexString
+///
+/// Each of the characters is encoded in a single octet.
+pub fn encode_teletex_string(
+    data: &mut BerCodecData,
+    tag: Option<Tag>,
+    value: &str,
+) -> Result<(), BerCodecError> {
+    log::trace!("encode_teletex_string: tag: {:?}, value: {}", tag, value);
+
+    let octets = string_contents_octets(value, 1, "TeletexString", |c| (c as u32) <= 0xff)?;
+    encode_primitive(data, tag.unwrap_or(Tag::TELETEX_STRING), &octets)
+}
+
+/// Encode a GeneralString
+///
+/// Each of the characters is encoded in a single octet.
+pub fn encode_general_string(
+    data: &mut BerCodecData,
+    tag: Option<Tag>,
+    value: &str,
+) -> Result<(), BerCodecError> {
+    log::trace!("encode_general_string: tag: {:?}, value: {}", tag, value);
+
+    let octets = string_contents_octets(value, 1, "GeneralString", |c| (c as u32) <= 0xff)?;
+    encode_primitive(data, tag.unwrap_or(Tag::GENERAL_STRING), &octets)
+}
+
+/// Encode a UTCTime
+pub fn encode_utc_time(
+    data: &mut BerCodecData,
+    tag: Option<Tag>,
+    value: &str,
+) -> Result<(), BerCodecError> {
+    log::trace!("encode_utc_time: tag: {:?}, value: {}", tag, value);
+
+    check_time_value(value, Tag::UTC_TIME, false)?;
+    encode_primitive(data, tag.unwrap_or(Tag::UTC_TIME), value.as_bytes())
+}
+
+/// Encode a GeneralizedTime
+pub fn encode_generalized_time(
+    data: &mut BerCodecData,
+    tag: Option<Tag>,
+    value: &str,
+) -> Result<(), BerCodecError> {
+    log::trace!("encode_generalized_time: tag: {:?}, value: {}", tag, value);
+
+    check_time_value(value, Tag::GENERALIZED_TIME, false)?;
+    encode_primitive(data, tag.unwrap_or(Tag::GENERALIZED_TIME), value.as_bytes())
+}
+
+/// Encode a DATE
+pub fn encode_date(
+    data: &mut BerCodecData,
+    tag: Option<Tag>,
+    value: &str,
+) -> Result<(), BerCodecError> {
+    log::trace!("encode_date: tag: {:?}, value: {}", tag, value);
+
+    check_time_value(value, Tag::DATE, false)?;
+    encode_primitive(data, tag.unwrap_or(Tag::DATE), value.as_bytes())
+}
+
+/// Encode a TIME-OF-DAY
+pub fn encode_time_of_day(
+    data: &mut BerCodecData,
+    tag: Option<Tag>,
+    value: &str,
+) -> Result<(), BerCodecError> {
+    log::trace!("encode_time_of_day: tag: {:?}, value: {}", tag, value);
+
+    check_time_value(value, Tag::TIME_OF_DAY, false)?;
+    encode_primitive(data, tag.unwrap_or(Tag::TIME_OF_DAY), value.as_bytes())
+}
+
+/// Encode a DATE-TIME
+pub fn encode_date_time(
+    data: &mut BerCodecData,
+    tag: Option<Tag>,
+    value: &str,
+) -> Result<(), BerCodecError> {
+    log::trace!("encode_date_time: tag: {:?}, value: {}", tag, value);
+
+    check_time_value(value, Tag::DATE_TIME, false)?;
+    encode_primitive(data, tag.unwrap_or(Tag::DATE_TIME), value.as_bytes())
+}
+
+/// Encode a DURATION
+pub fn encode_duration(
+    data: &mut BerCodecData,
+    tag: Option<Tag>,
+    value: &str,
+) -> Result<(), BerCodecError> {
+    log::trace!("encode_duration: tag: {:?}, value: {}", tag, value);
+
+    check_time_value(value, Tag::DURATION, false)?;
+    encode_primitive(data, tag.unwrap_or(Tag::DURATION), value.as_bytes())
+}
+
+// Contents octets of a character string type with each of the characters encoded in
+// `octets_per_char` octets.
+fn string_contents_octets(
+    value: &str,
+    octets_per_char: usize,
+    type_name: &str,
+    is_valid: fn(char) -> bool,
+) -> Result<Vec<u8>, BerCodecError> {
+    let mut octets = Vec::with_capacity(value.len() * octets_per_char);
+    for c in value.chars() {
+        if !is_valid(c) {
+            return Err(BerCodecError::new(format!(
+                "Character '{}' is not valid for a {}.",
+                c, type_name
+            )));
+        }
+        octets.extend_from_slice(&(c as u32).to_be_bytes()[4 - octets_per_char..]);
+    }
+
+    Ok(octets)
+}
+
+pub(crate) fn integer_contents_octets(value: i128) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    let mut start = 0;
+    while start < bytes.len() - 1 {
+        let redundant = (bytes[start] == 0x00 && bytes[start + 1] & 0x80 == 0)
+            || (bytes[start] == 0xFF && bytes[start + 1] & 0x80 != 0);
+        if !redundant {
+            break;
+        }
+        start += 1;
+    }
+    bytes[start..].to_vec()
+}
+
+pub(crate) fn bitstring_contents_octets(bits: &BitSlice<u8, Msb0>) -> Vec<u8> {
+    let unused = (8 - bits.len() % 8) % 8;
+    let mut padded = BitVec::<u8, Msb0>::with_capacity(bits.len() + unused);
+    padded.extend_from_bitslice(bits);
+    padded.resize(bits.len() + unused, false);
+
+    let mut contents = vec![unused as u8];
+    contents.extend(padded.into_vec());
+    contents
+}
+
+// Validates the lexical form of a value of one of the Time Types. With `der`, only the restricted
+// forms of the `UTCTime` and `GeneralizedTime` values in UTC are valid (X.690 11.7 and 11.8).
+pub(crate) fn check_time_value(
+    value: &str,
+    time_type: Tag,
+    der: bool,
+) -> Result<(), BerCodecError> {
+    let result = match time_type {
+        Tag::UTC_TIME => DateTime::from_utc_time(value)
+            .map(|v| v.utc_offset == Some(0) && v.to_utc_time().as_deref().ok() == Some(value)),
+        Tag::GENERALIZED_TIME => DateTime::from_generalized_time(value).map(|v| {
+            v.utc_offset == Some(0)
+                && v.to_generalized_time() == value
+                && !v.fraction.is_some_and(|f| f.ends_with('0'))
+        }),
+        Tag::DATE => value.parse::<Date>().map(|_| true),
+        Tag::TIME_OF_DAY => value.parse::<TimeOfDay>().map(|_| true),
+        Tag::DATE_TIME => DateTime::from_date_time(value).map(|_| true),
+        _ => value.parse::<Duration>().map(|_| true),
+    };
+
+    match result {
+        Ok(canonical) if canonical || !der => Ok(()),
+        Ok(_) => Err(BerCodecError::new(format!(
+            "DerCodec:DecodeError:The value '{}' is not in the DER form.",
+            value
+        ))),
+        Err(e) => Err(BerCodecError::new(e.to_string())),
+    }
+}
+
+pub(crate) fn is_printable_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || " '()+,-./:=?".contains(c)
+}
+
+pub(crate) fn compare_padded(a: &[u8], b: &[u8]) -> std::cmp::Ordering {
+    let length = std::cmp::max(a.len(), b.len());
+    let a = a.iter().chain(std::iter::repeat(&0)).take(length);
+    let b = b.iter().chain(std::iter::repeat(&0)).take(length);
+    a.cmp(b)
+}
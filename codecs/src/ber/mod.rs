@@ -0,0 +1,519 @@
+#![allow(dead_code)]
+//! ASN.1 BER Codec
+//!
+//! Encoding and Decoding of ASN.1 Types using the Basic Encoding Rules (X.690). Every value is
+//! encoded as a Tag, Length and Value (TLV) triplet, where the Value itself may be a sequence of
+//! TLVs for the constructed types.
+
+pub mod error;
+
+pub mod encode;
+
+pub mod decode;
+
+pub mod der;
+
+pub use error::Error as BerCodecError;
+
+/// Class of an ASN.1 Tag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TagClass {
+    Universal = 0,
+    Application = 1,
+    ContextSpecific = 2,
+    Private = 3,
+}
+
+/// An ASN.1 Tag
+///
+/// A Tag consists of a [`TagClass`] and a Tag number. Whether the encoding is 'primitive' or
+/// 'constructed' is a property of the encoding (and not of the Tag) and hence is not part of this
+/// structure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Tag {
+    pub class: TagClass,
+    pub number: u32,
+}
+
+impl Tag {
+    pub const BOOLEAN: Tag = Tag::universal(1);
+    pub const INTEGER: Tag = Tag::universal(2);
+    pub const BIT_STRING: Tag = Tag::universal(3);
+    pub const OCTET_STRING: Tag = Tag::universal(4);
+    pub const NULL: Tag = Tag::universal(5);
+    pub const OBJECT_IDENTIFIER: Tag = Tag::universal(6);
+    pub const REAL: Tag = Tag::universal(9);
+    pub const ENUMERATED: Tag = Tag::universal(10);
+    pub const UTF8_STRING: Tag = Tag::universal(12);
+    pub const RELATIVE_OID: Tag = Tag::universal(13);
+    pub const SEQUENCE: Tag = Tag::universal(16);
+    pub const SET: Tag = Tag::universal(17);
+    pub const NUMERIC_STRING: Tag = Tag::universal(18);
+    pub const PRINTABLE_STRING: Tag = Tag::universal(19);
+    pub const TELETEX_STRING: Tag = Tag::universal(20);
+    pub const IA5_STRING: Tag = Tag::universal(22);
+    pub const UTC_TIME: Tag = Tag::universal(23);
+    pub const GENERALIZED_TIME: Tag = Tag::universal(24);
+    pub const VISIBLE_STRING: Tag = Tag::universal(26);
+    pub const GENERAL_STRING: Tag = Tag::universal(27);
+    pub const UNIVERSAL_STRING: Tag = Tag::universal(28);
+    pub const BMP_STRING: Tag = Tag::universal(30);
+    pub const DATE: Tag = Tag::universal(31);
+    pub const TIME_OF_DAY: Tag = Tag::universal(32);
+    pub const DATE_TIME: Tag = Tag::universal(33);
+    pub const DURATION: Tag = Tag::universal(34);
+
+    /// Creates a new Tag
+    pub const fn new(class: TagClass, number: u32) -> Self {
+        Self { class, number }
+    }
+
+    /// Creates a new `UNIVERSAL` class Tag
+    pub const fn universal(number: u32) -> Self {
+        Self::new(TagClass::Universal, number)
+    }
+
+    /// Creates a new `APPLICATION` class Tag
+    pub const fn application(number: u32) -> Self {
+        Self::new(TagClass::Application, number)
+    }
+
+    /// Creates a new Context Specific Tag
+    pub const fn context(number: u32) -> Self {
+        Self::new(TagClass::ContextSpecific, number)
+    }
+
+    /// Creates a new `PRIVATE` class Tag
+    pub const fn private(number: u32) -> Self {
+        Self::new(TagClass::Private, number)
+    }
+}
+
+impl std::fmt::Display for Tag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.class {
+            TagClass::Universal => write!(f, "[UNIVERSAL {}]", self.number),
+            TagClass::Application => write!(f, "[APPLICATION {}]", self.number),
+            TagClass::ContextSpecific => write!(f, "[{}]", self.number),
+            TagClass::Private => write!(f, "[PRIVATE {}]", self.number),
+        }
+    }
+}
+
+/// A Tag applied on an ASN.1 Type.
+///
+/// An 'explicit' tag adds a constructed encoding with the tag around the encoding of the type,
+/// while an 'implicit' tag replaces the outermost tag of the type. A Tagged Type is described by a
+/// sequence of `Tagging`s, the outermost first, where only the last one can be 'implicit'.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tagging {
+    pub tag: Tag,
+    pub explicit: bool,
+}
+
+impl Tagging {
+    /// Creates a new 'explicit' Tagging
+    pub const fn explicit(tag: Tag) -> Self {
+        Self {
+            tag,
+            explicit: true,
+        }
+    }
+
+    /// Creates a new 'implicit' Tagging
+    pub const fn implicit(tag: Tag) -> Self {
+        Self {
+            tag,
+            explicit: false,
+        }
+    }
+}
+
+/// Structure representing a BER Codec.
+///
+/// While En(De)coding ASN.1 Types using the BER encoding scheme, the encoded data is stored in a
+/// `Vec<u8>`. The contents of a constructed encoding are decoded from a separate `BerCodecData`
+/// created for those contents only, so that the end of the contents can be detected by the
+/// decoder of a constructed type.
+///
+/// The same structure is used for the DER Codec. A `BerCodecData` created for DER decodes in a
+/// 'strict' mode, where any encoding that is a valid BER encoding but not a valid DER encoding is
+/// rejected.
+#[derive(Default, Debug)]
+pub struct BerCodecData {
+    bytes: Vec<u8>,
+    decode_offset: usize,
+    key: Option<i128>,
+    der: bool,
+}
+
+impl BerCodecData {
+    /// Default `BerCodecData` for encoding.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create Our `BerCodecData` Structure from a slice of u8
+    pub fn from_slice(bytes: &[u8]) -> Self {
+        Self::from_slice_internal(bytes, false)
+    }
+
+    /// Create Our `BerCodecData` Structure from a slice of u8 for (strict) DER decoding.
+    pub fn from_slice_der(bytes: &[u8]) -> Self {
+        Self::from_slice_internal(bytes, true)
+    }
+
+    fn from_slice_internal(bytes: &[u8], der: bool) -> Self {
+        Self {
+            bytes: bytes.to_vec(),
+            decode_offset: 0,
+            key: None,
+            der,
+        }
+    }
+
+    /// Whether the data is decoded using the strict DER rules.
+    pub fn is_der(&self) -> bool {
+        self.der
+    }
+
+    /// Get's the inner buffer as a `Vec<u8>` consuming the struct.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    /// Get's the encoded bytes as a `Vec<u8>`.
+    pub fn get_inner(&self) -> Result<Vec<u8>, BerCodecError> {
+        Ok(self.bytes.clone())
+    }
+
+    /// Length of the encoded data in bytes.
+    pub fn length_in_bytes(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Number of bytes not yet decoded.
+    pub fn remaining(&self) -> usize {
+        self.bytes.len() - self.decode_offset
+    }
+
+    /// Current decode offset.
+    ///
+    /// Used together with [`BerCodecData::seek`] for decoding a value that may not be present, eg.
+    /// an `OPTIONAL` component of an untagged `CHOICE` type.
+    pub fn offset(&self) -> usize {
+        self.decode_offset
+    }
+
+    /// `seek` to the given decode offset.
+    pub fn seek(&mut self, offset: usize) {
+        self.decode_offset = offset;
+    }
+
+    /// Whether all the bytes in the buffer are decoded.
+    pub fn is_empty(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    /// Get's the current `key` value.
+    ///
+    /// This value will be used by a decoder to determine which 'decode' function is to be called
+    /// (for example in an `enum`, it will be used to determine which `variant` of the `enum` will
+    /// be decoded.
+    pub fn get_key(&self) -> Option<i128> {
+        self.key
+    }
+
+    /// Sets the current `key` value.
+    ///
+    /// See [`crate::PerCodecData::set_key`] for how this is used for decoding 'open' types.
+    pub fn set_key(&mut self, key: i128) {
+        let _ = self.key.replace(key);
+    }
+
+    /// Checks whether the encoded bytes are the same as that of the `other` encoding.
+    /// This is useful when deciding whether a component with a `DEFAULT` value is to be encoded.
+    pub fn same_encoding(&self, other: &Self) -> bool {
+        self.bytes == other.bytes
+    }
+
+    /// Dump current 'offset'.
+    pub fn dump(&self) {
+        log::trace!("offset: {}, bytes: {:02x?}", self.decode_offset, self.bytes);
+    }
+
+    fn peek_byte(&self) -> Result<u8, BerCodecError> {
+        self.bytes.get(self.decode_offset).copied().ok_or_else(|| {
+            BerCodecError::new("BerCodec:DecodeError:End of Buffer reached while decoding.")
+        })
+    }
+
+    fn get_byte(&mut self) -> Result<u8, BerCodecError> {
+        let byte = self.peek_byte()?;
+        self.decode_offset += 1;
+        Ok(byte)
+    }
+
+    fn get_bytes(&mut self, length: usize) -> Result<Vec<u8>, BerCodecError> {
+        if self.remaining() < length {
+            return Err(BerCodecError::new(format!(
+                "BerCodec:DecodeError:Requested Bytes to decode {}, Remaining bytes {}",
+                length,
+                self.remaining()
+            )));
+        }
+        let bytes = self.bytes[self.decode_offset..self.decode_offset + length].to_vec();
+        self.decode_offset += length;
+        Ok(bytes)
+    }
+
+    fn append_bytes(&mut self, bytes: &[u8]) {
+        self.bytes.extend_from_slice(bytes);
+    }
+}
+
+/// Trait representing a 'BER Codec'.
+///
+/// This 'trait' is to be derived by any `struct` or `enum` representing an ASN.1 Type. The
+/// `ber_encode_tagged` and `ber_decode_tagged` functions take an optional `tag`, which if present
+/// overrides the tag of the type (ie. the type is 'implicitly' tagged). Types that cannot be
+/// implicitly tagged (`CHOICE` and open types) are 'explicitly' tagged with the given `tag`.
+pub trait BerCodec {
+    type Output;
+
+    fn ber_decode_tagged(
+        data: &mut BerCodecData,
+        tag: Option<Tag>,
+    ) -> Result<Self::Output, BerCodecError>;
+
+    fn ber_encode_tagged(
+        &self,
+        data: &mut BerCodecData,
+        tag: Option<Tag>,
+    ) -> Result<(), BerCodecError>;
+
+    fn ber_decode(data: &mut BerCodecData) -> Result<Self::Output, BerCodecError> {
+        Self::ber_decode_tagged(data, None)
+    }
+
+    fn ber_encode(&self, data: &mut BerCodecData) -> Result<(), BerCodecError> {
+        self.ber_encode_tagged(data, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bitvec::prelude::*;
+
+    #[test]
+    fn tag_encode_decode() {
+        let tags = vec![
+            (Tag::INTEGER, false, vec![0x02]),
+            (Tag::SEQUENCE, true, vec![0x30]),
+            (Tag::context(3), false, vec![0x83]),
+            (Tag::application(30), true, vec![0x7e]),
+            (Tag::context(31), false, vec![0x9f, 0x1f]),
+            (Tag::private(201), true, vec![0xff, 0x81, 0x49]),
+        ];
+        for (tag, constructed, expected) in tags {
+            let mut d = BerCodecData::new();
+            encode::encode_tag(&mut d, tag, constructed);
+            assert_eq!(d.get_inner().unwrap(), expected, "tag: {}", tag);
+
+            let mut d = BerCodecData::from_slice(&expected);
+            let decoded = decode::decode_tag(&mut d).unwrap();
+            assert_eq!(decoded, (tag, constructed));
+        }
+    }
+
+    #[test]
+    fn length_encode_decode() {
+        let lengths = vec![
+            (0, vec![0x00]),
+            (127, vec![0x7f]),
+            (128, vec![0x81, 0x80]),
+            (256, vec![0x82, 0x01, 0x00]),
+            (65536, vec![0x83, 0x01, 0x00, 0x00]),
+        ];
+        for (length, expected) in lengths {
+            let mut d = BerCodecData::new();
+            encode::encode_length(&mut d, length);
+            assert_eq!(d.get_inner().unwrap(), expected, "length: {}", length);
+
+            let mut d = BerCodecData::from_slice(&expected);
+            assert_eq!(decode::decode_length(&mut d).unwrap(), Some(length));
+        }
+    }
+
+    #[test]
+    fn integer_encode_decode() {
+        let numbers = vec![
+            (0_i128, vec![0x02, 0x01, 0x00]),
+            (127, vec![0x02, 0x01, 0x7f]),
+            (128, vec![0x02, 0x02, 0x00, 0x80]),
+            (256, vec![0x02, 0x02, 0x01, 0x00]),
+            (-1, vec![0x02, 0x01, 0xff]),
+            (-128, vec![0x02, 0x01, 0x80]),
+            (-129, vec![0x02, 0x02, 0xff, 0x7f]),
+        ];
+        for (num, expected) in numbers {
+            let mut d = BerCodecData::new();
+            encode::encode_integer(&mut d, None, num).unwrap();
+            assert_eq!(d.get_inner().unwrap(), expected, "number: {}", num);
+
+            let mut d = BerCodecData::from_slice(&expected);
+            assert_eq!(decode::decode_integer(&mut d, None).unwrap(), num);
+        }
+    }
+
+    #[test]
+    fn implicit_tagged_integer() {
+        let mut d = BerCodecData::new();
+        encode::encode_integer(&mut d, Some(Tag::context(0)), 5).unwrap();
+        assert_eq!(d.get_inner().unwrap(), vec![0x80, 0x01, 0x05]);
+
+        let mut d = BerCodecData::from_slice(&[0x80, 0x01, 0x05]);
+        assert!(decode::decode_integer(&mut d, None).is_err());
+
+        let mut d = BerCodecData::from_slice(&[0x80, 0x01, 0x05]);
+        assert_eq!(
+            decode::decode_integer(&mut d, Some(Tag::context(0))).unwrap(),
+            5
+        );
+    }
+
+    #[test]
+    fn tagging_encode_decode() {
+        // [1] EXPLICIT [APPLICATION 2] IMPLICIT INTEGER
+        let taggings = [
+            Tagging::explicit(Tag::context(1)),
+            Tagging::implicit(Tag::application(2)),
+        ];
+        let mut d = BerCodecData::new();
+        encode::encode_with_tagging(&mut d, &taggings, |data, tag| {
+            encode::encode_integer(data, tag, 5)
+        })
+        .unwrap();
+        assert_eq!(d.get_inner().unwrap(), vec![0xa1, 0x03, 0x42, 0x01, 0x05]);
+
+        let mut d = BerCodecData::from_slice(&d.into_bytes());
+        let decoded = decode::decode_with_tagging(&mut d, &taggings, decode::decode_integer);
+        assert_eq!(decoded.unwrap(), 5);
+
+        let mut d = BerCodecData::from_slice(&[0xa1, 0x03, 0x02, 0x01, 0x05]);
+        let decoded = decode::decode_with_tagging(&mut d, &taggings, decode::decode_integer);
+        assert!(decoded.is_err());
+    }
+
+    #[test]
+    fn bitstring_encode_decode() {
+        let bits = bitvec![u8, Msb0; 1, 0, 1, 1, 0, 1, 1, 1, 0, 1];
+        let mut d = BerCodecData::new();
+        encode::encode_bitstring(&mut d, None, &bits).unwrap();
+        assert_eq!(d.get_inner().unwrap(), vec![0x03, 0x03, 0x06, 0xb7, 0x40]);
+
+        let mut d = BerCodecData::from_slice(&d.into_bytes());
+        assert_eq!(decode::decode_bitstring(&mut d, None).unwrap(), bits);
+    }
+
+    #[test]
+    fn constructed_octetstring_decode() {
+        // Constructed, indefinite length encoding of an OCTET STRING (X.690 8.7.3)
+        let encoded = vec![
+            0x24, 0x80, 0x04, 0x02, 0x01, 0x02, 0x04, 0x01, 0x03, 0x00, 0x00,
+        ];
+        let mut d = BerCodecData::from_slice(&encoded);
+        let value = decode::decode_octetstring(&mut d, None).unwrap();
+        assert_eq!(value, vec![0x01, 0x02, 0x03]);
+        assert!(d.is_empty());
+    }
+
+    #[test]
+    fn constructed_indefinite_length() {
+        // SEQUENCE { INTEGER 1, SEQUENCE { BOOLEAN TRUE } } with indefinite lengths
+        let encoded = vec![
+            0x30, 0x80, 0x02, 0x01, 0x01, 0x30, 0x80, 0x01, 0x01, 0xff, 0x00, 0x00, 0x00, 0x00,
+        ];
+        let mut d = BerCodecData::from_slice(&encoded);
+        let mut contents = decode::decode_constructed(&mut d, Tag::SEQUENCE).unwrap();
+        assert!(d.is_empty());
+        assert_eq!(decode::decode_integer(&mut contents, None).unwrap(), 1);
+        let mut inner = decode::decode_constructed(&mut contents, Tag::SEQUENCE).unwrap();
+        assert!(decode::decode_bool(&mut inner, None).unwrap());
+        assert!(contents.is_empty());
+    }
+
+    #[test]
+    fn string_encode_decode() {
+        let mut d = BerCodecData::new();
+        encode::encode_printable_string(&mut d, None, "hello").unwrap();
+        assert_eq!(
+            d.get_inner().unwrap(),
+            vec![0x13, 0x05, 0x68, 0x65, 0x6c, 0x6c, 0x6f]
+        );
+
+        let mut d = BerCodecData::from_slice(&d.into_bytes());
+        assert_eq!(
+            decode::decode_printable_string(&mut d, None).unwrap(),
+            "hello"
+        );
+
+        let mut d = BerCodecData::new();
+        assert!(encode::encode_printable_string(&mut d, None, "hello!").is_err());
+    }
+
+    #[test]
+    fn multi_octet_string_encode_decode() {
+        let mut d = BerCodecData::new();
+        encode::encode_bmp_string(&mut d, None, "\u{3b1}b").unwrap();
+        assert_eq!(
+            d.get_inner().unwrap(),
+            vec![0x1e, 0x04, 0x03, 0xb1, 0x00, 0x62]
+        );
+        let mut d = BerCodecData::from_slice(&d.into_bytes());
+        assert_eq!(decode::decode_bmp_string(&mut d, None).unwrap(), "\u{3b1}b");
+
+        let mut d = BerCodecData::new();
+        assert!(encode::encode_bmp_string(&mut d, None, "\u{1f600}").is_err());
+        encode::encode_universal_string(&mut d, None, "\u{1f600}").unwrap();
+        assert_eq!(
+            d.get_inner().unwrap(),
+            vec![0x1c, 0x04, 0x00, 0x01, 0xf6, 0x00]
+        );
+
+        // An odd number of octets for a BMPString.
+        let mut d = BerCodecData::from_slice(&[0x1e, 0x03, 0x00, 0x62, 0x00]);
+        assert!(decode::decode_bmp_string(&mut d, None).is_err());
+    }
+
+    #[test]
+    fn time_encode_decode() {
+        let mut d = BerCodecData::new();
+        encode::encode_utc_time(&mut d, None, "491231235959Z").unwrap();
+        assert_eq!(d.get_inner().unwrap()[..2], [0x17, 0x0d]);
+        let mut d = BerCodecData::from_slice_der(&d.into_bytes());
+        assert_eq!(
+            decode::decode_utc_time(&mut d, None).unwrap(),
+            "491231235959Z"
+        );
+
+        // Valid in BER but not in DER.
+        let mut d = BerCodecData::new();
+        encode::encode_generalized_time(&mut d, None, "20240229123456.50Z").unwrap();
+        let bytes = d.into_bytes();
+        let mut d = BerCodecData::from_slice(&bytes);
+        assert!(decode::decode_generalized_time(&mut d, None).is_ok());
+        let mut d = BerCodecData::from_slice_der(&bytes);
+        assert!(decode::decode_generalized_time(&mut d, None).is_err());
+
+        let mut d = BerCodecData::new();
+        encode::encode_date(&mut d, None, "2024-02-29").unwrap();
+        assert_eq!(d.get_inner().unwrap()[..3], [0x1f, 0x1f, 0x0a]);
+        let mut d = BerCodecData::from_slice(&d.into_bytes());
+        assert_eq!(decode::decode_date(&mut d, None).unwrap(), "2024-02-29");
+
+        let mut d = BerCodecData::new();
+        assert!(encode::encode_duration(&mut d, None, "P").is_err());
+    }
+}
@@ -0,0 +1,270 @@
+//! Decode APIs for JER Codec
+
+use bitvec::prelude::*;
+use serde_json::Value;
+
+use crate::jer::encode::{check_range, fixed_size};
+use crate::jer::{JerCodecData, JerCodecError};
+
+/// Decode a BOOLEAN Value
+pub fn decode_bool(data: &mut JerCodecData) -> Result<bool, JerCodecError> {
+    let value = data
+        .value
+        .as_bool()
+        .ok_or_else(|| unexpected_value(data, "a BOOLEAN"))?;
+
+    log::trace!("decode_bool: {}", value);
+    Ok(value)
+}
+
+/// Decode an INTEGER
+///
+/// A value that is outside the range of a non-extensible constraint is an error.
+pub fn decode_integer(
+    data: &mut JerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+) -> Result<i128, JerCodecError> {
+    let value = match data.value {
+        Value::Number(ref n) => n
+            .as_i64()
+            .map(i128::from)
+            .or_else(|| n.as_u64().map(i128::from)),
+        _ => None,
+    }
+    .ok_or_else(|| unexpected_value(data, "an INTEGER"))?;
+
+    check_range(lb, ub, is_extensible, value, "Value").map_err(decode_error)?;
+
+    log::trace!("decode_integer: value: {}", value);
+    Ok(value)
+}
+
+/// Decode an ENUMERATED Value
+///
+/// The identifier in the JSON string is looked up in the `named_values` to get the value.
+pub fn decode_enumerated(
+    data: &mut JerCodecData,
+    named_values: &[(&str, i128)],
+) -> Result<i128, JerCodecError> {
+    let name = data
+        .value
+        .as_str()
+        .ok_or_else(|| unexpected_value(data, "an ENUMERATED"))?;
+
+    let value = named_values
+        .iter()
+        .find(|(n, _)| *n == name)
+        .map(|(_, value)| *value)
+        .ok_or_else(|| {
+            JerCodecError::new(format!(
+                "JerCodec:DecodeError:'{}' is not a named value of ENUMERATED.",
+                name
+            ))
+        })?;
+
+    log::trace!("decode_enumerated: value: {}", value);
+    Ok(value)
+}
+
+/// Decode a NULL Value
+pub fn decode_null(data: &mut JerCodecData) -> Result<(), JerCodecError> {
+    if !data.value.is_null() {
+        return Err(unexpected_value(data, "a NULL"));
+    }
+
+    log::trace!("decode_null");
+    Ok(())
+}
+
+/// Decode a BIT STRING
+///
+/// See [`crate::jer::encode::encode_bitstring`] for how the constraints determine the encoding.
+pub fn decode_bitstring(
+    data: &mut JerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+) -> Result<BitVec<u8, Msb0>, JerCodecError> {
+    let (hex, length) = if fixed_size(lb, ub, is_extensible) {
+        (data.value.as_str(), lb.map(|lb| lb as u64))
+    } else {
+        (
+            data.value.get("value").and_then(Value::as_str),
+            data.value.get("length").and_then(Value::as_u64),
+        )
+    };
+
+    let (hex, length) = match (hex, length) {
+        (Some(hex), Some(length)) => (hex, length as usize),
+        _ => return Err(unexpected_value(data, "a BIT STRING")),
+    };
+
+    let octets = from_hex(hex)?;
+    if octets.len() != length.div_ceil(8) {
+        return Err(JerCodecError::new(format!(
+            "JerCodec:DecodeError:{} octets in the value of a BIT STRING of length {}.",
+            octets.len(),
+            length
+        )));
+    }
+
+    check_range(lb, ub, is_extensible, length as i128, "Length").map_err(decode_error)?;
+
+    let mut bits = BitVec::<u8, Msb0>::from_vec(octets);
+    bits.truncate(length);
+
+    log::trace!("decode_bitstring: length: {}", bits.len());
+    Ok(bits)
+}
+
+/// Decode an OCTET STRING
+pub fn decode_octetstring(
+    data: &mut JerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+) -> Result<Vec<u8>, JerCodecError> {
+    let hex = data
+        .value
+        .as_str()
+        .ok_or_else(|| unexpected_value(data, "an OCTET STRING"))?;
+
+    let octets = from_hex(hex)?;
+    check_range(lb, ub, is_extensible, octets.len() as i128, "Length").map_err(decode_error)?;
+
+    log::trace!("decode_octetstring: length: {}", octets.len());
+    Ok(octets)
+}
+
+/// Decode a Character String
+///
+/// The size constraints are in the number of characters.
+pub fn decode_character_string(
+    data: &mut JerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+) -> Result<String, JerCodecError> {
+    let value = data
+        .value
+        .as_str()
+        .ok_or_else(|| unexpected_value(data, "a Character String"))?;
+
+    check_range(
+        lb,
+        ub,
+        is_extensible,
+        value.chars().count() as i128,
+        "Length",
+    )
+    .map_err(decode_error)?;
+
+    log::trace!("decode_character_string: value: {}", value);
+    Ok(value.to_string())
+}
+
+/// Decode a Component of a SEQUENCE
+///
+/// Returns the `JerCodecData` for decoding the value of the component with the identifier
+/// `name`, or `None` if the component is absent.
+pub fn decode_sequence_component(
+    data: &mut JerCodecData,
+    name: &str,
+) -> Result<Option<JerCodecData>, JerCodecError> {
+    let object = data
+        .value
+        .as_object()
+        .ok_or_else(|| unexpected_value(data, "a SEQUENCE"))?;
+
+    log::trace!("decode_sequence_component: component: {}", name);
+    Ok(object.get(name).map(|value| data.component(value.clone())))
+}
+
+/// Check the Components of a SEQUENCE
+///
+/// Components other than the ones with identifiers in `names` are ignored if the `SEQUENCE` is
+/// extensible, as these can be the extension additions not known to us.
+pub fn check_sequence_components(
+    data: &JerCodecData,
+    names: &[&str],
+    is_extensible: bool,
+) -> Result<(), JerCodecError> {
+    let object = data
+        .value
+        .as_object()
+        .ok_or_else(|| unexpected_value(data, "a SEQUENCE"))?;
+
+    if is_extensible {
+        return Ok(());
+    }
+
+    match object.keys().find(|k| !names.contains(&k.as_str())) {
+        Some(unknown) => Err(JerCodecError::new(format!(
+            "JerCodec:DecodeError:Unknown Component '{}' in a SEQUENCE.",
+            unknown
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Decode a SEQUENCE OF
+///
+/// Returns the `JerCodecData` for decoding each of the items.
+pub fn decode_sequence_of(data: &mut JerCodecData) -> Result<Vec<JerCodecData>, JerCodecError> {
+    let items = data
+        .value
+        .as_array()
+        .ok_or_else(|| unexpected_value(data, "a SEQUENCE OF"))?;
+
+    log::trace!("decode_sequence_of: items: {}", items.len());
+    Ok(items
+        .iter()
+        .map(|item| data.component(item.clone()))
+        .collect())
+}
+
+/// Decode a CHOICE
+///
+/// Returns the identifier of the chosen alternative and the `JerCodecData` for decoding its
+/// value.
+pub fn decode_choice(data: &mut JerCodecData) -> Result<(String, JerCodecData), JerCodecError> {
+    let alternative = match data.value.as_object() {
+        Some(object) if object.len() == 1 => object.iter().next(),
+        _ => None,
+    };
+
+    match alternative {
+        Some((name, value)) => {
+            log::trace!("decode_choice: alternative: {}", name);
+            Ok((name.clone(), data.component(value.clone())))
+        }
+        None => Err(unexpected_value(data, "a CHOICE")),
+    }
+}
+
+fn from_hex(hex: &str) -> Result<Vec<u8>, JerCodecError> {
+    if !hex.len().is_multiple_of(2) || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(JerCodecError::new(format!(
+            "JerCodec:DecodeError:'{}' is not a valid hex string.",
+            hex
+        )));
+    }
+
+    Ok((0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect())
+}
+
+fn unexpected_value(data: &JerCodecData, expected: &str) -> JerCodecError {
+    JerCodecError::new(format!(
+        "JerCodec:DecodeError:Expected {}, found '{}'.",
+        expected, data.value
+    ))
+}
+
+fn decode_error(e: JerCodecError) -> JerCodecError {
+    JerCodecError::new(format!("JerCodec:DecodeError:{}", e))
+}
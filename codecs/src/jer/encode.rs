@@ -0,0 +1,245 @@
+//! Encode APIs for JER Codec
+
+use std::convert::TryFrom;
+
+use bitvec::prelude::*;
+use serde_json::{Map, Value};
+
+use crate::jer::{JerCodecData, JerCodecError};
+
+/// Encode a BOOLEAN Value
+pub fn encode_bool(data: &mut JerCodecData, value: bool) -> Result<(), JerCodecError> {
+    log::trace!("encode_bool: {}", value);
+
+    data.value = Value::Bool(value);
+
+    Ok(())
+}
+
+/// Encode an INTEGER
+///
+/// The value is encoded as a JSON number. A value that is outside the range of a non-extensible
+/// constraint is an error.
+pub fn encode_integer(
+    data: &mut JerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+    value: i128,
+) -> Result<(), JerCodecError> {
+    log::trace!(
+        "encode_integer: lb: {:?}, ub: {:?}, is_extensible: {}, value: {}",
+        lb,
+        ub,
+        is_extensible,
+        value
+    );
+
+    check_range(lb, ub, is_extensible, value, "Value")?;
+
+    data.value = if let Ok(value) = i64::try_from(value) {
+        Value::from(value)
+    } else if let Ok(value) = u64::try_from(value) {
+        Value::from(value)
+    } else {
+        return Err(JerCodecError::new(format!(
+            "Value {} cannot be represented as a JSON number.",
+            value
+        )));
+    };
+
+    Ok(())
+}
+
+/// Encode an ENUMERATED Value
+///
+/// The value is encoded as a JSON string with the identifier of the value from the `named_values`.
+pub fn encode_enumerated(
+    data: &mut JerCodecData,
+    named_values: &[(&str, i128)],
+    value: i128,
+) -> Result<(), JerCodecError> {
+    log::trace!("encode_enumerated: value: {}", value);
+
+    let name = named_values
+        .iter()
+        .find(|(_, v)| *v == value)
+        .map(|(name, _)| *name)
+        .ok_or_else(|| {
+            JerCodecError::new(format!(
+                "Value {} is not a named value of ENUMERATED.",
+                value
+            ))
+        })?;
+    data.value = Value::String(name.to_string());
+
+    Ok(())
+}
+
+/// Encode a NULL Value
+pub fn encode_null(data: &mut JerCodecData) -> Result<(), JerCodecError> {
+    log::trace!("encode_null");
+
+    data.value = Value::Null;
+
+    Ok(())
+}
+
+/// Encode a BIT STRING
+///
+/// The bits are encoded as a string of hexadecimal digits, with the last octet padded with zero
+/// bits. Unless the BIT STRING has a fixed size, the encoding is a JSON object with the hex
+/// string as `value` and the number of bits as `length`.
+pub fn encode_bitstring(
+    data: &mut JerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+    bit_string: &BitSlice<u8, Msb0>,
+) -> Result<(), JerCodecError> {
+    log::trace!(
+        "encode_bitstring: lb: {:?}, ub: {:?}, is_extensible: {}, length: {}",
+        lb,
+        ub,
+        is_extensible,
+        bit_string.len()
+    );
+
+    check_range(lb, ub, is_extensible, bit_string.len() as i128, "Length")?;
+
+    let mut bits = bit_string.to_bitvec();
+    bits.set_uninitialized(false);
+    let hex = Value::String(to_hex(bits.as_raw_slice()));
+
+    data.value = if fixed_size(lb, ub, is_extensible) {
+        hex
+    } else {
+        let mut object = Map::new();
+        object.insert("value".to_string(), hex);
+        object.insert("length".to_string(), Value::from(bit_string.len()));
+        Value::Object(object)
+    };
+
+    Ok(())
+}
+
+/// Encode an OCTET STRING
+///
+/// The octets are encoded as a string of hexadecimal digits.
+pub fn encode_octetstring(
+    data: &mut JerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+    octet_string: &[u8],
+) -> Result<(), JerCodecError> {
+    log::trace!(
+        "encode_octetstring: lb: {:?}, ub: {:?}, is_extensible: {}, length: {}",
+        lb,
+        ub,
+        is_extensible,
+        octet_string.len()
+    );
+
+    check_range(lb, ub, is_extensible, octet_string.len() as i128, "Length")?;
+
+    data.value = Value::String(to_hex(octet_string));
+
+    Ok(())
+}
+
+/// Encode a Character String
+///
+/// Values of all the character string types are encoded as JSON strings.
+pub fn encode_character_string(data: &mut JerCodecData, value: &str) -> Result<(), JerCodecError> {
+    log::trace!("encode_character_string: value: {}", value);
+
+    data.value = Value::String(value.to_string());
+
+    Ok(())
+}
+
+/// Encode a SEQUENCE
+///
+/// The `components` are the encoded values of the components present in the `SEQUENCE` along
+/// with their identifiers and are encoded as the members of a JSON object.
+pub fn encode_sequence(
+    data: &mut JerCodecData,
+    components: Vec<(&str, JerCodecData)>,
+) -> Result<(), JerCodecError> {
+    log::trace!("encode_sequence: components: {}", components.len());
+
+    let object = components
+        .into_iter()
+        .map(|(name, component)| (name.to_string(), component.value))
+        .collect::<Map<String, Value>>();
+    data.value = Value::Object(object);
+
+    Ok(())
+}
+
+/// Encode a SEQUENCE OF
+///
+/// The encoded values of the `items` are encoded as a JSON array.
+pub fn encode_sequence_of(
+    data: &mut JerCodecData,
+    items: Vec<JerCodecData>,
+) -> Result<(), JerCodecError> {
+    log::trace!("encode_sequence_of: items: {}", items.len());
+
+    data.value = Value::Array(items.into_iter().map(|item| item.value).collect());
+
+    Ok(())
+}
+
+/// Encode a CHOICE
+///
+/// The encoded value of the chosen `alternative` is encoded as the only member of a JSON object
+/// with the identifier of the alternative as the name of the member.
+pub fn encode_choice(
+    data: &mut JerCodecData,
+    name: &str,
+    alternative: JerCodecData,
+) -> Result<(), JerCodecError> {
+    log::trace!("encode_choice: alternative: {}", name);
+
+    let mut object = Map::new();
+    object.insert(name.to_string(), alternative.value);
+    data.value = Value::Object(object);
+
+    Ok(())
+}
+
+// Checks that the value (or the size) is in the range given by a non-extensible constraint.
+pub(crate) fn check_range(
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+    value: i128,
+    what: &str,
+) -> Result<(), JerCodecError> {
+    if is_extensible {
+        return Ok(());
+    }
+
+    if lb.is_some_and(|lb| value < lb) || ub.is_some_and(|ub| value > ub) {
+        return Err(JerCodecError::new(format!(
+            "{} {} is outside the range [{:?}, {:?}].",
+            what, value, lb, ub
+        )));
+    }
+
+    Ok(())
+}
+
+// Whether a BIT STRING with the given size constraints has a fixed size.
+pub(crate) fn fixed_size(lb: Option<i128>, ub: Option<i128>, is_extensible: bool) -> bool {
+    !is_extensible && lb.is_some() && lb == ub
+}
+
+fn to_hex(octets: &[u8]) -> String {
+    octets
+        .iter()
+        .map(|octet| format!("{:02X}", octet))
+        .collect()
+}
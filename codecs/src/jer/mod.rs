@@ -0,0 +1,254 @@
+#![allow(dead_code)]
+//! ASN.1 JER Codec
+//!
+//! Encoding and Decoding of ASN.1 Types using the JSON Encoding Rules (X.697). A value of an
+//! ASN.1 Type is represented as a JSON value, where the components of a `SEQUENCE` and the
+//! alternatives of a `CHOICE` use the identifiers from the ASN.1 definition and the values of an
+//! `ENUMERATED` type are represented by their identifiers.
+//!
+//! The encoded JSON values are held as a [`serde_json::Value`] inside the [`JerCodecData`], which
+//! can be converted to and from the JSON text.
+
+pub mod error;
+
+pub mod encode;
+
+pub mod decode;
+
+pub use error::Error as JerCodecError;
+
+/// Structure representing a JER Codec.
+///
+/// While En(De)coding ASN.1 Types using the JER encoding scheme, the encoded data is stored as a
+/// JSON value. A constructed type is decoded from a new `JerCodecData` for each of its
+/// components.
+#[derive(Default, Debug)]
+pub struct JerCodecData {
+    value: serde_json::Value,
+    key: Option<i128>,
+}
+
+impl JerCodecData {
+    /// Default `JerCodecData` for encoding.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create Our `JerCodecData` Structure from a JSON value.
+    pub fn from_value(value: serde_json::Value) -> Self {
+        Self { value, key: None }
+    }
+
+    /// Create Our `JerCodecData` Structure by parsing the JSON text.
+    pub fn from_json(json: &str) -> Result<Self, JerCodecError> {
+        let value = serde_json::from_str(json)
+            .map_err(|e| JerCodecError::new(format!("JerCodec:DecodeError:Invalid JSON: {}", e)))?;
+        Ok(Self::from_value(value))
+    }
+
+    /// Get's the encoded JSON value.
+    pub fn get_value(&self) -> &serde_json::Value {
+        &self.value
+    }
+
+    /// Get's the encoded JSON value consuming the struct.
+    pub fn into_value(self) -> serde_json::Value {
+        self.value
+    }
+
+    /// Get's the encoded JSON value as text.
+    pub fn to_json(&self) -> String {
+        self.value.to_string()
+    }
+
+    /// Get's the encoded JSON value as 'pretty printed' text.
+    pub fn to_json_pretty(&self) -> String {
+        serde_json::to_string_pretty(&self.value).unwrap_or_default()
+    }
+
+    /// Get's the current `key` value.
+    ///
+    /// This value will be used by a decoder to determine which 'decode' function is to be called
+    /// (for example in an `enum`, it will be used to determine which `variant` of the `enum` will
+    /// be decoded.
+    pub fn get_key(&self) -> Option<i128> {
+        self.key
+    }
+
+    /// Sets the current `key` value.
+    ///
+    /// See [`crate::PerCodecData::set_key`] for how this is used for decoding 'open' types.
+    pub fn set_key(&mut self, key: i128) {
+        let _ = self.key.replace(key);
+    }
+
+    /// Dump current 'value'.
+    pub fn dump(&self) {
+        log::trace!("key: {:?}, value: {}", self.key, self.value);
+    }
+
+    // A `JerCodecData` for a component of the current value, the `key` is carried to the
+    // component, so that an open type inside the component can be decoded.
+    fn component(&self, value: serde_json::Value) -> Self {
+        Self {
+            value,
+            key: self.key,
+        }
+    }
+}
+
+/// Trait representing a 'JER Codec'.
+///
+/// This 'trait' is to be derived by any `struct` or `enum` representing an ASN.1 Type.
+pub trait JerCodec {
+    type Output;
+
+    fn jer_decode(data: &mut JerCodecData) -> Result<Self::Output, JerCodecError>;
+
+    fn jer_encode(&self, data: &mut JerCodecData) -> Result<(), JerCodecError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bitvec::prelude::*;
+    use serde_json::json;
+
+    #[test]
+    fn integer_encode_decode() {
+        let mut data = JerCodecData::new();
+        encode::encode_integer(&mut data, Some(0), Some(65535), false, 258).unwrap();
+        assert_eq!(data.get_value(), &json!(258));
+
+        let mut data = JerCodecData::from_value(json!(-5));
+        assert_eq!(
+            decode::decode_integer(&mut data, None, None, false).unwrap(),
+            -5
+        );
+
+        // Value outside the root is an error unless the constraints are extensible.
+        let mut data = JerCodecData::new();
+        assert!(encode::encode_integer(&mut data, Some(0), Some(255), false, 256).is_err());
+        let mut data = JerCodecData::from_value(json!(256));
+        assert!(decode::decode_integer(&mut data, Some(0), Some(255), false).is_err());
+        let mut data = JerCodecData::from_value(json!(256));
+        assert_eq!(
+            decode::decode_integer(&mut data, Some(0), Some(255), true).unwrap(),
+            256
+        );
+
+        let mut data = JerCodecData::from_value(json!("5"));
+        assert!(decode::decode_integer(&mut data, None, None, false).is_err());
+    }
+
+    #[test]
+    fn enumerated_encode_decode() {
+        let named_values = [("reject", 0), ("ignore", 1), ("notify", 2)];
+
+        let mut data = JerCodecData::new();
+        encode::encode_enumerated(&mut data, &named_values, 1).unwrap();
+        assert_eq!(data.get_value(), &json!("ignore"));
+
+        let mut data = JerCodecData::from_value(json!("notify"));
+        assert_eq!(
+            decode::decode_enumerated(&mut data, &named_values).unwrap(),
+            2
+        );
+
+        let mut data = JerCodecData::new();
+        assert!(encode::encode_enumerated(&mut data, &named_values, 3).is_err());
+        let mut data = JerCodecData::from_value(json!("unknown"));
+        assert!(decode::decode_enumerated(&mut data, &named_values).is_err());
+    }
+
+    #[test]
+    fn bitstring_encode_decode() {
+        let bits = bits![u8, Msb0; 1, 0, 1, 1, 0, 1, 1, 1, 1, 0];
+
+        // Fixed Size: Only the hex string.
+        let mut data = JerCodecData::new();
+        encode::encode_bitstring(&mut data, Some(10), Some(10), false, bits).unwrap();
+        assert_eq!(data.get_value(), &json!("B780"));
+        let mut data = JerCodecData::from_value(json!("b780"));
+        let decoded = decode::decode_bitstring(&mut data, Some(10), Some(10), false).unwrap();
+        assert_eq!(decoded, bits);
+
+        // Variable Size: The hex string and the length.
+        let mut data = JerCodecData::new();
+        encode::encode_bitstring(&mut data, Some(0), None, false, bits).unwrap();
+        assert_eq!(data.get_value(), &json!({"value": "B780", "length": 10}));
+        let mut data = JerCodecData::from_value(json!({"value": "B780", "length": 10}));
+        let decoded = decode::decode_bitstring(&mut data, Some(0), None, false).unwrap();
+        assert_eq!(decoded, bits);
+
+        // Not enough octets for the length
+        let mut data = JerCodecData::from_value(json!({"value": "B7", "length": 10}));
+        assert!(decode::decode_bitstring(&mut data, Some(0), None, false).is_err());
+    }
+
+    #[test]
+    fn octetstring_encode_decode() {
+        let octets = vec![0xde, 0xad, 0xbe, 0xef];
+
+        let mut data = JerCodecData::new();
+        encode::encode_octetstring(&mut data, Some(4), Some(4), false, &octets).unwrap();
+        assert_eq!(data.get_value(), &json!("DEADBEEF"));
+
+        let mut data = JerCodecData::from_value(json!("deadbeef"));
+        assert_eq!(
+            decode::decode_octetstring(&mut data, Some(4), Some(4), false).unwrap(),
+            octets
+        );
+
+        let mut data = JerCodecData::from_value(json!("DEADBEE"));
+        assert!(decode::decode_octetstring(&mut data, None, None, false).is_err());
+        let mut data = JerCodecData::from_value(json!("DEAD"));
+        assert!(decode::decode_octetstring(&mut data, Some(4), Some(4), false).is_err());
+    }
+
+    #[test]
+    fn sequence_encode_decode() {
+        let mut data = JerCodecData::new();
+        let mut component = JerCodecData::new();
+        encode::encode_bool(&mut component, true).unwrap();
+        encode::encode_sequence(&mut data, vec![("flag", component)]).unwrap();
+        assert_eq!(data.get_value(), &json!({"flag": true}));
+
+        // A `SEQUENCE` without any components present is an empty object.
+        let mut data = JerCodecData::new();
+        encode::encode_sequence(&mut data, vec![]).unwrap();
+        assert_eq!(data.to_json(), "{}");
+
+        let mut data = JerCodecData::from_value(json!({"flag": true, "other": 1}));
+        data.set_key(5);
+        let mut component = decode::decode_sequence_component(&mut data, "flag")
+            .unwrap()
+            .unwrap();
+        assert_eq!(component.get_key(), Some(5));
+        assert!(decode::decode_bool(&mut component).unwrap());
+        assert!(decode::decode_sequence_component(&mut data, "none")
+            .unwrap()
+            .is_none());
+
+        // Unknown components are allowed only in an extensible `SEQUENCE`.
+        assert!(decode::check_sequence_components(&data, &["flag"], true).is_ok());
+        assert!(decode::check_sequence_components(&data, &["flag"], false).is_err());
+    }
+
+    #[test]
+    fn choice_encode_decode() {
+        let mut data = JerCodecData::new();
+        let mut alternative = JerCodecData::new();
+        encode::encode_null(&mut alternative).unwrap();
+        encode::encode_choice(&mut data, "empty", alternative).unwrap();
+        assert_eq!(data.to_json(), r#"{"empty":null}"#);
+
+        let mut data = JerCodecData::from_json(r#"{"empty": null}"#).unwrap();
+        let (name, mut alternative) = decode::decode_choice(&mut data).unwrap();
+        assert_eq!(name, "empty");
+        assert!(decode::decode_null(&mut alternative).is_ok());
+
+        let mut data = JerCodecData::from_json(r#"{"a": null, "b": null}"#).unwrap();
+        assert!(decode::decode_choice(&mut data).is_err());
+    }
+}
@@ -3,6 +3,16 @@
 #![allow(dead_code)]
 mod per;
 
+pub mod ber;
+
+pub mod oer;
+
+pub mod jer;
+
+pub mod xer;
+
+pub mod time;
+
 #[doc(inline)]
 pub use per::PerCodecData;
 
@@ -14,3 +24,30 @@ pub use per::aper;
 
 #[doc(inline)]
 pub use per::uper;
+
+#[doc(inline)]
+pub use ber::BerCodecData;
+
+#[doc(inline)]
+pub use ber::BerCodecError;
+
+#[doc(inline)]
+pub use oer::OerCodecData;
+
+#[doc(inline)]
+pub use oer::OerCodecError;
+
+#[doc(inline)]
+pub use jer::JerCodecData;
+
+#[doc(inline)]
+pub use jer::JerCodecError;
+
+#[doc(inline)]
+pub use xer::XerCodecData;
+
+#[doc(inline)]
+pub use xer::XerCodecError;
+
+#[doc(inline)]
+pub use time::TimeError;
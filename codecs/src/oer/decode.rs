@@ -0,0 +1,532 @@
+//! Decode APIs for OER Codec
+
+use bitvec::prelude::*;
+
+use crate::ber::{Tag, TagClass};
+use crate::oer::{OerCodecData, OerCodecError};
+use crate::time::DateTime;
+
+/// Decode a Length Determinant
+pub fn decode_length_determinant(data: &mut OerCodecData) -> Result<usize, OerCodecError> {
+    let first = data.get_byte()?;
+    let length = if first & 0x80 == 0 {
+        first as usize
+    } else {
+        let count = (first & 0x7f) as usize;
+        if count == 0 || count > std::mem::size_of::<usize>() {
+            return Err(OerCodecError::new(format!(
+                "OerCodec:DecodeError:Unsupported Length of {} octets for a Length Determinant.",
+                count
+            )));
+        }
+        let octets = data.get_bytes(count)?;
+        if data.coer && (octets[0] == 0 || (count == 1 && octets[0] < 0x80)) {
+            return Err(OerCodecError::new(
+                "CoerCodec:DecodeError:Length not encoded in the minimum number of octets.",
+            ));
+        }
+        octets
+            .iter()
+            .fold(0_usize, |length, octet| (length << 8) | *octet as usize)
+    };
+
+    log::trace!("decode_length_determinant: length: {}", length);
+    Ok(length)
+}
+
+/// Decode an INTEGER
+///
+/// See [`crate::oer::encode::encode_integer`] for how the constraints determine the encoding.
+pub fn decode_integer(
+    data: &mut OerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+) -> Result<i128, OerCodecError> {
+    let (lb, ub) = if is_extensible {
+        (None, None)
+    } else {
+        (lb, ub)
+    };
+    let unsigned = lb.is_some_and(|lb| lb >= 0);
+
+    let (octets, strict) = match crate::oer::encode::integer_fixed_size(lb, ub) {
+        Some(size) => (data.get_bytes(size)?, false),
+        None => {
+            let length = decode_length_determinant(data)?;
+            if length == 0 || length > 16 {
+                return Err(OerCodecError::new(format!(
+                    "OerCodec:DecodeError:Unsupported Length {} for an INTEGER.",
+                    length
+                )));
+            }
+            (data.get_bytes(length)?, data.coer)
+        }
+    };
+
+    let value = if unsigned {
+        if strict && octets.len() > 1 && octets[0] == 0 {
+            return Err(OerCodecError::new(
+                "CoerCodec:DecodeError:INTEGER not encoded in the minimum number of octets.",
+            ));
+        }
+        if octets.len() == 16 && octets[0] & 0x80 != 0 {
+            return Err(OerCodecError::new(
+                "OerCodec:DecodeError:INTEGER value too large.",
+            ));
+        }
+        octets
+            .iter()
+            .fold(0_i128, |value, octet| (value << 8) | *octet as i128)
+    } else {
+        signed_from_octets(&octets, strict)?
+    };
+
+    if let Some(lb) = lb {
+        if value < lb || ub.is_some_and(|ub| value > ub) {
+            return Err(OerCodecError::new(format!(
+                "OerCodec:DecodeError:Value {} is outside the range [{:?}, {:?}].",
+                value, lb, ub
+            )));
+        }
+    }
+
+    log::trace!("decode_integer: value: {}", value);
+    Ok(value)
+}
+
+/// Decode an ENUMERATED Value
+pub fn decode_enumerated(data: &mut OerCodecData) -> Result<i128, OerCodecError> {
+    let first = data.get_byte()?;
+    let value = if first & 0x80 == 0 {
+        first as i128
+    } else {
+        let count = (first & 0x7f) as usize;
+        if count == 0 || count > 16 {
+            return Err(OerCodecError::new(format!(
+                "OerCodec:DecodeError:Unsupported Length {} for an ENUMERATED.",
+                count
+            )));
+        }
+        let octets = data.get_bytes(count)?;
+        let value = signed_from_octets(&octets, data.coer)?;
+        if data.coer && (0..128).contains(&value) {
+            return Err(OerCodecError::new(format!(
+                "CoerCodec:DecodeError:Long form used for ENUMERATED value {}.",
+                value
+            )));
+        }
+        value
+    };
+
+    log::trace!("decode_enumerated: value: {}", value);
+    Ok(value)
+}
+
+/// Decode a BOOLEAN Value
+///
+/// Any non-zero value of the octet is decoded as `true`, except for COER where only `0xFF` is a
+/// valid encoding of `true`.
+pub fn decode_bool(data: &mut OerCodecData) -> Result<bool, OerCodecError> {
+    let octet = data.get_byte()?;
+    if data.coer && octet != 0x00 && octet != 0xFF {
+        return Err(OerCodecError::new(format!(
+            "CoerCodec:DecodeError:Invalid value 0x{:02x} for a BOOLEAN.",
+            octet
+        )));
+    }
+
+    log::trace!("decode_bool: {}", octet != 0);
+    Ok(octet != 0)
+}
+
+/// Decode a BIT STRING
+pub fn decode_bitstring(
+    data: &mut OerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+) -> Result<BitVec<u8, Msb0>, OerCodecError> {
+    let bits = match fixed_size(lb, ub, is_extensible) {
+        Some(size) => {
+            let mut bits = BitVec::<u8, Msb0>::from_vec(data.get_bytes(size.div_ceil(8))?);
+            if data.coer && bits[size..].any() {
+                return Err(OerCodecError::new(
+                    "CoerCodec:DecodeError:Unused bits of a BIT STRING are not zero.",
+                ));
+            }
+            bits.truncate(size);
+            bits
+        }
+        None => {
+            let length = decode_length_determinant(data)?;
+            let contents = data.get_bytes(length)?;
+            bitstring_from_octets(&contents, data.coer)?
+        }
+    };
+
+    log::trace!("decode_bitstring: length: {}", bits.len());
+    Ok(bits)
+}
+
+/// Decode an OCTET STRING
+pub fn decode_octetstring(
+    data: &mut OerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+) -> Result<Vec<u8>, OerCodecError> {
+    let length = match fixed_size(lb, ub, is_extensible) {
+        Some(size) => size,
+        None => decode_length_determinant(data)?,
+    };
+
+    log::trace!("decode_octetstring: length: {}", length);
+    data.get_bytes(length)
+}
+
+/// Decode a VisibleString
+pub fn decode_visible_string(
+    data: &mut OerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+) -> Result<String, OerCodecError> {
+    decode_known_multiplier_string(data, lb, ub, is_extensible, 1, "VisibleString", |c| {
+        (' '..='~').contains(&c)
+    })
+}
+
+/// Decode a UTCTime
+pub fn decode_utc_time(data: &mut OerCodecData) -> Result<String, OerCodecError> {
+    let value = decode_visible_string(data, None, None, false)?;
+    DateTime::from_utc_time(&value).map_err(|e| OerCodecError::new(e.to_string()))?;
+
+    Ok(value)
+}
+
+/// Decode a GeneralizedTime
+pub fn decode_generalized_time(data: &mut OerCodecData) -> Result<String, OerCodecError> {
+    let value = decode_visible_string(data, None, None, false)?;
+    DateTime::from_generalized_time(&value).map_err(|e| OerCodecError::new(e.to_string()))?;
+
+    Ok(value)
+}
+
+/// Decode a PrintableString
+pub fn decode_printable_string(
+    data: &mut OerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+) -> Result<String, OerCodecError> {
+    decode_known_multiplier_string(
+        data,
+        lb,
+        ub,
+        is_extensible,
+        1,
+        "PrintableString",
+        crate::ber::encode::is_printable_char,
+    )
+}
+
+/// Decode an IA5String
+pub fn decode_ia5_string(
+    data: &mut OerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+) -> Result<String, OerCodecError> {
+    decode_known_multiplier_string(data, lb, ub, is_extensible, 1, "IA5String", |c| {
+        c.is_ascii()
+    })
+}
+
+/// Decode a NumericString
+pub fn decode_numeric_string(
+    data: &mut OerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+) -> Result<String, OerCodecError> {
+    decode_known_multiplier_string(data, lb, ub, is_extensible, 1, "NumericString", |c| {
+        c == ' ' || c.is_ascii_digit()
+    })
+}
+
+/// Decode a BMPString
+pub fn decode_bmp_string(
+    data: &mut OerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+) -> Result<String, OerCodecError> {
+    decode_known_multiplier_string(data, lb, ub, is_extensible, 2, "BMPString", |_| true)
+}
+
+/// Decode a UniversalString
+pub fn decode_universal_string(
+    data: &mut OerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+) -> Result<String, OerCodecError> {
+    decode_known_multiplier_string(data, lb, ub, is_extensible, 4, "UniversalString", |_| true)
+}
+
+/// Decode a UTF8String
+pub fn decode_utf8_string(data: &mut OerCodecData) -> Result<String, OerCodecError> {
+    let length = decode_length_determinant(data)?;
+    let octets = data.get_bytes(length)?;
+
+    String::from_utf8(octets).map_err(|e| {
+        OerCodecError::new(format!(
+            "OerCodec:DecodeError:Invalid UTF8String encoding: {}",
+            e
+        ))
+    })
+}
+
+/// Decode a TeletexString
+pub fn decode_teletex_string(data: &mut OerCodecData) -> Result<String, OerCodecError> {
+    let length = decode_length_determinant(data)?;
+    Ok(data
+        .get_bytes(length)?
+        .into_iter()
+        .map(char::from)
+        .collect())
+}
+
+/// Decode a GeneralString
+pub fn decode_general_string(data: &mut OerCodecData) -> Result<String, OerCodecError> {
+    let length = decode_length_determinant(data)?;
+    Ok(data
+        .get_bytes(length)?
+        .into_iter()
+        .map(char::from)
+        .collect())
+}
+
+/// Decode the Preamble of a SEQUENCE
+///
+/// Returns the bitmap of the `OPTIONAL` components present and whether the extension additions
+/// are present.
+pub fn decode_sequence_preamble(
+    data: &mut OerCodecData,
+    is_extensible: bool,
+    optional_count: usize,
+) -> Result<(BitVec<u8, Msb0>, bool), OerCodecError> {
+    let count = optional_count + is_extensible as usize;
+    let mut preamble = BitVec::<u8, Msb0>::from_vec(data.get_bytes(count.div_ceil(8))?);
+    if data.coer && preamble[count..].any() {
+        return Err(OerCodecError::new(
+            "CoerCodec:DecodeError:Padding bits of a SEQUENCE preamble are not zero.",
+        ));
+    }
+    preamble.truncate(count);
+
+    let extended = is_extensible && preamble.remove(0);
+
+    log::trace!(
+        "decode_sequence_preamble: optionals: {:?}, extended: {}",
+        preamble,
+        extended
+    );
+    Ok((preamble, extended))
+}
+
+/// Decode the Extension Addition Presence Bitmap of a SEQUENCE
+///
+/// The bitmap is encoded as a BIT STRING with a bit for each of the extension additions, it is
+/// followed by an open type for each of the additions present.
+pub fn decode_extension_bitmap(data: &mut OerCodecData) -> Result<BitVec<u8, Msb0>, OerCodecError> {
+    let length = decode_length_determinant(data)?;
+    let contents = data.get_bytes(length)?;
+    let bitmap = bitstring_from_octets(&contents, data.coer)?;
+
+    log::trace!("decode_extension_bitmap: bitmap: {:?}", bitmap);
+    Ok(bitmap)
+}
+
+/// Skip the Extension Additions of a SEQUENCE
+///
+/// Unknown extension additions are ignored by the decoder.
+pub fn skip_extension_additions(data: &mut OerCodecData) -> Result<(), OerCodecError> {
+    let bitmap = decode_extension_bitmap(data)?;
+    for _ in bitmap.iter_ones() {
+        let _ = decode_open_type(data)?;
+    }
+
+    Ok(())
+}
+
+/// Decode the Quantity field of a SEQUENCE OF
+pub fn decode_sequence_of_quantity(data: &mut OerCodecData) -> Result<usize, OerCodecError> {
+    let length = decode_length_determinant(data)?;
+    if length == 0 || length > std::mem::size_of::<usize>() {
+        return Err(OerCodecError::new(format!(
+            "OerCodec:DecodeError:Unsupported Length {} for the Quantity of a SEQUENCE OF.",
+            length
+        )));
+    }
+    let octets = data.get_bytes(length)?;
+    if data.coer && octets.len() > 1 && octets[0] == 0 {
+        return Err(OerCodecError::new(
+            "CoerCodec:DecodeError:Quantity not encoded in the minimum number of octets.",
+        ));
+    }
+    let quantity = octets
+        .iter()
+        .fold(0_usize, |quantity, octet| (quantity << 8) | *octet as usize);
+
+    log::trace!("decode_sequence_of_quantity: quantity: {}", quantity);
+    Ok(quantity)
+}
+
+/// Decode the Tag of a CHOICE alternative
+pub fn decode_choice_tag(data: &mut OerCodecData) -> Result<Tag, OerCodecError> {
+    let first = data.get_byte()?;
+    let class = match first >> 6 {
+        0 => TagClass::Universal,
+        1 => TagClass::Application,
+        2 => TagClass::ContextSpecific,
+        _ => TagClass::Private,
+    };
+
+    let number = if first & 0x3f != 0x3f {
+        (first & 0x3f) as u32
+    } else {
+        let mut number = 0_u32;
+        loop {
+            let octet = data.get_byte()?;
+            if number == 0 && octet == 0x80 {
+                return Err(OerCodecError::new(
+                    "OerCodec:DecodeError:Tag number not encoded in the minimum number of octets.",
+                ));
+            }
+            if number > (u32::MAX >> 7) {
+                return Err(OerCodecError::new(
+                    "OerCodec:DecodeError:Tag number too large.",
+                ));
+            }
+            number = (number << 7) | (octet & 0x7f) as u32;
+            if octet & 0x80 == 0 {
+                break;
+            }
+        }
+        if data.coer && number < 63 {
+            return Err(OerCodecError::new(format!(
+                "CoerCodec:DecodeError:Subsequent octets used for Tag number {}.",
+                number
+            )));
+        }
+        number
+    };
+
+    let tag = Tag::new(class, number);
+    log::trace!("decode_choice_tag: tag: {}", tag);
+    Ok(tag)
+}
+
+/// Decode an Open Type
+///
+/// Returns the encoding of the value of the open type as a separate `OerCodecData`, from which the
+/// actual value can be decoded. The `key` of the `data` is carried over to the returned data.
+pub fn decode_open_type(data: &mut OerCodecData) -> Result<OerCodecData, OerCodecError> {
+    let length = decode_length_determinant(data)?;
+
+    let mut value = OerCodecData::from_slice_internal(&data.get_bytes(length)?, data.coer);
+    value.key = data.key;
+
+    log::trace!("decode_open_type: length: {}", length);
+    Ok(value)
+}
+
+// The fixed size of a string type if the size constraints are not extensible and lower and upper
+// bounds are same.
+fn fixed_size(lb: Option<i128>, ub: Option<i128>, is_extensible: bool) -> Option<usize> {
+    if is_extensible || lb.is_none() || lb != ub {
+        None
+    } else {
+        lb.map(|size| size as usize)
+    }
+}
+
+// Decodes a known-multiplier character string type with each of the characters encoded in
+// `octets_per_char` octets.
+fn decode_known_multiplier_string(
+    data: &mut OerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+    octets_per_char: usize,
+    type_name: &str,
+    is_valid: fn(char) -> bool,
+) -> Result<String, OerCodecError> {
+    let length = match fixed_size(lb, ub, is_extensible) {
+        Some(size) => size * octets_per_char,
+        None => decode_length_determinant(data)?,
+    };
+    if length % octets_per_char != 0 {
+        return Err(OerCodecError::new(format!(
+            "OerCodec:DecodeError:Length {} is not a multiple of {} for a {}.",
+            length, octets_per_char, type_name
+        )));
+    }
+
+    log::trace!("decode_known_multiplier_string: length: {}", length);
+    data.get_bytes(length)?
+        .chunks(octets_per_char)
+        .map(|octets| {
+            let value = octets
+                .iter()
+                .fold(0_u32, |value, octet| (value << 8) | *octet as u32);
+            char::from_u32(value)
+                .filter(|c| is_valid(*c))
+                .ok_or_else(|| {
+                    OerCodecError::new(format!(
+                        "OerCodec:DecodeError:Character 0x{:02x} is not valid for a {}.",
+                        value, type_name
+                    ))
+                })
+        })
+        .collect()
+}
+
+fn signed_from_octets(octets: &[u8], strict: bool) -> Result<i128, OerCodecError> {
+    if strict
+        && octets.len() > 1
+        && ((octets[0] == 0x00 && octets[1] & 0x80 == 0)
+            || (octets[0] == 0xFF && octets[1] & 0x80 != 0))
+    {
+        return Err(OerCodecError::new(
+            "CoerCodec:DecodeError:INTEGER not encoded in the minimum number of octets.",
+        ));
+    }
+
+    let initial = if octets[0] & 0x80 != 0 { -1_i128 } else { 0 };
+    Ok(octets
+        .iter()
+        .fold(initial, |value, octet| (value << 8) | *octet as i128))
+}
+
+// Decodes the unused bits octet followed by the bits of a BIT STRING.
+fn bitstring_from_octets(octets: &[u8], strict: bool) -> Result<BitVec<u8, Msb0>, OerCodecError> {
+    if octets.is_empty() || octets[0] > 7 || (octets.len() == 1 && octets[0] != 0) {
+        return Err(OerCodecError::new(
+            "OerCodec:DecodeError:Invalid encoding for a BIT STRING.",
+        ));
+    }
+
+    let unused = octets[0] as usize;
+    let mut bits = BitVec::<u8, Msb0>::from_slice(&octets[1..]);
+    if strict && bits[bits.len() - unused..].any() {
+        return Err(OerCodecError::new(
+            "CoerCodec:DecodeError:Unused bits of a BIT STRING are not zero.",
+        ));
+    }
+    bits.truncate(bits.len() - unused);
+
+    Ok(bits)
+}
@@ -0,0 +1,547 @@
+//! Encode APIs for OER Codec
+
+use std::convert::TryFrom;
+
+use bitvec::prelude::*;
+
+use crate::ber::Tag;
+use crate::oer::{OerCodecData, OerCodecError};
+use crate::time::DateTime;
+
+/// Encode a Length Determinant
+///
+/// Lengths less than 128 are encoded in the 'short' form in a single octet, others are encoded
+/// in the 'long' form where the first octet gives the number of subsequent length octets.
+pub fn encode_length_determinant(
+    data: &mut OerCodecData,
+    length: usize,
+) -> Result<(), OerCodecError> {
+    log::trace!("encode_length_determinant: length: {}", length);
+
+    if length < 128 {
+        data.append_bytes(&[length as u8]);
+    } else {
+        let octets = unsigned_octets(length as u128);
+        data.append_bytes(&[0x80 | octets.len() as u8]);
+        data.append_bytes(&octets);
+    }
+
+    Ok(())
+}
+
+/// Encode an INTEGER
+///
+/// If the constraints are not extensible and both the bounds fit in one of 1, 2, 4 or 8 octets,
+/// the value is encoded in that many octets. Otherwise the value is encoded in the minimum number
+/// of octets preceded by a length determinant. The encoding is unsigned, if the lower bound is
+/// non-negative.
+pub fn encode_integer(
+    data: &mut OerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+    value: i128,
+) -> Result<(), OerCodecError> {
+    log::trace!(
+        "encode_integer: lb: {:?}, ub: {:?}, is_extensible: {}, value: {}",
+        lb,
+        ub,
+        is_extensible,
+        value
+    );
+
+    let (lb, ub) = if is_extensible {
+        (None, None)
+    } else {
+        (lb, ub)
+    };
+
+    if let Some(lb) = lb {
+        if value < lb || ub.is_some_and(|ub| value > ub) {
+            return Err(OerCodecError::new(format!(
+                "Value {} is outside the range [{:?}, {:?}].",
+                value, lb, ub
+            )));
+        }
+    }
+
+    let unsigned = lb.is_some_and(|lb| lb >= 0);
+    match integer_fixed_size(lb, ub) {
+        Some(size) => {
+            let bytes = value.to_be_bytes();
+            data.append_bytes(&bytes[bytes.len() - size..]);
+        }
+        None => {
+            let octets = if unsigned {
+                unsigned_octets(value as u128)
+            } else {
+                signed_octets(value)
+            };
+            encode_length_determinant(data, octets.len())?;
+            data.append_bytes(&octets);
+        }
+    }
+
+    Ok(())
+}
+
+/// Encode an ENUMERATED Value
+///
+/// Values from 0 to 127 are encoded in a single octet, others are encoded as a length octet
+/// followed by the value in two's complement form.
+pub fn encode_enumerated(data: &mut OerCodecData, value: i128) -> Result<(), OerCodecError> {
+    log::trace!("encode_enumerated: value: {}", value);
+
+    if (0..128).contains(&value) {
+        data.append_bytes(&[value as u8]);
+    } else {
+        let octets = signed_octets(value);
+        data.append_bytes(&[0x80 | octets.len() as u8]);
+        data.append_bytes(&octets);
+    }
+
+    Ok(())
+}
+
+/// Encode a BOOLEAN Value
+pub fn encode_bool(data: &mut OerCodecData, value: bool) -> Result<(), OerCodecError> {
+    log::trace!("encode_bool: {}", value);
+
+    data.append_bytes(&[if value { 0xFF } else { 0x00 }]);
+
+    Ok(())
+}
+
+/// Encode a BIT STRING
+///
+/// A fixed size BIT STRING is encoded without a length determinant and the unused bits octet.
+pub fn encode_bitstring(
+    data: &mut OerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+    bit_string: &BitSlice<u8, Msb0>,
+) -> Result<(), OerCodecError> {
+    log::trace!(
+        "encode_bitstring: lb: {:?}, ub: {:?}, is_extensible: {}, length: {}",
+        lb,
+        ub,
+        is_extensible,
+        bit_string.len()
+    );
+
+    let contents = crate::ber::encode::bitstring_contents_octets(bit_string);
+    match fixed_size(lb, ub, is_extensible, bit_string.len())? {
+        true => data.append_bytes(&contents[1..]),
+        false => {
+            encode_length_determinant(data, contents.len())?;
+            data.append_bytes(&contents);
+        }
+    }
+
+    Ok(())
+}
+
+/// Encode an OCTET STRING
+///
+/// A fixed size OCTET STRING is encoded without a length determinant.
+pub fn encode_octetstring(
+    data: &mut OerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+    octet_string: &[u8],
+) -> Result<(), OerCodecError> {
+    log::trace!(
+        "encode_octetstring: lb: {:?}, ub: {:?}, is_extensible: {}, length: {}",
+        lb,
+        ub,
+        is_extensible,
+        octet_string.len()
+    );
+
+    if !fixed_size(lb, ub, is_extensible, octet_string.len())? {
+        encode_length_determinant(data, octet_string.len())?;
+    }
+    data.append_bytes(octet_string);
+
+    Ok(())
+}
+
+/// Encode a VisibleString
+pub fn encode_visible_string(
+    data: &mut OerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+    value: &str,
+) -> Result<(), OerCodecError> {
+    log::trace!("encode_visible_string: value: {}", value);
+
+    encode_known_multiplier_string(
+        data,
+        lb,
+        ub,
+        is_extensible,
+        value,
+        1,
+        "VisibleString",
+        |c| (' '..='~').contains(&c),
+    )
+}
+
+/// Encode a UTCTime
+///
+/// The value is encoded as a VisibleString after the validation of its lexical form.
+pub fn encode_utc_time(data: &mut OerCodecData, value: &str) -> Result<(), OerCodecError> {
+    log::trace!("encode_utc_time: value: {}", value);
+
+    DateTime::from_utc_time(value).map_err(|e| OerCodecError::new(e.to_string()))?;
+    encode_visible_string(data, None, None, false, value)
+}
+
+/// Encode a GeneralizedTime
+///
+/// The value is encoded as a VisibleString after the validation of its lexical form.
+pub fn encode_generalized_time(data: &mut OerCodecData, value: &str) -> Result<(), OerCodecError> {
+    log::trace!("encode_generalized_time: value: {}", value);
+
+    DateTime::from_generalized_time(value).map_err(|e| OerCodecError::new(e.to_string()))?;
+    encode_visible_string(data, None, None, false, value)
+}
+
+/// Encode a PrintableString
+pub fn encode_printable_string(
+    data: &mut OerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+    value: &str,
+) -> Result<(), OerCodecError> {
+    log::trace!("encode_printable_string: value: {}", value);
+
+    encode_known_multiplier_string(
+        data,
+        lb,
+        ub,
+        is_extensible,
+        value,
+        1,
+        "PrintableString",
+        crate::ber::encode::is_printable_char,
+    )
+}
+
+/// Encode an IA5String
+pub fn encode_ia5_string(
+    data: &mut OerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+    value: &str,
+) -> Result<(), OerCodecError> {
+    log::trace!("encode_ia5_string: value: {}", value);
+
+    encode_known_multiplier_string(data, lb, ub, is_extensible, value, 1, "IA5String", |c| {
+        c.is_ascii()
+    })
+}
+
+/// Encode a NumericString
+pub fn encode_numeric_string(
+    data: &mut OerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+    value: &str,
+) -> Result<(), OerCodecError> {
+    log::trace!("encode_numeric_string: value: {}", value);
+
+    encode_known_multiplier_string(
+        data,
+        lb,
+        ub,
+        is_extensible,
+        value,
+        1,
+        "NumericString",
+        |c| c == ' ' || c.is_ascii_digit(),
+    )
+}
+
+/// Encode a BMPString
+///
+/// Each of the characters is encoded in two octets.
+pub fn encode_bmp_string(
+    data: &mut OerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+    value: &str,
+) -> Result<(), OerCodecError> {
+    log::trace!("encode_bmp_string: value: {}", value);
+
+    encode_known_multiplier_string(data, lb, ub, is_extensible, value, 2, "BMPString", |c| {
+        (c as u32) <= 0xffff
+    })
+}
+
+/// Encode a UniversalString
+///
+/// Each of the characters is encoded in four octets.
+pub fn encode_universal_string(
+    data: &mut OerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+    value: &str,
+) -> Result<(), OerCodecError> {
+    log::trace!("encode_universal_string: value: {}", value);
+
+    encode_known_multiplier_string(
+        data,
+        lb,
+        ub,
+        is_extensible,
+        value,
+        4,
+        "UniversalString",
+        |_| true,
+    )
+}
+
+/// Encode a UTF8String
+///
+/// The size constraints are in characters and not in octets, so a UTF8String is always encoded
+/// with a length determinant.
+pub fn encode_utf8_string(data: &mut OerCodecData, value: &str) -> Result<(), OerCodecError> {
+    log::trace!("encode_utf8_string: value: {}", value);
+
+    encode_length_determinant(data, value.len())?;
+    data.append_bytes(value.as_bytes());
+
+    Ok(())
+}
+
+/// Encode a TeletexString
+///
+/// Each of the characters is encoded in a single octet, always with a length determinant.
+pub fn encode_teletex_string(data: &mut OerCodecData, value: &str) -> Result<(), OerCodecError> {
+    log::trace!("encode_teletex_string: value: {}", value);
+
+    encode_octet_per_char_string(data, value)
+}
+
+/// Encode a GeneralString
+///
+/// Each of the characters is encoded in a single octet, always with a length determinant.
+pub fn encode_general_string(data: &mut OerCodecData, value: &str) -> Result<(), OerCodecError> {
+    log::trace!("encode_general_string: value: {}", value);
+
+    encode_octet_per_char_string(data, value)
+}
+
+/// Encode the Preamble of a SEQUENCE
+///
+/// The preamble is a bitmap with the extension bit (if the `SEQUENCE` is extensible) followed by
+/// a bit for each of the `OPTIONAL` components, padded with zero bits to an octet boundary.
+pub fn encode_sequence_preamble(
+    data: &mut OerCodecData,
+    is_extensible: bool,
+    optionals: &BitSlice<u8, Msb0>,
+    extended: bool,
+) -> Result<(), OerCodecError> {
+    log::trace!(
+        "encode_sequence_preamble: is_extensible: {}, optional_fields: {:?}, extended: {}",
+        is_extensible,
+        optionals,
+        extended
+    );
+
+    let mut preamble = BitVec::<u8, Msb0>::new();
+    if is_extensible {
+        preamble.push(extended);
+    }
+    preamble.extend_from_bitslice(optionals);
+    preamble.set_uninitialized(false);
+    data.append_bytes(preamble.as_raw_slice());
+
+    Ok(())
+}
+
+/// Encode the Extension Addition Presence Bitmap of a SEQUENCE
+///
+/// The `bitmap` has a bit for each of the extension additions of the `SEQUENCE` and is encoded as
+/// a BIT STRING with a length determinant.
+pub fn encode_extension_bitmap(
+    data: &mut OerCodecData,
+    bitmap: &BitSlice<u8, Msb0>,
+) -> Result<(), OerCodecError> {
+    log::trace!("encode_extension_bitmap: bitmap: {:?}", bitmap);
+
+    let contents = crate::ber::encode::bitstring_contents_octets(bitmap);
+    encode_length_determinant(data, contents.len())?;
+    data.append_bytes(&contents);
+
+    Ok(())
+}
+
+/// Encode the Quantity field of a SEQUENCE OF
+///
+/// The number of elements is encoded as an unsigned integer in the minimum number of octets
+/// preceded by a length determinant.
+pub fn encode_sequence_of_quantity(
+    data: &mut OerCodecData,
+    quantity: usize,
+) -> Result<(), OerCodecError> {
+    log::trace!("encode_sequence_of_quantity: quantity: {}", quantity);
+
+    let octets = unsigned_octets(quantity as u128);
+    encode_length_determinant(data, octets.len())?;
+    data.append_bytes(&octets);
+
+    Ok(())
+}
+
+/// Encode the Tag of a CHOICE alternative
+///
+/// The first octet has the class of the tag in the two most significant bits and the tag number
+/// in the remaining bits if it is less than 63. Larger tag numbers are encoded in base 128 in the
+/// subsequent octets.
+pub fn encode_choice_tag(data: &mut OerCodecData, tag: Tag) -> Result<(), OerCodecError> {
+    log::trace!("encode_choice_tag: tag: {}", tag);
+
+    let first = (tag.class as u8) << 6;
+    if tag.number < 63 {
+        data.append_bytes(&[first | tag.number as u8]);
+    } else {
+        let mut octets = vec![(tag.number & 0x7f) as u8];
+        let mut number = tag.number >> 7;
+        while number > 0 {
+            octets.push((number & 0x7f) as u8 | 0x80);
+            number >>= 7;
+        }
+        octets.push(first | 0x3f);
+        octets.reverse();
+        data.append_bytes(&octets);
+    }
+
+    Ok(())
+}
+
+/// Encode an Open Type
+///
+/// The `value` is the complete encoding of the value of the open type, which is encoded preceded
+/// by a length determinant.
+pub fn encode_open_type(
+    data: &mut OerCodecData,
+    value: &OerCodecData,
+) -> Result<(), OerCodecError> {
+    log::trace!("encode_open_type: length: {}", value.length_in_bytes());
+
+    encode_length_determinant(data, value.length_in_bytes())?;
+    data.append_bytes(&value.bytes);
+
+    Ok(())
+}
+
+// Number of octets used for a fixed size encoding of an INTEGER if any.
+pub(crate) fn integer_fixed_size(lb: Option<i128>, ub: Option<i128>) -> Option<usize> {
+    let (lb, ub) = (lb?, ub?);
+    let sizes = [1_usize, 2, 4, 8];
+    if lb >= 0 {
+        sizes.iter().copied().find(|size| ub < 1_i128 << (size * 8))
+    } else {
+        sizes.iter().copied().find(|size| {
+            let limit = 1_i128 << (size * 8 - 1);
+            lb >= -limit && ub < limit
+        })
+    }
+}
+
+// Whether a string type with the given size constraints is encoded without a length determinant.
+// It is an error if the `length` does not match a fixed size.
+fn fixed_size(
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+    length: usize,
+) -> Result<bool, OerCodecError> {
+    if is_extensible || lb.is_none() || lb != ub {
+        return Ok(false);
+    }
+
+    let size = lb.unwrap();
+    if length as i128 != size {
+        return Err(OerCodecError::new(format!(
+            "Length {} does not match the fixed size {}.",
+            length, size
+        )));
+    }
+
+    Ok(true)
+}
+
+// Encodes each of the characters of a known-multiplier character string type in `octets_per_char`
+// octets. The size constraints are in characters, the length determinant is in octets.
+#[allow(clippy::too_many_arguments)]
+fn encode_known_multiplier_string(
+    data: &mut OerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+    value: &str,
+    octets_per_char: usize,
+    type_name: &str,
+    is_valid: fn(char) -> bool,
+) -> Result<(), OerCodecError> {
+    let mut octets = Vec::with_capacity(value.len() * octets_per_char);
+    for c in value.chars() {
+        if !is_valid(c) {
+            return Err(OerCodecError::new(format!(
+                "Character '{}' is not valid for a {}.",
+                c, type_name
+            )));
+        }
+        octets.extend_from_slice(&(c as u32).to_be_bytes()[4 - octets_per_char..]);
+    }
+
+    if !fixed_size(lb, ub, is_extensible, octets.len() / octets_per_char)? {
+        encode_length_determinant(data, octets.len())?;
+    }
+    data.append_bytes(&octets);
+
+    Ok(())
+}
+
+fn encode_octet_per_char_string(data: &mut OerCodecData, value: &str) -> Result<(), OerCodecError> {
+    let octets = value
+        .chars()
+        .map(|c| {
+            u8::try_from(c).map_err(|_| {
+                OerCodecError::new(format!(
+                    "Character '{}' cannot be encoded in a single octet.",
+                    c
+                ))
+            })
+        })
+        .collect::<Result<Vec<u8>, _>>()?;
+
+    encode_length_determinant(data, octets.len())?;
+    data.append_bytes(&octets);
+
+    Ok(())
+}
+
+fn unsigned_octets(value: u128) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    let first = bytes
+        .iter()
+        .position(|b| *b != 0)
+        .unwrap_or(bytes.len() - 1);
+    bytes[first..].to_vec()
+}
+
+fn signed_octets(value: i128) -> Vec<u8> {
+    crate::ber::encode::integer_contents_octets(value)
+}
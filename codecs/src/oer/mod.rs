@@ -0,0 +1,380 @@
+#![allow(dead_code)]
+//! ASN.1 OER Codec
+//!
+//! Encoding and Decoding of ASN.1 Types using the Octet Encoding Rules (X.696). All the encodings
+//! are an integral number of octets and the constraints on the types are used to determine
+//! whether the lengths of the values are encoded or not.
+//!
+//! The encoder always produces the Canonical OER (COER) encodings. A `OerCodecData` created using
+//! [`OerCodecData::from_slice_coer`] decodes in a strict mode, where any encoding that is a valid
+//! BASIC-OER encoding but not a valid COER encoding is rejected.
+
+pub mod error;
+
+pub mod encode;
+
+pub mod decode;
+
+pub use error::Error as OerCodecError;
+
+/// Structure representing an OER Codec.
+///
+/// While En(De)coding ASN.1 Types using the OER encoding scheme, the encoded data is stored in a
+/// `Vec<u8>`.
+#[derive(Default, Debug)]
+pub struct OerCodecData {
+    bytes: Vec<u8>,
+    decode_offset: usize,
+    key: Option<i128>,
+    coer: bool,
+}
+
+impl OerCodecData {
+    /// Default `OerCodecData` for encoding.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create Our `OerCodecData` Structure from a slice of u8
+    pub fn from_slice(bytes: &[u8]) -> Self {
+        Self::from_slice_internal(bytes, false)
+    }
+
+    /// Create Our `OerCodecData` Structure from a slice of u8 for (strict) COER decoding.
+    pub fn from_slice_coer(bytes: &[u8]) -> Self {
+        Self::from_slice_internal(bytes, true)
+    }
+
+    fn from_slice_internal(bytes: &[u8], coer: bool) -> Self {
+        Self {
+            bytes: bytes.to_vec(),
+            decode_offset: 0,
+            key: None,
+            coer,
+        }
+    }
+
+    /// Whether the data is decoded using the strict COER rules.
+    pub fn is_coer(&self) -> bool {
+        self.coer
+    }
+
+    /// Get's the inner buffer as a `Vec<u8>` consuming the struct.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    /// Get's the encoded bytes as a `Vec<u8>`.
+    pub fn get_inner(&self) -> Result<Vec<u8>, OerCodecError> {
+        Ok(self.bytes.clone())
+    }
+
+    /// Length of the encoded data in bytes.
+    pub fn length_in_bytes(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Number of bytes not yet decoded.
+    pub fn remaining(&self) -> usize {
+        self.bytes.len() - self.decode_offset
+    }
+
+    /// Whether all the bytes in the buffer are decoded.
+    pub fn is_empty(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    /// Get's the current `key` value.
+    ///
+    /// This value will be used by a decoder to determine which 'decode' function is to be called
+    /// (for example in an `enum`, it will be used to determine which `variant` of the `enum` will
+    /// be decoded.
+    pub fn get_key(&self) -> Option<i128> {
+        self.key
+    }
+
+    /// Sets the current `key` value.
+    ///
+    /// See [`crate::PerCodecData::set_key`] for how this is used for decoding 'open' types.
+    pub fn set_key(&mut self, key: i128) {
+        let _ = self.key.replace(key);
+    }
+
+    /// Checks whether the encoded bytes are the same as that of the `other` encoding.
+    /// This is useful when deciding whether a component with a `DEFAULT` value is to be encoded.
+    pub fn same_encoding(&self, other: &Self) -> bool {
+        self.bytes == other.bytes
+    }
+
+    /// Dump current 'offset'.
+    pub fn dump(&self) {
+        log::trace!("offset: {}, bytes: {:02x?}", self.decode_offset, self.bytes);
+    }
+
+    fn get_byte(&mut self) -> Result<u8, OerCodecError> {
+        let byte = self.bytes.get(self.decode_offset).copied().ok_or_else(|| {
+            OerCodecError::new("OerCodec:DecodeError:End of Buffer reached while decoding.")
+        })?;
+        self.decode_offset += 1;
+        Ok(byte)
+    }
+
+    fn get_bytes(&mut self, length: usize) -> Result<Vec<u8>, OerCodecError> {
+        if self.remaining() < length {
+            return Err(OerCodecError::new(format!(
+                "OerCodec:DecodeError:Requested Bytes to decode {}, Remaining bytes {}",
+                length,
+                self.remaining()
+            )));
+        }
+        let bytes = self.bytes[self.decode_offset..self.decode_offset + length].to_vec();
+        self.decode_offset += length;
+        Ok(bytes)
+    }
+
+    fn append_bytes(&mut self, bytes: &[u8]) {
+        self.bytes.extend_from_slice(bytes);
+    }
+}
+
+/// Trait representing an 'OER Codec'.
+///
+/// This 'trait' is to be derived by any `struct` or `enum` representing an ASN.1 Type.
+pub trait OerCodec {
+    type Output;
+
+    fn oer_decode(data: &mut OerCodecData) -> Result<Self::Output, OerCodecError>;
+
+    fn oer_encode(&self, data: &mut OerCodecData) -> Result<(), OerCodecError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bitvec::prelude::*;
+
+    #[test]
+    fn length_encode_decode() {
+        let lengths = vec![
+            (0, vec![0x00]),
+            (127, vec![0x7f]),
+            (128, vec![0x81, 0x80]),
+            (256, vec![0x82, 0x01, 0x00]),
+        ];
+        for (length, encoded) in lengths {
+            let mut data = OerCodecData::new();
+            encode::encode_length_determinant(&mut data, length).unwrap();
+            assert_eq!(data.bytes, encoded);
+
+            let mut data = OerCodecData::from_slice_coer(&encoded);
+            assert_eq!(
+                decode::decode_length_determinant(&mut data).unwrap(),
+                length
+            );
+        }
+
+        // Long form for a length less than 128 is fine in BASIC-OER but not in COER.
+        let mut data = OerCodecData::from_slice(&[0x81, 0x05]);
+        assert_eq!(decode::decode_length_determinant(&mut data).unwrap(), 5);
+        let mut data = OerCodecData::from_slice_coer(&[0x81, 0x05]);
+        assert!(decode::decode_length_determinant(&mut data).is_err());
+    }
+
+    #[test]
+    fn integer_encode_decode() {
+        let values = vec![
+            // Fixed size unsigned
+            (Some(0), Some(255), false, 200, vec![0xc8]),
+            (Some(0), Some(65535), false, 258, vec![0x01, 0x02]),
+            (
+                Some(1),
+                Some(4294967295),
+                false,
+                1,
+                vec![0x00, 0x00, 0x00, 0x01],
+            ),
+            // Fixed size signed
+            (Some(-128), Some(127), false, -1, vec![0xff]),
+            (Some(-1000), Some(1000), false, -2, vec![0xff, 0xfe]),
+            // Length prefixed unsigned
+            (Some(0), None, false, 256, vec![0x02, 0x01, 0x00]),
+            (Some(0), None, false, 0, vec![0x01, 0x00]),
+            // Length prefixed signed
+            (None, None, false, -129, vec![0x02, 0xff, 0x7f]),
+            (None, Some(10), false, 5, vec![0x01, 0x05]),
+            // Extensible constraints are not OER visible.
+            (Some(0), Some(255), true, 200, vec![0x02, 0x00, 0xc8]),
+        ];
+        for (lb, ub, ext, value, encoded) in values {
+            let mut data = OerCodecData::new();
+            encode::encode_integer(&mut data, lb, ub, ext, value).unwrap();
+            assert_eq!(data.bytes, encoded, "value: {}", value);
+
+            let mut data = OerCodecData::from_slice_coer(&encoded);
+            assert_eq!(
+                decode::decode_integer(&mut data, lb, ub, ext).unwrap(),
+                value
+            );
+        }
+
+        // Value outside the root is an error for the fixed size encodings.
+        let mut data = OerCodecData::new();
+        assert!(encode::encode_integer(&mut data, Some(0), Some(255), false, 256).is_err());
+
+        // Redundant leading octets
+        let mut data = OerCodecData::from_slice(&[0x02, 0x00, 0x05]);
+        assert_eq!(
+            decode::decode_integer(&mut data, None, None, false).unwrap(),
+            5
+        );
+        let mut data = OerCodecData::from_slice_coer(&[0x02, 0x00, 0x05]);
+        assert!(decode::decode_integer(&mut data, None, None, false).is_err());
+    }
+
+    #[test]
+    fn enumerated_encode_decode() {
+        let values = vec![
+            (0, vec![0x00]),
+            (127, vec![0x7f]),
+            (128, vec![0x82, 0x00, 0x80]),
+            (-1, vec![0x81, 0xff]),
+        ];
+        for (value, encoded) in values {
+            let mut data = OerCodecData::new();
+            encode::encode_enumerated(&mut data, value).unwrap();
+            assert_eq!(data.bytes, encoded);
+
+            let mut data = OerCodecData::from_slice_coer(&encoded);
+            assert_eq!(decode::decode_enumerated(&mut data).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn bitstring_encode_decode() {
+        let bits = bits![u8, Msb0; 1, 0, 1, 1, 0, 1, 1, 1, 1, 0];
+
+        // Fixed Size: No length or unused bits octet.
+        let mut data = OerCodecData::new();
+        encode::encode_bitstring(&mut data, Some(10), Some(10), false, bits).unwrap();
+        assert_eq!(data.bytes, vec![0xb7, 0x80]);
+        let mut data = OerCodecData::from_slice(&[0xb7, 0x80]);
+        let decoded = decode::decode_bitstring(&mut data, Some(10), Some(10), false).unwrap();
+        assert_eq!(decoded, bits);
+
+        let mut data = OerCodecData::new();
+        encode::encode_bitstring(&mut data, Some(0), None, false, bits).unwrap();
+        assert_eq!(data.bytes, vec![0x03, 0x06, 0xb7, 0x80]);
+        let mut data = OerCodecData::from_slice(&[0x03, 0x06, 0xb7, 0x80]);
+        let decoded = decode::decode_bitstring(&mut data, Some(0), None, false).unwrap();
+        assert_eq!(decoded, bits);
+
+        // Unused bits not zero
+        let mut data = OerCodecData::from_slice_coer(&[0x03, 0x06, 0xb7, 0x81]);
+        assert!(decode::decode_bitstring(&mut data, Some(0), None, false).is_err());
+    }
+
+    #[test]
+    fn octetstring_encode_decode() {
+        let octets = vec![0xde, 0xad, 0xbe, 0xef];
+
+        let mut data = OerCodecData::new();
+        encode::encode_octetstring(&mut data, Some(4), Some(4), false, &octets).unwrap();
+        assert_eq!(data.bytes, octets);
+
+        let mut data = OerCodecData::new();
+        encode::encode_octetstring(&mut data, Some(4), Some(4), true, &octets).unwrap();
+        assert_eq!(data.bytes, vec![0x04, 0xde, 0xad, 0xbe, 0xef]);
+
+        let mut data = OerCodecData::new();
+        assert!(encode::encode_octetstring(&mut data, Some(2), Some(2), false, &octets).is_err());
+    }
+
+    #[test]
+    fn charstring_encode_decode() {
+        // Fixed Size: The size is in characters and each character of a BMPString takes two octets.
+        let mut data = OerCodecData::new();
+        encode::encode_bmp_string(&mut data, Some(2), Some(2), false, "\u{3b1}b").unwrap();
+        assert_eq!(data.bytes, vec![0x03, 0xb1, 0x00, 0x62]);
+        let mut data = OerCodecData::from_slice(&[0x03, 0xb1, 0x00, 0x62]);
+        let decoded = decode::decode_bmp_string(&mut data, Some(2), Some(2), false).unwrap();
+        assert_eq!(decoded, "\u{3b1}b");
+
+        // The length determinant is in octets.
+        let mut data = OerCodecData::new();
+        encode::encode_universal_string(&mut data, None, None, false, "a").unwrap();
+        assert_eq!(data.bytes, vec![0x04, 0x00, 0x00, 0x00, 0x61]);
+        let mut data = OerCodecData::from_slice(&[0x03, 0x00, 0x00, 0x61]);
+        assert!(decode::decode_universal_string(&mut data, None, None, false).is_err());
+
+        let mut data = OerCodecData::new();
+        assert!(encode::encode_numeric_string(&mut data, None, None, false, "12a").is_err());
+        assert!(encode::encode_ia5_string(&mut data, None, None, false, "\u{e9}").is_err());
+        encode::encode_teletex_string(&mut data, "\u{e9}").unwrap();
+        assert_eq!(data.bytes, vec![0x01, 0xe9]);
+        let mut data = OerCodecData::from_slice(&[0x01, 0xe9]);
+        assert_eq!(decode::decode_teletex_string(&mut data).unwrap(), "\u{e9}");
+
+        // The Useful Time Types are encoded as a VisibleString.
+        let mut data = OerCodecData::new();
+        assert!(encode::encode_utc_time(&mut data, "4912312359").is_err());
+        encode::encode_utc_time(&mut data, "4912312359Z").unwrap();
+        assert_eq!(data.bytes[0], 0x0b);
+        let mut data = OerCodecData::from_slice(&data.bytes);
+        assert_eq!(decode::decode_utc_time(&mut data).unwrap(), "4912312359Z");
+    }
+
+    #[test]
+    fn sequence_preamble_encode_decode() {
+        let optionals = bits![u8, Msb0; 1, 0, 1];
+
+        let mut data = OerCodecData::new();
+        encode::encode_sequence_preamble(&mut data, true, optionals, false).unwrap();
+        assert_eq!(data.bytes, vec![0x50]);
+
+        let mut data = OerCodecData::from_slice(&[0x50]);
+        let (bitmap, extended) = decode::decode_sequence_preamble(&mut data, true, 3).unwrap();
+        assert_eq!(bitmap, optionals);
+        assert!(!extended);
+
+        // Non-zero padding bits
+        let mut data = OerCodecData::from_slice_coer(&[0x51]);
+        assert!(decode::decode_sequence_preamble(&mut data, true, 3).is_err());
+    }
+
+    #[test]
+    fn choice_tag_encode_decode() {
+        let tags = vec![
+            (crate::ber::Tag::context(3), vec![0x83]),
+            (crate::ber::Tag::context(63), vec![0xbf, 0x3f]),
+            (crate::ber::Tag::application(200), vec![0x7f, 0x81, 0x48]),
+        ];
+        for (tag, encoded) in tags {
+            let mut data = OerCodecData::new();
+            encode::encode_choice_tag(&mut data, tag).unwrap();
+            assert_eq!(data.bytes, encoded);
+
+            let mut data = OerCodecData::from_slice_coer(&encoded);
+            assert_eq!(decode::decode_choice_tag(&mut data).unwrap(), tag);
+        }
+    }
+
+    #[test]
+    fn open_type_extensions_skipped() {
+        // Presence bitmap for 2 additions with only the second present and its encoding.
+        let encoded = [0x02, 0x06, 0x40, 0x02, 0x01, 0x02];
+        let mut data = OerCodecData::from_slice(&encoded);
+        decode::skip_extension_additions(&mut data).unwrap();
+        assert!(data.is_empty());
+    }
+
+    #[test]
+    fn extension_bitmap_encode_decode() {
+        let bitmap = bits![u8, Msb0; 0, 1];
+        let mut data = OerCodecData::new();
+        encode::encode_extension_bitmap(&mut data, bitmap).unwrap();
+        assert_eq!(data.bytes, [0x02, 0x06, 0x40]);
+
+        let mut data = OerCodecData::from_slice_coer(&[0x02, 0x06, 0x40]);
+        assert_eq!(decode::decode_extension_bitmap(&mut data).unwrap(), bitmap);
+    }
+}
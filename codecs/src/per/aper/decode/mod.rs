@@ -45,6 +45,29 @@ pub fn decode_sequence_header(
     decode_sequence_header_common(data, is_extensible, optional_count, true)
 }
 
+/// Decode the bitmap of the extension additions of a sequence
+///
+/// This is decoded after the components in the extension root when the sequence header indicates
+/// that the extension additions are present. Each of the additions present is then decoded as an
+/// open type using [`decode_open_type`].
+pub fn decode_sequence_extension_bitmap(
+    data: &mut PerCodecData,
+) -> Result<BitVec<u8, Msb0>, PerCodecError> {
+    log::trace!("decode_sequence_extension_bitmap");
+
+    decode_sequence_extension_bitmap_common(data, true)
+}
+
+/// Decode an Open Type
+///
+/// Returns the encoding of the value of the open type as a separate `PerCodecData`, from which
+/// the actual value can be decoded. The `key` of the `data` is carried over to the returned data.
+pub fn decode_open_type(data: &mut PerCodecData) -> Result<PerCodecData, PerCodecError> {
+    log::trace!("decode_open_type");
+
+    decode_open_type_common(data, true)
+}
+
 /// Decode an Integer
 ///
 /// Given an Integer Specification with PER Visible Constraints, decode an Integer Value to obtain
@@ -143,6 +166,29 @@ pub fn decode_octetstring(
     decode_octetstring_common(data, lb, ub, is_extensible, true)
 }
 
+/// Decode an OBJECT IDENTIFIER
+///
+/// Returns the arcs (components) of the value.
+pub fn decode_object_identifier(data: &mut PerCodecData) -> Result<Vec<u128>, PerCodecError> {
+    log::trace!("decode_object_identifier");
+
+    decode_object_identifier_common(data, false, true)
+}
+
+/// Decode a RELATIVE-OID
+pub fn decode_relative_oid(data: &mut PerCodecData) -> Result<Vec<u128>, PerCodecError> {
+    log::trace!("decode_relative_oid");
+
+    decode_object_identifier_common(data, true, true)
+}
+
+/// Decode a REAL
+pub fn decode_real(data: &mut PerCodecData) -> Result<f64, PerCodecError> {
+    log::trace!("decode_real");
+
+    decode_real_common(data, true)
+}
+
 /// Decodes a Length determinent
 pub fn decode_length_determinent(
     data: &mut PerCodecData,
@@ -155,5 +201,32 @@ pub fn decode_length_determinent(
     decode_length_determinent_common(data, lb, ub, normally_small, true)
 }
 
+/// Decode the items of a SEQUENCE OF preceded by their count
+///
+/// `decode_items` is called with the number of items to be decoded. It is called once for each
+/// fragment, when there are 16K items or more.
+pub fn decode_sequence_of<F>(
+    data: &mut PerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+    decode_items: F,
+) -> Result<(), PerCodecError>
+where
+    F: FnMut(&mut PerCodecData, usize) -> Result<(), PerCodecError>,
+{
+    log::trace!(
+        "decode_sequence_of: lb: {:?}, ub: {:?}, is_extensible: {}",
+        lb,
+        ub,
+        is_extensible
+    );
+
+    decode_sequence_of_common(data, lb, ub, is_extensible, decode_items, true)
+}
+
 mod decode_charstrings;
 pub use decode_charstrings::*;
+
+mod decode_time;
+pub use decode_time::*;
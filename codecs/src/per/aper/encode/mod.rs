@@ -7,6 +7,10 @@ use crate::per::PerCodecData;
 #[allow(unused)]
 use crate::per::common::encode::*;
 
+use crate::per::common::charset::*;
+
+use crate::per::common::time::*;
+
 use crate::PerCodecError;
 
 /// Encode a Choice Index
@@ -50,6 +54,29 @@ pub fn encode_sequence_header(
     encode_sequence_header_common(data, is_extensible, optionals, extended, true)
 }
 
+/// Encode the bitmap of the extension additions of a sequence
+///
+/// When any of the extension additions of an extensible sequence are present, the bitmap with a
+/// bit for each of the extension additions follows the components in the extension root. Each of
+/// the additions present is then encoded as an open type using [`encode_open_type`].
+pub fn encode_sequence_extension_bitmap(
+    data: &mut PerCodecData,
+    bitmap: &BitSlice<u8, Msb0>,
+) -> Result<(), PerCodecError> {
+    log::trace!("encode_sequence_extension_bitmap: bitmap: {:?}", bitmap);
+
+    encode_sequence_extension_bitmap_common(data, bitmap, true)
+}
+
+/// Encode an Open Type
+///
+/// The `value` is the complete encoding of the value of the open type.
+pub fn encode_open_type(data: &mut PerCodecData, value: PerCodecData) -> Result<(), PerCodecError> {
+    log::trace!("encode_open_type: length: {}", value.bits.len());
+
+    encode_open_type_common(data, value, true)
+}
+
 /// Encode an INTEGER
 ///
 /// This API is also used by other `encode` functions to encode an integer value.
@@ -148,6 +175,34 @@ pub fn encode_octetstring(
     encode_octet_string_common(data, lb, ub, is_extensible, octet_string, extended, true)
 }
 
+/// Encode a REAL
+///
+/// The value is encoded in the binary form with the base 2, as in the CER/DER encoding.
+pub fn encode_real(data: &mut PerCodecData, value: f64) -> Result<(), PerCodecError> {
+    log::trace!("encode_real: value: {}", value);
+
+    encode_real_common(data, value, true)
+}
+
+/// Encode an OBJECT IDENTIFIER
+///
+/// The `arcs` are the components of the value. eg. `[1, 2, 840, 113549]` for `1.2.840.113549`.
+pub fn encode_object_identifier(
+    data: &mut PerCodecData,
+    arcs: &[u128],
+) -> Result<(), PerCodecError> {
+    log::trace!("encode_object_identifier: arcs: {:?}", arcs);
+
+    encode_object_identifier_common(data, arcs, false, true)
+}
+
+/// Encode a RELATIVE-OID
+pub fn encode_relative_oid(data: &mut PerCodecData, arcs: &[u128]) -> Result<(), PerCodecError> {
+    log::trace!("encode_relative_oid: arcs: {:?}", arcs);
+
+    encode_object_identifier_common(data, arcs, true, true)
+}
+
 // Encode a Length Determinent
 pub fn encode_length_determinent(
     data: &mut PerCodecData,
@@ -167,6 +222,32 @@ pub fn encode_length_determinent(
     encode_length_determinent_common(data, lb, ub, normally_small, value, true)
 }
 
+/// Encode the items of a SEQUENCE OF preceded by their count
+///
+/// `encode_items` is called with the range of the items to be encoded. It is called once for each
+/// fragment, when there are 16K items or more.
+pub fn encode_sequence_of<F>(
+    data: &mut PerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+    length: usize,
+    encode_items: F,
+) -> Result<(), PerCodecError>
+where
+    F: FnMut(&mut PerCodecData, std::ops::Range<usize>) -> Result<(), PerCodecError>,
+{
+    log::trace!(
+        "encode_sequence_of: lb: {:?}, ub: {:?}, is_extensible: {}, length: {}",
+        lb,
+        ub,
+        is_extensible,
+        length
+    );
+
+    encode_sequence_of_common(data, lb, ub, is_extensible, length, encode_items, true)
+}
+
 /// Encode a VisibleString CharacterString Type.
 pub fn encode_visible_string(
     data: &mut PerCodecData,
@@ -185,7 +266,16 @@ pub fn encode_visible_string(
         extended
     );
 
-    encode_string_common(data, lb, ub, is_extensible, value, extended, true)
+    encode_known_multiplier_string_common(
+        data,
+        lb,
+        ub,
+        is_extensible,
+        value,
+        extended,
+        &VISIBLE_STRING,
+        true,
+    )
 }
 
 /// Encode a PrintableString CharacterString Type.
@@ -206,11 +296,80 @@ pub fn encode_printable_string(
         extended
     );
 
-    encode_string_common(data, lb, ub, is_extensible, value, extended, true)
+    encode_known_multiplier_string_common(
+        data,
+        lb,
+        ub,
+        is_extensible,
+        value,
+        extended,
+        &PRINTABLE_STRING,
+        true,
+    )
 }
 
-/// Encode a UTF8String CharacterString Type.
-pub fn encode_utf8_string(
+/// Encode a IA5String CharacterString Type.
+pub fn encode_ia5_string(
+    data: &mut PerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+    value: &String,
+    extended: bool,
+) -> Result<(), PerCodecError> {
+    log::trace!(
+        "encode_ia5_string: lb: {:?}, ub: {:?}, is_extensible: {}, value: {}, extended: {}",
+        lb,
+        ub,
+        is_extensible,
+        value,
+        extended
+    );
+
+    encode_known_multiplier_string_common(
+        data,
+        lb,
+        ub,
+        is_extensible,
+        value,
+        extended,
+        &IA5_STRING,
+        true,
+    )
+}
+
+/// Encode a NumericString CharacterString Type.
+pub fn encode_numeric_string(
+    data: &mut PerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+    value: &String,
+    extended: bool,
+) -> Result<(), PerCodecError> {
+    log::trace!(
+        "encode_numeric_string: lb: {:?}, ub: {:?}, is_extensible: {}, value: {}, extended: {}",
+        lb,
+        ub,
+        is_extensible,
+        value,
+        extended
+    );
+
+    encode_known_multiplier_string_common(
+        data,
+        lb,
+        ub,
+        is_extensible,
+        value,
+        extended,
+        &NUMERIC_STRING,
+        true,
+    )
+}
+
+/// Encode a BMPString CharacterString Type.
+pub fn encode_bmp_string(
     data: &mut PerCodecData,
     lb: Option<i128>,
     ub: Option<i128>,
@@ -219,7 +378,7 @@ pub fn encode_utf8_string(
     extended: bool,
 ) -> Result<(), PerCodecError> {
     log::trace!(
-        "encode_utf8_string: lb: {:?}, ub: {:?}, is_extensible: {}, value: {}, extended: {}",
+        "encode_bmp_string: lb: {:?}, ub: {:?}, is_extensible: {}, value: {}, extended: {}",
         lb,
         ub,
         is_extensible,
@@ -227,7 +386,179 @@ pub fn encode_utf8_string(
         extended
     );
 
-    encode_string_common(data, lb, ub, is_extensible, value, extended, true)
+    encode_known_multiplier_string_common(
+        data,
+        lb,
+        ub,
+        is_extensible,
+        value,
+        extended,
+        &BMP_STRING,
+        true,
+    )
+}
+
+/// Encode a UniversalString CharacterString Type.
+pub fn encode_universal_string(
+    data: &mut PerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+    value: &String,
+    extended: bool,
+) -> Result<(), PerCodecError> {
+    log::trace!(
+        "encode_universal_string: lb: {:?}, ub: {:?}, is_extensible: {}, value: {}, extended: {}",
+        lb,
+        ub,
+        is_extensible,
+        value,
+        extended
+    );
+
+    encode_known_multiplier_string_common(
+        data,
+        lb,
+        ub,
+        is_extensible,
+        value,
+        extended,
+        &UNIVERSAL_STRING,
+        true,
+    )
+}
+
+/// Encode a known-multiplier CharacterString Type with a PermittedAlphabet constraint.
+///
+/// The `alphabet` has all the characters of the effective permitted alphabet, which decides the
+/// number of bits used for each of the characters.
+#[allow(clippy::too_many_arguments)]
+pub fn encode_permitted_alphabet_string(
+    data: &mut PerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+    value: &String,
+    extended: bool,
+    alphabet: &str,
+) -> Result<(), PerCodecError> {
+    log::trace!(
+        "encode_permitted_alphabet_string: lb: {:?}, ub: {:?}, is_extensible: {}, value: {}, extended: {}, alphabet: {}",
+        lb,
+        ub,
+        is_extensible,
+        value,
+        extended,
+        alphabet
+    );
+
+    encode_known_multiplier_string_common(
+        data,
+        lb,
+        ub,
+        is_extensible,
+        value,
+        extended,
+        &CharacterSet::from_alphabet(alphabet),
+        true,
+    )
+}
+
+/// Encode a UTF8String CharacterString Type.
+///
+/// The size constraints of a UTF8String are not PER-visible, so `lb`, `ub`, `is_extensible` and
+/// `extended` are not used.
+pub fn encode_utf8_string(
+    data: &mut PerCodecData,
+    _lb: Option<i128>,
+    _ub: Option<i128>,
+    _is_extensible: bool,
+    value: &String,
+    _extended: bool,
+) -> Result<(), PerCodecError> {
+    log::trace!("encode_utf8_string: value: {}", value);
+
+    encode_non_known_multiplier_string_common(data, value, true, true)
+}
+
+/// Encode a TeletexString CharacterString Type.
+///
+/// The size constraints of a TeletexString are not PER-visible, so `lb`, `ub`, `is_extensible` and
+/// `extended` are not used.
+/// Each of the characters is encoded as a single octet.
+pub fn encode_teletex_string(
+    data: &mut PerCodecData,
+    _lb: Option<i128>,
+    _ub: Option<i128>,
+    _is_extensible: bool,
+    value: &String,
+    _extended: bool,
+) -> Result<(), PerCodecError> {
+    log::trace!("encode_teletex_string: value: {}", value);
+
+    encode_non_known_multiplier_string_common(data, value, false, true)
+}
+
+/// Encode a GeneralString CharacterString Type.
+///
+/// The size constraints of a GeneralString are not PER-visible, so `lb`, `ub`, `is_extensible` and
+/// `extended` are not used.
+/// Each of the characters is encoded as a single octet.
+pub fn encode_general_string(
+    data: &mut PerCodecData,
+    _lb: Option<i128>,
+    _ub: Option<i128>,
+    _is_extensible: bool,
+    value: &String,
+    _extended: bool,
+) -> Result<(), PerCodecError> {
+    log::trace!("encode_general_string: value: {}", value);
+
+    encode_non_known_multiplier_string_common(data, value, false, true)
+}
+
+// Section 32 of X.691 for all the Time Types.
+
+/// Encode a UTCTime Value.
+pub fn encode_utc_time(data: &mut PerCodecData, value: &str) -> Result<(), PerCodecError> {
+    log::trace!("encode_utc_time: value: {}", value);
+
+    encode_useful_time_common(data, value, false, true)
+}
+
+/// Encode a GeneralizedTime Value.
+pub fn encode_generalized_time(data: &mut PerCodecData, value: &str) -> Result<(), PerCodecError> {
+    log::trace!("encode_generalized_time: value: {}", value);
+
+    encode_useful_time_common(data, value, true, true)
+}
+
+/// Encode a DATE Value.
+pub fn encode_date(data: &mut PerCodecData, value: &str) -> Result<(), PerCodecError> {
+    log::trace!("encode_date: value: {}", value);
+
+    encode_date_common(data, value, true)
+}
+
+/// Encode a TIME-OF-DAY Value.
+pub fn encode_time_of_day(data: &mut PerCodecData, value: &str) -> Result<(), PerCodecError> {
+    log::trace!("encode_time_of_day: value: {}", value);
+
+    encode_time_of_day_common(data, value, true)
+}
+
+/// Encode a DATE-TIME Value.
+pub fn encode_date_time(data: &mut PerCodecData, value: &str) -> Result<(), PerCodecError> {
+    log::trace!("encode_date_time: value: {}", value);
+
+    encode_date_time_common(data, value, true)
+}
+
+/// Encode a DURATION Value.
+pub fn encode_duration(data: &mut PerCodecData, value: &str) -> Result<(), PerCodecError> {
+    log::trace!("encode_duration: value: {}", value);
+
+    encode_duration_common(data, value, true)
 }
 
 #[cfg(test)]
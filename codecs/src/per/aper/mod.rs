@@ -108,6 +108,251 @@ mod tests {
         assert_eq!(s1, s2);
     }
 
+    #[test]
+    fn sequence_extensions_coding() {
+        let mut d = PerCodecData::new_aper();
+        encode::encode_sequence_header(&mut d, true, bits![u8, Msb0;], true).unwrap();
+        encode::encode_sequence_extension_bitmap(&mut d, bits![u8, Msb0; 0, 1]).unwrap();
+        let mut addition = PerCodecData::new_aper();
+        encode::encode_bool(&mut addition, true).unwrap();
+        encode::encode_open_type(&mut d, addition).unwrap();
+        assert_eq!(d.get_inner().unwrap(), vec![0x81, 0x40, 0x01, 0x80]);
+
+        let (_, extended) = decode::decode_sequence_header(&mut d, true, 0).unwrap();
+        assert!(extended);
+        let bitmap = decode::decode_sequence_extension_bitmap(&mut d).unwrap();
+        assert_eq!(bitmap, bits![u8, Msb0; 0, 1]);
+        let mut addition = decode::decode_open_type(&mut d).unwrap();
+        assert!(decode::decode_bool(&mut addition).unwrap());
+    }
+
+    #[test]
+    fn choice_extension_index_coding() {
+        let mut d = PerCodecData::new_aper();
+        encode::encode_choice_idx(&mut d, 0, 2, true, 1, true).unwrap();
+        assert_eq!(d.get_inner().unwrap(), vec![0x81]);
+        assert_eq!(
+            decode::decode_choice_idx(&mut d, 0, 2, true).unwrap(),
+            (1, true)
+        );
+
+        let mut d = PerCodecData::new_aper();
+        encode::encode_choice_idx(&mut d, 0, 2, true, 70, true).unwrap();
+        assert_eq!(d.get_inner().unwrap(), vec![0xc0, 0x01, 0x46]);
+        assert_eq!(
+            decode::decode_choice_idx(&mut d, 0, 2, true).unwrap(),
+            (70, true)
+        );
+
+        let mut d = PerCodecData::new_aper();
+        assert!(encode::encode_choice_idx(&mut d, 0, 2, false, 1, true).is_err());
+    }
+
+    #[test]
+    fn enumerated_extension_value_coding() {
+        let mut d = PerCodecData::new_aper();
+        encode::encode_enumerated(&mut d, Some(0), Some(1), true, 1, true).unwrap();
+        assert_eq!(d.get_inner().unwrap(), vec![0x81]);
+        assert_eq!(
+            decode::decode_enumerated(&mut d, Some(0), Some(1), true).unwrap(),
+            (1, true)
+        );
+
+        let mut d = PerCodecData::new_aper();
+        assert!(encode::encode_enumerated(&mut d, Some(0), Some(1), false, 1, true).is_err());
+    }
+
+    #[test]
+    fn object_identifier_coding() {
+        let mut d = PerCodecData::new_aper();
+        encode::encode_object_identifier(&mut d, &[1, 2, 840, 113549]).unwrap();
+        assert_eq!(
+            d.get_inner().unwrap(),
+            vec![0x06, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d]
+        );
+        assert_eq!(
+            decode::decode_object_identifier(&mut d).unwrap(),
+            vec![1, 2, 840, 113549]
+        );
+
+        let mut d = PerCodecData::new_aper();
+        encode::encode_relative_oid(&mut d, &[8571, 3, 2]).unwrap();
+        assert_eq!(d.get_inner().unwrap(), vec![0x04, 0xc2, 0x7b, 0x03, 0x02]);
+        assert_eq!(
+            decode::decode_relative_oid(&mut d).unwrap(),
+            vec![8571, 3, 2]
+        );
+
+        let mut d = PerCodecData::new_aper();
+        assert!(encode::encode_object_identifier(&mut d, &[1, 40]).is_err());
+        assert!(encode::encode_object_identifier(&mut d, &[1]).is_err());
+
+        let mut d = PerCodecData::from_slice_aper(&[0x02, 0x80, 0x01]);
+        assert!(decode::decode_relative_oid(&mut d).is_err());
+        let mut d = PerCodecData::from_slice_aper(&[0x02, 0x2a, 0x86]);
+        assert!(decode::decode_object_identifier(&mut d).is_err());
+    }
+
+    #[test]
+    fn fragmented_octetstring_coding() {
+        // 64K octets in the first fragment, followed by the length of the remaining octets.
+        let octets = (0..70000).map(|i| i as u8).collect::<Vec<u8>>();
+        let mut d = PerCodecData::new_aper();
+        encode::encode_octetstring(&mut d, None, None, false, &octets, false).unwrap();
+        let encoded = d.into_bytes();
+        assert_eq!(encoded.len(), 1 + 65536 + 2 + 4464);
+        assert_eq!(encoded[0], 0xc4);
+        assert_eq!(encoded[65537..65539], [0x91, 0x70]);
+        let mut d = PerCodecData::from_slice_aper(&encoded);
+        assert_eq!(
+            decode::decode_octetstring(&mut d, None, None, false).unwrap(),
+            octets
+        );
+
+        // An exact multiple of 16K is followed by an empty final fragment.
+        let octets = vec![0x5a; 16384];
+        let mut d = PerCodecData::new_aper();
+        encode::encode_octetstring(&mut d, Some(1), Some(100000), false, &octets, false).unwrap();
+        let encoded = d.into_bytes();
+        assert_eq!(encoded.len(), 1 + 16384 + 1);
+        assert_eq!((encoded[0], encoded[16385]), (0xc1, 0x00));
+        let mut d = PerCodecData::from_slice_aper(&encoded);
+        assert_eq!(
+            decode::decode_octetstring(&mut d, Some(1), Some(100000), false).unwrap(),
+            octets
+        );
+
+        // Lengths constrained to less than 64K are never fragmented.
+        let octets = vec![0xa5; 17000];
+        let mut d = PerCodecData::new_aper();
+        encode::encode_octetstring(&mut d, Some(0), Some(20000), false, &octets, false).unwrap();
+        let encoded = d.into_bytes();
+        assert_eq!(encoded[..2], [0x42, 0x68]);
+        let mut d = PerCodecData::from_slice_aper(&encoded);
+        assert_eq!(
+            decode::decode_octetstring(&mut d, Some(0), Some(20000), false).unwrap(),
+            octets
+        );
+
+        // A truncated fragment is an error.
+        let mut truncated = vec![0xc2];
+        truncated.resize(100, 0);
+        let mut d = PerCodecData::from_slice_aper(&truncated);
+        assert!(decode::decode_octetstring(&mut d, None, None, false).is_err());
+    }
+
+    #[test]
+    fn fragmented_bitstring_coding() {
+        let bits = (0..40000).map(|i| i % 3 == 0).collect::<BitVec<u8, Msb0>>();
+        let mut d = PerCodecData::new_aper();
+        encode::encode_bitstring(&mut d, None, None, false, &bits, false).unwrap();
+        let mut d = PerCodecData::from_slice_aper(&d.into_bytes());
+        assert_eq!(
+            decode::decode_bitstring(&mut d, None, None, false).unwrap(),
+            bits
+        );
+    }
+
+    #[test]
+    fn fragmented_open_type_coding() {
+        let mut value = PerCodecData::new_aper();
+        encode::encode_octetstring(
+            &mut value,
+            Some(20000),
+            Some(20000),
+            false,
+            &vec![1; 20000],
+            false,
+        )
+        .unwrap();
+        let mut d = PerCodecData::new_aper();
+        encode::encode_open_type(&mut d, value).unwrap();
+        let mut d = PerCodecData::from_slice_aper(&d.into_bytes());
+        let mut value = decode::decode_open_type(&mut d).unwrap();
+        assert_eq!(
+            decode::decode_octetstring(&mut value, Some(20000), Some(20000), false).unwrap(),
+            vec![1; 20000]
+        );
+    }
+
+    #[test]
+    fn known_multiplier_string_coding() {
+        // Four bits for each of the characters of a NumericString.
+        let mut d = PerCodecData::new_aper();
+        let s1 = "123".to_string();
+        encode::encode_numeric_string(&mut d, None, None, false, &s1, false).unwrap();
+        assert_eq!(d.get_inner().unwrap(), vec![0x03, 0x23, 0x40]);
+        let s2 = decode::decode_numeric_string(&mut d, None, None, false).unwrap();
+        assert_eq!(s1, s2);
+
+        // Characters of an IA5String take an octet and are aligned when longer than two octets.
+        let mut d = PerCodecData::new_aper();
+        let s1 = "abc".to_string();
+        encode::encode_bool(&mut d, true).unwrap();
+        encode::encode_ia5_string(&mut d, Some(3), Some(3), false, &s1, false).unwrap();
+        assert_eq!(d.get_inner().unwrap(), vec![0x80, 0x61, 0x62, 0x63]);
+        assert!(decode::decode_bool(&mut d).unwrap());
+        let s2 = decode::decode_ia5_string(&mut d, Some(3), Some(3), false).unwrap();
+        assert_eq!(s1, s2);
+
+        let mut d = PerCodecData::new_aper();
+        let s1 = "\u{3b1}\u{3b2}".to_string();
+        encode::encode_bmp_string(&mut d, None, None, false, &s1, false).unwrap();
+        assert_eq!(d.get_inner().unwrap(), vec![0x02, 0x03, 0xb1, 0x03, 0xb2]);
+        let s2 = decode::decode_bmp_string(&mut d, None, None, false).unwrap();
+        assert_eq!(s1, s2);
+
+        let mut d = PerCodecData::new_aper();
+        let s1 = "\u{1f600}".to_string();
+        assert!(encode::encode_bmp_string(&mut d, None, None, false, &s1, false).is_err());
+        encode::encode_universal_string(&mut d, None, None, false, &s1, false).unwrap();
+        assert_eq!(d.get_inner().unwrap(), vec![0x01, 0x00, 0x01, 0xf6, 0x00]);
+        let s2 = decode::decode_universal_string(&mut d, None, None, false).unwrap();
+        assert_eq!(s1, s2);
+    }
+
+    #[test]
+    fn real_coding() {
+        let mut d = PerCodecData::new_aper();
+        encode::encode_real(&mut d, 1.0).unwrap();
+        encode::encode_real(&mut d, -0.375).unwrap();
+        encode::encode_real(&mut d, 0.0).unwrap();
+        encode::encode_real(&mut d, f64::NEG_INFINITY).unwrap();
+        assert_eq!(
+            d.get_inner().unwrap(),
+            vec![0x03, 0x80, 0x00, 0x01, 0x03, 0xc0, 0xfd, 0x03, 0x00, 0x01, 0x41]
+        );
+        assert_eq!(decode::decode_real(&mut d).unwrap(), 1.0);
+        assert_eq!(decode::decode_real(&mut d).unwrap(), -0.375);
+        assert_eq!(decode::decode_real(&mut d).unwrap(), 0.0);
+        assert_eq!(decode::decode_real(&mut d).unwrap(), f64::NEG_INFINITY);
+
+        for value in [f64::MAX, f64::MIN_POSITIVE, 5e-324, -1.0e300, std::f64::consts::PI] {
+            let mut d = PerCodecData::new_aper();
+            encode::encode_real(&mut d, value).unwrap();
+            assert_eq!(decode::decode_real(&mut d).unwrap(), value);
+        }
+
+        let mut d = PerCodecData::new_aper();
+        encode::encode_real(&mut d, f64::NAN).unwrap();
+        assert!(decode::decode_real(&mut d).unwrap().is_nan());
+
+        // Base 16 with a scale factor, and the decimal NR3 form.
+        let mut d = PerCodecData::from_slice_aper(&[0x03, 0xa4, 0x01, 0x03]);
+        assert_eq!(decode::decode_real(&mut d).unwrap(), 96.0);
+        let mut d = PerCodecData::from_slice_aper(&[0x06, 0x03, b'-', b'1', b',', b'5', b'E']);
+        assert!(decode::decode_real(&mut d).is_err());
+        let mut d = PerCodecData::from_slice_aper(&[0x06, 0x03, b'-', b'1', b',', b'5', b'3']);
+        assert_eq!(decode::decode_real(&mut d).unwrap(), -1.53);
+        let mut d = PerCodecData::from_slice_aper(&[0x05, 0x03, b'1', b'5', b'E', b'1']);
+        assert_eq!(decode::decode_real(&mut d).unwrap(), 150.0);
+
+        let mut d = PerCodecData::from_slice_aper(&[0x02, 0x40, 0x00]);
+        assert!(decode::decode_real(&mut d).is_err());
+        let mut d = PerCodecData::from_slice_aper(&[0x02, 0xb0, 0x01]);
+        assert!(decode::decode_real(&mut d).is_err());
+    }
+
     #[test]
     fn empty_string() {
         let mut d = PerCodecData::new_aper();
@@ -116,4 +361,44 @@ mod tests {
         let s2 = decode::decode_printable_string(&mut d, None, None, false).unwrap();
         assert_eq!(s1, s2);
     }
+
+    #[test]
+    fn time_types_coding() {
+        let mut d = PerCodecData::new_aper();
+        encode::encode_date(&mut d, "2024-02-29").unwrap();
+        assert_eq!(d.get_inner().unwrap(), vec![0x40, 0x03, 0x1e, 0x00]);
+        assert_eq!(decode::decode_date(&mut d).unwrap(), "2024-02-29");
+
+        // A year in the `remainder` of the years.
+        let mut d = PerCodecData::new_aper();
+        encode::encode_date_time(&mut d, "1600-01-01T24:00:00").unwrap();
+        assert_eq!(
+            decode::decode_date_time(&mut d).unwrap(),
+            "1600-01-01T24:00:00"
+        );
+
+        let mut d = PerCodecData::new_aper();
+        encode::encode_duration(&mut d, "PT0.5S").unwrap();
+        assert_eq!(
+            d.get_inner().unwrap(),
+            vec![0x03, 0x01, 0x00, 0x01, 0x00, 0x01, 0x05]
+        );
+        assert_eq!(decode::decode_duration(&mut d).unwrap(), "PT0.5S");
+
+        let mut d = PerCodecData::new_aper();
+        encode::encode_utc_time(&mut d, "491231235959Z").unwrap();
+        encode::encode_generalized_time(&mut d, "20240229123456.025+0530").unwrap();
+        encode::encode_time_of_day(&mut d, "23:59:60").unwrap();
+        assert_eq!(decode::decode_utc_time(&mut d).unwrap(), "491231235959Z");
+        assert_eq!(
+            decode::decode_generalized_time(&mut d).unwrap(),
+            "20240229123456.025+0530"
+        );
+        assert_eq!(decode::decode_time_of_day(&mut d).unwrap(), "23:59:60");
+
+        let mut d = PerCodecData::new_aper();
+        assert!(encode::encode_utc_time(&mut d, "20240229123456Z").is_err());
+        assert!(encode::encode_date(&mut d, "2023-02-29").is_err());
+        assert!(encode::encode_duration(&mut d, "P1S").is_err());
+    }
 }
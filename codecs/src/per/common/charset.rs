@@ -0,0 +1,215 @@
+//! Character sets of the known-multiplier character string types (Section 30.5)
+
+use std::borrow::Cow;
+
+use crate::PerCodecError;
+
+// The characters permitted in a known-multiplier character string type. The characters are given
+// as ranges of their values in the ascending order, which is also the canonical order of the
+// characters. (Section 30.5.4)
+pub(crate) struct CharacterSet {
+    name: &'static str,
+    ranges: Cow<'static, [(u32, u32)]>,
+}
+
+pub(crate) const NUMERIC_STRING: CharacterSet = CharacterSet {
+    name: "NumericString",
+    ranges: Cow::Borrowed(&[(0x20, 0x20), (0x30, 0x39)]),
+};
+
+pub(crate) const PRINTABLE_STRING: CharacterSet = CharacterSet {
+    name: "PrintableString",
+    ranges: Cow::Borrowed(&[
+        (0x20, 0x20),
+        (0x27, 0x29),
+        (0x2b, 0x3a),
+        (0x3d, 0x3d),
+        (0x3f, 0x3f),
+        (0x41, 0x5a),
+        (0x61, 0x7a),
+    ]),
+};
+
+pub(crate) const VISIBLE_STRING: CharacterSet = CharacterSet {
+    name: "VisibleString",
+    ranges: Cow::Borrowed(&[(0x20, 0x7e)]),
+};
+
+pub(crate) const IA5_STRING: CharacterSet = CharacterSet {
+    name: "IA5String",
+    ranges: Cow::Borrowed(&[(0x00, 0x7f)]),
+};
+
+pub(crate) const BMP_STRING: CharacterSet = CharacterSet {
+    name: "BMPString",
+    ranges: Cow::Borrowed(&[(0x0000, 0xffff)]),
+};
+
+pub(crate) const UNIVERSAL_STRING: CharacterSet = CharacterSet {
+    name: "UniversalString",
+    ranges: Cow::Borrowed(&[(0x0000_0000, 0xffff_ffff)]),
+};
+
+impl CharacterSet {
+    // The effective permitted alphabet of a string type with a PermittedAlphabet constraint.
+    // (Section 30.5.2)
+    pub(crate) fn from_alphabet(alphabet: &str) -> Self {
+        let mut values = alphabet.chars().map(|c| c as u32).collect::<Vec<u32>>();
+        values.sort_unstable();
+        values.dedup();
+
+        let mut ranges: Vec<(u32, u32)> = vec![];
+        for value in values {
+            match ranges.last_mut() {
+                Some((_, hi)) if *hi + 1 == value => *hi = value,
+                _ => ranges.push((value, value)),
+            }
+        }
+
+        Self {
+            name: "string with a PermittedAlphabet constraint",
+            ranges: Cow::Owned(ranges),
+        }
+    }
+
+    // Number of bits used for encoding each of the characters. This is the number of bits needed
+    // for the number of the characters in the set, rounded up to a power of two for the ALIGNED
+    // variant. (Section 30.5.2 and 30.5.3)
+    pub(crate) fn bits_per_char(&self, aligned: bool) -> usize {
+        let count: u64 = self
+            .ranges
+            .iter()
+            .map(|(lo, hi)| (hi - lo) as u64 + 1)
+            .sum();
+        let bits = (64 - count.saturating_sub(1).leading_zeros()) as usize;
+        if aligned {
+            bits.next_power_of_two()
+        } else {
+            bits
+        }
+    }
+
+    // When the largest value of a character does not fit in `bits`, the characters are encoded as
+    // their indexes in the canonical order instead of their values. (Section 30.5.4)
+    fn is_indexed(&self, bits: usize) -> bool {
+        let largest = self.ranges.last().map(|(_, hi)| *hi as u64).unwrap_or(0);
+        largest >= 1_u64 << bits
+    }
+
+    // Returns the value to be encoded in `bits` for the character `c`.
+    pub(crate) fn encode_char(&self, c: char, bits: usize) -> Result<u32, PerCodecError> {
+        let value = c as u32;
+        let mut index = 0;
+        for &(lo, hi) in self.ranges.iter() {
+            if (lo..=hi).contains(&value) {
+                return Ok(if self.is_indexed(bits) {
+                    index + value - lo
+                } else {
+                    value
+                });
+            }
+            index += hi - lo + 1;
+        }
+
+        Err(PerCodecError::new(format!(
+            "Character '{}' is not valid for a {}.",
+            c.escape_default(),
+            self.name
+        )))
+    }
+
+    // Returns the character for the `value` decoded from `bits`.
+    pub(crate) fn decode_char(&self, value: u32, bits: usize) -> Result<char, PerCodecError> {
+        let mut decoded = None;
+        if self.is_indexed(bits) {
+            let mut index = value;
+            for &(lo, hi) in self.ranges.iter() {
+                if index <= hi - lo {
+                    decoded = Some(lo + index);
+                    break;
+                }
+                index -= hi - lo + 1;
+            }
+        } else if self
+            .ranges
+            .iter()
+            .any(|(lo, hi)| (*lo..=*hi).contains(&value))
+        {
+            decoded = Some(value);
+        }
+
+        decoded.and_then(char::from_u32).ok_or_else(|| {
+            PerCodecError::new(format!(
+                "PerCodec:DecodeError:Character 0x{:x} is not valid for a {}.",
+                value, self.name
+            ))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bits_per_char() {
+        for (charset, unaligned, aligned) in [
+            (&NUMERIC_STRING, 4, 4),
+            (&PRINTABLE_STRING, 7, 8),
+            (&VISIBLE_STRING, 7, 8),
+            (&IA5_STRING, 7, 8),
+            (&BMP_STRING, 16, 16),
+            (&UNIVERSAL_STRING, 32, 32),
+        ]
+        .iter()
+        {
+            assert_eq!(charset.bits_per_char(false), *unaligned, "{}", charset.name);
+            assert_eq!(charset.bits_per_char(true), *aligned, "{}", charset.name);
+        }
+    }
+
+    #[test]
+    fn numeric_string_is_indexed() {
+        assert_eq!(NUMERIC_STRING.encode_char(' ', 4).unwrap(), 0);
+        assert_eq!(NUMERIC_STRING.encode_char('9', 4).unwrap(), 10);
+        assert_eq!(NUMERIC_STRING.decode_char(1, 4).unwrap(), '0');
+        assert!(NUMERIC_STRING.encode_char('a', 4).is_err());
+        assert!(NUMERIC_STRING.decode_char(11, 4).is_err());
+    }
+
+    #[test]
+    fn permitted_alphabet() {
+        // `FROM ("a".."z" | "A".."Z" | "0".."9" | ".-")`
+        let alphabet = ('a'..='z')
+            .chain('A'..='Z')
+            .chain('0'..='9')
+            .chain(".-".chars())
+            .collect::<String>();
+        let charset = CharacterSet::from_alphabet(&alphabet);
+        assert_eq!(charset.bits_per_char(false), 6);
+        assert_eq!(charset.bits_per_char(true), 8);
+        assert_eq!(charset.encode_char('-', 6).unwrap(), 0);
+        assert_eq!(charset.encode_char('A', 6).unwrap(), 12);
+        assert_eq!(charset.encode_char('z', 6).unwrap(), 63);
+        assert_eq!(charset.encode_char('z', 8).unwrap(), 'z' as u32);
+        assert_eq!(charset.decode_char(2, 6).unwrap(), '0');
+        assert!(charset.encode_char('_', 6).is_err());
+
+        let charset = CharacterSet::from_alphabet("aaa");
+        assert_eq!(charset.bits_per_char(false), 0);
+        assert_eq!(charset.encode_char('a', 0).unwrap(), 0);
+        assert_eq!(charset.decode_char(0, 0).unwrap(), 'a');
+    }
+
+    #[test]
+    fn printable_string_characters() {
+        for c in "AZaz09 '()+,-./:=?".chars() {
+            let value = PRINTABLE_STRING.encode_char(c, 7).unwrap();
+            assert_eq!(value, c as u32);
+            assert_eq!(PRINTABLE_STRING.decode_char(value, 7).unwrap(), c);
+        }
+        for &c in &['!', '*', ';', '@', '_', '~'] {
+            assert!(PRINTABLE_STRING.encode_char(c, 7).is_err());
+        }
+    }
+}
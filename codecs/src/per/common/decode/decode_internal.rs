@@ -18,13 +18,171 @@ pub(super) fn decode_normally_small_non_negative_whole_number_common(
         decode_semi_constrained_whole_number_common(data, 0_i128, aligned)
     }
 }
+// Decodes the arcs from the subidentifiers in the contents octets of the BER encoding. The first
+// subidentifier of an OBJECT IDENTIFIER holds the first two arcs.
+pub(super) fn decode_object_identifier_contents_common(
+    contents: &[u8],
+    relative: bool,
+) -> Result<Vec<u128>, PerCodecError> {
+    if contents.is_empty() {
+        return Err(PerCodecError::new(
+            "Empty contents of an OBJECT IDENTIFIER or RELATIVE-OID",
+        ));
+    }
+
+    let mut subidentifiers = vec![];
+    let mut subidentifier: Option<u128> = None;
+    for octet in contents {
+        let value = match subidentifier {
+            None if *octet == 0x80 => {
+                return Err(PerCodecError::new(
+                    "Subidentifier not encoded in the fewest possible octets",
+                ));
+            }
+            None => 0,
+            Some(value) => value,
+        };
+        if value.leading_zeros() < 7 {
+            return Err(PerCodecError::new("Subidentifier is too large to decode"));
+        }
+        let value = (value << 7) | (*octet & 0x7f) as u128;
+        if octet & 0x80 == 0x80 {
+            subidentifier = Some(value);
+        } else {
+            subidentifiers.push(value);
+            subidentifier = None;
+        }
+    }
+    if subidentifier.is_some() {
+        return Err(PerCodecError::new("Last subidentifier is incomplete"));
+    }
+
+    if relative {
+        return Ok(subidentifiers);
+    }
+
+    let first = subidentifiers[0];
+    let (arc0, arc1) = match first {
+        0..=39 => (0, first),
+        40..=79 => (1, first - 40),
+        _ => (2, first - 80),
+    };
+
+    Ok([arc0, arc1]
+        .iter()
+        .chain(subidentifiers[1..].iter())
+        .cloned()
+        .collect())
+}
+
+// Decodes the value from the contents octets of the BER encoding of a REAL (X.690 8.5). The binary
+// form with any base and scale factor, the decimal forms (ISO 6093 NR1, NR2 and NR3) and the
+// special values are supported.
+pub(super) fn decode_real_contents_common(contents: &[u8]) -> Result<f64, PerCodecError> {
+    let first = match contents.first() {
+        Some(first) => *first,
+        None => return Ok(0.0),
+    };
+
+    if first & 0x80 == 0x80 {
+        let (exponent_length, exponent_start) = match first & 0x03 {
+            0x03 => match contents.get(1) {
+                Some(length) => (*length as usize, 2),
+                None => return Err(PerCodecError::new("REAL exponent length is missing")),
+            },
+            format => (format as usize + 1, 1),
+        };
+        let exponent_end = exponent_start + exponent_length;
+        let exponent_octets = match contents.get(exponent_start..exponent_end) {
+            Some(octets) if !octets.is_empty() && octets.len() <= 8 => octets,
+            _ => return Err(PerCodecError::new("Invalid REAL exponent")),
+        };
+        let initial = if exponent_octets[0] & 0x80 == 0x80 {
+            -1_i64
+        } else {
+            0
+        };
+        let exponent = exponent_octets
+            .iter()
+            .fold(initial, |exponent, octet| (exponent << 8) | *octet as i64);
+
+        let mantissa_octets = &contents[exponent_end..];
+        if mantissa_octets.len() > 16 {
+            return Err(PerCodecError::new("REAL mantissa is too large to decode"));
+        }
+        let mantissa = mantissa_octets
+            .iter()
+            .fold(0_u128, |mantissa, octet| (mantissa << 8) | *octet as u128);
+
+        let log2_base = match (first >> 4) & 0x03 {
+            0 => 1,
+            1 => 3,
+            2 => 4,
+            _ => return Err(PerCodecError::new("Reserved base of a REAL")),
+        };
+        let scale_factor = ((first >> 2) & 0x03) as i64;
+        let exponent = exponent
+            .saturating_mul(log2_base)
+            .saturating_add(scale_factor);
+
+        let value = scale_by_power_of_two(mantissa as f64, exponent);
+        Ok(if first & 0x40 == 0x40 { -value } else { value })
+    } else if first & 0x40 == 0x40 {
+        match (first, contents.len()) {
+            (0x40, 1) => Ok(f64::INFINITY),
+            (0x41, 1) => Ok(f64::NEG_INFINITY),
+            (0x42, 1) => Ok(f64::NAN),
+            (0x43, 1) => Ok(-0.0),
+            _ => Err(PerCodecError::new(format!(
+                "Invalid special REAL value: {:02x?}",
+                contents
+            ))),
+        }
+    } else {
+        if !(0x01..=0x03).contains(&first) {
+            return Err(PerCodecError::new(format!(
+                "Unsupported decimal REAL form: {}",
+                first
+            )));
+        }
+        let number = std::str::from_utf8(&contents[1..])
+            .ok()
+            .filter(|number| {
+                number
+                    .chars()
+                    .all(|c| c.is_ascii_digit() || " +-.,Ee".contains(c))
+            })
+            .ok_or_else(|| PerCodecError::new("Invalid characters in decimal REAL"))?;
+        number
+            .trim_start_matches(' ')
+            .replace(',', ".")
+            .parse::<f64>()
+            .map_err(|_| PerCodecError::new(format!("Invalid decimal REAL: '{}'", number)))
+    }
+}
+
+// Multiplies the value by `2 ^ exponent` in steps, so that the intermediate powers of two do not
+// overflow or underflow.
+fn scale_by_power_of_two(mut value: f64, exponent: i64) -> f64 {
+    let mut exponent = exponent.clamp(-2400, 2400) as i32;
+    while exponent > 1000 {
+        value *= 2_f64.powi(1000);
+        exponent -= 1000;
+    }
+    while exponent < -1000 {
+        value *= 2_f64.powi(-1000);
+        exponent += 1000;
+    }
+    value * 2_f64.powi(exponent)
+}
+
 // Decode "Normally Small" Length Determinent
 //
 // This type of "length" determinent is used to encode bitmap length in the SEQUENCE extensions,
 // TODO: Support for the case when the length is greater than 64. We almost never come across this
 // case in practice, so right now it just Errors, if in real life we actually see this error for
 // any time it might have to be implemented to take care of that case.
-fn decode_normally_small_length_determinent_common(
+pub(super) fn decode_normally_small_length_determinent_common(
     data: &mut PerCodecData,
     aligned: bool,
 ) -> Result<usize, PerCodecError> {
@@ -53,13 +211,16 @@ pub(crate) fn decode_length_determinent_common(
     }
 
     let lb = if let Some(l) = lb {
-        l.try_into().unwrap()
+        l.try_into()
+            .map_err(|_| PerCodecError::new(format!("Invalid lower bound {} of length", l)))?
     } else {
         0usize
     };
 
     if let Some(ub) = ub {
-        let ub: usize = ub.try_into().unwrap();
+        let ub: usize = ub
+            .try_into()
+            .map_err(|_| PerCodecError::new(format!("Invalid upper bound {} of length", ub)))?;
         if ub < 65_536 {
             if lb == ub {
                 return Ok(ub);
@@ -89,24 +250,25 @@ fn decode_constrained_length_determinent_common(
         ub
     );
 
-    let range = ub - lb + 1;
+    if ub < lb || ub - lb >= 65536 {
+        return Err(PerCodecError::new(format!(
+            "Length with lb: {} and ub: {} is not a constrained length.",
+            lb, ub
+        )));
+    }
 
-    if range <= 65536 {
-        // Almost always for our use cases, so let's just use it.
-        let length = decode_constrained_whole_number_common(data, lb as i128, ub as i128, aligned)?;
-        log::trace!("decoded length : {}", length);
+    let length = decode_constrained_whole_number_common(data, lb as i128, ub as i128, aligned)?;
+    log::trace!("decoded length : {}", length);
 
-        data.dump();
+    data.dump();
 
-        Ok(length as usize)
-    } else {
-        unimplemented!("Lengths larger than 65536 are not supported yet.")
-    }
+    Ok(length as usize)
 }
 
 // Called when `ub` is not determined or `ub ` - `lb` is greater than 64K and in this case value of
-// `lb` is don't care.
-fn decode_indefinite_length_determinent_common(
+// `lb` is don't care. A length of 16K or more is the length of a fragment and is followed by
+// another length determinent (Section 10.9.3.8).
+pub(super) fn decode_indefinite_length_determinent_common(
     data: &mut PerCodecData,
     aligned: bool,
 ) -> Result<usize, PerCodecError> {
@@ -132,7 +294,7 @@ fn decode_indefinite_length_determinent_common(
 
     data.dump();
 
-    Ok(length.try_into().unwrap())
+    Ok(length as usize)
 }
 
 // Section 10.8 X.691
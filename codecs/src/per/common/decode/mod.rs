@@ -6,11 +6,44 @@ use bitvec::prelude::*;
 
 use crate::{PerCodecData, PerCodecError};
 
+use super::charset::CharacterSet;
+
 #[allow(unused)]
 use decode_internal::*;
 
 pub(crate) use decode_internal::decode_length_determinent_common;
 
+// Decode a Length Determinent followed by the items it counts, which may be in fragments.
+//
+// `decode_items` is called with the number of items to be decoded after each length determinent.
+// When the length is not constrained to less than 64K, a length of 16K or more is that of a
+// fragment, which is followed by another length determinent. (Section 10.9.3.8)
+pub(crate) fn decode_fragmented_common<F>(
+    data: &mut PerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    aligned: bool,
+    mut decode_items: F,
+) -> Result<(), PerCodecError>
+where
+    F: FnMut(&mut PerCodecData, usize) -> Result<(), PerCodecError>,
+{
+    if matches!(ub, Some(ub) if ub < 65_536) {
+        let length = decode_length_determinent_common(data, lb, ub, false, aligned)?;
+        return decode_items(data, length);
+    }
+
+    loop {
+        let length = decode_indefinite_length_determinent_common(data, aligned)?;
+        decode_items(data, length)?;
+        if length < 16384 {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
 // Common decode functions used by the API functions of the codec. The API functions call the
 // common functions For example, `decode_choice_idx` API will call `decode_choice_idx_common` fro
 // APER Codec by passing aligned as `true`.
@@ -64,6 +97,64 @@ pub fn decode_sequence_header_common(
     Ok((bitmap, extended))
 }
 
+// Common function to decode the bitmap of the extension additions present in a sequence.
+pub fn decode_sequence_extension_bitmap_common(
+    data: &mut PerCodecData,
+    aligned: bool,
+) -> Result<BitVec<u8, Msb0>, PerCodecError> {
+    let length = decode_normally_small_length_determinent_common(data, aligned)?;
+    let bitmap = data.get_bitvec(length)?;
+
+    data.dump();
+    Ok(bitmap)
+}
+
+// Common function to decode an open type. The encoding of the value of the open type is returned
+// as a separate `PerCodecData`, from which the actual value can be decoded.
+pub fn decode_open_type_common(
+    data: &mut PerCodecData,
+    aligned: bool,
+) -> Result<PerCodecData, PerCodecError> {
+    let mut octets = Vec::new();
+    decode_fragmented_common(data, None, None, aligned, |data, length| {
+        octets.extend(data.get_bytes(length)?);
+        Ok(())
+    })?;
+    let mut value = PerCodecData::from_slice_internal(&octets, aligned);
+    value.key = data.key;
+
+    data.dump();
+    Ok(value)
+}
+
+// Common function to decode an OBJECT IDENTIFIER or a RELATIVE-OID. Returns the arcs of the value.
+pub fn decode_object_identifier_common(
+    data: &mut PerCodecData,
+    relative: bool,
+    aligned: bool,
+) -> Result<Vec<u128>, PerCodecError> {
+    let length = decode_length_determinent_common(data, None, None, false, aligned)?;
+    let contents = data.get_bytes(length)?;
+
+    let arcs = decode_object_identifier_contents_common(&contents, relative)?;
+
+    data.dump();
+
+    Ok(arcs)
+}
+
+// Common function to decode a REAL.
+pub fn decode_real_common(data: &mut PerCodecData, aligned: bool) -> Result<f64, PerCodecError> {
+    let length = decode_length_determinent_common(data, None, None, false, aligned)?;
+    let contents = data.get_bytes(length)?;
+
+    let value = decode_real_contents_common(&contents)?;
+
+    data.dump();
+
+    Ok(value)
+}
+
 // Common function to decode INTEGER.
 pub fn decode_integer_common(
     data: &mut PerCodecData,
@@ -166,30 +257,18 @@ pub fn decode_bitstring_common(
         false
     };
 
-    let mut bv = BitVec::new();
-    loop {
-        let length = if is_extended {
-            decode_length_determinent_common(data, None, None, false, aligned)?
-        } else {
-            decode_length_determinent_common(data, lb, ub, false, aligned)?
-        };
+    let (lb, ub) = if is_extended { (None, None) } else { (lb, ub) };
 
+    let mut bv = BitVec::new();
+    decode_fragmented_common(data, lb, ub, aligned, |data, length| {
         if length > 0 {
-            if length > 16 {
-                if aligned {
-                    data.decode_align()?;
-                }
+            if length > 16 && aligned {
+                data.decode_align()?;
             }
             bv.extend(data.get_bitvec(length)?);
         }
-
-        // Fragmented So get the chunks in multiples of 16384,
-        if length >= 16384 {
-            continue;
-        } else {
-            break;
-        }
-    }
+        Ok(())
+    })?;
 
     data.dump();
 
@@ -217,79 +296,100 @@ pub fn decode_octetstring_common(
         false
     };
 
-    let mut octets = Vec::new();
-    loop {
-        let length = if is_extended {
-            decode_length_determinent_common(data, None, None, false, aligned)?
-        } else {
-            decode_length_determinent_common(data, lb, ub, false, aligned)?
-        };
+    let (lb, ub) = if is_extended { (None, None) } else { (lb, ub) };
 
+    let mut octets = Vec::new();
+    decode_fragmented_common(data, lb, ub, aligned, |data, length| {
         if length > 0 {
-            if length > 2 {
-                if aligned {
-                    data.decode_align()?;
-                }
+            if length > 2 && aligned {
+                data.decode_align()?;
             }
             octets.extend(data.get_bytes(length)?);
         }
-
-        // Fragmented So get the chunks in multiples of 16384,
-        if length >= 16384 {
-            continue;
-        } else {
-            break;
-        }
-    }
+        Ok(())
+    })?;
 
     data.dump();
 
     Ok(octets)
 }
 
-pub(crate) fn decode_string_common(
+// Common function to decode the items of a SEQUENCE OF preceded by their count. `decode_items` is
+// called with the number of items to be decoded. Refer to Section 20.
+pub fn decode_sequence_of_common<F>(
     data: &mut PerCodecData,
     lb: Option<i128>,
     ub: Option<i128>,
     is_extensible: bool,
-    bits_per_char: usize,
+    decode_items: F,
     aligned: bool,
-) -> Result<String, PerCodecError> {
+) -> Result<(), PerCodecError>
+where
+    F: FnMut(&mut PerCodecData, usize) -> Result<(), PerCodecError>,
+{
     let is_extended = if is_extensible {
         data.decode_bool()?
     } else {
         false
     };
+    let (lb, ub) = if is_extended { (None, None) } else { (lb, ub) };
+
+    decode_fragmented_common(data, lb, ub, aligned, decode_items)?;
+
+    data.dump();
+
+    Ok(())
+}
 
-    let length = if is_extended {
-        decode_length_determinent_common(data, None, None, false, aligned)?
+// Common function to decode a known-multiplier character string, where each of the characters is
+// decoded from the same number of bits, determined by the `charset` of the string type.
+pub(crate) fn decode_known_multiplier_string_common(
+    data: &mut PerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+    charset: &CharacterSet,
+    aligned: bool,
+) -> Result<String, PerCodecError> {
+    let is_extended = if is_extensible {
+        data.decode_bool()?
     } else {
-        decode_length_determinent_common(data, lb, ub, false, aligned)?
+        false
     };
+    let (lb, ub) = if is_extended { (None, None) } else { (lb, ub) };
 
-    let length = length * bits_per_char;
-    if length > 16 {
-        if aligned {
+    let bits = charset.bits_per_char(aligned);
+    let mut value = String::new();
+    decode_fragmented_common(data, lb, ub, aligned, |data, length| {
+        if length * bits > 16 && aligned {
             data.decode_align()?;
         }
-    }
-    let bits = data.get_bitvec(length)?;
-    let bytes = bits
-        .chunks_exact(bits_per_char)
-        .map(|c| {
-            let mut v = c.to_bitvec();
-            let howmany = 8 - bits_per_char;
-            for _ in 0..howmany {
-                v.insert(0, false);
-            }
-            let o = v.load_be::<u8>();
-            o
-        })
-        .collect::<Vec<u8>>();
+        for _ in 0..length {
+            let c = data.decode_bits_as_integer(bits, false)?;
+            value.push(charset.decode_char(c as u32, bits)?);
+        }
+        Ok(())
+    })?;
 
     data.dump();
 
-    std::str::from_utf8(&bytes)
-        .map(|s| s.to_string())
-        .map_err(|_| PerCodecError::new("UTF decode failed"))
+    Ok(value)
+}
+
+// Common function to decode a non-known-multiplier character string from the octets encoded with
+// an unconstrained length. Unless `utf8` is set, each octet is decoded as a single character.
+pub(crate) fn decode_non_known_multiplier_string_common(
+    data: &mut PerCodecData,
+    utf8: bool,
+    aligned: bool,
+) -> Result<String, PerCodecError> {
+    let octets = decode_octetstring_common(data, None, None, false, aligned)?;
+
+    if utf8 {
+        String::from_utf8(octets).map_err(|e| {
+            PerCodecError::new(format!("PerCodec:DecodeError:Invalid UTF8String: {}", e))
+        })
+    } else {
+        Ok(octets.into_iter().map(char::from).collect())
+    }
 }
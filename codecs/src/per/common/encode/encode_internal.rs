@@ -149,19 +149,155 @@ pub(super) fn encode_indefinite_length_determinent_common(
         let bytes = (value as u16 | 0x8000).to_be_bytes();
         data.append_bits(bytes.view_bits::<Msb0>());
     } else {
-        return Err(PerCodecError::new(
-            "Length determinent >= 16384 not implemented",
-        ));
+        return Err(PerCodecError::new(format!(
+            "Length determinent {} >= 16384 should be encoded in fragments",
+            value
+        )));
     }
     Ok(())
 }
 
+// Section 10.9.3.8.1: The length determinent of a fragment of 16K, 32K, 48K or 64K items, where
+// `multiplier` is 1 to 4.
+pub(super) fn encode_fragment_length_determinent_common(
+    data: &mut PerCodecData,
+    multiplier: usize,
+    aligned: bool,
+) -> Result<(), PerCodecError> {
+    if !(1..=4).contains(&multiplier) {
+        return Err(PerCodecError::new(format!(
+            "Fragment of {} times 16K items is not allowed",
+            multiplier
+        )));
+    }
+    if aligned {
+        data.align();
+    }
+    let byte = 0xC0_u8 | multiplier as u8;
+    data.append_bits(byte.view_bits::<Msb0>());
+    Ok(())
+}
+
+// Encode a "Normally Small" non-negative number (Section 10.6)
+//
+// This is used for encoding the Choice Indexes of the alternatives and the Enumerated values that
+// are not in the extension root.
+pub(super) fn encode_normally_small_non_negative_whole_number_common(
+    data: &mut PerCodecData,
+    value: i128,
+    aligned: bool,
+) -> Result<(), PerCodecError> {
+    if (0..64).contains(&value) {
+        data.encode_bool(false);
+        data.append_bits(&(value as u8).view_bits::<Msb0>()[2..8]);
+        Ok(())
+    } else {
+        data.encode_bool(true);
+        encode_semi_constrained_whole_number_common(data, 0, value, aligned)
+    }
+}
+
+// Encodes the arcs as the subidentifiers of the contents octets of the BER encoding. Each
+// subidentifier is encoded in as few octets as possible, 7 bits to an octet, with the most
+// significant bit set in all but the last octet. For an OBJECT IDENTIFIER the first two arcs are
+// combined into a single subidentifier.
+pub(super) fn encode_object_identifier_contents_common(
+    arcs: &[u128],
+    relative: bool,
+) -> Result<Vec<u8>, PerCodecError> {
+    let subidentifiers = if relative {
+        if arcs.is_empty() {
+            return Err(PerCodecError::new("RELATIVE-OID without any arcs"));
+        }
+        arcs.to_vec()
+    } else {
+        if arcs.len() < 2 {
+            return Err(PerCodecError::new(
+                "OBJECT IDENTIFIER should have at least two arcs",
+            ));
+        }
+        if arcs[0] > 2 || (arcs[0] < 2 && arcs[1] > 39) {
+            return Err(PerCodecError::new(format!(
+                "Invalid first arcs of OBJECT IDENTIFIER: {}.{}",
+                arcs[0], arcs[1]
+            )));
+        }
+        let first = arcs[1].checked_add(arcs[0] * 40).ok_or_else(|| {
+            PerCodecError::new("Second arc of OBJECT IDENTIFIER is too large to encode")
+        })?;
+        std::iter::once(first)
+            .chain(arcs[2..].iter().cloned())
+            .collect()
+    };
+
+    let mut contents = vec![];
+    for subidentifier in subidentifiers {
+        let mut octets = vec![(subidentifier & 0x7f) as u8];
+        let mut remaining = subidentifier >> 7;
+        while remaining > 0 {
+            octets.push((remaining & 0x7f) as u8 | 0x80);
+            remaining >>= 7;
+        }
+        contents.extend(octets.iter().rev());
+    }
+
+    Ok(contents)
+}
+
+// Encodes the value as the contents octets of the CER/DER encoding of a REAL (X.690 8.5 and 11.3).
+// Zero has no contents octets and the special values are encoded in a single octet. All the other
+// values are encoded in the binary form with the base 2, a scale factor of 0 and an odd mantissa.
+pub(super) fn encode_real_contents_common(value: f64) -> Vec<u8> {
+    if value == 0.0 {
+        return if value.is_sign_negative() {
+            vec![0x43]
+        } else {
+            vec![]
+        };
+    }
+    if value.is_nan() {
+        return vec![0x42];
+    }
+    if value.is_infinite() {
+        return if value > 0.0 { vec![0x40] } else { vec![0x41] };
+    }
+
+    let bits = value.to_bits();
+    let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+    let fraction = bits & ((1 << 52) - 1);
+    let (mantissa, exponent) = if biased_exponent == 0 {
+        (fraction, -1074)
+    } else {
+        (fraction | (1 << 52), biased_exponent - 1075)
+    };
+    let shift = mantissa.trailing_zeros();
+    let (mantissa, exponent) = (mantissa >> shift, exponent + shift as i32);
+
+    // The exponent of an `f64` always fits in two octets.
+    let exponent_octets = if (-128..128).contains(&exponent) {
+        1
+    } else {
+        2
+    };
+    let mut first = 0x80 | (exponent_octets - 1) as u8;
+    if value < 0.0 {
+        first |= 0x40;
+    }
+    let mantissa_octets = (64 - mantissa.leading_zeros() as usize).div_ceil(8);
+
+    let mut contents = vec![first];
+    contents.extend_from_slice(&exponent.to_be_bytes()[4 - exponent_octets..]);
+    contents.extend_from_slice(&mantissa.to_be_bytes()[8 - mantissa_octets..]);
+
+    contents
+}
+
 pub(super) fn encode_normally_small_length_determinent_common(
     data: &mut PerCodecData,
     value: usize,
     aligned: bool,
 ) -> Result<(), PerCodecError> {
-    if value <= 32 {
+    if value <= 64 {
         let byte = (value - 1) as u8;
         data.encode_bool(false);
         data.append_bits(&byte.view_bits::<Msb0>()[2..8]);
@@ -203,14 +339,14 @@ mod tests {
     fn encode_small_constrained_integer_aligned_range_0() {
         let mut data = PerCodecData::new_aper();
         encode_constrained_whole_number_common(&mut data, 1000, 1000, 1000, true).unwrap();
-        assert_eq!(data.into_bytes(), []);
+        assert!(data.into_bytes().is_empty());
     }
 
     #[test]
     fn encode_small_constrained_integer_unaligned_range_0() {
         let mut data = PerCodecData::new_aper();
         encode_constrained_whole_number_common(&mut data, 1000, 1000, 1000, false).unwrap();
-        assert_eq!(data.into_bytes(), []);
+        assert!(data.into_bytes().is_empty());
     }
 
     #[test]
@@ -1,9 +1,14 @@
 //! ASN.1 PER Encoding common functions
 
+use std::convert::TryFrom;
+use std::ops::Range;
+
 use bitvec::prelude::*;
 
 use crate::per::{PerCodecData, PerCodecError};
 
+use super::charset::CharacterSet;
+
 mod encode_internal;
 
 #[allow(unused)]
@@ -23,9 +28,9 @@ pub(crate) fn encode_choice_idx_common(
     extended: bool,
     aligned: bool,
 ) -> Result<(), PerCodecError> {
-    if extended {
+    if extended && !is_extensible {
         return Err(PerCodecError::new(
-            "Encode of extended choice not yet implemented",
+            "Extended alternative of a non-extensible choice",
         ));
     }
 
@@ -33,7 +38,11 @@ pub(crate) fn encode_choice_idx_common(
         data.encode_bool(extended);
     }
 
-    encode_integer_common(data, Some(lb), Some(ub), false, idx, false, aligned)
+    if extended {
+        encode_normally_small_non_negative_whole_number_common(data, idx, aligned)
+    } else {
+        encode_integer_common(data, Some(lb), Some(ub), false, idx, false, aligned)
+    }
 }
 
 // Common function to encode a sequence header.
@@ -44,9 +53,9 @@ pub(crate) fn encode_sequence_header_common(
     extended: bool,
     _aligned: bool,
 ) -> Result<(), PerCodecError> {
-    if extended {
+    if extended && !is_extensible {
         return Err(PerCodecError::new(
-            "Encode of extended sequence not yet implemented",
+            "Extension additions present in a non-extensible sequence",
         ));
     }
 
@@ -61,6 +70,87 @@ pub(crate) fn encode_sequence_header_common(
     Ok(())
 }
 
+// Common function to encode the bitmap of the extension additions present in a sequence.
+// Refer to Section 19.8.
+pub(crate) fn encode_sequence_extension_bitmap_common(
+    data: &mut PerCodecData,
+    bitmap: &BitSlice<u8, Msb0>,
+    aligned: bool,
+) -> Result<(), PerCodecError> {
+    encode_normally_small_length_determinent_common(data, bitmap.len(), aligned)?;
+    data.append_bits(bitmap);
+
+    data.dump_encode();
+
+    Ok(())
+}
+
+// Common function to encode an open type. The `value` is the complete encoding of the value of
+// the open type, which is padded to an octet boundary and encoded preceded by its length in
+// octets. Refer to Section 11.2.
+pub(crate) fn encode_open_type_common(
+    data: &mut PerCodecData,
+    mut value: PerCodecData,
+    aligned: bool,
+) -> Result<(), PerCodecError> {
+    // An empty encoding is replaced by a single zero octet. (Section 11.1.3)
+    if value.bits.is_empty() {
+        value.bits.resize(8, false);
+    }
+    value.align();
+
+    encode_fragmented_common(
+        data,
+        None,
+        None,
+        value.length_in_bytes(),
+        aligned,
+        |data, octets| {
+            data.append_bits(&value.bits[octets.start * 8..octets.end * 8]);
+            Ok(())
+        },
+    )?;
+
+    data.dump_encode();
+
+    Ok(())
+}
+
+// Common function to encode an OBJECT IDENTIFIER or a RELATIVE-OID. The contents octets of the BER
+// encoding of the value are encoded with an unconstrained length determinent.
+pub(crate) fn encode_object_identifier_common(
+    data: &mut PerCodecData,
+    arcs: &[u128],
+    relative: bool,
+    aligned: bool,
+) -> Result<(), PerCodecError> {
+    let contents = encode_object_identifier_contents_common(arcs, relative)?;
+
+    encode_length_determinent_common(data, None, None, false, contents.len(), aligned)?;
+    data.append_bits(contents.view_bits());
+
+    data.dump_encode();
+
+    Ok(())
+}
+
+// Common function to encode a REAL. The contents octets of the CER/DER encoding of the value are
+// encoded with an unconstrained length determinent. (Section 15)
+pub(crate) fn encode_real_common(
+    data: &mut PerCodecData,
+    value: f64,
+    aligned: bool,
+) -> Result<(), PerCodecError> {
+    let contents = encode_real_contents_common(value);
+
+    encode_length_determinent_common(data, None, None, false, contents.len(), aligned)?;
+    data.append_bits(contents.view_bits());
+
+    data.dump_encode();
+
+    Ok(())
+}
+
 // Common function to encode an integer
 pub(crate) fn encode_integer_common(
     data: &mut PerCodecData,
@@ -116,9 +206,9 @@ pub(crate) fn encode_enumerated_common(
     extended: bool,
     aligned: bool,
 ) -> Result<(), PerCodecError> {
-    if extended {
+    if extended && !is_extensible {
         return Err(PerCodecError::new(
-            "Encode of extended enumerated not yet implemented",
+            "Extended value of a non-extensible enumerated",
         ));
     }
 
@@ -126,7 +216,11 @@ pub(crate) fn encode_enumerated_common(
         data.encode_bool(extended);
     }
 
-    encode_integer_common(data, lb, ub, false, value, false, aligned)?;
+    if extended {
+        encode_normally_small_non_negative_whole_number_common(data, value, aligned)?;
+    } else {
+        encode_integer_common(data, lb, ub, false, value, false, aligned)?;
+    }
 
     data.dump();
 
@@ -154,22 +248,13 @@ pub(crate) fn encode_bitstring_common(
         data.encode_bool(extended);
     }
 
-    let length = bit_string.len();
-    if length >= 16384 {
-        return Err(PerCodecError::new(
-            "Encode of fragmented bitstring not yet implemented",
-        ));
-    }
-
-    encode_length_determinent_common(data, lb, ub, false, length, aligned)?;
-    if length > 0 {
-        if length > 16 {
-            if aligned {
-                data.align();
-            }
+    encode_fragmented_common(data, lb, ub, bit_string.len(), aligned, |data, bits| {
+        if bits.len() > 16 && aligned {
+            data.align();
         }
-        data.append_bits(bit_string);
-    }
+        data.append_bits(&bit_string[bits]);
+        Ok(())
+    })?;
 
     // TODO: Not sure if 15.11 is handled correctly?
     data.dump_encode();
@@ -197,23 +282,13 @@ pub(crate) fn encode_octet_string_common(
         data.encode_bool(extended);
     }
 
-    let length = octet_string.len();
-    if length >= 16384 {
-        return Err(PerCodecError::new(
-            "Encode of fragmented octetstring not yet implemented",
-        ));
-    }
-
-    encode_length_determinent_common(data, lb, ub, false, length, aligned)?;
-
-    if length > 0 {
-        if length > 2 {
-            if aligned {
-                data.align();
-            }
+    encode_fragmented_common(data, lb, ub, octet_string.len(), aligned, |data, octets| {
+        if octets.len() > 2 && aligned {
+            data.align();
         }
-        data.append_bits(octet_string.view_bits());
-    }
+        data.append_bits(octet_string[octets].view_bits());
+        Ok(())
+    })?;
 
     data.dump_encode();
     Ok(())
@@ -244,24 +319,7 @@ pub(crate) fn encode_length_determinent_common(
             aligned,
         )?,
         _ => {
-            if let Some(u) = ub {
-                if value > u as usize {
-                    return Err(PerCodecError::new(format!(
-                        "Cannot encode length determinent {} - greater than upper bound {}",
-                        value, u,
-                    )));
-                }
-            }
-
-            if let Some(l) = lb {
-                if value < l as usize {
-                    return Err(PerCodecError::new(format!(
-                        "Cannot encode length determinent {} - less than lower bound {}",
-                        value, l,
-                    )));
-                }
-            }
-
+            check_length_bounds(lb, ub, value)?;
             encode_indefinite_length_determinent_common(data, value, aligned)?
         }
     };
@@ -271,33 +329,175 @@ pub(crate) fn encode_length_determinent_common(
     Ok(())
 }
 
-// Common function to encode string value.
-pub(crate) fn encode_string_common(
+// Encode a Length Determinent followed by the items it counts, in fragments if required.
+//
+// When the length is not constrained to less than 64K and there are 16K items or more, the items
+// are encoded in fragments of 16K, 32K, 48K or 64K items, each preceded by its own length
+// determinent, and the remaining items (possibly none) follow the last fragment. (Section
+// 10.9.3.8). `encode_items` is called with the range of the items to be encoded each time.
+pub(crate) fn encode_fragmented_common<F>(
+    data: &mut PerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    length: usize,
+    aligned: bool,
+    mut encode_items: F,
+) -> Result<(), PerCodecError>
+where
+    F: FnMut(&mut PerCodecData, Range<usize>) -> Result<(), PerCodecError>,
+{
+    let constrained = matches!(ub, Some(ub) if ub < 65_536);
+    if constrained || length < 16384 {
+        encode_length_determinent_common(data, lb, ub, false, length, aligned)?;
+        return encode_items(data, 0..length);
+    }
+
+    check_length_bounds(lb, ub, length)?;
+
+    let mut offset = 0;
+    loop {
+        let remaining = length - offset;
+        if remaining < 16384 {
+            encode_indefinite_length_determinent_common(data, remaining, aligned)?;
+            encode_items(data, offset..length)?;
+            break;
+        }
+
+        let multiplier = std::cmp::min(remaining / 16384, 4);
+        encode_fragment_length_determinent_common(data, multiplier, aligned)?;
+        encode_items(data, offset..offset + multiplier * 16384)?;
+        offset += multiplier * 16384;
+    }
+
+    data.dump_encode();
+
+    Ok(())
+}
+
+fn check_length_bounds(
+    lb: Option<i128>,
+    ub: Option<i128>,
+    value: usize,
+) -> Result<(), PerCodecError> {
+    if let Some(u) = ub {
+        if value as i128 > u {
+            return Err(PerCodecError::new(format!(
+                "Cannot encode length determinent {} - greater than upper bound {}",
+                value, u,
+            )));
+        }
+    }
+
+    if let Some(l) = lb {
+        if (value as i128) < l {
+            return Err(PerCodecError::new(format!(
+                "Cannot encode length determinent {} - less than lower bound {}",
+                value, l,
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+// Common function to encode the items of a SEQUENCE OF preceded by their count. When the SIZE is
+// extensible, a count outside the root is encoded as an unconstrained length. Refer to Section 20.
+pub(crate) fn encode_sequence_of_common<F>(
+    data: &mut PerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+    length: usize,
+    encode_items: F,
+    aligned: bool,
+) -> Result<(), PerCodecError>
+where
+    F: FnMut(&mut PerCodecData, Range<usize>) -> Result<(), PerCodecError>,
+{
+    let (lb, ub) = if is_extensible {
+        let extended = check_length_bounds(lb, ub, length).is_err();
+        data.encode_bool(extended);
+        if extended {
+            (None, None)
+        } else {
+            (lb, ub)
+        }
+    } else {
+        (lb, ub)
+    };
+
+    encode_fragmented_common(data, lb, ub, length, aligned, encode_items)
+}
+
+// Common function to encode a known-multiplier character string. Each of the characters is encoded
+// in the same number of bits, determined by the `charset` of the string type. (Section 30.5)
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn encode_known_multiplier_string_common(
     data: &mut PerCodecData,
     lb: Option<i128>,
     ub: Option<i128>,
     is_extensible: bool,
-    value: &String,
+    value: &str,
     extended: bool,
+    charset: &CharacterSet,
     aligned: bool,
 ) -> Result<(), PerCodecError> {
-    if extended {
+    if extended && !is_extensible {
         return Err(PerCodecError::new(
-            "Encode of extended visible string not yet implemented",
+            "Extended value of a character string with non-extensible size",
         ));
     }
 
+    let bits = charset.bits_per_char(aligned);
+    let chars = value
+        .chars()
+        .map(|c| charset.encode_char(c, bits))
+        .collect::<Result<Vec<u32>, PerCodecError>>()?;
+
     if is_extensible {
         data.encode_bool(extended);
     }
-    encode_length_determinent_common(data, lb, ub, false, value.len(), aligned)?;
-    if value.len() > 2 {
-        if aligned {
+    let (lb, ub) = if extended { (None, None) } else { (lb, ub) };
+
+    encode_fragmented_common(data, lb, ub, chars.len(), aligned, |data, range| {
+        if range.len() * bits > 16 && aligned {
             data.align();
         }
-    }
-    data.append_bits(value.as_bits());
+        for c in &chars[range] {
+            data.append_bits(&c.to_be_bytes().view_bits::<Msb0>()[32 - bits..]);
+        }
+        Ok(())
+    })?;
 
     data.dump_encode();
     Ok(())
 }
+
+// Common function to encode a non-known-multiplier character string. The constraints of these
+// types are not PER-visible, so the octets of the value are always encoded with an unconstrained
+// length. (Section 30.6). Unless `utf8` is set, each of the characters is encoded as a single
+// octet and only the characters up to U+00FF can be encoded.
+pub(crate) fn encode_non_known_multiplier_string_common(
+    data: &mut PerCodecData,
+    value: &str,
+    utf8: bool,
+    aligned: bool,
+) -> Result<(), PerCodecError> {
+    let octets = if utf8 {
+        value.as_bytes().to_vec()
+    } else {
+        value
+            .chars()
+            .map(|c| {
+                u8::try_from(c).map_err(|_| {
+                    PerCodecError::new(format!(
+                        "Character '{}' cannot be encoded in a single octet.",
+                        c.escape_default()
+                    ))
+                })
+            })
+            .collect::<Result<Vec<u8>, PerCodecError>>()?
+    };
+
+    encode_octet_string_common(data, None, None, false, &octets, false, aligned)
+}
@@ -2,6 +2,10 @@ pub(crate) mod encode;
 
 pub(crate) mod decode;
 
+pub(crate) mod charset;
+
+pub(crate) mod time;
+
 // FIXME: Remove the pub(crate) when `decode` also pulled uner `common`.
 pub(crate) fn bytes_needed_for_range(range: i128) -> u8 {
     let bits_needed: u8 = 128 - (range - 1).leading_zeros() as u8;
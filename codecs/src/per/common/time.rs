@@ -0,0 +1,397 @@
+//! Common functions for the encoding of the Time Types
+//!
+//! `UTCTime` and `GeneralizedTime` values are encoded as a `VisibleString` after the validation of
+//! their lexical form. The time types of X.680 are encoded as the following types (Section 32 of
+//! X.691), which are encoded with the common functions for these types.
+//!
+//! ```asn1
+//! YEAR-ENCODING ::= CHOICE {
+//!     immediate   INTEGER (2005..2020),
+//!     near-future INTEGER (2021..2276),
+//!     near-past   INTEGER (1749..2004),
+//!     remainder   INTEGER (MIN..1748 | 2277..MAX) }
+//!
+//! DATE-ENCODING ::= SEQUENCE {
+//!     year  YEAR-ENCODING,
+//!     month INTEGER (1..12),
+//!     day   INTEGER (1..31) }
+//!
+//! TIME-OF-DAY-ENCODING ::= SEQUENCE {
+//!     hours   INTEGER (0..24),
+//!     minutes INTEGER (0..59),
+//!     seconds INTEGER (0..60) }
+//!
+//! DATE-TIME-ENCODING ::= SEQUENCE {
+//!     date DATE-ENCODING,
+//!     time TIME-OF-DAY-ENCODING }
+//!
+//! DURATION-ENCODING ::= SEQUENCE {
+//!     years   INTEGER (0..MAX) OPTIONAL,
+//!     months  INTEGER (0..MAX) OPTIONAL,
+//!     weeks   INTEGER (0..MAX) OPTIONAL,
+//!     days    INTEGER (0..MAX) OPTIONAL,
+//!     hours   INTEGER (0..MAX) OPTIONAL,
+//!     minutes INTEGER (0..MAX) OPTIONAL,
+//!     seconds INTEGER (0..MAX) OPTIONAL,
+//!     fractional-part SEQUENCE {
+//!         number-of-digits INTEGER (1..MAX),
+//!         fractional-value INTEGER (0..MAX) } OPTIONAL }
+//! ```
+
+use std::convert::TryFrom;
+
+use bitvec::prelude::*;
+
+use crate::per::{PerCodecData, PerCodecError};
+use crate::time::{Date, DateTime, Duration, TimeError, TimeOfDay};
+
+use super::charset::VISIBLE_STRING;
+use super::decode::*;
+use super::encode::*;
+
+// The alternatives of `YEAR-ENCODING` except the `remainder`.
+const YEAR_RANGES: [(i128, i128); 3] = [(2005, 2020), (2021, 2276), (1749, 2004)];
+
+fn time_error(e: TimeError) -> PerCodecError {
+    PerCodecError::new(e.to_string())
+}
+
+// Common function to encode a `UTCTime` or a `GeneralizedTime` value.
+pub(crate) fn encode_useful_time_common(
+    data: &mut PerCodecData,
+    value: &str,
+    generalized: bool,
+    aligned: bool,
+) -> Result<(), PerCodecError> {
+    if generalized {
+        DateTime::from_generalized_time(value).map_err(time_error)?;
+    } else {
+        DateTime::from_utc_time(value).map_err(time_error)?;
+    }
+
+    encode_known_multiplier_string_common(
+        data,
+        None,
+        None,
+        false,
+        value,
+        false,
+        &VISIBLE_STRING,
+        aligned,
+    )
+}
+
+// Common function to decode a `UTCTime` or a `GeneralizedTime` value.
+pub(crate) fn decode_useful_time_common(
+    data: &mut PerCodecData,
+    generalized: bool,
+    aligned: bool,
+) -> Result<String, PerCodecError> {
+    let value =
+        decode_known_multiplier_string_common(data, None, None, false, &VISIBLE_STRING, aligned)?;
+
+    if generalized {
+        DateTime::from_generalized_time(&value).map_err(time_error)?;
+    } else {
+        DateTime::from_utc_time(&value).map_err(time_error)?;
+    }
+
+    Ok(value)
+}
+
+fn encode_date_internal(
+    data: &mut PerCodecData,
+    date: &Date,
+    aligned: bool,
+) -> Result<(), PerCodecError> {
+    let year = date.year as i128;
+    match YEAR_RANGES
+        .iter()
+        .position(|(lb, ub)| (*lb..=*ub).contains(&year))
+    {
+        Some(idx) => {
+            let (lb, ub) = YEAR_RANGES[idx];
+            encode_choice_idx_common(data, 0, 3, false, idx as i128, false, aligned)?;
+            encode_integer_common(data, Some(lb), Some(ub), false, year, false, aligned)?;
+        }
+        None => {
+            encode_choice_idx_common(data, 0, 3, false, 3, false, aligned)?;
+            encode_integer_common(data, None, None, false, year, false, aligned)?;
+        }
+    }
+    encode_integer_common(
+        data,
+        Some(1),
+        Some(12),
+        false,
+        date.month as i128,
+        false,
+        aligned,
+    )?;
+    encode_integer_common(
+        data,
+        Some(1),
+        Some(31),
+        false,
+        date.day as i128,
+        false,
+        aligned,
+    )
+}
+
+fn decode_date_internal(data: &mut PerCodecData, aligned: bool) -> Result<Date, PerCodecError> {
+    let (idx, _) = decode_choice_idx_common(data, 0, 3, false, aligned)?;
+    let (lb, ub) = match YEAR_RANGES.get(idx as usize) {
+        Some((lb, ub)) => (Some(*lb), Some(*ub)),
+        None => (None, None),
+    };
+    let (year, _) = decode_integer_common(data, lb, ub, false, aligned)?;
+    let (month, _) = decode_integer_common(data, Some(1), Some(12), false, aligned)?;
+    let (day, _) = decode_integer_common(data, Some(1), Some(31), false, aligned)?;
+
+    let year = i32::try_from(year)
+        .map_err(|_| PerCodecError::new(format!("The year {} is too large.", year)))?;
+    Ok(Date {
+        year,
+        month: month as u8,
+        day: day as u8,
+    })
+}
+
+fn encode_time_of_day_internal(
+    data: &mut PerCodecData,
+    time: &TimeOfDay,
+    aligned: bool,
+) -> Result<(), PerCodecError> {
+    for (value, ub) in [(time.hour, 24), (time.minute, 59), (time.second, 60)] {
+        encode_integer_common(
+            data,
+            Some(0),
+            Some(ub),
+            false,
+            value as i128,
+            false,
+            aligned,
+        )?;
+    }
+    Ok(())
+}
+
+fn decode_time_of_day_internal(
+    data: &mut PerCodecData,
+    aligned: bool,
+) -> Result<TimeOfDay, PerCodecError> {
+    let (hour, _) = decode_integer_common(data, Some(0), Some(24), false, aligned)?;
+    let (minute, _) = decode_integer_common(data, Some(0), Some(59), false, aligned)?;
+    let (second, _) = decode_integer_common(data, Some(0), Some(60), false, aligned)?;
+
+    Ok(TimeOfDay {
+        hour: hour as u8,
+        minute: minute as u8,
+        second: second as u8,
+    })
+}
+
+// Common function to encode a `DATE` value.
+pub(crate) fn encode_date_common(
+    data: &mut PerCodecData,
+    value: &str,
+    aligned: bool,
+) -> Result<(), PerCodecError> {
+    let date = value.parse::<Date>().map_err(time_error)?;
+
+    encode_date_internal(data, &date, aligned)?;
+
+    data.dump_encode();
+
+    Ok(())
+}
+
+// Common function to decode a `DATE` value.
+pub(crate) fn decode_date_common(
+    data: &mut PerCodecData,
+    aligned: bool,
+) -> Result<String, PerCodecError> {
+    let date = decode_date_internal(data, aligned)?;
+
+    // Validates the decoded value.
+    let value = date.to_string();
+    value.parse::<Date>().map_err(time_error)?;
+
+    data.dump();
+
+    Ok(value)
+}
+
+// Common function to encode a `TIME-OF-DAY` value.
+pub(crate) fn encode_time_of_day_common(
+    data: &mut PerCodecData,
+    value: &str,
+    aligned: bool,
+) -> Result<(), PerCodecError> {
+    let time = value.parse::<TimeOfDay>().map_err(time_error)?;
+
+    encode_time_of_day_internal(data, &time, aligned)?;
+
+    data.dump_encode();
+
+    Ok(())
+}
+
+// Common function to decode a `TIME-OF-DAY` value.
+pub(crate) fn decode_time_of_day_common(
+    data: &mut PerCodecData,
+    aligned: bool,
+) -> Result<String, PerCodecError> {
+    let time = decode_time_of_day_internal(data, aligned)?;
+
+    let value = time.to_string();
+    value.parse::<TimeOfDay>().map_err(time_error)?;
+
+    data.dump();
+
+    Ok(value)
+}
+
+// Common function to encode a `DATE-TIME` value.
+pub(crate) fn encode_date_time_common(
+    data: &mut PerCodecData,
+    value: &str,
+    aligned: bool,
+) -> Result<(), PerCodecError> {
+    let date_time = DateTime::from_date_time(value).map_err(time_error)?;
+
+    encode_date_internal(data, &date_time.date, aligned)?;
+    encode_time_of_day_internal(data, &date_time.time, aligned)?;
+
+    data.dump_encode();
+
+    Ok(())
+}
+
+// Common function to decode a `DATE-TIME` value.
+pub(crate) fn decode_date_time_common(
+    data: &mut PerCodecData,
+    aligned: bool,
+) -> Result<String, PerCodecError> {
+    let date_time = DateTime {
+        date: decode_date_internal(data, aligned)?,
+        time: decode_time_of_day_internal(data, aligned)?,
+        ..DateTime::default()
+    };
+
+    let value = date_time.to_date_time();
+    DateTime::from_date_time(&value).map_err(time_error)?;
+
+    data.dump();
+
+    Ok(value)
+}
+
+// Common function to encode a `DURATION` value.
+pub(crate) fn encode_duration_common(
+    data: &mut PerCodecData,
+    value: &str,
+    aligned: bool,
+) -> Result<(), PerCodecError> {
+    let duration = value.parse::<Duration>().map_err(time_error)?;
+    let components = [
+        duration.years,
+        duration.months,
+        duration.weeks,
+        duration.days,
+        duration.hours,
+        duration.minutes,
+        duration.seconds,
+    ];
+
+    let mut optionals = components
+        .iter()
+        .map(Option::is_some)
+        .collect::<BitVec<u8, Msb0>>();
+    optionals.push(duration.fraction.is_some());
+    encode_sequence_header_common(data, false, &optionals, false, aligned)?;
+
+    for component in components.iter().flatten() {
+        encode_integer_common(
+            data,
+            Some(0),
+            None,
+            false,
+            *component as i128,
+            false,
+            aligned,
+        )?;
+    }
+
+    if let Some(fraction) = duration.fraction.as_ref() {
+        let fractional_value = fraction
+            .parse::<i128>()
+            .map_err(|_| PerCodecError::new(format!("The fraction of '{}' is too long.", value)))?;
+        encode_integer_common(
+            data,
+            Some(1),
+            None,
+            false,
+            fraction.len() as i128,
+            false,
+            aligned,
+        )?;
+        encode_integer_common(data, Some(0), None, false, fractional_value, false, aligned)?;
+    }
+
+    data.dump_encode();
+
+    Ok(())
+}
+
+// Common function to decode a `DURATION` value.
+pub(crate) fn decode_duration_common(
+    data: &mut PerCodecData,
+    aligned: bool,
+) -> Result<String, PerCodecError> {
+    let (optionals, _) = decode_sequence_header_common(data, false, 8, aligned)?;
+
+    let mut components = [None; 7];
+    for (component, present) in components.iter_mut().zip(optionals.iter()) {
+        if *present {
+            let (value, _) = decode_integer_common(data, Some(0), None, false, aligned)?;
+            let value = u64::try_from(value).map_err(|_| {
+                PerCodecError::new(format!("The duration component {} is too large.", value))
+            })?;
+            component.replace(value);
+        }
+    }
+
+    let fraction = if optionals[7] {
+        let (digits, _) = decode_integer_common(data, Some(1), None, false, aligned)?;
+        let (value, _) = decode_integer_common(data, Some(0), None, false, aligned)?;
+        if digits > 39 || value.to_string().len() as i128 > digits {
+            return Err(PerCodecError::new(format!(
+                "Invalid fractional part {} with {} digits.",
+                value, digits
+            )));
+        }
+        Some(format!("{:0width$}", value, width = digits as usize))
+    } else {
+        None
+    };
+
+    let [years, months, weeks, days, hours, minutes, seconds] = components;
+    let value = Duration {
+        years,
+        months,
+        weeks,
+        days,
+        hours,
+        minutes,
+        seconds,
+        fraction,
+    }
+    .to_string();
+    value.parse::<Duration>().map_err(time_error)?;
+
+    data.dump();
+
+    Ok(value)
+}
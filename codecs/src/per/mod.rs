@@ -271,7 +271,10 @@ impl PerCodecData {
         Ok(bv)
     }
 
-    fn get_bytes(&mut self, length: usize) -> Result<Vec<u8>, PerCodecError> {
+    /// Get's `length` bytes from the current decode offset.
+    ///
+    /// This is useful when decoding the value of an open type that is not known to us.
+    pub fn get_bytes(&mut self, length: usize) -> Result<Vec<u8>, PerCodecError> {
         let length = length * 8;
         if length + self.decode_offset > self.bits.len() {
             return Err(PerCodecError::new(
@@ -376,4 +379,10 @@ impl PerCodecData {
         other.align();
         self.append_bits(&other.bits)
     }
+
+    /// Checks whether the encoded bits are the same as that of the `other` encoding.
+    /// This is useful when deciding whether a component with a `DEFAULT` value is to be encoded.
+    pub fn same_encoding(&self, other: &Self) -> bool {
+        self.bits == other.bits
+    }
 }
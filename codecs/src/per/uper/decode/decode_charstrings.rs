@@ -1,7 +1,11 @@
 //! Functionality for decoding character strings
 
-use crate::per::common::decode::decode_string_common;
-use crate::{PerCodecData, PerCodecError};
+use crate::per::common::charset::*;
+use crate::per::common::decode::{
+    decode_known_multiplier_string_common, decode_non_known_multiplier_string_common,
+};
+use crate::per::PerCodecData;
+use crate::PerCodecError;
 
 // 27.5.3 and 27.5.4
 /// Decode a VisibleString CharacterString Type.
@@ -17,7 +21,7 @@ pub fn decode_visible_string(
         ub,
         is_extensible
     );
-    decode_string_common(data, lb, ub, is_extensible, 7, false)
+    decode_known_multiplier_string_common(data, lb, ub, is_extensible, &VISIBLE_STRING, false)
 }
 
 /// Decode a PrintableString CharacterString Type.
@@ -33,24 +37,140 @@ pub fn decode_printable_string(
         ub,
         is_extensible
     );
-    decode_string_common(data, lb, ub, is_extensible, 7, false)
+    decode_known_multiplier_string_common(data, lb, ub, is_extensible, &PRINTABLE_STRING, false)
 }
 
-// UTF-8 String is always - indefinite length case as it's not a fixed character width string. It's
-// almost like decoding an octet string.
-// 27.6
-/// Decode a UTF8String CharacterString Type.
-pub fn decode_utf8_string(
+/// Decode a IA5String CharacterString Type.
+pub fn decode_ia5_string(
     data: &mut PerCodecData,
     lb: Option<i128>,
     ub: Option<i128>,
     is_extensible: bool,
 ) -> Result<String, PerCodecError> {
     log::trace!(
-        "decode_utf8_string: lb: {:?}, ub: {:?}, is_extensible: {}",
+        "decode_ia5_string: lb: {:?}, ub: {:?}, is_extensible: {}",
         lb,
         ub,
         is_extensible
     );
-    decode_string_common(data, lb, ub, is_extensible, 8, false)
+    decode_known_multiplier_string_common(data, lb, ub, is_extensible, &IA5_STRING, false)
+}
+
+/// Decode a NumericString CharacterString Type.
+pub fn decode_numeric_string(
+    data: &mut PerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+) -> Result<String, PerCodecError> {
+    log::trace!(
+        "decode_numeric_string: lb: {:?}, ub: {:?}, is_extensible: {}",
+        lb,
+        ub,
+        is_extensible
+    );
+    decode_known_multiplier_string_common(data, lb, ub, is_extensible, &NUMERIC_STRING, false)
+}
+
+/// Decode a BMPString CharacterString Type.
+pub fn decode_bmp_string(
+    data: &mut PerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+) -> Result<String, PerCodecError> {
+    log::trace!(
+        "decode_bmp_string: lb: {:?}, ub: {:?}, is_extensible: {}",
+        lb,
+        ub,
+        is_extensible
+    );
+    decode_known_multiplier_string_common(data, lb, ub, is_extensible, &BMP_STRING, false)
+}
+
+/// Decode a UniversalString CharacterString Type.
+pub fn decode_universal_string(
+    data: &mut PerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+) -> Result<String, PerCodecError> {
+    log::trace!(
+        "decode_universal_string: lb: {:?}, ub: {:?}, is_extensible: {}",
+        lb,
+        ub,
+        is_extensible
+    );
+    decode_known_multiplier_string_common(data, lb, ub, is_extensible, &UNIVERSAL_STRING, false)
+}
+
+/// Decode a known-multiplier CharacterString Type with a PermittedAlphabet constraint.
+///
+/// The `alphabet` has all the characters of the effective permitted alphabet.
+pub fn decode_permitted_alphabet_string(
+    data: &mut PerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+    alphabet: &str,
+) -> Result<String, PerCodecError> {
+    log::trace!(
+        "decode_permitted_alphabet_string: lb: {:?}, ub: {:?}, is_extensible: {}, alphabet: {}",
+        lb,
+        ub,
+        is_extensible,
+        alphabet
+    );
+    decode_known_multiplier_string_common(
+        data,
+        lb,
+        ub,
+        is_extensible,
+        &CharacterSet::from_alphabet(alphabet),
+        false,
+    )
+}
+
+/// Decode a UTF8String CharacterString Type.
+///
+/// The size constraints of a UTF8String are not PER-visible, so `lb`, `ub` and `is_extensible` are
+/// not used.
+pub fn decode_utf8_string(
+    data: &mut PerCodecData,
+    _lb: Option<i128>,
+    _ub: Option<i128>,
+    _is_extensible: bool,
+) -> Result<String, PerCodecError> {
+    log::trace!("decode_utf8_string");
+    decode_non_known_multiplier_string_common(data, true, false)
+}
+
+/// Decode a TeletexString CharacterString Type.
+///
+/// The size constraints of a TeletexString are not PER-visible, so `lb`, `ub` and `is_extensible` are
+/// not used.
+/// Each of the octets is decoded as a single character.
+pub fn decode_teletex_string(
+    data: &mut PerCodecData,
+    _lb: Option<i128>,
+    _ub: Option<i128>,
+    _is_extensible: bool,
+) -> Result<String, PerCodecError> {
+    log::trace!("decode_teletex_string");
+    decode_non_known_multiplier_string_common(data, false, false)
+}
+
+/// Decode a GeneralString CharacterString Type.
+///
+/// The size constraints of a GeneralString are not PER-visible, so `lb`, `ub` and `is_extensible` are
+/// not used.
+/// Each of the octets is decoded as a single character.
+pub fn decode_general_string(
+    data: &mut PerCodecData,
+    _lb: Option<i128>,
+    _ub: Option<i128>,
+    _is_extensible: bool,
+) -> Result<String, PerCodecError> {
+    log::trace!("decode_general_string");
+    decode_non_known_multiplier_string_common(data, false, false)
 }
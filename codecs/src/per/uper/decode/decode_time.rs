@@ -0,0 +1,47 @@
+//! Functionality for decoding the Time Types
+
+use crate::per::common::time::*;
+use crate::per::PerCodecData;
+use crate::PerCodecError;
+
+/// Decode a UTCTime Value.
+pub fn decode_utc_time(data: &mut PerCodecData) -> Result<String, PerCodecError> {
+    log::trace!("decode_utc_time");
+
+    decode_useful_time_common(data, false, false)
+}
+
+/// Decode a GeneralizedTime Value.
+pub fn decode_generalized_time(data: &mut PerCodecData) -> Result<String, PerCodecError> {
+    log::trace!("decode_generalized_time");
+
+    decode_useful_time_common(data, true, false)
+}
+
+/// Decode a DATE Value.
+pub fn decode_date(data: &mut PerCodecData) -> Result<String, PerCodecError> {
+    log::trace!("decode_date");
+
+    decode_date_common(data, false)
+}
+
+/// Decode a TIME-OF-DAY Value.
+pub fn decode_time_of_day(data: &mut PerCodecData) -> Result<String, PerCodecError> {
+    log::trace!("decode_time_of_day");
+
+    decode_time_of_day_common(data, false)
+}
+
+/// Decode a DATE-TIME Value.
+pub fn decode_date_time(data: &mut PerCodecData) -> Result<String, PerCodecError> {
+    log::trace!("decode_date_time");
+
+    decode_date_time_common(data, false)
+}
+
+/// Decode a DURATION Value.
+pub fn decode_duration(data: &mut PerCodecData) -> Result<String, PerCodecError> {
+    log::trace!("decode_duration");
+
+    decode_duration_common(data, false)
+}
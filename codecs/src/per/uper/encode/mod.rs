@@ -7,6 +7,10 @@ use crate::{PerCodecData, PerCodecError};
 #[allow(unused)]
 use crate::per::common::encode::*;
 
+use crate::per::common::charset::*;
+
+use crate::per::common::time::*;
+
 /// Encode a Choice Index
 ///
 /// During Encoding a 'CHOICE' Type to help decoding, the 'CHOICE' Index is encoded first, followed
@@ -48,6 +52,29 @@ pub fn encode_sequence_header(
     encode_sequence_header_common(data, is_extensible, optionals, extended, false)
 }
 
+/// Encode the bitmap of the extension additions of a sequence
+///
+/// When any of the extension additions of an extensible sequence are present, the bitmap with a
+/// bit for each of the extension additions follows the components in the extension root. Each of
+/// the additions present is then encoded as an open type using [`encode_open_type`].
+pub fn encode_sequence_extension_bitmap(
+    data: &mut PerCodecData,
+    bitmap: &BitSlice<u8, Msb0>,
+) -> Result<(), PerCodecError> {
+    log::trace!("encode_sequence_extension_bitmap: bitmap: {:?}", bitmap);
+
+    encode_sequence_extension_bitmap_common(data, bitmap, false)
+}
+
+/// Encode an Open Type
+///
+/// The `value` is the complete encoding of the value of the open type.
+pub fn encode_open_type(data: &mut PerCodecData, value: PerCodecData) -> Result<(), PerCodecError> {
+    log::trace!("encode_open_type: length: {}", value.bits.len());
+
+    encode_open_type_common(data, value, false)
+}
+
 /// Encode an INTEGER
 ///
 /// This API is also used by other `encode` functions to encode an integer value.
@@ -146,6 +173,34 @@ pub fn encode_octetstring(
     encode_octet_string_common(data, lb, ub, is_extensible, octet_string, extended, false)
 }
 
+/// Encode a REAL
+///
+/// The value is encoded in the binary form with the base 2, as in the CER/DER encoding.
+pub fn encode_real(data: &mut PerCodecData, value: f64) -> Result<(), PerCodecError> {
+    log::trace!("encode_real: value: {}", value);
+
+    encode_real_common(data, value, false)
+}
+
+/// Encode an OBJECT IDENTIFIER
+///
+/// The `arcs` are the components of the value. eg. `[1, 2, 840, 113549]` for `1.2.840.113549`.
+pub fn encode_object_identifier(
+    data: &mut PerCodecData,
+    arcs: &[u128],
+) -> Result<(), PerCodecError> {
+    log::trace!("encode_object_identifier: arcs: {:?}", arcs);
+
+    encode_object_identifier_common(data, arcs, false, false)
+}
+
+/// Encode a RELATIVE-OID
+pub fn encode_relative_oid(data: &mut PerCodecData, arcs: &[u128]) -> Result<(), PerCodecError> {
+    log::trace!("encode_relative_oid: arcs: {:?}", arcs);
+
+    encode_object_identifier_common(data, arcs, true, false)
+}
+
 // Encode a Length Determinent
 pub fn encode_length_determinent(
     data: &mut PerCodecData,
@@ -165,6 +220,32 @@ pub fn encode_length_determinent(
     encode_length_determinent_common(data, lb, ub, normally_small, value, false)
 }
 
+/// Encode the items of a SEQUENCE OF preceded by their count
+///
+/// `encode_items` is called with the range of the items to be encoded. It is called once for each
+/// fragment, when there are 16K items or more.
+pub fn encode_sequence_of<F>(
+    data: &mut PerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+    length: usize,
+    encode_items: F,
+) -> Result<(), PerCodecError>
+where
+    F: FnMut(&mut PerCodecData, std::ops::Range<usize>) -> Result<(), PerCodecError>,
+{
+    log::trace!(
+        "encode_sequence_of: lb: {:?}, ub: {:?}, is_extensible: {}, length: {}",
+        lb,
+        ub,
+        is_extensible,
+        length
+    );
+
+    encode_sequence_of_common(data, lb, ub, is_extensible, length, encode_items, false)
+}
+
 /// Encode a VisibleString CharacterString Type.
 pub fn encode_visible_string(
     data: &mut PerCodecData,
@@ -183,7 +264,16 @@ pub fn encode_visible_string(
         extended
     );
 
-    encode_ascii_ish_string_common(data, lb, ub, is_extensible, value, extended)
+    encode_known_multiplier_string_common(
+        data,
+        lb,
+        ub,
+        is_extensible,
+        value,
+        extended,
+        &VISIBLE_STRING,
+        false,
+    )
 }
 
 /// Encode a PrintableString CharacterString Type.
@@ -204,11 +294,20 @@ pub fn encode_printable_string(
         extended
     );
 
-    encode_ascii_ish_string_common(data, lb, ub, is_extensible, value, extended)
+    encode_known_multiplier_string_common(
+        data,
+        lb,
+        ub,
+        is_extensible,
+        value,
+        extended,
+        &PRINTABLE_STRING,
+        false,
+    )
 }
 
-/// Encode a UTF8String CharacterString Type.
-pub fn encode_utf8_string(
+/// Encode a IA5String CharacterString Type.
+pub fn encode_ia5_string(
     data: &mut PerCodecData,
     lb: Option<i128>,
     ub: Option<i128>,
@@ -217,7 +316,7 @@ pub fn encode_utf8_string(
     extended: bool,
 ) -> Result<(), PerCodecError> {
     log::trace!(
-        "encode_utf8_string: lb: {:?}, ub: {:?}, is_extensible: {}, value: {}, extended: {}",
+        "encode_ia5_string: lb: {:?}, ub: {:?}, is_extensible: {}, value: {}, extended: {}",
         lb,
         ub,
         is_extensible,
@@ -225,19 +324,20 @@ pub fn encode_utf8_string(
         extended
     );
 
-    encode_octet_string_common(
+    encode_known_multiplier_string_common(
         data,
         lb,
         ub,
         is_extensible,
-        value.as_bytes(),
+        value,
         extended,
+        &IA5_STRING,
         false,
     )
 }
 
-// Common function used by PrintableString and VisibleString
-fn encode_ascii_ish_string_common(
+/// Encode a NumericString CharacterString Type.
+pub fn encode_numeric_string(
     data: &mut PerCodecData,
     lb: Option<i128>,
     ub: Option<i128>,
@@ -245,32 +345,218 @@ fn encode_ascii_ish_string_common(
     value: &String,
     extended: bool,
 ) -> Result<(), PerCodecError> {
-    if extended {
-        return Err(PerCodecError::new(
-            "Encode of extended octetstring not yet implemented",
-        ));
-    }
+    log::trace!(
+        "encode_numeric_string: lb: {:?}, ub: {:?}, is_extensible: {}, value: {}, extended: {}",
+        lb,
+        ub,
+        is_extensible,
+        value,
+        extended
+    );
 
-    if is_extensible {
-        data.encode_bool(extended);
-    }
+    encode_known_multiplier_string_common(
+        data,
+        lb,
+        ub,
+        is_extensible,
+        value,
+        extended,
+        &NUMERIC_STRING,
+        false,
+    )
+}
 
-    encode_length_determinent_common(data, lb, ub, false, value.len(), false)?;
+/// Encode a BMPString CharacterString Type.
+pub fn encode_bmp_string(
+    data: &mut PerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+    value: &String,
+    extended: bool,
+) -> Result<(), PerCodecError> {
+    log::trace!(
+        "encode_bmp_string: lb: {:?}, ub: {:?}, is_extensible: {}, value: {}, extended: {}",
+        lb,
+        ub,
+        is_extensible,
+        value,
+        extended
+    );
 
-    // FIXME: bits_per_char is hardcoded it shold be obtained from the 'alphabet' of the string.
-    let bits_per_char = 7;
-    let offset = 8 - bits_per_char;
-    let chars_vec = value
-        .chars()
-        .map(|c| BitSlice::<_, Msb0>::from_element(&(c as u8))[offset..].to_bitvec())
-        .collect::<Vec<_>>()
-        .into_iter()
-        .flatten()
-        .collect::<BitVec<u8, Msb0>>();
+    encode_known_multiplier_string_common(
+        data,
+        lb,
+        ub,
+        is_extensible,
+        value,
+        extended,
+        &BMP_STRING,
+        false,
+    )
+}
 
-    data.append_bits(chars_vec.as_bitslice());
+/// Encode a UniversalString CharacterString Type.
+pub fn encode_universal_string(
+    data: &mut PerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+    value: &String,
+    extended: bool,
+) -> Result<(), PerCodecError> {
+    log::trace!(
+        "encode_universal_string: lb: {:?}, ub: {:?}, is_extensible: {}, value: {}, extended: {}",
+        lb,
+        ub,
+        is_extensible,
+        value,
+        extended
+    );
 
-    Ok(())
+    encode_known_multiplier_string_common(
+        data,
+        lb,
+        ub,
+        is_extensible,
+        value,
+        extended,
+        &UNIVERSAL_STRING,
+        false,
+    )
+}
+
+/// Encode a known-multiplier CharacterString Type with a PermittedAlphabet constraint.
+///
+/// The `alphabet` has all the characters of the effective permitted alphabet, which decides the
+/// number of bits used for each of the characters.
+#[allow(clippy::too_many_arguments)]
+pub fn encode_permitted_alphabet_string(
+    data: &mut PerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+    value: &String,
+    extended: bool,
+    alphabet: &str,
+) -> Result<(), PerCodecError> {
+    log::trace!(
+        "encode_permitted_alphabet_string: lb: {:?}, ub: {:?}, is_extensible: {}, value: {}, extended: {}, alphabet: {}",
+        lb,
+        ub,
+        is_extensible,
+        value,
+        extended,
+        alphabet
+    );
+
+    encode_known_multiplier_string_common(
+        data,
+        lb,
+        ub,
+        is_extensible,
+        value,
+        extended,
+        &CharacterSet::from_alphabet(alphabet),
+        false,
+    )
+}
+
+/// Encode a UTF8String CharacterString Type.
+///
+/// The size constraints of a UTF8String are not PER-visible, so `lb`, `ub`, `is_extensible` and
+/// `extended` are not used.
+pub fn encode_utf8_string(
+    data: &mut PerCodecData,
+    _lb: Option<i128>,
+    _ub: Option<i128>,
+    _is_extensible: bool,
+    value: &String,
+    _extended: bool,
+) -> Result<(), PerCodecError> {
+    log::trace!("encode_utf8_string: value: {}", value);
+
+    encode_non_known_multiplier_string_common(data, value, true, false)
+}
+
+/// Encode a TeletexString CharacterString Type.
+///
+/// The size constraints of a TeletexString are not PER-visible, so `lb`, `ub`, `is_extensible` and
+/// `extended` are not used.
+/// Each of the characters is encoded as a single octet.
+pub fn encode_teletex_string(
+    data: &mut PerCodecData,
+    _lb: Option<i128>,
+    _ub: Option<i128>,
+    _is_extensible: bool,
+    value: &String,
+    _extended: bool,
+) -> Result<(), PerCodecError> {
+    log::trace!("encode_teletex_string: value: {}", value);
+
+    encode_non_known_multiplier_string_common(data, value, false, false)
+}
+
+/// Encode a GeneralString CharacterString Type.
+///
+/// The size constraints of a GeneralString are not PER-visible, so `lb`, `ub`, `is_extensible` and
+/// `extended` are not used.
+/// Each of the characters is encoded as a single octet.
+pub fn encode_general_string(
+    data: &mut PerCodecData,
+    _lb: Option<i128>,
+    _ub: Option<i128>,
+    _is_extensible: bool,
+    value: &String,
+    _extended: bool,
+) -> Result<(), PerCodecError> {
+    log::trace!("encode_general_string: value: {}", value);
+
+    encode_non_known_multiplier_string_common(data, value, false, false)
+}
+
+// Section 32 of X.691 for all the Time Types.
+
+/// Encode a UTCTime Value.
+pub fn encode_utc_time(data: &mut PerCodecData, value: &str) -> Result<(), PerCodecError> {
+    log::trace!("encode_utc_time: value: {}", value);
+
+    encode_useful_time_common(data, value, false, false)
+}
+
+/// Encode a GeneralizedTime Value.
+pub fn encode_generalized_time(data: &mut PerCodecData, value: &str) -> Result<(), PerCodecError> {
+    log::trace!("encode_generalized_time: value: {}", value);
+
+    encode_useful_time_common(data, value, true, false)
+}
+
+/// Encode a DATE Value.
+pub fn encode_date(data: &mut PerCodecData, value: &str) -> Result<(), PerCodecError> {
+    log::trace!("encode_date: value: {}", value);
+
+    encode_date_common(data, value, false)
+}
+
+/// Encode a TIME-OF-DAY Value.
+pub fn encode_time_of_day(data: &mut PerCodecData, value: &str) -> Result<(), PerCodecError> {
+    log::trace!("encode_time_of_day: value: {}", value);
+
+    encode_time_of_day_common(data, value, false)
+}
+
+/// Encode a DATE-TIME Value.
+pub fn encode_date_time(data: &mut PerCodecData, value: &str) -> Result<(), PerCodecError> {
+    log::trace!("encode_date_time: value: {}", value);
+
+    encode_date_time_common(data, value, false)
+}
+
+/// Encode a DURATION Value.
+pub fn encode_duration(data: &mut PerCodecData, value: &str) -> Result<(), PerCodecError> {
+    log::trace!("encode_duration: value: {}", value);
+
+    encode_duration_common(data, value, false)
 }
 
 #[cfg(test)]
@@ -288,6 +574,17 @@ mod tests {
         assert_eq!(data.bits[0], true);
     }
 
+    #[test]
+    fn open_type_not_aligned() {
+        let mut data = PerCodecData::new_uper();
+        encode_sequence_header(&mut data, true, bits![u8, Msb0;], true).unwrap();
+        encode_sequence_extension_bitmap(&mut data, bits![u8, Msb0; 0, 1]).unwrap();
+        let mut addition = PerCodecData::new_uper();
+        encode_bool(&mut addition, true).unwrap();
+        encode_open_type(&mut data, addition).unwrap();
+        assert_eq!(data.into_bytes(), [0x81, 0x40, 0x60, 0x00]);
+    }
+
     #[test]
     fn int_too_small() {
         assert!(encode_integer(
@@ -418,6 +715,123 @@ mod tests {
         .is_err());
     }
 
+    #[test]
+    fn fragmented_visible_string() {
+        let value = "abcdefghij".repeat(2000);
+        let mut data = PerCodecData::new_uper();
+        encode_visible_string(&mut data, None, None, false, &value, false).unwrap();
+        let encoded = data.into_bytes();
+        // 16K characters of 7 bits, then 3616 characters with a two octet length.
+        assert_eq!(encoded.len(), (8 + 16384 * 7 + 16 + 3616 * 7) / 8);
+        assert_eq!(encoded[0], 0xc1);
+
+        let mut data = PerCodecData::from_slice_uper(&encoded);
+        let decoded =
+            crate::uper::decode::decode_visible_string(&mut data, None, None, false).unwrap();
+        assert_eq!(decoded, value);
+    }
+
+    #[test]
+    fn fragmented_octetstring_too_big() {
+        assert!(encode_octetstring(
+            &mut PerCodecData::new_uper(),
+            None,
+            Some(65536),
+            false,
+            &vec![0; 65537],
+            false
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn numeric_string_indexes() {
+        let mut data = PerCodecData::new_uper();
+        encode_numeric_string(&mut data, None, None, false, &"1 9".to_string(), false).unwrap();
+        assert_eq!(data.into_bytes(), vec![0x03, 0x20, 0xa0]);
+
+        assert!(encode_numeric_string(
+            &mut PerCodecData::new_uper(),
+            None,
+            None,
+            false,
+            &"1a".to_string(),
+            false
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn permitted_alphabet_string() {
+        // `NumericString (FROM ("0".."9"))` has 10 characters, which are encoded as indexes.
+        let value = "19".to_string();
+        let mut data = PerCodecData::new_uper();
+        encode_permitted_alphabet_string(&mut data, None, None, false, &value, false, "0123456789")
+            .unwrap();
+        let encoded = data.into_bytes();
+        assert_eq!(encoded, vec![0x02, 0x19]);
+
+        let mut data = PerCodecData::from_slice_uper(&encoded);
+        let decoded = crate::uper::decode::decode_permitted_alphabet_string(
+            &mut data,
+            None,
+            None,
+            false,
+            "0123456789",
+        )
+        .unwrap();
+        assert_eq!(decoded, value);
+
+        assert!(encode_permitted_alphabet_string(
+            &mut PerCodecData::new_uper(),
+            None,
+            None,
+            false,
+            &" 1".to_string(),
+            false,
+            "0123456789"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn ia5_string_control_characters() {
+        let value = "a\tb\r\n".to_string();
+        let mut data = PerCodecData::new_uper();
+        encode_ia5_string(&mut data, Some(5), Some(5), false, &value, false).unwrap();
+        let encoded = data.into_bytes();
+        assert_eq!(encoded.len(), 5);
+
+        let mut data = PerCodecData::from_slice_uper(&encoded);
+        let decoded =
+            crate::uper::decode::decode_ia5_string(&mut data, Some(5), Some(5), false).unwrap();
+        assert_eq!(decoded, value);
+    }
+
+    #[test]
+    fn teletex_string_size_not_per_visible() {
+        let value = "caf\u{e9}".to_string();
+        let mut data = PerCodecData::new_uper();
+        encode_teletex_string(&mut data, Some(1), Some(2), false, &value, false).unwrap();
+        let encoded = data.into_bytes();
+        assert_eq!(encoded, vec![0x04, 0x63, 0x61, 0x66, 0xe9]);
+
+        let mut data = PerCodecData::from_slice_uper(&encoded);
+        let decoded =
+            crate::uper::decode::decode_teletex_string(&mut data, Some(1), Some(2), false).unwrap();
+        assert_eq!(decoded, value);
+
+        assert!(encode_general_string(
+            &mut PerCodecData::new_uper(),
+            None,
+            None,
+            false,
+            &"\u{3b1}".to_string(),
+            false
+        )
+        .is_err());
+    }
+
     #[test]
     fn bitstring_uper_ascii_ish_string() {
         // Taken from the example in x.691
@@ -426,4 +840,32 @@ mod tests {
         let result = encode_visible_string(&mut codec_data, None, None, false, &value, false);
         assert!(result.is_ok(), "{:#?}", result.err().unwrap());
     }
+
+    #[test]
+    fn real_uper() {
+        // The length determinent is not aligned.
+        let mut data = PerCodecData::new_uper();
+        encode_bool(&mut data, true).unwrap();
+        encode_real(&mut data, 0.5).unwrap();
+        let encoded = data.into_bytes();
+        assert_eq!(encoded, vec![0x81, 0xc0, 0x7f, 0x80, 0x80]);
+
+        let mut data = PerCodecData::from_slice_uper(&encoded);
+        assert!(crate::uper::decode::decode_bool(&mut data).unwrap());
+        let decoded = crate::uper::decode::decode_real(&mut data).unwrap();
+        assert_eq!(decoded, 0.5);
+    }
+
+    #[test]
+    fn date_uper() {
+        // The near-future year 2024 takes eight bits and is not aligned.
+        let mut data = PerCodecData::new_uper();
+        encode_date(&mut data, "2024-02-29").unwrap();
+        let encoded = data.into_bytes();
+        assert_eq!(encoded, vec![0x40, 0xc7, 0x80]);
+
+        let mut data = PerCodecData::from_slice_uper(&encoded);
+        let decoded = crate::uper::decode::decode_date(&mut data).unwrap();
+        assert_eq!(decoded, "2024-02-29");
+    }
 }
@@ -0,0 +1,606 @@
+//! Broken-down values of the ASN.1 Time Types
+//!
+//! The Rust types generated for `UTCTime`, `GeneralizedTime` and the time types of X.680 (`DATE`,
+//! `TIME-OF-DAY`, `DATE-TIME` and `DURATION`) hold the values in their lexical form as a `String`.
+//! The types in this module are the broken-down forms of these values. The lexical forms are
+//! validated when they are converted to the broken-down forms, which is also done by the codecs
+//! before encoding or after decoding a value.
+
+pub mod error;
+
+pub use error::Error as TimeError;
+
+use std::fmt;
+use std::str::FromStr;
+
+/// A Date in the Gregorian calendar (`DATE` type).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Date {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+}
+
+/// A Time of the Day (`TIME-OF-DAY` type).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TimeOfDay {
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+}
+
+/// A Date and a Time of the Day (`DATE-TIME`, `UTCTime` and `GeneralizedTime` types).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DateTime {
+    pub date: Date,
+    pub time: TimeOfDay,
+
+    /// Decimal digits of the fraction of a second. For example `"25"` for a quarter of a second.
+    pub fraction: Option<String>,
+
+    /// Difference from UTC in minutes. `Some(0)` for UTC and `None` for the local time.
+    pub utc_offset: Option<i16>,
+}
+
+/// A Duration (`DURATION` type).
+///
+/// Only the components present in the lexical form (for example `P1Y2M` or `PT30M`) are `Some`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Duration {
+    pub years: Option<u64>,
+    pub months: Option<u64>,
+    pub weeks: Option<u64>,
+    pub days: Option<u64>,
+    pub hours: Option<u64>,
+    pub minutes: Option<u64>,
+    pub seconds: Option<u64>,
+
+    /// Decimal digits of the fraction of the last of the components present.
+    pub fraction: Option<String>,
+}
+
+impl Date {
+    fn validate(&self) -> Option<()> {
+        let days = match self.month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if self.year % 4 == 0 && (self.year % 100 != 0 || self.year % 400 == 0) => 29,
+            2 => 28,
+            _ => return None,
+        };
+        if (1..=days).contains(&self.day) {
+            Some(())
+        } else {
+            None
+        }
+    }
+}
+
+impl TimeOfDay {
+    // The hour `24` is only valid for the end of the day and the second `60` for a leap second.
+    fn validate(&self) -> Option<()> {
+        let end_of_day = self.hour == 24 && self.minute == 0 && self.second == 0;
+        if (self.hour < 24 || end_of_day) && self.minute < 60 && self.second <= 60 {
+            Some(())
+        } else {
+            None
+        }
+    }
+}
+
+impl DateTime {
+    /// Converts from the lexical form of a `UTCTime` value: `YYMMDDhhmm[ss]` followed by `Z` or a
+    /// `+hhmm` or `-hhmm` difference from UTC. Two digit years from `50` are in the 20th century.
+    pub fn from_utc_time(value: &str) -> Result<Self, TimeError> {
+        let error = || TimeError::new(format!("Invalid UTCTime value '{}'.", value));
+
+        let mut lexer = Lexer::new(value);
+        let year = lexer.digits(2).ok_or_else(error)? as i32;
+        let year = if year < 50 { 2000 + year } else { 1900 + year };
+        let date = lexer.date(year).ok_or_else(error)?;
+        let hour = lexer.digits(2).ok_or_else(error)? as u8;
+        let minute = lexer.digits(2).ok_or_else(error)? as u8;
+        let second = lexer.optional_digits(2).unwrap_or(0) as u8;
+        let time = TimeOfDay {
+            hour,
+            minute,
+            second,
+        };
+        time.validate().ok_or_else(error)?;
+        let utc_offset = lexer.utc_offset(true).ok_or_else(error)?;
+        if utc_offset.is_none() || !lexer.is_empty() {
+            return Err(error());
+        }
+
+        Ok(Self {
+            date,
+            time,
+            fraction: None,
+            utc_offset,
+        })
+    }
+
+    /// Converts to the lexical form of a `UTCTime` value, always with the seconds.
+    pub fn to_utc_time(&self) -> Result<String, TimeError> {
+        if !(1950..2050).contains(&self.date.year) || self.fraction.is_some() {
+            return Err(TimeError::new(format!(
+                "The value {:?} cannot be a UTCTime value.",
+                self
+            )));
+        }
+        let utc_offset = self.utc_offset.ok_or_else(|| {
+            TimeError::new("The local time cannot be a UTCTime value.".to_string())
+        })?;
+
+        Ok(format!(
+            "{:02}{:02}{:02}{:02}{:02}{:02}{}",
+            self.date.year % 100,
+            self.date.month,
+            self.date.day,
+            self.time.hour,
+            self.time.minute,
+            self.time.second,
+            format_utc_offset(utc_offset)
+        ))
+    }
+
+    /// Converts from the lexical form of a `GeneralizedTime` value: `YYYYMMDDHH[MM[SS[.f...]]]`
+    /// optionally followed by `Z` or a `+hh[mm]` or `-hh[mm]` difference from UTC. The missing
+    /// minutes and seconds are zero. Only a fraction of the second is supported.
+    pub fn from_generalized_time(value: &str) -> Result<Self, TimeError> {
+        let error = || TimeError::new(format!("Invalid GeneralizedTime value '{}'.", value));
+
+        let mut lexer = Lexer::new(value);
+        let year = lexer.digits(4).ok_or_else(error)? as i32;
+        let date = lexer.date(year).ok_or_else(error)?;
+        let hour = lexer.digits(2).ok_or_else(error)? as u8;
+        let (minute, second) = match lexer.optional_digits(2) {
+            Some(minute) => (minute, lexer.optional_digits(2)),
+            None => (0, None),
+        };
+        let time = TimeOfDay {
+            hour,
+            minute: minute as u8,
+            second: second.unwrap_or(0) as u8,
+        };
+        time.validate().ok_or_else(error)?;
+        let fraction = if second.is_some() {
+            lexer.fraction()
+        } else {
+            None
+        };
+        let utc_offset = lexer.utc_offset(false).ok_or_else(error)?;
+        if !lexer.is_empty() {
+            return Err(error());
+        }
+
+        Ok(Self {
+            date,
+            time,
+            fraction,
+            utc_offset,
+        })
+    }
+
+    /// Converts to the lexical form of a `GeneralizedTime` value, always with the minutes and the
+    /// seconds.
+    pub fn to_generalized_time(&self) -> String {
+        let mut value = format!(
+            "{:04}{:02}{:02}{:02}{:02}{:02}",
+            self.date.year,
+            self.date.month,
+            self.date.day,
+            self.time.hour,
+            self.time.minute,
+            self.time.second
+        );
+        if let Some(fraction) = self.fraction.as_ref() {
+            value.push('.');
+            value.push_str(fraction);
+        }
+        if let Some(utc_offset) = self.utc_offset {
+            value.push_str(&format_utc_offset(utc_offset));
+        }
+        value
+    }
+
+    /// Converts from the lexical form of a `DATE-TIME` value: `YYYY-MM-DDThh:mm:ss`.
+    pub fn from_date_time(value: &str) -> Result<Self, TimeError> {
+        let error = || TimeError::new(format!("Invalid DATE-TIME value '{}'.", value));
+
+        let mut lexer = Lexer::new(value);
+        let date = lexer.iso_date().ok_or_else(error)?;
+        if !lexer.eat(b'T') {
+            return Err(error());
+        }
+        let time = lexer.iso_time().ok_or_else(error)?;
+        if !lexer.is_empty() {
+            return Err(error());
+        }
+
+        Ok(Self {
+            date,
+            time,
+            fraction: None,
+            utc_offset: None,
+        })
+    }
+
+    /// Converts to the lexical form of a `DATE-TIME` value.
+    pub fn to_date_time(&self) -> String {
+        format!("{}T{}", self.date, self.time)
+    }
+}
+
+/// Converts from the lexical form of a `DATE` value: `YYYY-MM-DD`.
+impl FromStr for Date {
+    type Err = TimeError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut lexer = Lexer::new(value);
+        match lexer.iso_date() {
+            Some(date) if lexer.is_empty() => Ok(date),
+            _ => Err(TimeError::new(format!("Invalid DATE value '{}'.", value))),
+        }
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Converts from the lexical form of a `TIME-OF-DAY` value: `hh:mm:ss`.
+impl FromStr for TimeOfDay {
+    type Err = TimeError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut lexer = Lexer::new(value);
+        match lexer.iso_time() {
+            Some(time) if lexer.is_empty() => Ok(time),
+            _ => Err(TimeError::new(format!(
+                "Invalid TIME-OF-DAY value '{}'.",
+                value
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for TimeOfDay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)
+    }
+}
+
+/// Converts from the lexical form of a `DURATION` value, like `P1Y2M10DT2H30M` or `PT0.5S`. Only
+/// the last of the components may have a fraction.
+impl FromStr for Duration {
+    type Err = TimeError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let error = || TimeError::new(format!("Invalid DURATION value '{}'.", value));
+
+        let mut lexer = Lexer::new(value);
+        if !lexer.eat(b'P') {
+            return Err(error());
+        }
+
+        let mut duration = Duration::default();
+        let mut designators = "YMWD".chars();
+        let mut in_time = false;
+        let mut count = 0;
+        while !lexer.is_empty() {
+            if duration.fraction.is_some() {
+                return Err(error());
+            }
+            if !in_time && lexer.eat(b'T') {
+                in_time = true;
+                designators = "HMS".chars();
+                if lexer.is_empty() {
+                    return Err(error());
+                }
+                continue;
+            }
+
+            let number = lexer.number().ok_or_else(error)?;
+            duration.fraction = lexer.fraction();
+            let designator = lexer.next().ok_or_else(error)? as char;
+            if !designators.any(|d| d == designator) {
+                return Err(error());
+            }
+            let component = match (in_time, designator) {
+                (false, 'Y') => &mut duration.years,
+                (false, 'M') => &mut duration.months,
+                (false, 'W') => &mut duration.weeks,
+                (false, 'D') => &mut duration.days,
+                (true, 'H') => &mut duration.hours,
+                (true, 'M') => &mut duration.minutes,
+                _ => &mut duration.seconds,
+            };
+            component.replace(number);
+            count += 1;
+        }
+        if count == 0 {
+            return Err(error());
+        }
+
+        Ok(duration)
+    }
+}
+
+impl fmt::Display for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The first four components are before the `T`.
+        let components = [
+            (self.years, 'Y'),
+            (self.months, 'M'),
+            (self.weeks, 'W'),
+            (self.days, 'D'),
+            (self.hours, 'H'),
+            (self.minutes, 'M'),
+            (self.seconds, 'S'),
+        ];
+        let last = components.iter().rposition(|(c, _)| c.is_some());
+
+        write!(f, "P")?;
+        for (i, (component, designator)) in components.iter().enumerate() {
+            if i == 4 && components[4..].iter().any(|(c, _)| c.is_some()) {
+                write!(f, "T")?;
+            }
+            if let Some(number) = component {
+                write!(f, "{}", number)?;
+                if let (Some(fraction), true) = (self.fraction.as_ref(), Some(i) == last) {
+                    write!(f, ".{}", fraction)?;
+                }
+                write!(f, "{}", designator)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn format_utc_offset(utc_offset: i16) -> String {
+    if utc_offset == 0 {
+        "Z".to_string()
+    } else {
+        let sign = if utc_offset < 0 { '-' } else { '+' };
+        let minutes = utc_offset.abs();
+        format!("{}{:02}{:02}", sign, minutes / 60, minutes % 60)
+    }
+}
+
+// A cursor over the ASCII characters of a lexical form. The functions return `None` if the
+// expected characters are not found.
+struct Lexer<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Lexer<'a> {
+    fn new(value: &'a str) -> Self {
+        Self {
+            bytes: value.as_bytes(),
+            pos: 0,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.pos == self.bytes.len()
+    }
+
+    fn next(&mut self) -> Option<u8> {
+        let c = self.bytes.get(self.pos).copied();
+        if c.is_some() {
+            self.pos += 1;
+        }
+        c
+    }
+
+    fn eat(&mut self, c: u8) -> bool {
+        if self.bytes.get(self.pos) == Some(&c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    // Exactly `count` decimal digits.
+    fn digits(&mut self, count: usize) -> Option<u32> {
+        let digits = self.bytes.get(self.pos..self.pos + count)?;
+        if !digits.iter().all(u8::is_ascii_digit) {
+            return None;
+        }
+        self.pos += count;
+        Some(
+            digits
+                .iter()
+                .fold(0, |value, digit| value * 10 + (digit - b'0') as u32),
+        )
+    }
+
+    // `count` decimal digits if the next character is a digit.
+    fn optional_digits(&mut self, count: usize) -> Option<u32> {
+        match self.bytes.get(self.pos) {
+            Some(c) if c.is_ascii_digit() => self.digits(count),
+            _ => None,
+        }
+    }
+
+    // One or more decimal digits.
+    fn number(&mut self) -> Option<u64> {
+        let start = self.pos;
+        while self.bytes.get(self.pos).is_some_and(u8::is_ascii_digit) {
+            self.pos += 1;
+        }
+        std::str::from_utf8(&self.bytes[start..self.pos])
+            .ok()?
+            .parse()
+            .ok()
+    }
+
+    // Digits of a fraction after a `.` or a `,`. Without any digits, the `.` or the `,` is left
+    // for the caller to reject.
+    fn fraction(&mut self) -> Option<String> {
+        if !matches!(self.bytes.get(self.pos), Some(b'.') | Some(b',')) {
+            return None;
+        }
+        let start = self.pos + 1;
+        let end = start
+            + self.bytes[start..]
+                .iter()
+                .take_while(|c| c.is_ascii_digit())
+                .count();
+        if end == start {
+            return None;
+        }
+        self.pos = end;
+        Some(String::from_utf8_lossy(&self.bytes[start..end]).to_string())
+    }
+
+    // `MMDD` following the year.
+    fn date(&mut self, year: i32) -> Option<Date> {
+        let month = self.digits(2)? as u8;
+        let day = self.digits(2)? as u8;
+        let date = Date { year, month, day };
+        date.validate().map(|_| date)
+    }
+
+    // `YYYY-MM-DD`
+    fn iso_date(&mut self) -> Option<Date> {
+        let year = self.digits(4)? as i32;
+        if !self.eat(b'-') {
+            return None;
+        }
+        let month = self.digits(2)? as u8;
+        if !self.eat(b'-') {
+            return None;
+        }
+        let day = self.digits(2)? as u8;
+        let date = Date { year, month, day };
+        date.validate().map(|_| date)
+    }
+
+    // `hh:mm:ss`
+    fn iso_time(&mut self) -> Option<TimeOfDay> {
+        let hour = self.digits(2)? as u8;
+        if !self.eat(b':') {
+            return None;
+        }
+        let minute = self.digits(2)? as u8;
+        if !self.eat(b':') {
+            return None;
+        }
+        let second = self.digits(2)? as u8;
+        let time = TimeOfDay {
+            hour,
+            minute,
+            second,
+        };
+        time.validate().map(|_| time)
+    }
+
+    // `Z` or `+hh[mm]` or `-hh[mm]`. The minutes are required if `with_minutes` is set. Returns
+    // `Some(None)` if there is no difference from UTC.
+    fn utc_offset(&mut self, with_minutes: bool) -> Option<Option<i16>> {
+        if self.eat(b'Z') {
+            return Some(Some(0));
+        }
+        let sign = if self.eat(b'+') {
+            1
+        } else if self.eat(b'-') {
+            -1
+        } else {
+            return Some(None);
+        };
+        let hours = self.digits(2)? as i16;
+        let minutes = if with_minutes {
+            self.digits(2)?
+        } else {
+            self.optional_digits(2).unwrap_or(0)
+        } as i16;
+        if hours > 23 || minutes > 59 {
+            return None;
+        }
+        Some(Some(sign * (hours * 60 + minutes)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn utc_time() {
+        let value = DateTime::from_utc_time("4912312359Z").unwrap();
+        assert_eq!(value.date.year, 2049);
+        assert_eq!(value.time.second, 0);
+        assert_eq!(value.to_utc_time().unwrap(), "491231235900Z");
+
+        let value = DateTime::from_utc_time("500101000000-0530").unwrap();
+        assert_eq!(value.date.year, 1950);
+        assert_eq!(value.utc_offset, Some(-330));
+        assert_eq!(value.to_utc_time().unwrap(), "500101000000-0530");
+
+        for &invalid in &["4912312359", "491231235Z", "490230000000Z", "491231246000Z"] {
+            assert!(DateTime::from_utc_time(invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn generalized_time() {
+        let value = DateTime::from_generalized_time("20240229123456.25Z").unwrap();
+        assert_eq!(value.date.day, 29);
+        assert_eq!(value.fraction.as_deref(), Some("25"));
+        assert_eq!(value.to_generalized_time(), "20240229123456.25Z");
+
+        let value = DateTime::from_generalized_time("2023010112+01").unwrap();
+        assert_eq!(value.utc_offset, Some(60));
+        assert_eq!(value.to_generalized_time(), "20230101120000+0100");
+        assert_eq!(
+            DateTime::from_generalized_time("20230101120000")
+                .unwrap()
+                .utc_offset,
+            None
+        );
+
+        for &invalid in &["20230229120000Z", "202301011", "20230101120000.Z"] {
+            assert!(DateTime::from_generalized_time(invalid).is_err());
+        }
+    }
+
+    #[test]
+    fn date_and_time_of_day() {
+        let date = "1999-12-31".parse::<Date>().unwrap();
+        assert_eq!(date.to_string(), "1999-12-31");
+        assert!("1999-13-01".parse::<Date>().is_err());
+        assert!("99-12-31".parse::<Date>().is_err());
+
+        let time = "23:59:60".parse::<TimeOfDay>().unwrap();
+        assert_eq!(time.to_string(), "23:59:60");
+        assert!("24:00:01".parse::<TimeOfDay>().is_err());
+
+        let value = DateTime::from_date_time("2024-02-29T24:00:00").unwrap();
+        assert_eq!(value.to_date_time(), "2024-02-29T24:00:00");
+        assert!(DateTime::from_date_time("2024-02-29 12:00:00").is_err());
+    }
+
+    #[test]
+    fn duration() {
+        let duration = "P1Y2M10DT2H30M".parse::<Duration>().unwrap();
+        assert_eq!(duration.years, Some(1));
+        assert_eq!(duration.minutes, Some(30));
+        assert_eq!(duration.seconds, None);
+        assert_eq!(duration.to_string(), "P1Y2M10DT2H30M");
+
+        let duration = "PT0.5S".parse::<Duration>().unwrap();
+        assert_eq!(duration.fraction.as_deref(), Some("5"));
+        assert_eq!(duration.to_string(), "PT0.5S");
+        assert_eq!("P2W".parse::<Duration>().unwrap().weeks, Some(2));
+
+        for &invalid in &["P", "PT", "P1.5Y2M", "P1D2Y", "1Y", "P1H"] {
+            assert!(invalid.parse::<Duration>().is_err(), "{}", invalid);
+        }
+    }
+}
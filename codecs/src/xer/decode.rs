@@ -0,0 +1,248 @@
+//! Decode APIs for XER Codec
+
+use bitvec::prelude::*;
+
+use crate::xer::encode::check_range;
+use crate::xer::{XerCodecData, XerCodecError};
+
+/// Decode an Element
+///
+/// Returns the `XerCodecData` for decoding the value from the contents of the next element. It
+/// is an error if the name of the next element is not `name`.
+pub fn decode_element(data: &mut XerCodecData, name: &str) -> Result<XerCodecData, XerCodecError> {
+    let element = data.elements.get(data.decode_offset).ok_or_else(|| {
+        XerCodecError::new(format!(
+            "XerCodec:DecodeError:Expected element '{}', no more elements.",
+            name
+        ))
+    })?;
+
+    if element.name != name {
+        return Err(XerCodecError::new(format!(
+            "XerCodec:DecodeError:Expected element '{}', found '{}'.",
+            name, element.name
+        )));
+    }
+
+    let content = XerCodecData {
+        text: element.text.clone(),
+        elements: element.children.clone(),
+        decode_offset: 0,
+        key: data.key,
+    };
+    data.decode_offset += 1;
+
+    log::trace!("decode_element: name: {}", name);
+    Ok(content)
+}
+
+/// Name of the next Element if any.
+pub fn peek_element_name(data: &XerCodecData) -> Option<&str> {
+    data.elements
+        .get(data.decode_offset)
+        .map(|element| element.name.as_str())
+}
+
+/// Skip the next Element
+///
+/// Used for skipping the elements for the unknown extension additions.
+pub fn skip_element(data: &mut XerCodecData) -> Result<(), XerCodecError> {
+    if data.is_empty() {
+        return Err(XerCodecError::new(
+            "XerCodec:DecodeError:No more elements to skip.",
+        ));
+    }
+
+    log::trace!(
+        "skip_element: name: {}",
+        data.elements[data.decode_offset].name
+    );
+    data.decode_offset += 1;
+
+    Ok(())
+}
+
+/// Decode a BOOLEAN Value
+pub fn decode_bool(data: &mut XerCodecData) -> Result<bool, XerCodecError> {
+    let value = match decode_empty_element(data, "a BOOLEAN")?.as_str() {
+        "true" => true,
+        "false" => false,
+        other => {
+            return Err(XerCodecError::new(format!(
+                "XerCodec:DecodeError:'{}' is not a BOOLEAN value.",
+                other
+            )))
+        }
+    };
+
+    log::trace!("decode_bool: {}", value);
+    Ok(value)
+}
+
+/// Decode an INTEGER
+///
+/// A value that is outside the range of a non-extensible constraint is an error.
+pub fn decode_integer(
+    data: &mut XerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+) -> Result<i128, XerCodecError> {
+    let text = data.text.trim();
+    let value = text.parse::<i128>().map_err(|_| {
+        XerCodecError::new(format!(
+            "XerCodec:DecodeError:'{}' is not an INTEGER value.",
+            text
+        ))
+    })?;
+
+    check_range(lb, ub, is_extensible, value, "Value").map_err(decode_error)?;
+
+    log::trace!("decode_integer: value: {}", value);
+    Ok(value)
+}
+
+/// Decode an ENUMERATED Value
+///
+/// The identifier of the empty element is looked up in the `named_values` to get the value.
+pub fn decode_enumerated(
+    data: &mut XerCodecData,
+    named_values: &[(&str, i128)],
+) -> Result<i128, XerCodecError> {
+    let name = decode_empty_element(data, "an ENUMERATED")?;
+
+    let value = named_values
+        .iter()
+        .find(|(n, _)| *n == name)
+        .map(|(_, value)| *value)
+        .ok_or_else(|| {
+            XerCodecError::new(format!(
+                "XerCodec:DecodeError:'{}' is not a named value of ENUMERATED.",
+                name
+            ))
+        })?;
+
+    log::trace!("decode_enumerated: value: {}", value);
+    Ok(value)
+}
+
+/// Decode a NULL Value
+pub fn decode_null(data: &mut XerCodecData) -> Result<(), XerCodecError> {
+    if !data.text.trim().is_empty() || !data.elements.is_empty() {
+        return Err(XerCodecError::new(
+            "XerCodec:DecodeError:Unexpected contents for a NULL value.",
+        ));
+    }
+
+    log::trace!("decode_null");
+    Ok(())
+}
+
+/// Decode a BIT STRING
+///
+/// Whitespace between the `0` and `1` characters is ignored.
+pub fn decode_bitstring(
+    data: &mut XerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+) -> Result<BitVec<u8, Msb0>, XerCodecError> {
+    let mut bits = BitVec::<u8, Msb0>::new();
+    for c in data.text.chars().filter(|c| !c.is_whitespace()) {
+        match c {
+            '0' => bits.push(false),
+            '1' => bits.push(true),
+            _ => {
+                return Err(XerCodecError::new(format!(
+                    "XerCodec:DecodeError:Invalid character '{}' in a BIT STRING.",
+                    c
+                )))
+            }
+        }
+    }
+
+    check_range(lb, ub, is_extensible, bits.len() as i128, "Length").map_err(decode_error)?;
+
+    log::trace!("decode_bitstring: length: {}", bits.len());
+    Ok(bits)
+}
+
+/// Decode an OCTET STRING
+///
+/// Whitespace between the hexadecimal digits is ignored.
+pub fn decode_octetstring(
+    data: &mut XerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+) -> Result<Vec<u8>, XerCodecError> {
+    let digits = data
+        .text
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| {
+            c.to_digit(16).ok_or_else(|| {
+                XerCodecError::new(format!(
+                    "XerCodec:DecodeError:Invalid character '{}' in an OCTET STRING.",
+                    c
+                ))
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if !digits.len().is_multiple_of(2) {
+        return Err(XerCodecError::new(
+            "XerCodec:DecodeError:Odd number of hexadecimal digits in an OCTET STRING.",
+        ));
+    }
+    let octets = digits
+        .chunks(2)
+        .map(|pair| (pair[0] << 4 | pair[1]) as u8)
+        .collect::<Vec<u8>>();
+
+    check_range(lb, ub, is_extensible, octets.len() as i128, "Length").map_err(decode_error)?;
+
+    log::trace!("decode_octetstring: length: {}", octets.len());
+    Ok(octets)
+}
+
+/// Decode a Character String
+///
+/// The size constraints are in the number of characters.
+pub fn decode_character_string(
+    data: &mut XerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+) -> Result<String, XerCodecError> {
+    let value = data.text.clone();
+
+    check_range(
+        lb,
+        ub,
+        is_extensible,
+        value.chars().count() as i128,
+        "Length",
+    )
+    .map_err(decode_error)?;
+
+    log::trace!("decode_character_string: value: {}", value);
+    Ok(value)
+}
+
+// The values of some types are encoded as a single empty element, returns the name of the element.
+fn decode_empty_element(data: &mut XerCodecData, expected: &str) -> Result<String, XerCodecError> {
+    match data.elements.as_slice() {
+        [element] if element.text.is_empty() && element.children.is_empty() => {
+            data.decode_offset = 1;
+            Ok(element.name.clone())
+        }
+        _ => Err(XerCodecError::new(format!(
+            "XerCodec:DecodeError:Expected an empty element for {}.",
+            expected
+        ))),
+    }
+}
+
+fn decode_error(e: XerCodecError) -> XerCodecError {
+    XerCodecError::new(format!("XerCodec:DecodeError:{}", e))
+}
@@ -0,0 +1,192 @@
+//! Encode APIs for XER Codec
+
+use bitvec::prelude::*;
+
+use crate::xer::{XerCodecData, XerCodecError, XmlElement};
+
+/// Encode an Element
+///
+/// The `content` is the encoding of a value, which is added as an element with the given `name`.
+pub fn encode_element(
+    data: &mut XerCodecData,
+    name: &str,
+    content: XerCodecData,
+) -> Result<(), XerCodecError> {
+    log::trace!("encode_element: name: {}", name);
+
+    data.elements.push(XmlElement {
+        name: name.to_string(),
+        text: content.text,
+        children: content.elements,
+    });
+
+    Ok(())
+}
+
+/// Encode a BOOLEAN Value
+///
+/// The value is encoded as an empty element `<true/>` or `<false/>`.
+pub fn encode_bool(data: &mut XerCodecData, value: bool) -> Result<(), XerCodecError> {
+    log::trace!("encode_bool: {}", value);
+
+    encode_empty_element(data, if value { "true" } else { "false" });
+
+    Ok(())
+}
+
+/// Encode an INTEGER
+///
+/// The value is encoded in decimal. A value that is outside the range of a non-extensible
+/// constraint is an error.
+pub fn encode_integer(
+    data: &mut XerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+    value: i128,
+) -> Result<(), XerCodecError> {
+    log::trace!(
+        "encode_integer: lb: {:?}, ub: {:?}, is_extensible: {}, value: {}",
+        lb,
+        ub,
+        is_extensible,
+        value
+    );
+
+    check_range(lb, ub, is_extensible, value, "Value")?;
+
+    data.text = value.to_string();
+
+    Ok(())
+}
+
+/// Encode an ENUMERATED Value
+///
+/// The value is encoded as an empty element with the identifier of the value from the
+/// `named_values`.
+pub fn encode_enumerated(
+    data: &mut XerCodecData,
+    named_values: &[(&str, i128)],
+    value: i128,
+) -> Result<(), XerCodecError> {
+    log::trace!("encode_enumerated: value: {}", value);
+
+    let name = named_values
+        .iter()
+        .find(|(_, v)| *v == value)
+        .map(|(name, _)| *name)
+        .ok_or_else(|| {
+            XerCodecError::new(format!(
+                "Value {} is not a named value of ENUMERATED.",
+                value
+            ))
+        })?;
+    encode_empty_element(data, name);
+
+    Ok(())
+}
+
+/// Encode a NULL Value
+///
+/// There is no content for a NULL value.
+pub fn encode_null(_data: &mut XerCodecData) -> Result<(), XerCodecError> {
+    log::trace!("encode_null");
+
+    Ok(())
+}
+
+/// Encode a BIT STRING
+///
+/// Each bit is encoded as a `0` or a `1` character.
+pub fn encode_bitstring(
+    data: &mut XerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+    bit_string: &BitSlice<u8, Msb0>,
+) -> Result<(), XerCodecError> {
+    log::trace!(
+        "encode_bitstring: lb: {:?}, ub: {:?}, is_extensible: {}, length: {}",
+        lb,
+        ub,
+        is_extensible,
+        bit_string.len()
+    );
+
+    check_range(lb, ub, is_extensible, bit_string.len() as i128, "Length")?;
+
+    data.text = bit_string
+        .iter()
+        .map(|bit| if *bit { '1' } else { '0' })
+        .collect();
+
+    Ok(())
+}
+
+/// Encode an OCTET STRING
+///
+/// The octets are encoded as hexadecimal digits.
+pub fn encode_octetstring(
+    data: &mut XerCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+    octet_string: &[u8],
+) -> Result<(), XerCodecError> {
+    log::trace!(
+        "encode_octetstring: lb: {:?}, ub: {:?}, is_extensible: {}, length: {}",
+        lb,
+        ub,
+        is_extensible,
+        octet_string.len()
+    );
+
+    check_range(lb, ub, is_extensible, octet_string.len() as i128, "Length")?;
+
+    data.text = octet_string
+        .iter()
+        .map(|octet| format!("{:02X}", octet))
+        .collect();
+
+    Ok(())
+}
+
+/// Encode a Character String
+///
+/// The characters are encoded as the text of the element.
+pub fn encode_character_string(data: &mut XerCodecData, value: &str) -> Result<(), XerCodecError> {
+    log::trace!("encode_character_string: value: {}", value);
+
+    data.text = value.to_string();
+
+    Ok(())
+}
+
+fn encode_empty_element(data: &mut XerCodecData, name: &str) {
+    data.elements.push(XmlElement {
+        name: name.to_string(),
+        ..Default::default()
+    });
+}
+
+// Checks that the value (or the size) is in the range given by a non-extensible constraint.
+pub(crate) fn check_range(
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+    value: i128,
+    what: &str,
+) -> Result<(), XerCodecError> {
+    if is_extensible {
+        return Ok(());
+    }
+
+    if lb.is_some_and(|lb| value < lb) || ub.is_some_and(|ub| value > ub) {
+        return Err(XerCodecError::new(format!(
+            "{} {} is outside the range [{:?}, {:?}].",
+            what, value, lb, ub
+        )));
+    }
+
+    Ok(())
+}
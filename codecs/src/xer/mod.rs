@@ -0,0 +1,241 @@
+#![allow(dead_code)]
+//! ASN.1 XER Codec
+//!
+//! Encoding and Decoding of ASN.1 Types using the Basic XML Encoding Rules (X.693). A value of an
+//! ASN.1 Type is encoded as an XML element, the name of the element is the identifier of the
+//! component (or the alternative) in the enclosing `SEQUENCE` (or `CHOICE`) and is the name of the
+//! ASN.1 Type otherwise.
+//!
+//! While decoding, the XML document is first read into a tree of elements and the values are
+//! decoded from the elements in order, similar to how the values are decoded from the bytes by
+//! the other Codecs.
+
+pub mod error;
+
+pub mod encode;
+
+pub mod decode;
+
+mod xml;
+
+pub use error::Error as XerCodecError;
+
+use xml::XmlElement;
+
+/// Structure representing an XER Codec.
+///
+/// While En(De)coding ASN.1 Types using the XER encoding scheme, the encoded data is stored as a
+/// list of XML elements and the text, which together form the contents of an enclosing element.
+#[derive(Default, Debug)]
+pub struct XerCodecData {
+    text: String,
+    elements: Vec<XmlElement>,
+    decode_offset: usize,
+    key: Option<i128>,
+}
+
+impl XerCodecData {
+    /// Default `XerCodecData` for encoding.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create Our `XerCodecData` Structure by parsing the XML document.
+    pub fn from_xml(xml: &str) -> Result<Self, XerCodecError> {
+        let elements = xml::parse(xml)?;
+        Ok(Self {
+            elements,
+            ..Default::default()
+        })
+    }
+
+    /// Get's the encoded elements as an XML document.
+    pub fn to_xml(&self) -> String {
+        xml::write(&self.elements, false)
+    }
+
+    /// Get's the encoded elements as an XML document with one element per line and the nested
+    /// elements indented.
+    pub fn to_xml_pretty(&self) -> String {
+        xml::write(&self.elements, true)
+    }
+
+    /// Whether all the elements are decoded.
+    pub fn is_empty(&self) -> bool {
+        self.decode_offset == self.elements.len()
+    }
+
+    /// Get's the current `key` value.
+    ///
+    /// This value will be used by a decoder to determine which 'decode' function is to be called
+    /// (for example in an `enum`, it will be used to determine which `variant` of the `enum` will
+    /// be decoded.
+    pub fn get_key(&self) -> Option<i128> {
+        self.key
+    }
+
+    /// Sets the current `key` value.
+    ///
+    /// See [`crate::PerCodecData::set_key`] for how this is used for decoding 'open' types.
+    pub fn set_key(&mut self, key: i128) {
+        let _ = self.key.replace(key);
+    }
+
+    /// Dump current 'offset'.
+    pub fn dump(&self) {
+        log::trace!(
+            "offset: {}, elements: {:?}",
+            self.decode_offset,
+            self.elements
+                .iter()
+                .map(|e| e.name.as_str())
+                .collect::<Vec<_>>()
+        );
+    }
+}
+
+/// Trait representing an 'XER Codec'.
+///
+/// This 'trait' is to be derived by any `struct` or `enum` representing an ASN.1 Type. The `tag`
+/// is the name of the XML element for the value, if `None` the name of the ASN.1 Type is used.
+pub trait XerCodec {
+    type Output;
+
+    fn xer_decode_tagged(
+        data: &mut XerCodecData,
+        tag: Option<&str>,
+    ) -> Result<Self::Output, XerCodecError>;
+
+    fn xer_encode_tagged(
+        &self,
+        data: &mut XerCodecData,
+        tag: Option<&str>,
+    ) -> Result<(), XerCodecError>;
+
+    fn xer_decode(data: &mut XerCodecData) -> Result<Self::Output, XerCodecError> {
+        Self::xer_decode_tagged(data, None)
+    }
+
+    fn xer_encode(&self, data: &mut XerCodecData) -> Result<(), XerCodecError> {
+        self.xer_encode_tagged(data, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bitvec::prelude::*;
+
+    #[test]
+    fn xml_read_write() {
+        let xml = r#"<?xml version="1.0"?>
+            <!-- A Comment -->
+            <Message>
+                <name>a &lt;b&gt; &amp; c&#x21;</name>
+                <flag><true/></flag>
+                <empty/>
+            </Message>"#;
+        let data = XerCodecData::from_xml(xml).unwrap();
+        assert_eq!(
+            data.to_xml(),
+            "<Message><name>a &lt;b&gt; &amp; c!</name><flag><true/></flag><empty/></Message>"
+        );
+        assert_eq!(
+            data.to_xml_pretty(),
+            "<Message>\n    <name>a &lt;b&gt; &amp; c!</name>\n    <flag><true/></flag>\n    <empty/>\n</Message>\n"
+        );
+
+        assert!(XerCodecData::from_xml("<a><b></a>").is_err());
+        assert!(XerCodecData::from_xml("<a>&unknown;</a>").is_err());
+    }
+
+    #[test]
+    fn integer_encode_decode() {
+        let mut data = XerCodecData::new();
+        let mut content = XerCodecData::new();
+        encode::encode_integer(&mut content, Some(0), Some(65535), false, 258).unwrap();
+        encode::encode_element(&mut data, "id", content).unwrap();
+        assert_eq!(data.to_xml(), "<id>258</id>");
+
+        let mut data = XerCodecData::from_xml("<id> -5 </id>").unwrap();
+        let mut content = decode::decode_element(&mut data, "id").unwrap();
+        assert_eq!(
+            decode::decode_integer(&mut content, None, None, false).unwrap(),
+            -5
+        );
+        assert!(data.is_empty());
+
+        let mut data = XerCodecData::from_xml("<id>256</id>").unwrap();
+        let mut content = decode::decode_element(&mut data, "id").unwrap();
+        assert!(decode::decode_integer(&mut content, Some(0), Some(255), false).is_err());
+
+        let mut data = XerCodecData::from_xml("<id>5</id>").unwrap();
+        assert!(decode::decode_element(&mut data, "value").is_err());
+    }
+
+    #[test]
+    fn bool_enumerated_encode_decode() {
+        let named_values = [("reject", 0), ("ignore", 1), ("notify", 2)];
+
+        let mut data = XerCodecData::new();
+        let mut content = XerCodecData::new();
+        encode::encode_enumerated(&mut content, &named_values, 1).unwrap();
+        encode::encode_element(&mut data, "criticality", content).unwrap();
+        let mut content = XerCodecData::new();
+        encode::encode_bool(&mut content, false).unwrap();
+        encode::encode_element(&mut data, "flag", content).unwrap();
+        assert_eq!(
+            data.to_xml(),
+            "<criticality><ignore/></criticality><flag><false/></flag>"
+        );
+
+        let mut data = XerCodecData::from_xml(&data.to_xml()).unwrap();
+        let mut content = decode::decode_element(&mut data, "criticality").unwrap();
+        assert_eq!(
+            decode::decode_enumerated(&mut content, &named_values).unwrap(),
+            1
+        );
+        let mut content = decode::decode_element(&mut data, "flag").unwrap();
+        assert!(!decode::decode_bool(&mut content).unwrap());
+
+        let mut data = XerCodecData::from_xml("<c><unknown/></c>").unwrap();
+        let mut content = decode::decode_element(&mut data, "c").unwrap();
+        assert!(decode::decode_enumerated(&mut content, &named_values).is_err());
+    }
+
+    #[test]
+    fn bitstring_octetstring_encode_decode() {
+        let bits = bits![u8, Msb0; 1, 0, 1, 1, 0, 1, 1, 1, 1, 0];
+
+        let mut data = XerCodecData::new();
+        encode::encode_bitstring(&mut data, Some(10), Some(10), false, bits).unwrap();
+        assert_eq!(data.text, "1011011110");
+        let decoded = decode::decode_bitstring(&mut data, Some(10), Some(10), false).unwrap();
+        assert_eq!(decoded, bits);
+
+        let mut data = XerCodecData::new();
+        encode::encode_octetstring(&mut data, None, None, false, &[0xde, 0xad]).unwrap();
+        assert_eq!(data.text, "DEAD");
+        let decoded = decode::decode_octetstring(&mut data, None, None, false).unwrap();
+        assert_eq!(decoded, vec![0xde, 0xad]);
+
+        let mut data = XerCodecData::from_xml("<b>10 12</b>").unwrap();
+        let mut content = decode::decode_element(&mut data, "b").unwrap();
+        assert!(decode::decode_bitstring(&mut content, None, None, false).is_err());
+    }
+
+    #[test]
+    fn sequence_components_decode() {
+        let mut data = XerCodecData::from_xml("<s><a>1</a><c/></s>").unwrap();
+        data.set_key(5);
+        let mut content = decode::decode_element(&mut data, "s").unwrap();
+        assert_eq!(content.get_key(), Some(5));
+        assert_eq!(decode::peek_element_name(&content), Some("a"));
+        let _ = decode::decode_element(&mut content, "a").unwrap();
+        assert_eq!(decode::peek_element_name(&content), Some("c"));
+        decode::skip_element(&mut content).unwrap();
+        assert!(content.is_empty());
+        assert_eq!(decode::peek_element_name(&content), None);
+        assert!(decode::skip_element(&mut content).is_err());
+    }
+}
@@ -0,0 +1,262 @@
+//! A minimal XML reader and writer for the XER Codec
+//!
+//! The XER encodings do not use attributes, processing instructions or mixed content, so only the
+//! elements and their text are retained when reading an XML document. The XML declaration,
+//! comments and any attributes are skipped.
+
+use crate::xer::XerCodecError;
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(crate) struct XmlElement {
+    pub(crate) name: String,
+    pub(crate) text: String,
+    pub(crate) children: Vec<XmlElement>,
+}
+
+// Parses the XML document and returns the top level elements.
+pub(crate) fn parse(xml: &str) -> Result<Vec<XmlElement>, XerCodecError> {
+    let mut reader = Reader { xml, offset: 0 };
+    let mut elements = vec![];
+    loop {
+        reader.skip_misc()?;
+        if reader.rest().is_empty() {
+            break;
+        }
+        elements.push(reader.element()?);
+    }
+    Ok(elements)
+}
+
+// Writes the elements as XML, with each element on a separate line and the nested elements
+// indented if `pretty` is true.
+pub(crate) fn write(elements: &[XmlElement], pretty: bool) -> String {
+    let mut out = String::new();
+    for element in elements {
+        write_element(&mut out, element, pretty, 0);
+    }
+    out
+}
+
+fn write_element(out: &mut String, element: &XmlElement, pretty: bool, depth: usize) {
+    if pretty {
+        out.push_str(&"    ".repeat(depth));
+    }
+    if element.text.is_empty() && element.children.is_empty() {
+        out.push_str(&format!("<{}/>", element.name));
+    } else if element.children.is_empty() {
+        out.push_str(&format!(
+            "<{}>{}</{}>",
+            element.name,
+            escape(&element.text),
+            element.name
+        ));
+    } else if element.children.iter().all(is_empty_element) {
+        // Values like `<true/>` and the ENUMERATED identifiers are kept on the same line.
+        out.push_str(&format!("<{}>", element.name));
+        for child in &element.children {
+            write_element(out, child, false, 0);
+        }
+        out.push_str(&format!("</{}>", element.name));
+    } else {
+        out.push_str(&format!("<{}>", element.name));
+        if pretty {
+            out.push('\n');
+        }
+        for child in &element.children {
+            write_element(out, child, pretty, depth + 1);
+        }
+        if pretty {
+            out.push_str(&"    ".repeat(depth));
+        }
+        out.push_str(&format!("</{}>", element.name));
+    }
+    if pretty {
+        out.push('\n');
+    }
+}
+
+fn is_empty_element(element: &XmlElement) -> bool {
+    element.text.is_empty() && element.children.is_empty()
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '&' => escaped.push_str("&amp;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+struct Reader<'a> {
+    xml: &'a str,
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn rest(&self) -> &'a str {
+        &self.xml[self.offset..]
+    }
+
+    fn error(&self, msg: &str) -> XerCodecError {
+        XerCodecError::new(format!(
+            "XerCodec:DecodeError:Invalid XML at offset {}: {}",
+            self.offset, msg
+        ))
+    }
+
+    // Skips the whitespace, XML declaration, comments and the document type declaration.
+    fn skip_misc(&mut self) -> Result<(), XerCodecError> {
+        loop {
+            let rest = self.rest();
+            let trimmed = rest.trim_start();
+            self.offset += rest.len() - trimmed.len();
+            let end = if trimmed.starts_with("<?") {
+                "?>"
+            } else if trimmed.starts_with("<!--") {
+                "-->"
+            } else if trimmed.starts_with("<!") {
+                ">"
+            } else {
+                return Ok(());
+            };
+            match trimmed.find(end) {
+                Some(idx) => self.offset += idx + end.len(),
+                None => return Err(self.error("Unterminated markup.")),
+            }
+        }
+    }
+
+    fn element(&mut self) -> Result<XmlElement, XerCodecError> {
+        if !self.rest().starts_with('<') {
+            return Err(self.error("Expected an element."));
+        }
+        self.offset += 1;
+
+        let name_len = self
+            .rest()
+            .find(|c: char| c.is_whitespace() || c == '>' || c == '/')
+            .ok_or_else(|| self.error("Unterminated tag."))?;
+        let name = self.rest()[..name_len].to_string();
+        if name.is_empty() {
+            return Err(self.error("Empty element name."));
+        }
+        self.offset += name_len;
+
+        // Attributes are not used by XER and are skipped.
+        let mut quote = None;
+        let tag_len = self
+            .rest()
+            .char_indices()
+            .find(|(_, c)| match quote {
+                Some(q) => {
+                    if *c == q {
+                        quote = None;
+                    }
+                    false
+                }
+                None => {
+                    if *c == '"' || *c == '\'' {
+                        quote = Some(*c);
+                    }
+                    *c == '>'
+                }
+            })
+            .map(|(idx, _)| idx)
+            .ok_or_else(|| self.error("Unterminated tag."))?;
+        let empty = self.rest()[..tag_len].ends_with('/');
+        self.offset += tag_len + 1;
+
+        let mut element = XmlElement {
+            name,
+            ..Default::default()
+        };
+        if empty {
+            return Ok(element);
+        }
+
+        loop {
+            let rest = self.rest();
+            let text_len = rest
+                .find('<')
+                .ok_or_else(|| self.error("Unterminated element."))?;
+            element
+                .text
+                .push_str(&unescape(&rest[..text_len]).map_err(|e| self.error(&e))?);
+            self.offset += text_len;
+
+            let rest = self.rest();
+            if rest.starts_with("</") {
+                let end = rest
+                    .find('>')
+                    .ok_or_else(|| self.error("Unterminated tag."))?;
+                if rest[2..end].trim() != element.name {
+                    return Err(self.error(&format!(
+                        "Expected the end tag for '{}', found '{}'.",
+                        element.name,
+                        &rest[2..end]
+                    )));
+                }
+                self.offset += end + 1;
+                break;
+            } else if rest.starts_with("<!--") {
+                self.skip_misc()?;
+            } else if let Some(cdata) = rest.strip_prefix("<![CDATA[") {
+                let end = cdata
+                    .find("]]>")
+                    .ok_or_else(|| self.error("Unterminated CDATA."))?;
+                element.text.push_str(&cdata[..end]);
+                self.offset += "<![CDATA[".len() + end + "]]>".len();
+            } else {
+                element.children.push(self.element()?);
+            }
+        }
+
+        // The whitespace between the child elements is not a part of the value.
+        if !element.children.is_empty() && element.text.trim().is_empty() {
+            element.text.clear();
+        }
+
+        Ok(element)
+    }
+}
+
+fn unescape(text: &str) -> Result<String, String> {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(idx) = rest.find('&') {
+        unescaped.push_str(&rest[..idx]);
+        rest = &rest[idx..];
+        let end = rest
+            .find(';')
+            .ok_or_else(|| "Unterminated entity reference.".to_string())?;
+        let entity = &rest[1..end];
+        let c = match entity {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => {
+                if let Some(hex) = entity.strip_prefix("#x") {
+                    u32::from_str_radix(hex, 16).ok().and_then(char::from_u32)
+                } else if let Some(dec) = entity.strip_prefix('#') {
+                    dec.parse::<u32>().ok().and_then(char::from_u32)
+                } else {
+                    None
+                }
+            }
+        };
+        match c {
+            Some(c) => unescaped.push(c),
+            None => return Err(format!("Unknown entity reference '&{};'.", entity)),
+        }
+        rest = &rest[end + 1..];
+    }
+    unescaped.push_str(rest);
+    Ok(unescaped)
+}
@@ -30,6 +30,24 @@ pub(crate) struct TyCodecParams {
     // Number of Optional Fields (In ASN.1 SEQUENCE types.)
     pub(crate) optional_fields: Option<syn::LitInt>,
 
+    // Identifiers and Values of an ENUMERATED type, of the form "reject(0), ignore(1)".
+    pub(crate) named_values: Option<syn::LitStr>,
+
+    // Characters of the effective permitted alphabet of a character string type.
+    pub(crate) alphabet: Option<syn::LitStr>,
+
+    // Name of the Type in the ASN.1 definition.
+    pub(crate) name: Option<syn::LitStr>,
+
+    // Tags of the Type, of the form "[APPLICATION 1] IMPLICIT". Used by the tag based Codecs.
+    pub(crate) tag: Option<syn::LitStr>,
+
+    // Whether the `validate` method of the Type is called before encoding a value.
+    pub(crate) validate: Option<syn::LitBool>,
+
+    // Whether the `validate` method of the Type is called after decoding a value.
+    pub(crate) validate_decode: Option<syn::LitBool>,
+
     // The actual 'attribute' from the Syntax tree from which this struct is generated. This will
     // be used mainly for error reporting inside the functions where this struct is passed.
     pub(crate) attr: Option<syn::Attribute>,
@@ -166,6 +184,88 @@ pub(crate) fn parse_ty_meta_as_codec_params(
                                 )),
                             }
                         }
+                        // parses #[asn(named_values = "reject(0), ignore(1)")]
+                        syn::NestedMeta::Meta(syn::Meta::NameValue(ref m)) if m.path == NAMED_VALUES => {
+                            match m.lit {
+                                syn::Lit::Str(ref named_values) => {
+                                    let named_values = named_values.clone();
+                                    codec_params.named_values.replace(named_values);
+                                }
+                                _ => errors.push(syn::Error::new_spanned(
+                                    nested,
+                                    "`named_values` value should be a String Literal",
+                                )),
+                            }
+                        }
+                        // parses #[asn(alphabet = "0123456789")]
+                        syn::NestedMeta::Meta(syn::Meta::NameValue(ref m)) if m.path == ALPHABET => {
+                            match m.lit {
+                                syn::Lit::Str(ref alphabet) => {
+                                    let alphabet = alphabet.clone();
+                                    codec_params.alphabet.replace(alphabet);
+                                }
+                                _ => errors.push(syn::Error::new_spanned(
+                                    nested,
+                                    "`alphabet` value should be a String Literal",
+                                )),
+                            }
+                        }
+                        // parses #[asn(name = "NGAP-PDU")]
+                        syn::NestedMeta::Meta(syn::Meta::NameValue(ref m)) if m.path == NAME => {
+                            match m.lit {
+                                syn::Lit::Str(ref name) => {
+                                    let name = name.clone();
+                                    codec_params.name.replace(name);
+                                }
+                                _ => errors.push(syn::Error::new_spanned(
+                                    nested,
+                                    "`name` value should be a String Literal",
+                                )),
+                            }
+                        }
+                        // parses #[asn(tag = "[APPLICATION 1] IMPLICIT")]
+                        syn::NestedMeta::Meta(syn::Meta::NameValue(ref m)) if m.path == TAG => {
+                            match m.lit {
+                                syn::Lit::Str(ref tag) => {
+                                    let tag = tag.clone();
+                                    codec_params.tag.replace(tag);
+                                }
+                                _ => errors.push(syn::Error::new_spanned(
+                                    nested,
+                                    "`tag` value should be a String Literal",
+                                )),
+                            }
+                        }
+                        // parses #[asn(validate = true)]
+                        syn::NestedMeta::Meta(syn::Meta::NameValue(ref m))
+                            if m.path == VALIDATE =>
+                        {
+                            match m.lit {
+                                syn::Lit::Bool(ref v) => {
+                                    let validate = v.clone();
+                                    codec_params.validate.replace(validate);
+                                }
+                                _ => errors.push(syn::Error::new_spanned(
+                                    nested,
+                                    "`validate` value should be a Boolean Literal",
+                                )),
+                            }
+                        }
+                        // parses #[asn(validate_decode = true)]
+                        syn::NestedMeta::Meta(syn::Meta::NameValue(ref m))
+                            if m.path == VALIDATE_DECODE =>
+                        {
+                            match m.lit {
+                                syn::Lit::Bool(ref v) => {
+                                    let validate_decode = v.clone();
+                                    codec_params.validate_decode.replace(validate_decode);
+                                }
+                                _ => errors.push(syn::Error::new_spanned(
+                                    nested,
+                                    "`validate_decode` value should be a Boolean Literal",
+                                )),
+                            }
+                        }
                         _ => errors.push(syn::Error::new_spanned(
                             &nested,
                             "Unsupported attribute value. Attribute values should be of the form `a = b`"
@@ -201,12 +301,31 @@ pub(crate) struct FieldVarCodecParams {
     // Is the value from outside the "Extension" (ie. not from the Extension Root.)
     pub(crate) extended: Option<syn::LitBool>,
 
-    // Optional Field Index
+    // Optional Field Index. For a component of an extension addition group, this is the index in
+    // the bitmap of the `OPTIONAL` components of the group.
     pub(crate) optional_idx: Option<syn::LitInt>,
 
+    // Index of the extension addition (or the extension addition group) for a component that is
+    // not in the extension root.
+    pub(crate) extension_idx: Option<syn::LitInt>,
+
     // If this is a Key Field
     pub(crate) key_field: Option<syn::LitBool>,
 
+    // If this variant holds the index and the encoding of an alternative (of an extensible
+    // `CHOICE`) that is not known to us.
+    pub(crate) unknown: Option<syn::LitBool>,
+
+    // Identifier of the Component or Alternative in the ASN.1 definition.
+    pub(crate) name: Option<syn::LitStr>,
+
+    // Path of the function returning the `DEFAULT` value of the component (of a `SEQUENCE`).
+    pub(crate) default: Option<syn::LitStr>,
+
+    // Tags of the Component or Alternative, of the form "[0] EXPLICIT [APPLICATION 1] IMPLICIT".
+    // Empty for an untagged one. Used by the tag based Codecs.
+    pub(crate) tag: Option<syn::LitStr>,
+
     // The actual 'attribute' from the Syntax tree from which this struct is generated. This will
     // be used mainly for error reporting inside the functions where this struct is passed.
     pub(crate) attr: Option<syn::Attribute>,
@@ -271,6 +390,19 @@ pub(crate) fn parse_fld_meta_as_codec_params(
                                 )),
                             }
                         }
+                        // parses #[asn(extension_idx = 0)]
+                        syn::NestedMeta::Meta(syn::Meta::NameValue(ref m)) if m.path == EXTENSION_IDX => {
+                            match m.lit {
+                                syn::Lit::Int(ref ext_idx) => {
+                                    let ext_idx = ext_idx.clone();
+                                    codec_params.extension_idx.replace(ext_idx);
+                                }
+                                _ => errors.push(syn::Error::new_spanned(
+                                    nested,
+                                    "`extension_idx` value should be an Integer Literal",
+                                )),
+                            }
+                        }
                         // parses #[asn(key_field = true)]
                         syn::NestedMeta::Meta(syn::Meta::NameValue(ref m)) if m.path == KEY_FIELD => {
                             match m.lit {
@@ -284,6 +416,58 @@ pub(crate) fn parse_fld_meta_as_codec_params(
                                 )),
                             }
                         }
+                        // parses #[asn(unknown = true)]
+                        syn::NestedMeta::Meta(syn::Meta::NameValue(ref m)) if m.path == UNKNOWN => {
+                            match m.lit {
+                                syn::Lit::Bool(ref unknown) => {
+                                    let unknown = unknown.clone();
+                                    codec_params.unknown.replace(unknown);
+                                }
+                                _ => errors.push(syn::Error::new_spanned(
+                                    nested,
+                                    "`unknown` value should be a Bool Literal",
+                                )),
+                            }
+                        }
+                        // parses #[asn(name = "protocolIEs")]
+                        syn::NestedMeta::Meta(syn::Meta::NameValue(ref m)) if m.path == NAME => {
+                            match m.lit {
+                                syn::Lit::Str(ref name) => {
+                                    let name = name.clone();
+                                    codec_params.name.replace(name);
+                                }
+                                _ => errors.push(syn::Error::new_spanned(
+                                    nested,
+                                    "`name` value should be a String Literal",
+                                )),
+                            }
+                        }
+                        // parses #[asn(default = "Record::default_children")]
+                        syn::NestedMeta::Meta(syn::Meta::NameValue(ref m)) if m.path == DEFAULT => {
+                            match m.lit {
+                                syn::Lit::Str(ref default) if default.parse::<syn::Path>().is_ok() => {
+                                    let default = default.clone();
+                                    codec_params.default.replace(default);
+                                }
+                                _ => errors.push(syn::Error::new_spanned(
+                                    nested,
+                                    "`default` value should be a String Literal with the path of a function",
+                                )),
+                            }
+                        }
+                        // parses #[asn(tag = "[0] EXPLICIT")]
+                        syn::NestedMeta::Meta(syn::Meta::NameValue(ref m)) if m.path == TAG => {
+                            match m.lit {
+                                syn::Lit::Str(ref tag) => {
+                                    let tag = tag.clone();
+                                    codec_params.tag.replace(tag);
+                                }
+                                _ => errors.push(syn::Error::new_spanned(
+                                    nested,
+                                    "`tag` value should be a String Literal",
+                                )),
+                            }
+                        }
                         _ => errors.push(syn::Error::new_spanned(
                             &nested,
                             "Unsupported attribute value. Attribute values should be of the form `a = b`"
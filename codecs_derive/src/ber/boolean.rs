@@ -0,0 +1,41 @@
+//! `BER` Code generation for ASN.1 BOOLEAN Type
+
+use quote::quote;
+
+use crate::attrs::TyCodecParams;
+
+pub(super) fn generate_ber_codec_for_asn_boolean(
+    ast: &syn::DeriveInput,
+    _params: &TyCodecParams,
+    der: bool,
+) -> proc_macro::TokenStream {
+    let name = &ast.ident;
+    let super::CodecFns {
+        codec_path,
+        codec_decode_tagged_fn,
+        codec_encode_tagged_fn,
+        ..
+    } = super::codec_fns(der);
+
+    let tokens = quote! {
+
+        impl #codec_path for #name {
+            type Output = Self;
+
+            fn #codec_decode_tagged_fn(data: &mut asn1_codecs::BerCodecData, tag: Option<asn1_codecs::ber::Tag>) -> Result<Self::Output, asn1_codecs::BerCodecError> {
+                log::trace!(concat!("decode: ", stringify!(#name)));
+
+                let value = asn1_codecs::ber::decode::decode_bool(data, tag)?;
+                Ok(Self(value))
+            }
+
+            fn #codec_encode_tagged_fn(&self, data: &mut asn1_codecs::BerCodecData, tag: Option<asn1_codecs::ber::Tag>) -> Result<(), asn1_codecs::BerCodecError> {
+                log::trace!(concat!("encode: ", stringify!(#name)));
+
+                asn1_codecs::ber::encode::encode_bool(data, tag, self.0)
+            }
+        }
+    };
+
+    tokens.into()
+}
@@ -0,0 +1,82 @@
+//! `BER` Code generation for ASN.1 Character String Types
+
+use quote::quote;
+
+use crate::{attrs::TyCodecParams, utils};
+
+pub(super) fn generate_ber_codec_for_asn_charstring(
+    ast: &syn::DeriveInput,
+    params: &TyCodecParams,
+    der: bool,
+) -> proc_macro::TokenStream {
+    let name = &ast.ident;
+    let super::CodecFns {
+        codec_path,
+        codec_decode_tagged_fn,
+        codec_encode_tagged_fn,
+        ..
+    } = super::codec_fns(der);
+
+    let (encode_fn_name, decode_fn_name) = match params.ty.as_ref().unwrap().value().as_str() {
+        "UTF8String" => (quote!(encode_utf8_string), quote!(decode_utf8_string)),
+        "PrintableString" => (
+            quote!(encode_printable_string),
+            quote!(decode_printable_string),
+        ),
+        "VisibleString" => (quote!(encode_visible_string), quote!(decode_visible_string)),
+        "IA5String" => (quote!(encode_ia5_string), quote!(decode_ia5_string)),
+        "NumericString" => (quote!(encode_numeric_string), quote!(decode_numeric_string)),
+        "BMPString" => (quote!(encode_bmp_string), quote!(decode_bmp_string)),
+        "UniversalString" => (
+            quote!(encode_universal_string),
+            quote!(decode_universal_string),
+        ),
+        "TeletexString" | "T61String" => {
+            (quote!(encode_teletex_string), quote!(decode_teletex_string))
+        }
+        "GeneralString" => (quote!(encode_general_string), quote!(decode_general_string)),
+        "UTCTime" => (quote!(encode_utc_time), quote!(decode_utc_time)),
+        "GeneralizedTime" => (
+            quote!(encode_generalized_time),
+            quote!(decode_generalized_time),
+        ),
+        "DATE" => (quote!(encode_date), quote!(decode_date)),
+        "TIME-OF-DAY" => (quote!(encode_time_of_day), quote!(decode_time_of_day)),
+        "DATE-TIME" => (quote!(encode_date_time), quote!(decode_date_time)),
+        "DURATION" => (quote!(encode_duration), quote!(decode_duration)),
+        _ => {
+            return syn::Error::new_spanned(params.ty.as_ref(), "Unsupported Character String Type")
+                .to_compile_error()
+                .into()
+        }
+    };
+
+    let ty = utils::get_newtype_inner_ty(ast);
+    if ty.is_none() {
+        return syn::Error::new_spanned(ast, format!("{} Should be a Unit Struct.", name))
+            .to_compile_error()
+            .into();
+    }
+
+    let tokens = quote! {
+
+        impl #codec_path for #name {
+            type Output = Self;
+
+            fn #codec_decode_tagged_fn(data: &mut asn1_codecs::BerCodecData, tag: Option<asn1_codecs::ber::Tag>) -> Result<Self::Output, asn1_codecs::BerCodecError> {
+                log::trace!(concat!("decode: ", stringify!(#name)));
+
+                let decoded = asn1_codecs::ber::decode::#decode_fn_name(data, tag)?;
+                Ok(Self(decoded))
+            }
+
+            fn #codec_encode_tagged_fn(&self, data: &mut asn1_codecs::BerCodecData, tag: Option<asn1_codecs::ber::Tag>) -> Result<(), asn1_codecs::BerCodecError> {
+                log::trace!(concat!("encode: ", stringify!(#name)));
+
+                asn1_codecs::ber::encode::#encode_fn_name(data, tag, &self.0)
+            }
+        }
+    };
+
+    tokens.into()
+}
@@ -0,0 +1,204 @@
+//! `BER` Code generation for ASN.1 Choice Type
+
+use proc_macro::TokenStream;
+use quote::quote;
+
+use crate::attrs::{parse_fld_meta_as_codec_params, TyCodecParams};
+use crate::utils::is_unknown_variant;
+
+pub(super) fn generate_ber_codec_for_asn_choice(
+    ast: &syn::DeriveInput,
+    params: &TyCodecParams,
+    der: bool,
+) -> proc_macro::TokenStream {
+    let name = &ast.ident;
+    let super::CodecFns {
+        codec_path,
+        codec_decode_tagged_fn,
+        codec_encode_tagged_fn,
+        ..
+    } = super::codec_fns(der);
+
+    let lb = params.lb.as_ref().unwrap().value().parse::<i128>().unwrap();
+    let ub = params.ub.as_ref().unwrap().value().parse::<i128>().unwrap();
+
+    let variant_tokens = generate_choice_variant_tokens_using_attrs(ast, ub - lb + 1, der);
+    if variant_tokens.is_err() {
+        return variant_tokens.err().unwrap().to_compile_error().into();
+    }
+    let (variant_decode_tokens, untagged_decode_tokens, variant_encode_tokens) =
+        variant_tokens.unwrap();
+    // An untagged alternative (an untagged `CHOICE`) has no tag to match against, so it's decoded
+    // if it can be.
+    let untagged_decode_tokens = if untagged_decode_tokens.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            let offset = data.offset();
+            #(#untagged_decode_tokens)*
+        }
+    };
+
+    let tokens = quote! {
+
+        impl #codec_path for #name {
+            type Output = Self;
+
+            fn #codec_decode_tagged_fn(data: &mut asn1_codecs::BerCodecData, tag: Option<asn1_codecs::ber::Tag>) -> Result<Self::Output, asn1_codecs::BerCodecError> {
+                log::trace!(concat!("decode: ", stringify!(#name)));
+
+                // A tagged `CHOICE` is always explicitly tagged.
+                if let Some(tag) = tag {
+                    let mut contents = asn1_codecs::ber::decode::decode_constructed(data, tag)?;
+                    let decoded = Self::#codec_decode_tagged_fn(&mut contents, None)?;
+                    if !contents.is_empty() {
+                        return Err(asn1_codecs::BerCodecError::new(format!("{} bytes remaining after decoding a CHOICE.", contents.remaining())));
+                    }
+                    return Ok(decoded);
+                }
+
+                let tag = match asn1_codecs::ber::decode::peek_tag(data)? {
+                    Some((tag, _)) => tag,
+                    None => return Err(asn1_codecs::BerCodecError::new("End of Buffer reached while decoding a CHOICE.")),
+                };
+                match (tag.class, tag.number) {
+                    #(#variant_decode_tokens)*
+                    _ => {
+                        #untagged_decode_tokens
+                        Err(asn1_codecs::BerCodecError::new(format!("Tag {} is not a valid Tag for the CHOICE", tag)))
+                    }
+                }
+            }
+
+            fn #codec_encode_tagged_fn(&self, data: &mut asn1_codecs::BerCodecData, tag: Option<asn1_codecs::ber::Tag>) -> Result<(), asn1_codecs::BerCodecError> {
+                log::trace!(concat!("encode: ", stringify!(#name)));
+
+                if let Some(tag) = tag {
+                    let mut contents = asn1_codecs::BerCodecData::new();
+                    self.#codec_encode_tagged_fn(&mut contents, None)?;
+                    return asn1_codecs::ber::encode::encode_constructed(data, tag, &contents);
+                }
+
+                match self {
+                    #(#variant_encode_tokens)*
+                }
+            }
+        }
+    };
+
+    TokenStream::from(tokens)
+}
+
+type VariantTokens = (
+    Vec<proc_macro2::TokenStream>,
+    Vec<proc_macro2::TokenStream>,
+    Vec<proc_macro2::TokenStream>,
+);
+
+// The alternatives are tagged as given by their `tag` attributes. Without those, the alternatives
+// are 'automatically' tagged, the alternatives in the 'root' are tagged in the order of the key and
+// the 'additions' are tagged after all the 'root' alternatives.
+fn generate_choice_variant_tokens_using_attrs(
+    ast: &syn::DeriveInput,
+    root_count: i128,
+    der: bool,
+) -> Result<VariantTokens, syn::Error> {
+    let super::CodecFns {
+        codec_decode_tagged_fn,
+        codec_encode_tagged_fn,
+        ..
+    } = super::codec_fns(der);
+
+    let mut decode_tokens = vec![];
+    let mut untagged_decode_tokens = vec![];
+    let mut encode_tokens = vec![];
+
+    let mut errors = vec![];
+    if let syn::Data::Enum(ref data) = ast.data {
+        for variant in &data.variants {
+            let codec_params = parse_fld_meta_as_codec_params(&variant.attrs);
+            match codec_params {
+                Err(e) => errors.push(e),
+                Ok(cp) => {
+                    // The encoding of an alternative not known to us is kept only by the PER Codecs.
+                    match is_unknown_variant(variant, &cp) {
+                        Err(e) => {
+                            errors.push(e);
+                            continue;
+                        }
+                        Ok(true) => {
+                            let variant_ident = &variant.ident;
+                            encode_tokens.push(quote! {
+                                Self::#variant_ident(..) => Err(asn1_codecs::BerCodecError::new("Alternative not known to us cannot be encoded.")),
+                            });
+                            continue;
+                        }
+                        Ok(false) => {}
+                    }
+
+                    let key = cp.key.as_ref();
+                    if key.is_none() {
+                        errors.push(syn::Error::new_spanned(
+                        variant,
+                        "Missing Key for the variant. Please provide `#[asn(key = <int>)]` attribute.",
+                    ));
+                        continue;
+                    }
+                    let key = key.unwrap().base10_parse::<i128>()?;
+                    let extended = cp.extended.as_ref().map(|e| e.value()).unwrap_or_default();
+                    let tag_number = if extended { key + root_count } else { key } as u32;
+                    let taggings = match super::field_taggings(&cp, tag_number) {
+                        Ok(taggings) => taggings,
+                        Err(e) => {
+                            errors.push(e);
+                            continue;
+                        }
+                    };
+                    let pattern = taggings.first().map(super::Tagging::pattern_tokens);
+                    let taggings = super::taggings_tokens(&taggings);
+
+                    let variant_ident = &variant.ident;
+                    if let syn::Fields::Unnamed(ref fields) = variant.fields {
+                        if fields.unnamed.len() == 1 {
+                            let ty = &fields.unnamed.first().as_ref().unwrap().ty;
+                            let decode_tokens_for_variant = quote! {
+                                asn1_codecs::ber::decode::decode_with_tagging(data, &#taggings, #ty::#codec_decode_tagged_fn)
+                            };
+                            match pattern {
+                                Some(pattern) => decode_tokens.push(quote! {
+                                    #pattern => Ok(Self::#variant_ident(#decode_tokens_for_variant?)),
+                                }),
+                                None => untagged_decode_tokens.push(quote! {
+                                    if let Ok(value) = #decode_tokens_for_variant {
+                                        return Ok(Self::#variant_ident(value));
+                                    }
+                                    data.seek(offset);
+                                }),
+                            }
+                            let variant_encode_token = quote! {
+                                Self::#variant_ident(ref v) => {
+                                    asn1_codecs::ber::encode::encode_with_tagging(data, &#taggings, |data, tag| v.#codec_encode_tagged_fn(data, tag))
+                                }
+                            };
+                            encode_tokens.push(variant_encode_token);
+                        } else {
+                            errors.push(syn::Error::new_spanned(
+                                variant,
+                                "Unsupported variant type".to_string(),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    if let Some((first, others)) = errors.split_first_mut() {
+        for e in others {
+            first.combine(e.clone())
+        }
+        Err(first.clone())
+    } else {
+        Ok((decode_tokens, untagged_decode_tokens, encode_tokens))
+    }
+}
@@ -0,0 +1,48 @@
+//! `BER` Code generation for ASN.1 ENUMERATED Type
+
+use quote::quote;
+
+use crate::{attrs::TyCodecParams, utils};
+
+pub(super) fn generate_ber_codec_for_asn_enumerated(
+    ast: &syn::DeriveInput,
+    _params: &TyCodecParams,
+    der: bool,
+) -> proc_macro::TokenStream {
+    let name = &ast.ident;
+    let super::CodecFns {
+        codec_path,
+        codec_decode_tagged_fn,
+        codec_encode_tagged_fn,
+        ..
+    } = super::codec_fns(der);
+
+    let ty = utils::get_newtype_inner_ty(ast);
+    if ty.is_none() {
+        return syn::Error::new_spanned(ast, format!("{} Should be a Unit Struct.", name))
+            .to_compile_error()
+            .into();
+    }
+
+    let tokens = quote! {
+
+        impl #codec_path for #name {
+            type Output = Self;
+
+            fn #codec_decode_tagged_fn(data: &mut asn1_codecs::BerCodecData, tag: Option<asn1_codecs::ber::Tag>) -> Result<Self::Output, asn1_codecs::BerCodecError> {
+                log::trace!(concat!("decode: ", stringify!(#name)));
+
+                let decoded = asn1_codecs::ber::decode::decode_enumerated(data, tag)?;
+                Ok(Self(decoded as #ty))
+            }
+
+            fn #codec_encode_tagged_fn(&self, data: &mut asn1_codecs::BerCodecData, tag: Option<asn1_codecs::ber::Tag>) -> Result<(), asn1_codecs::BerCodecError> {
+                log::trace!(concat!("encode: ", stringify!(#name)));
+
+                asn1_codecs::ber::encode::encode_enumerated(data, tag, self.0 as i128)
+            }
+        }
+    };
+
+    tokens.into()
+}
@@ -0,0 +1,268 @@
+//! Implementation of `BerCodec` and `DerCodec` `impl` generation for different ASN Types.
+
+use proc_macro2::{Delimiter, Group, TokenTree};
+use quote::{format_ident, quote};
+
+use super::attrs::{FieldVarCodecParams, TyCodecParams};
+
+mod bitstring;
+mod boolean;
+mod charstring;
+mod choice;
+mod enumerated;
+mod integer;
+mod null;
+mod octetstring;
+mod oid;
+mod open;
+mod seq;
+mod seqof;
+
+// Paths of the trait and its functions used by the generated code. The DER Codec uses the same
+// encoding and decoding APIs, the strict checks are performed using the `der` flag in the
+// `BerCodecData`.
+struct CodecFns {
+    codec_path: proc_macro2::TokenStream,
+    codec_decode_tagged_fn: proc_macro2::TokenStream,
+    codec_encode_tagged_fn: proc_macro2::TokenStream,
+    codec_decode_fn: proc_macro2::TokenStream,
+    codec_encode_fn: proc_macro2::TokenStream,
+}
+
+fn codec_fns(der: bool) -> CodecFns {
+    if der {
+        CodecFns {
+            codec_path: quote!(asn1_codecs::ber::der::DerCodec),
+            codec_decode_tagged_fn: quote!(der_decode_tagged),
+            codec_encode_tagged_fn: quote!(der_encode_tagged),
+            codec_decode_fn: quote!(der_decode),
+            codec_encode_fn: quote!(der_encode),
+        }
+    } else {
+        CodecFns {
+            codec_path: quote!(asn1_codecs::ber::BerCodec),
+            codec_decode_tagged_fn: quote!(ber_decode_tagged),
+            codec_encode_tagged_fn: quote!(ber_encode_tagged),
+            codec_decode_fn: quote!(ber_decode),
+            codec_encode_fn: quote!(ber_encode),
+        }
+    }
+}
+
+pub(crate) fn generate_codec(
+    ast: &syn::DeriveInput,
+    params: &TyCodecParams,
+    der: bool,
+) -> proc_macro::TokenStream {
+    let taggings = match params.tag.as_ref().map(parse_tagging).transpose() {
+        Ok(taggings) => taggings.unwrap_or_default(),
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    let tokens = generate_codec_for_type(ast, params, der);
+    if taggings.is_empty() {
+        tokens
+    } else {
+        wrap_with_tagging(tokens.into(), &taggings, der).into()
+    }
+}
+
+fn generate_codec_for_type(
+    ast: &syn::DeriveInput,
+    params: &TyCodecParams,
+    der: bool,
+) -> proc_macro::TokenStream {
+    let ty = params.ty.as_ref().unwrap();
+    match ty.value().as_str() {
+        "BOOLEAN" => boolean::generate_ber_codec_for_asn_boolean(ast, params, der),
+        "CHOICE" => choice::generate_ber_codec_for_asn_choice(ast, params, der),
+        "INTEGER" => integer::generate_ber_codec_for_asn_integer(ast, params, der),
+        "ENUMERATED" => enumerated::generate_ber_codec_for_asn_enumerated(ast, params, der),
+        "BITSTRING" => bitstring::generate_ber_codec_for_asn_bitstring(ast, params, der),
+        "OCTET-STRING" => octetstring::generate_ber_codec_for_asn_octetstring(ast, params, der),
+        "UTF8String" | "PrintableString" | "VisibleString" | "IA5String" | "NumericString"
+        | "BMPString" | "UniversalString" | "TeletexString" | "T61String" | "GeneralString"
+        | "UTCTime" | "GeneralizedTime" | "DATE" | "TIME-OF-DAY" | "DATE-TIME" | "DURATION" => {
+            charstring::generate_ber_codec_for_asn_charstring(ast, params, der)
+        }
+        "NULL" => null::generate_ber_codec_for_asn_null(ast, params, der),
+        "SEQUENCE" => seq::generate_ber_codec_for_asn_sequence(ast, params, der, false),
+        "SET" => seq::generate_ber_codec_for_asn_sequence(ast, params, der, true),
+        "OPEN" => open::generate_ber_codec_for_asn_open_type(ast, params, der),
+        "SEQUENCE-OF" => seqof::generate_ber_codec_for_asn_sequence_of(ast, params, der, false),
+        "SET-OF" => seqof::generate_ber_codec_for_asn_sequence_of(ast, params, der, true),
+        "OBJECT-IDENTIFIER" | "RELATIVE-OID" => {
+            oid::generate_ber_codec_for_asn_object_identifier(ast, params, der)
+        }
+        _ => syn::Error::new_spanned(ty.clone(), "This ASN.1 Type is not supported.")
+            .to_compile_error()
+            .into(),
+    }
+}
+
+// A Tagging parsed from the `tag` attribute of a type, a component or an alternative.
+struct Tagging {
+    class: syn::Ident,
+    number: u32,
+    explicit: bool,
+}
+
+impl Tagging {
+    fn tag_tokens(&self) -> proc_macro2::TokenStream {
+        let class = &self.class;
+        let number = self.number;
+        quote! { asn1_codecs::ber::Tag::new(asn1_codecs::ber::TagClass::#class, #number) }
+    }
+
+    // Pattern matching the `(class, number)` of a decoded Tag.
+    fn pattern_tokens(&self) -> proc_macro2::TokenStream {
+        let class = &self.class;
+        let number = self.number;
+        quote! { (asn1_codecs::ber::TagClass::#class, #number) }
+    }
+
+    fn tokens(&self) -> proc_macro2::TokenStream {
+        let tag = self.tag_tokens();
+        if self.explicit {
+            quote! { asn1_codecs::ber::Tagging::explicit(#tag) }
+        } else {
+            quote! { asn1_codecs::ber::Tagging::implicit(#tag) }
+        }
+    }
+}
+
+fn taggings_tokens(taggings: &[Tagging]) -> proc_macro2::TokenStream {
+    let taggings = taggings.iter().map(Tagging::tokens);
+    quote! { [#(#taggings),*] }
+}
+
+// Parses the `tag` attribute of the form "[0] EXPLICIT [APPLICATION 1] IMPLICIT". Only the last of
+// the Taggings can be `IMPLICIT`.
+fn parse_tagging(tag: &syn::LitStr) -> Result<Vec<Tagging>, syn::Error> {
+    let error = || {
+        syn::Error::new_spanned(
+            tag,
+            "`tag` value should be of the form \"[0] EXPLICIT [APPLICATION 1] IMPLICIT\"",
+        )
+    };
+
+    let value = tag.value();
+    let mut rest = value.trim();
+    let mut taggings = vec![];
+    while !rest.is_empty() {
+        let inner = rest.strip_prefix('[').ok_or_else(error)?;
+        let end = inner.find(']').ok_or_else(error)?;
+        let (class, number) = match inner[..end].split_whitespace().collect::<Vec<&str>>()[..] {
+            [number] => ("ContextSpecific", number),
+            ["UNIVERSAL", number] => ("Universal", number),
+            ["APPLICATION", number] => ("Application", number),
+            ["PRIVATE", number] => ("Private", number),
+            _ => return Err(error()),
+        };
+        let number = number.parse::<u32>().map_err(|_| error())?;
+
+        let mut words = inner[end + 1..].trim_start().splitn(2, char::is_whitespace);
+        let explicit = match words.next() {
+            Some("EXPLICIT") => true,
+            Some("IMPLICIT") => false,
+            _ => return Err(error()),
+        };
+        rest = words.next().unwrap_or_default().trim_start();
+
+        taggings.push(Tagging {
+            class: format_ident!("{}", class),
+            number,
+            explicit,
+        });
+    }
+
+    if taggings.iter().rev().skip(1).any(|t| !t.explicit) {
+        return Err(error());
+    }
+    Ok(taggings)
+}
+
+// Taggings of a component or an alternative. Without a `tag` attribute, the component is
+// 'automatically' tagged with a context specific tag with the given number.
+fn field_taggings(cp: &FieldVarCodecParams, number: u32) -> Result<Vec<Tagging>, syn::Error> {
+    match cp.tag {
+        Some(ref tag) => parse_tagging(tag),
+        None => Ok(vec![Tagging {
+            class: format_ident!("ContextSpecific"),
+            number,
+            explicit: false,
+        }]),
+    }
+}
+
+// Applies the Taggings of a Tagged Type on the generated `impl`.
+//
+// The bodies of the `decode` and `encode` functions are wrapped in closures, that are called with
+// the 'implicit' tag (if any) after handling the 'explicit' tags. The `tag` passed to these
+// functions replaces the outermost tag.
+fn wrap_with_tagging(
+    tokens: proc_macro2::TokenStream,
+    taggings: &[Tagging],
+    der: bool,
+) -> proc_macro2::TokenStream {
+    let CodecFns {
+        codec_decode_tagged_fn,
+        codec_encode_tagged_fn,
+        ..
+    } = codec_fns(der);
+    let decode_fn = codec_decode_tagged_fn.to_string();
+    let encode_fn = codec_encode_tagged_fn.to_string();
+
+    let taggings = taggings_tokens(taggings);
+    let taggings = quote! {
+        let mut taggings = #taggings;
+        if let Some(tag) = tag {
+            taggings[0].tag = tag;
+        }
+    };
+
+    let wrap_decode = |body: &Group| {
+        quote! {
+            {
+                #taggings
+                asn1_codecs::ber::decode::decode_with_tagging(data, &taggings, |data: &mut asn1_codecs::BerCodecData, tag: Option<asn1_codecs::ber::Tag>| -> Result<Self::Output, asn1_codecs::BerCodecError> #body)
+            }
+        }
+    };
+    let wrap_encode = |body: &Group| {
+        quote! {
+            {
+                #taggings
+                asn1_codecs::ber::encode::encode_with_tagging(data, &taggings, |data: &mut asn1_codecs::BerCodecData, tag: Option<asn1_codecs::ber::Tag>| -> Result<(), asn1_codecs::BerCodecError> #body)
+            }
+        }
+    };
+
+    // The items of the `impl` are inside the only 'brace' group at the top level. The body of a
+    // function is the first 'brace' group after it's name.
+    tokens
+        .into_iter()
+        .map(|tt| match tt {
+            TokenTree::Group(ref items) if items.delimiter() == Delimiter::Brace => {
+                let mut wrapped = proc_macro2::TokenStream::new();
+                let mut wrap: Option<&dyn Fn(&Group) -> proc_macro2::TokenStream> = None;
+                for item in items.stream() {
+                    match item {
+                        TokenTree::Ident(ref i) if *i == decode_fn => wrap = Some(&wrap_decode),
+                        TokenTree::Ident(ref i) if *i == encode_fn => wrap = Some(&wrap_encode),
+                        TokenTree::Group(ref body) if body.delimiter() == Delimiter::Brace => {
+                            if let Some(wrap) = wrap.take() {
+                                wrapped.extend(wrap(body));
+                                continue;
+                            }
+                        }
+                        _ => {}
+                    }
+                    wrapped.extend([item]);
+                }
+                TokenTree::Group(Group::new(Delimiter::Brace, wrapped))
+            }
+            other => other,
+        })
+        .collect()
+}
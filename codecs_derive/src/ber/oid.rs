@@ -0,0 +1,40 @@
+//! `BER` Code generation for ASN.1 OBJECT IDENTIFIER Type
+
+use quote::quote;
+
+use crate::attrs::TyCodecParams;
+
+pub(super) fn generate_ber_codec_for_asn_object_identifier(
+    ast: &syn::DeriveInput,
+    _params: &TyCodecParams,
+    der: bool,
+) -> proc_macro::TokenStream {
+    let name = &ast.ident;
+    let super::CodecFns {
+        codec_path,
+        codec_decode_tagged_fn,
+        codec_encode_tagged_fn,
+        ..
+    } = super::codec_fns(der);
+
+    let tokens = quote! {
+
+        impl #codec_path for #name {
+            type Output = Self;
+
+            fn #codec_decode_tagged_fn(_data: &mut asn1_codecs::BerCodecData, _tag: Option<asn1_codecs::ber::Tag>) -> Result<Self::Output, asn1_codecs::BerCodecError> {
+                log::trace!(concat!("decode: ", stringify!(#name)));
+
+                Err(asn1_codecs::BerCodecError::new("Object Identifier Decode Not Supported!"))
+            }
+
+            fn #codec_encode_tagged_fn(&self, _data: &mut asn1_codecs::BerCodecData, _tag: Option<asn1_codecs::ber::Tag>) -> Result<(), asn1_codecs::BerCodecError> {
+                log::trace!(concat!("encode: ", stringify!(#name)));
+
+                Err(asn1_codecs::BerCodecError::new("Object Identifier Encode Not Supported!"))
+            }
+        }
+    };
+
+    tokens.into()
+}
@@ -0,0 +1,415 @@
+//! `BER` Code generation for ASN.1 `SEQUENCE` and `SET` types
+
+use quote::{format_ident, quote};
+
+use crate::attrs::{parse_fld_meta_as_codec_params, TyCodecParams};
+use crate::utils::{get_default_fn, get_field_type, is_unknown_extensions_field};
+
+struct FieldTokens {
+    decode_tokens: proc_macro2::TokenStream,
+    encode_tokens: Vec<proc_macro2::TokenStream>,
+}
+
+pub(super) fn generate_ber_codec_for_asn_sequence(
+    ast: &syn::DeriveInput,
+    params: &TyCodecParams,
+    der: bool,
+    set: bool,
+) -> proc_macro::TokenStream {
+    let name = &ast.ident;
+    let super::CodecFns {
+        codec_path,
+        codec_decode_tagged_fn,
+        codec_encode_tagged_fn,
+        ..
+    } = super::codec_fns(der);
+
+    let ext = params.ext.as_ref().map(|e| e.value()).unwrap_or_default();
+
+    let field_tokens = generate_seq_field_codec_tokens_using_attrs(ast, der, set, ext);
+    if field_tokens.is_err() {
+        return field_tokens.err().unwrap().to_compile_error().into();
+    }
+    let field_tokens = field_tokens.unwrap();
+    let fld_decode_tokens = field_tokens.decode_tokens;
+    let fld_encode_tokens = field_tokens.encode_tokens;
+
+    let (default_tag, type_name) = if set {
+        (quote!(asn1_codecs::ber::Tag::SET), "SET")
+    } else {
+        (quote!(asn1_codecs::ber::Tag::SEQUENCE), "SEQUENCE")
+    };
+
+    // Unknown components are possible only in an extensible `SEQUENCE` and are ignored. For a `SET`
+    // these are taken care of when decoding the components.
+    let trailing_tokens = if set {
+        quote! {}
+    } else if ext {
+        quote! {
+            while !data.is_empty() {
+                asn1_codecs::ber::decode::skip_tlv(data)?;
+            }
+        }
+    } else {
+        quote! {
+            if !data.is_empty() {
+                return Err(asn1_codecs::BerCodecError::new(format!("{} bytes remaining after decoding a {}.", data.remaining(), #type_name)));
+            }
+        }
+    };
+
+    let encode_tokens = if set && der {
+        quote! {
+            let mut components = vec![];
+
+            #(#fld_encode_tokens)*
+
+            asn1_codecs::ber::encode::encode_set_canonical(data, tag.unwrap_or(#default_tag), components)
+        }
+    } else {
+        quote! {
+            let mut contents = asn1_codecs::BerCodecData::new();
+
+            #(#fld_encode_tokens)*
+
+            asn1_codecs::ber::encode::encode_constructed(data, tag.unwrap_or(#default_tag), &contents)
+        }
+    };
+
+    let tokens = quote! {
+        impl #codec_path for #name {
+            type Output = Self;
+
+            fn #codec_decode_tagged_fn(data: &mut asn1_codecs::BerCodecData, tag: Option<asn1_codecs::ber::Tag>) -> Result<Self::Output, asn1_codecs::BerCodecError> {
+                log::trace!(concat!("decode: ", stringify!(#name)));
+
+                let mut contents = asn1_codecs::ber::decode::decode_constructed(data, tag.unwrap_or(#default_tag))?;
+                let data = &mut contents;
+
+                #fld_decode_tokens
+
+                #trailing_tokens
+
+                Ok(decoded)
+            }
+
+            fn #codec_encode_tagged_fn(&self, data: &mut asn1_codecs::BerCodecData, tag: Option<asn1_codecs::ber::Tag>) -> Result<(), asn1_codecs::BerCodecError> {
+                log::trace!(concat!("encode: ", stringify!(#name)));
+
+                #encode_tokens
+            }
+        }
+    };
+
+    tokens.into()
+}
+
+// The components are tagged as given by their `tag` attributes. Without those, the components are
+// 'automatically' tagged ie. a component is tagged with a context specific tag with the number
+// being the position of the component in the `SEQUENCE`.
+//
+// The components of a `SEQUENCE` are decoded in the order of their definition. The components of
+// a `SET` may appear in any order, so each encoding is matched against the tags of the
+// components, after verifying the canonical order of the tags for DER. An untagged component (an
+// untagged `CHOICE`) has no tag to match against, so it's decoded if it can be.
+//
+// An absent component with a `DEFAULT` value takes the default value. For DER, a component with a
+// value that is the same as it's default value is not encoded (and is rejected when decoding
+// strictly). The values are compared by their encodings.
+fn generate_seq_field_codec_tokens_using_attrs(
+    ast: &syn::DeriveInput,
+    der: bool,
+    set: bool,
+    ext: bool,
+) -> Result<FieldTokens, syn::Error> {
+    let super::CodecFns {
+        codec_decode_tagged_fn,
+        codec_encode_tagged_fn,
+        ..
+    } = super::codec_fns(der);
+
+    let mut seq_decode_tokens = vec![];
+    let mut set_init_tokens = vec![];
+    let mut set_match_tokens = vec![];
+    let mut set_untagged_tokens = vec![];
+    let mut set_value_tokens = vec![];
+    let mut encode_tokens = vec![];
+
+    let mut errors: Vec<syn::Error> = vec![];
+    if let syn::Data::Struct(ref data) = ast.data {
+        if let syn::Fields::Named(ref fields) = data.fields {
+            for (idx, field) in fields.named.iter().enumerate() {
+                let codec_params = parse_fld_meta_as_codec_params(&field.attrs);
+                match codec_params {
+                    Err(e) => errors.push(e),
+                    Ok(cp) => {
+                        // The extension additions not known to us are kept only by the PER Codecs.
+                        if is_unknown_extensions_field(&cp) {
+                            let id = field.ident.as_ref().unwrap();
+                            seq_decode_tokens.push(quote! { #id: Default::default(), });
+                            set_value_tokens.push(quote! { #id: Default::default(), });
+                            continue;
+                        }
+
+                        let field_type = get_field_type(field);
+                        if field_type.ty.is_none() {
+                            errors.push(syn::Error::new_spanned(
+                                field,
+                                "Field Type is not in supported Format!",
+                            ));
+                            continue;
+                        }
+                        let ty_ident = field_type.ty.unwrap();
+                        let optional = field_type.is_optional;
+                        let default_fn = match get_default_fn(field, &cp) {
+                            Ok(default_fn) => default_fn,
+                            Err(e) => {
+                                errors.push(e);
+                                continue;
+                            }
+                        };
+                        let taggings = match super::field_taggings(&cp, idx as u32) {
+                            Ok(taggings) => taggings,
+                            Err(e) => {
+                                errors.push(e);
+                                continue;
+                            }
+                        };
+                        let tag = taggings.first().map(super::Tagging::tag_tokens);
+                        let taggings = super::taggings_tokens(&taggings);
+                        let id = field.ident.as_ref().unwrap();
+
+                        let decode_tokens = quote! {
+                            asn1_codecs::ber::decode::decode_with_tagging(data, &#taggings, #ty_ident::#codec_decode_tagged_fn)
+                        };
+                        let is_key_field = cp
+                            .key_field
+                            .as_ref()
+                            .map(|kf| kf.value())
+                            .unwrap_or_default();
+                        let value_decode_tokens = if is_key_field {
+                            quote! {
+                                {
+                                let value = #decode_tokens?;
+                                let _ = data.set_key(value.0 as i128);
+                                value
+                                }
+                            }
+                        } else {
+                            quote! {
+                                #decode_tokens?
+                            }
+                        };
+
+                        let absent = match default_fn {
+                            Some(ref default_fn) => quote! { Some(#default_fn()) },
+                            None => quote! { None },
+                        };
+                        let is_default = |value: proc_macro2::TokenStream| match default_fn {
+                            Some(ref default_fn) if der => Some(quote! {
+                                {
+                                let mut encoded = asn1_codecs::BerCodecData::new();
+                                #value.#codec_encode_tagged_fn(&mut encoded, None)?;
+                                let mut default = asn1_codecs::BerCodecData::new();
+                                #default_fn().#codec_encode_tagged_fn(&mut default, None)?;
+                                encoded.same_encoding(&default)
+                                }
+                            }),
+                            _ => None,
+                        };
+                        let default_check = match is_default(quote! { value }) {
+                            Some(is_default) => {
+                                let error = format!(
+                                    "Component '{}' with it's DEFAULT value is encoded.",
+                                    id
+                                );
+                                quote! {
+                                    if data.is_der() && #is_default {
+                                        return Err(asn1_codecs::BerCodecError::new(#error));
+                                    }
+                                }
+                            }
+                            None => quote! {},
+                        };
+
+                        if set {
+                            let var = format_ident!("field_{}", idx);
+                            set_init_tokens.push(quote! { let mut #var = None; });
+                            let missing = match tag {
+                                Some(ref tag) => {
+                                    set_match_tokens.push(quote! {
+                                        t if t == #tag => {
+                                            if #var.is_some() {
+                                                return Err(asn1_codecs::BerCodecError::new(format!("Component with Tag {} repeated in a SET.", t)));
+                                            }
+                                            #var = Some(#value_decode_tokens);
+                                        }
+                                    });
+                                    quote! { format!("Component with Tag {} missing in a SET.", #tag) }
+                                }
+                                None => {
+                                    set_untagged_tokens.push(quote! {
+                                        if #var.is_none() {
+                                            if let Ok(value) = #decode_tokens {
+                                                #var = Some(value);
+                                                continue;
+                                            }
+                                            data.seek(offset);
+                                        }
+                                    });
+                                    let missing = format!("Component '{}' missing in a SET.", id);
+                                    quote! { #missing }
+                                }
+                            };
+                            let value_tokens = if optional {
+                                quote! {
+                                    match #var {
+                                        Some(value) => {
+                                            #default_check
+                                            Some(value)
+                                        }
+                                        None => #absent,
+                                    }
+                                }
+                            } else {
+                                quote! {
+                                    #var.ok_or_else(|| asn1_codecs::BerCodecError::new(#missing))?
+                                }
+                            };
+                            set_value_tokens.push(quote! { #id: #value_tokens, });
+                        } else {
+                            let fld_decode_tokens = match (optional, tag) {
+                                (true, Some(tag)) => quote! {
+                                    {
+                                    let present = match asn1_codecs::ber::decode::peek_tag(data)? {
+                                        Some((t, _)) => t == #tag,
+                                        None => false,
+                                    };
+                                    if present {
+                                        let value = #value_decode_tokens;
+                                        #default_check
+                                        Some(value)
+                                    } else {
+                                        #absent
+                                    }
+                                    }
+                                },
+                                (true, None) => quote! {
+                                    {
+                                    let offset = data.offset();
+                                    match #decode_tokens {
+                                        Ok(value) => {
+                                            #default_check
+                                            Some(value)
+                                        }
+                                        Err(_) => {
+                                            data.seek(offset);
+                                            #absent
+                                        }
+                                    }
+                                    }
+                                },
+                                (false, _) => quote! {
+                                    {
+                                    #value_decode_tokens
+                                    }
+                                },
+                            };
+                            seq_decode_tokens.push(quote! { #id: #fld_decode_tokens, });
+                        }
+
+                        let field_encode_token = if let Some(is_default) =
+                            is_default(quote! { #id })
+                        {
+                            quote! {
+                                if let Some(ref #id) = self.#id {
+                                    if !#is_default {
+                                        asn1_codecs::ber::encode::encode_with_tagging(&mut contents, &#taggings, |data, tag| #id.#codec_encode_tagged_fn(data, tag))?;
+                                    }
+                                }
+                            }
+                        } else if optional {
+                            quote! {
+                                if let Some(ref #id) = self.#id {
+                                    asn1_codecs::ber::encode::encode_with_tagging(&mut contents, &#taggings, |data, tag| #id.#codec_encode_tagged_fn(data, tag))?;
+                                }
+                            }
+                        } else {
+                            quote! {
+                                asn1_codecs::ber::encode::encode_with_tagging(&mut contents, &#taggings, |data, tag| self.#id.#codec_encode_tagged_fn(data, tag))?;
+                            }
+                        };
+                        // For DER, each of the components of a `SET` is encoded separately so
+                        // that these can be sorted.
+                        let field_encode_token = if set && der {
+                            quote! {
+                                {
+                                let mut contents = asn1_codecs::BerCodecData::new();
+                                #field_encode_token
+                                if !contents.is_empty() {
+                                    components.push(contents);
+                                }
+                                }
+                            }
+                        } else {
+                            field_encode_token
+                        };
+                        encode_tokens.push(field_encode_token);
+                    }
+                }
+            }
+        }
+    }
+
+    if let Some((first, others)) = errors.split_first_mut() {
+        for e in others {
+            first.combine(e.clone())
+        }
+        return Err(first.clone());
+    }
+
+    let decode_tokens = if set {
+        let unknown_tokens = if ext {
+            quote! {
+                asn1_codecs::ber::decode::skip_tlv(data)?;
+            }
+        } else {
+            quote! {
+                return Err(asn1_codecs::BerCodecError::new(format!("Unexpected Tag {} in a SET.", t)));
+            }
+        };
+        let untagged_tokens = if set_untagged_tokens.is_empty() {
+            quote! {}
+        } else {
+            quote! {
+                let offset = data.offset();
+                #(#set_untagged_tokens)*
+            }
+        };
+        quote! {
+            asn1_codecs::ber::decode::check_set_order(data)?;
+
+            #(#set_init_tokens)*
+            while let Some((t, _)) = asn1_codecs::ber::decode::peek_tag(data)? {
+                match t {
+                    #(#set_match_tokens)*
+                    _ => {
+                        #untagged_tokens
+                        #unknown_tokens
+                    }
+                }
+            }
+
+            let decoded = Self{#(#set_value_tokens)*};
+        }
+    } else {
+        quote! {
+            let decoded = Self{#(#seq_decode_tokens)*};
+        }
+    };
+
+    Ok(FieldTokens {
+        decode_tokens,
+        encode_tokens,
+    })
+}
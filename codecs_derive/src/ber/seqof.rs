@@ -0,0 +1,108 @@
+//! `BER` Code generation for ASN.1 SEQUENCE OF and SET OF Types
+
+use quote::quote;
+
+use crate::{attrs::TyCodecParams, utils};
+
+pub(super) fn generate_ber_codec_for_asn_sequence_of(
+    ast: &syn::DeriveInput,
+    _params: &TyCodecParams,
+    der: bool,
+    set: bool,
+) -> proc_macro::TokenStream {
+    let name = &ast.ident;
+    let super::CodecFns {
+        codec_path,
+        codec_decode_tagged_fn,
+        codec_encode_tagged_fn,
+        codec_decode_fn,
+        codec_encode_fn,
+    } = super::codec_fns(der);
+
+    let ty = if let syn::Data::Struct(ref d) = &ast.data {
+        match d.fields {
+            syn::Fields::Unnamed(ref f) => {
+                if f.unnamed.len() == 1 {
+                    let first = f.unnamed.first().unwrap();
+                    utils::get_inner_ty_for_vec(first)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    } else {
+        None
+    };
+
+    if ty.is_none() {
+        return syn::Error::new_spanned(ast, format!("{} Should be a Unit Struct.", name))
+            .to_compile_error()
+            .into();
+    }
+
+    let default_tag = if set {
+        quote!(asn1_codecs::ber::Tag::SET)
+    } else {
+        quote!(asn1_codecs::ber::Tag::SEQUENCE)
+    };
+
+    // For DER, the elements of a `SET OF` are sorted by their encodings.
+    let encode_tokens = if set && der {
+        quote! {
+            let mut elements = vec![];
+            for elem in &self.0 {
+                let mut element = asn1_codecs::BerCodecData::new();
+                elem.#codec_encode_fn(&mut element)?;
+                elements.push(element);
+            }
+
+            asn1_codecs::ber::encode::encode_set_of_sorted(data, tag.unwrap_or(#default_tag), elements)
+        }
+    } else {
+        quote! {
+            let mut contents = asn1_codecs::BerCodecData::new();
+            for elem in &self.0 {
+                elem.#codec_encode_fn(&mut contents)?;
+            }
+
+            asn1_codecs::ber::encode::encode_constructed(data, tag.unwrap_or(#default_tag), &contents)
+        }
+    };
+    let order_tokens = if set {
+        quote! {
+            asn1_codecs::ber::decode::check_set_of_order(&contents)?;
+        }
+    } else {
+        quote! {}
+    };
+
+    let tokens = quote! {
+
+        impl #codec_path for #name {
+            type Output = Self;
+
+            fn #codec_decode_tagged_fn(data: &mut asn1_codecs::BerCodecData, tag: Option<asn1_codecs::ber::Tag>) -> Result<Self::Output, asn1_codecs::BerCodecError> {
+                log::trace!(concat!("decode: ", stringify!(#name)));
+
+                let mut contents = asn1_codecs::ber::decode::decode_constructed(data, tag.unwrap_or(#default_tag))?;
+                #order_tokens
+
+                let mut items = vec![];
+                while !contents.is_empty() {
+                    items.push(#ty::#codec_decode_fn(&mut contents)?);
+                }
+
+                Ok(Self(items))
+            }
+
+            fn #codec_encode_tagged_fn(&self, data: &mut asn1_codecs::BerCodecData, tag: Option<asn1_codecs::ber::Tag>) -> Result<(), asn1_codecs::BerCodecError> {
+                log::trace!(concat!("encode: ", stringify!(#name)));
+
+                #encode_tokens
+            }
+        }
+    };
+
+    tokens.into()
+}
@@ -0,0 +1,34 @@
+//! `JER` Code generation for ASN.1 BOOLEAN Type
+
+use quote::quote;
+
+use crate::attrs::TyCodecParams;
+
+pub(super) fn generate_jer_codec_for_asn_boolean(
+    ast: &syn::DeriveInput,
+    _params: &TyCodecParams,
+) -> proc_macro::TokenStream {
+    let name = &ast.ident;
+
+    let tokens = quote! {
+
+        impl asn1_codecs::jer::JerCodec for #name {
+            type Output = Self;
+
+            fn jer_decode(data: &mut asn1_codecs::JerCodecData) -> Result<Self::Output, asn1_codecs::JerCodecError> {
+                log::trace!(concat!("decode: ", stringify!(#name)));
+
+                let value = asn1_codecs::jer::decode::decode_bool(data)?;
+                Ok(Self(value))
+            }
+
+            fn jer_encode(&self, data: &mut asn1_codecs::JerCodecData) -> Result<(), asn1_codecs::JerCodecError> {
+                log::trace!(concat!("encode: ", stringify!(#name)));
+
+                asn1_codecs::jer::encode::encode_bool(data, self.0)
+            }
+        }
+    };
+
+    tokens.into()
+}
@@ -0,0 +1,122 @@
+//! `JER` Code generation for ASN.1 Choice Type
+
+use proc_macro::TokenStream;
+use quote::quote;
+
+use crate::attrs::{parse_fld_meta_as_codec_params, TyCodecParams};
+use crate::utils::is_unknown_variant;
+
+pub(super) fn generate_jer_codec_for_asn_choice(
+    ast: &syn::DeriveInput,
+    _params: &TyCodecParams,
+) -> proc_macro::TokenStream {
+    let name = &ast.ident;
+
+    let variant_tokens = generate_choice_variant_tokens_using_attrs(ast);
+    if variant_tokens.is_err() {
+        return variant_tokens.err().unwrap().to_compile_error().into();
+    }
+    let (variant_decode_tokens, variant_encode_tokens) = variant_tokens.unwrap();
+
+    let tokens = quote! {
+
+        impl asn1_codecs::jer::JerCodec for #name {
+            type Output = Self;
+
+            fn jer_decode(data: &mut asn1_codecs::JerCodecData) -> Result<Self::Output, asn1_codecs::JerCodecError> {
+                log::trace!(concat!("decode: ", stringify!(#name)));
+
+                let (alternative, mut value) = asn1_codecs::jer::decode::decode_choice(data)?;
+                match alternative.as_str() {
+                    #(#variant_decode_tokens)*
+                    _ => Err(asn1_codecs::JerCodecError::new(format!("JerCodec:DecodeError:'{}' is not a valid Alternative for the CHOICE", alternative)))
+                }
+            }
+
+            fn jer_encode(&self, data: &mut asn1_codecs::JerCodecData) -> Result<(), asn1_codecs::JerCodecError> {
+                log::trace!(concat!("encode: ", stringify!(#name)));
+
+                match self {
+                    #(#variant_encode_tokens)*
+                }
+            }
+        }
+    };
+
+    TokenStream::from(tokens)
+}
+
+// The alternatives are identified by their identifiers from the ASN.1 definition given by the
+// `name` attribute, or the name of the variant if the attribute is not present. The alternatives
+// in the 'root' and the 'additions' are encoded in the same way.
+fn generate_choice_variant_tokens_using_attrs(
+    ast: &syn::DeriveInput,
+) -> Result<(Vec<proc_macro2::TokenStream>, Vec<proc_macro2::TokenStream>), syn::Error> {
+    let mut decode_tokens = vec![];
+    let mut encode_tokens = vec![];
+
+    let mut errors = vec![];
+    if let syn::Data::Enum(ref data) = ast.data {
+        for variant in &data.variants {
+            let codec_params = parse_fld_meta_as_codec_params(&variant.attrs);
+            match codec_params {
+                Err(e) => errors.push(e),
+                Ok(cp) => {
+                    // The encoding of an alternative not known to us is kept only by the PER Codecs.
+                    match is_unknown_variant(variant, &cp) {
+                        Err(e) => {
+                            errors.push(e);
+                            continue;
+                        }
+                        Ok(true) => {
+                            let variant_ident = &variant.ident;
+                            encode_tokens.push(quote! {
+                                Self::#variant_ident(..) => Err(asn1_codecs::JerCodecError::new("Alternative not known to us cannot be encoded.")),
+                            });
+                            continue;
+                        }
+                        Ok(false) => {}
+                    }
+
+                    let variant_ident = &variant.ident;
+                    let variant_name = cp
+                        .name
+                        .as_ref()
+                        .map(|n| n.value())
+                        .unwrap_or_else(|| variant_ident.to_string());
+                    if let syn::Fields::Unnamed(ref fields) = variant.fields {
+                        if fields.unnamed.len() == 1 {
+                            let ty = &fields.unnamed.first().as_ref().unwrap().ty;
+                            let variant_decode_token = quote! {
+                                #variant_name => Ok(Self::#variant_ident(#ty::jer_decode(&mut value)?)),
+                            };
+                            let variant_encode_token = quote! {
+                                Self::#variant_ident(ref v) => {
+                                    let mut value = asn1_codecs::JerCodecData::new();
+                                    v.jer_encode(&mut value)?;
+                                    asn1_codecs::jer::encode::encode_choice(data, #variant_name, value)
+                                }
+                            };
+                            decode_tokens.push(variant_decode_token);
+                            encode_tokens.push(variant_encode_token);
+                        } else {
+                            errors.push(syn::Error::new_spanned(
+                                variant,
+                                "Unsupported variant type".to_string(),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    if let Some((first, others)) = errors.split_first_mut() {
+        for e in others {
+            first.combine(e.clone())
+        }
+        Err(first.clone())
+    } else {
+        Ok((decode_tokens, encode_tokens))
+    }
+}
@@ -0,0 +1,58 @@
+//! `JER` Code generation for ASN.1 ENUMERATED Type
+
+use quote::quote;
+
+use crate::{attrs::TyCodecParams, utils};
+
+pub(super) fn generate_jer_codec_for_asn_enumerated(
+    ast: &syn::DeriveInput,
+    params: &TyCodecParams,
+) -> proc_macro::TokenStream {
+    let name = &ast.ident;
+
+    let ty = utils::get_newtype_inner_ty(ast);
+    if ty.is_none() {
+        return syn::Error::new_spanned(ast, format!("{} Should be a Unit Struct.", name))
+            .to_compile_error()
+            .into();
+    }
+
+    // The values are encoded using their identifiers, so the `named_values` are required.
+    let named_values = match params.named_values {
+        Some(ref named_values) => utils::parse_named_values(named_values),
+        None => Err(syn::Error::new_spanned(
+            params.attr.as_ref(),
+            "Missing parameter 'named_values' for the ENUMERATED.",
+        )),
+    };
+    if named_values.is_err() {
+        return named_values.err().unwrap().to_compile_error().into();
+    }
+    let named_value_tokens = named_values
+        .unwrap()
+        .into_iter()
+        .map(|(name, value)| quote! { (#name, #value) })
+        .collect::<Vec<_>>();
+
+    let tokens = quote! {
+
+        impl asn1_codecs::jer::JerCodec for #name {
+            type Output = Self;
+
+            fn jer_decode(data: &mut asn1_codecs::JerCodecData) -> Result<Self::Output, asn1_codecs::JerCodecError> {
+                log::trace!(concat!("decode: ", stringify!(#name)));
+
+                let decoded = asn1_codecs::jer::decode::decode_enumerated(data, &[#(#named_value_tokens),*])?;
+                Ok(Self(decoded as #ty))
+            }
+
+            fn jer_encode(&self, data: &mut asn1_codecs::JerCodecData) -> Result<(), asn1_codecs::JerCodecError> {
+                log::trace!(concat!("encode: ", stringify!(#name)));
+
+                asn1_codecs::jer::encode::encode_enumerated(data, &[#(#named_value_tokens),*], self.0 as i128)
+            }
+        }
+    };
+
+    tokens.into()
+}
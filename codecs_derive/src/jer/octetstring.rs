@@ -0,0 +1,43 @@
+//! `JER` Code generation for ASN.1 OCTET STRING Type
+
+use quote::quote;
+
+use crate::{attrs::TyCodecParams, utils};
+
+pub(super) fn generate_jer_codec_for_asn_octetstring(
+    ast: &syn::DeriveInput,
+    params: &TyCodecParams,
+) -> proc_macro::TokenStream {
+    let name = &ast.ident;
+
+    let ty = utils::get_newtype_inner_ty(ast);
+    if ty.is_none() {
+        return syn::Error::new_spanned(ast, format!("{} Should be a Unit Struct.", name))
+            .to_compile_error()
+            .into();
+    }
+
+    let (sz_lb, sz_ub, sz_ext) = utils::get_sz_bounds_extensible_from_params(params);
+
+    let tokens = quote! {
+
+        impl asn1_codecs::jer::JerCodec for #name {
+            type Output = Self;
+
+            fn jer_decode(data: &mut asn1_codecs::JerCodecData) -> Result<Self::Output, asn1_codecs::JerCodecError> {
+                log::trace!(concat!("decode: ", stringify!(#name)));
+
+                let decoded = asn1_codecs::jer::decode::decode_octetstring(data, #sz_lb, #sz_ub, #sz_ext)?;
+                Ok(Self(decoded))
+            }
+
+            fn jer_encode(&self, data: &mut asn1_codecs::JerCodecData) -> Result<(), asn1_codecs::JerCodecError> {
+                log::trace!(concat!("encode: ", stringify!(#name)));
+
+                asn1_codecs::jer::encode::encode_octetstring(data, #sz_lb, #sz_ub, #sz_ext, &self.0)
+            }
+        }
+    };
+
+    tokens.into()
+}
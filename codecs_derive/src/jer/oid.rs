@@ -0,0 +1,33 @@
+//! `JER` Code generation for ASN.1 OBJECT IDENTIFIER Type
+
+use quote::quote;
+
+use crate::attrs::TyCodecParams;
+
+pub(super) fn generate_jer_codec_for_asn_object_identifier(
+    ast: &syn::DeriveInput,
+    _params: &TyCodecParams,
+) -> proc_macro::TokenStream {
+    let name = &ast.ident;
+
+    let tokens = quote! {
+
+        impl asn1_codecs::jer::JerCodec for #name {
+            type Output = Self;
+
+            fn jer_decode(_data: &mut asn1_codecs::JerCodecData) -> Result<Self::Output, asn1_codecs::JerCodecError> {
+                log::trace!(concat!("decode: ", stringify!(#name)));
+
+                Err(asn1_codecs::JerCodecError::new("Object Identifier Decode Not Supported!"))
+            }
+
+            fn jer_encode(&self, _data: &mut asn1_codecs::JerCodecData) -> Result<(), asn1_codecs::JerCodecError> {
+                log::trace!(concat!("encode: ", stringify!(#name)));
+
+                Err(asn1_codecs::JerCodecError::new("Object Identifier Encode Not Supported!"))
+            }
+        }
+    };
+
+    tokens.into()
+}
@@ -0,0 +1,186 @@
+//! `JER` Code generation for ASN.1 `SEQUENCE` and `SET` types
+
+use quote::quote;
+
+use crate::attrs::{parse_fld_meta_as_codec_params, TyCodecParams};
+use crate::utils::{get_default_fn, get_field_type, is_unknown_extensions_field};
+
+struct FieldTokens {
+    decode_tokens: Vec<proc_macro2::TokenStream>,
+    encode_tokens: Vec<proc_macro2::TokenStream>,
+    names: Vec<String>,
+}
+
+pub(super) fn generate_jer_codec_for_asn_sequence(
+    ast: &syn::DeriveInput,
+    params: &TyCodecParams,
+) -> proc_macro::TokenStream {
+    let name = &ast.ident;
+
+    let ext = params.ext.as_ref().map(|e| e.value()).unwrap_or_default();
+
+    let field_tokens = generate_seq_field_codec_tokens_using_attrs(ast);
+    if field_tokens.is_err() {
+        return field_tokens.err().unwrap().to_compile_error().into();
+    }
+    let field_tokens = field_tokens.unwrap();
+    let fld_decode_tokens = field_tokens.decode_tokens;
+    let fld_encode_tokens = field_tokens.encode_tokens;
+    let fld_names = field_tokens.names;
+
+    let tokens = quote! {
+        impl asn1_codecs::jer::JerCodec for #name {
+            type Output = Self;
+
+            fn jer_decode(data: &mut asn1_codecs::JerCodecData) -> Result<Self::Output, asn1_codecs::JerCodecError> {
+                log::trace!(concat!("decode: ", stringify!(#name)));
+
+                let decoded = Self{#(#fld_decode_tokens)*};
+
+                asn1_codecs::jer::decode::check_sequence_components(data, &[#(#fld_names),*], #ext)?;
+
+                Ok(decoded)
+            }
+
+            fn jer_encode(&self, data: &mut asn1_codecs::JerCodecData) -> Result<(), asn1_codecs::JerCodecError> {
+                log::trace!(concat!("encode: ", stringify!(#name)));
+
+                let mut components = vec![];
+
+                #(#fld_encode_tokens)*
+
+                asn1_codecs::jer::encode::encode_sequence(data, components)
+            }
+        }
+    };
+
+    tokens.into()
+}
+
+// The components are identified by their identifiers from the ASN.1 definition given by the `name`
+// attribute, or the name of the field if the attribute is not present.
+fn generate_seq_field_codec_tokens_using_attrs(
+    ast: &syn::DeriveInput,
+) -> Result<FieldTokens, syn::Error> {
+    let mut decode_tokens = vec![];
+    let mut encode_tokens = vec![];
+    let mut names = vec![];
+
+    let mut errors: Vec<syn::Error> = vec![];
+    if let syn::Data::Struct(ref data) = ast.data {
+        if let syn::Fields::Named(ref fields) = data.fields {
+            for field in &fields.named {
+                let codec_params = parse_fld_meta_as_codec_params(&field.attrs);
+                match codec_params {
+                    Err(e) => errors.push(e),
+                    Ok(cp) => {
+                        // The extension additions not known to us are kept only by the PER Codecs.
+                        if is_unknown_extensions_field(&cp) {
+                            let id = field.ident.as_ref().unwrap();
+                            decode_tokens.push(quote! { #id: Default::default(), });
+                            continue;
+                        }
+
+                        let field_type = get_field_type(field);
+                        if field_type.ty.is_none() {
+                            errors.push(syn::Error::new_spanned(
+                                field,
+                                "Field Type is not in supported Format!",
+                            ));
+                            continue;
+                        }
+                        let ty_ident = field_type.ty.unwrap();
+                        let optional = field_type.is_optional;
+                        let id = field.ident.as_ref().unwrap();
+                        // An absent component with a `DEFAULT` value takes the default value.
+                        let absent = match get_default_fn(field, &cp) {
+                            Ok(Some(default_fn)) => quote! { Some(#default_fn()) },
+                            Ok(None) => quote! { None },
+                            Err(e) => {
+                                errors.push(e);
+                                continue;
+                            }
+                        };
+                        let fld_name = cp
+                            .name
+                            .as_ref()
+                            .map(|n| n.value())
+                            .unwrap_or_else(|| id.to_string());
+
+                        let fld_decode_tokens = if optional {
+                            quote! {
+                                match asn1_codecs::jer::decode::decode_sequence_component(data, #fld_name)? {
+                                    Some(mut component) => Some(#ty_ident::jer_decode(&mut component)?),
+                                    None => #absent,
+                                }
+                            }
+                        } else {
+                            let is_key_field = cp
+                                .key_field
+                                .as_ref()
+                                .map(|kf| kf.value())
+                                .unwrap_or_default();
+
+                            let value_decode_tokens = if !is_key_field {
+                                quote! {
+                                    #ty_ident::jer_decode(&mut component)?
+                                }
+                            } else {
+                                quote! {
+                                    {
+                                    let value = #ty_ident::jer_decode(&mut component)?;
+                                    let _ = data.set_key(value.0 as i128);
+                                    value
+                                    }
+                                }
+                            };
+                            quote! {
+                                {
+                                let mut component = asn1_codecs::jer::decode::decode_sequence_component(data, #fld_name)?
+                                    .ok_or_else(|| asn1_codecs::JerCodecError::new(concat!("JerCodec:DecodeError:Component '", #fld_name, "' missing in a SEQUENCE.")))?;
+                                #value_decode_tokens
+                                }
+                            }
+                        };
+
+                        let value_encode_tokens = quote! {
+                            let mut component = asn1_codecs::JerCodecData::new();
+                            #id.jer_encode(&mut component)?;
+                            components.push((#fld_name, component));
+                        };
+                        let field_encode_token = if optional {
+                            quote! {
+                                if let Some(ref #id) = self.#id {
+                                    #value_encode_tokens
+                                }
+                            }
+                        } else {
+                            quote! {
+                                {
+                                let #id = &self.#id;
+                                #value_encode_tokens
+                                }
+                            }
+                        };
+                        decode_tokens.push(quote! { #id: #fld_decode_tokens, });
+                        encode_tokens.push(field_encode_token);
+                        names.push(fld_name);
+                    }
+                }
+            }
+        }
+    }
+
+    if let Some((first, others)) = errors.split_first_mut() {
+        for e in others {
+            first.combine(e.clone())
+        }
+        Err(first.clone())
+    } else {
+        Ok(FieldTokens {
+            decode_tokens,
+            encode_tokens,
+            names,
+        })
+    }
+}
@@ -0,0 +1,67 @@
+//! `JER` Code generation for ASN.1 SEQUENCE OF Type
+
+use quote::quote;
+
+use crate::{attrs::TyCodecParams, utils};
+
+pub(super) fn generate_jer_codec_for_asn_sequence_of(
+    ast: &syn::DeriveInput,
+    _params: &TyCodecParams,
+) -> proc_macro::TokenStream {
+    let name = &ast.ident;
+
+    let ty = if let syn::Data::Struct(ref d) = &ast.data {
+        match d.fields {
+            syn::Fields::Unnamed(ref f) => {
+                if f.unnamed.len() == 1 {
+                    let first = f.unnamed.first().unwrap();
+                    utils::get_inner_ty_for_vec(first)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    } else {
+        None
+    };
+
+    if ty.is_none() {
+        return syn::Error::new_spanned(ast, format!("{} Should be a Unit Struct.", name))
+            .to_compile_error()
+            .into();
+    }
+
+    let tokens = quote! {
+
+        impl asn1_codecs::jer::JerCodec for #name {
+            type Output = Self;
+
+            fn jer_decode(data: &mut asn1_codecs::JerCodecData) -> Result<Self::Output, asn1_codecs::JerCodecError> {
+                log::trace!(concat!("decode: ", stringify!(#name)));
+
+                let mut items = vec![];
+                for mut item in asn1_codecs::jer::decode::decode_sequence_of(data)? {
+                    items.push(#ty::jer_decode(&mut item)?);
+                }
+
+                Ok(Self(items))
+            }
+
+            fn jer_encode(&self, data: &mut asn1_codecs::JerCodecData) -> Result<(), asn1_codecs::JerCodecError> {
+                log::trace!(concat!("encode: ", stringify!(#name)));
+
+                let mut items = vec![];
+                for elem in &self.0 {
+                    let mut item = asn1_codecs::JerCodecData::new();
+                    elem.jer_encode(&mut item)?;
+                    items.push(item);
+                }
+
+                asn1_codecs::jer::encode::encode_sequence_of(data, items)
+            }
+        }
+    };
+
+    tokens.into()
+}
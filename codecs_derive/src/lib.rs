@@ -9,8 +9,18 @@ mod symbol;
 
 mod per;
 
+mod ber;
+
+mod oer;
+
+mod jer;
+
+mod xer;
+
 mod utils;
 
+mod validate;
+
 /// APER Codec Derive Macro support.
 #[proc_macro_derive(AperCodec, attributes(asn))]
 pub fn derive_aper_codec(input: TokenStream) -> TokenStream {
@@ -21,7 +31,10 @@ pub fn derive_aper_codec(input: TokenStream) -> TokenStream {
         codec_params.err().unwrap().to_compile_error().into()
     } else {
         let codec_params = codec_params.unwrap();
-        per::generate_codec(&ast, &codec_params, true)
+        validate::add_validation(
+            per::generate_codec(&ast, &codec_params, true),
+            &codec_params,
+        )
     }
 }
 
@@ -35,7 +48,73 @@ pub fn derive_uper_codec(input: TokenStream) -> TokenStream {
         codec_params.err().unwrap().to_compile_error().into()
     } else {
         let codec_params = codec_params.unwrap();
-        per::generate_codec(&ast, &codec_params, false)
+        validate::add_validation(
+            per::generate_codec(&ast, &codec_params, false),
+            &codec_params,
+        )
+    }
+}
+
+/// BER Codec Derive Macro support.
+#[proc_macro_derive(BerCodec, attributes(asn))]
+pub fn derive_ber_codec(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    match codec_params_or_err(&ast) {
+        Ok(codec_params) => validate::add_validation(
+            ber::generate_codec(&ast, &codec_params, false),
+            &codec_params,
+        ),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+#[proc_macro_derive(DerCodec, attributes(asn))]
+pub fn derive_der_codec(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    match codec_params_or_err(&ast) {
+        Ok(codec_params) => validate::add_validation(
+            ber::generate_codec(&ast, &codec_params, true),
+            &codec_params,
+        ),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+#[proc_macro_derive(OerCodec, attributes(asn))]
+pub fn derive_oer_codec(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    match codec_params_or_err(&ast) {
+        Ok(codec_params) => {
+            validate::add_validation(oer::generate_codec(&ast, &codec_params), &codec_params)
+        }
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+#[proc_macro_derive(JerCodec, attributes(asn))]
+pub fn derive_jer_codec(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    match codec_params_or_err(&ast) {
+        Ok(codec_params) => {
+            validate::add_validation(jer::generate_codec(&ast, &codec_params), &codec_params)
+        }
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+#[proc_macro_derive(XerCodec, attributes(asn))]
+pub fn derive_xer_codec(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    match codec_params_or_err(&ast) {
+        Ok(codec_params) => {
+            validate::add_validation(xer::generate_codec(&ast, &codec_params), &codec_params)
+        }
+        Err(e) => e.to_compile_error().into(),
     }
 }
 
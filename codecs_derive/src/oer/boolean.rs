@@ -0,0 +1,34 @@
+//! `OER` Code generation for ASN.1 BOOLEAN Type
+
+use quote::quote;
+
+use crate::attrs::TyCodecParams;
+
+pub(super) fn generate_oer_codec_for_asn_boolean(
+    ast: &syn::DeriveInput,
+    _params: &TyCodecParams,
+) -> proc_macro::TokenStream {
+    let name = &ast.ident;
+
+    let tokens = quote! {
+
+        impl asn1_codecs::oer::OerCodec for #name {
+            type Output = Self;
+
+            fn oer_decode(data: &mut asn1_codecs::OerCodecData) -> Result<Self::Output, asn1_codecs::OerCodecError> {
+                log::trace!(concat!("decode: ", stringify!(#name)));
+
+                let value = asn1_codecs::oer::decode::decode_bool(data)?;
+                Ok(Self(value))
+            }
+
+            fn oer_encode(&self, data: &mut asn1_codecs::OerCodecData) -> Result<(), asn1_codecs::OerCodecError> {
+                log::trace!(concat!("encode: ", stringify!(#name)));
+
+                asn1_codecs::oer::encode::encode_bool(data, self.0)
+            }
+        }
+    };
+
+    tokens.into()
+}
@@ -0,0 +1,104 @@
+//! `OER` Code generation for ASN.1 Character String Types
+
+use quote::quote;
+
+use crate::{attrs::TyCodecParams, utils};
+
+pub(super) fn generate_oer_codec_for_asn_charstring(
+    ast: &syn::DeriveInput,
+    params: &TyCodecParams,
+) -> proc_macro::TokenStream {
+    let name = &ast.ident;
+
+    let ty = utils::get_newtype_inner_ty(ast);
+    if ty.is_none() {
+        return syn::Error::new_spanned(ast, format!("{} Should be a Unit Struct.", name))
+            .to_compile_error()
+            .into();
+    }
+
+    let (sz_lb, sz_ub, sz_ext) = utils::get_sz_bounds_extensible_from_params(params);
+
+    // The size constraints of a UTF8String are not used, as the number of octets for a character
+    // is not fixed. Neither are those of the other character string types that are not
+    // known-multiplier types.
+    let (decode_tokens, encode_tokens) = match params.ty.as_ref().unwrap().value().as_str() {
+        "UTF8String" => (
+            quote!(asn1_codecs::oer::decode::decode_utf8_string(data)),
+            quote!(asn1_codecs::oer::encode::encode_utf8_string(data, &self.0)),
+        ),
+        "PrintableString" => (
+            quote!(asn1_codecs::oer::decode::decode_printable_string(data, #sz_lb, #sz_ub, #sz_ext)),
+            quote!(asn1_codecs::oer::encode::encode_printable_string(data, #sz_lb, #sz_ub, #sz_ext, &self.0)),
+        ),
+        "VisibleString" => (
+            quote!(asn1_codecs::oer::decode::decode_visible_string(data, #sz_lb, #sz_ub, #sz_ext)),
+            quote!(asn1_codecs::oer::encode::encode_visible_string(data, #sz_lb, #sz_ub, #sz_ext, &self.0)),
+        ),
+        "IA5String" => (
+            quote!(asn1_codecs::oer::decode::decode_ia5_string(data, #sz_lb, #sz_ub, #sz_ext)),
+            quote!(asn1_codecs::oer::encode::encode_ia5_string(data, #sz_lb, #sz_ub, #sz_ext, &self.0)),
+        ),
+        "NumericString" => (
+            quote!(asn1_codecs::oer::decode::decode_numeric_string(data, #sz_lb, #sz_ub, #sz_ext)),
+            quote!(asn1_codecs::oer::encode::encode_numeric_string(data, #sz_lb, #sz_ub, #sz_ext, &self.0)),
+        ),
+        "BMPString" => (
+            quote!(asn1_codecs::oer::decode::decode_bmp_string(data, #sz_lb, #sz_ub, #sz_ext)),
+            quote!(asn1_codecs::oer::encode::encode_bmp_string(data, #sz_lb, #sz_ub, #sz_ext, &self.0)),
+        ),
+        "UniversalString" => (
+            quote!(asn1_codecs::oer::decode::decode_universal_string(data, #sz_lb, #sz_ub, #sz_ext)),
+            quote!(asn1_codecs::oer::encode::encode_universal_string(data, #sz_lb, #sz_ub, #sz_ext, &self.0)),
+        ),
+        "TeletexString" | "T61String" => (
+            quote!(asn1_codecs::oer::decode::decode_teletex_string(data)),
+            quote!(asn1_codecs::oer::encode::encode_teletex_string(
+                data, &self.0
+            )),
+        ),
+        "GeneralString" => (
+            quote!(asn1_codecs::oer::decode::decode_general_string(data)),
+            quote!(asn1_codecs::oer::encode::encode_general_string(
+                data, &self.0
+            )),
+        ),
+        "UTCTime" => (
+            quote!(asn1_codecs::oer::decode::decode_utc_time(data)),
+            quote!(asn1_codecs::oer::encode::encode_utc_time(data, &self.0)),
+        ),
+        "GeneralizedTime" => (
+            quote!(asn1_codecs::oer::decode::decode_generalized_time(data)),
+            quote!(asn1_codecs::oer::encode::encode_generalized_time(
+                data, &self.0
+            )),
+        ),
+        _ => {
+            return syn::Error::new_spanned(params.ty.as_ref(), "Unsupported Character String Type")
+                .to_compile_error()
+                .into()
+        }
+    };
+
+    let tokens = quote! {
+
+        impl asn1_codecs::oer::OerCodec for #name {
+            type Output = Self;
+
+            fn oer_decode(data: &mut asn1_codecs::OerCodecData) -> Result<Self::Output, asn1_codecs::OerCodecError> {
+                log::trace!(concat!("decode: ", stringify!(#name)));
+
+                let decoded = #decode_tokens?;
+                Ok(Self(decoded))
+            }
+
+            fn oer_encode(&self, data: &mut asn1_codecs::OerCodecData) -> Result<(), asn1_codecs::OerCodecError> {
+                log::trace!(concat!("encode: ", stringify!(#name)));
+
+                #encode_tokens
+            }
+        }
+    };
+
+    tokens.into()
+}
@@ -0,0 +1,153 @@
+//! `OER` Code generation for ASN.1 Choice Type
+
+use proc_macro::TokenStream;
+use quote::quote;
+
+use crate::attrs::{parse_fld_meta_as_codec_params, TyCodecParams};
+use crate::utils::is_unknown_variant;
+
+pub(super) fn generate_oer_codec_for_asn_choice(
+    ast: &syn::DeriveInput,
+    params: &TyCodecParams,
+) -> proc_macro::TokenStream {
+    let name = &ast.ident;
+
+    let lb = params.lb.as_ref().unwrap().value().parse::<i128>().unwrap();
+    let ub = params.ub.as_ref().unwrap().value().parse::<i128>().unwrap();
+
+    let variant_tokens = generate_choice_variant_tokens_using_attrs(ast, ub - lb + 1);
+    if variant_tokens.is_err() {
+        return variant_tokens.err().unwrap().to_compile_error().into();
+    }
+    let (variant_decode_tokens, variant_encode_tokens) = variant_tokens.unwrap();
+
+    let tokens = quote! {
+
+        impl asn1_codecs::oer::OerCodec for #name {
+            type Output = Self;
+
+            fn oer_decode(data: &mut asn1_codecs::OerCodecData) -> Result<Self::Output, asn1_codecs::OerCodecError> {
+                log::trace!(concat!("decode: ", stringify!(#name)));
+
+                let tag = asn1_codecs::oer::decode::decode_choice_tag(data)?;
+                match (tag.class, tag.number) {
+                    #(#variant_decode_tokens)*
+                    _ => Err(asn1_codecs::OerCodecError::new(format!("Tag {} is not a valid Tag for the CHOICE", tag)))
+                }
+            }
+
+            fn oer_encode(&self, data: &mut asn1_codecs::OerCodecData) -> Result<(), asn1_codecs::OerCodecError> {
+                log::trace!(concat!("encode: ", stringify!(#name)));
+
+                match self {
+                    #(#variant_encode_tokens)*
+                }
+            }
+        }
+    };
+
+    TokenStream::from(tokens)
+}
+
+// The alternatives are 'automatically' tagged, the alternatives in the 'root' are tagged in the
+// order of the key and the 'additions' are tagged after all the 'root' alternatives. The value of
+// an addition is encoded as an open type.
+fn generate_choice_variant_tokens_using_attrs(
+    ast: &syn::DeriveInput,
+    root_count: i128,
+) -> Result<(Vec<proc_macro2::TokenStream>, Vec<proc_macro2::TokenStream>), syn::Error> {
+    let mut decode_tokens = vec![];
+    let mut encode_tokens = vec![];
+
+    let mut errors = vec![];
+    if let syn::Data::Enum(ref data) = ast.data {
+        for variant in &data.variants {
+            let codec_params = parse_fld_meta_as_codec_params(&variant.attrs);
+            match codec_params {
+                Err(e) => errors.push(e),
+                Ok(cp) => {
+                    // The encoding of an alternative not known to us is kept only by the PER Codecs.
+                    match is_unknown_variant(variant, &cp) {
+                        Err(e) => {
+                            errors.push(e);
+                            continue;
+                        }
+                        Ok(true) => {
+                            let variant_ident = &variant.ident;
+                            encode_tokens.push(quote! {
+                                Self::#variant_ident(..) => Err(asn1_codecs::OerCodecError::new("Alternative not known to us cannot be encoded.")),
+                            });
+                            continue;
+                        }
+                        Ok(false) => {}
+                    }
+
+                    let key = cp.key.as_ref();
+                    if key.is_none() {
+                        errors.push(syn::Error::new_spanned(
+                        variant,
+                        "Missing Key for the variant. Please provide `#[asn(key = <int>)]` attribute.",
+                    ));
+                        continue;
+                    }
+                    let key = key.unwrap().base10_parse::<i128>()?;
+                    let extended = cp.extended.as_ref().map(|e| e.value()).unwrap_or_default();
+                    let tag_number = if extended { key + root_count } else { key } as u32;
+
+                    let variant_ident = &variant.ident;
+                    if let syn::Fields::Unnamed(ref fields) = variant.fields {
+                        if fields.unnamed.len() == 1 {
+                            let ty = &fields.unnamed.first().as_ref().unwrap().ty;
+                            let (variant_decode_token, variant_encode_token) = if extended {
+                                (
+                                    quote! {
+                                        (asn1_codecs::ber::TagClass::ContextSpecific, #tag_number) => {
+                                            let mut value = asn1_codecs::oer::decode::decode_open_type(data)?;
+                                            Ok(Self::#variant_ident(#ty::oer_decode(&mut value)?))
+                                        }
+                                    },
+                                    quote! {
+                                        Self::#variant_ident(ref v) => {
+                                            asn1_codecs::oer::encode::encode_choice_tag(data, asn1_codecs::ber::Tag::context(#tag_number))?;
+                                            let mut value = asn1_codecs::OerCodecData::new();
+                                            v.oer_encode(&mut value)?;
+                                            asn1_codecs::oer::encode::encode_open_type(data, &value)
+                                        }
+                                    },
+                                )
+                            } else {
+                                (
+                                    quote! {
+                                        (asn1_codecs::ber::TagClass::ContextSpecific, #tag_number) => Ok(Self::#variant_ident(#ty::oer_decode(data)?)),
+                                    },
+                                    quote! {
+                                        Self::#variant_ident(ref v) => {
+                                            asn1_codecs::oer::encode::encode_choice_tag(data, asn1_codecs::ber::Tag::context(#tag_number))?;
+                                            v.oer_encode(data)
+                                        }
+                                    },
+                                )
+                            };
+                            decode_tokens.push(variant_decode_token);
+                            encode_tokens.push(variant_encode_token);
+                        } else {
+                            errors.push(syn::Error::new_spanned(
+                                variant,
+                                "Unsupported variant type".to_string(),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    if let Some((first, others)) = errors.split_first_mut() {
+        for e in others {
+            first.combine(e.clone())
+        }
+        Err(first.clone())
+    } else {
+        Ok((decode_tokens, encode_tokens))
+    }
+}
@@ -0,0 +1,41 @@
+//! `OER` Code generation for ASN.1 ENUMERATED Type
+
+use quote::quote;
+
+use crate::{attrs::TyCodecParams, utils};
+
+pub(super) fn generate_oer_codec_for_asn_enumerated(
+    ast: &syn::DeriveInput,
+    _params: &TyCodecParams,
+) -> proc_macro::TokenStream {
+    let name = &ast.ident;
+
+    let ty = utils::get_newtype_inner_ty(ast);
+    if ty.is_none() {
+        return syn::Error::new_spanned(ast, format!("{} Should be a Unit Struct.", name))
+            .to_compile_error()
+            .into();
+    }
+
+    let tokens = quote! {
+
+        impl asn1_codecs::oer::OerCodec for #name {
+            type Output = Self;
+
+            fn oer_decode(data: &mut asn1_codecs::OerCodecData) -> Result<Self::Output, asn1_codecs::OerCodecError> {
+                log::trace!(concat!("decode: ", stringify!(#name)));
+
+                let decoded = asn1_codecs::oer::decode::decode_enumerated(data)?;
+                Ok(Self(decoded as #ty))
+            }
+
+            fn oer_encode(&self, data: &mut asn1_codecs::OerCodecData) -> Result<(), asn1_codecs::OerCodecError> {
+                log::trace!(concat!("encode: ", stringify!(#name)));
+
+                asn1_codecs::oer::encode::encode_enumerated(data, self.0 as i128)
+            }
+        }
+    };
+
+    tokens.into()
+}
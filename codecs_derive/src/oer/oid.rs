@@ -0,0 +1,33 @@
+//! `OER` Code generation for ASN.1 OBJECT IDENTIFIER Type
+
+use quote::quote;
+
+use crate::attrs::TyCodecParams;
+
+pub(super) fn generate_oer_codec_for_asn_object_identifier(
+    ast: &syn::DeriveInput,
+    _params: &TyCodecParams,
+) -> proc_macro::TokenStream {
+    let name = &ast.ident;
+
+    let tokens = quote! {
+
+        impl asn1_codecs::oer::OerCodec for #name {
+            type Output = Self;
+
+            fn oer_decode(_data: &mut asn1_codecs::OerCodecData) -> Result<Self::Output, asn1_codecs::OerCodecError> {
+                log::trace!(concat!("decode: ", stringify!(#name)));
+
+                Err(asn1_codecs::OerCodecError::new("Object Identifier Decode Not Supported!"))
+            }
+
+            fn oer_encode(&self, _data: &mut asn1_codecs::OerCodecData) -> Result<(), asn1_codecs::OerCodecError> {
+                log::trace!(concat!("encode: ", stringify!(#name)));
+
+                Err(asn1_codecs::OerCodecError::new("Object Identifier Encode Not Supported!"))
+            }
+        }
+    };
+
+    tokens.into()
+}
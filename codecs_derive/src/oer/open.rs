@@ -0,0 +1,135 @@
+//! `OER` Code generation for ASN.1 OPEN type
+
+use quote::quote;
+
+use crate::attrs::{parse_fld_meta_as_codec_params, TyCodecParams};
+use crate::utils::is_unknown_variant;
+
+pub(super) fn generate_oer_codec_for_asn_open_type(
+    ast: &syn::DeriveInput,
+    _params: &TyCodecParams,
+) -> proc_macro::TokenStream {
+    let name = &ast.ident;
+
+    let variant_tokens = generate_open_type_variant_tokens_using_attrs(ast);
+    if variant_tokens.is_err() {
+        return variant_tokens.err().unwrap().to_compile_error().into();
+    }
+    let (variant_decode_tokens, variant_encode_tokens) = variant_tokens.unwrap();
+
+    let encode_tokens = if !variant_encode_tokens.is_empty() {
+        quote! {
+            let mut value = asn1_codecs::OerCodecData::new();
+            match self {
+                #(#variant_encode_tokens)*
+            }?;
+            asn1_codecs::oer::encode::encode_open_type(data, &value)
+        }
+    } else {
+        quote! {
+            Ok(())
+        }
+    };
+
+    let tokens = quote! {
+        impl asn1_codecs::oer::OerCodec for #name {
+            type Output = Self;
+
+            fn oer_decode(data: &mut asn1_codecs::OerCodecData) -> Result<Self::Output, asn1_codecs::OerCodecError> {
+                log::trace!(concat!("decode: ", stringify!(#name)));
+
+                let mut value = asn1_codecs::oer::decode::decode_open_type(data)?;
+
+                if value.get_key().is_none() {
+                    return Err(asn1_codecs::OerCodecError::new("Decoding OPEN Type, but `key` is not determined!"));
+                }
+
+                let key = value.get_key().unwrap();
+
+                match key {
+                    #(#variant_decode_tokens)*
+                    _ => Err(asn1_codecs::OerCodecError::new(format!("Key {} Not Found", key).as_str()))
+                }
+            }
+
+            fn oer_encode(&self, data: &mut asn1_codecs::OerCodecData) -> Result<(), asn1_codecs::OerCodecError> {
+                log::trace!(concat!("encode: ", stringify!(#name)));
+
+                #encode_tokens
+            }
+        }
+    };
+
+    tokens.into()
+}
+
+fn generate_open_type_variant_tokens_using_attrs(
+    ast: &syn::DeriveInput,
+) -> Result<(Vec<proc_macro2::TokenStream>, Vec<proc_macro2::TokenStream>), syn::Error> {
+    let mut decode_tokens = vec![];
+    let mut encode_tokens = vec![];
+
+    let mut errors = vec![];
+    if let syn::Data::Enum(ref data) = ast.data {
+        for variant in &data.variants {
+            let codec_params = parse_fld_meta_as_codec_params(&variant.attrs);
+            match codec_params {
+                Err(e) => errors.push(e),
+                Ok(cp) => {
+                    // The encoding of a value not known to us is kept only by the PER Codecs.
+                    match is_unknown_variant(variant, &cp) {
+                        Err(e) => {
+                            errors.push(e);
+                            continue;
+                        }
+                        Ok(true) => {
+                            let variant_ident = &variant.ident;
+                            encode_tokens.push(quote! {
+                                Self::#variant_ident(..) => Err(asn1_codecs::OerCodecError::new("Value not known to us cannot be encoded.")),
+                            });
+                            continue;
+                        }
+                        Ok(false) => {}
+                    }
+
+                    let key = cp.key.as_ref();
+                    if key.is_none() {
+                        errors.push(syn::Error::new_spanned(
+                        variant,
+                        "Missing Key for the variant. Please provide `#[asn(key = <int>)]` attribute.",
+                    ));
+                        continue;
+                    }
+                    let variant_ident = &variant.ident;
+                    if let syn::Fields::Unnamed(ref fields) = variant.fields {
+                        if fields.unnamed.len() == 1 {
+                            let ty = &fields.unnamed.first().as_ref().unwrap().ty;
+                            let variant_decode_token = quote! {
+                                #key => Ok(Self::#variant_ident(#ty::oer_decode(&mut value)?)),
+                            };
+                            let variant_encode_token = quote! {
+                                Self::#variant_ident(ref v) => v.oer_encode(&mut value),
+                            };
+                            decode_tokens.push(variant_decode_token);
+                            encode_tokens.push(variant_encode_token);
+                        } else {
+                            errors.push(syn::Error::new_spanned(
+                                variant,
+                                "Unsupported variant type".to_string(),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    if let Some((first, others)) = errors.split_first_mut() {
+        for e in others {
+            first.combine(e.clone())
+        }
+        Err(first.clone())
+    } else {
+        Ok((decode_tokens, encode_tokens))
+    }
+}
@@ -0,0 +1,425 @@
+//! `OER` Code generation for ASN.1 `SEQUENCE` type
+
+use quote::quote;
+
+use crate::attrs::{parse_fld_meta_as_codec_params, TyCodecParams};
+use crate::utils::{
+    get_default_fn, get_extension_component, get_field_type, group_extension_components,
+    is_unknown_extensions_field, ExtensionAddition,
+};
+
+struct FieldTokens {
+    decode_tokens: Vec<proc_macro2::TokenStream>,
+    encode_tokens: Vec<proc_macro2::TokenStream>,
+    hdr_encode_tokens: Vec<proc_macro2::TokenStream>,
+    additions: Vec<ExtensionAddition>,
+}
+
+pub(super) fn generate_oer_codec_for_asn_sequence(
+    ast: &syn::DeriveInput,
+    params: &TyCodecParams,
+) -> proc_macro::TokenStream {
+    let name = &ast.ident;
+
+    let ext = params.ext.as_ref().map(|e| e.value()).unwrap_or_default();
+    let opt_count = params
+        .optional_fields
+        .clone()
+        .unwrap_or_else(|| syn::LitInt::new("0", proc_macro2::Span::call_site()));
+
+    let field_tokens = generate_seq_field_codec_tokens_using_attrs(ast);
+    if field_tokens.is_err() {
+        return field_tokens.err().unwrap().to_compile_error().into();
+    }
+    let field_tokens = field_tokens.unwrap();
+    let fld_decode_tokens = field_tokens.decode_tokens;
+    let hdr_encode_tokens = field_tokens.hdr_encode_tokens;
+    let fld_encode_tokens = field_tokens.encode_tokens;
+    let additions = field_tokens.additions;
+
+    if !ext && !additions.is_empty() {
+        return syn::Error::new_spanned(
+            &ast.ident,
+            "Extension Additions are present in a SEQUENCE that is not extensible.",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let (ext_decode_tokens, ext_encode_tokens) = if additions.is_empty() {
+        (
+            quote! {
+                let decoded = Self{#(#fld_decode_tokens)*};
+
+                if extensions_present {
+                    asn1_codecs::oer::decode::skip_extension_additions(data)?;
+                }
+            },
+            quote! {
+                asn1_codecs::oer::encode::encode_sequence_preamble(data, #ext, &bitmap, false)?;
+
+                #(#fld_encode_tokens)*
+            },
+        )
+    } else {
+        let (addition_decode_tokens, addition_encode_tokens) =
+            generate_seq_extension_codec_tokens(&additions);
+        let addition_init_tokens = additions.iter().flat_map(|a| a.components.iter()).map(|c| {
+            let id = &c.id;
+            quote! { #id: None, }
+        });
+        (
+            quote! {
+                let mut decoded = Self{#(#fld_decode_tokens)* #(#addition_init_tokens)*};
+
+                #addition_decode_tokens
+            },
+            quote! {
+                #addition_encode_tokens
+
+                asn1_codecs::oer::encode::encode_sequence_preamble(data, #ext, &bitmap, extended)?;
+
+                #(#fld_encode_tokens)*
+
+                if extended {
+                    asn1_codecs::oer::encode::encode_extension_bitmap(data, &extensions)?;
+                    for addition in &additions {
+                        asn1_codecs::oer::encode::encode_open_type(data, addition)?;
+                    }
+                }
+            },
+        )
+    };
+
+    let tokens = quote! {
+        impl asn1_codecs::oer::OerCodec for #name {
+            type Output = Self;
+
+            fn oer_decode(data: &mut asn1_codecs::OerCodecData) -> Result<Self::Output, asn1_codecs::OerCodecError> {
+                log::trace!(concat!("decode: ", stringify!(#name)));
+
+                let (bitmap, extensions_present) = asn1_codecs::oer::decode::decode_sequence_preamble(data, #ext, #opt_count)?;
+                #ext_decode_tokens
+
+                Ok(decoded)
+            }
+
+            fn oer_encode(&self, data: &mut asn1_codecs::OerCodecData) -> Result<(), asn1_codecs::OerCodecError> {
+                log::trace!(concat!("encode: ", stringify!(#name)));
+
+                let mut bitmap = bitvec::bitvec![u8, bitvec::prelude::Msb0; 0; #opt_count];
+
+                #(#hdr_encode_tokens)*
+
+                #ext_encode_tokens
+
+                Ok(())
+            }
+        }
+    };
+
+    tokens.into()
+}
+
+// An absent component with a `DEFAULT` value takes the default value. A component with a value that
+// is the same as it's default value is not encoded (and is rejected when decoding strictly), since
+// the encodings are always the COER encodings. The values are compared by their encodings.
+fn generate_seq_field_codec_tokens_using_attrs(
+    ast: &syn::DeriveInput,
+) -> Result<FieldTokens, syn::Error> {
+    let mut decode_tokens = vec![];
+    let mut encode_tokens = vec![];
+    let mut hdr_encode_tokens = vec![];
+    let mut extension_components = vec![];
+
+    let mut errors: Vec<syn::Error> = vec![];
+    if let syn::Data::Struct(ref data) = ast.data {
+        if let syn::Fields::Named(ref fields) = data.fields {
+            for field in &fields.named {
+                let codec_params = parse_fld_meta_as_codec_params(&field.attrs);
+                match codec_params {
+                    Err(e) => errors.push(e),
+                    Ok(cp) => {
+                        // The extension additions not known to us are kept only by the PER Codecs.
+                        if is_unknown_extensions_field(&cp) {
+                            let id = field.ident.as_ref().unwrap();
+                            decode_tokens.push(quote! { #id: Default::default(), });
+                            continue;
+                        }
+
+                        match get_extension_component(field, &cp) {
+                            Err(e) => {
+                                errors.push(e);
+                                continue;
+                            }
+                            Ok(Some(component)) => {
+                                extension_components.push(component);
+                                continue;
+                            }
+                            Ok(None) => {}
+                        }
+
+                        let field_type = get_field_type(field);
+                        if field_type.ty.is_none() {
+                            errors.push(syn::Error::new_spanned(
+                                field,
+                                "Field Type is not in supported Format!",
+                            ));
+                            continue;
+                        }
+                        let ty_ident = field_type.ty.unwrap();
+                        let optional = field_type.is_optional;
+                        let id = field.ident.as_ref().unwrap();
+                        let default_fn = match get_default_fn(field, &cp) {
+                            Ok(default_fn) => default_fn,
+                            Err(e) => {
+                                errors.push(e);
+                                continue;
+                            }
+                        };
+
+                        let fld_decode_tokens = if optional {
+                            let optional_idx = cp.optional_idx.as_ref();
+                            if optional_idx.is_none() {
+                                errors.push(syn::Error::new_spanned(
+                                    field,
+                                    "Optional Field without Optional Index.",
+                                ));
+                                continue;
+                            }
+                            match default_fn {
+                                Some(ref default_fn) => {
+                                    let is_default = |value: proc_macro2::TokenStream| {
+                                        quote! {
+                                            {
+                                            let mut encoded = asn1_codecs::OerCodecData::new();
+                                            #value.oer_encode(&mut encoded)?;
+                                            let mut default = asn1_codecs::OerCodecData::new();
+                                            #default_fn().oer_encode(&mut default)?;
+                                            encoded.same_encoding(&default)
+                                            }
+                                        }
+                                    };
+                                    let is_id_default = is_default(quote! { #id });
+                                    let is_value_default = is_default(quote! { value });
+                                    let error = format!(
+                                        "OerCodec:DecodeError:Component '{}' with it's DEFAULT value is encoded.",
+                                        id
+                                    );
+                                    hdr_encode_tokens.push(quote! {
+                                        if let Some(ref #id) = self.#id {
+                                            if !#is_id_default {
+                                                bitmap.set(#optional_idx, true);
+                                            }
+                                        }
+                                    });
+                                    quote! {
+                                        {
+                                        if bitmap[#optional_idx] {
+                                            let value = #ty_ident::oer_decode(data)?;
+                                            if data.is_coer() && #is_value_default {
+                                                return Err(asn1_codecs::OerCodecError::new(#error));
+                                            }
+                                            Some(value)
+                                        } else {
+                                            Some(#default_fn())
+                                        }
+                                        }
+                                    }
+                                }
+                                None => {
+                                    hdr_encode_tokens.push(quote! {
+                                        if self.#id.is_some() {
+                                            bitmap.set(#optional_idx, true);
+                                        }
+                                    });
+                                    quote! {
+                                        {
+                                        if bitmap[#optional_idx] {
+                                            Some(#ty_ident::oer_decode(data)?)
+                                        } else {
+                                            None
+                                        }
+                                        }
+                                    }
+                                }
+                            }
+                        } else {
+                            let is_key_field = cp
+                                .key_field
+                                .as_ref()
+                                .map(|kf| kf.value())
+                                .unwrap_or_default();
+
+                            if !is_key_field {
+                                quote! {
+                                    {
+                                    #ty_ident::oer_decode(data)?
+                                    }
+                                }
+                            } else {
+                                quote! {
+                                    {
+                                    let value = #ty_ident::oer_decode(data)?;
+                                    let _ = data.set_key(value.0 as i128);
+                                    value
+                                    }
+                                }
+                            }
+                        };
+
+                        let field_encode_token = if default_fn.is_some() {
+                            let optional_idx = cp.optional_idx.as_ref();
+                            quote! {
+                                if bitmap[#optional_idx] {
+                                    self.#id.as_ref().unwrap().oer_encode(data)?;
+                                }
+                            }
+                        } else if optional {
+                            quote! {
+                                if let Some(ref #id) = self.#id {
+                                    #id.oer_encode(data)?;
+                                }
+                            }
+                        } else {
+                            quote! {
+                                self.#id.oer_encode(data)?;
+                            }
+                        };
+                        decode_tokens.push(quote! { #id: #fld_decode_tokens, });
+                        encode_tokens.push(field_encode_token);
+                    }
+                }
+            }
+        }
+    }
+
+    if let Some((first, others)) = errors.split_first_mut() {
+        for e in others {
+            first.combine(e.clone())
+        }
+        Err(first.clone())
+    } else {
+        Ok(FieldTokens {
+            decode_tokens,
+            encode_tokens,
+            hdr_encode_tokens,
+            additions: group_extension_components(extension_components),
+        })
+    }
+}
+
+// Generates the tokens for decoding and encoding the known extension additions. Each extension
+// addition is an open type, the components of an extension addition group are encoded like a
+// `SEQUENCE` with a preamble that is not extensible.
+fn generate_seq_extension_codec_tokens(
+    additions: &[ExtensionAddition],
+) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    let mut decode_arms = vec![];
+    let mut encode_tokens = vec![];
+    for addition in additions {
+        let idx = addition.idx;
+        let opt_count = addition.optional_count();
+
+        let mut comp_decode_tokens = vec![];
+        let mut comp_encode_tokens = vec![];
+        let mut group_hdr_encode_tokens = vec![];
+        let mut presence_tokens = vec![];
+        for component in &addition.components {
+            let id = &component.id;
+            let ty = &component.ty;
+            presence_tokens.push(quote! { self.#id.is_some() });
+            match component.optional_idx {
+                Some(ref optidx) => {
+                    comp_decode_tokens.push(quote! {
+                        if group_bitmap[#optidx] {
+                            decoded.#id = Some(#ty::oer_decode(&mut addition)?);
+                        }
+                    });
+                    group_hdr_encode_tokens.push(quote! {
+                        if self.#id.is_some() {
+                            group_bitmap.set(#optidx, true);
+                        }
+                    });
+                    comp_encode_tokens.push(quote! {
+                        if let Some(ref #id) = self.#id {
+                            #id.oer_encode(&mut addition)?;
+                        }
+                    });
+                }
+                None => {
+                    comp_decode_tokens.push(quote! {
+                        decoded.#id = Some(#ty::oer_decode(&mut addition)?);
+                    });
+                    let id_str = id.to_string();
+                    comp_encode_tokens.push(quote! {
+                        match self.#id {
+                            Some(ref #id) => #id.oer_encode(&mut addition)?,
+                            None => {
+                                return Err(asn1_codecs::OerCodecError::new(concat!(
+                                    "Mandatory component '",
+                                    #id_str,
+                                    "' of the Extension Addition is missing."
+                                )));
+                            }
+                        }
+                    });
+                }
+            }
+        }
+
+        let (group_hdr_decode_tokens, group_hdr_encode) = if opt_count > 0 {
+            (
+                quote! {
+                    let (group_bitmap, _) = asn1_codecs::oer::decode::decode_sequence_preamble(&mut addition, false, #opt_count)?;
+                },
+                quote! {
+                    let mut group_bitmap = bitvec::bitvec![u8, bitvec::prelude::Msb0; 0; #opt_count];
+                    #(#group_hdr_encode_tokens)*
+                    asn1_codecs::oer::encode::encode_sequence_preamble(&mut addition, false, &group_bitmap, false)?;
+                },
+            )
+        } else {
+            (quote! {}, quote! {})
+        };
+
+        decode_arms.push(quote! {
+            #idx => {
+                #group_hdr_decode_tokens
+                #(#comp_decode_tokens)*
+            }
+        });
+        encode_tokens.push(quote! {
+            if #(#presence_tokens)||* {
+                extensions.set(#idx, true);
+                let mut addition = asn1_codecs::OerCodecData::new();
+                #group_hdr_encode
+                #(#comp_encode_tokens)*
+                additions.push(addition);
+            }
+        });
+    }
+
+    let ext_count = additions.iter().map(|a| a.idx).max().unwrap_or_default() + 1;
+
+    let decode_tokens = quote! {
+        if extensions_present {
+            let extensions = asn1_codecs::oer::decode::decode_extension_bitmap(data)?;
+            for idx in extensions.iter_ones() {
+                let mut addition = asn1_codecs::oer::decode::decode_open_type(data)?;
+                match idx {
+                    #(#decode_arms)*
+                    _ => {}
+                }
+            }
+        }
+    };
+    let encode_tokens = quote! {
+        let mut extensions = bitvec::bitvec![u8, bitvec::prelude::Msb0; 0; #ext_count];
+        let mut additions = vec![];
+        #(#encode_tokens)*
+        let extended = extensions.any();
+    };
+
+    (decode_tokens, encode_tokens)
+}
@@ -0,0 +1,67 @@
+//! `OER` Code generation for ASN.1 SEQUENCE OF Type
+
+use quote::quote;
+
+use crate::{attrs::TyCodecParams, utils};
+
+pub(super) fn generate_oer_codec_for_asn_sequence_of(
+    ast: &syn::DeriveInput,
+    _params: &TyCodecParams,
+) -> proc_macro::TokenStream {
+    let name = &ast.ident;
+
+    let ty = if let syn::Data::Struct(ref d) = &ast.data {
+        match d.fields {
+            syn::Fields::Unnamed(ref f) => {
+                if f.unnamed.len() == 1 {
+                    let first = f.unnamed.first().unwrap();
+                    utils::get_inner_ty_for_vec(first)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    } else {
+        None
+    };
+
+    if ty.is_none() {
+        return syn::Error::new_spanned(ast, format!("{} Should be a Unit Struct.", name))
+            .to_compile_error()
+            .into();
+    }
+
+    let tokens = quote! {
+
+        impl asn1_codecs::oer::OerCodec for #name {
+            type Output = Self;
+
+            fn oer_decode(data: &mut asn1_codecs::OerCodecData) -> Result<Self::Output, asn1_codecs::OerCodecError> {
+                log::trace!(concat!("decode: ", stringify!(#name)));
+
+                let quantity = asn1_codecs::oer::decode::decode_sequence_of_quantity(data)?;
+
+                let mut items = vec![];
+                for _ in 0..quantity {
+                    items.push(#ty::oer_decode(data)?);
+                }
+
+                Ok(Self(items))
+            }
+
+            fn oer_encode(&self, data: &mut asn1_codecs::OerCodecData) -> Result<(), asn1_codecs::OerCodecError> {
+                log::trace!(concat!("encode: ", stringify!(#name)));
+
+                asn1_codecs::oer::encode::encode_sequence_of_quantity(data, self.0.len())?;
+
+                for elem in &self.0 {
+                    elem.oer_encode(data)?;
+                }
+                Ok(())
+            }
+        }
+    };
+
+    tokens.into()
+}
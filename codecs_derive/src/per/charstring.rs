@@ -29,13 +29,43 @@ pub(super) fn generate_aper_codec_for_asn_charstring(
             syn::Ident::new("encode_visible_string", Span::call_site()),
         ),
 
+        "IA5String" => (
+            syn::Ident::new("decode_ia5_string", Span::call_site()),
+            syn::Ident::new("encode_ia5_string", Span::call_site()),
+        ),
+
+        "NumericString" => (
+            syn::Ident::new("decode_numeric_string", Span::call_site()),
+            syn::Ident::new("encode_numeric_string", Span::call_site()),
+        ),
+
+        "BMPString" => (
+            syn::Ident::new("decode_bmp_string", Span::call_site()),
+            syn::Ident::new("encode_bmp_string", Span::call_site()),
+        ),
+
+        "UniversalString" => (
+            syn::Ident::new("decode_universal_string", Span::call_site()),
+            syn::Ident::new("encode_universal_string", Span::call_site()),
+        ),
+
+        "TeletexString" | "T61String" => (
+            syn::Ident::new("decode_teletex_string", Span::call_site()),
+            syn::Ident::new("encode_teletex_string", Span::call_site()),
+        ),
+
+        "GeneralString" => (
+            syn::Ident::new("decode_general_string", Span::call_site()),
+            syn::Ident::new("encode_general_string", Span::call_site()),
+        ),
+
         _ => (
             syn::Ident::new("unsupported", Span::call_site()),
             syn::Ident::new("unsupported", Span::call_site()),
         ),
     };
 
-    if decode_fn_name == "unspported" {
+    if decode_fn_name == "unsupported" {
         return syn::Error::new_spanned(ty_attr, "Character String Type is not supported.")
             .to_compile_error()
             .into();
@@ -126,6 +156,25 @@ pub(super) fn generate_aper_codec_for_asn_charstring(
         }
     };
 
+    // A PermittedAlphabet constraint is PER-visible only for the known-multiplier types.
+    let known_multiplier = !matches!(
+        ty_attr.value().as_str(),
+        "UTF8String" | "TeletexString" | "T61String" | "GeneralString"
+    );
+    let (decode_tokens, encode_tokens) = match params.alphabet.as_ref() {
+        Some(alphabet) if known_multiplier => {
+            let codec = if aligned { quote!(aper) } else { quote!(uper) };
+            (
+                quote!(asn1_codecs::#codec::decode::decode_permitted_alphabet_string(data, #sz_lb, #sz_ub, #sz_ext, #alphabet)),
+                quote!(asn1_codecs::#codec::encode::encode_permitted_alphabet_string(data, #sz_lb, #sz_ub, #sz_ext, &self.0, false, #alphabet)),
+            )
+        }
+        _ => (
+            quote!(#ty_decode_path(data, #sz_lb, #sz_ub, #sz_ext)),
+            quote!(#ty_encode_path(data, #sz_lb, #sz_ub, #sz_ext, &self.0, false)),
+        ),
+    };
+
     let tokens = quote! {
 
         impl #codec_path for #name {
@@ -134,14 +183,14 @@ pub(super) fn generate_aper_codec_for_asn_charstring(
             fn #codec_decode_fn(data: &mut asn1_codecs::PerCodecData) -> Result<Self::Output, asn1_codecs::PerCodecError> {
                 log::trace!(concat!("decode: ", stringify!(#name)));
 
-                let decoded = #ty_decode_path(data, #sz_lb, #sz_ub, #sz_ext)?;
+                let decoded = #decode_tokens?;
                 Ok(Self(decoded))
             }
 
             fn #codec_encode_fn(&self, data: &mut asn1_codecs::PerCodecData) -> Result<(), asn1_codecs::PerCodecError> {
                 log::trace!(concat!("encode: ", stringify!(#name)));
 
-                #ty_encode_path(data, #sz_lb, #sz_ub, #sz_ext, &self.0, false)
+                #encode_tokens
             }
         }
     };
@@ -4,6 +4,23 @@ use proc_macro::TokenStream;
 use quote::quote;
 
 use crate::attrs::{parse_fld_meta_as_codec_params, TyCodecParams};
+use crate::utils::is_unknown_variant;
+
+struct VariantTokens {
+    decode_tokens: Vec<proc_macro2::TokenStream>,
+    ext_decode_tokens: Vec<proc_macro2::TokenStream>,
+    encode_tokens: Vec<proc_macro2::TokenStream>,
+    unknown_decode_token: Option<proc_macro2::TokenStream>,
+}
+
+struct CodecPaths {
+    codec_new_perdata_path: proc_macro2::TokenStream,
+    codec_from_slice_path: proc_macro2::TokenStream,
+    codec_encode_fn: proc_macro2::TokenStream,
+    codec_decode_fn: proc_macro2::TokenStream,
+    choice_encode_path: proc_macro2::TokenStream,
+    open_type_encode_path: proc_macro2::TokenStream,
+}
 
 pub(super) fn generate_aper_codec_for_asn_choice(
     ast: &syn::DeriveInput,
@@ -12,41 +29,80 @@ pub(super) fn generate_aper_codec_for_asn_choice(
 ) -> proc_macro::TokenStream {
     let name = &ast.ident;
 
-    let (codec_path, codec_encode_fn, codec_decode_fn, ty_encode_path, ty_decode_path) = if aligned
-    {
+    let (codec_path, ty_decode_path, open_type_decode_path, paths) = if aligned {
         (
             quote!(asn1_codecs::aper::AperCodec),
-            quote!(aper_encode),
-            quote!(aper_decode),
-            quote!(asn1_codecs::aper::encode::encode_choice_idx),
             quote!(asn1_codecs::aper::decode::decode_choice_idx),
+            quote!(asn1_codecs::aper::decode::decode_open_type),
+            CodecPaths {
+                codec_new_perdata_path: quote!(asn1_codecs::PerCodecData::new_aper),
+                codec_from_slice_path: quote!(asn1_codecs::PerCodecData::from_slice_aper),
+                codec_encode_fn: quote!(aper_encode),
+                codec_decode_fn: quote!(aper_decode),
+                choice_encode_path: quote!(asn1_codecs::aper::encode::encode_choice_idx),
+                open_type_encode_path: quote!(asn1_codecs::aper::encode::encode_open_type),
+            },
         )
     } else {
         (
             quote!(asn1_codecs::uper::UperCodec),
-            quote!(uper_encode),
-            quote!(uper_decode),
-            quote!(asn1_codecs::uper::encode::encode_choice_idx),
             quote!(asn1_codecs::uper::decode::decode_choice_idx),
+            quote!(asn1_codecs::uper::decode::decode_open_type),
+            CodecPaths {
+                codec_new_perdata_path: quote!(asn1_codecs::PerCodecData::new_uper),
+                codec_from_slice_path: quote!(asn1_codecs::PerCodecData::from_slice_uper),
+                codec_encode_fn: quote!(uper_encode),
+                codec_decode_fn: quote!(uper_decode),
+                choice_encode_path: quote!(asn1_codecs::uper::encode::encode_choice_idx),
+                open_type_encode_path: quote!(asn1_codecs::uper::encode::encode_open_type),
+            },
         )
     };
+    let codec_encode_fn = &paths.codec_encode_fn;
+    let codec_decode_fn = &paths.codec_decode_fn;
+
     let lb = params.lb.as_ref().unwrap().value().parse::<i128>().unwrap();
     let ub = params.ub.as_ref().unwrap().value().parse::<i128>().unwrap();
     let ext = params.ext.as_ref();
 
-    let variant_tokens = generate_choice_variant_decode_tokens_using_attrs(
-        ast,
-        lb,
-        ub,
-        ext,
-        codec_encode_fn.clone(),
-        codec_decode_fn.clone(),
-        ty_encode_path,
-    );
+    let variant_tokens =
+        generate_choice_variant_decode_tokens_using_attrs(ast, lb, ub, ext, &paths);
     if variant_tokens.is_err() {
         return variant_tokens.err().unwrap().to_compile_error().into();
     }
-    let (variant_decode_tokens, variant_encode_tokens) = variant_tokens.unwrap();
+    let VariantTokens {
+        decode_tokens: variant_decode_tokens,
+        ext_decode_tokens: variant_ext_decode_tokens,
+        encode_tokens: variant_encode_tokens,
+        unknown_decode_token,
+    } = variant_tokens.unwrap();
+
+    // The value of an alternative that is not in the extension root is an open type. An
+    // alternative that is not known to us is either kept as it is or is an error.
+    let unknown_error_token = quote! {
+        Err(asn1_codecs::PerCodecError::new(format!("Index {} is not a known Extension Index of the Choice", idx).as_str()))
+    };
+    let ext_decode_tokens = match unknown_decode_token {
+        None if variant_ext_decode_tokens.is_empty() => unknown_error_token,
+        None => quote! {
+            let mut value = #open_type_decode_path(data)?;
+            match idx {
+                #(#variant_ext_decode_tokens)*
+                _ => #unknown_error_token
+            }
+        },
+        Some(unknown_decode_token) if variant_ext_decode_tokens.is_empty() => quote! {
+            let value = #open_type_decode_path(data)?;
+            #unknown_decode_token
+        },
+        Some(unknown_decode_token) => quote! {
+            let mut value = #open_type_decode_path(data)?;
+            match idx {
+                #(#variant_ext_decode_tokens)*
+                _ => #unknown_decode_token
+            }
+        },
+    };
 
     let tokens = quote! {
 
@@ -63,7 +119,7 @@ pub(super) fn generate_aper_codec_for_asn_choice(
                         _ => Err(asn1_codecs::PerCodecError::new(format!("Index {} is not a valid Choice Index", idx).as_str()))
                     }
                 } else {
-                    Err(asn1_codecs::PerCodecError::new("CHOICE Additions not supported yet."))
+                    #ext_decode_tokens
                 }
             }
 
@@ -85,12 +141,21 @@ fn generate_choice_variant_decode_tokens_using_attrs(
     lb: i128,
     ub: i128,
     ext: Option<&syn::LitBool>,
-    codec_encode_fn: proc_macro2::TokenStream,
-    codec_decode_fn: proc_macro2::TokenStream,
-    choice_encode_path: proc_macro2::TokenStream,
-) -> Result<(Vec<proc_macro2::TokenStream>, Vec<proc_macro2::TokenStream>), syn::Error> {
+    paths: &CodecPaths,
+) -> Result<VariantTokens, syn::Error> {
+    let CodecPaths {
+        codec_new_perdata_path,
+        codec_from_slice_path,
+        codec_encode_fn,
+        codec_decode_fn,
+        choice_encode_path,
+        open_type_encode_path,
+    } = paths;
+
     let mut decode_tokens = vec![];
+    let mut ext_decode_tokens = vec![];
     let mut encode_tokens = vec![];
+    let mut unknown_decode_token = None;
 
     let mut errors = vec![];
     if let syn::Data::Enum(ref data) = ast.data {
@@ -99,6 +164,27 @@ fn generate_choice_variant_decode_tokens_using_attrs(
             match codec_params {
                 Err(e) => errors.push(e),
                 Ok(cp) => {
+                    match is_unknown_variant(variant, &cp) {
+                        Err(e) => {
+                            errors.push(e);
+                            continue;
+                        }
+                        Ok(true) => {
+                            let variant_ident = &variant.ident;
+                            unknown_decode_token.replace(quote! {
+                                Ok(Self::#variant_ident(idx, value.into_bytes()))
+                            });
+                            encode_tokens.push(quote! {
+                                Self::#variant_ident(idx, ref bytes) => {
+                                    #choice_encode_path(data, #lb, #ub, #ext, *idx, true)?;
+                                    #open_type_encode_path(data, #codec_from_slice_path(bytes))
+                                }
+                            });
+                            continue;
+                        }
+                        Ok(false) => {}
+                    }
+
                     let key = cp.key.as_ref();
                     if key.is_none() {
                         errors.push(syn::Error::new_spanned(
@@ -107,22 +193,34 @@ fn generate_choice_variant_decode_tokens_using_attrs(
                     ));
                         continue;
                     }
-                    let extended = cp.extended.as_ref();
+                    let extended = cp.extended.as_ref().map(|e| e.value()).unwrap_or_default();
                     let variant_ident = &variant.ident;
                     if let syn::Fields::Unnamed(ref fields) = variant.fields {
                         if fields.unnamed.len() == 1 {
                             let ty = &fields.unnamed.first().as_ref().unwrap().ty;
-                            let variant_decode_token = quote! {
-                                #key => Ok(Self::#variant_ident(#ty::#codec_decode_fn(data)?)),
-                            };
-                            let variant_encode_token = quote! {
-                                Self::#variant_ident(ref v) => {
-                                    #choice_encode_path(data, #lb, #ub, #ext, #key, #extended)?;
-                                    v.#codec_encode_fn(data)
-                                }
-                            };
-                            decode_tokens.push(variant_decode_token);
-                            encode_tokens.push(variant_encode_token);
+                            if extended {
+                                ext_decode_tokens.push(quote! {
+                                    #key => Ok(Self::#variant_ident(#ty::#codec_decode_fn(&mut value)?)),
+                                });
+                                encode_tokens.push(quote! {
+                                    Self::#variant_ident(ref v) => {
+                                        #choice_encode_path(data, #lb, #ub, #ext, #key, true)?;
+                                        let mut value = #codec_new_perdata_path();
+                                        v.#codec_encode_fn(&mut value)?;
+                                        #open_type_encode_path(data, value)
+                                    }
+                                });
+                            } else {
+                                decode_tokens.push(quote! {
+                                    #key => Ok(Self::#variant_ident(#ty::#codec_decode_fn(data)?)),
+                                });
+                                encode_tokens.push(quote! {
+                                    Self::#variant_ident(ref v) => {
+                                        #choice_encode_path(data, #lb, #ub, #ext, #key, false)?;
+                                        v.#codec_encode_fn(data)
+                                    }
+                                });
+                            }
                         } else {
                             errors.push(syn::Error::new_spanned(
                                 variant,
@@ -141,6 +239,11 @@ fn generate_choice_variant_decode_tokens_using_attrs(
         }
         Err(first.clone())
     } else {
-        Ok((decode_tokens, encode_tokens))
+        Ok(VariantTokens {
+            decode_tokens,
+            ext_decode_tokens,
+            encode_tokens,
+            unknown_decode_token,
+        })
     }
 }
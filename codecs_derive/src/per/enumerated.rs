@@ -53,6 +53,38 @@ pub(super) fn generate_aper_codec_for_asn_enumerated(
 
     let (lb, ub, ext) = utils::get_bounds_extensible_from_params(params);
 
+    // The root values are the values from `lb` to `ub`. Values that are not in the extension root
+    // are held after the root values. ie. the first extension value is `ub + 1` and so on. This
+    // keeps the values of the extension additions (including those not known to us) distinct from
+    // the root values.
+    let first_ext_value = match params.ub {
+        Some(ref ub) => match ub.value().parse::<i128>() {
+            Ok(ub) => ub + 1,
+            Err(_) => {
+                return syn::Error::new_spanned(ub, "`ub` of an ENUMERATED should be an integer.")
+                    .to_compile_error()
+                    .into();
+            }
+        },
+        None => 0,
+    };
+
+    let is_extensible = params.ext.as_ref().map(|e| e.value()).unwrap_or_default();
+    let encode_tokens = if is_extensible {
+        quote! {
+            let value = self.0 as i128;
+            if value >= #first_ext_value {
+                #ty_encode_path(data, #lb, #ub, #ext, value - #first_ext_value, true)
+            } else {
+                #ty_encode_path(data, #lb, #ub, #ext, value, false)
+            }
+        }
+    } else {
+        quote! {
+            #ty_encode_path(data, #lb, #ub, #ext, self.0 as i128, false)
+        }
+    };
+
     let tokens = quote! {
 
         impl #codec_path for #name {
@@ -61,15 +93,21 @@ pub(super) fn generate_aper_codec_for_asn_enumerated(
             fn #codec_decode_fn(data: &mut asn1_codecs::PerCodecData) -> Result<Self::Output, asn1_codecs::PerCodecError> {
                 log::trace!(concat!("decode: ", stringify!(#name)));
 
-                let decoded = #ty_decode_path(data, #lb, #ub, #ext)?;
+                let (value, extended) = #ty_decode_path(data, #lb, #ub, #ext)?;
+                let value = if extended { value + #first_ext_value } else { value };
+
+                // An extension value not known to us may not fit in the type.
+                let value = <#ty as std::convert::TryFrom<i128>>::try_from(value).map_err(|_| {
+                    asn1_codecs::PerCodecError::new(format!("Value {} out of range for the ENUMERATED {}", value, stringify!(#name)).as_str())
+                })?;
 
-                Ok(Self(decoded.0 as #ty))
+                Ok(Self(value))
             }
 
             fn #codec_encode_fn(&self, data: &mut asn1_codecs::PerCodecData) -> Result<(), asn1_codecs::PerCodecError> {
                 log::trace!(concat!("encode: ", stringify!(#name)));
 
-                #ty_encode_path(data, #lb, #ub, #ext, self.0 as i128, false)
+                #encode_tokens
             }
         }
     };
@@ -12,8 +12,10 @@ mod null;
 mod octetstring;
 mod oid;
 mod open;
+mod real;
 mod seq;
 mod seqof;
+mod time;
 
 pub(crate) fn generate_codec(
     ast: &syn::DeriveInput,
@@ -30,15 +32,23 @@ pub(crate) fn generate_codec(
         "OCTET-STRING" => {
             octetstring::generate_aper_codec_for_asn_octetstring(ast, params, aligned)
         }
-        "UTF8String" | "PrintableString" | "VisibleString" => {
+        "UTF8String" | "PrintableString" | "VisibleString" | "IA5String" | "NumericString"
+        | "BMPString" | "UniversalString" | "TeletexString" | "T61String" | "GeneralString" => {
             charstring::generate_aper_codec_for_asn_charstring(ast, params, aligned)
         }
+        "UTCTime" | "GeneralizedTime" | "DATE" | "TIME-OF-DAY" | "DATE-TIME" | "DURATION" => {
+            time::generate_aper_codec_for_asn_time(ast, params, aligned)
+        }
         "NULL" => null::generate_aper_codec_for_asn_null(ast, params, aligned),
-        "SEQUENCE" => seq::generate_aper_codec_for_asn_sequence(ast, params, aligned),
+        "REAL" => real::generate_aper_codec_for_asn_real(ast, params, aligned),
+        "SEQUENCE" | "SET" => seq::generate_aper_codec_for_asn_sequence(ast, params, aligned),
         "OPEN" => open::generate_aper_codec_for_asn_open_type(ast, params, aligned),
         "SEQUENCE-OF" => seqof::generate_aper_codec_for_asn_sequence_of(ast, params, aligned),
         "OBJECT-IDENTIFIER" => {
-            oid::generate_aper_codec_for_asn_object_identifier(ast, params, aligned)
+            oid::generate_aper_codec_for_asn_object_identifier(ast, params, aligned, false)
+        }
+        "RELATIVE-OID" => {
+            oid::generate_aper_codec_for_asn_object_identifier(ast, params, aligned, true)
         }
         _ => syn::Error::new_spanned(ty.clone(), "This ASN.1 Type is not supported.")
             .to_compile_error()
@@ -1,4 +1,4 @@
-//! Handling of ASN.1 NULL Type
+//! `APER` Code generation for ASN.1 OBJECT IDENTIFIER and RELATIVE-OID Types
 
 use quote::quote;
 
@@ -8,37 +8,58 @@ pub(super) fn generate_aper_codec_for_asn_object_identifier(
     ast: &syn::DeriveInput,
     _params: &TyCodecParams,
     aligned: bool,
+    relative: bool,
 ) -> proc_macro::TokenStream {
     let name = &ast.ident;
 
-    let (codec_path, codec_encode_fn, codec_decode_fn) = if aligned {
-        (
-            quote!(asn1_codecs::aper::AperCodec),
-            quote!(aper_encode),
-            quote!(aper_decode),
-        )
-    } else {
-        (
-            quote!(asn1_codecs::uper::UperCodec),
-            quote!(uper_encode),
-            quote!(uper_decode),
-        )
-    };
+    let (codec_path, codec_encode_fn, codec_decode_fn, ty_encode_path, ty_decode_path) =
+        match (aligned, relative) {
+            (true, false) => (
+                quote!(asn1_codecs::aper::AperCodec),
+                quote!(aper_encode),
+                quote!(aper_decode),
+                quote!(asn1_codecs::aper::encode::encode_object_identifier),
+                quote!(asn1_codecs::aper::decode::decode_object_identifier),
+            ),
+            (true, true) => (
+                quote!(asn1_codecs::aper::AperCodec),
+                quote!(aper_encode),
+                quote!(aper_decode),
+                quote!(asn1_codecs::aper::encode::encode_relative_oid),
+                quote!(asn1_codecs::aper::decode::decode_relative_oid),
+            ),
+            (false, false) => (
+                quote!(asn1_codecs::uper::UperCodec),
+                quote!(uper_encode),
+                quote!(uper_decode),
+                quote!(asn1_codecs::uper::encode::encode_object_identifier),
+                quote!(asn1_codecs::uper::decode::decode_object_identifier),
+            ),
+            (false, true) => (
+                quote!(asn1_codecs::uper::UperCodec),
+                quote!(uper_encode),
+                quote!(uper_decode),
+                quote!(asn1_codecs::uper::encode::encode_relative_oid),
+                quote!(asn1_codecs::uper::decode::decode_relative_oid),
+            ),
+        };
+
     let tokens = quote! {
 
         impl #codec_path for #name {
 
             type Output = Self;
 
-            fn #codec_decode_fn(_data: &mut asn1_codecs::PerCodecData) -> Result<Self::Output, asn1_codecs::PerCodecError> {
+            fn #codec_decode_fn(data: &mut asn1_codecs::PerCodecData) -> Result<Self::Output, asn1_codecs::PerCodecError> {
                 log::trace!(concat!("decode: ", stringify!(#name)));
 
-                Err(asn1_codecs::PerCodecError::new("Object Identifier Decode Not Supported!"))
+                Ok(Self(#ty_decode_path(data)?))
             }
-            fn #codec_encode_fn(&self, _data: &mut asn1_codecs::PerCodecData) -> Result<(), asn1_codecs::PerCodecError> {
+
+            fn #codec_encode_fn(&self, data: &mut asn1_codecs::PerCodecData) -> Result<(), asn1_codecs::PerCodecError> {
                 log::trace!(concat!("encode: ", stringify!(#name)));
 
-                Err(asn1_codecs::PerCodecError::new("Object Identifier Encode Not Supported!"))
+                #ty_encode_path(data, &self.0)
             }
         }
     };
@@ -3,6 +3,13 @@
 use quote::quote;
 
 use crate::attrs::{parse_fld_meta_as_codec_params, TyCodecParams};
+use crate::utils::is_unknown_variant;
+
+struct VariantTokens {
+    decode_tokens: Vec<proc_macro2::TokenStream>,
+    encode_tokens: Vec<proc_macro2::TokenStream>,
+    unknown_decode_token: Option<proc_macro2::TokenStream>,
+}
 
 pub(super) fn generate_aper_codec_for_asn_open_type(
     ast: &syn::DeriveInput,
@@ -14,6 +21,7 @@ pub(super) fn generate_aper_codec_for_asn_open_type(
     let (
         codec_path,
         codec_new_perdata_path,
+        codec_from_slice_path,
         codec_encode_fn,
         codec_decode_fn,
         ty_encode_path,
@@ -22,6 +30,7 @@ pub(super) fn generate_aper_codec_for_asn_open_type(
         (
             quote!(asn1_codecs::aper::AperCodec),
             quote!(asn1_codecs::PerCodecData::new_aper),
+            quote!(asn1_codecs::PerCodecData::from_slice_aper),
             quote!(aper_encode),
             quote!(aper_decode),
             quote!(asn1_codecs::aper::encode::encode_length_determinent),
@@ -31,6 +40,7 @@ pub(super) fn generate_aper_codec_for_asn_open_type(
         (
             quote!(asn1_codecs::uper::UperCodec),
             quote!(asn1_codecs::PerCodecData::new_uper),
+            quote!(asn1_codecs::PerCodecData::from_slice_uper),
             quote!(uper_encode),
             quote!(uper_decode),
             quote!(asn1_codecs::uper::encode::encode_length_determinent),
@@ -41,11 +51,21 @@ pub(super) fn generate_aper_codec_for_asn_open_type(
         ast,
         codec_encode_fn.clone(),
         codec_decode_fn.clone(),
+        codec_from_slice_path,
     );
     if variant_tokens.is_err() {
         return variant_tokens.err().unwrap().to_compile_error().into();
     }
-    let (variant_decode_tokens, variant_encode_tokens) = variant_tokens.unwrap();
+    let VariantTokens {
+        decode_tokens: variant_decode_tokens,
+        encode_tokens: variant_encode_tokens,
+        unknown_decode_token,
+    } = variant_tokens.unwrap();
+    let unknown_decode_token = unknown_decode_token.unwrap_or_else(|| {
+        quote! {
+            _ => Err(asn1_codecs::PerCodecError::new(format!("Key {} Not Found", key).as_str()))
+        }
+    });
 
     let encode_tokens = if !variant_encode_tokens.is_empty() {
         quote! {
@@ -83,7 +103,7 @@ pub(super) fn generate_aper_codec_for_asn_open_type(
 
                 match key {
                     #(#variant_decode_tokens)*
-                    _ => Err(asn1_codecs::PerCodecError::new(format!("Key {} Not Found", key).as_str()))
+                    #unknown_decode_token
                 }
             }
 
@@ -102,9 +122,11 @@ fn generate_open_type_variant_tokens_using_attrs(
     ast: &syn::DeriveInput,
     codec_encode_fn: proc_macro2::TokenStream,
     codec_decode_fn: proc_macro2::TokenStream,
-) -> Result<(Vec<proc_macro2::TokenStream>, Vec<proc_macro2::TokenStream>), syn::Error> {
+    codec_from_slice_path: proc_macro2::TokenStream,
+) -> Result<VariantTokens, syn::Error> {
     let mut decode_tokens = vec![];
     let mut encode_tokens = vec![];
+    let mut unknown_decode_token = None;
 
     let mut errors = vec![];
     if let syn::Data::Enum(ref data) = ast.data {
@@ -113,6 +135,25 @@ fn generate_open_type_variant_tokens_using_attrs(
             match codec_params {
                 Err(e) => errors.push(e),
                 Ok(cp) => {
+                    // The value for a `key` not known to us is kept as it's encoding.
+                    match is_unknown_variant(variant, &cp) {
+                        Err(e) => {
+                            errors.push(e);
+                            continue;
+                        }
+                        Ok(true) => {
+                            let variant_ident = &variant.ident;
+                            unknown_decode_token.replace(quote! {
+                                _ => Ok(Self::#variant_ident(key, data.get_bytes(length)?)),
+                            });
+                            encode_tokens.push(quote! {
+                                Self::#variant_ident(_, ref bytes) => inner = #codec_from_slice_path(bytes),
+                            });
+                            continue;
+                        }
+                        Ok(false) => {}
+                    }
+
                     let key = cp.key.as_ref();
                     if key.is_none() {
                         errors.push(syn::Error::new_spanned(
@@ -153,6 +194,10 @@ fn generate_open_type_variant_tokens_using_attrs(
         }
         Err(first.clone())
     } else {
-        Ok((decode_tokens, encode_tokens))
+        Ok(VariantTokens {
+            decode_tokens,
+            encode_tokens,
+            unknown_decode_token,
+        })
     }
 }
@@ -3,11 +3,30 @@
 use quote::quote;
 
 use crate::attrs::{parse_fld_meta_as_codec_params, TyCodecParams};
+use crate::utils::{
+    get_default_fn, get_extension_component, get_field_type, group_extension_components,
+    is_unknown_extensions_field, ExtensionAddition,
+};
 
 struct FieldTokens {
     decode_tokens: Vec<proc_macro2::TokenStream>,
     encode_tokens: Vec<proc_macro2::TokenStream>,
     hdr_encode_tokens: Vec<proc_macro2::TokenStream>,
+    additions: Vec<ExtensionAddition>,
+    unknown_extensions: Option<syn::Ident>,
+}
+
+struct CodecPaths {
+    codec_new_perdata_path: proc_macro2::TokenStream,
+    codec_from_slice_path: proc_macro2::TokenStream,
+    codec_encode_fn: proc_macro2::TokenStream,
+    codec_decode_fn: proc_macro2::TokenStream,
+    ty_encode_path: proc_macro2::TokenStream,
+    ty_decode_path: proc_macro2::TokenStream,
+    ext_bitmap_encode_path: proc_macro2::TokenStream,
+    ext_bitmap_decode_path: proc_macro2::TokenStream,
+    open_type_encode_path: proc_macro2::TokenStream,
+    open_type_decode_path: proc_macro2::TokenStream,
 }
 
 pub(super) fn generate_aper_codec_for_asn_sequence(
@@ -17,37 +36,66 @@ pub(super) fn generate_aper_codec_for_asn_sequence(
 ) -> proc_macro::TokenStream {
     let name = &ast.ident;
 
-    let (codec_path, codec_encode_fn, codec_decode_fn, ty_encode_path, ty_decode_path) = if aligned
-    {
+    let (codec_path, paths) = if aligned {
         (
             quote!(asn1_codecs::aper::AperCodec),
-            quote!(aper_encode),
-            quote!(aper_decode),
-            quote!(asn1_codecs::aper::encode::encode_sequence_header),
-            quote!(asn1_codecs::aper::decode::decode_sequence_header),
+            CodecPaths {
+                codec_new_perdata_path: quote!(asn1_codecs::PerCodecData::new_aper),
+                codec_from_slice_path: quote!(asn1_codecs::PerCodecData::from_slice_aper),
+                codec_encode_fn: quote!(aper_encode),
+                codec_decode_fn: quote!(aper_decode),
+                ty_encode_path: quote!(asn1_codecs::aper::encode::encode_sequence_header),
+                ty_decode_path: quote!(asn1_codecs::aper::decode::decode_sequence_header),
+                ext_bitmap_encode_path: quote!(
+                    asn1_codecs::aper::encode::encode_sequence_extension_bitmap
+                ),
+                ext_bitmap_decode_path: quote!(
+                    asn1_codecs::aper::decode::decode_sequence_extension_bitmap
+                ),
+                open_type_encode_path: quote!(asn1_codecs::aper::encode::encode_open_type),
+                open_type_decode_path: quote!(asn1_codecs::aper::decode::decode_open_type),
+            },
         )
     } else {
         (
             quote!(asn1_codecs::uper::UperCodec),
-            quote!(uper_encode),
-            quote!(uper_decode),
-            quote!(asn1_codecs::uper::encode::encode_sequence_header),
-            quote!(asn1_codecs::uper::decode::decode_sequence_header),
+            CodecPaths {
+                codec_new_perdata_path: quote!(asn1_codecs::PerCodecData::new_uper),
+                codec_from_slice_path: quote!(asn1_codecs::PerCodecData::from_slice_uper),
+                codec_encode_fn: quote!(uper_encode),
+                codec_decode_fn: quote!(uper_decode),
+                ty_encode_path: quote!(asn1_codecs::uper::encode::encode_sequence_header),
+                ty_decode_path: quote!(asn1_codecs::uper::decode::decode_sequence_header),
+                ext_bitmap_encode_path: quote!(
+                    asn1_codecs::uper::encode::encode_sequence_extension_bitmap
+                ),
+                ext_bitmap_decode_path: quote!(
+                    asn1_codecs::uper::decode::decode_sequence_extension_bitmap
+                ),
+                open_type_encode_path: quote!(asn1_codecs::uper::encode::encode_open_type),
+                open_type_decode_path: quote!(asn1_codecs::uper::decode::decode_open_type),
+            },
         )
     };
+    let CodecPaths {
+        ref codec_encode_fn,
+        ref codec_decode_fn,
+        ref ty_encode_path,
+        ref ty_decode_path,
+        ref ext_bitmap_encode_path,
+        ref open_type_encode_path,
+        ..
+    } = paths;
 
     let ext = params.ext.as_ref();
+    let is_extensible = ext.map(|e| e.value()).unwrap_or_default();
     let opt_count = if params.optional_fields.is_some() {
         params.optional_fields.as_ref().unwrap().clone()
     } else {
         syn::LitInt::new("0", proc_macro2::Span::call_site())
     };
 
-    let field_tokens = generate_seq_field_codec_tokens_using_attrs(
-        ast,
-        codec_encode_fn.clone(),
-        codec_decode_fn.clone(),
-    );
+    let field_tokens = generate_seq_field_codec_tokens_using_attrs(ast, &paths);
     if field_tokens.is_err() {
         return field_tokens.err().unwrap().to_compile_error().into();
     }
@@ -55,6 +103,86 @@ pub(super) fn generate_aper_codec_for_asn_sequence(
     let fld_decode_tokens = field_tokens.decode_tokens;
     let hdr_encode_tokens = field_tokens.hdr_encode_tokens;
     let fld_encode_tokens = field_tokens.encode_tokens;
+    let additions = field_tokens.additions;
+    let unknown_extensions = field_tokens.unknown_extensions;
+
+    if !is_extensible && (!additions.is_empty() || unknown_extensions.is_some()) {
+        return syn::Error::new_spanned(
+            &ast.ident,
+            "Extension Additions are present in a SEQUENCE that is not extensible.",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let (ext_decode_tokens, ext_encode_tokens) = if additions.is_empty()
+        && unknown_extensions.is_none()
+    {
+        // Extension Additions, if any, present in the encoding are not known to us and are
+        // skipped.
+        let skip_additions_tokens = if is_extensible {
+            let CodecPaths {
+                ref ext_bitmap_decode_path,
+                ref open_type_decode_path,
+                ..
+            } = paths;
+            quote! {
+                if extensions_present {
+                    let extensions = #ext_bitmap_decode_path(data)?;
+                    for _ in extensions.iter_ones() {
+                        let _ = #open_type_decode_path(data)?;
+                    }
+                }
+            }
+        } else {
+            quote! {}
+        };
+        let extensions_present = if is_extensible {
+            quote!(extensions_present)
+        } else {
+            quote!(_extensions_present)
+        };
+        (
+            quote! {
+                let (bitmap, #extensions_present) = #ty_decode_path(data, #ext, #opt_count)?;
+                let decoded = Self{#(#fld_decode_tokens)*};
+                #skip_additions_tokens
+            },
+            quote! {
+                #ty_encode_path(data, #ext, &bitmap, false)?;
+
+                #(#fld_encode_tokens)*
+            },
+        )
+    } else {
+        let (addition_decode_tokens, addition_encode_tokens) =
+            generate_seq_extension_codec_tokens(&additions, unknown_extensions.as_ref(), &paths);
+        let addition_init_tokens = additions.iter().flat_map(|a| a.components.iter()).map(|c| {
+            let id = &c.id;
+            quote! { #id: None, }
+        });
+        (
+            quote! {
+                let (bitmap, extensions_present) = #ty_decode_path(data, #ext, #opt_count)?;
+                let mut decoded = Self{#(#fld_decode_tokens)* #(#addition_init_tokens)*};
+                #addition_decode_tokens
+            },
+            quote! {
+                #addition_encode_tokens
+
+                #ty_encode_path(data, #ext, &bitmap, extended)?;
+
+                #(#fld_encode_tokens)*
+
+                if extended {
+                    #ext_bitmap_encode_path(data, &extensions)?;
+                    for addition in additions {
+                        #open_type_encode_path(data, addition)?;
+                    }
+                }
+            },
+        )
+    };
 
     let tokens = quote! {
         impl #codec_path for #name {
@@ -63,8 +191,9 @@ pub(super) fn generate_aper_codec_for_asn_sequence(
             fn #codec_decode_fn(data: &mut asn1_codecs::PerCodecData) -> Result<Self::Output, asn1_codecs::PerCodecError> {
                 log::trace!(concat!("decode: ", stringify!(#name)));
 
-                let (bitmap, _extensions_present) = #ty_decode_path(data, #ext, #opt_count)?;
-                Ok(Self{#(#fld_decode_tokens)*})
+                #ext_decode_tokens
+
+                Ok(decoded)
             }
 
             fn #codec_encode_fn(&self, data: &mut asn1_codecs::PerCodecData) -> Result<(), asn1_codecs::PerCodecError> {
@@ -74,9 +203,7 @@ pub(super) fn generate_aper_codec_for_asn_sequence(
 
                 #(#hdr_encode_tokens)*
 
-                #ty_encode_path(data, #ext, &bitmap, false)?;
-
-                #(#fld_encode_tokens)*
+                #ext_encode_tokens
 
                 Ok(())
             }
@@ -88,12 +215,20 @@ pub(super) fn generate_aper_codec_for_asn_sequence(
 
 fn generate_seq_field_codec_tokens_using_attrs(
     ast: &syn::DeriveInput,
-    codec_encode_fn: proc_macro2::TokenStream,
-    codec_decode_fn: proc_macro2::TokenStream,
+    paths: &CodecPaths,
 ) -> Result<FieldTokens, syn::Error> {
+    let CodecPaths {
+        codec_new_perdata_path,
+        codec_encode_fn,
+        codec_decode_fn,
+        ..
+    } = paths;
+
     let mut decode_tokens = vec![];
     let mut encode_tokens = vec![];
     let mut hdr_encode_tokens = vec![];
+    let mut extension_components = vec![];
+    let mut unknown_extensions = None;
 
     let mut errors: Vec<syn::Error> = vec![];
     if let syn::Data::Struct(ref data) = ast.data {
@@ -103,6 +238,25 @@ fn generate_seq_field_codec_tokens_using_attrs(
                 match codec_params {
                     Err(e) => errors.push(e),
                     Ok(cp) => {
+                        if is_unknown_extensions_field(&cp) {
+                            let id = field.ident.as_ref().unwrap();
+                            decode_tokens.push(quote! { #id: Default::default(), });
+                            unknown_extensions.replace(id.clone());
+                            continue;
+                        }
+
+                        match get_extension_component(field, &cp) {
+                            Err(e) => {
+                                errors.push(e);
+                                continue;
+                            }
+                            Ok(Some(component)) => {
+                                extension_components.push(component);
+                                continue;
+                            }
+                            Ok(None) => {}
+                        }
+
                         let field_type = get_field_type(field);
                         if field_type.ty.is_none() {
                             errors.push(syn::Error::new_spanned(
@@ -113,6 +267,13 @@ fn generate_seq_field_codec_tokens_using_attrs(
                         } else {
                             let ty_ident = field_type.ty.unwrap();
                             let optional = field_type.is_optional;
+                            let default_fn = match get_default_fn(field, &cp) {
+                                Ok(default_fn) => default_fn,
+                                Err(e) => {
+                                    errors.push(e);
+                                    continue;
+                                }
+                            };
                             let fld_decode_tokens = if optional {
                                 let optional_idx = cp.optional_idx.as_ref();
 
@@ -126,13 +287,19 @@ fn generate_seq_field_codec_tokens_using_attrs(
                                         quote! {}
                                     }
                                     Some(optidx) => {
+                                        // An absent component with a `DEFAULT` value takes the
+                                        // default value.
+                                        let absent = match default_fn {
+                                            Some(ref default_fn) => quote! { Some(#default_fn()) },
+                                            None => quote! { None },
+                                        };
                                         quote! {
                                             {
                                             let present = bitmap[#optidx];
                                             if present {
                                                 Some(#ty_ident::#codec_decode_fn(data)?)
                                             } else {
-                                                None
+                                                #absent
                                             }
                                             }
                                         }
@@ -164,7 +331,14 @@ fn generate_seq_field_codec_tokens_using_attrs(
                             };
 
                             let id = field.ident.as_ref().unwrap();
-                            let field_encode_token = if optional {
+                            let optional_idx = cp.optional_idx.as_ref();
+                            let field_encode_token = if default_fn.is_some() {
+                                quote! {
+                                    if bitmap[#optional_idx] {
+                                        self.#id.as_ref().unwrap().#codec_encode_fn(data)?;
+                                    }
+                                }
+                            } else if optional {
                                 quote! {
                                     if self.#id.is_some() {
                                         let #id = self.#id.as_ref().unwrap();
@@ -177,8 +351,21 @@ fn generate_seq_field_codec_tokens_using_attrs(
                                     self.#id.#codec_encode_fn(data)?;
                                 }
                             };
-                            let header_encode_token = if optional {
-                                let optional_idx = cp.optional_idx.as_ref();
+                            // A component with a value that is the same as it's `DEFAULT` value
+                            // is not encoded. The values are compared by their encodings.
+                            let header_encode_token = if let Some(ref default_fn) = default_fn {
+                                quote! {
+                                    if let Some(ref value) = self.#id {
+                                        let mut encoded = #codec_new_perdata_path();
+                                        value.#codec_encode_fn(&mut encoded)?;
+                                        let mut default = #codec_new_perdata_path();
+                                        #default_fn().#codec_encode_fn(&mut default)?;
+                                        if !encoded.same_encoding(&default) {
+                                            bitmap.set(#optional_idx, true);
+                                        }
+                                    }
+                                }
+                            } else if optional {
                                 quote! {
                                     if self.#id.is_some() {
                                         bitmap.set(#optional_idx, true);
@@ -209,50 +396,195 @@ fn generate_seq_field_codec_tokens_using_attrs(
             decode_tokens,
             hdr_encode_tokens,
             encode_tokens,
+            additions: group_extension_components(extension_components),
+            unknown_extensions,
         })
     }
 }
 
-struct StructFieldType {
-    ty: Option<syn::Ident>,
-    is_optional: bool,
-}
+// Generates the tokens for decoding and encoding the known extension additions. Each extension
+// addition is encoded as an open type, with the components of an extension addition group
+// preceded by the bitmap of it's `OPTIONAL` components.
+//
+// If the `unknown_extensions` field is present, the encodings of the extension additions not known
+// to us are kept in it, so that these are encoded again as they were received. The length of the
+// received bitmap is also kept (when it is different from the number of the known additions).
+fn generate_seq_extension_codec_tokens(
+    additions: &[ExtensionAddition],
+    unknown_extensions: Option<&syn::Ident>,
+    paths: &CodecPaths,
+) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    let CodecPaths {
+        codec_new_perdata_path,
+        codec_from_slice_path,
+        codec_encode_fn,
+        codec_decode_fn,
+        ty_encode_path,
+        ty_decode_path,
+        ext_bitmap_decode_path,
+        open_type_decode_path,
+        ..
+    } = paths;
 
-fn get_field_type(field: &syn::Field) -> StructFieldType {
-    fn field_is_optional(field: &syn::Field) -> bool {
-        if let syn::Type::Path(ref typepath) = field.ty {
-            typepath.path.leading_colon.is_none()
-                && typepath.path.segments.len() == 1
-                && typepath.path.segments.iter().next().unwrap().ident == "Option"
-        } else {
-            false
+    let mut decode_arms = vec![];
+    let mut encode_tokens = vec![];
+    for addition in additions {
+        let idx = addition.idx;
+        let opt_count = addition.optional_count();
+
+        let mut comp_decode_tokens = vec![];
+        let mut comp_encode_tokens = vec![];
+        let mut group_hdr_encode_tokens = vec![];
+        let mut presence_tokens = vec![];
+        for component in &addition.components {
+            let id = &component.id;
+            let ty = &component.ty;
+            presence_tokens.push(quote! { self.#id.is_some() });
+            match component.optional_idx {
+                Some(ref optidx) => {
+                    comp_decode_tokens.push(quote! {
+                        if group_bitmap[#optidx] {
+                            decoded.#id = Some(#ty::#codec_decode_fn(&mut addition)?);
+                        }
+                    });
+                    group_hdr_encode_tokens.push(quote! {
+                        if self.#id.is_some() {
+                            group_bitmap.set(#optidx, true);
+                        }
+                    });
+                    comp_encode_tokens.push(quote! {
+                        if let Some(ref #id) = self.#id {
+                            #id.#codec_encode_fn(&mut addition)?;
+                        }
+                    });
+                }
+                None => {
+                    comp_decode_tokens.push(quote! {
+                        decoded.#id = Some(#ty::#codec_decode_fn(&mut addition)?);
+                    });
+                    let id_str = id.to_string();
+                    comp_encode_tokens.push(quote! {
+                        match self.#id {
+                            Some(ref #id) => #id.#codec_encode_fn(&mut addition)?,
+                            None => {
+                                return Err(asn1_codecs::PerCodecError::new(concat!(
+                                    "Mandatory component '",
+                                    #id_str,
+                                    "' of the Extension Addition is missing."
+                                )));
+                            }
+                        }
+                    });
+                }
+            }
         }
+
+        let (group_hdr_decode_tokens, group_hdr_encode) = if opt_count > 0 {
+            (
+                quote! {
+                    let (group_bitmap, _) = #ty_decode_path(&mut addition, false, #opt_count)?;
+                },
+                quote! {
+                    let mut group_bitmap = bitvec::bitvec![u8, bitvec::prelude::Msb0; 0; #opt_count];
+                    #(#group_hdr_encode_tokens)*
+                    #ty_encode_path(&mut addition, false, &group_bitmap, false)?;
+                },
+            )
+        } else {
+            (quote! {}, quote! {})
+        };
+
+        decode_arms.push(quote! {
+            #idx => {
+                #group_hdr_decode_tokens
+                #(#comp_decode_tokens)*
+            }
+        });
+        encode_tokens.push(quote! {
+            if #(#presence_tokens)||* {
+                extensions.set(#idx, true);
+                let mut addition = #codec_new_perdata_path();
+                #group_hdr_encode
+                #(#comp_encode_tokens)*
+                additions.push(addition);
+            }
+        });
     }
 
-    let is_optional = field_is_optional(field);
-
-    let ty = if is_optional {
-        if let syn::Type::Path(ref tp) = field.ty {
-            let type_params = &tp.path.segments.iter().next().unwrap().arguments;
-            match type_params {
-                syn::PathArguments::AngleBracketed(params) => {
-                    let generic_args = params.args.iter().next().unwrap();
-                    if let syn::GenericArgument::Type(syn::Type::Path(tpinner)) = generic_args {
-                        Some(tpinner.path.segments.iter().next().unwrap().ident.clone())
-                    } else {
-                        None
+    let ext_count = additions
+        .iter()
+        .map(|a| a.idx + 1)
+        .max()
+        .unwrap_or_default();
+
+    let decode_tokens = match unknown_extensions {
+        None => quote! {
+            if extensions_present {
+                let extensions = #ext_bitmap_decode_path(data)?;
+                for idx in extensions.iter_ones() {
+                    let mut addition = #open_type_decode_path(data)?;
+                    match idx {
+                        #(#decode_arms)*
+                        _ => {}
                     }
                 }
-                _ => None,
             }
-        } else {
-            None
+        },
+        Some(unknown) => {
+            let addition_mut = if decode_arms.is_empty() {
+                quote! {}
+            } else {
+                quote! { mut }
+            };
+            quote! {
+            if extensions_present {
+                let extensions = #ext_bitmap_decode_path(data)?;
+                let mut unknown = vec![None; extensions.len()];
+                for idx in extensions.iter_ones() {
+                    let #addition_mut addition = #open_type_decode_path(data)?;
+                    match idx {
+                        #(#decode_arms)*
+                        _ => {
+                            unknown[idx] = Some(addition.into_bytes());
+                        }
+                    }
+                }
+                if extensions.len() != #ext_count || extensions.not_any() {
+                    decoded.#unknown = unknown;
+                }
+            }
+            }
         }
-    } else if let syn::Type::Path(ref tp) = field.ty {
-        Some(tp.path.segments.iter().next().unwrap().ident.clone())
-    } else {
-        None
     };
 
-    StructFieldType { ty, is_optional }
+    let unknown_encode_tokens = match unknown_extensions {
+        None => quote! {
+            let extended = extensions.any();
+        },
+        Some(unknown) => quote! {
+            if !self.#unknown.is_empty() {
+                let length = std::cmp::max(
+                    self.#unknown.len(),
+                    extensions.last_one().map(|idx| idx + 1).unwrap_or_default(),
+                );
+                extensions.resize(length, false);
+                for (idx, bytes) in self.#unknown.iter().enumerate().skip(#ext_count) {
+                    if let Some(bytes) = bytes {
+                        extensions.set(idx, true);
+                        additions.push(#codec_from_slice_path(bytes));
+                    }
+                }
+            }
+            let extended = extensions.any() || !self.#unknown.is_empty();
+        },
+    };
+
+    let encode_tokens = quote! {
+        let mut extensions = bitvec::bitvec![u8, bitvec::prelude::Msb0; 0; #ext_count];
+        let mut additions = vec![];
+        #(#encode_tokens)*
+        #unknown_encode_tokens
+    };
+
+    (decode_tokens, encode_tokens)
 }
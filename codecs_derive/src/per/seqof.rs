@@ -1,4 +1,4 @@
-//! `APER` Code generation for ASN.1 OCTET STRING Type
+//! `APER` Code generation for ASN.1 SEQUENCE OF Type
 
 use quote::quote;
 
@@ -17,16 +17,16 @@ pub(super) fn generate_aper_codec_for_asn_sequence_of(
             quote!(asn1_codecs::aper::AperCodec),
             quote!(aper_encode),
             quote!(aper_decode),
-            quote!(asn1_codecs::aper::encode::encode_length_determinent),
-            quote!(asn1_codecs::aper::decode::decode_length_determinent),
+            quote!(asn1_codecs::aper::encode::encode_sequence_of),
+            quote!(asn1_codecs::aper::decode::decode_sequence_of),
         )
     } else {
         (
             quote!(asn1_codecs::uper::UperCodec),
             quote!(uper_encode),
             quote!(uper_decode),
-            quote!(asn1_codecs::uper::encode::encode_length_determinent),
-            quote!(asn1_codecs::uper::decode::decode_length_determinent),
+            quote!(asn1_codecs::uper::encode::encode_sequence_of),
+            quote!(asn1_codecs::uper::decode::decode_sequence_of),
         )
     };
     let ty = if let syn::Data::Struct(ref d) = &ast.data {
@@ -34,7 +34,7 @@ pub(super) fn generate_aper_codec_for_asn_sequence_of(
             syn::Fields::Unnamed(ref f) => {
                 if f.unnamed.len() == 1 {
                     let first = f.unnamed.first().unwrap();
-                    let inner_ty = get_inner_ty_for_vec(first);
+                    let inner_ty = utils::get_inner_ty_for_vec(first);
                     Some(inner_ty)
                 } else {
                     None
@@ -62,17 +62,13 @@ pub(super) fn generate_aper_codec_for_asn_sequence_of(
             fn #codec_decode_fn(data: &mut asn1_codecs::PerCodecData) -> Result<Self::Output, asn1_codecs::PerCodecError> {
                 log::trace!(concat!("decode: ", stringify!(#name)));
 
-                let length = #ty_decode_path(data, #sz_lb, #sz_ub, #sz_ext)?;
-
                 let mut items = vec![];
-                let mut count = 0;
-                loop {
-                    items.push(#ty::#codec_decode_fn(data)?);
-                    count += 1;
-                    if count == length {
-                        break;
+                #ty_decode_path(data, #sz_lb, #sz_ub, #sz_ext, |data, count| {
+                    for _ in 0..count {
+                        items.push(#ty::#codec_decode_fn(data)?);
                     }
-                }
+                    Ok(())
+                })?;
 
                 Ok(Self(items))
             }
@@ -80,34 +76,15 @@ pub(super) fn generate_aper_codec_for_asn_sequence_of(
             fn #codec_encode_fn(&self, data:&mut asn1_codecs::PerCodecData) -> Result<(), asn1_codecs::PerCodecError> {
                 log::trace!(concat!("encode: ", stringify!(#name)));
 
-                let _ = #ty_encode_path(data, #sz_lb, #sz_ub, #sz_ext, self.0.len());
-
-                for elem in &self.0 {
-                    let _ = elem.#codec_encode_fn(data)?;
-                }
-                Ok(())
+                #ty_encode_path(data, #sz_lb, #sz_ub, #sz_ext, self.0.len(), |data, range| {
+                    for elem in &self.0[range] {
+                        elem.#codec_encode_fn(data)?;
+                    }
+                    Ok(())
+                })
             }
         }
     };
 
     tokens.into()
 }
-
-fn get_inner_ty_for_vec(field: &syn::Field) -> Option<syn::Ident> {
-    if let syn::Type::Path(ref tp) = field.ty {
-        let type_params = &tp.path.segments.iter().next().unwrap().arguments;
-        match type_params {
-            syn::PathArguments::AngleBracketed(params) => {
-                let generic_args = params.args.iter().next().unwrap();
-                if let syn::GenericArgument::Type(syn::Type::Path(tpinner)) = generic_args {
-                    Some(tpinner.path.segments.iter().next().unwrap().ident.clone())
-                } else {
-                    None
-                }
-            }
-            _ => None,
-        }
-    } else {
-        None
-    }
-}
@@ -0,0 +1,72 @@
+//! `APER` Code generation for ASN.1 Time Types
+
+use proc_macro2::Span;
+use quote::quote;
+
+use crate::attrs::TyCodecParams;
+
+pub(super) fn generate_aper_codec_for_asn_time(
+    ast: &syn::DeriveInput,
+    params: &TyCodecParams,
+    aligned: bool,
+) -> proc_macro::TokenStream {
+    let name = &ast.ident;
+    let ty_attr = params.ty.as_ref().unwrap();
+
+    let fn_suffix = match ty_attr.value().as_str() {
+        "UTCTime" => "utc_time",
+        "GeneralizedTime" => "generalized_time",
+        "DATE" => "date",
+        "TIME-OF-DAY" => "time_of_day",
+        "DATE-TIME" => "date_time",
+        "DURATION" => "duration",
+        _ => {
+            return syn::Error::new_spanned(ty_attr, "Unsupported Time Type")
+                .to_compile_error()
+                .into()
+        }
+    };
+    let decode_fn_name = syn::Ident::new(&format!("decode_{}", fn_suffix), Span::call_site());
+    let encode_fn_name = syn::Ident::new(&format!("encode_{}", fn_suffix), Span::call_site());
+
+    let (codec_path, codec_encode_fn, codec_decode_fn, ty_encode_path, ty_decode_path) = if aligned
+    {
+        (
+            quote!(asn1_codecs::aper::AperCodec),
+            quote!(aper_encode),
+            quote!(aper_decode),
+            quote!(asn1_codecs::aper::encode::#encode_fn_name),
+            quote!(asn1_codecs::aper::decode::#decode_fn_name),
+        )
+    } else {
+        (
+            quote!(asn1_codecs::uper::UperCodec),
+            quote!(uper_encode),
+            quote!(uper_decode),
+            quote!(asn1_codecs::uper::encode::#encode_fn_name),
+            quote!(asn1_codecs::uper::decode::#decode_fn_name),
+        )
+    };
+
+    let tokens = quote! {
+
+        impl #codec_path for #name {
+
+            type Output = Self;
+
+            fn #codec_decode_fn(data: &mut asn1_codecs::PerCodecData) -> Result<Self::Output, asn1_codecs::PerCodecError> {
+                log::trace!(concat!("decode: ", stringify!(#name)));
+
+                Ok(Self(#ty_decode_path(data)?))
+            }
+
+            fn #codec_encode_fn(&self, data: &mut asn1_codecs::PerCodecData) -> Result<(), asn1_codecs::PerCodecError> {
+                log::trace!(concat!("encode: ", stringify!(#name)));
+
+                #ty_encode_path(data, &self.0)
+            }
+        }
+    };
+
+    tokens.into()
+}
@@ -15,7 +15,16 @@ pub(crate) const KEY: Symbol = Symbol("key");
 pub(crate) const EXTENDED: Symbol = Symbol("extended");
 pub(crate) const OPTIONAL_FIELDS: Symbol = Symbol("optional_fields");
 pub(crate) const OPTIONAL_IDX: Symbol = Symbol("optional_idx");
+pub(crate) const EXTENSION_IDX: Symbol = Symbol("extension_idx");
 pub(crate) const KEY_FIELD: Symbol = Symbol("key_field");
+pub(crate) const UNKNOWN: Symbol = Symbol("unknown");
+pub(crate) const NAME: Symbol = Symbol("name");
+pub(crate) const NAMED_VALUES: Symbol = Symbol("named_values");
+pub(crate) const ALPHABET: Symbol = Symbol("alphabet");
+pub(crate) const DEFAULT: Symbol = Symbol("default");
+pub(crate) const TAG: Symbol = Symbol("tag");
+pub(crate) const VALIDATE: Symbol = Symbol("validate");
+pub(crate) const VALIDATE_DECODE: Symbol = Symbol("validate_decode");
 
 impl PartialEq<Symbol> for Ident {
     fn eq(&self, word: &Symbol) -> bool {
@@ -2,7 +2,7 @@ use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{LitBool, LitStr};
 
-use crate::attrs::TyCodecParams;
+use crate::attrs::{FieldVarCodecParams, TyCodecParams};
 
 pub(super) fn get_bounds_extensible_from_params(
     params: &TyCodecParams,
@@ -54,3 +54,232 @@ fn get_bounds_extensible_from_lb_ub_ext(
 
     (lb, ub, ext)
 }
+
+pub(super) struct StructFieldType {
+    pub(super) ty: Option<syn::Ident>,
+    pub(super) is_optional: bool,
+}
+
+pub(super) fn get_field_type(field: &syn::Field) -> StructFieldType {
+    fn field_is_optional(field: &syn::Field) -> bool {
+        if let syn::Type::Path(ref typepath) = field.ty {
+            typepath.path.leading_colon.is_none()
+                && typepath.path.segments.len() == 1
+                && typepath.path.segments.iter().next().unwrap().ident == "Option"
+        } else {
+            false
+        }
+    }
+
+    let is_optional = field_is_optional(field);
+
+    let ty = if is_optional {
+        if let syn::Type::Path(ref tp) = field.ty {
+            let type_params = &tp.path.segments.iter().next().unwrap().arguments;
+            match type_params {
+                syn::PathArguments::AngleBracketed(params) => {
+                    let generic_args = params.args.iter().next().unwrap();
+                    if let syn::GenericArgument::Type(syn::Type::Path(tpinner)) = generic_args {
+                        Some(tpinner.path.segments.iter().next().unwrap().ident.clone())
+                    } else {
+                        None
+                    }
+                }
+                _ => None,
+            }
+        } else {
+            None
+        }
+    } else if let syn::Type::Path(ref tp) = field.ty {
+        Some(tp.path.segments.iter().next().unwrap().ident.clone())
+    } else {
+        None
+    };
+
+    StructFieldType { ty, is_optional }
+}
+
+pub(super) fn get_inner_ty_for_vec(field: &syn::Field) -> Option<syn::Ident> {
+    if let syn::Type::Path(ref tp) = field.ty {
+        let type_params = &tp.path.segments.iter().next().unwrap().arguments;
+        match type_params {
+            syn::PathArguments::AngleBracketed(params) => {
+                let generic_args = params.args.iter().next().unwrap();
+                if let syn::GenericArgument::Type(syn::Type::Path(tpinner)) = generic_args {
+                    Some(tpinner.path.segments.iter().next().unwrap().ident.clone())
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    } else {
+        None
+    }
+}
+
+pub(super) fn get_newtype_inner_ty(ast: &syn::DeriveInput) -> Option<syn::Type> {
+    if let syn::Data::Struct(ref d) = &ast.data {
+        match d.fields {
+            syn::Fields::Unnamed(ref f) => {
+                if f.unnamed.len() == 1 {
+                    let first = f.unnamed.first().unwrap();
+                    Some(first.ty.clone())
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    } else {
+        None
+    }
+}
+
+// Parses the `named_values` of the form "reject(0), ignore(1)".
+pub(super) fn parse_named_values(
+    named_values: &syn::LitStr,
+) -> Result<Vec<(String, i128)>, syn::Error> {
+    named_values
+        .value()
+        .split(',')
+        .map(|named_value| {
+            let named_value = named_value.trim();
+            named_value
+                .strip_suffix(')')
+                .and_then(|nv| nv.split_once('('))
+                .and_then(|(name, value)| {
+                    let value = value.trim().parse::<i128>().ok()?;
+                    Some((name.trim().to_string(), value))
+                })
+                .ok_or_else(|| {
+                    syn::Error::new_spanned(
+                        named_values,
+                        format!(
+                            "Invalid named value '{}', expected 'name(value)'.",
+                            named_value
+                        ),
+                    )
+                })
+        })
+        .collect()
+}
+
+// A component of a `SEQUENCE` that is not in the extension root.
+pub(super) struct ExtensionComponent {
+    pub(super) id: syn::Ident,
+    pub(super) ty: syn::Ident,
+
+    // Index in the bitmap of the `OPTIONAL` components of the extension addition group.
+    pub(super) optional_idx: Option<syn::LitInt>,
+}
+
+// An extension addition of a `SEQUENCE` with its components. An extension addition that is not an
+// extension addition group is encoded just like a group with a single component.
+pub(super) struct ExtensionAddition {
+    pub(super) idx: usize,
+    pub(super) components: Vec<ExtensionComponent>,
+}
+
+impl ExtensionAddition {
+    pub(super) fn optional_count(&self) -> usize {
+        self.components
+            .iter()
+            .filter(|c| c.optional_idx.is_some())
+            .count()
+    }
+}
+
+// Returns the index of the extension addition and the component, if the field has the
+// `extension_idx` attribute. Such a field is always an `Option`, since the extension additions
+// may be absent in the encodings from the peers using an earlier version of the definition.
+pub(super) fn get_extension_component(
+    field: &syn::Field,
+    params: &FieldVarCodecParams,
+) -> Result<Option<(usize, ExtensionComponent)>, syn::Error> {
+    let extension_idx = match params.extension_idx {
+        Some(ref idx) => idx.base10_parse::<usize>()?,
+        None => return Ok(None),
+    };
+
+    let field_type = get_field_type(field);
+    match field_type.ty {
+        Some(ty) if field_type.is_optional => Ok(Some((
+            extension_idx,
+            ExtensionComponent {
+                id: field.ident.clone().unwrap(),
+                ty,
+                optional_idx: params.optional_idx.clone(),
+            },
+        ))),
+        _ => Err(syn::Error::new_spanned(
+            field,
+            "Extension Addition should be an `Option`.",
+        )),
+    }
+}
+
+// Returns the path of the function returning the `DEFAULT` value, if the field has the `default`
+// attribute. Such a field is always an `Option`, since the component is absent in the encodings
+// when it's value is the default value.
+pub(super) fn get_default_fn(
+    field: &syn::Field,
+    params: &FieldVarCodecParams,
+) -> Result<Option<syn::Path>, syn::Error> {
+    match params.default {
+        Some(ref default) if get_field_type(field).is_optional => {
+            Ok(Some(default.parse::<syn::Path>()?))
+        }
+        Some(_) => Err(syn::Error::new_spanned(
+            field,
+            "Field with a `default` value should be an `Option`.",
+        )),
+        None => Ok(None),
+    }
+}
+
+// Groups the components by the extension addition, in the order of the extension additions.
+pub(super) fn group_extension_components(
+    components: Vec<(usize, ExtensionComponent)>,
+) -> Vec<ExtensionAddition> {
+    let mut additions = std::collections::BTreeMap::<usize, Vec<ExtensionComponent>>::new();
+    for (idx, component) in components {
+        additions.entry(idx).or_default().push(component);
+    }
+
+    additions
+        .into_iter()
+        .map(|(idx, components)| ExtensionAddition { idx, components })
+        .collect()
+}
+
+// Returns whether the field of a `SEQUENCE` holds the extension additions that are not known to us.
+// Such a field holds the encoding of each of the extension additions present, indexed by the
+// position of the addition (with `None` for the additions known to us).
+pub(super) fn is_unknown_extensions_field(params: &FieldVarCodecParams) -> bool {
+    params
+        .unknown
+        .as_ref()
+        .map(|u| u.value())
+        .unwrap_or_default()
+}
+
+// Returns whether the variant holds the alternatives of an extensible `CHOICE` (or the values of an
+// `OPEN` type) that are not known to us. Such a variant is of the form `Unknown(i128, Vec<u8>)`,
+// with the index of the alternative (or the `key`) and it's encoding.
+pub(super) fn is_unknown_variant(
+    variant: &syn::Variant,
+    params: &FieldVarCodecParams,
+) -> Result<bool, syn::Error> {
+    if !is_unknown_extensions_field(params) {
+        return Ok(false);
+    }
+
+    match variant.fields {
+        syn::Fields::Unnamed(ref fields) if fields.unnamed.len() == 2 => Ok(true),
+        _ => Err(syn::Error::new_spanned(
+            variant,
+            "Variant for the unknown alternatives should be of the form `Unknown(i128, Vec<u8>)`.",
+        )),
+    }
+}
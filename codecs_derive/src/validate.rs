@@ -0,0 +1,82 @@
+//! Calling the `validate` method of a Type from the generated Codecs.
+//!
+//! The ASN.1 Compiler generates a `validate` method for the types with constraints that are not
+//! handled by the Codecs themselves (eg. `WITH COMPONENTS`). The `encode` methods of the generated
+//! `impl`s call it before encoding the value and (with `validate_decode`) the `decode` methods call
+//! it on the decoded value. A value failing the validation is an error of the Codec.
+
+use quote::quote;
+
+use crate::attrs::TyCodecParams;
+
+pub(crate) fn add_validation(
+    tokens: proc_macro::TokenStream,
+    params: &TyCodecParams,
+) -> proc_macro::TokenStream {
+    let validate = params.validate.as_ref().is_some_and(|v| v.value);
+    let validate_decode = params.validate_decode.as_ref().is_some_and(|v| v.value);
+    if !validate && !validate_decode {
+        return tokens;
+    }
+
+    let mut file = match syn::parse::<syn::File>(tokens) {
+        Ok(file) => file,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    for item in &mut file.items {
+        if let syn::Item::Impl(ref mut item_impl) = item {
+            if item_impl.trait_.is_none() {
+                continue;
+            }
+            for impl_item in &mut item_impl.items {
+                if let syn::ImplItem::Method(ref mut method) = impl_item {
+                    let error = match codec_error_type(&method.sig.output) {
+                        Some(error) => error.clone(),
+                        None => continue,
+                    };
+                    let block = &method.block;
+                    if method.sig.receiver().is_some() {
+                        if validate {
+                            method.block = syn::parse_quote! {
+                                {
+                                    self.validate().map_err(#error::new)?;
+                                    #block
+                                }
+                            };
+                        }
+                    } else if validate_decode {
+                        let output = &method.sig.output;
+                        method.block = syn::parse_quote! {
+                            {
+                                #[allow(clippy::redundant_closure_call)]
+                                let decoded = (|| #output #block)()?;
+                                decoded.validate().map_err(#error::new)?;
+                                Ok(decoded)
+                            }
+                        };
+                    }
+                }
+            }
+        }
+    }
+
+    quote!(#file).into()
+}
+
+// The Error type `E` of a Codec method returning `Result<T, E>`.
+fn codec_error_type(output: &syn::ReturnType) -> Option<&syn::Type> {
+    if let syn::ReturnType::Type(_, ref ty) = output {
+        if let syn::Type::Path(ref path) = **ty {
+            let last = path.path.segments.last()?;
+            if last.ident == "Result" {
+                if let syn::PathArguments::AngleBracketed(ref args) = last.arguments {
+                    if let Some(syn::GenericArgument::Type(ref error)) = args.args.iter().nth(1) {
+                        return Some(error);
+                    }
+                }
+            }
+        }
+    }
+    None
+}
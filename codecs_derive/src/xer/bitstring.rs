@@ -0,0 +1,48 @@
+//! `XER` Code generation for ASN.1 BIT STRING Type
+
+use quote::quote;
+
+use crate::{attrs::TyCodecParams, utils};
+
+pub(super) fn generate_xer_codec_for_asn_bitstring(
+    ast: &syn::DeriveInput,
+    params: &TyCodecParams,
+) -> proc_macro::TokenStream {
+    let name = &ast.ident;
+
+    let ty = utils::get_newtype_inner_ty(ast);
+    if ty.is_none() {
+        return syn::Error::new_spanned(ast, format!("{} Should be a Unit Struct.", name))
+            .to_compile_error()
+            .into();
+    }
+
+    let (sz_lb, sz_ub, sz_ext) = utils::get_sz_bounds_extensible_from_params(params);
+
+    let type_name = super::get_type_name(ast, params);
+
+    let tokens = quote! {
+
+        impl asn1_codecs::xer::XerCodec for #name {
+            type Output = Self;
+
+            fn xer_decode_tagged(data: &mut asn1_codecs::XerCodecData, tag: Option<&str>) -> Result<Self::Output, asn1_codecs::XerCodecError> {
+                log::trace!(concat!("decode: ", stringify!(#name)));
+
+                let mut content = asn1_codecs::xer::decode::decode_element(data, tag.unwrap_or(#type_name))?;
+                let decoded = asn1_codecs::xer::decode::decode_bitstring(&mut content, #sz_lb, #sz_ub, #sz_ext)?;
+                Ok(Self(decoded))
+            }
+
+            fn xer_encode_tagged(&self, data: &mut asn1_codecs::XerCodecData, tag: Option<&str>) -> Result<(), asn1_codecs::XerCodecError> {
+                log::trace!(concat!("encode: ", stringify!(#name)));
+
+                let mut content = asn1_codecs::XerCodecData::new();
+                asn1_codecs::xer::encode::encode_bitstring(&mut content, #sz_lb, #sz_ub, #sz_ext, &self.0)?;
+                asn1_codecs::xer::encode::encode_element(data, tag.unwrap_or(#type_name), content)
+            }
+        }
+    };
+
+    tokens.into()
+}
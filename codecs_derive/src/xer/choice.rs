@@ -0,0 +1,125 @@
+//! `XER` Code generation for ASN.1 Choice Type
+
+use proc_macro::TokenStream;
+use quote::quote;
+
+use crate::attrs::{parse_fld_meta_as_codec_params, TyCodecParams};
+use crate::utils::is_unknown_variant;
+
+pub(super) fn generate_xer_codec_for_asn_choice(
+    ast: &syn::DeriveInput,
+    params: &TyCodecParams,
+) -> proc_macro::TokenStream {
+    let name = &ast.ident;
+
+    let type_name = super::get_type_name(ast, params);
+
+    let variant_tokens = generate_choice_variant_tokens_using_attrs(ast);
+    if variant_tokens.is_err() {
+        return variant_tokens.err().unwrap().to_compile_error().into();
+    }
+    let (variant_decode_tokens, variant_encode_tokens) = variant_tokens.unwrap();
+
+    let tokens = quote! {
+
+        impl asn1_codecs::xer::XerCodec for #name {
+            type Output = Self;
+
+            fn xer_decode_tagged(data: &mut asn1_codecs::XerCodecData, tag: Option<&str>) -> Result<Self::Output, asn1_codecs::XerCodecError> {
+                log::trace!(concat!("decode: ", stringify!(#name)));
+
+                let mut content = asn1_codecs::xer::decode::decode_element(data, tag.unwrap_or(#type_name))?;
+                let alternative = asn1_codecs::xer::decode::peek_element_name(&content).map(str::to_string);
+                match alternative.as_deref() {
+                    #(#variant_decode_tokens)*
+                    Some(other) => Err(asn1_codecs::XerCodecError::new(format!("XerCodec:DecodeError:'{}' is not a valid Alternative for the CHOICE", other))),
+                    None => Err(asn1_codecs::XerCodecError::new("XerCodec:DecodeError:No Alternative for the CHOICE")),
+                }
+            }
+
+            fn xer_encode_tagged(&self, data: &mut asn1_codecs::XerCodecData, tag: Option<&str>) -> Result<(), asn1_codecs::XerCodecError> {
+                log::trace!(concat!("encode: ", stringify!(#name)));
+
+                let mut content = asn1_codecs::XerCodecData::new();
+                match self {
+                    #(#variant_encode_tokens)*
+                }?;
+
+                asn1_codecs::xer::encode::encode_element(data, tag.unwrap_or(#type_name), content)
+            }
+        }
+    };
+
+    TokenStream::from(tokens)
+}
+
+// The chosen alternative is encoded as an element named using its identifier from the ASN.1
+// definition given by the `name` attribute, or the name of the variant if the attribute is not
+// present.
+fn generate_choice_variant_tokens_using_attrs(
+    ast: &syn::DeriveInput,
+) -> Result<(Vec<proc_macro2::TokenStream>, Vec<proc_macro2::TokenStream>), syn::Error> {
+    let mut decode_tokens = vec![];
+    let mut encode_tokens = vec![];
+
+    let mut errors = vec![];
+    if let syn::Data::Enum(ref data) = ast.data {
+        for variant in &data.variants {
+            let codec_params = parse_fld_meta_as_codec_params(&variant.attrs);
+            match codec_params {
+                Err(e) => errors.push(e),
+                Ok(cp) => {
+                    // The encoding of an alternative not known to us is kept only by the PER Codecs.
+                    match is_unknown_variant(variant, &cp) {
+                        Err(e) => {
+                            errors.push(e);
+                            continue;
+                        }
+                        Ok(true) => {
+                            let variant_ident = &variant.ident;
+                            encode_tokens.push(quote! {
+                                Self::#variant_ident(..) => Err(asn1_codecs::XerCodecError::new("Alternative not known to us cannot be encoded.")),
+                            });
+                            continue;
+                        }
+                        Ok(false) => {}
+                    }
+
+                    let variant_ident = &variant.ident;
+                    let variant_name = cp
+                        .name
+                        .as_ref()
+                        .map(|n| n.value())
+                        .unwrap_or_else(|| variant_ident.to_string());
+                    if let syn::Fields::Unnamed(ref fields) = variant.fields {
+                        if fields.unnamed.len() == 1 {
+                            let ty = &fields.unnamed.first().as_ref().unwrap().ty;
+                            let variant_decode_token = quote! {
+                                Some(#variant_name) => Ok(Self::#variant_ident(#ty::xer_decode_tagged(&mut content, Some(#variant_name))?)),
+                            };
+                            let variant_encode_token = quote! {
+                                Self::#variant_ident(ref v) => v.xer_encode_tagged(&mut content, Some(#variant_name)),
+                            };
+                            decode_tokens.push(variant_decode_token);
+                            encode_tokens.push(variant_encode_token);
+                        } else {
+                            errors.push(syn::Error::new_spanned(
+                                variant,
+                                "Unsupported variant type".to_string(),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    if let Some((first, others)) = errors.split_first_mut() {
+        for e in others {
+            first.combine(e.clone())
+        }
+        Err(first.clone())
+    } else {
+        Ok((decode_tokens, encode_tokens))
+    }
+}
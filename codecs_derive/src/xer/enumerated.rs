@@ -0,0 +1,63 @@
+//! `XER` Code generation for ASN.1 ENUMERATED Type
+
+use quote::quote;
+
+use crate::{attrs::TyCodecParams, utils};
+
+pub(super) fn generate_xer_codec_for_asn_enumerated(
+    ast: &syn::DeriveInput,
+    params: &TyCodecParams,
+) -> proc_macro::TokenStream {
+    let name = &ast.ident;
+
+    let ty = utils::get_newtype_inner_ty(ast);
+    if ty.is_none() {
+        return syn::Error::new_spanned(ast, format!("{} Should be a Unit Struct.", name))
+            .to_compile_error()
+            .into();
+    }
+
+    // The values are encoded using their identifiers, so the `named_values` are required.
+    let named_values = match params.named_values {
+        Some(ref named_values) => utils::parse_named_values(named_values),
+        None => Err(syn::Error::new_spanned(
+            params.attr.as_ref(),
+            "Missing parameter 'named_values' for the ENUMERATED.",
+        )),
+    };
+    if named_values.is_err() {
+        return named_values.err().unwrap().to_compile_error().into();
+    }
+    let named_value_tokens = named_values
+        .unwrap()
+        .into_iter()
+        .map(|(name, value)| quote! { (#name, #value) })
+        .collect::<Vec<_>>();
+
+    let type_name = super::get_type_name(ast, params);
+
+    let tokens = quote! {
+
+        impl asn1_codecs::xer::XerCodec for #name {
+            type Output = Self;
+
+            fn xer_decode_tagged(data: &mut asn1_codecs::XerCodecData, tag: Option<&str>) -> Result<Self::Output, asn1_codecs::XerCodecError> {
+                log::trace!(concat!("decode: ", stringify!(#name)));
+
+                let mut content = asn1_codecs::xer::decode::decode_element(data, tag.unwrap_or(#type_name))?;
+                let decoded = asn1_codecs::xer::decode::decode_enumerated(&mut content, &[#(#named_value_tokens),*])?;
+                Ok(Self(decoded as #ty))
+            }
+
+            fn xer_encode_tagged(&self, data: &mut asn1_codecs::XerCodecData, tag: Option<&str>) -> Result<(), asn1_codecs::XerCodecError> {
+                log::trace!(concat!("encode: ", stringify!(#name)));
+
+                let mut content = asn1_codecs::XerCodecData::new();
+                asn1_codecs::xer::encode::encode_enumerated(&mut content, &[#(#named_value_tokens),*], self.0 as i128)?;
+                asn1_codecs::xer::encode::encode_element(data, tag.unwrap_or(#type_name), content)
+            }
+        }
+    };
+
+    tokens.into()
+}
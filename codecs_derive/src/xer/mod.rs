@@ -0,0 +1,56 @@
+//! Implementation of `XerCodec` `impl` generation for different ASN Types.
+
+use super::attrs::TyCodecParams;
+
+mod bitstring;
+mod boolean;
+mod charstring;
+mod choice;
+mod enumerated;
+mod integer;
+mod null;
+mod octetstring;
+mod oid;
+mod open;
+mod seq;
+mod seqof;
+
+pub(crate) fn generate_codec(
+    ast: &syn::DeriveInput,
+    params: &TyCodecParams,
+) -> proc_macro::TokenStream {
+    let ty = params.ty.as_ref().unwrap();
+    match ty.value().as_str() {
+        "BOOLEAN" => boolean::generate_xer_codec_for_asn_boolean(ast, params),
+        "CHOICE" => choice::generate_xer_codec_for_asn_choice(ast, params),
+        "INTEGER" => integer::generate_xer_codec_for_asn_integer(ast, params),
+        "ENUMERATED" => enumerated::generate_xer_codec_for_asn_enumerated(ast, params),
+        "BITSTRING" => bitstring::generate_xer_codec_for_asn_bitstring(ast, params),
+        "OCTET-STRING" => octetstring::generate_xer_codec_for_asn_octetstring(ast, params),
+        "UTF8String" | "PrintableString" | "VisibleString" | "IA5String" | "NumericString"
+        | "BMPString" | "UniversalString" | "TeletexString" | "T61String" | "GeneralString"
+        | "UTCTime" | "GeneralizedTime" | "DATE" | "TIME-OF-DAY" | "DATE-TIME" | "DURATION" => {
+            charstring::generate_xer_codec_for_asn_charstring(ast, params)
+        }
+        "NULL" => null::generate_xer_codec_for_asn_null(ast, params),
+        "SEQUENCE" | "SET" => seq::generate_xer_codec_for_asn_sequence(ast, params),
+        "OPEN" => open::generate_xer_codec_for_asn_open_type(ast, params),
+        "SEQUENCE-OF" | "SET-OF" => seqof::generate_xer_codec_for_asn_sequence_of(ast, params),
+        "OBJECT-IDENTIFIER" | "RELATIVE-OID" => {
+            oid::generate_xer_codec_for_asn_object_identifier(ast, params)
+        }
+        _ => syn::Error::new_spanned(ty.clone(), "This ASN.1 Type is not supported.")
+            .to_compile_error()
+            .into(),
+    }
+}
+
+// Name of the element for a value of the type, when it is not a component of a `SEQUENCE` or a
+// `CHOICE`. This is the name of the type from the ASN.1 definition if available.
+fn get_type_name(ast: &syn::DeriveInput, params: &TyCodecParams) -> String {
+    params
+        .name
+        .as_ref()
+        .map(|n| n.value())
+        .unwrap_or_else(|| ast.ident.to_string())
+}
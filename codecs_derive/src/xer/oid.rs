@@ -0,0 +1,33 @@
+//! `XER` Code generation for ASN.1 OBJECT IDENTIFIER Type
+
+use quote::quote;
+
+use crate::attrs::TyCodecParams;
+
+pub(super) fn generate_xer_codec_for_asn_object_identifier(
+    ast: &syn::DeriveInput,
+    _params: &TyCodecParams,
+) -> proc_macro::TokenStream {
+    let name = &ast.ident;
+
+    let tokens = quote! {
+
+        impl asn1_codecs::xer::XerCodec for #name {
+            type Output = Self;
+
+            fn xer_decode_tagged(_data: &mut asn1_codecs::XerCodecData, _tag: Option<&str>) -> Result<Self::Output, asn1_codecs::XerCodecError> {
+                log::trace!(concat!("decode: ", stringify!(#name)));
+
+                Err(asn1_codecs::XerCodecError::new("Object Identifier Decode Not Supported!"))
+            }
+
+            fn xer_encode_tagged(&self, _data: &mut asn1_codecs::XerCodecData, _tag: Option<&str>) -> Result<(), asn1_codecs::XerCodecError> {
+                log::trace!(concat!("encode: ", stringify!(#name)));
+
+                Err(asn1_codecs::XerCodecError::new("Object Identifier Encode Not Supported!"))
+            }
+        }
+    };
+
+    tokens.into()
+}
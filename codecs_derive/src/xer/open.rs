@@ -0,0 +1,144 @@
+//! `XER` Code generation for ASN.1 OPEN type
+
+use quote::quote;
+
+use crate::attrs::{parse_fld_meta_as_codec_params, TyCodecParams};
+use crate::utils::is_unknown_variant;
+
+pub(super) fn generate_xer_codec_for_asn_open_type(
+    ast: &syn::DeriveInput,
+    params: &TyCodecParams,
+) -> proc_macro::TokenStream {
+    let name = &ast.ident;
+
+    let type_name = super::get_type_name(ast, params);
+
+    let variant_tokens = generate_open_type_variant_tokens_using_attrs(ast);
+    if variant_tokens.is_err() {
+        return variant_tokens.err().unwrap().to_compile_error().into();
+    }
+    let (variant_decode_tokens, variant_encode_tokens) = variant_tokens.unwrap();
+
+    let encode_tokens = if !variant_encode_tokens.is_empty() {
+        quote! {
+            match self {
+                #(#variant_encode_tokens)*
+            }?;
+        }
+    } else {
+        quote! {}
+    };
+
+    let tokens = quote! {
+        impl asn1_codecs::xer::XerCodec for #name {
+            type Output = Self;
+
+            fn xer_decode_tagged(data: &mut asn1_codecs::XerCodecData, tag: Option<&str>) -> Result<Self::Output, asn1_codecs::XerCodecError> {
+                log::trace!(concat!("decode: ", stringify!(#name)));
+
+                let mut content = asn1_codecs::xer::decode::decode_element(data, tag.unwrap_or(#type_name))?;
+
+                if content.get_key().is_none() {
+                    return Err(asn1_codecs::XerCodecError::new("Decoding OPEN Type, but `key` is not determined!"));
+                }
+
+                let key = content.get_key().unwrap();
+
+                match key {
+                    #(#variant_decode_tokens)*
+                    _ => Err(asn1_codecs::XerCodecError::new(format!("Key {} Not Found", key).as_str()))
+                }
+            }
+
+            fn xer_encode_tagged(&self, data: &mut asn1_codecs::XerCodecData, tag: Option<&str>) -> Result<(), asn1_codecs::XerCodecError> {
+                log::trace!(concat!("encode: ", stringify!(#name)));
+
+                let mut content = asn1_codecs::XerCodecData::new();
+
+                #encode_tokens
+
+                asn1_codecs::xer::encode::encode_element(data, tag.unwrap_or(#type_name), content)
+            }
+        }
+    };
+
+    tokens.into()
+}
+
+// The value of the actual type is encoded as an element named using the name of the type given by
+// the `name` attribute of the variant, or the name of the actual type if the attribute is not
+// present.
+fn generate_open_type_variant_tokens_using_attrs(
+    ast: &syn::DeriveInput,
+) -> Result<(Vec<proc_macro2::TokenStream>, Vec<proc_macro2::TokenStream>), syn::Error> {
+    let mut decode_tokens = vec![];
+    let mut encode_tokens = vec![];
+
+    let mut errors = vec![];
+    if let syn::Data::Enum(ref data) = ast.data {
+        for variant in &data.variants {
+            let codec_params = parse_fld_meta_as_codec_params(&variant.attrs);
+            match codec_params {
+                Err(e) => errors.push(e),
+                Ok(cp) => {
+                    // The encoding of a value not known to us is kept only by the PER Codecs.
+                    match is_unknown_variant(variant, &cp) {
+                        Err(e) => {
+                            errors.push(e);
+                            continue;
+                        }
+                        Ok(true) => {
+                            let variant_ident = &variant.ident;
+                            encode_tokens.push(quote! {
+                                Self::#variant_ident(..) => Err(asn1_codecs::XerCodecError::new("Value not known to us cannot be encoded.")),
+                            });
+                            continue;
+                        }
+                        Ok(false) => {}
+                    }
+
+                    let key = cp.key.as_ref();
+                    if key.is_none() {
+                        errors.push(syn::Error::new_spanned(
+                        variant,
+                        "Missing Key for the variant. Please provide `#[asn(key = <int>)]` attribute.",
+                    ));
+                        continue;
+                    }
+                    let variant_ident = &variant.ident;
+                    let variant_tag = match cp.name {
+                        Some(ref name) => quote! { Some(#name) },
+                        None => quote! { None },
+                    };
+                    if let syn::Fields::Unnamed(ref fields) = variant.fields {
+                        if fields.unnamed.len() == 1 {
+                            let ty = &fields.unnamed.first().as_ref().unwrap().ty;
+                            let variant_decode_token = quote! {
+                                #key => Ok(Self::#variant_ident(#ty::xer_decode_tagged(&mut content, #variant_tag)?)),
+                            };
+                            let variant_encode_token = quote! {
+                                Self::#variant_ident(ref v) => v.xer_encode_tagged(&mut content, #variant_tag),
+                            };
+                            decode_tokens.push(variant_decode_token);
+                            encode_tokens.push(variant_encode_token);
+                        } else {
+                            errors.push(syn::Error::new_spanned(
+                                variant,
+                                "Unsupported variant type".to_string(),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    if let Some((first, others)) = errors.split_first_mut() {
+        for e in others {
+            first.combine(e.clone())
+        }
+        Err(first.clone())
+    } else {
+        Ok((decode_tokens, encode_tokens))
+    }
+}
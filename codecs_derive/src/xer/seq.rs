@@ -0,0 +1,186 @@
+//! `XER` Code generation for ASN.1 `SEQUENCE` and `SET` types
+
+use quote::quote;
+
+use crate::attrs::{parse_fld_meta_as_codec_params, TyCodecParams};
+use crate::utils::{get_default_fn, get_field_type, is_unknown_extensions_field};
+
+struct FieldTokens {
+    decode_tokens: Vec<proc_macro2::TokenStream>,
+    encode_tokens: Vec<proc_macro2::TokenStream>,
+}
+
+pub(super) fn generate_xer_codec_for_asn_sequence(
+    ast: &syn::DeriveInput,
+    params: &TyCodecParams,
+) -> proc_macro::TokenStream {
+    let name = &ast.ident;
+
+    let ext = params.ext.as_ref().map(|e| e.value()).unwrap_or_default();
+    let type_name = super::get_type_name(ast, params);
+
+    let field_tokens = generate_seq_field_codec_tokens_using_attrs(ast);
+    if field_tokens.is_err() {
+        return field_tokens.err().unwrap().to_compile_error().into();
+    }
+    let field_tokens = field_tokens.unwrap();
+    let fld_decode_tokens = field_tokens.decode_tokens;
+    let fld_encode_tokens = field_tokens.encode_tokens;
+
+    // Unknown components are possible only in an extensible `SEQUENCE` and are ignored.
+    let trailing_tokens = if ext {
+        quote! {
+            while !data.is_empty() {
+                asn1_codecs::xer::decode::skip_element(data)?;
+            }
+        }
+    } else {
+        quote! {
+            if let Some(unknown) = asn1_codecs::xer::decode::peek_element_name(data) {
+                return Err(asn1_codecs::XerCodecError::new(format!("XerCodec:DecodeError:Unexpected element '{}' in a SEQUENCE.", unknown)));
+            }
+        }
+    };
+
+    let tokens = quote! {
+        impl asn1_codecs::xer::XerCodec for #name {
+            type Output = Self;
+
+            fn xer_decode_tagged(data: &mut asn1_codecs::XerCodecData, tag: Option<&str>) -> Result<Self::Output, asn1_codecs::XerCodecError> {
+                log::trace!(concat!("decode: ", stringify!(#name)));
+
+                let mut content = asn1_codecs::xer::decode::decode_element(data, tag.unwrap_or(#type_name))?;
+                let data = &mut content;
+
+                let decoded = Self{#(#fld_decode_tokens)*};
+
+                #trailing_tokens
+
+                Ok(decoded)
+            }
+
+            fn xer_encode_tagged(&self, data: &mut asn1_codecs::XerCodecData, tag: Option<&str>) -> Result<(), asn1_codecs::XerCodecError> {
+                log::trace!(concat!("encode: ", stringify!(#name)));
+
+                let mut content = asn1_codecs::XerCodecData::new();
+
+                #(#fld_encode_tokens)*
+
+                asn1_codecs::xer::encode::encode_element(data, tag.unwrap_or(#type_name), content)
+            }
+        }
+    };
+
+    tokens.into()
+}
+
+// The components are encoded as elements named using their identifiers from the ASN.1 definition
+// given by the `name` attribute, or the name of the field if the attribute is not present.
+fn generate_seq_field_codec_tokens_using_attrs(
+    ast: &syn::DeriveInput,
+) -> Result<FieldTokens, syn::Error> {
+    let mut decode_tokens = vec![];
+    let mut encode_tokens = vec![];
+
+    let mut errors: Vec<syn::Error> = vec![];
+    if let syn::Data::Struct(ref data) = ast.data {
+        if let syn::Fields::Named(ref fields) = data.fields {
+            for field in &fields.named {
+                let codec_params = parse_fld_meta_as_codec_params(&field.attrs);
+                match codec_params {
+                    Err(e) => errors.push(e),
+                    Ok(cp) => {
+                        // The extension additions not known to us are kept only by the PER Codecs.
+                        if is_unknown_extensions_field(&cp) {
+                            let id = field.ident.as_ref().unwrap();
+                            decode_tokens.push(quote! { #id: Default::default(), });
+                            continue;
+                        }
+
+                        let field_type = get_field_type(field);
+                        if field_type.ty.is_none() {
+                            errors.push(syn::Error::new_spanned(
+                                field,
+                                "Field Type is not in supported Format!",
+                            ));
+                            continue;
+                        }
+                        let ty_ident = field_type.ty.unwrap();
+                        let optional = field_type.is_optional;
+                        let id = field.ident.as_ref().unwrap();
+                        // An absent component with a `DEFAULT` value takes the default value.
+                        let absent = match get_default_fn(field, &cp) {
+                            Ok(Some(default_fn)) => quote! { Some(#default_fn()) },
+                            Ok(None) => quote! { None },
+                            Err(e) => {
+                                errors.push(e);
+                                continue;
+                            }
+                        };
+                        let fld_name = cp
+                            .name
+                            .as_ref()
+                            .map(|n| n.value())
+                            .unwrap_or_else(|| id.to_string());
+
+                        let fld_decode_tokens = if optional {
+                            quote! {
+                                if asn1_codecs::xer::decode::peek_element_name(data) == Some(#fld_name) {
+                                    Some(#ty_ident::xer_decode_tagged(data, Some(#fld_name))?)
+                                } else {
+                                    #absent
+                                }
+                            }
+                        } else {
+                            let is_key_field = cp
+                                .key_field
+                                .as_ref()
+                                .map(|kf| kf.value())
+                                .unwrap_or_default();
+
+                            if !is_key_field {
+                                quote! {
+                                    #ty_ident::xer_decode_tagged(data, Some(#fld_name))?
+                                }
+                            } else {
+                                quote! {
+                                    {
+                                    let value = #ty_ident::xer_decode_tagged(data, Some(#fld_name))?;
+                                    let _ = data.set_key(value.0 as i128);
+                                    value
+                                    }
+                                }
+                            }
+                        };
+
+                        let field_encode_token = if optional {
+                            quote! {
+                                if let Some(ref #id) = self.#id {
+                                    #id.xer_encode_tagged(&mut content, Some(#fld_name))?;
+                                }
+                            }
+                        } else {
+                            quote! {
+                                self.#id.xer_encode_tagged(&mut content, Some(#fld_name))?;
+                            }
+                        };
+                        decode_tokens.push(quote! { #id: #fld_decode_tokens, });
+                        encode_tokens.push(field_encode_token);
+                    }
+                }
+            }
+        }
+    }
+
+    if let Some((first, others)) = errors.split_first_mut() {
+        for e in others {
+            first.combine(e.clone())
+        }
+        Err(first.clone())
+    } else {
+        Ok(FieldTokens {
+            decode_tokens,
+            encode_tokens,
+        })
+    }
+}
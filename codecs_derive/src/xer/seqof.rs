@@ -0,0 +1,70 @@
+//! `XER` Code generation for ASN.1 SEQUENCE OF Type
+
+use quote::quote;
+
+use crate::{attrs::TyCodecParams, utils};
+
+pub(super) fn generate_xer_codec_for_asn_sequence_of(
+    ast: &syn::DeriveInput,
+    params: &TyCodecParams,
+) -> proc_macro::TokenStream {
+    let name = &ast.ident;
+
+    let ty = if let syn::Data::Struct(ref d) = &ast.data {
+        match d.fields {
+            syn::Fields::Unnamed(ref f) => {
+                if f.unnamed.len() == 1 {
+                    let first = f.unnamed.first().unwrap();
+                    utils::get_inner_ty_for_vec(first)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    } else {
+        None
+    };
+
+    if ty.is_none() {
+        return syn::Error::new_spanned(ast, format!("{} Should be a Unit Struct.", name))
+            .to_compile_error()
+            .into();
+    }
+
+    let type_name = super::get_type_name(ast, params);
+
+    // Each of the items is encoded as an element with the name of the type of the item.
+    let tokens = quote! {
+
+        impl asn1_codecs::xer::XerCodec for #name {
+            type Output = Self;
+
+            fn xer_decode_tagged(data: &mut asn1_codecs::XerCodecData, tag: Option<&str>) -> Result<Self::Output, asn1_codecs::XerCodecError> {
+                log::trace!(concat!("decode: ", stringify!(#name)));
+
+                let mut content = asn1_codecs::xer::decode::decode_element(data, tag.unwrap_or(#type_name))?;
+
+                let mut items = vec![];
+                while !content.is_empty() {
+                    items.push(#ty::xer_decode(&mut content)?);
+                }
+
+                Ok(Self(items))
+            }
+
+            fn xer_encode_tagged(&self, data: &mut asn1_codecs::XerCodecData, tag: Option<&str>) -> Result<(), asn1_codecs::XerCodecError> {
+                log::trace!(concat!("encode: ", stringify!(#name)));
+
+                let mut content = asn1_codecs::XerCodecData::new();
+                for elem in &self.0 {
+                    elem.xer_encode(&mut content)?;
+                }
+
+                asn1_codecs::xer::encode::encode_element(data, tag.unwrap_or(#type_name), content)
+            }
+        }
+    };
+
+    tokens.into()
+}
@@ -0,0 +1,106 @@
+#![allow(non_camel_case_types, dead_code)]
+
+use asn1_codecs::{ber::BerCodec, BerCodecData};
+use asn1_codecs_derive::BerCodec;
+
+#[derive(Debug, BerCodec, PartialEq)]
+#[asn(type = "INTEGER", lb = "0", ub = "65535")]
+pub struct ProtocolIE_ID(u16);
+
+#[derive(Debug, BerCodec, PartialEq)]
+#[asn(type = "ENUMERATED", lb = "0", ub = "2")]
+pub struct Criticality(u8);
+
+#[derive(Debug, BerCodec, PartialEq)]
+#[asn(type = "OCTET-STRING")]
+pub struct LPPa_PDU(Vec<u8>);
+
+#[derive(Debug, BerCodec, PartialEq)]
+#[asn(type = "BOOLEAN")]
+pub struct Flag(bool);
+
+#[derive(Debug, BerCodec, PartialEq)]
+#[asn(type = "PrintableString")]
+pub struct Name(String);
+
+#[derive(Debug, BerCodec, PartialEq)]
+#[asn(type = "OPEN")]
+pub enum ProtocolIEValue {
+    #[asn(key = 8)]
+    Criticality(Criticality),
+    #[asn(key = 147)]
+    LPPa_PDU(LPPa_PDU),
+}
+
+#[derive(Debug, BerCodec, PartialEq)]
+#[asn(type = "SEQUENCE", extensible = false)]
+pub struct ProtocolIE {
+    #[asn(key_field = true)]
+    pub id: ProtocolIE_ID,
+    pub value: ProtocolIEValue,
+}
+
+#[derive(Debug, BerCodec, PartialEq)]
+#[asn(type = "SEQUENCE-OF")]
+pub struct ProtocolIEs(Vec<ProtocolIE>);
+
+#[derive(Debug, BerCodec, PartialEq)]
+#[asn(type = "CHOICE", lb = "0", ub = "1", extensible = true)]
+pub enum Identity {
+    #[asn(key = 0, extended = false)]
+    Name(Name),
+    #[asn(key = 1, extended = false)]
+    Id(ProtocolIE_ID),
+    #[asn(key = 0, extended = true)]
+    Flag(Flag),
+}
+
+#[derive(Debug, BerCodec, PartialEq)]
+#[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
+pub struct Message {
+    pub identity: Identity,
+    #[asn(optional_idx = 0)]
+    pub criticality: Option<Criticality>,
+    pub ies: ProtocolIEs,
+}
+
+fn main() {
+    let message = Message {
+        identity: Identity::Name(Name("hampi".to_string())),
+        criticality: None,
+        ies: ProtocolIEs(vec![
+            ProtocolIE {
+                id: ProtocolIE_ID(8),
+                value: ProtocolIEValue::Criticality(Criticality(1)),
+            },
+            ProtocolIE {
+                id: ProtocolIE_ID(147),
+                value: ProtocolIEValue::LPPa_PDU(LPPa_PDU(vec![0xde, 0xad])),
+            },
+        ]),
+    };
+
+    let mut data = BerCodecData::new();
+    let result = message.ber_encode(&mut data);
+    assert!(result.is_ok(), "{:?}", result.err().unwrap());
+
+    let encoded = data.into_bytes();
+    assert_eq!(
+        hex::encode(&encoded),
+        "3021a007800568616d7069a2163008800108a1030a0101300a80020093a1040402dead"
+    );
+
+    let mut data = BerCodecData::from_slice(&encoded);
+    let decoded = Message::ber_decode(&mut data);
+    assert!(decoded.is_ok(), "{:?}", decoded.err().unwrap());
+    assert_eq!(decoded.unwrap(), message);
+
+    // An additional unknown component in an extensible SEQUENCE is ignored.
+    let mut with_extension = encoded.clone();
+    with_extension[1] += 3;
+    with_extension.extend([0x85, 0x01, 0x00]);
+    let mut data = BerCodecData::from_slice(&with_extension);
+    let decoded = Message::ber_decode(&mut data);
+    assert!(decoded.is_ok(), "{:?}", decoded.err().unwrap());
+    assert_eq!(decoded.unwrap(), message);
+}
@@ -0,0 +1,138 @@
+#![allow(non_camel_case_types, dead_code)]
+
+use asn1_codecs::{
+    ber::{der::DerCodec, BerCodec},
+    BerCodecData,
+};
+use asn1_codecs_derive::{BerCodec, DerCodec};
+
+#[derive(Debug, BerCodec, DerCodec, PartialEq)]
+#[asn(type = "INTEGER", lb = "-128", ub = "1024")]
+pub struct Value(i16);
+
+#[derive(Debug, BerCodec, DerCodec, PartialEq)]
+#[asn(type = "BOOLEAN")]
+pub struct Flag(bool);
+
+#[derive(Debug, BerCodec, DerCodec, PartialEq)]
+#[asn(type = "SET-OF")]
+pub struct Values(Vec<Value>);
+
+#[derive(Debug, BerCodec, DerCodec, PartialEq)]
+#[asn(type = "SET", extensible = false, optional_fields = 1)]
+pub struct Record {
+    pub value: Value,
+    #[asn(optional_idx = 0)]
+    pub flag: Option<Flag>,
+    pub values: Values,
+}
+
+#[derive(Debug, BerCodec, DerCodec, PartialEq)]
+#[asn(type = "SEQUENCE", extensible = false, optional_fields = 1)]
+pub struct Config {
+    pub value: Value,
+    #[asn(optional_idx = 0, default = "Config::default_flag")]
+    pub flag: Option<Flag>,
+}
+
+impl Config {
+    pub fn default_flag() -> Flag {
+        Flag(true)
+    }
+}
+
+fn main() {
+    let record = Record {
+        value: Value(5),
+        flag: Some(Flag(true)),
+        values: Values(vec![Value(256), Value(3), Value(-1)]),
+    };
+
+    // Elements of the SET OF are sorted by their encodings.
+    let mut data = BerCodecData::new();
+    let result = record.der_encode(&mut data);
+    assert!(result.is_ok(), "{:?}", result.err().unwrap());
+
+    let encoded = data.into_bytes();
+    assert_eq!(
+        hex::encode(&encoded),
+        "31128001058101ffa20a0201030201ff02020100"
+    );
+
+    let mut data = BerCodecData::from_slice_der(&encoded);
+    let decoded = Record::der_decode(&mut data);
+    assert!(decoded.is_ok(), "{:?}", decoded.err().unwrap());
+
+    // Elements decoded in the order of their encoding.
+    let decoded = decoded.unwrap();
+    assert_eq!(
+        decoded.values,
+        Values(vec![Value(3), Value(-1), Value(256)])
+    );
+
+    // Components of a SET in any order are fine for BER but not for DER.
+    let reordered = hex::decode("3112a20a0201030201ff020201008101ff800105").unwrap();
+    let mut data = BerCodecData::from_slice(&reordered);
+    let decoded = Record::ber_decode(&mut data);
+    assert!(decoded.is_ok(), "{:?}", decoded.err().unwrap());
+    assert_eq!(
+        decoded.unwrap(),
+        Record::der_decode(&mut BerCodecData::from_slice_der(&encoded)).unwrap()
+    );
+
+    let mut data = BerCodecData::from_slice_der(&reordered);
+    assert!(Record::der_decode(&mut data).is_err());
+
+    // A BOOLEAN `true` other than 0xFF is not a valid DER encoding.
+    let non_der = hex::decode("3112800105810101a20a0201030201ff02020100").unwrap();
+    let mut data = BerCodecData::from_slice(&non_der);
+    assert!(Record::ber_decode(&mut data).is_ok());
+    let mut data = BerCodecData::from_slice_der(&non_der);
+    assert!(Record::der_decode(&mut data).is_err());
+
+    // A missing mandatory component of a SET.
+    let missing = hex::decode("31038101ff").unwrap();
+    let mut data = BerCodecData::from_slice(&missing);
+    assert!(Record::ber_decode(&mut data).is_err());
+
+    // A component with it's DEFAULT value is not encoded for DER and is filled in when decoded.
+    for flag in [None, Some(Flag(true))] {
+        let config = Config {
+            value: Value(5),
+            flag,
+        };
+        let mut data = BerCodecData::new();
+        config.der_encode(&mut data).unwrap();
+        let encoded = data.into_bytes();
+        assert_eq!(hex::encode(&encoded), "3003800105");
+
+        let mut data = BerCodecData::from_slice_der(&encoded);
+        let decoded = Config::der_decode(&mut data).unwrap();
+        assert_eq!(decoded.flag, Some(Flag(true)));
+
+        let mut data = BerCodecData::from_slice(&encoded);
+        let decoded = Config::ber_decode(&mut data).unwrap();
+        assert_eq!(decoded.flag, Some(Flag(true)));
+    }
+
+    let config = Config {
+        value: Value(5),
+        flag: Some(Flag(false)),
+    };
+    let mut data = BerCodecData::new();
+    config.der_encode(&mut data).unwrap();
+    let encoded = data.into_bytes();
+    assert_eq!(hex::encode(&encoded), "3006800105810100");
+    let mut data = BerCodecData::from_slice_der(&encoded);
+    assert_eq!(Config::der_decode(&mut data).unwrap(), config);
+
+    // The DEFAULT value encoded is fine for BER but not for DER.
+    let with_default = hex::decode("30068001058101ff").unwrap();
+    let mut data = BerCodecData::from_slice(&with_default);
+    assert_eq!(
+        Config::ber_decode(&mut data).unwrap().flag,
+        Some(Flag(true))
+    );
+    let mut data = BerCodecData::from_slice_der(&with_default);
+    assert!(Config::der_decode(&mut data).is_err());
+}
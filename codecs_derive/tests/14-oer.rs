@@ -0,0 +1,112 @@
+#![allow(non_camel_case_types, dead_code)]
+
+use asn1_codecs::{oer::OerCodec, OerCodecData};
+use asn1_codecs_derive::OerCodec;
+
+#[derive(Debug, OerCodec, PartialEq)]
+#[asn(type = "INTEGER", lb = "0", ub = "65535")]
+pub struct ProtocolIE_ID(u16);
+
+#[derive(Debug, OerCodec, PartialEq)]
+#[asn(type = "ENUMERATED", lb = "0", ub = "2")]
+pub struct Criticality(u8);
+
+#[derive(Debug, OerCodec, PartialEq)]
+#[asn(type = "OCTET-STRING")]
+pub struct LPPa_PDU(Vec<u8>);
+
+#[derive(Debug, OerCodec, PartialEq)]
+#[asn(type = "BOOLEAN")]
+pub struct Flag(bool);
+
+#[derive(Debug, OerCodec, PartialEq)]
+#[asn(type = "VisibleString", sz_lb = "5", sz_ub = "5")]
+pub struct Name(String);
+
+#[derive(Debug, OerCodec, PartialEq)]
+#[asn(type = "OPEN")]
+pub enum ProtocolIEValue {
+    #[asn(key = 8)]
+    Criticality(Criticality),
+    #[asn(key = 147)]
+    LPPa_PDU(LPPa_PDU),
+}
+
+#[derive(Debug, OerCodec, PartialEq)]
+#[asn(type = "SEQUENCE", extensible = false)]
+pub struct ProtocolIE {
+    #[asn(key_field = true)]
+    pub id: ProtocolIE_ID,
+    pub value: ProtocolIEValue,
+}
+
+#[derive(Debug, OerCodec, PartialEq)]
+#[asn(type = "SEQUENCE-OF")]
+pub struct ProtocolIEs(Vec<ProtocolIE>);
+
+#[derive(Debug, OerCodec, PartialEq)]
+#[asn(type = "CHOICE", lb = "0", ub = "1", extensible = true)]
+pub enum Identity {
+    #[asn(key = 0, extended = false)]
+    Name(Name),
+    #[asn(key = 1, extended = false)]
+    Id(ProtocolIE_ID),
+    #[asn(key = 0, extended = true)]
+    Flag(Flag),
+}
+
+#[derive(Debug, OerCodec, PartialEq)]
+#[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
+pub struct Message {
+    pub identity: Identity,
+    #[asn(optional_idx = 0)]
+    pub criticality: Option<Criticality>,
+    pub ies: ProtocolIEs,
+}
+
+fn main() {
+    let message = Message {
+        identity: Identity::Name(Name("hampi".to_string())),
+        criticality: Some(Criticality(2)),
+        ies: ProtocolIEs(vec![
+            ProtocolIE {
+                id: ProtocolIE_ID(8),
+                value: ProtocolIEValue::Criticality(Criticality(1)),
+            },
+            ProtocolIE {
+                id: ProtocolIE_ID(147),
+                value: ProtocolIEValue::LPPa_PDU(LPPa_PDU(vec![0xde, 0xad])),
+            },
+        ]),
+    };
+
+    let mut data = OerCodecData::new();
+    let result = message.oer_encode(&mut data);
+    assert!(result.is_ok(), "{:?}", result.err().unwrap());
+
+    let encoded = data.into_bytes();
+    assert_eq!(
+        hex::encode(&encoded),
+        "408068616d70690201020008010100930302dead"
+    );
+
+    let mut data = OerCodecData::from_slice_coer(&encoded);
+    let decoded = Message::oer_decode(&mut data);
+    assert!(decoded.is_ok(), "{:?}", decoded.err().unwrap());
+    assert_eq!(decoded.unwrap(), message);
+
+    // An alternative in the extension is encoded as an open type.
+    let identity = Identity::Flag(Flag(true));
+    let mut data = OerCodecData::new();
+    identity.oer_encode(&mut data).unwrap();
+    let encoded = data.into_bytes();
+    assert_eq!(hex::encode(&encoded), "8201ff");
+    let mut data = OerCodecData::from_slice(&encoded);
+    assert_eq!(Identity::oer_decode(&mut data).unwrap(), identity);
+
+    // A BOOLEAN `true` other than 0xFF is not a valid COER encoding.
+    let mut data = OerCodecData::from_slice(&[0x82, 0x01, 0x01]);
+    assert!(Identity::oer_decode(&mut data).is_ok());
+    let mut data = OerCodecData::from_slice_coer(&[0x82, 0x01, 0x01]);
+    assert!(Identity::oer_decode(&mut data).is_err());
+}
@@ -0,0 +1,119 @@
+#![allow(non_camel_case_types, dead_code)]
+
+use asn1_codecs::{jer::JerCodec, JerCodecData};
+use asn1_codecs_derive::JerCodec;
+
+#[derive(Debug, JerCodec, PartialEq)]
+#[asn(type = "INTEGER", lb = "0", ub = "65535")]
+pub struct ProtocolIE_ID(u16);
+
+#[derive(Debug, JerCodec, PartialEq)]
+#[asn(
+    type = "ENUMERATED",
+    lb = "0",
+    ub = "2",
+    named_values = "reject(0), ignore(1), notify(2)"
+)]
+pub struct Criticality(u8);
+
+#[derive(Debug, JerCodec, PartialEq)]
+#[asn(type = "OCTET-STRING")]
+pub struct LPPa_PDU(Vec<u8>);
+
+#[derive(Debug, JerCodec, PartialEq)]
+#[asn(type = "BITSTRING", sz_lb = "1", sz_ub = "16")]
+pub struct Flags(bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
+
+#[derive(Debug, JerCodec, PartialEq)]
+#[asn(type = "VisibleString", sz_lb = "5", sz_ub = "5")]
+pub struct Name(String);
+
+#[derive(Debug, JerCodec, PartialEq)]
+#[asn(type = "OPEN")]
+pub enum ProtocolIEValue {
+    #[asn(key = 8)]
+    Criticality(Criticality),
+    #[asn(key = 147)]
+    LPPa_PDU(LPPa_PDU),
+}
+
+#[derive(Debug, JerCodec, PartialEq)]
+#[asn(type = "SEQUENCE", extensible = false)]
+pub struct ProtocolIE {
+    #[asn(key_field = true)]
+    pub id: ProtocolIE_ID,
+    pub value: ProtocolIEValue,
+}
+
+#[derive(Debug, JerCodec, PartialEq)]
+#[asn(type = "SEQUENCE-OF")]
+pub struct ProtocolIEs(Vec<ProtocolIE>);
+
+#[derive(Debug, JerCodec, PartialEq)]
+#[asn(type = "CHOICE", lb = "0", ub = "1", extensible = true)]
+pub enum Identity {
+    #[asn(key = 0, extended = false, name = "name")]
+    Name(Name),
+    #[asn(key = 1, extended = false, name = "id")]
+    Id(ProtocolIE_ID),
+}
+
+#[derive(Debug, JerCodec, PartialEq)]
+#[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
+pub struct Message {
+    pub identity: Identity,
+    #[asn(optional_idx = 0)]
+    pub criticality: Option<Criticality>,
+    #[asn(optional_idx = 1)]
+    pub flags: Option<Flags>,
+    #[asn(name = "protocolIEs")]
+    pub ies: ProtocolIEs,
+}
+
+fn main() {
+    let message = Message {
+        identity: Identity::Name(Name("hampi".to_string())),
+        criticality: Some(Criticality(2)),
+        flags: None,
+        ies: ProtocolIEs(vec![
+            ProtocolIE {
+                id: ProtocolIE_ID(8),
+                value: ProtocolIEValue::Criticality(Criticality(1)),
+            },
+            ProtocolIE {
+                id: ProtocolIE_ID(147),
+                value: ProtocolIEValue::LPPa_PDU(LPPa_PDU(vec![0xde, 0xad])),
+            },
+        ]),
+    };
+
+    let mut data = JerCodecData::new();
+    let result = message.jer_encode(&mut data);
+    assert!(result.is_ok(), "{:?}", result.err().unwrap());
+
+    let encoded = data.to_json();
+    assert_eq!(
+        encoded,
+        r#"{"identity":{"name":"hampi"},"criticality":"notify","protocolIEs":[{"id":8,"value":"ignore"},{"id":147,"value":"DEAD"}]}"#
+    );
+
+    let mut data = JerCodecData::from_json(&encoded).unwrap();
+    let decoded = Message::jer_decode(&mut data);
+    assert!(decoded.is_ok(), "{:?}", decoded.err().unwrap());
+    assert_eq!(decoded.unwrap(), message);
+
+    // A BIT STRING that is not of a fixed size is encoded with its length.
+    let flags = Flags(bitvec::bitvec![u8, bitvec::order::Msb0; 1, 0, 1]);
+    let mut data = JerCodecData::new();
+    flags.jer_encode(&mut data).unwrap();
+    assert_eq!(data.to_json(), r#"{"value":"A0","length":3}"#);
+
+    // Unknown components are ignored in an extensible SEQUENCE, but not otherwise.
+    let mut data = JerCodecData::from_json(
+        r#"{"identity": {"id": 1}, "protocolIEs": [], "extension": true}"#,
+    )
+    .unwrap();
+    assert!(Message::jer_decode(&mut data).is_ok());
+    let mut data = JerCodecData::from_json(r#"{"id": 8, "value": "reject", "extra": 1}"#).unwrap();
+    assert!(ProtocolIE::jer_decode(&mut data).is_err());
+}
@@ -0,0 +1,118 @@
+#![allow(non_camel_case_types, dead_code)]
+
+use asn1_codecs::{xer::XerCodec, XerCodecData};
+use asn1_codecs_derive::XerCodec;
+
+#[derive(Debug, XerCodec, PartialEq)]
+#[asn(type = "INTEGER", lb = "0", ub = "65535", name = "ProtocolIE-ID")]
+pub struct ProtocolIE_ID(u16);
+
+#[derive(Debug, XerCodec, PartialEq)]
+#[asn(
+    type = "ENUMERATED",
+    lb = "0",
+    ub = "2",
+    named_values = "reject(0), ignore(1), notify(2)"
+)]
+pub struct Criticality(u8);
+
+#[derive(Debug, XerCodec, PartialEq)]
+#[asn(type = "OCTET-STRING", name = "LPPa-PDU")]
+pub struct LPPa_PDU(Vec<u8>);
+
+#[derive(Debug, XerCodec, PartialEq)]
+#[asn(type = "BITSTRING", sz_lb = "1", sz_ub = "16")]
+pub struct Flags(bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
+
+#[derive(Debug, XerCodec, PartialEq)]
+#[asn(type = "VisibleString", sz_lb = "5", sz_ub = "5")]
+pub struct Name(String);
+
+#[derive(Debug, XerCodec, PartialEq)]
+#[asn(type = "OPEN")]
+pub enum ProtocolIEValue {
+    #[asn(key = 8)]
+    Criticality(Criticality),
+    #[asn(key = 147, name = "LPPa-PDU")]
+    LPPa_PDU(LPPa_PDU),
+}
+
+#[derive(Debug, XerCodec, PartialEq)]
+#[asn(type = "SEQUENCE", extensible = false, name = "SEQUENCE")]
+pub struct ProtocolIE {
+    #[asn(key_field = true)]
+    pub id: ProtocolIE_ID,
+    pub value: ProtocolIEValue,
+}
+
+#[derive(Debug, XerCodec, PartialEq)]
+#[asn(type = "SEQUENCE-OF")]
+pub struct ProtocolIEs(Vec<ProtocolIE>);
+
+#[derive(Debug, XerCodec, PartialEq)]
+#[asn(type = "CHOICE", lb = "0", ub = "1", extensible = true)]
+pub enum Identity {
+    #[asn(key = 0, extended = false, name = "name")]
+    Name(Name),
+    #[asn(key = 1, extended = false, name = "id")]
+    Id(ProtocolIE_ID),
+}
+
+#[derive(Debug, XerCodec, PartialEq)]
+#[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
+pub struct Message {
+    pub identity: Identity,
+    #[asn(optional_idx = 0)]
+    pub criticality: Option<Criticality>,
+    #[asn(optional_idx = 1)]
+    pub flags: Option<Flags>,
+    #[asn(name = "protocolIEs")]
+    pub ies: ProtocolIEs,
+}
+
+fn main() {
+    let message = Message {
+        identity: Identity::Name(Name("hampi".to_string())),
+        criticality: Some(Criticality(2)),
+        flags: None,
+        ies: ProtocolIEs(vec![
+            ProtocolIE {
+                id: ProtocolIE_ID(8),
+                value: ProtocolIEValue::Criticality(Criticality(1)),
+            },
+            ProtocolIE {
+                id: ProtocolIE_ID(147),
+                value: ProtocolIEValue::LPPa_PDU(LPPa_PDU(vec![0xde, 0xad])),
+            },
+        ]),
+    };
+
+    let mut data = XerCodecData::new();
+    let result = message.xer_encode(&mut data);
+    assert!(result.is_ok(), "{:?}", result.err().unwrap());
+
+    let encoded = data.to_xml();
+    assert_eq!(
+        encoded,
+        "<Message><identity><name>hampi</name></identity><criticality><notify/></criticality>\
+         <protocolIEs><SEQUENCE><id>8</id><value><Criticality><ignore/></Criticality></value></SEQUENCE>\
+         <SEQUENCE><id>147</id><value><LPPa-PDU>DEAD</LPPa-PDU></value></SEQUENCE></protocolIEs></Message>"
+    );
+
+    let mut data = XerCodecData::from_xml(&data.to_xml_pretty()).unwrap();
+    let decoded = Message::xer_decode(&mut data);
+    assert!(decoded.is_ok(), "{:?}", decoded.err().unwrap());
+    assert_eq!(decoded.unwrap(), message);
+
+    // Unknown components are ignored in an extensible SEQUENCE, but not otherwise.
+    let mut data = XerCodecData::from_xml(
+        "<Message><identity><id>1</id></identity><protocolIEs/><extension/></Message>",
+    )
+    .unwrap();
+    assert!(Message::xer_decode(&mut data).is_ok());
+    let mut data = XerCodecData::from_xml(
+        "<SEQUENCE><id>8</id><value><Criticality><reject/></Criticality></value><extra/></SEQUENCE>",
+    )
+    .unwrap();
+    assert!(ProtocolIE::xer_decode(&mut data).is_err());
+}
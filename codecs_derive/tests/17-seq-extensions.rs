@@ -0,0 +1,122 @@
+#![allow(non_camel_case_types, dead_code)]
+
+use asn1_codecs::{aper::AperCodec, oer::OerCodec, uper::UperCodec};
+use asn1_codecs::{OerCodecData, PerCodecData};
+use asn1_codecs_derive::{AperCodec, OerCodec, UperCodec};
+
+#[derive(Debug, AperCodec, UperCodec, OerCodec, PartialEq)]
+#[asn(type = "INTEGER", lb = "0", ub = "65535")]
+pub struct ProtocolIE_ID(u16);
+
+#[derive(Debug, AperCodec, UperCodec, OerCodec, PartialEq)]
+#[asn(type = "ENUMERATED", lb = "0", ub = "2")]
+pub struct Criticality(u8);
+
+// The version of the `SEQUENCE` before the extension additions were added.
+#[derive(Debug, AperCodec, UperCodec, OerCodec, PartialEq)]
+#[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
+pub struct Item_V1 {
+    pub id: ProtocolIE_ID,
+    #[asn(optional_idx = 0)]
+    pub criticality: Option<Criticality>,
+}
+
+#[derive(Debug, AperCodec, UperCodec, OerCodec, PartialEq)]
+#[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
+pub struct Item {
+    pub id: ProtocolIE_ID,
+    #[asn(optional_idx = 0)]
+    pub criticality: Option<Criticality>,
+    #[asn(extension_idx = 0)]
+    pub ext_criticality: Option<Criticality>,
+    #[asn(extension_idx = 1)]
+    pub group_id: Option<ProtocolIE_ID>,
+    #[asn(extension_idx = 1, optional_idx = 0)]
+    pub group_criticality: Option<Criticality>,
+}
+
+fn main() {
+    let item = Item {
+        id: ProtocolIE_ID(1),
+        criticality: None,
+        ext_criticality: Some(Criticality(1)),
+        group_id: Some(ProtocolIE_ID(5)),
+        group_criticality: None,
+    };
+
+    let mut data = PerCodecData::new_aper();
+    let result = item.aper_encode(&mut data);
+    assert!(result.is_ok(), "{:?}", result.err().unwrap());
+    let encoded = data.into_bytes();
+    assert_eq!(hex::encode(&encoded), "8000010380014003000005");
+
+    let mut data = PerCodecData::from_slice_aper(&encoded);
+    assert_eq!(Item::aper_decode(&mut data).unwrap(), item);
+
+    // Extension additions not known to the decoder are skipped.
+    let mut data = PerCodecData::from_slice_aper(&encoded);
+    let decoded = Item_V1::aper_decode(&mut data).unwrap();
+    assert_eq!(
+        decoded,
+        Item_V1 {
+            id: ProtocolIE_ID(1),
+            criticality: None
+        }
+    );
+
+    let mut data = PerCodecData::new_uper();
+    item.uper_encode(&mut data).unwrap();
+    let encoded = data.into_bytes();
+    let mut data = PerCodecData::from_slice_uper(&encoded);
+    assert_eq!(Item::uper_decode(&mut data).unwrap(), item);
+    let mut data = PerCodecData::from_slice_uper(&encoded);
+    assert!(Item_V1::uper_decode(&mut data).is_ok());
+
+    // Without any extension additions, the encoding is same as that of the earlier version.
+    let item = Item {
+        id: ProtocolIE_ID(1),
+        criticality: Some(Criticality(2)),
+        ext_criticality: None,
+        group_id: None,
+        group_criticality: None,
+    };
+    let mut data = PerCodecData::new_aper();
+    item.aper_encode(&mut data).unwrap();
+    let mut v1_data = PerCodecData::new_aper();
+    Item_V1 {
+        id: ProtocolIE_ID(1),
+        criticality: Some(Criticality(2)),
+    }
+    .aper_encode(&mut v1_data)
+    .unwrap();
+    assert_eq!(data.into_bytes(), v1_data.into_bytes());
+
+    // A mandatory component of an extension addition group cannot be absent, if the group is
+    // present.
+    let item = Item {
+        id: ProtocolIE_ID(1),
+        criticality: None,
+        ext_criticality: None,
+        group_id: None,
+        group_criticality: Some(Criticality(0)),
+    };
+    let mut data = PerCodecData::new_aper();
+    assert!(item.aper_encode(&mut data).is_err());
+
+    let item = Item {
+        id: ProtocolIE_ID(1),
+        criticality: None,
+        ext_criticality: None,
+        group_id: Some(ProtocolIE_ID(5)),
+        group_criticality: Some(Criticality(0)),
+    };
+    let mut data = OerCodecData::new();
+    item.oer_encode(&mut data).unwrap();
+    let encoded = data.into_bytes();
+    assert_eq!(hex::encode(&encoded), "8000010206400480000500");
+
+    let mut data = OerCodecData::from_slice_coer(&encoded);
+    assert_eq!(Item::oer_decode(&mut data).unwrap(), item);
+    let mut data = OerCodecData::from_slice_coer(&encoded);
+    assert!(Item_V1::oer_decode(&mut data).is_ok());
+}
@@ -0,0 +1,83 @@
+#![allow(non_camel_case_types, dead_code)]
+
+use asn1_codecs::{aper::AperCodec, ber::BerCodec, uper::UperCodec};
+use asn1_codecs::{BerCodecData, PerCodecData};
+use asn1_codecs_derive::{AperCodec, BerCodec, UperCodec};
+
+#[derive(Debug, AperCodec, UperCodec, BerCodec, PartialEq)]
+#[asn(type = "INTEGER", lb = "0", ub = "65535")]
+pub struct ProtocolIE_ID(u16);
+
+#[derive(Debug, AperCodec, UperCodec, BerCodec, PartialEq)]
+#[asn(type = "ENUMERATED", lb = "0", ub = "2")]
+pub struct Criticality(u8);
+
+// The version of the `CHOICE` before the alternatives were added in the extension.
+#[derive(Debug, AperCodec, UperCodec, BerCodec, PartialEq)]
+#[asn(type = "CHOICE", extensible = true, lb = "0", ub = "1")]
+pub enum Value_V1 {
+    #[asn(key = 0, extended = false)]
+    Id(ProtocolIE_ID),
+    #[asn(key = 1, extended = false)]
+    Criticality(Criticality),
+    #[asn(unknown = true)]
+    Unknown(i128, Vec<u8>),
+}
+
+#[derive(Debug, AperCodec, UperCodec, PartialEq)]
+#[asn(type = "CHOICE", extensible = true, lb = "0", ub = "1")]
+pub enum Value_V1_Strict {
+    #[asn(key = 0, extended = false)]
+    Id(ProtocolIE_ID),
+    #[asn(key = 1, extended = false)]
+    Criticality(Criticality),
+}
+
+#[derive(Debug, AperCodec, UperCodec, PartialEq)]
+#[asn(type = "CHOICE", extensible = true, lb = "0", ub = "1")]
+pub enum Value {
+    #[asn(key = 0, extended = false)]
+    Id(ProtocolIE_ID),
+    #[asn(key = 1, extended = false)]
+    Criticality(Criticality),
+    #[asn(key = 0, extended = true)]
+    ExtCriticality(Criticality),
+}
+
+fn main() {
+    let value = Value::ExtCriticality(Criticality(2));
+    let mut data = PerCodecData::new_aper();
+    let result = value.aper_encode(&mut data);
+    assert!(result.is_ok(), "{:?}", result.err().unwrap());
+    let encoded = data.into_bytes();
+    assert_eq!(hex::encode(&encoded), "800180");
+
+    let mut data = PerCodecData::from_slice_aper(&encoded);
+    assert_eq!(Value::aper_decode(&mut data).unwrap(), value);
+
+    // An alternative not known to the decoder is kept as it is and is encoded again unchanged.
+    let mut data = PerCodecData::from_slice_aper(&encoded);
+    let unknown = Value_V1::aper_decode(&mut data).unwrap();
+    assert_eq!(unknown, Value_V1::Unknown(0, vec![0x80]));
+    let mut data = PerCodecData::new_aper();
+    unknown.aper_encode(&mut data).unwrap();
+    assert_eq!(data.into_bytes(), encoded);
+
+    let mut data = PerCodecData::from_slice_aper(&encoded);
+    assert!(Value_V1_Strict::aper_decode(&mut data).is_err());
+
+    let mut data = PerCodecData::new_uper();
+    value.uper_encode(&mut data).unwrap();
+    let encoded = data.into_bytes();
+    let mut data = PerCodecData::from_slice_uper(&encoded);
+    assert_eq!(Value::uper_decode(&mut data).unwrap(), value);
+    let mut data = PerCodecData::from_slice_uper(&encoded);
+    let unknown = Value_V1::uper_decode(&mut data).unwrap();
+    let mut data = PerCodecData::new_uper();
+    unknown.uper_encode(&mut data).unwrap();
+    assert_eq!(data.into_bytes(), encoded);
+
+    // The encoding of an alternative not known to us is only meaningful to the PER Codecs.
+    let mut data = BerCodecData::new();
+    assert!(unknown.ber_encode(&mut data).is_err());
+}
@@ -0,0 +1,197 @@
+#![allow(non_camel_case_types, dead_code)]
+
+use asn1_codecs::{aper::AperCodec, ber::BerCodec, uper::UperCodec};
+use asn1_codecs::{BerCodecData, PerCodecData};
+use asn1_codecs_derive::{AperCodec, BerCodec, UperCodec};
+
+#[derive(Debug, AperCodec, UperCodec, BerCodec, PartialEq)]
+#[asn(type = "INTEGER", lb = "0", ub = "65535")]
+pub struct ProtocolIE_ID(u16);
+
+#[derive(Debug, AperCodec, UperCodec, BerCodec, PartialEq)]
+#[asn(type = "BOOLEAN")]
+pub struct Flag(bool);
+
+// `Cause ::= ENUMERATED { normal, busy, ..., congested, overload }`
+#[derive(Debug, AperCodec, UperCodec, BerCodec, PartialEq)]
+#[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
+pub struct Cause(u8);
+impl Cause {
+    const NORMAL: u8 = 0u8;
+    const BUSY: u8 = 1u8;
+    const CONGESTED: u8 = 2u8;
+    const OVERLOAD: u8 = 3u8;
+}
+
+// The version of `Cause` before the extension additions.
+#[derive(Debug, AperCodec, UperCodec, BerCodec, PartialEq)]
+#[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
+pub struct Cause_V1(u8);
+
+#[derive(Debug, AperCodec, UperCodec, PartialEq)]
+#[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
+pub struct Cause_Wide(u16);
+
+#[derive(Debug, AperCodec, UperCodec, PartialEq)]
+#[asn(type = "ENUMERATED", extensible = true, lb = "1", ub = "2")]
+pub struct Rank(u8);
+
+#[derive(Debug, AperCodec, UperCodec, BerCodec, PartialEq)]
+#[asn(type = "SEQUENCE", extensible = true)]
+pub struct Item {
+    pub id: ProtocolIE_ID,
+    #[asn(extension_idx = 0)]
+    pub flag: Option<Flag>,
+    #[asn(extension_idx = 1)]
+    pub cause: Option<Cause>,
+    #[asn(extension_idx = 2)]
+    pub ext_id: Option<ProtocolIE_ID>,
+}
+
+// The version of `Item` with only the first of the extension additions.
+#[derive(Debug, AperCodec, UperCodec, BerCodec, PartialEq)]
+#[asn(type = "SEQUENCE", extensible = true)]
+pub struct Item_V1 {
+    pub id: ProtocolIE_ID,
+    #[asn(extension_idx = 0)]
+    pub flag: Option<Flag>,
+    #[asn(unknown = true)]
+    pub unknown_extensions: Vec<Option<Vec<u8>>>,
+}
+
+// The version of `Item` without any of the extension additions.
+#[derive(Debug, AperCodec, UperCodec, BerCodec, PartialEq)]
+#[asn(type = "SEQUENCE", extensible = true)]
+pub struct Item_V0 {
+    pub id: ProtocolIE_ID,
+    #[asn(unknown = true)]
+    pub unknown_extensions: Vec<Option<Vec<u8>>>,
+}
+
+#[derive(Debug, AperCodec, UperCodec, PartialEq)]
+#[asn(type = "OPEN")]
+pub enum Value {
+    #[asn(key = 1)]
+    Id(ProtocolIE_ID),
+    #[asn(key = 2)]
+    Flag(Flag),
+    #[asn(unknown = true)]
+    Unknown(i128, Vec<u8>),
+}
+
+#[derive(Debug, AperCodec, UperCodec, PartialEq)]
+#[asn(type = "SEQUENCE", extensible = false)]
+pub struct Field {
+    #[asn(key_field = true)]
+    pub id: ProtocolIE_ID,
+    pub value: Value,
+}
+
+fn main() {
+    let item = Item {
+        id: ProtocolIE_ID(7),
+        flag: Some(Flag(true)),
+        cause: Some(Cause(Cause::OVERLOAD)),
+        ext_id: None,
+    };
+    let mut data = PerCodecData::new_aper();
+    item.aper_encode(&mut data).unwrap();
+    let encoded = data.into_bytes();
+    assert_eq!(hex::encode(&encoded), "800007058001800181");
+
+    let mut data = PerCodecData::from_slice_aper(&encoded);
+    assert_eq!(Item::aper_decode(&mut data).unwrap(), item);
+
+    // The extension additions not known to the decoder are encoded again unchanged.
+    let mut data = PerCodecData::from_slice_aper(&encoded);
+    let item_v1 = Item_V1::aper_decode(&mut data).unwrap();
+    assert_eq!(item_v1.flag, Some(Flag(true)));
+    assert_eq!(
+        item_v1.unknown_extensions,
+        vec![None, Some(vec![0x81]), None]
+    );
+    let mut data = PerCodecData::new_aper();
+    item_v1.aper_encode(&mut data).unwrap();
+    assert_eq!(data.into_bytes(), encoded);
+
+    let mut data = PerCodecData::from_slice_aper(&encoded);
+    let item_v0 = Item_V0::aper_decode(&mut data).unwrap();
+    let mut data = PerCodecData::new_aper();
+    item_v0.aper_encode(&mut data).unwrap();
+    assert_eq!(data.into_bytes(), encoded);
+
+    // The length of the bitmap of the extension additions is also kept.
+    let item = Item {
+        cause: None,
+        ..item
+    };
+    let mut data = PerCodecData::new_uper();
+    item.uper_encode(&mut data).unwrap();
+    let encoded = data.into_bytes();
+    let mut data = PerCodecData::from_slice_uper(&encoded);
+    let item_v1 = Item_V1::uper_decode(&mut data).unwrap();
+    assert_eq!(item_v1.unknown_extensions, vec![None, None, None]);
+    let mut data = PerCodecData::new_uper();
+    item_v1.uper_encode(&mut data).unwrap();
+    assert_eq!(data.into_bytes(), encoded);
+
+    // The extension additions not known to us are ignored by the other codecs.
+    let mut data = BerCodecData::new();
+    item_v1.ber_encode(&mut data).unwrap();
+    let mut data = BerCodecData::from_slice(&data.into_bytes());
+    let decoded = Item_V1::ber_decode(&mut data).unwrap();
+    assert!(decoded.unknown_extensions.is_empty());
+
+    // The values of an `ENUMERATED` in the extension follow the values of the root.
+    for &value in &[
+        Cause::NORMAL,
+        Cause::BUSY,
+        Cause::CONGESTED,
+        Cause::OVERLOAD,
+    ] {
+        let mut data = PerCodecData::new_aper();
+        Cause(value).aper_encode(&mut data).unwrap();
+        let encoded = data.into_bytes();
+        let mut data = PerCodecData::from_slice_aper(&encoded);
+        let cause_v1 = Cause_V1::aper_decode(&mut data).unwrap();
+        assert_eq!(cause_v1, Cause_V1(value));
+        let mut data = PerCodecData::new_aper();
+        cause_v1.aper_encode(&mut data).unwrap();
+        assert_eq!(data.into_bytes(), encoded);
+    }
+
+    // An extension value that does not fit in the type is not decoded.
+    let mut data = PerCodecData::new_aper();
+    Cause_Wide(300).aper_encode(&mut data).unwrap();
+    let mut data = PerCodecData::from_slice_aper(&data.into_bytes());
+    assert!(Cause_V1::aper_decode(&mut data).is_err());
+
+    // The values of the extension follow the root values, that need not start at 0.
+    for value in 1..=4 {
+        let mut data = PerCodecData::new_uper();
+        Rank(value).uper_encode(&mut data).unwrap();
+        let encoded = data.into_bytes();
+        // Only the values after the root values are extended.
+        assert_eq!(encoded[0] & 0x80 != 0, value > 2);
+        let mut data = PerCodecData::from_slice_uper(&encoded);
+        assert_eq!(Rank::uper_decode(&mut data).unwrap(), Rank(value));
+    }
+
+    // The value for a `key` not known to us is encoded again unchanged.
+    let field = Field {
+        id: ProtocolIE_ID(9),
+        value: Value::Unknown(9, vec![0x12, 0x34]),
+    };
+    let mut data = PerCodecData::new_aper();
+    field.aper_encode(&mut data).unwrap();
+    let encoded = data.into_bytes();
+    assert_eq!(hex::encode(&encoded), "0009021234");
+    let mut data = PerCodecData::from_slice_aper(&encoded);
+    assert_eq!(Field::aper_decode(&mut data).unwrap(), field);
+
+    let mut data = PerCodecData::new_uper();
+    field.uper_encode(&mut data).unwrap();
+    let encoded = data.into_bytes();
+    let mut data = PerCodecData::from_slice_uper(&encoded);
+    assert_eq!(Field::uper_decode(&mut data).unwrap(), field);
+}
@@ -0,0 +1,45 @@
+#![allow(non_camel_case_types)]
+
+use asn1_codecs::PerCodecData;
+use asn1_codecs::{aper::AperCodec, uper::UperCodec};
+use asn1_codecs_derive::{AperCodec, UperCodec};
+
+#[derive(Debug, AperCodec, UperCodec, PartialEq)]
+#[asn(type = "OBJECT-IDENTIFIER")]
+pub struct RANfunctionOID(Vec<u128>);
+
+#[derive(Debug, AperCodec, UperCodec, PartialEq)]
+#[asn(type = "RELATIVE-OID")]
+pub struct RANfunctionRelOID(Vec<u128>);
+
+#[derive(Debug, AperCodec, UperCodec, PartialEq)]
+#[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
+pub struct RANfunction_Item {
+    pub oid: RANfunctionOID,
+    #[asn(optional_idx = 0)]
+    pub rel_oid: Option<RANfunctionRelOID>,
+}
+
+fn main() {
+    let item = RANfunction_Item {
+        oid: RANfunctionOID(vec![1, 2, 840, 113549]),
+        rel_oid: Some(RANfunctionRelOID(vec![8571, 3, 2])),
+    };
+
+    let mut data = PerCodecData::new_aper();
+    item.aper_encode(&mut data).unwrap();
+    let encoded = data.into_bytes();
+    assert_eq!(hex::encode(&encoded), "40062a864886f70d04c27b0302");
+    let mut data = PerCodecData::from_slice_aper(&encoded);
+    assert_eq!(RANfunction_Item::aper_decode(&mut data).unwrap(), item);
+
+    let mut data = PerCodecData::new_uper();
+    item.uper_encode(&mut data).unwrap();
+    let encoded = data.into_bytes();
+    let mut data = PerCodecData::from_slice_uper(&encoded);
+    assert_eq!(RANfunction_Item::uper_decode(&mut data).unwrap(), item);
+
+    // An absolute OBJECT IDENTIFIER needs at least two arcs.
+    let mut data = PerCodecData::new_aper();
+    assert!(RANfunctionOID(vec![1]).aper_encode(&mut data).is_err());
+}
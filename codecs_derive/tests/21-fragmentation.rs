@@ -0,0 +1,67 @@
+#![allow(non_camel_case_types, dead_code)]
+
+use asn1_codecs::PerCodecData;
+use asn1_codecs::{aper::AperCodec, uper::UperCodec};
+use asn1_codecs_derive::{AperCodec, UperCodec};
+
+#[derive(Debug, AperCodec, UperCodec, PartialEq)]
+#[asn(type = "INTEGER", lb = "0", ub = "255")]
+pub struct Octet(u8);
+
+#[derive(Debug, AperCodec, UperCodec, PartialEq)]
+#[asn(
+    type = "SEQUENCE-OF",
+    sz_extensible = false,
+    sz_lb = "0",
+    sz_ub = "100000"
+)]
+pub struct Octets(Vec<Octet>);
+
+#[derive(Debug, AperCodec, UperCodec, PartialEq)]
+#[asn(type = "SEQUENCE-OF", sz_extensible = true, sz_lb = "1", sz_ub = "4")]
+pub struct FewOctets(Vec<Octet>);
+
+#[derive(Debug, AperCodec, UperCodec, PartialEq)]
+#[asn(type = "OCTET-STRING")]
+pub struct Container(Vec<u8>);
+
+fn main() {
+    // 16K items in the first fragment, followed by the count of the remaining items.
+    let octets = Octets((0..20000).map(|i| Octet(i as u8)).collect());
+    let mut data = PerCodecData::new_aper();
+    octets.aper_encode(&mut data).unwrap();
+    let encoded = data.into_bytes();
+    assert_eq!(encoded.len(), 1 + 16384 + 2 + 3616);
+    assert_eq!(encoded[0], 0xc1);
+    let mut data = PerCodecData::from_slice_aper(&encoded);
+    assert_eq!(Octets::aper_decode(&mut data).unwrap(), octets);
+
+    let mut data = PerCodecData::new_uper();
+    octets.uper_encode(&mut data).unwrap();
+    let mut data = PerCodecData::from_slice_uper(&data.into_bytes());
+    assert_eq!(Octets::uper_decode(&mut data).unwrap(), octets);
+
+    // An empty SEQUENCE OF has no items.
+    let mut data = PerCodecData::from_slice_aper(&[0x00, 0x00]);
+    assert_eq!(Octets::aper_decode(&mut data).unwrap(), Octets(vec![]));
+
+    // A count outside the extensible SIZE constraint is encoded as an unconstrained length.
+    let few = FewOctets(vec![Octet(1), Octet(2)]);
+    let mut data = PerCodecData::new_aper();
+    few.aper_encode(&mut data).unwrap();
+    assert_eq!(hex::encode(data.into_bytes()), "200102");
+
+    let more = FewOctets((1..=5).map(Octet).collect());
+    let mut data = PerCodecData::new_aper();
+    more.aper_encode(&mut data).unwrap();
+    let encoded = data.into_bytes();
+    assert_eq!(hex::encode(&encoded), "80050102030405");
+    let mut data = PerCodecData::from_slice_aper(&encoded);
+    assert_eq!(FewOctets::aper_decode(&mut data).unwrap(), more);
+
+    let container = Container(vec![0xab; 70000]);
+    let mut data = PerCodecData::new_uper();
+    container.uper_encode(&mut data).unwrap();
+    let mut data = PerCodecData::from_slice_uper(&data.into_bytes());
+    assert_eq!(Container::uper_decode(&mut data).unwrap(), container);
+}
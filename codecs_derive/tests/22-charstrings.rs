@@ -0,0 +1,64 @@
+#![allow(non_camel_case_types)]
+
+use asn1_codecs::PerCodecData;
+use asn1_codecs::{aper::AperCodec, uper::UperCodec};
+use asn1_codecs_derive::{AperCodec, UperCodec};
+
+#[derive(Debug, AperCodec, UperCodec, PartialEq)]
+#[asn(
+    type = "NumericString",
+    sz_extensible = false,
+    sz_lb = "1",
+    sz_ub = "15"
+)]
+pub struct Msisdn(String);
+
+#[derive(Debug, AperCodec, UperCodec, PartialEq)]
+#[asn(type = "IA5String")]
+pub struct Name(String);
+
+#[derive(Debug, AperCodec, UperCodec, PartialEq)]
+#[asn(type = "BMPString")]
+pub struct DisplayName(String);
+
+// The size constraint of a TeletexString is not PER-visible.
+#[derive(Debug, AperCodec, UperCodec, PartialEq)]
+#[asn(type = "T61String", sz_extensible = false, sz_lb = "1", sz_ub = "4")]
+pub struct Label(String);
+
+#[derive(Debug, AperCodec, UperCodec, PartialEq)]
+#[asn(type = "SEQUENCE", extensible = false)]
+pub struct Subscriber {
+    pub msisdn: Msisdn,
+    pub name: Name,
+    pub display_name: DisplayName,
+    pub label: Label,
+}
+
+fn main() {
+    let subscriber = Subscriber {
+        msisdn: Msisdn("123".to_string()),
+        name: Name("Hi".to_string()),
+        display_name: DisplayName("\u{3b1}".to_string()),
+        label: Label("\u{e9}".to_string()),
+    };
+
+    let mut data = PerCodecData::new_aper();
+    subscriber.aper_encode(&mut data).unwrap();
+    let encoded = data.into_bytes();
+    assert_eq!(hex::encode(&encoded), "22340248690103b101e9");
+    let mut data = PerCodecData::from_slice_aper(&encoded);
+    assert_eq!(Subscriber::aper_decode(&mut data).unwrap(), subscriber);
+
+    let mut data = PerCodecData::new_uper();
+    subscriber.uper_encode(&mut data).unwrap();
+    let encoded = data.into_bytes();
+    assert_eq!(hex::encode(&encoded), "22340291a4040ec407a4");
+    let mut data = PerCodecData::from_slice_uper(&encoded);
+    assert_eq!(Subscriber::uper_decode(&mut data).unwrap(), subscriber);
+
+    // Characters outside the alphabet of the type are not encoded.
+    let msisdn = Msisdn("12a".to_string());
+    let mut data = PerCodecData::new_uper();
+    assert!(msisdn.uper_encode(&mut data).is_err());
+}
@@ -0,0 +1,48 @@
+#![allow(non_camel_case_types)]
+
+use asn1_codecs::PerCodecData;
+use asn1_codecs::{aper::AperCodec, uper::UperCodec};
+use asn1_codecs_derive::{AperCodec, UperCodec};
+
+// `VisibleString (FROM ("a".."z" | "A".."Z" | "0".."9" | ".-")) (SIZE (1..255))`
+#[derive(Debug, AperCodec, UperCodec, PartialEq)]
+#[asn(
+    type = "VisibleString",
+    sz_extensible = false,
+    sz_lb = "1",
+    sz_ub = "255",
+    alphabet = "-.0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz"
+)]
+pub struct FQDN(String);
+
+// The PermittedAlphabet constraint of a UTF8String is not PER-visible.
+#[derive(Debug, AperCodec, UperCodec, PartialEq)]
+#[asn(type = "UTF8String", alphabet = "ab")]
+pub struct Note(String);
+
+fn main() {
+    // Six bits for each of the 64 characters, encoded as their indexes in the alphabet.
+    let fqdn = FQDN("ab".to_string());
+    let mut data = PerCodecData::new_uper();
+    fqdn.uper_encode(&mut data).unwrap();
+    let encoded = data.into_bytes();
+    assert_eq!(hex::encode(&encoded), "019a70");
+    let mut data = PerCodecData::from_slice_uper(&encoded);
+    assert_eq!(FQDN::uper_decode(&mut data).unwrap(), fqdn);
+
+    // Eight bits in the ALIGNED variant, which fit the values of the characters.
+    let mut data = PerCodecData::new_aper();
+    fqdn.aper_encode(&mut data).unwrap();
+    let encoded = data.into_bytes();
+    assert_eq!(hex::encode(&encoded), "016162");
+    let mut data = PerCodecData::from_slice_aper(&encoded);
+    assert_eq!(FQDN::aper_decode(&mut data).unwrap(), fqdn);
+
+    let mut data = PerCodecData::new_uper();
+    assert!(FQDN("a_b".to_string()).uper_encode(&mut data).is_err());
+
+    let note = Note("xyz".to_string());
+    let mut data = PerCodecData::new_uper();
+    note.uper_encode(&mut data).unwrap();
+    assert_eq!(hex::encode(data.into_bytes()), "0378797a");
+}
@@ -0,0 +1,50 @@
+#![allow(non_camel_case_types)]
+
+use asn1_codecs::PerCodecData;
+use asn1_codecs::{aper::AperCodec, uper::UperCodec};
+use asn1_codecs_derive::{AperCodec, UperCodec};
+
+#[derive(Debug, AperCodec, UperCodec, PartialEq)]
+#[asn(type = "UTCTime")]
+pub struct AbsoluteTime(String);
+
+#[derive(Debug, AperCodec, UperCodec, PartialEq)]
+#[asn(type = "DATE")]
+pub struct Birthday(String);
+
+#[derive(Debug, AperCodec, UperCodec, PartialEq)]
+#[asn(type = "DURATION")]
+pub struct Validity(String);
+
+fn main() {
+    let time = AbsoluteTime("491231235959Z".to_string());
+    let mut data = PerCodecData::new_uper();
+    time.uper_encode(&mut data).unwrap();
+    let encoded = data.into_bytes();
+    assert_eq!(encoded[0], 0x0d);
+    let mut data = PerCodecData::from_slice_uper(&encoded);
+    assert_eq!(AbsoluteTime::uper_decode(&mut data).unwrap(), time);
+
+    let mut data = PerCodecData::new_uper();
+    assert!(AbsoluteTime("4912312359".to_string())
+        .uper_encode(&mut data)
+        .is_err());
+
+    // The year CHOICE, month and day of the DATE.
+    let birthday = Birthday("2024-02-29".to_string());
+    let mut data = PerCodecData::new_uper();
+    birthday.uper_encode(&mut data).unwrap();
+    assert_eq!(hex::encode(data.into_bytes()), "40c780");
+    let mut data = PerCodecData::new_aper();
+    birthday.aper_encode(&mut data).unwrap();
+    let encoded = data.into_bytes();
+    assert_eq!(hex::encode(&encoded), "40031e00");
+    let mut data = PerCodecData::from_slice_aper(&encoded);
+    assert_eq!(Birthday::aper_decode(&mut data).unwrap(), birthday);
+
+    let validity = Validity("P1Y2MT0.5S".to_string());
+    let mut data = PerCodecData::new_aper();
+    validity.aper_encode(&mut data).unwrap();
+    let mut data = PerCodecData::from_slice_aper(&data.into_bytes());
+    assert_eq!(Validity::aper_decode(&mut data).unwrap(), validity);
+}
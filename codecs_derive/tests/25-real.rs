@@ -0,0 +1,36 @@
+#![allow(non_camel_case_types)]
+
+use asn1_codecs::PerCodecData;
+use asn1_codecs::{aper::AperCodec, uper::UperCodec};
+use asn1_codecs_derive::{AperCodec, UperCodec};
+
+#[derive(Debug, AperCodec, UperCodec, PartialEq)]
+#[asn(type = "REAL")]
+pub struct Measurement(f64);
+
+#[derive(Debug, AperCodec, UperCodec, PartialEq)]
+#[asn(type = "SEQUENCE", extensible = false, optional_fields = 1)]
+pub struct MeasurementReport {
+    pub value: Measurement,
+    #[asn(optional_idx = 0)]
+    pub accuracy: Option<Measurement>,
+}
+
+fn main() {
+    let report = MeasurementReport {
+        value: Measurement(-12.75),
+        accuracy: Some(Measurement(f64::INFINITY)),
+    };
+
+    let mut data = PerCodecData::new_aper();
+    report.aper_encode(&mut data).unwrap();
+    let encoded = data.into_bytes();
+    assert_eq!(hex::encode(&encoded), "8003c0fe330140");
+    let mut data = PerCodecData::from_slice_aper(&encoded);
+    assert_eq!(MeasurementReport::aper_decode(&mut data).unwrap(), report);
+
+    let mut data = PerCodecData::new_uper();
+    report.uper_encode(&mut data).unwrap();
+    let mut data = PerCodecData::from_slice_uper(&data.into_bytes());
+    assert_eq!(MeasurementReport::uper_decode(&mut data).unwrap(), report);
+}
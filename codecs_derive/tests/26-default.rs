@@ -0,0 +1,92 @@
+#![allow(non_camel_case_types)]
+
+use asn1_codecs::{aper::AperCodec, jer::JerCodec, oer::OerCodec, uper::UperCodec, xer::XerCodec};
+use asn1_codecs::{JerCodecData, OerCodecData, PerCodecData, XerCodecData};
+use asn1_codecs_derive::{AperCodec, JerCodec, OerCodec, UperCodec, XerCodec};
+
+#[derive(Debug, AperCodec, UperCodec, OerCodec, JerCodec, XerCodec, PartialEq)]
+#[asn(type = "INTEGER", lb = "0", ub = "7")]
+pub struct Priority(u8);
+
+#[derive(Debug, AperCodec, UperCodec, OerCodec, JerCodec, XerCodec, PartialEq)]
+#[asn(type = "BOOLEAN")]
+pub struct Enabled(bool);
+
+#[derive(Debug, AperCodec, UperCodec, OerCodec, JerCodec, XerCodec, PartialEq)]
+#[asn(type = "SEQUENCE", extensible = false, optional_fields = 2)]
+pub struct Config {
+    #[asn(optional_idx = 0, default = "Config::default_priority")]
+    pub priority: Option<Priority>,
+    #[asn(optional_idx = 1)]
+    pub enabled: Option<Enabled>,
+}
+
+impl Config {
+    pub fn default_priority() -> Priority {
+        Priority(3)
+    }
+}
+
+fn main() {
+    // A value same as the default value is not encoded and is filled in when decoded.
+    for priority in [None, Some(Priority(3))] {
+        let config = Config {
+            priority,
+            enabled: None,
+        };
+        let mut data = PerCodecData::new_aper();
+        config.aper_encode(&mut data).unwrap();
+        let encoded = data.into_bytes();
+        assert_eq!(hex::encode(&encoded), "00");
+
+        let mut data = PerCodecData::from_slice_aper(&encoded);
+        let decoded = Config::aper_decode(&mut data).unwrap();
+        assert_eq!(decoded.priority, Some(Priority(3)));
+    }
+
+    let config = Config {
+        priority: Some(Priority(5)),
+        enabled: Some(Enabled(true)),
+    };
+
+    let mut data = PerCodecData::new_aper();
+    config.aper_encode(&mut data).unwrap();
+    let encoded = data.into_bytes();
+    assert_eq!(hex::encode(&encoded), "ec");
+    let mut data = PerCodecData::from_slice_aper(&encoded);
+    assert_eq!(Config::aper_decode(&mut data).unwrap(), config);
+
+    let mut data = PerCodecData::new_uper();
+    config.uper_encode(&mut data).unwrap();
+    let mut data = PerCodecData::from_slice_uper(&data.into_bytes());
+    assert_eq!(Config::uper_decode(&mut data).unwrap(), config);
+
+    // The DEFAULT value is filled in by the other codecs too.
+    let mut data = OerCodecData::new();
+    Config {
+        priority: Some(Priority(3)),
+        enabled: None,
+    }
+    .oer_encode(&mut data)
+    .unwrap();
+    assert_eq!(hex::encode(data.into_bytes()), "00");
+
+    let mut data = OerCodecData::from_slice_coer(&[0x00]);
+    let decoded = Config::oer_decode(&mut data).unwrap();
+    assert_eq!(decoded.priority, Some(Priority(3)));
+
+    // The DEFAULT value encoded is fine for BASIC-OER but not for COER.
+    let mut data = OerCodecData::from_slice(&[0x80, 0x03]);
+    let decoded = Config::oer_decode(&mut data).unwrap();
+    assert_eq!(decoded.priority, Some(Priority(3)));
+    let mut data = OerCodecData::from_slice_coer(&[0x80, 0x03]);
+    assert!(Config::oer_decode(&mut data).is_err());
+
+    let mut data = JerCodecData::from_json("{}").unwrap();
+    let decoded = Config::jer_decode(&mut data).unwrap();
+    assert_eq!(decoded.priority, Some(Priority(3)));
+
+    let mut data = XerCodecData::from_xml("<Config/>").unwrap();
+    let decoded = Config::xer_decode(&mut data).unwrap();
+    assert_eq!(decoded.priority, Some(Priority(3)));
+}
@@ -0,0 +1,200 @@
+#![allow(non_camel_case_types, dead_code)]
+
+use asn1_codecs::{
+    ber::{der::DerCodec, BerCodec},
+    BerCodecData,
+};
+use asn1_codecs_derive::{BerCodec, DerCodec};
+
+// PersonnelRecord from X.690 Annex A.1
+
+#[derive(Debug, BerCodec, DerCodec, PartialEq)]
+#[asn(
+    type = "SET",
+    extensible = false,
+    optional_fields = 1,
+    tag = "[APPLICATION 0] IMPLICIT"
+)]
+pub struct PersonnelRecord {
+    #[asn(tag = "[APPLICATION 1] IMPLICIT")]
+    pub name: Name,
+    #[asn(tag = "[APPLICATION 2] IMPLICIT")]
+    pub number: EmployeeNumber,
+    #[asn(tag = "[0] EXPLICIT")]
+    pub title: PersonnelRecordTitle,
+    #[asn(tag = "[1] EXPLICIT")]
+    pub date_of_hire: Date,
+    #[asn(tag = "[2] EXPLICIT")]
+    pub name_of_spouse: Name,
+    #[asn(optional_idx = 0, tag = "[3] IMPLICIT")]
+    pub children: Option<PersonnelRecordChildren>,
+}
+
+#[derive(Debug, BerCodec, DerCodec, PartialEq)]
+#[asn(type = "VisibleString")]
+pub struct PersonnelRecordTitle(String);
+
+#[derive(Debug, BerCodec, DerCodec, PartialEq)]
+#[asn(type = "SEQUENCE-OF")]
+pub struct PersonnelRecordChildren(Vec<ChildInformation>);
+
+#[derive(Debug, BerCodec, DerCodec, PartialEq)]
+#[asn(type = "SET", extensible = false)]
+pub struct ChildInformation {
+    #[asn(tag = "[APPLICATION 1] IMPLICIT")]
+    pub name: Name,
+    #[asn(tag = "[0] EXPLICIT")]
+    pub date_of_birth: Date,
+}
+
+#[derive(Debug, BerCodec, DerCodec, PartialEq)]
+#[asn(
+    type = "SEQUENCE",
+    extensible = false,
+    tag = "[APPLICATION 1] IMPLICIT"
+)]
+pub struct Name {
+    #[asn(tag = "[UNIVERSAL 26] IMPLICIT")]
+    pub given_name: NameString,
+    #[asn(tag = "[UNIVERSAL 26] IMPLICIT")]
+    pub initial: NameString,
+    #[asn(tag = "[UNIVERSAL 26] IMPLICIT")]
+    pub family_name: NameString,
+}
+
+#[derive(Debug, BerCodec, DerCodec, PartialEq)]
+#[asn(type = "VisibleString")]
+pub struct NameString(String);
+
+#[derive(Debug, BerCodec, DerCodec, PartialEq)]
+#[asn(type = "INTEGER", tag = "[APPLICATION 2] IMPLICIT")]
+pub struct EmployeeNumber(i64);
+
+#[derive(Debug, BerCodec, DerCodec, PartialEq)]
+#[asn(type = "VisibleString", tag = "[APPLICATION 3] IMPLICIT")]
+pub struct Date(String);
+
+// An untagged CHOICE and an explicitly tagged type
+
+#[derive(Debug, BerCodec, DerCodec, PartialEq)]
+#[asn(type = "CHOICE", lb = "0", ub = "1", extensible = false)]
+pub enum Contact {
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
+    Phone(NameString),
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
+    Email(NameString),
+}
+
+#[derive(Debug, BerCodec, DerCodec, PartialEq)]
+#[asn(type = "INTEGER", tag = "[APPLICATION 5] EXPLICIT")]
+pub struct Version(i64);
+
+#[derive(Debug, BerCodec, DerCodec, PartialEq)]
+#[asn(type = "INTEGER")]
+pub struct Value(i64);
+
+#[derive(Debug, BerCodec, DerCodec, PartialEq)]
+#[asn(type = "SEQUENCE", extensible = false, optional_fields = 1)]
+pub struct Entry {
+    #[asn(optional_idx = 0, tag = "")]
+    pub contact: Option<Contact>,
+    #[asn(tag = "[UNIVERSAL 2] IMPLICIT")]
+    pub value: Value,
+    #[asn(tag = "[0] IMPLICIT")]
+    pub version: Version,
+}
+
+fn name(given_name: &str, initial: &str, family_name: &str) -> Name {
+    Name {
+        given_name: NameString(given_name.to_string()),
+        initial: NameString(initial.to_string()),
+        family_name: NameString(family_name.to_string()),
+    }
+}
+
+fn main() {
+    let record = PersonnelRecord {
+        name: name("John", "P", "Smith"),
+        number: EmployeeNumber(51),
+        title: PersonnelRecordTitle("Director".to_string()),
+        date_of_hire: Date("19710917".to_string()),
+        name_of_spouse: name("Mary", "T", "Smith"),
+        children: Some(PersonnelRecordChildren(vec![
+            ChildInformation {
+                name: name("Ralph", "T", "Smith"),
+                date_of_birth: Date("19571111".to_string()),
+            },
+            ChildInformation {
+                name: name("Susan", "B", "Jones"),
+                date_of_birth: Date("19590717".to_string()),
+            },
+        ])),
+    };
+
+    // The encoding from X.690 A.1, with the components of the SET in the textual order.
+    let textual = "608185\
+        61101a044a6f686e1a01501a05536d697468\
+        a00a1a084469726563746f72\
+        420133\
+        a10a43083139373130393137\
+        a21261101a044d6172791a01541a05536d697468\
+        a342\
+        311f61111a0552616c70681a01541a05536d697468a00a43083139353731313131\
+        311f61111a05537573616e1a01421a054a6f6e6573a00a43083139353930373137";
+
+    let mut data = BerCodecData::from_slice(&hex::decode(textual).unwrap());
+    let decoded = PersonnelRecord::ber_decode(&mut data);
+    assert!(decoded.is_ok(), "{:?}", decoded.err().unwrap());
+    assert_eq!(decoded.unwrap(), record);
+
+    // DER encodes the components of the SET in the canonical order of their tags.
+    let mut data = BerCodecData::new();
+    let result = record.der_encode(&mut data);
+    assert!(result.is_ok(), "{:?}", result.err().unwrap());
+    let encoded = hex::encode(data.into_bytes());
+    let canonical = textual.replacen(
+        "a00a1a084469726563746f72420133",
+        "420133a00a1a084469726563746f72",
+        1,
+    );
+    assert_eq!(encoded, canonical);
+
+    let mut data = BerCodecData::from_slice_der(&hex::decode(canonical).unwrap());
+    assert_eq!(PersonnelRecord::der_decode(&mut data).unwrap(), record);
+
+    let mut data = BerCodecData::from_slice_der(&hex::decode(textual).unwrap());
+    assert!(PersonnelRecord::der_decode(&mut data).is_err());
+
+    // An explicitly tagged type, tagged implicitly in a component.
+    let mut data = BerCodecData::new();
+    Version(1).ber_encode(&mut data).unwrap();
+    assert_eq!(hex::encode(data.into_bytes()), "6503020101");
+
+    // An untagged CHOICE in an OPTIONAL component is present only if it can be decoded.
+    let entries = vec![
+        (
+            Entry {
+                contact: Some(Contact::Email(NameString("a".to_string()))),
+                value: Value(5),
+                version: Version(1),
+            },
+            "300b810161020105a003020101",
+        ),
+        (
+            Entry {
+                contact: None,
+                value: Value(5),
+                version: Version(1),
+            },
+            "3008020105a003020101",
+        ),
+    ];
+    for (entry, expected) in entries {
+        let mut data = BerCodecData::new();
+        entry.ber_encode(&mut data).unwrap();
+        assert_eq!(hex::encode(data.into_bytes()), expected);
+
+        let mut data = BerCodecData::from_slice(&hex::decode(expected).unwrap());
+        assert_eq!(Entry::ber_decode(&mut data).unwrap(), entry);
+    }
+}
@@ -0,0 +1,94 @@
+#![allow(dead_code)]
+
+use asn1_codecs::{aper::AperCodec, ber::BerCodec, PerCodecData};
+use asn1_codecs_derive::{AperCodec, BerCodec, UperCodec};
+
+#[derive(Debug, AperCodec, UperCodec, BerCodec, PartialEq)]
+#[asn(type = "INTEGER", lb = "0", ub = "255")]
+pub struct InnerId(pub u8);
+
+#[derive(Debug, AperCodec, UperCodec, BerCodec, PartialEq)]
+#[asn(type = "BOOLEAN")]
+pub struct InnerFlag(pub bool);
+
+#[derive(Debug, AperCodec, UperCodec, BerCodec, PartialEq)]
+#[asn(type = "SEQUENCE", extensible = false)]
+pub struct Inner {
+    #[asn(tag = "[0] IMPLICIT")]
+    pub id: InnerId,
+    #[asn(tag = "[1] IMPLICIT")]
+    pub flag: InnerFlag,
+}
+
+// Container ::= OCTET STRING (CONTAINING Inner)
+#[derive(Debug, AperCodec, UperCodec, BerCodec, PartialEq)]
+#[asn(type = "OCTET-STRING")]
+pub struct Container(pub Vec<u8>);
+impl Container {
+    pub fn aper_decode_contents(&self) -> Result<Inner, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::from_slice_aper(&self.0);
+        <Inner as asn1_codecs::aper::AperCodec>::aper_decode(&mut data)
+    }
+    pub fn aper_encode_contents(contents: &Inner) -> Result<Self, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::new_aper();
+        asn1_codecs::aper::AperCodec::aper_encode(contents, &mut data)?;
+        Ok(Self(data.into_bytes()))
+    }
+    pub fn ber_decode_contents(&self) -> Result<Inner, asn1_codecs::BerCodecError> {
+        let mut data = asn1_codecs::BerCodecData::from_slice(&self.0);
+        <Inner as asn1_codecs::ber::BerCodec>::ber_decode(&mut data)
+    }
+    pub fn ber_encode_contents(contents: &Inner) -> Result<Self, asn1_codecs::BerCodecError> {
+        let mut data = asn1_codecs::BerCodecData::new();
+        asn1_codecs::ber::BerCodec::ber_encode(contents, &mut data)?;
+        Ok(Self(data.into_bytes()))
+    }
+}
+
+// BitContainer ::= BIT STRING (CONTAINING Inner ENCODED BY
+//     { joint-iso-itu-t asn1(1) packed-encoding(3) basic(0) unaligned(1) })
+#[derive(Debug, AperCodec, UperCodec, BerCodec, PartialEq)]
+#[asn(type = "BITSTRING")]
+pub struct BitContainer(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
+impl BitContainer {
+    pub fn uper_decode_contents(&self) -> Result<Inner, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::from_slice_uper(self.0.as_raw_slice());
+        <Inner as asn1_codecs::uper::UperCodec>::uper_decode(&mut data)
+    }
+    pub fn uper_encode_contents(contents: &Inner) -> Result<Self, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::new_uper();
+        asn1_codecs::uper::UperCodec::uper_encode(contents, &mut data)?;
+        Ok(Self(bitvec::vec::BitVec::from_vec(data.into_bytes())))
+    }
+}
+
+fn main() {
+    let inner = Inner {
+        id: InnerId(0x5a),
+        flag: InnerFlag(true),
+    };
+
+    // The raw bytes of the contained value remain available.
+    let container = Container::aper_encode_contents(&inner).unwrap();
+    assert_eq!(hex::encode(&container.0), "5a80");
+    assert_eq!(container.aper_decode_contents().unwrap(), inner);
+
+    // The container itself is encoded as any other OCTET STRING.
+    let mut data = PerCodecData::new_aper();
+    container.aper_encode(&mut data).unwrap();
+    let encoded = data.into_bytes();
+    assert_eq!(hex::encode(&encoded), "025a80");
+    let mut data = PerCodecData::from_slice_aper(&encoded);
+    let decoded = Container::aper_decode(&mut data).unwrap();
+    assert_eq!(decoded.aper_decode_contents().unwrap(), inner);
+
+    let container = Container::ber_encode_contents(&inner).unwrap();
+    assert_eq!(hex::encode(&container.0), "300680015a8101ff");
+    assert_eq!(container.ber_decode_contents().unwrap(), inner);
+    let mut data = asn1_codecs::BerCodecData::new();
+    container.ber_encode(&mut data).unwrap();
+    assert_eq!(hex::encode(data.into_bytes()), "0408300680015a8101ff");
+
+    let bit_container = BitContainer::uper_encode_contents(&inner).unwrap();
+    assert_eq!(bit_container.uper_decode_contents().unwrap(), inner);
+}
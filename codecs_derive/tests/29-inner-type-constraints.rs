@@ -0,0 +1,142 @@
+#![allow(dead_code)]
+
+use asn1_codecs::{aper::AperCodec, ber::BerCodec, uper::UperCodec, BerCodecData, PerCodecData};
+use asn1_codecs_derive::{AperCodec, BerCodec, UperCodec};
+
+#[derive(Debug, AperCodec, UperCodec, BerCodec, PartialEq)]
+#[asn(type = "INTEGER", lb = "0", ub = "255")]
+pub struct Count(pub u8);
+
+#[derive(Debug, AperCodec, UperCodec, BerCodec, PartialEq)]
+#[asn(type = "BOOLEAN")]
+pub struct Flag(pub bool);
+
+// Base ::= SEQUENCE { a INTEGER (0..255), b BOOLEAN OPTIONAL }
+#[derive(Debug, AperCodec, UperCodec, BerCodec, PartialEq)]
+#[asn(type = "SEQUENCE", extensible = false, optional_fields = 1)]
+pub struct Base {
+    #[asn(tag = "[0] IMPLICIT")]
+    pub a: Count,
+    #[asn(optional_idx = 0, tag = "[1] IMPLICIT")]
+    pub b: Option<Flag>,
+}
+
+// Restricted ::= Base (WITH COMPONENTS { ..., a (1..10), b PRESENT })
+#[derive(Debug, AperCodec, UperCodec, BerCodec, PartialEq)]
+#[asn(
+    type = "SEQUENCE",
+    extensible = false,
+    optional_fields = 1,
+    validate = true,
+    validate_decode = true
+)]
+pub struct Restricted {
+    #[asn(tag = "[0] IMPLICIT")]
+    pub a: Count,
+    #[asn(optional_idx = 0, tag = "[1] IMPLICIT")]
+    pub b: Option<Flag>,
+}
+impl Restricted {
+    pub fn validate(&self) -> Result<(), String> {
+        if (1..=10).contains(&(self.a.0 as i128)) && self.b.is_some() {
+            Ok(())
+        } else {
+            Err("Value of 'Restricted' does not satisfy the constraint.".to_string())
+        }
+    }
+}
+
+// OnlyCount ::= CHOICE { count INTEGER (0..255), flag BOOLEAN } (WITH COMPONENTS { count PRESENT })
+#[derive(Debug, AperCodec, UperCodec, BerCodec, PartialEq)]
+#[asn(
+    type = "CHOICE",
+    lb = "0",
+    ub = "1",
+    extensible = false,
+    validate = true
+)]
+pub enum OnlyCount {
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
+    Count(Count),
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
+    Flag(Flag),
+}
+impl OnlyCount {
+    pub fn validate(&self) -> Result<(), String> {
+        if matches!(self, OnlyCount::Count(..)) && !matches!(self, OnlyCount::Flag(..)) {
+            Ok(())
+        } else {
+            Err("Value of 'OnlyCount' does not satisfy the constraint.".to_string())
+        }
+    }
+}
+
+// SmallCounts ::= SEQUENCE OF Count (WITH COMPONENT (0..9))
+#[derive(Debug, AperCodec, UperCodec, BerCodec, PartialEq)]
+#[asn(type = "SEQUENCE-OF", validate = true)]
+pub struct SmallCounts(pub Vec<Count>);
+impl SmallCounts {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.0.iter().all(|v| (0..=9).contains(&(v.0 as i128))) {
+            Ok(())
+        } else {
+            Err("Value of 'SmallCounts' does not satisfy the constraint.".to_string())
+        }
+    }
+}
+
+fn main() {
+    // A valid value is encoded and decoded as usual.
+    let restricted = Restricted {
+        a: Count(5),
+        b: Some(Flag(true)),
+    };
+    let mut data = PerCodecData::new_aper();
+    restricted.aper_encode(&mut data).unwrap();
+    let encoded = data.into_bytes();
+    let mut data = PerCodecData::from_slice_aper(&encoded);
+    assert_eq!(Restricted::aper_decode(&mut data).unwrap(), restricted);
+
+    // An invalid value is not encoded.
+    let invalid = Restricted {
+        a: Count(5),
+        b: None,
+    };
+    let mut data = PerCodecData::new_uper();
+    assert!(invalid.uper_encode(&mut data).is_err());
+    let mut data = BerCodecData::new();
+    assert!(invalid.ber_encode(&mut data).is_err());
+
+    // A decoded value that is not valid is a decode error.
+    let base = Base {
+        a: Count(20),
+        b: Some(Flag(false)),
+    };
+    let mut data = PerCodecData::new_aper();
+    base.aper_encode(&mut data).unwrap();
+    let encoded = data.into_bytes();
+    let mut data = PerCodecData::from_slice_aper(&encoded);
+    assert!(Restricted::aper_decode(&mut data).is_err());
+
+    let mut data = BerCodecData::new();
+    base.ber_encode(&mut data).unwrap();
+    let encoded = data.into_bytes();
+    let mut data = BerCodecData::from_slice(&encoded);
+    assert!(Restricted::ber_decode(&mut data).is_err());
+
+    // Only the `CHOICE` alternative that is `PRESENT` is allowed.
+    let mut data = PerCodecData::new_aper();
+    assert!(OnlyCount::Count(Count(3)).aper_encode(&mut data).is_ok());
+    let mut data = PerCodecData::new_aper();
+    assert!(OnlyCount::Flag(Flag(true)).aper_encode(&mut data).is_err());
+
+    // Every element of the `SEQUENCE OF` is checked.
+    let mut data = PerCodecData::new_aper();
+    assert!(SmallCounts(vec![Count(1), Count(9)])
+        .aper_encode(&mut data)
+        .is_ok());
+    let mut data = PerCodecData::new_aper();
+    assert!(SmallCounts(vec![Count(1), Count(10)])
+        .aper_encode(&mut data)
+        .is_err());
+}
@@ -13,4 +13,22 @@ fn tests() {
     t.pass("tests/09-open.rs");
     t.pass("tests/10-seqof.rs");
     t.pass("tests/11-issue-59.rs");
+    t.pass("tests/12-ber.rs");
+    t.pass("tests/13-der.rs");
+    t.pass("tests/14-oer.rs");
+    t.pass("tests/15-jer.rs");
+    t.pass("tests/16-xer.rs");
+    t.pass("tests/17-seq-extensions.rs");
+    t.pass("tests/18-choice-extensions.rs");
+    t.pass("tests/19-unknown-extensions.rs");
+    t.pass("tests/20-oid.rs");
+    t.pass("tests/21-fragmentation.rs");
+    t.pass("tests/22-charstrings.rs");
+    t.pass("tests/23-permitted-alphabet.rs");
+    t.pass("tests/24-time.rs");
+    t.pass("tests/25-real.rs");
+    t.pass("tests/26-default.rs");
+    t.pass("tests/27-tags.rs");
+    t.pass("tests/28-contents.rs");
+    t.pass("tests/29-inner-type-constraints.rs");
 }
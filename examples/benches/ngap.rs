@@ -1,9 +1,820 @@
 #![allow(dead_code, unreachable_patterns, non_camel_case_types)]
-use asn1_codecs_derive::AperCodec;
-use bitvec::order::Msb0;
-use bitvec::vec::BitVec;
 
-#[derive(Debug, AperCodec)]
+pub const ID_AMF_TNL_ASSOCIATION_FAILED_TO_SETUP_LIST: u16 = 4;
+
+pub const ID_AMF_TNL_ASSOCIATION_SETUP_LIST: u16 = 5;
+
+pub const ID_AMF_TNL_ASSOCIATION_TO_ADD_LIST: u16 = 6;
+
+pub const ID_AMF_TNL_ASSOCIATION_TO_REMOVE_LIST: u16 = 7;
+
+pub const ID_AMF_TNL_ASSOCIATION_TO_UPDATE_LIST: u16 = 8;
+
+pub const ID_AMF_UE_NGAP_ID: u16 = 10;
+
+pub const ID_AMFCP_RELOCATION_INDICATION: u8 = 64;
+
+pub const ID_AMF_CONFIGURATION_UPDATE: u8 = 0;
+
+pub const ID_AMF_NAME: u16 = 1;
+
+pub const ID_AMF_OVERLOAD_RESPONSE: u16 = 2;
+
+pub const ID_AMF_SET_ID: u16 = 3;
+
+pub const ID_AMF_STATUS_INDICATION: u8 = 1;
+
+pub const ID_AMF_TRAFFIC_LOAD_REDUCTION_INDICATION: u16 = 9;
+
+pub const ID_ADDITIONAL_DL_FORWARDING_UPTNL_INFORMATION: u16 = 152;
+
+pub const ID_ADDITIONAL_DL_QOS_FLOW_PER_TNL_INFORMATION: u16 = 155;
+
+pub const ID_ADDITIONAL_DLUPTNL_INFORMATION_FOR_HO_LIST: u16 = 153;
+
+pub const ID_ADDITIONAL_NGU_UP_TNL_INFORMATION: u16 = 154;
+
+pub const ID_ADDITIONAL_REDUNDANT_DL_NGU_UP_TNL_INFORMATION: u16 = 183;
+
+pub const ID_ADDITIONAL_REDUNDANT_DL_QOS_FLOW_PER_TNL_INFORMATION: u16 = 184;
+
+pub const ID_ADDITIONAL_REDUNDANT_NGU_UP_TNL_INFORMATION: u16 = 185;
+
+pub const ID_ADDITIONAL_REDUNDANT_UL_NGU_UP_TNL_INFORMATION: u16 = 186;
+
+pub const ID_ADDITIONAL_UL_NGU_UP_TNL_INFORMATION: u16 = 126;
+
+pub const ID_ADDITIONAL_UL_FORWARDING_UPTNL_INFORMATION: u16 = 172;
+
+pub const ID_ALLOWED_NSSAI: u16 = 0;
+
+pub const ID_ALTERNATIVE_QO_S_PARA_SET_LIST: u16 = 220;
+
+pub const ID_ASSISTANCE_DATA_FOR_PAGING: u16 = 11;
+
+pub const ID_AUTHENTICATED_INDICATION: u16 = 245;
+
+pub const ID_BROADCAST_CANCELLED_AREA_LIST: u16 = 12;
+
+pub const ID_BROADCAST_COMPLETED_AREA_LIST: u16 = 13;
+
+pub const ID_BURST_ARRIVAL_TIME_DOWNLINK: u16 = 279;
+
+pub const ID_C_EMODE_B_SUPPORT_INDICATOR: u16 = 224;
+
+pub const ID_C_EMODE_BRESTRICTED: u16 = 222;
+
+pub const ID_CN_ASSISTED_RAN_TUNING: u16 = 165;
+
+pub const ID_CN_PACKET_DELAY_BUDGET_DL: u16 = 187;
+
+pub const ID_CN_PACKET_DELAY_BUDGET_UL: u16 = 188;
+
+pub const ID_CN_TYPE_RESTRICTIONS_FOR_EQUIVALENT: u16 = 160;
+
+pub const ID_CN_TYPE_RESTRICTIONS_FOR_SERVING: u16 = 161;
+
+pub const ID_CANCEL_ALL_WARNING_MESSAGES: u16 = 14;
+
+pub const ID_CAUSE: u16 = 15;
+
+pub const ID_CELL_ID_LIST_FOR_RESTART: u16 = 16;
+
+pub const ID_CELL_TRAFFIC_TRACE: u8 = 2;
+
+pub const ID_COMMON_NETWORK_INSTANCE: u16 = 166;
+
+pub const ID_CONCURRENT_WARNING_MESSAGE_IND: u16 = 17;
+
+pub const ID_CONFIGURED_TAC_INDICATION: u16 = 272;
+
+pub const ID_CONNECTION_ESTABLISHMENT_INDICATION: u8 = 65;
+
+pub const ID_CORE_NETWORK_ASSISTANCE_INFORMATION_FOR_INACTIVE: u16 = 18;
+
+pub const ID_CRITICALITY_DIAGNOSTICS: u16 = 19;
+
+pub const ID_CURRENT_QO_S_PARA_SET_INDEX: u16 = 221;
+
+pub const ID_DAPS_REQUEST_INFO: u16 = 266;
+
+pub const ID_DAPS_RESPONSE_INFO_LIST: u16 = 267;
+
+pub const ID_DL_CP_SECURITY_INFORMATION: u16 = 212;
+
+pub const ID_DL_NGU_UP_TNL_INFORMATION: u16 = 128;
+
+pub const ID_DATA_CODING_SCHEME: u16 = 20;
+
+pub const ID_DATA_FORWARDING_NOT_POSSIBLE: u16 = 127;
+
+pub const ID_DATA_FORWARDING_RESPONSE_ERAB_LIST: u16 = 249;
+
+pub const ID_DEACTIVATE_TRACE: u8 = 3;
+
+pub const ID_DEFAULT_PAGING_DRX: u16 = 21;
+
+pub const ID_DIRECT_FORWARDING_PATH_AVAILABILITY: u16 = 22;
+
+pub const ID_DOWNLINK_NAS_TRANSPORT: u8 = 4;
+
+pub const ID_DOWNLINK_NON_UE_ASSOCIATED_NRP_PA_TRANSPORT: u8 = 5;
+
+pub const ID_DOWNLINK_RAN_CONFIGURATION_TRANSFER: u8 = 6;
+
+pub const ID_DOWNLINK_RAN_EARLY_STATUS_TRANSFER: u8 = 63;
+
+pub const ID_DOWNLINK_RAN_STATUS_TRANSFER: u8 = 7;
+
+pub const ID_DOWNLINK_RIM_INFORMATION_TRANSFER: u8 = 54;
+
+pub const ID_DOWNLINK_UE_ASSOCIATED_NRP_PA_TRANSPORT: u8 = 8;
+
+pub const ID_EDT_SESSION: u16 = 227;
+
+pub const ID_ENDC_SON_CONFIGURATION_TRANSFER_DL: u16 = 157;
+
+pub const ID_ENDC_SON_CONFIGURATION_TRANSFER_UL: u16 = 158;
+
+pub const ID_EUTRA_CGI: u16 = 25;
+
+pub const ID_EARLY_STATUS_TRANSFER_TRANSPARENT_CONTAINER: u16 = 268;
+
+pub const ID_EMERGENCY_AREA_ID_LIST_FOR_RESTART: u16 = 23;
+
+pub const ID_EMERGENCY_FALLBACK_INDICATOR: u16 = 24;
+
+pub const ID_END_INDICATION: u16 = 226;
+
+pub const ID_ENDPOINT_IP_ADDRESS_AND_PORT: u16 = 169;
+
+pub const ID_ENHANCED_COVERAGE_RESTRICTION: u16 = 205;
+
+pub const ID_ERROR_INDICATION: u8 = 9;
+
+pub const ID_EXTENDED_AMF_NAME: u16 = 274;
+
+pub const ID_EXTENDED_CONNECTED_TIME: u16 = 206;
+
+pub const ID_EXTENDED_RAN_NODE_NAME: u16 = 273;
+
+pub const ID_EXTENDED_PACKET_DELAY_BUDGET: u16 = 189;
+
+pub const ID_EXTENDED_RAT_RESTRICTION_INFORMATION: u16 = 180;
+
+pub const ID_EXTENDED_SLICE_SUPPORT_LIST: u16 = 270;
+
+pub const ID_EXTENDED_TAI_SLICE_SUPPORT_LIST: u16 = 271;
+
+pub const ID_EXTENDED_UE_IDENTITY_INDEX_VALUE: u16 = 280;
+
+pub const ID_FIVE_G_S_TMSI: u16 = 26;
+
+pub const ID_GUAMI: u16 = 28;
+
+pub const ID_GUAMI_TYPE: u16 = 176;
+
+pub const ID_GLOBAL_CABLE_ID: u16 = 275;
+
+pub const ID_GLOBAL_RAN_NODE_ID: u16 = 27;
+
+pub const ID_GLOBAL_TNGF_ID: u16 = 240;
+
+pub const ID_GLOBAL_TWIF_ID: u16 = 241;
+
+pub const ID_GLOBAL_W_AGF_ID: u16 = 242;
+
+pub const ID_HANDOVER_CANCEL: u8 = 10;
+
+pub const ID_HANDOVER_FLAG: u16 = 143;
+
+pub const ID_HANDOVER_NOTIFICATION: u8 = 11;
+
+pub const ID_HANDOVER_PREPARATION: u8 = 12;
+
+pub const ID_HANDOVER_RESOURCE_ALLOCATION: u8 = 13;
+
+pub const ID_HANDOVER_SUCCESS: u8 = 61;
+
+pub const ID_HANDOVER_TYPE: u16 = 29;
+
+pub const ID_IAB_AUTHORIZED: u16 = 199;
+
+pub const ID_IAB_SUPPORTED: u16 = 200;
+
+pub const ID_IAB_NODE_INDICATION: u16 = 201;
+
+pub const ID_IMS_VOICE_SUPPORT_INDICATOR: u16 = 30;
+
+pub const ID_INDEX_TO_RFSP: u16 = 31;
+
+pub const ID_INFO_ON_RECOMMENDED_CELLS_AND_RAN_NODES_FOR_PAGING: u16 = 32;
+
+pub const ID_INITIAL_CONTEXT_SETUP: u8 = 14;
+
+pub const ID_INITIAL_UE_MESSAGE: u8 = 15;
+
+pub const ID_INTERSYSTEM_SON_CONFIGURATION_TRANSFER_DL: u16 = 250;
+
+pub const ID_INTERSYSTEM_SON_CONFIGURATION_TRANSFER_UL: u16 = 251;
+
+pub const ID_LTEM_INDICATION: u16 = 225;
+
+pub const ID_LTEUE_SIDELINK_AGGREGATE_MAXIMUM_BITRATE: u16 = 217;
+
+pub const ID_LTEV2X_SERVICES_AUTHORIZED: u16 = 215;
+
+pub const ID_LAST_EUTRAN_PLMN_IDENTITY: u16 = 150;
+
+pub const ID_LOCATION_REPORT: u8 = 18;
+
+pub const ID_LOCATION_REPORTING_ADDITIONAL_INFO: u16 = 170;
+
+pub const ID_LOCATION_REPORTING_CONTROL: u8 = 16;
+
+pub const ID_LOCATION_REPORTING_FAILURE_INDICATION: u8 = 17;
+
+pub const ID_LOCATION_REPORTING_REQUEST_TYPE: u16 = 33;
+
+pub const ID_MDT_CONFIGURATION: u16 = 255;
+
+pub const ID_MANAGEMENT_BASED_MDTPLMN_LIST: u16 = 254;
+
+pub const ID_MASKED_IMEISV: u16 = 34;
+
+pub const ID_MAXIMUM_INTEGRITY_PROTECTED_DATA_RATE_DL: u16 = 151;
+
+pub const ID_MESSAGE_IDENTIFIER: u16 = 35;
+
+pub const ID_MOBILITY_RESTRICTION_LIST: u16 = 36;
+
+pub const ID_NAS_PDU: u16 = 38;
+
+pub const ID_NASC: u16 = 37;
+
+pub const ID_NAS_NON_DELIVERY_INDICATION: u8 = 19;
+
+pub const ID_NAS_SECURITY_PARAMETERS_FROM_NGRAN: u16 = 39;
+
+pub const ID_NB_IO_T_DEFAULT_PAGING_DRX: u16 = 204;
+
+pub const ID_NB_IO_T_PAGING_E_DRX_INFO: u16 = 203;
+
+pub const ID_NB_IO_T_PAGING_DRX: u16 = 202;
+
+pub const ID_NB_IO_T_UE_PRIORITY: u16 = 210;
+
+pub const ID_NGAP_MESSAGE: u16 = 42;
+
+pub const ID_NGRAN_CGI: u16 = 43;
+
+pub const ID_NGRAN_TNL_ASSOCIATION_TO_REMOVE_LIST: u16 = 167;
+
+pub const ID_NGRAN_TRACE_ID: u16 = 44;
+
+pub const ID_NG_RESET: u8 = 20;
+
+pub const ID_NG_SETUP: u8 = 21;
+
+pub const ID_NID: u16 = 263;
+
+pub const ID_NPN_ACCESS_INFORMATION: u16 = 259;
+
+pub const ID_NPN_MOBILITY_INFORMATION: u16 = 261;
+
+pub const ID_NPN_PAGING_ASSISTANCE_INFORMATION: u16 = 260;
+
+pub const ID_NPN_SUPPORT: u16 = 258;
+
+pub const ID_NR_CGI: u16 = 45;
+
+pub const ID_NRP_PA_PDU: u16 = 46;
+
+pub const ID_NRUE_SIDELINK_AGGREGATE_MAXIMUM_BITRATE: u16 = 218;
+
+pub const ID_NRV2X_SERVICES_AUTHORIZED: u16 = 216;
+
+pub const ID_NETWORK_INSTANCE: u16 = 129;
+
+pub const ID_NEW_AMF_UE_NGAP_ID: u16 = 40;
+
+pub const ID_NEW_GUAMI: u16 = 162;
+
+pub const ID_NEW_SECURITY_CONTEXT_IND: u16 = 41;
+
+pub const ID_NOTIFY_SOURCE_NGRAN_NODE: u16 = 269;
+
+pub const ID_NUMBER_OF_BROADCASTS_REQUESTED: u16 = 47;
+
+pub const ID_OLD_AMF: u16 = 48;
+
+pub const ID_OLD_ASSOCIATED_QOS_FLOW_LIST_U_LENDMARKEREXPECTED: u16 = 159;
+
+pub const ID_OVERLOAD_START: u8 = 22;
+
+pub const ID_OVERLOAD_START_NSSAI_LIST: u16 = 49;
+
+pub const ID_OVERLOAD_STOP: u8 = 23;
+
+pub const ID_PC5_QO_S_PARAMETERS: u16 = 219;
+
+pub const ID_PDU_SESSION_AGGREGATE_MAXIMUM_BIT_RATE: u16 = 130;
+
+pub const ID_PDU_SESSION_RESOURCE_ADMITTED_LIST: u16 = 53;
+
+pub const ID_PDU_SESSION_RESOURCE_FAILED_TO_MODIFY_LIST_MOD_CFM: u16 = 131;
+
+pub const ID_PDU_SESSION_RESOURCE_FAILED_TO_MODIFY_LIST_MOD_RES: u16 = 54;
+
+pub const ID_PDU_SESSION_RESOURCE_FAILED_TO_RESUME_LIST_RES_REQ: u16 = 229;
+
+pub const ID_PDU_SESSION_RESOURCE_FAILED_TO_RESUME_LIST_RES_RES: u16 = 230;
+
+pub const ID_PDU_SESSION_RESOURCE_FAILED_TO_SETUP_LIST_CXT_FAIL: u16 = 132;
+
+pub const ID_PDU_SESSION_RESOURCE_FAILED_TO_SETUP_LIST_CXT_RES: u16 = 55;
+
+pub const ID_PDU_SESSION_RESOURCE_FAILED_TO_SETUP_LIST_HO_ACK: u16 = 56;
+
+pub const ID_PDU_SESSION_RESOURCE_FAILED_TO_SETUP_LIST_PS_REQ: u16 = 57;
+
+pub const ID_PDU_SESSION_RESOURCE_FAILED_TO_SETUP_LIST_SU_RES: u16 = 58;
+
+pub const ID_PDU_SESSION_RESOURCE_HANDOVER_LIST: u16 = 59;
+
+pub const ID_PDU_SESSION_RESOURCE_LIST_CXT_REL_CPL: u16 = 60;
+
+pub const ID_PDU_SESSION_RESOURCE_LIST_CXT_REL_REQ: u16 = 133;
+
+pub const ID_PDU_SESSION_RESOURCE_LIST_HO_RQD: u16 = 61;
+
+pub const ID_PDU_SESSION_RESOURCE_MODIFY: u8 = 26;
+
+pub const ID_PDU_SESSION_RESOURCE_MODIFY_INDICATION: u8 = 27;
+
+pub const ID_PDU_SESSION_RESOURCE_MODIFY_LIST_MOD_CFM: u16 = 62;
+
+pub const ID_PDU_SESSION_RESOURCE_MODIFY_LIST_MOD_IND: u16 = 63;
+
+pub const ID_PDU_SESSION_RESOURCE_MODIFY_LIST_MOD_REQ: u16 = 64;
+
+pub const ID_PDU_SESSION_RESOURCE_MODIFY_LIST_MOD_RES: u16 = 65;
+
+pub const ID_PDU_SESSION_RESOURCE_NOTIFY: u8 = 30;
+
+pub const ID_PDU_SESSION_RESOURCE_NOTIFY_LIST: u16 = 66;
+
+pub const ID_PDU_SESSION_RESOURCE_RELEASE: u8 = 28;
+
+pub const ID_PDU_SESSION_RESOURCE_RELEASE_RESPONSE_TRANSFER: u16 = 145;
+
+pub const ID_PDU_SESSION_RESOURCE_RELEASED_LIST_NOT: u16 = 67;
+
+pub const ID_PDU_SESSION_RESOURCE_RELEASED_LIST_PS_ACK: u16 = 68;
+
+pub const ID_PDU_SESSION_RESOURCE_RELEASED_LIST_PS_FAIL: u16 = 69;
+
+pub const ID_PDU_SESSION_RESOURCE_RELEASED_LIST_REL_RES: u16 = 70;
+
+pub const ID_PDU_SESSION_RESOURCE_RESUME_LIST_RES_REQ: u16 = 232;
+
+pub const ID_PDU_SESSION_RESOURCE_RESUME_LIST_RES_RES: u16 = 233;
+
+pub const ID_PDU_SESSION_RESOURCE_SECONDARY_RAT_USAGE_LIST: u16 = 142;
+
+pub const ID_PDU_SESSION_RESOURCE_SETUP: u8 = 29;
+
+pub const ID_PDU_SESSION_RESOURCE_SETUP_LIST_CXT_REQ: u16 = 71;
+
+pub const ID_PDU_SESSION_RESOURCE_SETUP_LIST_CXT_RES: u16 = 72;
+
+pub const ID_PDU_SESSION_RESOURCE_SETUP_LIST_HO_REQ: u16 = 73;
+
+pub const ID_PDU_SESSION_RESOURCE_SETUP_LIST_SU_REQ: u16 = 74;
+
+pub const ID_PDU_SESSION_RESOURCE_SETUP_LIST_SU_RES: u16 = 75;
+
+pub const ID_PDU_SESSION_RESOURCE_SUSPEND_LIST_SUS_REQ: u16 = 231;
+
+pub const ID_PDU_SESSION_RESOURCE_SWITCHED_LIST: u16 = 77;
+
+pub const ID_PDU_SESSION_RESOURCE_TO_BE_SWITCHED_DL_LIST: u16 = 76;
+
+pub const ID_PDU_SESSION_RESOURCE_TO_RELEASE_LIST_HO_CMD: u16 = 78;
+
+pub const ID_PDU_SESSION_RESOURCE_TO_RELEASE_LIST_REL_CMD: u16 = 79;
+
+pub const ID_PDU_SESSION_TYPE: u16 = 134;
+
+pub const ID_PLMN_SUPPORT_LIST: u16 = 80;
+
+pub const ID_PS_CELL_INFORMATION: u16 = 149;
+
+pub const ID_PWS_CANCEL: u8 = 32;
+
+pub const ID_PWS_FAILED_CELL_ID_LIST: u16 = 81;
+
+pub const ID_PWS_FAILURE_INDICATION: u8 = 33;
+
+pub const ID_PWS_RESTART_INDICATION: u8 = 34;
+
+pub const ID_PAGING: u8 = 24;
+
+pub const ID_PAGING_ASSIS_DATAFOR_C_ECAPAB_UE: u16 = 207;
+
+pub const ID_PAGING_DRX: u16 = 50;
+
+pub const ID_PAGING_ORIGIN: u16 = 51;
+
+pub const ID_PAGING_PRIORITY: u16 = 52;
+
+pub const ID_PAGINGE_DRX_INFORMATION: u16 = 223;
+
+pub const ID_PATH_SWITCH_REQUEST: u8 = 25;
+
+pub const ID_PRIVACY_INDICATOR: u16 = 256;
+
+pub const ID_PRIVATE_MESSAGE: u8 = 31;
+
+pub const ID_QOS_FLOW_ADD_OR_MODIFY_REQUEST_LIST: u16 = 135;
+
+pub const ID_QOS_FLOW_FEEDBACK_LIST: u16 = 278;
+
+pub const ID_QOS_FLOW_PARAMETERS_LIST: u16 = 277;
+
+pub const ID_QOS_FLOW_SETUP_REQUEST_LIST: u16 = 136;
+
+pub const ID_QOS_FLOW_TO_RELEASE_LIST: u16 = 137;
+
+pub const ID_QOS_MONITORING_REPORTING_FREQUENCY: u16 = 276;
+
+pub const ID_QOS_MONITORING_REQUEST: u16 = 181;
+
+pub const ID_RAN_UE_NGAP_ID: u16 = 85;
+
+pub const ID_RANCP_RELOCATION_INDICATION: u8 = 57;
+
+pub const ID_RAN_CONFIGURATION_UPDATE: u8 = 35;
+
+pub const ID_RAN_NODE_NAME: u16 = 82;
+
+pub const ID_RAN_PAGING_PRIORITY: u16 = 83;
+
+pub const ID_RAN_STATUS_TRANSFER_TRANSPARENT_CONTAINER: u16 = 84;
+
+pub const ID_RAT_INFORMATION: u16 = 179;
+
+pub const ID_RG_LEVEL_WIRELINE_ACCESS_CHARACTERISTICS: u16 = 238;
+
+pub const ID_RIM_INFORMATION_TRANSFER: u16 = 175;
+
+pub const ID_RRC_RESUME_CAUSE: u16 = 237;
+
+pub const ID_RRC_ESTABLISHMENT_CAUSE: u16 = 90;
+
+pub const ID_RRC_INACTIVE_TRANSITION_REPORT: u8 = 37;
+
+pub const ID_RRC_INACTIVE_TRANSITION_REPORT_REQUEST: u16 = 91;
+
+pub const ID_RRC_STATE: u16 = 92;
+
+pub const ID_REDIRECTION_VOICE_FALLBACK: u16 = 146;
+
+pub const ID_REDUNDANT_COMMON_NETWORK_INSTANCE: u16 = 190;
+
+pub const ID_REDUNDANT_DL_NGU_TNL_INFORMATION_REUSED: u16 = 191;
+
+pub const ID_REDUNDANT_DL_NGU_UP_TNL_INFORMATION: u16 = 192;
+
+pub const ID_REDUNDANT_DL_QOS_FLOW_PER_TNL_INFORMATION: u16 = 193;
+
+pub const ID_REDUNDANT_PDU_SESSION_INFORMATION: u16 = 197;
+
+pub const ID_REDUNDANT_QOS_FLOW_INDICATOR: u16 = 194;
+
+pub const ID_REDUNDANT_UL_NGU_UP_TNL_INFORMATION: u16 = 195;
+
+pub const ID_RELATIVE_AMF_CAPACITY: u16 = 86;
+
+pub const ID_REPETITION_PERIOD: u16 = 87;
+
+pub const ID_REROUTE_NAS_REQUEST: u8 = 36;
+
+pub const ID_RESET_TYPE: u16 = 88;
+
+pub const ID_RETRIEVE_UE_INFORMATION: u8 = 55;
+
+pub const ID_ROUTING_ID: u16 = 89;
+
+pub const ID_S_NSSAI: u16 = 148;
+
+pub const ID_SCTP_TL_AS: u16 = 173;
+
+pub const ID_SON_CONFIGURATION_TRANSFER_DL: u16 = 98;
+
+pub const ID_SON_CONFIGURATION_TRANSFER_UL: u16 = 99;
+
+pub const ID_SON_INFORMATION_REPORT: u16 = 252;
+
+pub const ID_SRVCC_OPERATION_POSSIBLE: u16 = 177;
+
+pub const ID_SECONDARY_RAT_DATA_USAGE_REPORT: u8 = 52;
+
+pub const ID_SECONDARY_RAT_USAGE_INFORMATION: u16 = 144;
+
+pub const ID_SECURITY_CONTEXT: u16 = 93;
+
+pub const ID_SECURITY_INDICATION: u16 = 138;
+
+pub const ID_SECURITY_KEY: u16 = 94;
+
+pub const ID_SECURITY_RESULT: u16 = 156;
+
+pub const ID_SELECTED_PLMN_IDENTITY: u16 = 174;
+
+pub const ID_SERIAL_NUMBER: u16 = 95;
+
+pub const ID_SERVED_GUAMI_LIST: u16 = 96;
+
+pub const ID_SG_NB_UE_X2AP_ID: u16 = 182;
+
+pub const ID_SLICE_SUPPORT_LIST: u16 = 97;
+
+pub const ID_SOURCE_AMF_UE_NGAP_ID: u16 = 100;
+
+pub const ID_SOURCE_TO_TARGET_AMF_INFORMATION_REROUTE: u16 = 171;
+
+pub const ID_SOURCE_TO_TARGET_TRANSPARENT_CONTAINER: u16 = 101;
+
+pub const ID_SUPPORTED_TA_LIST: u16 = 102;
+
+pub const ID_SUSPEND_REQUEST_INDICATION: u16 = 235;
+
+pub const ID_SUSPEND_RESPONSE_INDICATION: u16 = 236;
+
+pub const ID_TAI: u16 = 213;
+
+pub const ID_TAI_LIST_FOR_PAGING: u16 = 103;
+
+pub const ID_TAI_LIST_FOR_RESTART: u16 = 104;
+
+pub const ID_TNGF_IDENTITY_INFORMATION: u16 = 246;
+
+pub const ID_TNL_ASSOCIATION_TRANSPORT_LAYER_ADDRESS_NGRAN: u16 = 168;
+
+pub const ID_TSC_TRAFFIC_CHARACTERISTICS: u16 = 196;
+
+pub const ID_TWIF_IDENTITY_INFORMATION: u16 = 247;
+
+pub const ID_TARGET_ID: u16 = 105;
+
+pub const ID_TARGET_RNC_ID: u16 = 178;
+
+pub const ID_TARGET_TO_SOURCE_TRANSPARENT_CONTAINER: u16 = 106;
+
+pub const ID_TARGETTO_SOURCE_FAILURE_TRANSPARENT_CONTAINER: u16 = 262;
+
+pub const ID_TIME_TO_WAIT: u16 = 107;
+
+pub const ID_TRACE_ACTIVATION: u16 = 108;
+
+pub const ID_TRACE_COLLECTION_ENTITY_IP_ADDRESS: u16 = 109;
+
+pub const ID_TRACE_COLLECTION_ENTITY_URI: u16 = 257;
+
+pub const ID_TRACE_FAILURE_INDICATION: u8 = 38;
+
+pub const ID_TRACE_START: u8 = 39;
+
+pub const ID_UE_DIFFERENTIATION_INFO: u16 = 209;
+
+pub const ID_UE_NGAP_I_DS: u16 = 114;
+
+pub const ID_UE_UP_C_IO_T_SUPPORT: u16 = 234;
+
+pub const ID_UE_ASSOCIATED_LOGICAL_NG_CONNECTION_LIST: u16 = 111;
+
+pub const ID_UE_AGGREGATE_MAXIMUM_BIT_RATE: u16 = 110;
+
+pub const ID_UE_CAPABILITY_INFO_REQUEST: u16 = 228;
+
+pub const ID_UE_CONTEXT_MODIFICATION: u8 = 40;
+
+pub const ID_UE_CONTEXT_RELEASE: u8 = 41;
+
+pub const ID_UE_CONTEXT_RELEASE_REQUEST: u8 = 42;
+
+pub const ID_UE_CONTEXT_REQUEST: u16 = 112;
+
+pub const ID_UE_CONTEXT_RESUME: u8 = 58;
+
+pub const ID_UE_CONTEXT_SUSPEND: u8 = 59;
+
+pub const ID_UE_HISTORY_INFORMATION_FROM_THE_UE: u16 = 253;
+
+pub const ID_UE_INFORMATION_TRANSFER: u8 = 56;
+
+pub const ID_UE_PAGING_IDENTITY: u16 = 115;
+
+pub const ID_UE_PRESENCE_IN_AREA_OF_INTEREST_LIST: u16 = 116;
+
+pub const ID_UE_RADIO_CAPABILITY: u16 = 117;
+
+pub const ID_UE_RADIO_CAPABILITY_EUTRA_FORMAT: u16 = 265;
+
+pub const ID_UE_RADIO_CAPABILITY_CHECK: u8 = 43;
+
+pub const ID_UE_RADIO_CAPABILITY_FOR_PAGING: u16 = 118;
+
+pub const ID_UE_RADIO_CAPABILITY_FOR_PAGING_OF_NB_IO_T: u16 = 214;
+
+pub const ID_UE_RADIO_CAPABILITY_ID: u16 = 264;
+
+pub const ID_UE_RADIO_CAPABILITY_ID_MAPPING: u8 = 60;
+
+pub const ID_UE_RADIO_CAPABILITY_INFO_INDICATION: u8 = 44;
+
+pub const ID_UE_RETENTION_INFORMATION: u16 = 147;
+
+pub const ID_UE_SECURITY_CAPABILITIES: u16 = 119;
+
+pub const ID_UETNLA_BINDING_RELEASE: u8 = 45;
+
+pub const ID_UL_CP_SECURITY_INFORMATION: u16 = 211;
+
+pub const ID_UL_NGU_UP_TNL_INFORMATION: u16 = 139;
+
+pub const ID_UL_NGU_UP_TNL_MODIFY_LIST: u16 = 140;
+
+pub const ID_UL_FORWARDING: u16 = 163;
+
+pub const ID_UL_FORWARDING_UP_TNL_INFORMATION: u16 = 164;
+
+pub const ID_UNAVAILABLE_GUAMI_LIST: u16 = 120;
+
+pub const ID_UPLINK_NAS_TRANSPORT: u8 = 46;
+
+pub const ID_UPLINK_NON_UE_ASSOCIATED_NRP_PA_TRANSPORT: u8 = 47;
+
+pub const ID_UPLINK_RAN_CONFIGURATION_TRANSFER: u8 = 48;
+
+pub const ID_UPLINK_RAN_EARLY_STATUS_TRANSFER: u8 = 62;
+
+pub const ID_UPLINK_RAN_STATUS_TRANSFER: u8 = 49;
+
+pub const ID_UPLINK_RIM_INFORMATION_TRANSFER: u8 = 53;
+
+pub const ID_UPLINK_UE_ASSOCIATED_NRP_PA_TRANSPORT: u8 = 50;
+
+pub const ID_USED_RSN_INFORMATION: u16 = 198;
+
+pub const ID_USER_LOCATION_INFORMATION: u16 = 121;
+
+pub const ID_USER_LOCATION_INFORMATION_TNGF: u16 = 244;
+
+pub const ID_USER_LOCATION_INFORMATION_TWIF: u16 = 248;
+
+pub const ID_USER_LOCATION_INFORMATION_W_AGF: u16 = 243;
+
+pub const ID_W_AGF_IDENTITY_INFORMATION: u16 = 239;
+
+pub const ID_WUS_ASSISTANCE_INFORMATION: u16 = 208;
+
+pub const ID_WARNING_AREA_COORDINATES: u16 = 141;
+
+pub const ID_WARNING_AREA_LIST: u16 = 122;
+
+pub const ID_WARNING_MESSAGE_CONTENTS: u16 = 123;
+
+pub const ID_WARNING_SECURITY_INFO: u16 = 124;
+
+pub const ID_WARNING_TYPE: u16 = 125;
+
+pub const ID_WRITE_REPLACE_WARNING: u8 = 51;
+
+pub const MAX_NRARFCN: i64 = 3279165;
+
+pub const MAX_PRIVATE_I_ES: i64 = 65535;
+
+pub const MAX_PROTOCOL_EXTENSIONS: i64 = 65535;
+
+pub const MAX_PROTOCOL_I_ES: i64 = 65535;
+
+pub const MAXNOOF_ALLOWED_AREAS: i64 = 16;
+
+pub const MAXNOOF_ALLOWED_CA_GSPER_PLMN: i64 = 256;
+
+pub const MAXNOOF_ALLOWED_S_NSSA_IS: i64 = 8;
+
+pub const MAXNOOF_AO_I: i64 = 64;
+
+pub const MAXNOOF_BPLM_NS: i64 = 12;
+
+pub const MAXNOOF_BLUETOOTH_NAME: i64 = 4;
+
+pub const MAXNOOF_CAG_SPER_CELL: i64 = 64;
+
+pub const MAXNOOF_CANDIDATE_CELLS: i64 = 32;
+
+pub const MAXNOOF_CELL_I_DFOR_MDT: i64 = 32;
+
+pub const MAXNOOF_CELL_I_DFOR_WARNING: i64 = 65535;
+
+pub const MAXNOOF_CELLIN_AO_I: i64 = 256;
+
+pub const MAXNOOF_CELLIN_EAI: i64 = 65535;
+
+pub const MAXNOOF_CELLIN_TAI: i64 = 65535;
+
+pub const MAXNOOF_CELLS_UE_MOVING_TRAJECTORY: i64 = 16;
+
+pub const MAXNOOF_CELLSIN_UE_HISTORY_INFO: i64 = 16;
+
+pub const MAXNOOF_CELLSING_NB: i64 = 16384;
+
+pub const MAXNOOF_CELLSINNGE_NB: i64 = 256;
+
+pub const MAXNOOF_DR_BS: i64 = 32;
+
+pub const MAXNOOF_E_RA_BS: i64 = 256;
+
+pub const MAXNOOF_EA_IFOR_RESTART: i64 = 256;
+
+pub const MAXNOOF_EPLM_NS: i64 = 15;
+
+pub const MAXNOOF_EPLM_NS_PLUS_ONE: i64 = 16;
+
+pub const MAXNOOF_EMERGENCY_AREA_ID: i64 = 65535;
+
+pub const MAXNOOF_ERRORS: i64 = 256;
+
+pub const MAXNOOF_EXT_SLICE_ITEMS: i64 = 65535;
+
+pub const MAXNOOF_FORB_TA_CS: i64 = 4096;
+
+pub const MAXNOOF_FREQFOR_MDT: i64 = 8;
+
+pub const MAXNOOF_MDTPLM_NS: i64 = 16;
+
+pub const MAXNOOF_MULTI_CONNECTIVITY: i64 = 4;
+
+pub const MAXNOOF_MULTI_CONNECTIVITY_MINUS_ONE: i64 = 3;
+
+pub const MAXNOOF_NG_CONNECTIONS_TO_RESET: i64 = 65536;
+
+pub const MAXNOOF_NR_CELL_BANDS: i64 = 32;
+
+pub const MAXNOOF_NEIGH_PC_IFOR_MDT: i64 = 32;
+
+pub const MAXNOOF_PC5_QO_S_FLOWS: i64 = 2048;
+
+pub const MAXNOOF_PDU_SESSIONS: i64 = 256;
+
+pub const MAXNOOF_PLM_NS: i64 = 12;
+
+pub const MAXNOOF_QOS_FLOWS: i64 = 64;
+
+pub const MAXNOOF_QOS_PARA_SETS: i64 = 8;
+
+pub const MAXNOOF_RAN_NODEIN_AO_I: i64 = 64;
+
+pub const MAXNOOF_RECOMMENDED_CELLS: i64 = 16;
+
+pub const MAXNOOF_RECOMMENDED_RAN_NODES: i64 = 16;
+
+pub const MAXNOOF_SENSOR_NAME: i64 = 3;
+
+pub const MAXNOOF_SERVED_GUAM_IS: i64 = 256;
+
+pub const MAXNOOF_SLICE_ITEMS: i64 = 1024;
+
+pub const MAXNOOF_TA_CS: i64 = 256;
+
+pub const MAXNOOF_TA_IFOR_INACTIVE: i64 = 16;
+
+pub const MAXNOOF_TA_IFOR_PAGING: i64 = 16;
+
+pub const MAXNOOF_TA_IFOR_RESTART: i64 = 2048;
+
+pub const MAXNOOF_TA_IFOR_WARNING: i64 = 65535;
+
+pub const MAXNOOF_TA_IIN_AO_I: i64 = 16;
+
+pub const MAXNOOF_T_AFOR_MDT: i64 = 8;
+
+pub const MAXNOOF_TNL_ASSOCIATIONS: i64 = 32;
+
+pub const MAXNOOF_TIME_PERIODS: i64 = 2;
+
+pub const MAXNOOF_WLAN_NAME: i64 = 4;
+
+pub const MAXNOOF_XN_EXT_TL_AS: i64 = 16;
+
+pub const MAXNOOF_XN_GTP_TL_AS: i64 = 16;
+
+pub const MAXNOOF_XN_TL_AS: i64 = 2;
+
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct AMF_TNLAssociationSetupItem {
     pub amf_tnl_association_address: CPTransportLayerInformation,
@@ -11,11 +822,11 @@ pub struct AMF_TNLAssociationSetupItem {
     pub ie_extensions: Option<AMF_TNLAssociationSetupItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "32")]
 pub struct AMF_TNLAssociationSetupList(pub Vec<AMF_TNLAssociationSetupItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct AMF_TNLAssociationToAddItem {
     pub amf_tnl_association_address: CPTransportLayerInformation,
@@ -26,11 +837,11 @@ pub struct AMF_TNLAssociationToAddItem {
     pub ie_extensions: Option<AMF_TNLAssociationToAddItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "32")]
 pub struct AMF_TNLAssociationToAddList(pub Vec<AMF_TNLAssociationToAddItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct AMF_TNLAssociationToRemoveItem {
     pub amf_tnl_association_address: CPTransportLayerInformation,
@@ -38,11 +849,11 @@ pub struct AMF_TNLAssociationToRemoveItem {
     pub ie_extensions: Option<AMF_TNLAssociationToRemoveItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "32")]
 pub struct AMF_TNLAssociationToRemoveList(pub Vec<AMF_TNLAssociationToRemoveItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct AMF_TNLAssociationToUpdateItem {
     pub amf_tnl_association_address: CPTransportLayerInformation,
@@ -54,39 +865,39 @@ pub struct AMF_TNLAssociationToUpdateItem {
     pub ie_extensions: Option<AMF_TNLAssociationToUpdateItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "32")]
 pub struct AMF_TNLAssociationToUpdateList(pub Vec<AMF_TNLAssociationToUpdateItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "1099511627775")]
 pub struct AMF_UE_NGAP_ID(pub u64);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct AMFCPRelocationIndication {
     pub protocol_i_es: AMFCPRelocationIndicationProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct AMFConfigurationUpdate {
     pub protocol_i_es: AMFConfigurationUpdateProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct AMFConfigurationUpdateAcknowledge {
     pub protocol_i_es: AMFConfigurationUpdateAcknowledgeProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct AMFConfigurationUpdateFailure {
     pub protocol_i_es: AMFConfigurationUpdateFailureProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "PrintableString",
     sz_extensible = true,
@@ -95,11 +906,11 @@ pub struct AMFConfigurationUpdateFailure {
 )]
 pub struct AMFName(pub String);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "UTF8String", sz_extensible = true, sz_lb = "1", sz_ub = "150")]
 pub struct AMFNameUTF8String(pub String);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "VisibleString",
     sz_extensible = true,
@@ -108,7 +919,7 @@ pub struct AMFNameUTF8String(pub String);
 )]
 pub struct AMFNameVisibleString(pub String);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum AMFPagingTarget {
     #[asn(key = 0, extended = false)]
@@ -116,28 +927,28 @@ pub enum AMFPagingTarget {
     #[asn(key = 1, extended = false)]
     TAI(TAI),
     #[asn(key = 2, extended = false)]
-    Choice_Extensions(AMFPagingTargetchoice_Extensions),
+    Choice_Extensions(AMFPagingTarget_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "6", sz_ub = "6")]
-pub struct AMFPointer(pub BitVec<u8, Msb0>);
+pub struct AMFPointer(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "8", sz_ub = "8")]
-pub struct AMFRegionID(pub BitVec<u8, Msb0>);
+pub struct AMFRegionID(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "10", sz_ub = "10")]
-pub struct AMFSetID(pub BitVec<u8, Msb0>);
+pub struct AMFSetID(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct AMFStatusIndication {
     pub protocol_i_es: AMFStatusIndicationProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct AdditionalDLUPTNLInformationForHOItem {
     pub additional_dl_ngu_up_tnl_information: UPTransportLayerInformation,
@@ -148,18 +959,18 @@ pub struct AdditionalDLUPTNLInformationForHOItem {
     pub ie_extensions: Option<AdditionalDLUPTNLInformationForHOItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "3")]
 pub struct AdditionalDLUPTNLInformationForHOList(pub Vec<AdditionalDLUPTNLInformationForHOItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct AdditionalQosFlowInformation(pub u8);
 impl AdditionalQosFlowInformation {
     pub const MORE_LIKELY: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct AllocationAndRetentionPriority {
     pub priority_level_arp: PriorityLevelARP,
@@ -169,7 +980,7 @@ pub struct AllocationAndRetentionPriority {
     pub ie_extensions: Option<AllocationAndRetentionPriorityIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -178,25 +989,25 @@ pub struct AllocationAndRetentionPriority {
 )]
 pub struct Allowed_CAG_List_per_PLMN(pub Vec<CAG_ID>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct Allowed_PNI_NPN_Item {
     pub plmn_identity: PLMNIdentity,
-    pub pni_npn_restricted: ENUMERATED_2,
+    pub pni_npn_restricted: Allowed_PNI_NPN_ItemPNI_NPN_restricted,
     pub allowed_cag_list_per_plmn: Allowed_CAG_List_per_PLMN,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<Allowed_PNI_NPN_ItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct Allowed_PNI_NPN_List(pub Vec<Allowed_PNI_NPN_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "8")]
 pub struct AllowedNSSAI(pub Vec<AllowedNSSAI_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct AllowedNSSAI_Item {
     pub s_nssai: S_NSSAI,
@@ -204,15 +1015,15 @@ pub struct AllowedNSSAI_Item {
     pub ie_extensions: Option<AllowedNSSAI_ItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct AllowedTACs(pub Vec<TAC>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "1", ub = "8", extensible = true)]
 pub struct AlternativeQoSParaSetIndex(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 5)]
 pub struct AlternativeQoSParaSetItem {
     pub alternative_qo_s_para_set_index: AlternativeQoSParaSetIndex,
@@ -228,15 +1039,15 @@ pub struct AlternativeQoSParaSetItem {
     pub ie_extensions: Option<AlternativeQoSParaSetItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "8")]
 pub struct AlternativeQoSParaSetList(pub Vec<AlternativeQoSParaSetItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "8", extensible = true)]
 pub struct AlternativeQoSParaSetNotifyIndex(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 4)]
 pub struct AreaOfInterest {
     #[asn(optional_idx = 0)]
@@ -249,7 +1060,7 @@ pub struct AreaOfInterest {
     pub ie_extensions: Option<AreaOfInterestIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct AreaOfInterestCellItem {
     pub ngran_cgi: NGRAN_CGI,
@@ -257,7 +1068,7 @@ pub struct AreaOfInterestCellItem {
     pub ie_extensions: Option<AreaOfInterestCellItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -266,7 +1077,7 @@ pub struct AreaOfInterestCellItem {
 )]
 pub struct AreaOfInterestCellList(pub Vec<AreaOfInterestCellItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct AreaOfInterestItem {
     pub area_of_interest: AreaOfInterest,
@@ -275,11 +1086,11 @@ pub struct AreaOfInterestItem {
     pub ie_extensions: Option<AreaOfInterestItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "64")]
 pub struct AreaOfInterestList(pub Vec<AreaOfInterestItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct AreaOfInterestRANNodeItem {
     pub global_ran_node_id: GlobalRANNodeID,
@@ -287,11 +1098,11 @@ pub struct AreaOfInterestRANNodeItem {
     pub ie_extensions: Option<AreaOfInterestRANNodeItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "64")]
 pub struct AreaOfInterestRANNodeList(pub Vec<AreaOfInterestRANNodeItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct AreaOfInterestTAIItem {
     pub tai: TAI,
@@ -299,11 +1110,11 @@ pub struct AreaOfInterestTAIItem {
     pub ie_extensions: Option<AreaOfInterestTAIItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct AreaOfInterestTAIList(pub Vec<AreaOfInterestTAIItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "4", extensible = false)]
 pub enum AreaScopeOfMDT_EUTRA {
     #[asn(key = 0, extended = false)]
@@ -311,14 +1122,14 @@ pub enum AreaScopeOfMDT_EUTRA {
     #[asn(key = 1, extended = false)]
     TABased(TABasedMDT),
     #[asn(key = 2, extended = false)]
-    PLMNWide(NULL_3),
+    PLMNWide(AreaScopeOfMDT_EUTRA_pLMNWide),
     #[asn(key = 3, extended = false)]
     TAIBased(TAIBasedMDT),
     #[asn(key = 4, extended = false)]
-    Choice_Extensions(AreaScopeOfMDT_EUTRAchoice_Extensions),
+    Choice_Extensions(AreaScopeOfMDT_EUTRA_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "4", extensible = false)]
 pub enum AreaScopeOfMDT_NR {
     #[asn(key = 0, extended = false)]
@@ -326,14 +1137,14 @@ pub enum AreaScopeOfMDT_NR {
     #[asn(key = 1, extended = false)]
     TABased(TABasedMDT),
     #[asn(key = 2, extended = false)]
-    PLMNWide(NULL_4),
+    PLMNWide(AreaScopeOfMDT_NR_pLMNWide),
     #[asn(key = 3, extended = false)]
     TAIBased(TAIBasedMDT),
     #[asn(key = 4, extended = false)]
-    Choice_Extensions(AreaScopeOfMDT_NRchoice_Extensions),
+    Choice_Extensions(AreaScopeOfMDT_NR_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct AreaScopeOfNeighCellsItem {
     pub nr_frequency_info: NRFrequencyInfo,
@@ -343,11 +1154,11 @@ pub struct AreaScopeOfNeighCellsItem {
     pub ie_extensions: Option<AreaScopeOfNeighCellsItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "8")]
 pub struct AreaScopeOfNeighCellsList(pub Vec<AreaScopeOfNeighCellsItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct AssistanceDataForPaging {
     #[asn(optional_idx = 0)]
@@ -358,7 +1169,7 @@ pub struct AssistanceDataForPaging {
     pub ie_extensions: Option<AssistanceDataForPagingIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct AssistanceDataForRecommendedCells {
     pub recommended_cells_for_paging: RecommendedCellsForPaging,
@@ -366,43 +1177,43 @@ pub struct AssistanceDataForRecommendedCells {
     pub ie_extensions: Option<AssistanceDataForRecommendedCellsIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct AssociatedQosFlowItem {
     pub qos_flow_identifier: QosFlowIdentifier,
     #[asn(optional_idx = 0)]
-    pub qos_flow_mapping_indication: Option<ENUMERATED_5>,
+    pub qos_flow_mapping_indication: Option<AssociatedQosFlowItemQosFlowMappingIndication>,
     #[asn(optional_idx = 1)]
     pub ie_extensions: Option<AssociatedQosFlowItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "64")]
 pub struct AssociatedQosFlowList(pub Vec<AssociatedQosFlowItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct AuthenticatedIndication(pub u8);
 impl AuthenticatedIndication {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "4095", extensible = true)]
 pub struct AveragingWindow(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "4000000000000", extensible = true)]
 pub struct BitRate(pub u64);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct BluetoothMeasConfig(pub u8);
 impl BluetoothMeasConfig {
     pub const SETUP: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct BluetoothMeasConfigNameItem {
     pub bluetooth_name: BluetoothName,
@@ -410,23 +1221,23 @@ pub struct BluetoothMeasConfigNameItem {
     pub ie_extensions: Option<BluetoothMeasConfigNameItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "4")]
 pub struct BluetoothMeasConfigNameList(pub Vec<BluetoothMeasConfigNameItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct BluetoothMeasurementConfiguration {
     pub bluetooth_meas_config: BluetoothMeasConfig,
     #[asn(optional_idx = 0)]
     pub bluetooth_meas_config_name_list: Option<BluetoothMeasConfigNameList>,
     #[asn(optional_idx = 1)]
-    pub bt_rssi: Option<ENUMERATED_6>,
+    pub bt_rssi: Option<BluetoothMeasurementConfigurationBt_rssi>,
     #[asn(optional_idx = 2)]
     pub ie_extensions: Option<BluetoothMeasurementConfigurationIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "OCTET-STRING",
     sz_extensible = false,
@@ -435,7 +1246,7 @@ pub struct BluetoothMeasurementConfiguration {
 )]
 pub struct BluetoothName(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "6", extensible = false)]
 pub enum BroadcastCancelledAreaList {
     #[asn(key = 0, extended = false)]
@@ -451,10 +1262,10 @@ pub enum BroadcastCancelledAreaList {
     #[asn(key = 5, extended = false)]
     EmergencyAreaIDCancelledNR(EmergencyAreaIDCancelledNR),
     #[asn(key = 6, extended = false)]
-    Choice_Extensions(BroadcastCancelledAreaListchoice_Extensions),
+    Choice_Extensions(BroadcastCancelledAreaList_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "6", extensible = false)]
 pub enum BroadcastCompletedAreaList {
     #[asn(key = 0, extended = false)]
@@ -470,10 +1281,10 @@ pub enum BroadcastCompletedAreaList {
     #[asn(key = 5, extended = false)]
     EmergencyAreaIDBroadcastNR(EmergencyAreaIDBroadcastNR),
     #[asn(key = 6, extended = false)]
-    Choice_Extensions(BroadcastCompletedAreaListchoice_Extensions),
+    Choice_Extensions(BroadcastCompletedAreaList_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct BroadcastPLMNItem {
     pub plmn_identity: PLMNIdentity,
@@ -482,26 +1293,26 @@ pub struct BroadcastPLMNItem {
     pub ie_extensions: Option<BroadcastPLMNItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "12")]
 pub struct BroadcastPLMNList(pub Vec<BroadcastPLMNItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
 pub struct BurstArrivalTime(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "32", sz_ub = "32")]
-pub struct CAG_ID(pub BitVec<u8, Msb0>);
+pub struct CAG_ID(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct CEmodeBSupport_Indicator(pub u8);
 impl CEmodeBSupport_Indicator {
     pub const SUPPORTED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct CEmodeBrestricted(pub u8);
 impl CEmodeBrestricted {
@@ -509,7 +1320,7 @@ impl CEmodeBrestricted {
     pub const NOT_RESTRICTED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct CNAssistedRANTuning {
     #[asn(optional_idx = 0)]
@@ -518,61 +1329,61 @@ pub struct CNAssistedRANTuning {
     pub ie_extensions: Option<CNAssistedRANTuningIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "15")]
 pub struct CNTypeRestrictionsForEquivalent(pub Vec<CNTypeRestrictionsForEquivalentItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CNTypeRestrictionsForEquivalentItem {
     pub plmn_identity: PLMNIdentity,
-    pub cn_type: ENUMERATED_7,
+    pub cn_type: CNTypeRestrictionsForEquivalentItemCn_Type,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<CNTypeRestrictionsForEquivalentItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct CNTypeRestrictionsForServing(pub u8);
 impl CNTypeRestrictionsForServing {
     pub const EPC_FORBIDDEN: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct COUNTValueForPDCP_SN12 {
-    pub pdcp_sn12: INTEGER_8,
-    pub hfn_pdcp_sn12: INTEGER_9,
+    pub pdcp_sn12: COUNTValueForPDCP_SN12PDCP_SN12,
+    pub hfn_pdcp_sn12: COUNTValueForPDCP_SN12HFN_PDCP_SN12,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<COUNTValueForPDCP_SN12IE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct COUNTValueForPDCP_SN18 {
-    pub pdcp_sn18: INTEGER_10,
-    pub hfn_pdcp_sn18: INTEGER_11,
+    pub pdcp_sn18: COUNTValueForPDCP_SN18PDCP_SN18,
+    pub hfn_pdcp_sn18: COUNTValueForPDCP_SN18HFN_PDCP_SN18,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<COUNTValueForPDCP_SN18IE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = false)]
 pub enum CPTransportLayerInformation {
     #[asn(key = 0, extended = false)]
     EndpointIPAddress(TransportLayerAddress),
     #[asn(key = 1, extended = false)]
-    Choice_Extensions(CPTransportLayerInformationchoice_Extensions),
+    Choice_Extensions(CPTransportLayerInformation_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct CancelAllWarningMessages(pub u8);
 impl CancelAllWarningMessages {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -581,7 +1392,7 @@ impl CancelAllWarningMessages {
 )]
 pub struct CancelledCellsInEAI_EUTRA(pub Vec<CancelledCellsInEAI_EUTRA_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CancelledCellsInEAI_EUTRA_Item {
     pub eutra_cgi: EUTRA_CGI,
@@ -590,7 +1401,7 @@ pub struct CancelledCellsInEAI_EUTRA_Item {
     pub ie_extensions: Option<CancelledCellsInEAI_EUTRA_ItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -599,7 +1410,7 @@ pub struct CancelledCellsInEAI_EUTRA_Item {
 )]
 pub struct CancelledCellsInEAI_NR(pub Vec<CancelledCellsInEAI_NR_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CancelledCellsInEAI_NR_Item {
     pub nr_cgi: NR_CGI,
@@ -608,7 +1419,7 @@ pub struct CancelledCellsInEAI_NR_Item {
     pub ie_extensions: Option<CancelledCellsInEAI_NR_ItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -617,7 +1428,7 @@ pub struct CancelledCellsInEAI_NR_Item {
 )]
 pub struct CancelledCellsInTAI_EUTRA(pub Vec<CancelledCellsInTAI_EUTRA_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CancelledCellsInTAI_EUTRA_Item {
     pub eutra_cgi: EUTRA_CGI,
@@ -626,7 +1437,7 @@ pub struct CancelledCellsInTAI_EUTRA_Item {
     pub ie_extensions: Option<CancelledCellsInTAI_EUTRA_ItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -635,7 +1446,7 @@ pub struct CancelledCellsInTAI_EUTRA_Item {
 )]
 pub struct CancelledCellsInTAI_NR(pub Vec<CancelledCellsInTAI_NR_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CancelledCellsInTAI_NR_Item {
     pub nr_cgi: NR_CGI,
@@ -644,7 +1455,7 @@ pub struct CancelledCellsInTAI_NR_Item {
     pub ie_extensions: Option<CancelledCellsInTAI_NR_ItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum CandidateCell {
     #[asn(key = 0, extended = false)]
@@ -652,10 +1463,10 @@ pub enum CandidateCell {
     #[asn(key = 1, extended = false)]
     CandidatePCI(CandidatePCI),
     #[asn(key = 2, extended = false)]
-    Choice_Extensions(CandidateCellchoice_Extensions),
+    Choice_Extensions(CandidateCell_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CandidateCellID {
     pub candidate_cell_id: NR_CGI,
@@ -663,7 +1474,7 @@ pub struct CandidateCellID {
     pub ie_extensions: Option<CandidateCellIDIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CandidateCellItem {
     pub candidate_cell: CandidateCell,
@@ -671,20 +1482,20 @@ pub struct CandidateCellItem {
     pub ie_extensions: Option<CandidateCellItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "32")]
 pub struct CandidateCellList(pub Vec<CandidateCellItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CandidatePCI {
-    pub candidate_pci: INTEGER_12,
-    pub candidate_nrarfcn: INTEGER_13,
+    pub candidate_pci: CandidatePCICandidatePCI,
+    pub candidate_nrarfcn: CandidatePCICandidateNRARFCN,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<CandidatePCIIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "5", extensible = false)]
 pub enum Cause {
     #[asn(key = 0, extended = false)]
@@ -698,10 +1509,10 @@ pub enum Cause {
     #[asn(key = 4, extended = false)]
     Misc(CauseMisc),
     #[asn(key = 5, extended = false)]
-    Choice_Extensions(Causechoice_Extensions),
+    Choice_Extensions(Cause_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "5")]
 pub struct CauseMisc(pub u8);
 impl CauseMisc {
@@ -713,7 +1524,7 @@ impl CauseMisc {
     pub const UNSPECIFIED: u8 = 5u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "3")]
 pub struct CauseNas(pub u8);
 impl CauseNas {
@@ -723,7 +1534,7 @@ impl CauseNas {
     pub const UNSPECIFIED: u8 = 3u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "6")]
 pub struct CauseProtocol(pub u8);
 impl CauseProtocol {
@@ -736,7 +1547,7 @@ impl CauseProtocol {
     pub const UNSPECIFIED: u8 = 6u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "44")]
 pub struct CauseRadioNetwork(pub u8);
 impl CauseRadioNetwork {
@@ -785,9 +1596,16 @@ impl CauseRadioNetwork {
     pub const RESOURCES_NOT_AVAILABLE_FOR_THE_SLICE: u8 = 42u8;
     pub const UE_MAX_INTEGRITY_PROTECTED_DATA_RATE_REASON: u8 = 43u8;
     pub const RELEASE_DUE_TO_CN_DETECTED_MOBILITY: u8 = 44u8;
+    pub const N26_INTERFACE_NOT_AVAILABLE: u8 = 45u8;
+    pub const RELEASE_DUE_TO_PRE_EMPTION: u8 = 46u8;
+    pub const MULTIPLE_LOCATION_REPORTING_REFERENCE_ID_INSTANCES: u8 = 47u8;
+    pub const RSN_NOT_AVAILABLE_FOR_THE_UP: u8 = 48u8;
+    pub const NPN_ACCESS_DENIED: u8 = 49u8;
+    pub const CAG_ONLY_ACCESS_DENIED: u8 = 50u8;
+    pub const INSUFFICIENT_UE_CAPABILITIES: u8 = 51u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct CauseTransport(pub u8);
 impl CauseTransport {
@@ -795,7 +1613,7 @@ impl CauseTransport {
     pub const UNSPECIFIED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct Cell_CAGInformation {
     pub ngran_cgi: NGRAN_CGI,
@@ -804,7 +1622,7 @@ pub struct Cell_CAGInformation {
     pub ie_extensions: Option<Cell_CAGInformationIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CellBasedMDT_EUTRA {
     pub cell_id_listfor_mdt: CellIdListforMDT_EUTRA,
@@ -812,7 +1630,7 @@ pub struct CellBasedMDT_EUTRA {
     pub ie_extensions: Option<CellBasedMDT_EUTRAIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CellBasedMDT_NR {
     pub cell_id_listfor_mdt: CellIdListforMDT_NR,
@@ -820,11 +1638,11 @@ pub struct CellBasedMDT_NR {
     pub ie_extensions: Option<CellBasedMDT_NRIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "64")]
 pub struct CellCAGList(pub Vec<CAG_ID>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -833,7 +1651,7 @@ pub struct CellCAGList(pub Vec<CAG_ID>);
 )]
 pub struct CellIDBroadcastEUTRA(pub Vec<CellIDBroadcastEUTRA_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CellIDBroadcastEUTRA_Item {
     pub eutra_cgi: EUTRA_CGI,
@@ -841,7 +1659,7 @@ pub struct CellIDBroadcastEUTRA_Item {
     pub ie_extensions: Option<CellIDBroadcastEUTRA_ItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -850,7 +1668,7 @@ pub struct CellIDBroadcastEUTRA_Item {
 )]
 pub struct CellIDBroadcastNR(pub Vec<CellIDBroadcastNR_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CellIDBroadcastNR_Item {
     pub nr_cgi: NR_CGI,
@@ -858,7 +1676,7 @@ pub struct CellIDBroadcastNR_Item {
     pub ie_extensions: Option<CellIDBroadcastNR_ItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -867,7 +1685,7 @@ pub struct CellIDBroadcastNR_Item {
 )]
 pub struct CellIDCancelledEUTRA(pub Vec<CellIDCancelledEUTRA_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CellIDCancelledEUTRA_Item {
     pub eutra_cgi: EUTRA_CGI,
@@ -876,7 +1694,7 @@ pub struct CellIDCancelledEUTRA_Item {
     pub ie_extensions: Option<CellIDCancelledEUTRA_ItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -885,7 +1703,7 @@ pub struct CellIDCancelledEUTRA_Item {
 )]
 pub struct CellIDCancelledNR(pub Vec<CellIDCancelledNR_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CellIDCancelledNR_Item {
     pub nr_cgi: NR_CGI,
@@ -894,7 +1712,7 @@ pub struct CellIDCancelledNR_Item {
     pub ie_extensions: Option<CellIDCancelledNR_ItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum CellIDListForRestart {
     #[asn(key = 0, extended = false)]
@@ -902,18 +1720,18 @@ pub enum CellIDListForRestart {
     #[asn(key = 1, extended = false)]
     NR_CGIListforRestart(NR_CGIList),
     #[asn(key = 2, extended = false)]
-    Choice_Extensions(CellIDListForRestartchoice_Extensions),
+    Choice_Extensions(CellIDListForRestart_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "32")]
 pub struct CellIdListforMDT_EUTRA(pub Vec<EUTRA_CGI>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "32")]
 pub struct CellIdListforMDT_NR(pub Vec<NR_CGI>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "3")]
 pub struct CellSize(pub u8);
 impl CellSize {
@@ -923,13 +1741,13 @@ impl CellSize {
     pub const LARGE: u8 = 3u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct CellTrafficTrace {
     pub protocol_i_es: CellTrafficTraceProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CellType {
     pub cell_size: CellSize,
@@ -937,11 +1755,11 @@ pub struct CellType {
     pub ie_extensions: Option<CellTypeIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
 pub struct CommonNetworkInstance(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -950,7 +1768,7 @@ pub struct CommonNetworkInstance(pub Vec<u8>);
 )]
 pub struct CompletedCellsInEAI_EUTRA(pub Vec<CompletedCellsInEAI_EUTRA_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CompletedCellsInEAI_EUTRA_Item {
     pub eutra_cgi: EUTRA_CGI,
@@ -958,7 +1776,7 @@ pub struct CompletedCellsInEAI_EUTRA_Item {
     pub ie_extensions: Option<CompletedCellsInEAI_EUTRA_ItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -967,7 +1785,7 @@ pub struct CompletedCellsInEAI_EUTRA_Item {
 )]
 pub struct CompletedCellsInEAI_NR(pub Vec<CompletedCellsInEAI_NR_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CompletedCellsInEAI_NR_Item {
     pub nr_cgi: NR_CGI,
@@ -975,7 +1793,7 @@ pub struct CompletedCellsInEAI_NR_Item {
     pub ie_extensions: Option<CompletedCellsInEAI_NR_ItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -984,7 +1802,7 @@ pub struct CompletedCellsInEAI_NR_Item {
 )]
 pub struct CompletedCellsInTAI_EUTRA(pub Vec<CompletedCellsInTAI_EUTRA_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CompletedCellsInTAI_EUTRA_Item {
     pub eutra_cgi: EUTRA_CGI,
@@ -992,7 +1810,7 @@ pub struct CompletedCellsInTAI_EUTRA_Item {
     pub ie_extensions: Option<CompletedCellsInTAI_EUTRA_ItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1001,7 +1819,7 @@ pub struct CompletedCellsInTAI_EUTRA_Item {
 )]
 pub struct CompletedCellsInTAI_NR(pub Vec<CompletedCellsInTAI_NR_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CompletedCellsInTAI_NR_Item {
     pub nr_cgi: NR_CGI,
@@ -1009,14 +1827,14 @@ pub struct CompletedCellsInTAI_NR_Item {
     pub ie_extensions: Option<CompletedCellsInTAI_NR_ItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct ConcurrentWarningMessageInd(pub u8);
 impl ConcurrentWarningMessageInd {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct ConfidentialityProtectionIndication(pub u8);
 impl ConfidentialityProtectionIndication {
@@ -1025,7 +1843,7 @@ impl ConfidentialityProtectionIndication {
     pub const NOT_NEEDED: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct ConfidentialityProtectionResult(pub u8);
 impl ConfidentialityProtectionResult {
@@ -1033,7 +1851,7 @@ impl ConfidentialityProtectionResult {
     pub const NOT_PERFORMED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "OCTET-STRING",
     sz_extensible = false,
@@ -1042,20 +1860,20 @@ impl ConfidentialityProtectionResult {
 )]
 pub struct ConfiguredNSSAI(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct ConfiguredTACIndication(pub u8);
 impl ConfiguredTACIndication {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct ConnectionEstablishmentIndication {
     pub protocol_i_es: ConnectionEstablishmentIndicationProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 4)]
 pub struct CoreNetworkAssistanceInformationForInactive {
     pub ue_identity_index_value: UEIdentityIndexValue,
@@ -1071,11 +1889,11 @@ pub struct CoreNetworkAssistanceInformationForInactive {
     pub ie_extensions: Option<CoreNetworkAssistanceInformationForInactiveIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
 pub struct CoverageEnhancementLevel(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "2")]
 pub struct Criticality(pub u8);
 impl Criticality {
@@ -1084,7 +1902,7 @@ impl Criticality {
     pub const NOTIFY: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 5)]
 pub struct CriticalityDiagnostics {
     #[asn(optional_idx = 0)]
@@ -1099,7 +1917,7 @@ pub struct CriticalityDiagnostics {
     pub ie_extensions: Option<CriticalityDiagnosticsIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CriticalityDiagnostics_IE_Item {
     pub ie_criticality: Criticality,
@@ -1109,7 +1927,7 @@ pub struct CriticalityDiagnostics_IE_Item {
     pub ie_extensions: Option<CriticalityDiagnostics_IE_ItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1118,23 +1936,23 @@ pub struct CriticalityDiagnostics_IE_Item {
 )]
 pub struct CriticalityDiagnostics_IE_List(pub Vec<CriticalityDiagnostics_IE_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct DAPSRequestInfo {
-    pub daps_indicator: ENUMERATED_14,
+    pub daps_indicator: DAPSRequestInfoDAPSIndicator,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<DAPSRequestInfoIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct DAPSResponseInfo {
-    pub dapsresponseindicator: ENUMERATED_15,
+    pub dapsresponseindicator: DAPSResponseInfoDapsresponseindicator,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<DAPSResponseInfoIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct DAPSResponseInfoItem {
     pub drb_id: DRB_ID,
@@ -1143,11 +1961,11 @@ pub struct DAPSResponseInfoItem {
     pub ie_extension: Option<DAPSResponseInfoItemIE_Extension>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "32")]
 pub struct DAPSResponseInfoList(pub Vec<DAPSResponseInfoItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct DL_CP_SecurityInformation {
     pub dl_nas_mac: DL_NAS_MAC,
@@ -1155,29 +1973,29 @@ pub struct DL_CP_SecurityInformation {
     pub ie_extensions: Option<DL_CP_SecurityInformationIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "16", sz_ub = "16")]
-pub struct DL_NAS_MAC(pub BitVec<u8, Msb0>);
+pub struct DL_NAS_MAC(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct DL_NGU_TNLInformationReused(pub u8);
 impl DL_NGU_TNLInformationReused {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct DLForwarding(pub u8);
 impl DLForwarding {
     pub const DL_FORWARDING_PROPOSED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "1", ub = "32", extensible = true)]
 pub struct DRB_ID(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum DRBStatusDL {
     #[asn(key = 0, extended = false)]
@@ -1185,10 +2003,10 @@ pub enum DRBStatusDL {
     #[asn(key = 1, extended = false)]
     DRBStatusDL18(DRBStatusDL18),
     #[asn(key = 2, extended = false)]
-    Choice_Extensions(DRBStatusDLchoice_Extensions),
+    Choice_Extensions(DRBStatusDL_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct DRBStatusDL12 {
     pub dl_count_value: COUNTValueForPDCP_SN12,
@@ -1196,7 +2014,7 @@ pub struct DRBStatusDL12 {
     pub ie_extension: Option<DRBStatusDL12IE_Extension>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct DRBStatusDL18 {
     pub dl_count_value: COUNTValueForPDCP_SN18,
@@ -1204,7 +2022,7 @@ pub struct DRBStatusDL18 {
     pub ie_extension: Option<DRBStatusDL18IE_Extension>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum DRBStatusUL {
     #[asn(key = 0, extended = false)]
@@ -1212,30 +2030,30 @@ pub enum DRBStatusUL {
     #[asn(key = 1, extended = false)]
     DRBStatusUL18(DRBStatusUL18),
     #[asn(key = 2, extended = false)]
-    Choice_Extensions(DRBStatusULchoice_Extensions),
+    Choice_Extensions(DRBStatusUL_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct DRBStatusUL12 {
     pub ul_count_value: COUNTValueForPDCP_SN12,
     #[asn(optional_idx = 0)]
-    pub receive_status_of_ul_pdcp_sd_us: Option<BIT_STRING_16>,
+    pub receive_status_of_ul_pdcp_sd_us: Option<DRBStatusUL12ReceiveStatusOfUL_PDCP_SDUs>,
     #[asn(optional_idx = 1)]
     pub ie_extension: Option<DRBStatusUL12IE_Extension>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct DRBStatusUL18 {
     pub ul_count_value: COUNTValueForPDCP_SN18,
     #[asn(optional_idx = 0)]
-    pub receive_status_of_ul_pdcp_sd_us: Option<BIT_STRING_17>,
+    pub receive_status_of_ul_pdcp_sd_us: Option<DRBStatusUL18ReceiveStatusOfUL_PDCP_SDUs>,
     #[asn(optional_idx = 1)]
     pub ie_extension: Option<DRBStatusUL18IE_Extension>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct DRBsSubjectToEarlyStatusTransfer_Item {
     pub drb_id: DRB_ID,
@@ -1244,11 +2062,11 @@ pub struct DRBsSubjectToEarlyStatusTransfer_Item {
     pub ie_extension: Option<DRBsSubjectToEarlyStatusTransfer_ItemIE_Extension>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "32")]
 pub struct DRBsSubjectToEarlyStatusTransfer_List(pub Vec<DRBsSubjectToEarlyStatusTransfer_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct DRBsSubjectToStatusTransferItem {
     pub drb_id: DRB_ID,
@@ -1258,11 +2076,11 @@ pub struct DRBsSubjectToStatusTransferItem {
     pub ie_extension: Option<DRBsSubjectToStatusTransferItemIE_Extension>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "32")]
 pub struct DRBsSubjectToStatusTransferList(pub Vec<DRBsSubjectToStatusTransferItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct DRBsToQosFlowsMappingItem {
     pub drb_id: DRB_ID,
@@ -1271,29 +2089,29 @@ pub struct DRBsToQosFlowsMappingItem {
     pub ie_extensions: Option<DRBsToQosFlowsMappingItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "32")]
 pub struct DRBsToQosFlowsMappingList(pub Vec<DRBsToQosFlowsMappingItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "8", sz_ub = "8")]
-pub struct DataCodingScheme(pub BitVec<u8, Msb0>);
+pub struct DataCodingScheme(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct DataForwardingAccepted(pub u8);
 impl DataForwardingAccepted {
     pub const DATA_FORWARDING_ACCEPTED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct DataForwardingNotPossible(pub u8);
 impl DataForwardingNotPossible {
     pub const DATA_FORWARDING_NOT_POSSIBLE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct DataForwardingResponseDRBItem {
     pub drb_id: DRB_ID,
@@ -1305,11 +2123,11 @@ pub struct DataForwardingResponseDRBItem {
     pub ie_extensions: Option<DataForwardingResponseDRBItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "32")]
 pub struct DataForwardingResponseDRBList(pub Vec<DataForwardingResponseDRBItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1318,7 +2136,7 @@ pub struct DataForwardingResponseDRBList(pub Vec<DataForwardingResponseDRBItem>)
 )]
 pub struct DataForwardingResponseERABList(pub Vec<DataForwardingResponseERABListItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct DataForwardingResponseERABListItem {
     pub e_rab_id: E_RAB_ID,
@@ -1327,13 +2145,13 @@ pub struct DataForwardingResponseERABListItem {
     pub ie_extensions: Option<DataForwardingResponseERABListItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct DeactivateTrace {
     pub protocol_i_es: DeactivateTraceProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct DelayCritical(pub u8);
 impl DelayCritical {
@@ -1341,56 +2159,56 @@ impl DelayCritical {
     pub const NON_DELAY_CRITICAL: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct DirectForwardingPathAvailability(pub u8);
 impl DirectForwardingPathAvailability {
     pub const DIRECT_PATH_AVAILABLE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct DownlinkNASTransport {
     pub protocol_i_es: DownlinkNASTransportProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct DownlinkNonUEAssociatedNRPPaTransport {
     pub protocol_i_es: DownlinkNonUEAssociatedNRPPaTransportProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct DownlinkRANConfigurationTransfer {
     pub protocol_i_es: DownlinkRANConfigurationTransferProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct DownlinkRANEarlyStatusTransfer {
     pub protocol_i_es: DownlinkRANEarlyStatusTransferProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct DownlinkRANStatusTransfer {
     pub protocol_i_es: DownlinkRANStatusTransferProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct DownlinkRIMInformationTransfer {
     pub protocol_i_es: DownlinkRIMInformationTransferProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct DownlinkUEAssociatedNRPPaTransport {
     pub protocol_i_es: DownlinkUEAssociatedNRPPaTransportProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 5)]
 pub struct Dynamic5QIDescriptor {
     pub priority_level_qos: PriorityLevelQos,
@@ -1408,11 +2226,11 @@ pub struct Dynamic5QIDescriptor {
     pub ie_extensions: Option<Dynamic5QIDescriptorIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "15", extensible = true)]
 pub struct E_RAB_ID(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct E_RABInformationItem {
     pub e_rab_id: E_RAB_ID,
@@ -1422,7 +2240,7 @@ pub struct E_RABInformationItem {
     pub ie_extensions: Option<E_RABInformationItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1431,37 +2249,37 @@ pub struct E_RABInformationItem {
 )]
 pub struct E_RABInformationList(pub Vec<E_RABInformationItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct EDT_Session(pub u8);
 impl EDT_Session {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
 pub struct EN_DCSONConfigurationTransfer(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "4", extensible = false)]
 pub enum ENB_ID {
     #[asn(key = 0, extended = false)]
-    MacroENB_ID(BIT_STRING_18),
+    MacroENB_ID(ENB_ID_macroENB_ID),
     #[asn(key = 1, extended = false)]
-    HomeENB_ID(BIT_STRING_19),
+    HomeENB_ID(ENB_ID_homeENB_ID),
     #[asn(key = 2, extended = false)]
-    Short_macroENB_ID(BIT_STRING_20),
+    Short_macroENB_ID(ENB_ID_short_macroENB_ID),
     #[asn(key = 3, extended = false)]
-    Long_macroENB_ID(BIT_STRING_21),
+    Long_macroENB_ID(ENB_ID_long_macroENB_ID),
     #[asn(key = 4, extended = false)]
-    Choice_Extensions(ENB_IDchoice_Extensions),
+    Choice_Extensions(ENB_ID_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "2", sz_ub = "2")]
 pub struct EPS_TAC(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct EPS_TAI {
     pub plmn_identity: PLMNIdentity,
@@ -1470,7 +2288,7 @@ pub struct EPS_TAI {
     pub ie_extensions: Option<EPS_TAIIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct EUTRA_CGI {
     pub plmn_identity: PLMNIdentity,
@@ -1479,7 +2297,7 @@ pub struct EUTRA_CGI {
     pub ie_extensions: Option<EUTRA_CGIIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1488,7 +2306,7 @@ pub struct EUTRA_CGI {
 )]
 pub struct EUTRA_CGIList(pub Vec<EUTRA_CGI>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1497,19 +2315,19 @@ pub struct EUTRA_CGIList(pub Vec<EUTRA_CGI>);
 )]
 pub struct EUTRA_CGIListForWarning(pub Vec<EUTRA_CGI>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "28", sz_ub = "28")]
-pub struct EUTRACellIdentity(pub BitVec<u8, Msb0>);
+pub struct EUTRACellIdentity(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = true, sz_lb = "16", sz_ub = "16")]
-pub struct EUTRAencryptionAlgorithms(pub BitVec<u8, Msb0>);
+pub struct EUTRAencryptionAlgorithms(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = true, sz_lb = "16", sz_ub = "16")]
-pub struct EUTRAintegrityProtectionAlgorithms(pub BitVec<u8, Msb0>);
+pub struct EUTRAintegrityProtectionAlgorithms(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct EarlyStatusTransfer_TransparentContainer {
     pub procedure_stage: ProcedureStageChoice,
@@ -1517,11 +2335,11 @@ pub struct EarlyStatusTransfer_TransparentContainer {
     pub ie_extensions: Option<EarlyStatusTransfer_TransparentContainerIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "3", sz_ub = "3")]
 pub struct EmergencyAreaID(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1530,7 +2348,7 @@ pub struct EmergencyAreaID(pub Vec<u8>);
 )]
 pub struct EmergencyAreaIDBroadcastEUTRA(pub Vec<EmergencyAreaIDBroadcastEUTRA_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct EmergencyAreaIDBroadcastEUTRA_Item {
     pub emergency_area_id: EmergencyAreaID,
@@ -1539,7 +2357,7 @@ pub struct EmergencyAreaIDBroadcastEUTRA_Item {
     pub ie_extensions: Option<EmergencyAreaIDBroadcastEUTRA_ItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1548,7 +2366,7 @@ pub struct EmergencyAreaIDBroadcastEUTRA_Item {
 )]
 pub struct EmergencyAreaIDBroadcastNR(pub Vec<EmergencyAreaIDBroadcastNR_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct EmergencyAreaIDBroadcastNR_Item {
     pub emergency_area_id: EmergencyAreaID,
@@ -1557,7 +2375,7 @@ pub struct EmergencyAreaIDBroadcastNR_Item {
     pub ie_extensions: Option<EmergencyAreaIDBroadcastNR_ItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1566,7 +2384,7 @@ pub struct EmergencyAreaIDBroadcastNR_Item {
 )]
 pub struct EmergencyAreaIDCancelledEUTRA(pub Vec<EmergencyAreaIDCancelledEUTRA_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct EmergencyAreaIDCancelledEUTRA_Item {
     pub emergency_area_id: EmergencyAreaID,
@@ -1575,7 +2393,7 @@ pub struct EmergencyAreaIDCancelledEUTRA_Item {
     pub ie_extensions: Option<EmergencyAreaIDCancelledEUTRA_ItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1584,7 +2402,7 @@ pub struct EmergencyAreaIDCancelledEUTRA_Item {
 )]
 pub struct EmergencyAreaIDCancelledNR(pub Vec<EmergencyAreaIDCancelledNR_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct EmergencyAreaIDCancelledNR_Item {
     pub emergency_area_id: EmergencyAreaID,
@@ -1593,7 +2411,7 @@ pub struct EmergencyAreaIDCancelledNR_Item {
     pub ie_extensions: Option<EmergencyAreaIDCancelledNR_ItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1602,7 +2420,7 @@ pub struct EmergencyAreaIDCancelledNR_Item {
 )]
 pub struct EmergencyAreaIDList(pub Vec<EmergencyAreaID>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1611,7 +2429,7 @@ pub struct EmergencyAreaIDList(pub Vec<EmergencyAreaID>);
 )]
 pub struct EmergencyAreaIDListForRestart(pub Vec<EmergencyAreaID>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct EmergencyFallbackIndicator {
     pub emergency_fallback_request_indicator: EmergencyFallbackRequestIndicator,
@@ -1621,14 +2439,14 @@ pub struct EmergencyFallbackIndicator {
     pub ie_extensions: Option<EmergencyFallbackIndicatorIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct EmergencyFallbackRequestIndicator(pub u8);
 impl EmergencyFallbackRequestIndicator {
     pub const EMERGENCY_FALLBACK_REQUESTED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct EmergencyServiceTargetCN(pub u8);
 impl EmergencyServiceTargetCN {
@@ -1636,7 +2454,7 @@ impl EmergencyServiceTargetCN {
     pub const EPC: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct EndIndication(pub u8);
 impl EndIndication {
@@ -1644,7 +2462,7 @@ impl EndIndication {
     pub const FURTHER_DATA_EXISTS: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false, optional_fields = 1)]
 pub struct EndpointIPAddressAndPort {
     pub endpoint_ip_address: TransportLayerAddress,
@@ -1653,24 +2471,24 @@ pub struct EndpointIPAddressAndPort {
     pub ie_extensions: Option<EndpointIPAddressAndPortIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct Enhanced_CoverageRestriction(pub u8);
 impl Enhanced_CoverageRestriction {
     pub const RESTRICTED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "15")]
 pub struct EquivalentPLMNs(pub Vec<PLMNIdentity>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct ErrorIndication {
     pub protocol_i_es: ErrorIndicationProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct EventL1LoggedMDTConfig {
     pub l1_threshold: MeasurementThresholdL1LoggedMDT,
@@ -1680,18 +2498,18 @@ pub struct EventL1LoggedMDTConfig {
     pub ie_extensions: Option<EventL1LoggedMDTConfigIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum EventTrigger {
     #[asn(key = 0, extended = false)]
-    OutOfCoverage(ENUMERATED_22),
+    OutOfCoverage(EventTrigger_outOfCoverage),
     #[asn(key = 1, extended = false)]
     EventL1LoggedMDTConfig(EventL1LoggedMDTConfig),
     #[asn(key = 2, extended = false)]
-    Choice_Extensions(EventTriggerchoice_Extensions),
+    Choice_Extensions(EventTrigger_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "5")]
 pub struct EventType(pub u8);
 impl EventType {
@@ -1703,11 +2521,11 @@ impl EventType {
     pub const CANCEL_LOCATION_REPORTING_FOR_THE_UE: u8 = 5u8;
 }
 
-#[derive(Debug, AperCodec)]
-#[asn(type = "INTEGER", lb = "1", ub = "30", extensible = true)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
+#[asn(type = "INTEGER", lb = "1", ub = "181", extensible = true)]
 pub struct ExpectedActivityPeriod(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "6")]
 pub struct ExpectedHOInterval(pub u8);
 impl ExpectedHOInterval {
@@ -1720,11 +2538,11 @@ impl ExpectedHOInterval {
     pub const LONG_TIME: u8 = 6u8;
 }
 
-#[derive(Debug, AperCodec)]
-#[asn(type = "INTEGER", lb = "1", ub = "30", extensible = true)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
+#[asn(type = "INTEGER", lb = "1", ub = "181", extensible = true)]
 pub struct ExpectedIdlePeriod(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 4)]
 pub struct ExpectedUEActivityBehaviour {
     #[asn(optional_idx = 0)]
@@ -1737,7 +2555,7 @@ pub struct ExpectedUEActivityBehaviour {
     pub ie_extensions: Option<ExpectedUEActivityBehaviourIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 5)]
 pub struct ExpectedUEBehaviour {
     #[asn(optional_idx = 0)]
@@ -1752,7 +2570,7 @@ pub struct ExpectedUEBehaviour {
     pub ie_extensions: Option<ExpectedUEBehaviourIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct ExpectedUEMobility(pub u8);
 impl ExpectedUEMobility {
@@ -1760,21 +2578,21 @@ impl ExpectedUEMobility {
     pub const MOBILE: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct ExpectedUEMovingTrajectory(pub Vec<ExpectedUEMovingTrajectoryItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct ExpectedUEMovingTrajectoryItem {
     pub ngran_cgi: NGRAN_CGI,
     #[asn(optional_idx = 0)]
-    pub time_stayed_in_cell: Option<INTEGER_23>,
+    pub time_stayed_in_cell: Option<ExpectedUEMovingTrajectoryItemTimeStayedInCell>,
     #[asn(optional_idx = 1)]
     pub ie_extensions: Option<ExpectedUEMovingTrajectoryItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct Extended_AMFName {
     #[asn(optional_idx = 0)]
@@ -1785,11 +2603,11 @@ pub struct Extended_AMFName {
     pub ie_extensions: Option<Extended_AMFNameIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "255")]
 pub struct Extended_ConnectedTime(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct Extended_RANNodeName {
     #[asn(optional_idx = 0)]
@@ -1800,24 +2618,24 @@ pub struct Extended_RANNodeName {
     pub ie_extensions: Option<Extended_RANNodeNameIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "1", ub = "65535", extensible = true)]
 pub struct ExtendedPacketDelayBudget(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct ExtendedRATRestrictionInformation {
-    pub primary_rat_restriction: BIT_STRING_24,
-    pub secondary_rat_restriction: BIT_STRING_25,
+    pub primary_rat_restriction: ExtendedRATRestrictionInformationPrimaryRATRestriction,
+    pub secondary_rat_restriction: ExtendedRATRestrictionInformationSecondaryRATRestriction,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<ExtendedRATRestrictionInformationIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "4096", ub = "65535")]
 pub struct ExtendedRNC_ID(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1826,11 +2644,11 @@ pub struct ExtendedRNC_ID(pub u16);
 )]
 pub struct ExtendedSliceSupportList(pub Vec<SliceSupportItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "16", sz_ub = "16")]
-pub struct ExtendedUEIdentityIndexValue(pub BitVec<u8, Msb0>);
+pub struct ExtendedUEIdentityIndexValue(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct FailureIndication {
     pub uerlf_report_container: UERLFReportContainer,
@@ -1838,7 +2656,7 @@ pub struct FailureIndication {
     pub ie_extensions: Option<FailureIndicationIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct FirstDLCount {
     pub dr_bs_subject_to_early_status_transfer: DRBsSubjectToEarlyStatusTransfer_List,
@@ -1846,7 +2664,7 @@ pub struct FirstDLCount {
     pub ie_extension: Option<FirstDLCountIE_Extension>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct FiveG_S_TMSI {
     pub amf_set_id: AMFSetID,
@@ -1856,19 +2674,19 @@ pub struct FiveG_S_TMSI {
     pub ie_extensions: Option<FiveG_S_TMSIIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "4", sz_ub = "4")]
 pub struct FiveG_TMSI(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "255", extensible = true)]
 pub struct FiveQI(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct ForbiddenAreaInformation(pub Vec<ForbiddenAreaInformation_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct ForbiddenAreaInformation_Item {
     pub plmn_identity: PLMNIdentity,
@@ -1877,7 +2695,7 @@ pub struct ForbiddenAreaInformation_Item {
     pub ie_extensions: Option<ForbiddenAreaInformation_ItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1886,7 +2704,7 @@ pub struct ForbiddenAreaInformation_Item {
 )]
 pub struct ForbiddenTACs(pub Vec<TAC>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false, optional_fields = 1)]
 pub struct FromEUTRANtoNGRAN {
     pub sourcee_nbid: IntersystemSONeNBID,
@@ -1895,7 +2713,7 @@ pub struct FromEUTRANtoNGRAN {
     pub ie_extensions: Option<FromEUTRANtoNGRANIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false, optional_fields = 1)]
 pub struct FromNGRANtoEUTRAN {
     pub source_ngra_nnode_id: IntersystemSONNGRANnodeID,
@@ -1904,7 +2722,7 @@ pub struct FromNGRANtoEUTRAN {
     pub ie_extensions: Option<FromNGRANtoEUTRANIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 4)]
 pub struct GBR_QosInformation {
     pub maximum_flow_bit_rate_dl: BitRate,
@@ -1921,24 +2739,24 @@ pub struct GBR_QosInformation {
     pub ie_extensions: Option<GBR_QosInformationIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = false)]
 pub enum GNB_ID {
     #[asn(key = 0, extended = false)]
-    GNB_ID(BIT_STRING_26),
+    GNB_ID(GNB_ID_gNB_ID),
     #[asn(key = 1, extended = false)]
-    Choice_Extensions(GNB_IDchoice_Extensions),
+    Choice_Extensions(GNB_ID_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "22", sz_ub = "22")]
-pub struct GNBSetID(pub BitVec<u8, Msb0>);
+pub struct GNBSetID(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "4", sz_ub = "4")]
 pub struct GTP_TEID(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct GTPTunnel {
     pub transport_layer_address: TransportLayerAddress,
@@ -1947,7 +2765,7 @@ pub struct GTPTunnel {
     pub ie_extensions: Option<GTPTunnelIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct GUAMI {
     pub plmn_identity: PLMNIdentity,
@@ -1958,7 +2776,7 @@ pub struct GUAMI {
     pub ie_extensions: Option<GUAMIIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct GUAMIType(pub u8);
 impl GUAMIType {
@@ -1966,11 +2784,11 @@ impl GUAMIType {
     pub const MAPPED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
 pub struct GlobalCable_ID(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct GlobalENB_ID {
     pub plm_nidentity: PLMNIdentity,
@@ -1979,7 +2797,7 @@ pub struct GlobalENB_ID {
     pub ie_extensions: Option<GlobalENB_IDIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct GlobalGNB_ID {
     pub plmn_identity: PLMNIdentity,
@@ -1988,7 +2806,7 @@ pub struct GlobalGNB_ID {
     pub ie_extensions: Option<GlobalGNB_IDIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct GlobalLine_ID {
     pub global_line_identity: GlobalLineIdentity,
@@ -1998,11 +2816,11 @@ pub struct GlobalLine_ID {
     pub ie_extensions: Option<GlobalLine_IDIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
 pub struct GlobalLineIdentity(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct GlobalN3IWF_ID {
     pub plmn_identity: PLMNIdentity,
@@ -2011,7 +2829,7 @@ pub struct GlobalN3IWF_ID {
     pub ie_extensions: Option<GlobalN3IWF_IDIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct GlobalNgENB_ID {
     pub plmn_identity: PLMNIdentity,
@@ -2020,7 +2838,7 @@ pub struct GlobalNgENB_ID {
     pub ie_extensions: Option<GlobalNgENB_IDIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "3", extensible = false)]
 pub enum GlobalRANNodeID {
     #[asn(key = 0, extended = false)]
@@ -2030,10 +2848,10 @@ pub enum GlobalRANNodeID {
     #[asn(key = 2, extended = false)]
     GlobalN3IWF_ID(GlobalN3IWF_ID),
     #[asn(key = 3, extended = false)]
-    Choice_Extensions(GlobalRANNodeIDchoice_Extensions),
+    Choice_Extensions(GlobalRANNodeID_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct GlobalTNGF_ID {
     pub plmn_identity: PLMNIdentity,
@@ -2042,7 +2860,7 @@ pub struct GlobalTNGF_ID {
     pub ie_extensions: Option<GlobalTNGF_IDIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct GlobalTWIF_ID {
     pub plmn_identity: PLMNIdentity,
@@ -2051,7 +2869,7 @@ pub struct GlobalTWIF_ID {
     pub ie_extensions: Option<GlobalTWIF_IDIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct GlobalW_AGF_ID {
     pub plmn_identity: PLMNIdentity,
@@ -2060,21 +2878,21 @@ pub struct GlobalW_AGF_ID {
     pub ie_extensions: Option<GlobalW_AGF_IDIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
 pub struct HFCNode_ID(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 6)]
 pub struct HOReport {
-    pub handover_report_type: ENUMERATED_27,
+    pub handover_report_type: HOReportHandoverReportType,
     pub handover_cause: Cause,
     pub sourcecell_cgi: NGRAN_CGI,
     pub targetcell_cgi: NGRAN_CGI,
     #[asn(optional_idx = 0)]
     pub reestablishmentcell_cgi: Option<NGRAN_CGI>,
     #[asn(optional_idx = 1)]
-    pub sourcecell_c_rnti: Option<BIT_STRING_28>,
+    pub sourcecell_c_rnti: Option<HOReportSourcecellC_RNTI>,
     #[asn(optional_idx = 2)]
     pub targetcellin_e_utran: Option<EUTRA_CGI>,
     #[asn(optional_idx = 3)]
@@ -2085,25 +2903,25 @@ pub struct HOReport {
     pub ie_extensions: Option<HOReportIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct HandoverCancel {
     pub protocol_i_es: HandoverCancelProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct HandoverCancelAcknowledge {
     pub protocol_i_es: HandoverCancelAcknowledgeProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct HandoverCommand {
     pub protocol_i_es: HandoverCommandProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 4)]
 pub struct HandoverCommandTransfer {
     #[asn(optional_idx = 0)]
@@ -2116,32 +2934,32 @@ pub struct HandoverCommandTransfer {
     pub ie_extensions: Option<HandoverCommandTransferIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct HandoverFailure {
     pub protocol_i_es: HandoverFailureProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct HandoverFlag(pub u8);
 impl HandoverFlag {
     pub const HANDOVER_PREPARATION: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct HandoverNotify {
     pub protocol_i_es: HandoverNotifyProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct HandoverPreparationFailure {
     pub protocol_i_es: HandoverPreparationFailureProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct HandoverPreparationUnsuccessfulTransfer {
     pub cause: Cause,
@@ -2149,19 +2967,19 @@ pub struct HandoverPreparationUnsuccessfulTransfer {
     pub ie_extensions: Option<HandoverPreparationUnsuccessfulTransferIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct HandoverRequest {
     pub protocol_i_es: HandoverRequestProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct HandoverRequestAcknowledge {
     pub protocol_i_es: HandoverRequestAcknowledgeProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 5)]
 pub struct HandoverRequestAcknowledgeTransfer {
     pub dl_ngu_up_tnl_information: UPTransportLayerInformation,
@@ -2178,13 +2996,13 @@ pub struct HandoverRequestAcknowledgeTransfer {
     pub ie_extensions: Option<HandoverRequestAcknowledgeTransferIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct HandoverRequired {
     pub protocol_i_es: HandoverRequiredProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct HandoverRequiredTransfer {
     #[asn(optional_idx = 0)]
@@ -2193,7 +3011,7 @@ pub struct HandoverRequiredTransfer {
     pub ie_extensions: Option<HandoverRequiredTransferIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct HandoverResourceAllocationUnsuccessfulTransfer {
     pub cause: Cause,
@@ -2203,26 +3021,27 @@ pub struct HandoverResourceAllocationUnsuccessfulTransfer {
     pub ie_extensions: Option<HandoverResourceAllocationUnsuccessfulTransferIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct HandoverSuccess {
     pub protocol_i_es: HandoverSuccessProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct HandoverType(pub u8);
 impl HandoverType {
     pub const INTRA5GS: u8 = 0u8;
     pub const FIVEGS_TO_EPS: u8 = 1u8;
     pub const EPS_TO_5GS: u8 = 2u8;
+    pub const FIVEGS_TO_UTRAN: u8 = 3u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "30")]
 pub struct Hysteresis(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct IAB_Authorized(pub u8);
 impl IAB_Authorized {
@@ -2230,21 +3049,21 @@ impl IAB_Authorized {
     pub const NOT_AUTHORIZED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct IAB_Supported(pub u8);
 impl IAB_Supported {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct IABNodeIndication(pub u8);
 impl IABNodeIndication {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct IMSVoiceSupportIndicator(pub u8);
 impl IMSVoiceSupportIndicator {
@@ -2252,7 +3071,7 @@ impl IMSVoiceSupportIndicator {
     pub const NOT_SUPPORTED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 10)]
 pub struct ImmediateMDTNr {
     pub measurements_to_activate: MeasurementsToActivate,
@@ -2278,11 +3097,11 @@ pub struct ImmediateMDTNr {
     pub ie_extensions: Option<ImmediateMDTNrIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "1", ub = "256", extensible = true)]
 pub struct IndexToRFSP(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct InfoOnRecommendedCellsAndRANNodesForPaging {
     pub recommended_cells_for_paging: RecommendedCellsForPaging,
@@ -2291,31 +3110,31 @@ pub struct InfoOnRecommendedCellsAndRANNodesForPaging {
     pub ie_extensions: Option<InfoOnRecommendedCellsAndRANNodesForPagingIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct InitialContextSetupFailure {
     pub protocol_i_es: InitialContextSetupFailureProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct InitialContextSetupRequest {
     pub protocol_i_es: InitialContextSetupRequestProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct InitialContextSetupResponse {
     pub protocol_i_es: InitialContextSetupResponseProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct InitialUEMessage {
     pub protocol_i_es: InitialUEMessageProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct InitiatingMessage {
     #[asn(key_field = true)]
@@ -2324,7 +3143,7 @@ pub struct InitiatingMessage {
     pub value: InitiatingMessageValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct IntegrityProtectionIndication(pub u8);
 impl IntegrityProtectionIndication {
@@ -2333,7 +3152,7 @@ impl IntegrityProtectionIndication {
     pub const NOT_NEEDED: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct IntegrityProtectionResult(pub u8);
 impl IntegrityProtectionResult {
@@ -2341,11 +3160,11 @@ impl IntegrityProtectionResult {
     pub const NOT_PERFORMED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "1", ub = "16", extensible = true)]
 pub struct IntendedNumberOfPagingAttempts(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct InterSystemFailureIndication {
     #[asn(optional_idx = 0)]
@@ -2354,7 +3173,7 @@ pub struct InterSystemFailureIndication {
     pub ie_extensions: Option<InterSystemFailureIndicationIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct InterSystemHOReport {
     pub handover_report_type: InterSystemHandoverReportType,
@@ -2362,7 +3181,7 @@ pub struct InterSystemHOReport {
     pub ie_extensions: Option<InterSystemHOReportIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum InterSystemHandoverReportType {
     #[asn(key = 0, extended = false)]
@@ -2370,14 +3189,14 @@ pub enum InterSystemHandoverReportType {
     #[asn(key = 1, extended = false)]
     IntersystemUnnecessaryHO(IntersystemUnnecessaryHO),
     #[asn(key = 2, extended = false)]
-    Choice_Extensions(InterSystemHandoverReportTypechoice_Extensions),
+    Choice_Extensions(InterSystemHandoverReportType_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "8", sz_ub = "8")]
-pub struct InterfacesToTrace(pub BitVec<u8, Msb0>);
+pub struct InterfacesToTrace(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct IntersystemSONConfigurationTransfer {
     pub transfer_type: IntersystemSONTransferType,
@@ -2386,16 +3205,16 @@ pub struct IntersystemSONConfigurationTransfer {
     pub ie_extensions: Option<IntersystemSONConfigurationTransferIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = false)]
 pub enum IntersystemSONInformation {
     #[asn(key = 0, extended = false)]
     IntersystemSONInformationReport(IntersystemSONInformationReport),
     #[asn(key = 1, extended = false)]
-    Choice_Extensions(IntersystemSONInformationchoice_Extensions),
+    Choice_Extensions(IntersystemSONInformation_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum IntersystemSONInformationReport {
     #[asn(key = 0, extended = false)]
@@ -2403,10 +3222,10 @@ pub enum IntersystemSONInformationReport {
     #[asn(key = 1, extended = false)]
     FailureIndicationInformation(InterSystemFailureIndication),
     #[asn(key = 2, extended = false)]
-    Choice_Extensions(IntersystemSONInformationReportchoice_Extensions),
+    Choice_Extensions(IntersystemSONInformationReport_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct IntersystemSONNGRANnodeID {
     pub global_ran_node_id: GlobalRANNodeID,
@@ -2415,7 +3234,7 @@ pub struct IntersystemSONNGRANnodeID {
     pub ie_extensions: Option<IntersystemSONNGRANnodeIDIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum IntersystemSONTransferType {
     #[asn(key = 0, extended = false)]
@@ -2423,10 +3242,10 @@ pub enum IntersystemSONTransferType {
     #[asn(key = 1, extended = false)]
     FromNGRANtoEUTRAN(FromNGRANtoEUTRAN),
     #[asn(key = 2, extended = false)]
-    Choice_Extensions(IntersystemSONTransferTypechoice_Extensions),
+    Choice_Extensions(IntersystemSONTransferType_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct IntersystemSONeNBID {
     pub globale_nbid: GlobalENB_ID,
@@ -2435,22 +3254,22 @@ pub struct IntersystemSONeNBID {
     pub ie_extensions: Option<IntersystemSONeNBIDIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct IntersystemUnnecessaryHO {
     pub sourcecell_id: NGRAN_CGI,
     pub targetcell_id: EUTRA_CGI,
-    pub early_iratho: ENUMERATED_29,
+    pub early_iratho: IntersystemUnnecessaryHOEarlyIRATHO,
     pub candidate_cell_list: CandidateCellList,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<IntersystemUnnecessaryHOIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "2", sz_ub = "2")]
 pub struct LAC(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct LAI {
     pub plm_nidentity: PLMNIdentity,
@@ -2459,18 +3278,18 @@ pub struct LAI {
     pub ie_extensions: Option<LAIIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct LTEM_Indication(pub u8);
 impl LTEM_Indication {
     pub const LTE_M: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
 pub struct LTEUERLFReportContainer(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct LTEUESidelinkAggregateMaximumBitrate {
     pub ue_sidelink_aggregate_maximum_bit_rate: BitRate,
@@ -2478,7 +3297,7 @@ pub struct LTEUESidelinkAggregateMaximumBitrate {
     pub ie_extensions: Option<LTEUESidelinkAggregateMaximumBitrateIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct LTEV2XServicesAuthorized {
     #[asn(optional_idx = 0)]
@@ -2489,7 +3308,7 @@ pub struct LTEV2XServicesAuthorized {
     pub ie_extensions: Option<LTEV2XServicesAuthorizedIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "4", extensible = false)]
 pub enum LastVisitedCellInformation {
     #[asn(key = 0, extended = false)]
@@ -2501,10 +3320,10 @@ pub enum LastVisitedCellInformation {
     #[asn(key = 3, extended = false)]
     GERANCell(LastVisitedGERANCellInformation),
     #[asn(key = 4, extended = false)]
-    Choice_Extensions(LastVisitedCellInformationchoice_Extensions),
+    Choice_Extensions(LastVisitedCellInformation_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct LastVisitedCellItem {
     pub last_visited_cell_information: LastVisitedCellInformation,
@@ -2512,15 +3331,15 @@ pub struct LastVisitedCellItem {
     pub ie_extensions: Option<LastVisitedCellItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
 pub struct LastVisitedEUTRANCellInformation(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
 pub struct LastVisitedGERANCellInformation(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct LastVisitedNGRANCellInformation {
     pub global_cell_id: NGRAN_CGI,
@@ -2534,11 +3353,11 @@ pub struct LastVisitedNGRANCellInformation {
     pub ie_extensions: Option<LastVisitedNGRANCellInformationIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
 pub struct LastVisitedUTRANCellInformation(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct LineType(pub u8);
 impl LineType {
@@ -2546,7 +3365,7 @@ impl LineType {
     pub const PON: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct Links_to_log(pub u8);
 impl Links_to_log {
@@ -2555,36 +3374,36 @@ impl Links_to_log {
     pub const BOTH_UPLINK_AND_DOWNLINK: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct LocationReport {
     pub protocol_i_es: LocationReportProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct LocationReportingAdditionalInfo(pub u8);
 impl LocationReportingAdditionalInfo {
     pub const INCLUDE_PS_CELL: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct LocationReportingControl {
     pub protocol_i_es: LocationReportingControlProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct LocationReportingFailureIndication {
     pub protocol_i_es: LocationReportingFailureIndicationProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "1", ub = "64", extensible = true)]
 pub struct LocationReportingReferenceID(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct LocationReportingRequestType {
     pub event_type: EventType,
@@ -2597,7 +3416,7 @@ pub struct LocationReportingRequestType {
     pub ie_extensions: Option<LocationReportingRequestTypeIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 5)]
 pub struct LoggedMDTNr {
     pub logging_interval: LoggingInterval,
@@ -2615,18 +3434,18 @@ pub struct LoggedMDTNr {
     pub ie_extensions: Option<LoggedMDTNrIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum LoggedMDTTrigger {
     #[asn(key = 0, extended = false)]
-    Periodical(NULL_30),
+    Periodical(LoggedMDTTrigger_periodical),
     #[asn(key = 1, extended = false)]
     EventTrigger(EventTrigger),
     #[asn(key = 2, extended = false)]
-    Choice_Extensions(LoggedMDTTriggerchoice_Extensions),
+    Choice_Extensions(LoggedMDTTrigger_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "5")]
 pub struct LoggingDuration(pub u8);
 impl LoggingDuration {
@@ -2638,7 +3457,7 @@ impl LoggingDuration {
     pub const M120: u8 = 5u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "10")]
 pub struct LoggingInterval(pub u8);
 impl LoggingInterval {
@@ -2655,7 +3474,7 @@ impl LoggingInterval {
     pub const INFINITY: u8 = 10u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct M1Configuration {
     pub m1reporting_trigger: M1ReportingTrigger,
@@ -2667,7 +3486,7 @@ pub struct M1Configuration {
     pub ie_extensions: Option<M1ConfigurationIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct M1PeriodicReporting {
     pub report_interval: ReportIntervalMDT,
@@ -2676,7 +3495,7 @@ pub struct M1PeriodicReporting {
     pub ie_extensions: Option<M1PeriodicReportingIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct M1ReportingTrigger(pub u8);
 impl M1ReportingTrigger {
@@ -2685,7 +3504,7 @@ impl M1ReportingTrigger {
     pub const A2EVENTTRIGGERED_PERIODIC: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct M1ThresholdEventA2 {
     pub m1_threshold_type: M1ThresholdType,
@@ -2693,7 +3512,7 @@ pub struct M1ThresholdEventA2 {
     pub ie_extensions: Option<M1ThresholdEventA2IE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "3", extensible = false)]
 pub enum M1ThresholdType {
     #[asn(key = 0, extended = false)]
@@ -2703,10 +3522,10 @@ pub enum M1ThresholdType {
     #[asn(key = 2, extended = false)]
     Threshold_SINR(Threshold_SINR),
     #[asn(key = 3, extended = false)]
-    Choice_Extensions(M1ThresholdTypechoice_Extensions),
+    Choice_Extensions(M1ThresholdType_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct M4Configuration {
     pub m4period: M4period,
@@ -2715,7 +3534,7 @@ pub struct M4Configuration {
     pub ie_extensions: Option<M4ConfigurationIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "4")]
 pub struct M4period(pub u8);
 impl M4period {
@@ -2726,7 +3545,7 @@ impl M4period {
     pub const MIN1: u8 = 4u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct M5Configuration {
     pub m5period: M5period,
@@ -2735,7 +3554,7 @@ pub struct M5Configuration {
     pub ie_extensions: Option<M5ConfigurationIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "4")]
 pub struct M5period(pub u8);
 impl M5period {
@@ -2746,7 +3565,7 @@ impl M5period {
     pub const MIN1: u8 = 4u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct M6Configuration {
     pub m6report_interval: M6report_Interval,
@@ -2755,7 +3574,7 @@ pub struct M6Configuration {
     pub ie_extensions: Option<M6ConfigurationIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "13")]
 pub struct M6report_Interval(pub u8);
 impl M6report_Interval {
@@ -2775,7 +3594,7 @@ impl M6report_Interval {
     pub const MIN30: u8 = 13u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct M7Configuration {
     pub m7period: M7period,
@@ -2784,11 +3603,11 @@ pub struct M7Configuration {
     pub ie_extensions: Option<M7ConfigurationIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "1", ub = "60", extensible = true)]
 pub struct M7period(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct MDT_Activation(pub u8);
 impl MDT_Activation {
@@ -2797,7 +3616,7 @@ impl MDT_Activation {
     pub const IMMEDIATE_MDT_AND_TRACE: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct MDT_Configuration {
     #[asn(optional_idx = 0)]
@@ -2808,7 +3627,7 @@ pub struct MDT_Configuration {
     pub ie_extensions: Option<MDT_ConfigurationIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct MDT_Configuration_EUTRA {
     pub mdt_activation: MDT_Activation,
@@ -2820,7 +3639,7 @@ pub struct MDT_Configuration_EUTRA {
     pub ie_extensions: Option<MDT_Configuration_EUTRAIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct MDT_Configuration_NR {
     pub mdt_activation: MDT_Activation,
@@ -2832,7 +3651,7 @@ pub struct MDT_Configuration_NR {
     pub ie_extensions: Option<MDT_Configuration_NRIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct MDT_Location_Info {
     pub mdt_location_information: MDT_Location_Information,
@@ -2840,15 +3659,15 @@ pub struct MDT_Location_Info {
     pub ie_extensions: Option<MDT_Location_InfoIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "8", sz_ub = "8")]
-pub struct MDT_Location_Information(pub BitVec<u8, Msb0>);
+pub struct MDT_Location_Information(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
 pub struct MDTModeEutra(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum MDTModeNr {
     #[asn(key = 0, extended = false)]
@@ -2856,29 +3675,29 @@ pub enum MDTModeNr {
     #[asn(key = 1, extended = false)]
     LoggedMDTNr(LoggedMDTNr),
     #[asn(key = 2, extended = false)]
-    Choice_Extensions(MDTModeNrchoice_Extensions),
+    Choice_Extensions(MDTModeNr_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct MDTPLMNList(pub Vec<PLMNIdentity>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct MICOModeIndication(pub u8);
 impl MICOModeIndication {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "64", sz_ub = "64")]
-pub struct MaskedIMEISV(pub BitVec<u8, Msb0>);
+pub struct MaskedIMEISV(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "4095", extensible = true)]
 pub struct MaximumDataBurstVolume(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct MaximumIntegrityProtectedDataRate(pub u8);
 impl MaximumIntegrityProtectedDataRate {
@@ -2886,7 +3705,7 @@ impl MaximumIntegrityProtectedDataRate {
     pub const MAXIMUM_UE_RATE: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum MeasurementThresholdL1LoggedMDT {
     #[asn(key = 0, extended = false)]
@@ -2894,22 +3713,22 @@ pub enum MeasurementThresholdL1LoggedMDT {
     #[asn(key = 1, extended = false)]
     Threshold_RSRQ(Threshold_RSRQ),
     #[asn(key = 2, extended = false)]
-    Choice_Extensions(MeasurementThresholdL1LoggedMDTchoice_Extensions),
+    Choice_Extensions(MeasurementThresholdL1LoggedMDT_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "8", sz_ub = "8")]
-pub struct MeasurementsToActivate(pub BitVec<u8, Msb0>);
+pub struct MeasurementsToActivate(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "16", sz_ub = "16")]
-pub struct MessageIdentifier(pub BitVec<u8, Msb0>);
+pub struct MessageIdentifier(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "16", sz_ub = "16")]
-pub struct MobilityInformation(pub BitVec<u8, Msb0>);
+pub struct MobilityInformation(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 5)]
 pub struct MobilityRestrictionList {
     pub serving_plmn: PLMNIdentity,
@@ -2925,30 +3744,30 @@ pub struct MobilityRestrictionList {
     pub ie_extensions: Option<MobilityRestrictionListIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = false)]
 pub enum N3IWF_ID {
     #[asn(key = 0, extended = false)]
-    N3IWF_ID(BIT_STRING_31),
+    N3IWF_ID(N3IWF_ID_n3IWF_ID),
     #[asn(key = 1, extended = false)]
-    Choice_Extensions(N3IWF_IDchoice_Extensions),
+    Choice_Extensions(N3IWF_ID_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
 pub struct NAS_PDU(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct NASNonDeliveryIndication {
     pub protocol_i_es: NASNonDeliveryIndicationProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
 pub struct NASSecurityParametersFromNGRAN(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "3")]
 pub struct NB_IoT_DefaultPagingDRX(pub u8);
 impl NB_IoT_DefaultPagingDRX {
@@ -2958,7 +3777,7 @@ impl NB_IoT_DefaultPagingDRX {
     pub const RF1024: u8 = 3u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "15")]
 pub struct NB_IoT_Paging_TimeWindow(pub u8);
 impl NB_IoT_Paging_TimeWindow {
@@ -2980,7 +3799,7 @@ impl NB_IoT_Paging_TimeWindow {
     pub const S16: u8 = 15u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "13")]
 pub struct NB_IoT_Paging_eDRXCycle(pub u8);
 impl NB_IoT_Paging_eDRXCycle {
@@ -3000,7 +3819,7 @@ impl NB_IoT_Paging_eDRXCycle {
     pub const HF1024: u8 = 13u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct NB_IoT_Paging_eDRXInfo {
     pub nb_io_t_paging_e_drx_cycle: NB_IoT_Paging_eDRXCycle,
@@ -3010,7 +3829,7 @@ pub struct NB_IoT_Paging_eDRXInfo {
     pub ie_extensions: Option<NB_IoT_Paging_eDRXInfoIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "5")]
 pub struct NB_IoT_PagingDRX(pub u8);
 impl NB_IoT_PagingDRX {
@@ -3022,11 +3841,11 @@ impl NB_IoT_PagingDRX {
     pub const RF1024: u8 = 5u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "255", extensible = true)]
 pub struct NB_IoT_UEPriority(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = true)]
 pub enum NGAP_PDU {
     #[asn(key = 0, extended = false)]
@@ -3037,7 +3856,7 @@ pub enum NGAP_PDU {
     UnsuccessfulOutcome(UnsuccessfulOutcome),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum NGRAN_CGI {
     #[asn(key = 0, extended = false)]
@@ -3045,10 +3864,10 @@ pub enum NGRAN_CGI {
     #[asn(key = 1, extended = false)]
     EUTRA_CGI(EUTRA_CGI),
     #[asn(key = 2, extended = false)]
-    Choice_Extensions(NGRAN_CGIchoice_Extensions),
+    Choice_Extensions(NGRAN_CGI_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false, optional_fields = 2)]
 pub struct NGRAN_TNLAssociationToRemoveItem {
     pub tnl_association_transport_layer_address: CPTransportLayerInformation,
@@ -3058,58 +3877,58 @@ pub struct NGRAN_TNLAssociationToRemoveItem {
     pub ie_extensions: Option<NGRAN_TNLAssociationToRemoveItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "32")]
 pub struct NGRAN_TNLAssociationToRemoveList(pub Vec<NGRAN_TNLAssociationToRemoveItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "8", sz_ub = "8")]
 pub struct NGRANTraceID(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct NGReset {
     pub protocol_i_es: NGResetProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct NGResetAcknowledge {
     pub protocol_i_es: NGResetAcknowledgeProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct NGSetupFailure {
     pub protocol_i_es: NGSetupFailureProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct NGSetupRequest {
     pub protocol_i_es: NGSetupRequestProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct NGSetupResponse {
     pub protocol_i_es: NGSetupResponseProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "44", sz_ub = "44")]
-pub struct NID(pub BitVec<u8, Msb0>);
+pub struct NID(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = false)]
 pub enum NPN_AccessInformation {
     #[asn(key = 0, extended = false)]
     PNI_NPN_Access_Information(CellCAGList),
     #[asn(key = 1, extended = false)]
-    Choice_Extensions(NPN_AccessInformationchoice_Extensions),
+    Choice_Extensions(NPN_AccessInformation_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum NPN_MobilityInformation {
     #[asn(key = 0, extended = false)]
@@ -3117,28 +3936,28 @@ pub enum NPN_MobilityInformation {
     #[asn(key = 1, extended = false)]
     PNI_NPN_MobilityInformation(PNI_NPN_MobilityInformation),
     #[asn(key = 2, extended = false)]
-    Choice_Extensions(NPN_MobilityInformationchoice_Extensions),
+    Choice_Extensions(NPN_MobilityInformation_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = false)]
 pub enum NPN_PagingAssistanceInformation {
     #[asn(key = 0, extended = false)]
     PNI_NPN_PagingAssistance(Allowed_PNI_NPN_List),
     #[asn(key = 1, extended = false)]
-    Choice_Extensions(NPN_PagingAssistanceInformationchoice_Extensions),
+    Choice_Extensions(NPN_PagingAssistanceInformation_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = false)]
 pub enum NPN_Support {
     #[asn(key = 0, extended = false)]
     SNPN(NID),
     #[asn(key = 1, extended = false)]
-    Choice_Extensions(NPN_Supportchoice_Extensions),
+    Choice_Extensions(NPN_Support_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct NR_CGI {
     pub plmn_identity: PLMNIdentity,
@@ -3147,7 +3966,7 @@ pub struct NR_CGI {
     pub ie_extensions: Option<NR_CGIIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3156,7 +3975,7 @@ pub struct NR_CGI {
 )]
 pub struct NR_CGIList(pub Vec<NR_CGI>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3165,27 +3984,27 @@ pub struct NR_CGIList(pub Vec<NR_CGI>);
 )]
 pub struct NR_CGIListForWarning(pub Vec<NR_CGI>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "1007", extensible = true)]
 pub struct NR_PCI(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "3279165")]
 pub struct NRARFCN(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "36", sz_ub = "36")]
-pub struct NRCellIdentity(pub BitVec<u8, Msb0>);
+pub struct NRCellIdentity(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "1", ub = "1024", extensible = true)]
 pub struct NRFrequencyBand(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "32")]
 pub struct NRFrequencyBand_List(pub Vec<NRFrequencyBandItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct NRFrequencyBandItem {
     pub nr_frequency_band: NRFrequencyBand,
@@ -3193,7 +4012,7 @@ pub struct NRFrequencyBandItem {
     pub ie_extension: Option<NRFrequencyBandItemIE_Extension>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct NRFrequencyInfo {
     pub nr_arfcn: NRARFCN,
@@ -3202,19 +4021,19 @@ pub struct NRFrequencyInfo {
     pub ie_extension: Option<NRFrequencyInfoIE_Extension>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
 pub struct NRMobilityHistoryReport(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
 pub struct NRPPa_PDU(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
 pub struct NRUERLFReportContainer(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct NRUESidelinkAggregateMaximumBitrate {
     pub ue_sidelink_aggregate_maximum_bit_rate: BitRate,
@@ -3222,7 +4041,7 @@ pub struct NRUESidelinkAggregateMaximumBitrate {
     pub ie_extensions: Option<NRUESidelinkAggregateMaximumBitrateIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct NRV2XServicesAuthorized {
     #[asn(optional_idx = 0)]
@@ -3233,30 +4052,30 @@ pub struct NRV2XServicesAuthorized {
     pub ie_extensions: Option<NRV2XServicesAuthorizedIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = true, sz_lb = "16", sz_ub = "16")]
-pub struct NRencryptionAlgorithms(pub BitVec<u8, Msb0>);
+pub struct NRencryptionAlgorithms(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = true, sz_lb = "16", sz_ub = "16")]
-pub struct NRintegrityProtectionAlgorithms(pub BitVec<u8, Msb0>);
+pub struct NRintegrityProtectionAlgorithms(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "1", ub = "256", extensible = true)]
 pub struct NetworkInstance(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct NewSecurityContextInd(pub u8);
 impl NewSecurityContextInd {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "7")]
 pub struct NextHopChainingCount(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct NextPagingAreaScope(pub u8);
 impl NextPagingAreaScope {
@@ -3264,20 +4083,20 @@ impl NextPagingAreaScope {
     pub const CHANGED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "3", extensible = false)]
 pub enum NgENB_ID {
     #[asn(key = 0, extended = false)]
-    MacroNgENB_ID(BIT_STRING_32),
+    MacroNgENB_ID(NgENB_ID_macroNgENB_ID),
     #[asn(key = 1, extended = false)]
-    ShortMacroNgENB_ID(BIT_STRING_33),
+    ShortMacroNgENB_ID(NgENB_ID_shortMacroNgENB_ID),
     #[asn(key = 2, extended = false)]
-    LongMacroNgENB_ID(BIT_STRING_34),
+    LongMacroNgENB_ID(NgENB_ID_longMacroNgENB_ID),
     #[asn(key = 3, extended = false)]
-    Choice_Extensions(NgENB_IDchoice_Extensions),
+    Choice_Extensions(NgENB_ID_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 4)]
 pub struct NonDynamic5QIDescriptor {
     pub five_qi: FiveQI,
@@ -3291,11 +4110,11 @@ pub struct NonDynamic5QIDescriptor {
     pub ie_extensions: Option<NonDynamic5QIDescriptorIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct NotAllowedTACs(pub Vec<TAC>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct NotificationCause(pub u8);
 impl NotificationCause {
@@ -3303,29 +4122,29 @@ impl NotificationCause {
     pub const NOT_FULFILLED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct NotificationControl(pub u8);
 impl NotificationControl {
     pub const NOTIFICATION_REQUESTED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct NotifySourceNGRANNode(pub u8);
 impl NotifySourceNGRANNode {
     pub const NOTIFY_SOURCE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "65535")]
 pub struct NumberOfBroadcasts(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "65535")]
 pub struct NumberOfBroadcastsRequested(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "3")]
 pub struct OverloadAction(pub u8);
 impl OverloadAction {
@@ -3335,22 +4154,22 @@ impl OverloadAction {
     pub const PERMIT_HIGH_PRIORITY_SESSIONS_AND_MOBILE_TERMINATED_SERVICES_ONLY: u8 = 3u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = false)]
 pub enum OverloadResponse {
     #[asn(key = 0, extended = false)]
     OverloadAction(OverloadAction),
     #[asn(key = 1, extended = false)]
-    Choice_Extensions(OverloadResponsechoice_Extensions),
+    Choice_Extensions(OverloadResponse_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct OverloadStart {
     pub protocol_i_es: OverloadStartProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct OverloadStartNSSAIItem {
     pub slice_overload_list: SliceOverloadList,
@@ -3362,7 +4181,7 @@ pub struct OverloadStartNSSAIItem {
     pub ie_extensions: Option<OverloadStartNSSAIItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3371,13 +4190,13 @@ pub struct OverloadStartNSSAIItem {
 )]
 pub struct OverloadStartNSSAIList(pub Vec<OverloadStartNSSAIItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct OverloadStop {
     pub protocol_i_es: OverloadStopProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PC5FlowBitRates {
     pub guaranteed_flow_bit_rate: BitRate,
@@ -3386,7 +4205,7 @@ pub struct PC5FlowBitRates {
     pub ie_extensions: Option<PC5FlowBitRatesIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct PC5QoSFlowItem {
     pub pqi: FiveQI,
@@ -3398,7 +4217,7 @@ pub struct PC5QoSFlowItem {
     pub ie_extensions: Option<PC5QoSFlowItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3407,7 +4226,7 @@ pub struct PC5QoSFlowItem {
 )]
 pub struct PC5QoSFlowList(pub Vec<PC5QoSFlowItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct PC5QoSParameters {
     pub pc5_qo_s_flow_list: PC5QoSFlowList,
@@ -3417,11 +4236,11 @@ pub struct PC5QoSParameters {
     pub ie_extensions: Option<PC5QoSParametersIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "32")]
 pub struct PCIListForMDT(pub Vec<NR_PCI>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionAggregateMaximumBitRate {
     pub pdu_session_aggregate_maximum_bit_rate_dl: BitRate,
@@ -3430,20 +4249,21 @@ pub struct PDUSessionAggregateMaximumBitRate {
     pub ie_extensions: Option<PDUSessionAggregateMaximumBitRateIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "255")]
 pub struct PDUSessionID(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceAdmittedItem {
     pub pdu_session_id: PDUSessionID,
-    pub handover_request_acknowledge_transfer: OCTET_STRING_35,
+    pub handover_request_acknowledge_transfer:
+        PDUSessionResourceAdmittedItemHandoverRequestAcknowledgeTransfer,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceAdmittedItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3452,25 +4272,21 @@ pub struct PDUSessionResourceAdmittedItem {
 )]
 pub struct PDUSessionResourceAdmittedList(pub Vec<PDUSessionResourceAdmittedItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
-pub struct PDUSessionResourceFailedToModifyItemModCfm {
-    pub pdu_session_id: PDUSessionID,
-    pub pdu_session_resource_modify_indication_unsuccessful_transfer: OCTET_STRING_36,
-    #[asn(optional_idx = 0)]
-    pub ie_extensions: Option<PDUSessionResourceFailedToModifyItemModCfmIE_Extensions>,
-}
+pub struct PDUSessionResourceFailedToModifyItemModCfm { pub pdu_session_id : PDUSessionID , pub pdu_session_resource_modify_indication_unsuccessful_transfer : PDUSessionResourceFailedToModifyItemModCfmPDUSessionResourceModifyIndicationUnsuccessfulTransfer , # [asn (optional_idx = 0)] pub ie_extensions : Option < PDUSessionResourceFailedToModifyItemModCfmIE_Extensions > , }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceFailedToModifyItemModRes {
     pub pdu_session_id: PDUSessionID,
-    pub pdu_session_resource_modify_unsuccessful_transfer: OCTET_STRING_37,
+    pub pdu_session_resource_modify_unsuccessful_transfer:
+        PDUSessionResourceFailedToModifyItemModResPDUSessionResourceModifyUnsuccessfulTransfer,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceFailedToModifyItemModResIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3481,7 +4297,7 @@ pub struct PDUSessionResourceFailedToModifyListModCfm(
     pub Vec<PDUSessionResourceFailedToModifyItemModCfm>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3492,7 +4308,7 @@ pub struct PDUSessionResourceFailedToModifyListModRes(
     pub Vec<PDUSessionResourceFailedToModifyItemModRes>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceFailedToResumeItemRESReq {
     pub pdu_session_id: PDUSessionID,
@@ -3501,7 +4317,7 @@ pub struct PDUSessionResourceFailedToResumeItemRESReq {
     pub ie_extensions: Option<PDUSessionResourceFailedToResumeItemRESReqIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceFailedToResumeItemRESRes {
     pub pdu_session_id: PDUSessionID,
@@ -3510,7 +4326,7 @@ pub struct PDUSessionResourceFailedToResumeItemRESRes {
     pub ie_extensions: Option<PDUSessionResourceFailedToResumeItemRESResIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3521,7 +4337,7 @@ pub struct PDUSessionResourceFailedToResumeListRESReq(
     pub Vec<PDUSessionResourceFailedToResumeItemRESReq>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3532,52 +4348,57 @@ pub struct PDUSessionResourceFailedToResumeListRESRes(
     pub Vec<PDUSessionResourceFailedToResumeItemRESRes>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceFailedToSetupItemCxtFail {
     pub pdu_session_id: PDUSessionID,
-    pub pdu_session_resource_setup_unsuccessful_transfer: OCTET_STRING_38,
+    pub pdu_session_resource_setup_unsuccessful_transfer:
+        PDUSessionResourceFailedToSetupItemCxtFailPDUSessionResourceSetupUnsuccessfulTransfer,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceFailedToSetupItemCxtFailIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceFailedToSetupItemCxtRes {
     pub pdu_session_id: PDUSessionID,
-    pub pdu_session_resource_setup_unsuccessful_transfer: OCTET_STRING_39,
+    pub pdu_session_resource_setup_unsuccessful_transfer:
+        PDUSessionResourceFailedToSetupItemCxtResPDUSessionResourceSetupUnsuccessfulTransfer,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceFailedToSetupItemCxtResIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceFailedToSetupItemHOAck {
     pub pdu_session_id: PDUSessionID,
-    pub handover_resource_allocation_unsuccessful_transfer: OCTET_STRING_40,
+    pub handover_resource_allocation_unsuccessful_transfer:
+        PDUSessionResourceFailedToSetupItemHOAckHandoverResourceAllocationUnsuccessfulTransfer,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceFailedToSetupItemHOAckIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceFailedToSetupItemPSReq {
     pub pdu_session_id: PDUSessionID,
-    pub path_switch_request_setup_failed_transfer: OCTET_STRING_41,
+    pub path_switch_request_setup_failed_transfer:
+        PDUSessionResourceFailedToSetupItemPSReqPathSwitchRequestSetupFailedTransfer,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceFailedToSetupItemPSReqIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceFailedToSetupItemSURes {
     pub pdu_session_id: PDUSessionID,
-    pub pdu_session_resource_setup_unsuccessful_transfer: OCTET_STRING_42,
+    pub pdu_session_resource_setup_unsuccessful_transfer:
+        PDUSessionResourceFailedToSetupItemSUResPDUSessionResourceSetupUnsuccessfulTransfer,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceFailedToSetupItemSUResIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3588,7 +4409,7 @@ pub struct PDUSessionResourceFailedToSetupListCxtFail(
     pub Vec<PDUSessionResourceFailedToSetupItemCxtFail>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3599,7 +4420,7 @@ pub struct PDUSessionResourceFailedToSetupListCxtRes(
     pub Vec<PDUSessionResourceFailedToSetupItemCxtRes>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3610,7 +4431,7 @@ pub struct PDUSessionResourceFailedToSetupListHOAck(
     pub Vec<PDUSessionResourceFailedToSetupItemHOAck>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3621,7 +4442,7 @@ pub struct PDUSessionResourceFailedToSetupListPSReq(
     pub Vec<PDUSessionResourceFailedToSetupItemPSReq>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3632,16 +4453,16 @@ pub struct PDUSessionResourceFailedToSetupListSURes(
     pub Vec<PDUSessionResourceFailedToSetupItemSURes>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceHandoverItem {
     pub pdu_session_id: PDUSessionID,
-    pub handover_command_transfer: OCTET_STRING_43,
+    pub handover_command_transfer: PDUSessionResourceHandoverItemHandoverCommandTransfer,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceHandoverItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3650,7 +4471,7 @@ pub struct PDUSessionResourceHandoverItem {
 )]
 pub struct PDUSessionResourceHandoverList(pub Vec<PDUSessionResourceHandoverItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct PDUSessionResourceInformationItem {
     pub pdu_session_id: PDUSessionID,
@@ -3661,7 +4482,7 @@ pub struct PDUSessionResourceInformationItem {
     pub ie_extensions: Option<PDUSessionResourceInformationItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3670,7 +4491,7 @@ pub struct PDUSessionResourceInformationItem {
 )]
 pub struct PDUSessionResourceInformationList(pub Vec<PDUSessionResourceInformationItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceItemCxtRelCpl {
     pub pdu_session_id: PDUSessionID,
@@ -3678,7 +4499,7 @@ pub struct PDUSessionResourceItemCxtRelCpl {
     pub ie_extensions: Option<PDUSessionResourceItemCxtRelCplIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceItemCxtRelReq {
     pub pdu_session_id: PDUSessionID,
@@ -3686,16 +4507,16 @@ pub struct PDUSessionResourceItemCxtRelReq {
     pub ie_extensions: Option<PDUSessionResourceItemCxtRelReqIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceItemHORqd {
     pub pdu_session_id: PDUSessionID,
-    pub handover_required_transfer: OCTET_STRING_44,
+    pub handover_required_transfer: PDUSessionResourceItemHORqdHandoverRequiredTransfer,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceItemHORqdIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3704,7 +4525,7 @@ pub struct PDUSessionResourceItemHORqd {
 )]
 pub struct PDUSessionResourceListCxtRelCpl(pub Vec<PDUSessionResourceItemCxtRelCpl>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3713,7 +4534,7 @@ pub struct PDUSessionResourceListCxtRelCpl(pub Vec<PDUSessionResourceItemCxtRelC
 )]
 pub struct PDUSessionResourceListCxtRelReq(pub Vec<PDUSessionResourceItemCxtRelReq>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3722,13 +4543,13 @@ pub struct PDUSessionResourceListCxtRelReq(pub Vec<PDUSessionResourceItemCxtRelR
 )]
 pub struct PDUSessionResourceListHORqd(pub Vec<PDUSessionResourceItemHORqd>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PDUSessionResourceModifyConfirm {
     pub protocol_i_es: PDUSessionResourceModifyConfirmProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct PDUSessionResourceModifyConfirmTransfer {
     pub qos_flow_modify_confirm_list: QosFlowModifyConfirmList,
@@ -3741,13 +4562,13 @@ pub struct PDUSessionResourceModifyConfirmTransfer {
     pub ie_extensions: Option<PDUSessionResourceModifyConfirmTransferIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PDUSessionResourceModifyIndication {
     pub protocol_i_es: PDUSessionResourceModifyIndicationProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct PDUSessionResourceModifyIndicationTransfer {
     pub dl_qos_flow_per_tnl_information: QosFlowPerTNLInformation,
@@ -3757,7 +4578,7 @@ pub struct PDUSessionResourceModifyIndicationTransfer {
     pub ie_extensions: Option<PDUSessionResourceModifyIndicationTransferIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceModifyIndicationUnsuccessfulTransfer {
     pub cause: Cause,
@@ -3765,45 +4586,49 @@ pub struct PDUSessionResourceModifyIndicationUnsuccessfulTransfer {
     pub ie_extensions: Option<PDUSessionResourceModifyIndicationUnsuccessfulTransferIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceModifyItemModCfm {
     pub pdu_session_id: PDUSessionID,
-    pub pdu_session_resource_modify_confirm_transfer: OCTET_STRING_45,
+    pub pdu_session_resource_modify_confirm_transfer:
+        PDUSessionResourceModifyItemModCfmPDUSessionResourceModifyConfirmTransfer,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceModifyItemModCfmIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceModifyItemModInd {
     pub pdu_session_id: PDUSessionID,
-    pub pdu_session_resource_modify_indication_transfer: OCTET_STRING_46,
+    pub pdu_session_resource_modify_indication_transfer:
+        PDUSessionResourceModifyItemModIndPDUSessionResourceModifyIndicationTransfer,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceModifyItemModIndIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct PDUSessionResourceModifyItemModReq {
     pub pdu_session_id: PDUSessionID,
     #[asn(optional_idx = 0)]
     pub nas_pdu: Option<NAS_PDU>,
-    pub pdu_session_resource_modify_request_transfer: OCTET_STRING_47,
+    pub pdu_session_resource_modify_request_transfer:
+        PDUSessionResourceModifyItemModReqPDUSessionResourceModifyRequestTransfer,
     #[asn(optional_idx = 1)]
     pub ie_extensions: Option<PDUSessionResourceModifyItemModReqIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceModifyItemModRes {
     pub pdu_session_id: PDUSessionID,
-    pub pdu_session_resource_modify_response_transfer: OCTET_STRING_48,
+    pub pdu_session_resource_modify_response_transfer:
+        PDUSessionResourceModifyItemModResPDUSessionResourceModifyResponseTransfer,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceModifyItemModResIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3812,7 +4637,7 @@ pub struct PDUSessionResourceModifyItemModRes {
 )]
 pub struct PDUSessionResourceModifyListModCfm(pub Vec<PDUSessionResourceModifyItemModCfm>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3821,7 +4646,7 @@ pub struct PDUSessionResourceModifyListModCfm(pub Vec<PDUSessionResourceModifyIt
 )]
 pub struct PDUSessionResourceModifyListModInd(pub Vec<PDUSessionResourceModifyItemModInd>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3830,7 +4655,7 @@ pub struct PDUSessionResourceModifyListModInd(pub Vec<PDUSessionResourceModifyIt
 )]
 pub struct PDUSessionResourceModifyListModReq(pub Vec<PDUSessionResourceModifyItemModReq>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3839,25 +4664,25 @@ pub struct PDUSessionResourceModifyListModReq(pub Vec<PDUSessionResourceModifyIt
 )]
 pub struct PDUSessionResourceModifyListModRes(pub Vec<PDUSessionResourceModifyItemModRes>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PDUSessionResourceModifyRequest {
     pub protocol_i_es: PDUSessionResourceModifyRequestProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PDUSessionResourceModifyRequestTransfer {
     pub protocol_i_es: PDUSessionResourceModifyRequestTransferProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PDUSessionResourceModifyResponse {
     pub protocol_i_es: PDUSessionResourceModifyResponseProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 6)]
 pub struct PDUSessionResourceModifyResponseTransfer {
     #[asn(optional_idx = 0)]
@@ -3874,7 +4699,7 @@ pub struct PDUSessionResourceModifyResponseTransfer {
     pub ie_extensions: Option<PDUSessionResourceModifyResponseTransferIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct PDUSessionResourceModifyUnsuccessfulTransfer {
     pub cause: Cause,
@@ -3884,22 +4709,23 @@ pub struct PDUSessionResourceModifyUnsuccessfulTransfer {
     pub ie_extensions: Option<PDUSessionResourceModifyUnsuccessfulTransferIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PDUSessionResourceNotify {
     pub protocol_i_es: PDUSessionResourceNotifyProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceNotifyItem {
     pub pdu_session_id: PDUSessionID,
-    pub pdu_session_resource_notify_transfer: OCTET_STRING_49,
+    pub pdu_session_resource_notify_transfer:
+        PDUSessionResourceNotifyItemPDUSessionResourceNotifyTransfer,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceNotifyItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3908,7 +4734,7 @@ pub struct PDUSessionResourceNotifyItem {
 )]
 pub struct PDUSessionResourceNotifyList(pub Vec<PDUSessionResourceNotifyItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceNotifyReleasedTransfer {
     pub cause: Cause,
@@ -3916,7 +4742,7 @@ pub struct PDUSessionResourceNotifyReleasedTransfer {
     pub ie_extensions: Option<PDUSessionResourceNotifyReleasedTransferIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct PDUSessionResourceNotifyTransfer {
     #[asn(optional_idx = 0)]
@@ -3927,13 +4753,13 @@ pub struct PDUSessionResourceNotifyTransfer {
     pub ie_extensions: Option<PDUSessionResourceNotifyTransferIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PDUSessionResourceReleaseCommand {
     pub protocol_i_es: PDUSessionResourceReleaseCommandProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceReleaseCommandTransfer {
     pub cause: Cause,
@@ -3941,56 +4767,60 @@ pub struct PDUSessionResourceReleaseCommandTransfer {
     pub ie_extensions: Option<PDUSessionResourceReleaseCommandTransferIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PDUSessionResourceReleaseResponse {
     pub protocol_i_es: PDUSessionResourceReleaseResponseProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceReleaseResponseTransfer {
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceReleaseResponseTransferIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceReleasedItemNot {
     pub pdu_session_id: PDUSessionID,
-    pub pdu_session_resource_notify_released_transfer: OCTET_STRING_50,
+    pub pdu_session_resource_notify_released_transfer:
+        PDUSessionResourceReleasedItemNotPDUSessionResourceNotifyReleasedTransfer,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceReleasedItemNotIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceReleasedItemPSAck {
     pub pdu_session_id: PDUSessionID,
-    pub path_switch_request_unsuccessful_transfer: OCTET_STRING_51,
+    pub path_switch_request_unsuccessful_transfer:
+        PDUSessionResourceReleasedItemPSAckPathSwitchRequestUnsuccessfulTransfer,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceReleasedItemPSAckIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceReleasedItemPSFail {
     pub pdu_session_id: PDUSessionID,
-    pub path_switch_request_unsuccessful_transfer: OCTET_STRING_52,
+    pub path_switch_request_unsuccessful_transfer:
+        PDUSessionResourceReleasedItemPSFailPathSwitchRequestUnsuccessfulTransfer,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceReleasedItemPSFailIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceReleasedItemRelRes {
     pub pdu_session_id: PDUSessionID,
-    pub pdu_session_resource_release_response_transfer: OCTET_STRING_53,
+    pub pdu_session_resource_release_response_transfer:
+        PDUSessionResourceReleasedItemRelResPDUSessionResourceReleaseResponseTransfer,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceReleasedItemRelResIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3999,7 +4829,7 @@ pub struct PDUSessionResourceReleasedItemRelRes {
 )]
 pub struct PDUSessionResourceReleasedListNot(pub Vec<PDUSessionResourceReleasedItemNot>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4008,7 +4838,7 @@ pub struct PDUSessionResourceReleasedListNot(pub Vec<PDUSessionResourceReleasedI
 )]
 pub struct PDUSessionResourceReleasedListPSAck(pub Vec<PDUSessionResourceReleasedItemPSAck>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4017,7 +4847,7 @@ pub struct PDUSessionResourceReleasedListPSAck(pub Vec<PDUSessionResourceRelease
 )]
 pub struct PDUSessionResourceReleasedListPSFail(pub Vec<PDUSessionResourceReleasedItemPSFail>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4026,25 +4856,27 @@ pub struct PDUSessionResourceReleasedListPSFail(pub Vec<PDUSessionResourceReleas
 )]
 pub struct PDUSessionResourceReleasedListRelRes(pub Vec<PDUSessionResourceReleasedItemRelRes>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceResumeItemRESReq {
     pub pdu_session_id: PDUSessionID,
-    pub ue_context_resume_request_transfer: OCTET_STRING_54,
+    pub ue_context_resume_request_transfer:
+        PDUSessionResourceResumeItemRESReqUEContextResumeRequestTransfer,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceResumeItemRESReqIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceResumeItemRESRes {
     pub pdu_session_id: PDUSessionID,
-    pub ue_context_resume_response_transfer: OCTET_STRING_55,
+    pub ue_context_resume_response_transfer:
+        PDUSessionResourceResumeItemRESResUEContextResumeResponseTransfer,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceResumeItemRESResIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4053,7 +4885,7 @@ pub struct PDUSessionResourceResumeItemRESRes {
 )]
 pub struct PDUSessionResourceResumeListRESReq(pub Vec<PDUSessionResourceResumeItemRESReq>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4062,16 +4894,17 @@ pub struct PDUSessionResourceResumeListRESReq(pub Vec<PDUSessionResourceResumeIt
 )]
 pub struct PDUSessionResourceResumeListRESRes(pub Vec<PDUSessionResourceResumeItemRESRes>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceSecondaryRATUsageItem {
     pub pdu_session_id: PDUSessionID,
-    pub secondary_rat_data_usage_report_transfer: OCTET_STRING_56,
+    pub secondary_rat_data_usage_report_transfer:
+        PDUSessionResourceSecondaryRATUsageItemSecondaryRATDataUsageReportTransfer,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceSecondaryRATUsageItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4082,59 +4915,63 @@ pub struct PDUSessionResourceSecondaryRATUsageList(
     pub Vec<PDUSessionResourceSecondaryRATUsageItem>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct PDUSessionResourceSetupItemCxtReq {
     pub pdu_session_id: PDUSessionID,
     #[asn(optional_idx = 0)]
     pub nas_pdu: Option<NAS_PDU>,
     pub s_nssai: S_NSSAI,
-    pub pdu_session_resource_setup_request_transfer: OCTET_STRING_57,
+    pub pdu_session_resource_setup_request_transfer:
+        PDUSessionResourceSetupItemCxtReqPDUSessionResourceSetupRequestTransfer,
     #[asn(optional_idx = 1)]
     pub ie_extensions: Option<PDUSessionResourceSetupItemCxtReqIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceSetupItemCxtRes {
     pub pdu_session_id: PDUSessionID,
-    pub pdu_session_resource_setup_response_transfer: OCTET_STRING_58,
+    pub pdu_session_resource_setup_response_transfer:
+        PDUSessionResourceSetupItemCxtResPDUSessionResourceSetupResponseTransfer,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceSetupItemCxtResIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceSetupItemHOReq {
     pub pdu_session_id: PDUSessionID,
     pub s_nssai: S_NSSAI,
-    pub handover_request_transfer: OCTET_STRING_59,
+    pub handover_request_transfer: PDUSessionResourceSetupItemHOReqHandoverRequestTransfer,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceSetupItemHOReqIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct PDUSessionResourceSetupItemSUReq {
     pub pdu_session_id: PDUSessionID,
     #[asn(optional_idx = 0)]
     pub pdu_session_nas_pdu: Option<NAS_PDU>,
     pub s_nssai: S_NSSAI,
-    pub pdu_session_resource_setup_request_transfer: OCTET_STRING_60,
+    pub pdu_session_resource_setup_request_transfer:
+        PDUSessionResourceSetupItemSUReqPDUSessionResourceSetupRequestTransfer,
     #[asn(optional_idx = 1)]
     pub ie_extensions: Option<PDUSessionResourceSetupItemSUReqIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceSetupItemSURes {
     pub pdu_session_id: PDUSessionID,
-    pub pdu_session_resource_setup_response_transfer: OCTET_STRING_61,
+    pub pdu_session_resource_setup_response_transfer:
+        PDUSessionResourceSetupItemSUResPDUSessionResourceSetupResponseTransfer,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceSetupItemSUResIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4143,7 +4980,7 @@ pub struct PDUSessionResourceSetupItemSURes {
 )]
 pub struct PDUSessionResourceSetupListCxtReq(pub Vec<PDUSessionResourceSetupItemCxtReq>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4152,7 +4989,7 @@ pub struct PDUSessionResourceSetupListCxtReq(pub Vec<PDUSessionResourceSetupItem
 )]
 pub struct PDUSessionResourceSetupListCxtRes(pub Vec<PDUSessionResourceSetupItemCxtRes>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4161,7 +4998,7 @@ pub struct PDUSessionResourceSetupListCxtRes(pub Vec<PDUSessionResourceSetupItem
 )]
 pub struct PDUSessionResourceSetupListHOReq(pub Vec<PDUSessionResourceSetupItemHOReq>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4170,7 +5007,7 @@ pub struct PDUSessionResourceSetupListHOReq(pub Vec<PDUSessionResourceSetupItemH
 )]
 pub struct PDUSessionResourceSetupListSUReq(pub Vec<PDUSessionResourceSetupItemSUReq>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4179,25 +5016,25 @@ pub struct PDUSessionResourceSetupListSUReq(pub Vec<PDUSessionResourceSetupItemS
 )]
 pub struct PDUSessionResourceSetupListSURes(pub Vec<PDUSessionResourceSetupItemSURes>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PDUSessionResourceSetupRequest {
     pub protocol_i_es: PDUSessionResourceSetupRequestProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PDUSessionResourceSetupRequestTransfer {
     pub protocol_i_es: PDUSessionResourceSetupRequestTransferProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PDUSessionResourceSetupResponse {
     pub protocol_i_es: PDUSessionResourceSetupResponseProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 4)]
 pub struct PDUSessionResourceSetupResponseTransfer {
     pub dl_qos_flow_per_tnl_information: QosFlowPerTNLInformation,
@@ -4211,7 +5048,7 @@ pub struct PDUSessionResourceSetupResponseTransfer {
     pub ie_extensions: Option<PDUSessionResourceSetupResponseTransferIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct PDUSessionResourceSetupUnsuccessfulTransfer {
     pub cause: Cause,
@@ -4221,16 +5058,17 @@ pub struct PDUSessionResourceSetupUnsuccessfulTransfer {
     pub ie_extensions: Option<PDUSessionResourceSetupUnsuccessfulTransferIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceSuspendItemSUSReq {
     pub pdu_session_id: PDUSessionID,
-    pub ue_context_suspend_request_transfer: OCTET_STRING_62,
+    pub ue_context_suspend_request_transfer:
+        PDUSessionResourceSuspendItemSUSReqUEContextSuspendRequestTransfer,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceSuspendItemSUSReqIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4239,16 +5077,17 @@ pub struct PDUSessionResourceSuspendItemSUSReq {
 )]
 pub struct PDUSessionResourceSuspendListSUSReq(pub Vec<PDUSessionResourceSuspendItemSUSReq>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceSwitchedItem {
     pub pdu_session_id: PDUSessionID,
-    pub path_switch_request_acknowledge_transfer: OCTET_STRING_63,
+    pub path_switch_request_acknowledge_transfer:
+        PDUSessionResourceSwitchedItemPathSwitchRequestAcknowledgeTransfer,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceSwitchedItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4257,16 +5096,16 @@ pub struct PDUSessionResourceSwitchedItem {
 )]
 pub struct PDUSessionResourceSwitchedList(pub Vec<PDUSessionResourceSwitchedItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceToBeSwitchedDLItem {
     pub pdu_session_id: PDUSessionID,
-    pub path_switch_request_transfer: OCTET_STRING_64,
+    pub path_switch_request_transfer: PDUSessionResourceToBeSwitchedDLItemPathSwitchRequestTransfer,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceToBeSwitchedDLItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4275,25 +5114,27 @@ pub struct PDUSessionResourceToBeSwitchedDLItem {
 )]
 pub struct PDUSessionResourceToBeSwitchedDLList(pub Vec<PDUSessionResourceToBeSwitchedDLItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceToReleaseItemHOCmd {
     pub pdu_session_id: PDUSessionID,
-    pub handover_preparation_unsuccessful_transfer: OCTET_STRING_65,
+    pub handover_preparation_unsuccessful_transfer:
+        PDUSessionResourceToReleaseItemHOCmdHandoverPreparationUnsuccessfulTransfer,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceToReleaseItemHOCmdIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceToReleaseItemRelCmd {
     pub pdu_session_id: PDUSessionID,
-    pub pdu_session_resource_release_command_transfer: OCTET_STRING_66,
+    pub pdu_session_resource_release_command_transfer:
+        PDUSessionResourceToReleaseItemRelCmdPDUSessionResourceReleaseCommandTransfer,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceToReleaseItemRelCmdIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4302,7 +5143,7 @@ pub struct PDUSessionResourceToReleaseItemRelCmd {
 )]
 pub struct PDUSessionResourceToReleaseListHOCmd(pub Vec<PDUSessionResourceToReleaseItemHOCmd>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4311,7 +5152,7 @@ pub struct PDUSessionResourceToReleaseListHOCmd(pub Vec<PDUSessionResourceToRele
 )]
 pub struct PDUSessionResourceToReleaseListRelCmd(pub Vec<PDUSessionResourceToReleaseItemRelCmd>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "4")]
 pub struct PDUSessionType(pub u8);
 impl PDUSessionType {
@@ -4322,20 +5163,20 @@ impl PDUSessionType {
     pub const UNSTRUCTURED: u8 = 4u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionUsageReport {
-    pub rat_type: ENUMERATED_67,
+    pub rat_type: PDUSessionUsageReportRATType,
     pub pdu_session_timed_report_list: VolumeTimedReportList,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionUsageReportIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "3", sz_ub = "3")]
 pub struct PLMNIdentity(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PLMNSupportItem {
     pub plmn_identity: PLMNIdentity,
@@ -4344,11 +5185,11 @@ pub struct PLMNSupportItem {
     pub ie_extensions: Option<PLMNSupportItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "12")]
 pub struct PLMNSupportList(pub Vec<PLMNSupportItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PNI_NPN_MobilityInformation {
     pub allowed_pni_npi_list: Allowed_PNI_NPN_List,
@@ -4356,19 +5197,19 @@ pub struct PNI_NPN_MobilityInformation {
     pub ie_extensions: Option<PNI_NPN_MobilityInformationIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PWSCancelRequest {
     pub protocol_i_es: PWSCancelRequestProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PWSCancelResponse {
     pub protocol_i_es: PWSCancelResponseProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum PWSFailedCellIDList {
     #[asn(key = 0, extended = false)]
@@ -4376,45 +5217,45 @@ pub enum PWSFailedCellIDList {
     #[asn(key = 1, extended = false)]
     NR_CGI_PWSFailedList(NR_CGIList),
     #[asn(key = 2, extended = false)]
-    Choice_Extensions(PWSFailedCellIDListchoice_Extensions),
+    Choice_Extensions(PWSFailedCellIDList_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PWSFailureIndication {
     pub protocol_i_es: PWSFailureIndicationProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PWSRestartIndication {
     pub protocol_i_es: PWSRestartIndicationProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "1023", extensible = true)]
 pub struct PacketDelayBudget(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PacketErrorRate {
-    pub per_scalar: INTEGER_68,
-    pub per_exponent: INTEGER_69,
+    pub per_scalar: PacketErrorRatePERScalar,
+    pub per_exponent: PacketErrorRatePERExponent,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<PacketErrorRateIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "1000", extensible = true)]
 pub struct PacketLossRate(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct Paging {
     pub protocol_i_es: PagingProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "15")]
 pub struct Paging_Time_Window(pub u8);
 impl Paging_Time_Window {
@@ -4436,7 +5277,7 @@ impl Paging_Time_Window {
     pub const S16: u8 = 15u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "13")]
 pub struct Paging_eDRX_Cycle(pub u8);
 impl Paging_eDRX_Cycle {
@@ -4456,7 +5297,7 @@ impl Paging_eDRX_Cycle {
     pub const HF256: u8 = 13u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PagingAssisDataforCEcapabUE {
     pub eutra_cgi: EUTRA_CGI,
@@ -4465,11 +5306,11 @@ pub struct PagingAssisDataforCEcapabUE {
     pub ie_extensions: Option<PagingAssisDataforCEcapabUEIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "1", ub = "16", extensible = true)]
 pub struct PagingAttemptCount(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct PagingAttemptInformation {
     pub paging_attempt_count: PagingAttemptCount,
@@ -4480,7 +5321,7 @@ pub struct PagingAttemptInformation {
     pub ie_extensions: Option<PagingAttemptInformationIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "3")]
 pub struct PagingDRX(pub u8);
 impl PagingDRX {
@@ -4490,14 +5331,14 @@ impl PagingDRX {
     pub const V256: u8 = 3u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct PagingOrigin(pub u8);
 impl PagingOrigin {
     pub const NON_3GPP: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "7")]
 pub struct PagingPriority(pub u8);
 impl PagingPriority {
@@ -4511,7 +5352,7 @@ impl PagingPriority {
     pub const PRIOLEVEL8: u8 = 7u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "20")]
 pub struct PagingProbabilityInformation(pub u8);
 impl PagingProbabilityInformation {
@@ -4538,7 +5379,7 @@ impl PagingProbabilityInformation {
     pub const P100: u8 = 20u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct PagingeDRXInformation {
     pub paging_e_drx_cycle: Paging_eDRX_Cycle,
@@ -4548,19 +5389,19 @@ pub struct PagingeDRXInformation {
     pub ie_extensions: Option<PagingeDRXInformationIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PathSwitchRequest {
     pub protocol_i_es: PathSwitchRequestProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PathSwitchRequestAcknowledge {
     pub protocol_i_es: PathSwitchRequestAcknowledgeProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct PathSwitchRequestAcknowledgeTransfer {
     #[asn(optional_idx = 0)]
@@ -4571,13 +5412,13 @@ pub struct PathSwitchRequestAcknowledgeTransfer {
     pub ie_extensions: Option<PathSwitchRequestAcknowledgeTransferIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PathSwitchRequestFailure {
     pub protocol_i_es: PathSwitchRequestFailureProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PathSwitchRequestSetupFailedTransfer {
     pub cause: Cause,
@@ -4585,7 +5426,7 @@ pub struct PathSwitchRequestSetupFailedTransfer {
     pub ie_extensions: Option<PathSwitchRequestSetupFailedTransferIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct PathSwitchRequestTransfer {
     pub dl_ngu_up_tnl_information: UPTransportLayerInformation,
@@ -4598,7 +5439,7 @@ pub struct PathSwitchRequestTransfer {
     pub ie_extensions: Option<PathSwitchRequestTransferIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PathSwitchRequestUnsuccessfulTransfer {
     pub cause: Cause,
@@ -4606,7 +5447,7 @@ pub struct PathSwitchRequestUnsuccessfulTransfer {
     pub ie_extensions: Option<PathSwitchRequestUnsuccessfulTransferIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct PedestrianUE(pub u8);
 impl PedestrianUE {
@@ -4614,19 +5455,19 @@ impl PedestrianUE {
     pub const NOT_AUTHORIZED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "8", sz_ub = "8")]
-pub struct PeriodicRegistrationUpdateTimer(pub BitVec<u8, Msb0>);
+pub struct PeriodicRegistrationUpdateTimer(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "640000", extensible = true)]
 pub struct Periodicity(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "2", sz_ub = "2")]
 pub struct PortNumber(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct Pre_emptionCapability(pub u8);
 impl Pre_emptionCapability {
@@ -4634,7 +5475,7 @@ impl Pre_emptionCapability {
     pub const MAY_TRIGGER_PRE_EMPTION: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct Pre_emptionVulnerability(pub u8);
 impl Pre_emptionVulnerability {
@@ -4642,7 +5483,7 @@ impl Pre_emptionVulnerability {
     pub const PRE_EMPTABLE: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "2")]
 pub struct Presence(pub u8);
 impl Presence {
@@ -4651,15 +5492,15 @@ impl Presence {
     pub const MANDATORY: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "1", ub = "15")]
 pub struct PriorityLevelARP(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "1", ub = "127", extensible = true)]
 pub struct PriorityLevelQos(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct PrivacyIndicator(pub u8);
 impl PrivacyIndicator {
@@ -4667,57 +5508,57 @@ impl PrivacyIndicator {
     pub const LOGGED_MDT: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = false)]
 pub enum PrivateIE_ID {
     #[asn(key = 0, extended = false)]
-    Local(INTEGER_70),
+    Local(PrivateIE_ID_local),
     #[asn(key = 1, extended = false)]
-    Global(OBJECT_IDENTIFIER_71),
+    Global(PrivateIE_ID_global),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PrivateMessage {
     pub private_i_es: PrivateMessagePrivateIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "255")]
 pub struct ProcedureCode(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = false)]
 pub enum ProcedureStageChoice {
     #[asn(key = 0, extended = false)]
     First_dl_count(FirstDLCount),
     #[asn(key = 1, extended = false)]
-    Choice_Extensions(ProcedureStageChoicechoice_Extensions),
+    Choice_Extensions(ProcedureStageChoice_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "65535")]
 pub struct ProtocolExtensionID(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "65535")]
 pub struct ProtocolIE_ID(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct QoSFlowsUsageReport_Item {
     pub qos_flow_identifier: QosFlowIdentifier,
-    pub rat_type: ENUMERATED_72,
+    pub rat_type: QoSFlowsUsageReport_ItemRATType,
     pub qo_s_flows_timed_report_list: VolumeTimedReportList,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<QoSFlowsUsageReport_ItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "64")]
 pub struct QoSFlowsUsageReportList(pub Vec<QoSFlowsUsageReport_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum QosCharacteristics {
     #[asn(key = 0, extended = false)]
@@ -4725,10 +5566,10 @@ pub enum QosCharacteristics {
     #[asn(key = 1, extended = false)]
     Dynamic5QI(Dynamic5QIDescriptor),
     #[asn(key = 2, extended = false)]
-    Choice_Extensions(QosCharacteristicschoice_Extensions),
+    Choice_Extensions(QosCharacteristics_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct QosFlowAcceptedItem {
     pub qos_flow_identifier: QosFlowIdentifier,
@@ -4736,11 +5577,11 @@ pub struct QosFlowAcceptedItem {
     pub ie_extensions: Option<QosFlowAcceptedItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "64")]
 pub struct QosFlowAcceptedList(pub Vec<QosFlowAcceptedItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct QosFlowAddOrModifyRequestItem {
     pub qos_flow_identifier: QosFlowIdentifier,
@@ -4752,11 +5593,11 @@ pub struct QosFlowAddOrModifyRequestItem {
     pub ie_extensions: Option<QosFlowAddOrModifyRequestItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "64")]
 pub struct QosFlowAddOrModifyRequestList(pub Vec<QosFlowAddOrModifyRequestItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct QosFlowAddOrModifyResponseItem {
     pub qos_flow_identifier: QosFlowIdentifier,
@@ -4764,11 +5605,11 @@ pub struct QosFlowAddOrModifyResponseItem {
     pub ie_extensions: Option<QosFlowAddOrModifyResponseItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "64")]
 pub struct QosFlowAddOrModifyResponseList(pub Vec<QosFlowAddOrModifyResponseItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 4)]
 pub struct QosFlowFeedbackItem {
     pub qos_flow_identifier: QosFlowIdentifier,
@@ -4782,15 +5623,15 @@ pub struct QosFlowFeedbackItem {
     pub ie_extensions: Option<QosFlowFeedbackItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "64")]
 pub struct QosFlowFeedbackList(pub Vec<QosFlowFeedbackItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "63", extensible = true)]
 pub struct QosFlowIdentifier(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct QosFlowInformationItem {
     pub qos_flow_identifier: QosFlowIdentifier,
@@ -4800,11 +5641,11 @@ pub struct QosFlowInformationItem {
     pub ie_extensions: Option<QosFlowInformationItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "64")]
 pub struct QosFlowInformationList(pub Vec<QosFlowInformationItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct QosFlowItemWithDataForwarding {
     pub qos_flow_identifier: QosFlowIdentifier,
@@ -4814,7 +5655,7 @@ pub struct QosFlowItemWithDataForwarding {
     pub ie_extensions: Option<QosFlowItemWithDataForwardingIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 4)]
 pub struct QosFlowLevelQosParameters {
     pub qos_characteristics: QosCharacteristics,
@@ -4829,15 +5670,15 @@ pub struct QosFlowLevelQosParameters {
     pub ie_extensions: Option<QosFlowLevelQosParametersIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "64")]
 pub struct QosFlowListWithCause(pub Vec<QosFlowWithCauseItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "64")]
 pub struct QosFlowListWithDataForwarding(pub Vec<QosFlowItemWithDataForwarding>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct QosFlowModifyConfirmItem {
     pub qos_flow_identifier: QosFlowIdentifier,
@@ -4845,11 +5686,11 @@ pub struct QosFlowModifyConfirmItem {
     pub ie_extensions: Option<QosFlowModifyConfirmItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "64")]
 pub struct QosFlowModifyConfirmList(pub Vec<QosFlowModifyConfirmItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct QosFlowNotifyItem {
     pub qos_flow_identifier: QosFlowIdentifier,
@@ -4858,11 +5699,11 @@ pub struct QosFlowNotifyItem {
     pub ie_extensions: Option<QosFlowNotifyItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "64")]
 pub struct QosFlowNotifyList(pub Vec<QosFlowNotifyItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct QosFlowParametersItem {
     pub qos_flow_identifier: QosFlowIdentifier,
@@ -4872,11 +5713,11 @@ pub struct QosFlowParametersItem {
     pub ie_extensions: Option<QosFlowParametersItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "64")]
 pub struct QosFlowParametersList(pub Vec<QosFlowParametersItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct QosFlowPerTNLInformation {
     pub up_transport_layer_information: UPTransportLayerInformation,
@@ -4885,7 +5726,7 @@ pub struct QosFlowPerTNLInformation {
     pub ie_extensions: Option<QosFlowPerTNLInformationIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct QosFlowPerTNLInformationItem {
     pub qos_flow_per_tnl_information: QosFlowPerTNLInformation,
@@ -4893,11 +5734,11 @@ pub struct QosFlowPerTNLInformationItem {
     pub ie_extensions: Option<QosFlowPerTNLInformationItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "3")]
 pub struct QosFlowPerTNLInformationList(pub Vec<QosFlowPerTNLInformationItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct QosFlowSetupRequestItem {
     pub qos_flow_identifier: QosFlowIdentifier,
@@ -4908,11 +5749,11 @@ pub struct QosFlowSetupRequestItem {
     pub ie_extensions: Option<QosFlowSetupRequestItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "64")]
 pub struct QosFlowSetupRequestList(pub Vec<QosFlowSetupRequestItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct QosFlowToBeForwardedItem {
     pub qos_flow_identifier: QosFlowIdentifier,
@@ -4920,11 +5761,11 @@ pub struct QosFlowToBeForwardedItem {
     pub ie_extensions: Option<QosFlowToBeForwardedItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "64")]
 pub struct QosFlowToBeForwardedList(pub Vec<QosFlowToBeForwardedItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct QosFlowWithCauseItem {
     pub qos_flow_identifier: QosFlowIdentifier,
@@ -4933,48 +5774,49 @@ pub struct QosFlowWithCauseItem {
     pub ie_extensions: Option<QosFlowWithCauseItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "1", ub = "1800", extensible = true)]
 pub struct QosMonitoringReportingFrequency(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct QosMonitoringRequest(pub u8);
 impl QosMonitoringRequest {
     pub const UL: u8 = 0u8;
     pub const DL: u8 = 1u8;
     pub const BOTH: u8 = 2u8;
+    pub const STOP: u8 = 3u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "4294967295")]
 pub struct RAN_UE_NGAP_ID(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct RANCPRelocationIndication {
     pub protocol_i_es: RANCPRelocationIndicationProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct RANConfigurationUpdate {
     pub protocol_i_es: RANConfigurationUpdateProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct RANConfigurationUpdateAcknowledge {
     pub protocol_i_es: RANConfigurationUpdateAcknowledgeProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct RANConfigurationUpdateFailure {
     pub protocol_i_es: RANConfigurationUpdateFailureProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "PrintableString",
     sz_extensible = true,
@@ -4983,11 +5825,11 @@ pub struct RANConfigurationUpdateFailure {
 )]
 pub struct RANNodeName(pub String);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "UTF8String", sz_extensible = true, sz_lb = "1", sz_ub = "150")]
 pub struct RANNodeNameUTF8String(pub String);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "VisibleString",
     sz_extensible = true,
@@ -4996,11 +5838,11 @@ pub struct RANNodeNameUTF8String(pub String);
 )]
 pub struct RANNodeNameVisibleString(pub String);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "1", ub = "256")]
 pub struct RANPagingPriority(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RANStatusTransfer_TransparentContainer {
     pub dr_bs_subject_to_status_transfer_list: DRBsSubjectToStatusTransferList,
@@ -5008,7 +5850,7 @@ pub struct RANStatusTransfer_TransparentContainer {
     pub ie_extensions: Option<RANStatusTransfer_TransparentContainerIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct RAT_Information(pub u8);
 impl RAT_Information {
@@ -5016,15 +5858,15 @@ impl RAT_Information {
     pub const NB_IO_T: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = true, sz_lb = "8", sz_ub = "8")]
-pub struct RATRestrictionInformation(pub BitVec<u8, Msb0>);
+pub struct RATRestrictionInformation(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct RATRestrictions(pub Vec<RATRestrictions_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RATRestrictions_Item {
     pub plmn_identity: PLMNIdentity,
@@ -5033,20 +5875,20 @@ pub struct RATRestrictions_Item {
     pub ie_extensions: Option<RATRestrictions_ItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
 pub struct RGLevelWirelineAccessCharacteristics(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RIMInformation {
     pub targetg_nb_set_id: GNBSetID,
-    pub rim_rs_detection: ENUMERATED_73,
+    pub rim_rs_detection: RIMInformationRIM_RSDetection,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<RIMInformationIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RIMInformationTransfer {
     pub target_ran_node_id: TargetRANNodeID,
@@ -5056,15 +5898,15 @@ pub struct RIMInformationTransfer {
     pub ie_extensions: Option<RIMInformationTransferIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "4095")]
 pub struct RNC_ID(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
 pub struct RRCContainer(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "9")]
 pub struct RRCEstablishmentCause(pub u8);
 impl RRCEstablishmentCause {
@@ -5078,15 +5920,17 @@ impl RRCEstablishmentCause {
     pub const MO_SMS: u8 = 7u8;
     pub const MPS_PRIORITY_ACCESS: u8 = 8u8;
     pub const MCS_PRIORITY_ACCESS: u8 = 9u8;
+    pub const NOT_AVAILABLE: u8 = 10u8;
+    pub const MO_EXCEPTION_DATA: u8 = 11u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct RRCInactiveTransitionReport {
     pub protocol_i_es: RRCInactiveTransitionReportProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct RRCInactiveTransitionReportRequest(pub u8);
 impl RRCInactiveTransitionReportRequest {
@@ -5095,7 +5939,7 @@ impl RRCInactiveTransitionReportRequest {
     pub const CANCEL_REPORT: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct RRCState(pub u8);
 impl RRCState {
@@ -5103,7 +5947,7 @@ impl RRCState {
     pub const CONNECTED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct RSN(pub u8);
 impl RSN {
@@ -5111,7 +5955,7 @@ impl RSN {
     pub const V2: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "8")]
 pub struct Range(pub u8);
 impl Range {
@@ -5126,21 +5970,21 @@ impl Range {
     pub const M1000: u8 = 8u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct RecommendedCellItem {
     pub ngran_cgi: NGRAN_CGI,
     #[asn(optional_idx = 0)]
-    pub time_stayed_in_cell: Option<INTEGER_74>,
+    pub time_stayed_in_cell: Option<RecommendedCellItemTimeStayedInCell>,
     #[asn(optional_idx = 1)]
     pub ie_extensions: Option<RecommendedCellItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct RecommendedCellList(pub Vec<RecommendedCellItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RecommendedCellsForPaging {
     pub recommended_cell_list: RecommendedCellList,
@@ -5148,7 +5992,7 @@ pub struct RecommendedCellsForPaging {
     pub ie_extensions: Option<RecommendedCellsForPagingIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RecommendedRANNodeItem {
     pub amf_paging_target: AMFPagingTarget,
@@ -5156,11 +6000,11 @@ pub struct RecommendedRANNodeItem {
     pub ie_extensions: Option<RecommendedRANNodeItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct RecommendedRANNodeList(pub Vec<RecommendedRANNodeItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RecommendedRANNodesForPaging {
     pub recommended_ran_node_list: RecommendedRANNodeList,
@@ -5168,7 +6012,7 @@ pub struct RecommendedRANNodesForPaging {
     pub ie_extensions: Option<RecommendedRANNodesForPagingIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct RedirectionVoiceFallback(pub u8);
 impl RedirectionVoiceFallback {
@@ -5176,7 +6020,7 @@ impl RedirectionVoiceFallback {
     pub const NOT_POSSIBLE: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RedundantPDUSessionInformation {
     pub rsn: RSN,
@@ -5184,7 +6028,7 @@ pub struct RedundantPDUSessionInformation {
     pub ie_extensions: Option<RedundantPDUSessionInformationIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "1")]
 pub struct RedundantQosFlowIndicator(pub u8);
 impl RedundantQosFlowIndicator {
@@ -5192,14 +6036,14 @@ impl RedundantQosFlowIndicator {
     pub const FALSE: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct ReflectiveQosAttribute(pub u8);
 impl ReflectiveQosAttribute {
     pub const SUBJECT_TO: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "OCTET-STRING",
     sz_extensible = false,
@@ -5208,7 +6052,7 @@ impl ReflectiveQosAttribute {
 )]
 pub struct RejectedNSSAIinPLMN(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "OCTET-STRING",
     sz_extensible = false,
@@ -5217,15 +6061,15 @@ pub struct RejectedNSSAIinPLMN(pub Vec<u8>);
 )]
 pub struct RejectedNSSAIinTA(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "255")]
 pub struct RelativeAMFCapacity(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "131071")]
 pub struct RepetitionPeriod(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "7")]
 pub struct ReportAmountMDT(pub u8);
 impl ReportAmountMDT {
@@ -5239,14 +6083,14 @@ impl ReportAmountMDT {
     pub const RINFINITY: u8 = 7u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct ReportArea(pub u8);
 impl ReportArea {
     pub const CELL: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "12")]
 pub struct ReportIntervalMDT(pub u8);
 impl ReportIntervalMDT {
@@ -5265,20 +6109,20 @@ impl ReportIntervalMDT {
     pub const MIN60: u8 = 12u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct RerouteNASRequest {
     pub protocol_i_es: RerouteNASRequestProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct ResetAll(pub u8);
 impl ResetAll {
     pub const RESET_ALL: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum ResetType {
     #[asn(key = 0, extended = false)]
@@ -5286,20 +6130,20 @@ pub enum ResetType {
     #[asn(key = 1, extended = false)]
     PartOfNG_Interface(UE_associatedLogicalNG_connectionList),
     #[asn(key = 2, extended = false)]
-    Choice_Extensions(ResetTypechoice_Extensions),
+    Choice_Extensions(ResetType_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct RetrieveUEInformation {
     pub protocol_i_es: RetrieveUEInformationProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
 pub struct RoutingID(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct S_NSSAI {
     pub sst: SST,
@@ -5309,15 +6153,15 @@ pub struct S_NSSAI {
     pub ie_extensions: Option<S_NSSAIIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "2")]
 pub struct SCTP_TLAs(pub Vec<TransportLayerAddress>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "3", sz_ub = "3")]
 pub struct SD(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct SNPN_MobilityInformation {
     pub serving_nid: NID,
@@ -5325,7 +6169,7 @@ pub struct SNPN_MobilityInformation {
     pub ie_extensions: Option<SNPN_MobilityInformationIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct SONConfigurationTransfer {
     pub target_ran_node_id: TargetRANNodeID,
@@ -5337,7 +6181,7 @@ pub struct SONConfigurationTransfer {
     pub ie_extensions: Option<SONConfigurationTransferIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum SONInformation {
     #[asn(key = 0, extended = false)]
@@ -5345,10 +6189,10 @@ pub enum SONInformation {
     #[asn(key = 1, extended = false)]
     SONInformationReply(SONInformationReply),
     #[asn(key = 2, extended = false)]
-    Choice_Extensions(SONInformationchoice_Extensions),
+    Choice_Extensions(SONInformation_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct SONInformationReply {
     #[asn(optional_idx = 0)]
@@ -5357,7 +6201,7 @@ pub struct SONInformationReply {
     pub ie_extensions: Option<SONInformationReplyIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum SONInformationReport {
     #[asn(key = 0, extended = false)]
@@ -5365,17 +6209,17 @@ pub enum SONInformationReport {
     #[asn(key = 1, extended = false)]
     HOReportInformation(HOReport),
     #[asn(key = 2, extended = false)]
-    Choice_Extensions(SONInformationReportchoice_Extensions),
+    Choice_Extensions(SONInformationReport_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct SONInformationRequest(pub u8);
 impl SONInformationRequest {
     pub const XN_TNL_CONFIGURATION_INFO: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct SRVCCOperationPossible(pub u8);
 impl SRVCCOperationPossible {
@@ -5383,30 +6227,30 @@ impl SRVCCOperationPossible {
     pub const NOT_POSSIBLE: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "1", sz_ub = "1")]
 pub struct SST(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 4)]
 pub struct ScheduledCommunicationTime {
     #[asn(optional_idx = 0)]
-    pub dayof_week: Option<BIT_STRING_75>,
+    pub dayof_week: Option<ScheduledCommunicationTimeDayofWeek>,
     #[asn(optional_idx = 1)]
-    pub timeof_day_start: Option<INTEGER_76>,
+    pub timeof_day_start: Option<ScheduledCommunicationTimeTimeofDayStart>,
     #[asn(optional_idx = 2)]
-    pub timeof_day_end: Option<INTEGER_77>,
+    pub timeof_day_end: Option<ScheduledCommunicationTimeTimeofDayEnd>,
     #[asn(optional_idx = 3)]
     pub ie_extensions: Option<ScheduledCommunicationTimeIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct SecondaryRATDataUsageReport {
     pub protocol_i_es: SecondaryRATDataUsageReportProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct SecondaryRATDataUsageReportTransfer {
     #[asn(optional_idx = 0)]
@@ -5415,7 +6259,7 @@ pub struct SecondaryRATDataUsageReportTransfer {
     pub ie_extensions: Option<SecondaryRATDataUsageReportTransferIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct SecondaryRATUsageInformation {
     #[asn(optional_idx = 0)]
@@ -5426,7 +6270,7 @@ pub struct SecondaryRATUsageInformation {
     pub ie_extension: Option<SecondaryRATUsageInformationIE_Extension>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct SecurityContext {
     pub next_hop_chaining_count: NextHopChainingCount,
@@ -5435,7 +6279,7 @@ pub struct SecurityContext {
     pub ie_extensions: Option<SecurityContextIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct SecurityIndication {
     pub integrity_protection_indication: IntegrityProtectionIndication,
@@ -5446,16 +6290,16 @@ pub struct SecurityIndication {
     pub ie_extensions: Option<SecurityIndicationIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "BITSTRING",
     sz_extensible = false,
     sz_lb = "256",
     sz_ub = "256"
 )]
-pub struct SecurityKey(pub BitVec<u8, Msb0>);
+pub struct SecurityKey(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct SecurityResult {
     pub integrity_protection_result: IntegrityProtectionResult,
@@ -5464,14 +6308,14 @@ pub struct SecurityResult {
     pub ie_extensions: Option<SecurityResultIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct SensorMeasConfig(pub u8);
 impl SensorMeasConfig {
     pub const SETUP: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct SensorMeasConfigNameItem {
     pub sensor_name_config: SensorNameConfig,
@@ -5479,11 +6323,11 @@ pub struct SensorMeasConfigNameItem {
     pub ie_extensions: Option<SensorMeasConfigNameItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "3")]
 pub struct SensorMeasConfigNameList(pub Vec<SensorMeasConfigNameItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct SensorMeasurementConfiguration {
     pub sensor_meas_config: SensorMeasConfig,
@@ -5493,24 +6337,24 @@ pub struct SensorMeasurementConfiguration {
     pub ie_extensions: Option<SensorMeasurementConfigurationIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "3", extensible = false)]
 pub enum SensorNameConfig {
     #[asn(key = 0, extended = false)]
-    UncompensatedBarometricConfig(ENUMERATED_78),
+    UncompensatedBarometricConfig(SensorNameConfig_uncompensatedBarometricConfig),
     #[asn(key = 1, extended = false)]
-    UeSpeedConfig(ENUMERATED_79),
+    UeSpeedConfig(SensorNameConfig_ueSpeedConfig),
     #[asn(key = 2, extended = false)]
-    UeOrientationConfig(ENUMERATED_80),
+    UeOrientationConfig(SensorNameConfig_ueOrientationConfig),
     #[asn(key = 3, extended = false)]
-    Choice_Extensions(SensorNameConfigchoice_Extensions),
+    Choice_Extensions(SensorNameConfig_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "16", sz_ub = "16")]
-pub struct SerialNumber(pub BitVec<u8, Msb0>);
+pub struct SerialNumber(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct ServedGUAMIItem {
     pub guami: GUAMI,
@@ -5520,7 +6364,7 @@ pub struct ServedGUAMIItem {
     pub ie_extensions: Option<ServedGUAMIItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5529,11 +6373,11 @@ pub struct ServedGUAMIItem {
 )]
 pub struct ServedGUAMIList(pub Vec<ServedGUAMIItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct ServiceAreaInformation(pub Vec<ServiceAreaInformation_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct ServiceAreaInformation_Item {
     pub plmn_identity: PLMNIdentity,
@@ -5545,11 +6389,11 @@ pub struct ServiceAreaInformation_Item {
     pub ie_extensions: Option<ServiceAreaInformation_ItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "4294967295")]
 pub struct SgNB_UE_X2AP_ID(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct SliceOverloadItem {
     pub s_nssai: S_NSSAI,
@@ -5557,7 +6401,7 @@ pub struct SliceOverloadItem {
     pub ie_extensions: Option<SliceOverloadItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5566,7 +6410,7 @@ pub struct SliceOverloadItem {
 )]
 pub struct SliceOverloadList(pub Vec<SliceOverloadItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct SliceSupportItem {
     pub s_nssai: S_NSSAI,
@@ -5574,7 +6418,7 @@ pub struct SliceSupportItem {
     pub ie_extensions: Option<SliceSupportItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5583,7 +6427,7 @@ pub struct SliceSupportItem {
 )]
 pub struct SliceSupportList(pub Vec<SliceSupportItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 4)]
 pub struct SourceNGRANNode_ToTargetNGRANNode_TransparentContainer {
     pub rrc_container: RRCContainer,
@@ -5599,7 +6443,7 @@ pub struct SourceNGRANNode_ToTargetNGRANNode_TransparentContainer {
     pub ie_extensions: Option<SourceNGRANNode_ToTargetNGRANNode_TransparentContainerIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct SourceOfUEActivityBehaviourInformation(pub u8);
 impl SourceOfUEActivityBehaviourInformation {
@@ -5607,7 +6451,7 @@ impl SourceOfUEActivityBehaviourInformation {
     pub const STATISTICS: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct SourceRANNodeID {
     pub global_ran_node_id: GlobalRANNodeID,
@@ -5616,7 +6460,7 @@ pub struct SourceRANNodeID {
     pub ie_extensions: Option<SourceRANNodeIDIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 4)]
 pub struct SourceToTarget_AMFInformationReroute {
     #[asn(optional_idx = 0)]
@@ -5629,11 +6473,11 @@ pub struct SourceToTarget_AMFInformationReroute {
     pub ie_extensions: Option<SourceToTarget_AMFInformationRerouteIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
 pub struct SourceToTarget_TransparentContainer(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SuccessfulOutcome {
     #[asn(key_field = true)]
@@ -5642,7 +6486,7 @@ pub struct SuccessfulOutcome {
     pub value: SuccessfulOutcomeValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct SupportedTAItem {
     pub tac: TAC,
@@ -5651,7 +6495,7 @@ pub struct SupportedTAItem {
     pub ie_extensions: Option<SupportedTAItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5660,28 +6504,28 @@ pub struct SupportedTAItem {
 )]
 pub struct SupportedTAList(pub Vec<SupportedTAItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct Suspend_Request_Indication(pub u8);
 impl Suspend_Request_Indication {
     pub const SUSPEND_REQUESTED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct Suspend_Response_Indication(pub u8);
 impl Suspend_Response_Indication {
     pub const SUSPEND_INDICATED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct SuspendIndicator(pub u8);
 impl SuspendIndicator {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TABasedMDT {
     pub ta_listfor_mdt: TAListforMDT,
@@ -5689,11 +6533,11 @@ pub struct TABasedMDT {
     pub ie_extensions: Option<TABasedMDTIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "3", sz_ub = "3")]
 pub struct TAC(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TAI {
     pub plmn_identity: PLMNIdentity,
@@ -5702,7 +6546,7 @@ pub struct TAI {
     pub ie_extensions: Option<TAIIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TAIBasedMDT {
     pub tai_listfor_mdt: TAIListforMDT,
@@ -5710,7 +6554,7 @@ pub struct TAIBasedMDT {
     pub ie_extensions: Option<TAIBasedMDTIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5719,7 +6563,7 @@ pub struct TAIBasedMDT {
 )]
 pub struct TAIBroadcastEUTRA(pub Vec<TAIBroadcastEUTRA_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TAIBroadcastEUTRA_Item {
     pub tai: TAI,
@@ -5728,7 +6572,7 @@ pub struct TAIBroadcastEUTRA_Item {
     pub ie_extensions: Option<TAIBroadcastEUTRA_ItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5737,7 +6581,7 @@ pub struct TAIBroadcastEUTRA_Item {
 )]
 pub struct TAIBroadcastNR(pub Vec<TAIBroadcastNR_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TAIBroadcastNR_Item {
     pub tai: TAI,
@@ -5746,7 +6590,7 @@ pub struct TAIBroadcastNR_Item {
     pub ie_extensions: Option<TAIBroadcastNR_ItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5755,7 +6599,7 @@ pub struct TAIBroadcastNR_Item {
 )]
 pub struct TAICancelledEUTRA(pub Vec<TAICancelledEUTRA_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TAICancelledEUTRA_Item {
     pub tai: TAI,
@@ -5764,7 +6608,7 @@ pub struct TAICancelledEUTRA_Item {
     pub ie_extensions: Option<TAICancelledEUTRA_ItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5773,7 +6617,7 @@ pub struct TAICancelledEUTRA_Item {
 )]
 pub struct TAICancelledNR(pub Vec<TAICancelledNR_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TAICancelledNR_Item {
     pub tai: TAI,
@@ -5782,11 +6626,11 @@ pub struct TAICancelledNR_Item {
     pub ie_extensions: Option<TAICancelledNR_ItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct TAIListForInactive(pub Vec<TAIListForInactiveItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TAIListForInactiveItem {
     pub tai: TAI,
@@ -5794,11 +6638,11 @@ pub struct TAIListForInactiveItem {
     pub ie_extensions: Option<TAIListForInactiveItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct TAIListForPaging(pub Vec<TAIListForPagingItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TAIListForPagingItem {
     pub tai: TAI,
@@ -5806,7 +6650,7 @@ pub struct TAIListForPagingItem {
     pub ie_extensions: Option<TAIListForPagingItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5815,7 +6659,7 @@ pub struct TAIListForPagingItem {
 )]
 pub struct TAIListForRestart(pub Vec<TAI>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5824,32 +6668,32 @@ pub struct TAIListForRestart(pub Vec<TAI>);
 )]
 pub struct TAIListForWarning(pub Vec<TAI>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "8")]
 pub struct TAIListforMDT(pub Vec<TAI>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "8")]
 pub struct TAListforMDT(pub Vec<TAC>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
 pub struct TNAP_ID(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = false)]
 pub enum TNGF_ID {
     #[asn(key = 0, extended = false)]
-    TNGF_ID(BIT_STRING_81),
+    TNGF_ID(TNGF_ID_tNGF_ID),
     #[asn(key = 1, extended = false)]
-    Choice_Extensions(TNGF_IDchoice_Extensions),
+    Choice_Extensions(TNGF_ID_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "255")]
 pub struct TNLAddressWeightFactor(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TNLAssociationItem {
     pub tnl_association_address: CPTransportLayerInformation,
@@ -5858,11 +6702,11 @@ pub struct TNLAssociationItem {
     pub ie_extensions: Option<TNLAssociationItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "32")]
 pub struct TNLAssociationList(pub Vec<TNLAssociationItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct TNLAssociationUsage(pub u8);
 impl TNLAssociationUsage {
@@ -5871,7 +6715,7 @@ impl TNLAssociationUsage {
     pub const BOTH: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct TSCAssistanceInformation {
     pub periodicity: Periodicity,
@@ -5881,7 +6725,7 @@ pub struct TSCAssistanceInformation {
     pub ie_extensions: Option<TSCAssistanceInformationIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct TSCTrafficCharacteristics {
     #[asn(optional_idx = 0)]
@@ -5892,20 +6736,20 @@ pub struct TSCTrafficCharacteristics {
     pub ie_extensions: Option<TSCTrafficCharacteristicsIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
 pub struct TWAP_ID(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = false)]
 pub enum TWIF_ID {
     #[asn(key = 0, extended = false)]
-    TWIF_ID(BIT_STRING_82),
+    TWIF_ID(TWIF_ID_tWIF_ID),
     #[asn(key = 1, extended = false)]
-    Choice_Extensions(TWIF_IDchoice_Extensions),
+    Choice_Extensions(TWIF_ID_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum TargetID {
     #[asn(key = 0, extended = false)]
@@ -5913,10 +6757,10 @@ pub enum TargetID {
     #[asn(key = 1, extended = false)]
     TargeteNB_ID(TargeteNB_ID),
     #[asn(key = 2, extended = false)]
-    Choice_Extensions(TargetIDchoice_Extensions),
+    Choice_Extensions(TargetID_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TargetNGRANNode_ToSourceNGRANNode_FailureTransparentContainer {
     pub cell_cag_information: Cell_CAGInformation,
@@ -5925,7 +6769,7 @@ pub struct TargetNGRANNode_ToSourceNGRANNode_FailureTransparentContainer {
         Option<TargetNGRANNode_ToSourceNGRANNode_FailureTransparentContainerIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TargetNGRANNode_ToSourceNGRANNode_TransparentContainer {
     pub rrc_container: RRCContainer,
@@ -5933,7 +6777,7 @@ pub struct TargetNGRANNode_ToSourceNGRANNode_TransparentContainer {
     pub ie_extensions: Option<TargetNGRANNode_ToSourceNGRANNode_TransparentContainerIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TargetRANNodeID {
     pub global_ran_node_id: GlobalRANNodeID,
@@ -5942,7 +6786,7 @@ pub struct TargetRANNodeID {
     pub ie_extensions: Option<TargetRANNodeIDIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct TargetRNC_ID {
     pub lai: LAI,
@@ -5953,11 +6797,11 @@ pub struct TargetRNC_ID {
     pub ie_extensions: Option<TargetRNC_IDIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
 pub struct TargetToSource_TransparentContainer(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TargeteNB_ID {
     pub global_enb_id: GlobalNgENB_ID,
@@ -5966,27 +6810,27 @@ pub struct TargeteNB_ID {
     pub ie_extensions: Option<TargeteNB_IDIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
 pub struct TargettoSource_Failure_TransparentContainer(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "127")]
 pub struct Threshold_RSRP(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "127")]
 pub struct Threshold_RSRQ(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "127")]
 pub struct Threshold_SINR(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "4", sz_ub = "4")]
 pub struct TimeStamp(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "15")]
 pub struct TimeToTrigger(pub u8);
 impl TimeToTrigger {
@@ -6008,7 +6852,7 @@ impl TimeToTrigger {
     pub const MS5120: u8 = 15u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "5")]
 pub struct TimeToWait(pub u8);
 impl TimeToWait {
@@ -6020,22 +6864,22 @@ impl TimeToWait {
     pub const V60S: u8 = 5u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "4095")]
 pub struct TimeUEStayedInCell(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "40950")]
 pub struct TimeUEStayedInCellEnhancedGranularity(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct TimerApproachForGUAMIRemoval(pub u8);
 impl TimerApproachForGUAMIRemoval {
     pub const APPLY_TIMER: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct TooearlyIntersystemHO {
     pub sourcecell_id: EUTRA_CGI,
@@ -6046,7 +6890,7 @@ pub struct TooearlyIntersystemHO {
     pub ie_extensions: Option<TooearlyIntersystemHOIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TraceActivation {
     pub ngran_trace_id: NGRANTraceID,
@@ -6057,7 +6901,7 @@ pub struct TraceActivation {
     pub ie_extensions: Option<TraceActivationIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "5")]
 pub struct TraceDepth(pub u8);
 impl TraceDepth {
@@ -6069,27 +6913,27 @@ impl TraceDepth {
     pub const MAXIMUM_WITHOUT_VENDOR_SPECIFIC_EXTENSION: u8 = 5u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct TraceFailureIndication {
     pub protocol_i_es: TraceFailureIndicationProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct TraceStart {
     pub protocol_i_es: TraceStartProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "1", ub = "99")]
 pub struct TrafficLoadReductionIndication(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = true, sz_lb = "1", sz_ub = "160")]
-pub struct TransportLayerAddress(pub BitVec<u8, Msb0>);
+pub struct TransportLayerAddress(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "2")]
 pub struct TriggeringMessage(pub u8);
 impl TriggeringMessage {
@@ -6098,7 +6942,7 @@ impl TriggeringMessage {
     pub const UNSUCCESSFULL_OUTCOME: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct TypeOfError(pub u8);
 impl TypeOfError {
@@ -6106,26 +6950,27 @@ impl TypeOfError {
     pub const MISSING: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 7)]
 pub struct UE_DifferentiationInfo {
     #[asn(optional_idx = 0)]
-    pub periodic_communication_indicator: Option<ENUMERATED_83>,
+    pub periodic_communication_indicator:
+        Option<UE_DifferentiationInfoPeriodicCommunicationIndicator>,
     #[asn(optional_idx = 1)]
-    pub periodic_time: Option<INTEGER_84>,
+    pub periodic_time: Option<UE_DifferentiationInfoPeriodicTime>,
     #[asn(optional_idx = 2)]
     pub scheduled_communication_time: Option<ScheduledCommunicationTime>,
     #[asn(optional_idx = 3)]
-    pub stationary_indication: Option<ENUMERATED_85>,
+    pub stationary_indication: Option<UE_DifferentiationInfoStationaryIndication>,
     #[asn(optional_idx = 4)]
-    pub traffic_profile: Option<ENUMERATED_86>,
+    pub traffic_profile: Option<UE_DifferentiationInfoTrafficProfile>,
     #[asn(optional_idx = 5)]
-    pub battery_indication: Option<ENUMERATED_87>,
+    pub battery_indication: Option<UE_DifferentiationInfoBatteryIndication>,
     #[asn(optional_idx = 6)]
     pub ie_extensions: Option<UE_DifferentiationInfoIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct UE_NGAP_ID_pair {
     pub amf_ue_ngap_id: AMF_UE_NGAP_ID,
@@ -6134,7 +6979,7 @@ pub struct UE_NGAP_ID_pair {
     pub ie_extensions: Option<UE_NGAP_ID_pairIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum UE_NGAP_IDs {
     #[asn(key = 0, extended = false)]
@@ -6142,17 +6987,17 @@ pub enum UE_NGAP_IDs {
     #[asn(key = 1, extended = false)]
     AMF_UE_NGAP_ID(AMF_UE_NGAP_ID),
     #[asn(key = 2, extended = false)]
-    Choice_Extensions(UE_NGAP_IDschoice_Extensions),
+    Choice_Extensions(UE_NGAP_IDs_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct UE_UP_CIoT_Support(pub u8);
 impl UE_UP_CIoT_Support {
     pub const SUPPORTED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct UE_associatedLogicalNG_connectionItem {
     #[asn(optional_idx = 0)]
@@ -6163,7 +7008,7 @@ pub struct UE_associatedLogicalNG_connectionItem {
     pub ie_extensions: Option<UE_associatedLogicalNG_connectionItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6172,7 +7017,7 @@ pub struct UE_associatedLogicalNG_connectionItem {
 )]
 pub struct UE_associatedLogicalNG_connectionList(pub Vec<UE_associatedLogicalNG_connectionItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct UEAggregateMaximumBitRate {
     pub ue_aggregate_maximum_bit_rate_dl: BitRate,
@@ -6181,69 +7026,69 @@ pub struct UEAggregateMaximumBitRate {
     pub ie_extensions: Option<UEAggregateMaximumBitRateIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct UECapabilityInfoRequest(pub u8);
 impl UECapabilityInfoRequest {
     pub const REQUESTED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UEContextModificationFailure {
     pub protocol_i_es: UEContextModificationFailureProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UEContextModificationRequest {
     pub protocol_i_es: UEContextModificationRequestProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UEContextModificationResponse {
     pub protocol_i_es: UEContextModificationResponseProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UEContextReleaseCommand {
     pub protocol_i_es: UEContextReleaseCommandProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UEContextReleaseComplete {
     pub protocol_i_es: UEContextReleaseCompleteProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UEContextReleaseRequest {
     pub protocol_i_es: UEContextReleaseRequestProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct UEContextRequest(pub u8);
 impl UEContextRequest {
     pub const REQUESTED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UEContextResumeFailure {
     pub protocol_i_es: UEContextResumeFailureProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UEContextResumeRequest {
     pub protocol_i_es: UEContextResumeRequestProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct UEContextResumeRequestTransfer {
     #[asn(optional_idx = 0)]
@@ -6252,13 +7097,13 @@ pub struct UEContextResumeRequestTransfer {
     pub ie_extensions: Option<UEContextResumeRequestTransferIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UEContextResumeResponse {
     pub protocol_i_es: UEContextResumeResponseProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct UEContextResumeResponseTransfer {
     #[asn(optional_idx = 0)]
@@ -6267,19 +7112,19 @@ pub struct UEContextResumeResponseTransfer {
     pub ie_extensions: Option<UEContextResumeResponseTransferIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UEContextSuspendFailure {
     pub protocol_i_es: UEContextSuspendFailureProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UEContextSuspendRequest {
     pub protocol_i_es: UEContextSuspendRequestProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct UEContextSuspendRequestTransfer {
     #[asn(optional_idx = 0)]
@@ -6288,50 +7133,50 @@ pub struct UEContextSuspendRequestTransfer {
     pub ie_extensions: Option<UEContextSuspendRequestTransferIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UEContextSuspendResponse {
     pub protocol_i_es: UEContextSuspendResponseProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct UEHistoryInformation(pub Vec<LastVisitedCellItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = false)]
 pub enum UEHistoryInformationFromTheUE {
     #[asn(key = 0, extended = false)]
     NR(NRMobilityHistoryReport),
     #[asn(key = 1, extended = false)]
-    Choice_Extensions(UEHistoryInformationFromTheUEchoice_Extensions),
+    Choice_Extensions(UEHistoryInformationFromTheUE_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = false)]
 pub enum UEIdentityIndexValue {
     #[asn(key = 0, extended = false)]
-    IndexLength10(BIT_STRING_88),
+    IndexLength10(UEIdentityIndexValue_indexLength10),
     #[asn(key = 1, extended = false)]
-    Choice_Extensions(UEIdentityIndexValuechoice_Extensions),
+    Choice_Extensions(UEIdentityIndexValue_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UEInformationTransfer {
     pub protocol_i_es: UEInformationTransferProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = false)]
 pub enum UEPagingIdentity {
     #[asn(key = 0, extended = false)]
     FiveG_S_TMSI(FiveG_S_TMSI),
     #[asn(key = 1, extended = false)]
-    Choice_Extensions(UEPagingIdentitychoice_Extensions),
+    Choice_Extensions(UEPagingIdentity_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct UEPresence(pub u8);
 impl UEPresence {
@@ -6340,7 +7185,7 @@ impl UEPresence {
     pub const UNKNOWN: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct UEPresenceInAreaOfInterestItem {
     pub location_reporting_reference_id: LocationReportingReferenceID,
@@ -6349,11 +7194,11 @@ pub struct UEPresenceInAreaOfInterestItem {
     pub ie_extensions: Option<UEPresenceInAreaOfInterestItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "64")]
 pub struct UEPresenceInAreaOfInterestList(pub Vec<UEPresenceInAreaOfInterestItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum UERLFReportContainer {
     #[asn(key = 0, extended = false)]
@@ -6361,26 +7206,26 @@ pub enum UERLFReportContainer {
     #[asn(key = 1, extended = false)]
     LTE(LTEUERLFReportContainer),
     #[asn(key = 2, extended = false)]
-    Choice_Extensions(UERLFReportContainerchoice_Extensions),
+    Choice_Extensions(UERLFReportContainer_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
 pub struct UERadioCapability(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UERadioCapabilityCheckRequest {
     pub protocol_i_es: UERadioCapabilityCheckRequestProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UERadioCapabilityCheckResponse {
     pub protocol_i_es: UERadioCapabilityCheckResponseProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct UERadioCapabilityForPaging {
     #[asn(optional_idx = 0)]
@@ -6391,48 +7236,48 @@ pub struct UERadioCapabilityForPaging {
     pub ie_extensions: Option<UERadioCapabilityForPagingIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
 pub struct UERadioCapabilityForPagingOfEUTRA(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
 pub struct UERadioCapabilityForPagingOfNB_IoT(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
 pub struct UERadioCapabilityForPagingOfNR(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
 pub struct UERadioCapabilityID(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UERadioCapabilityIDMappingRequest {
     pub protocol_i_es: UERadioCapabilityIDMappingRequestProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UERadioCapabilityIDMappingResponse {
     pub protocol_i_es: UERadioCapabilityIDMappingResponseProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UERadioCapabilityInfoIndication {
     pub protocol_i_es: UERadioCapabilityInfoIndicationProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct UERetentionInformation(pub u8);
 impl UERetentionInformation {
     pub const UES_RETAINED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct UESecurityCapabilities {
     pub n_rencryption_algorithms: NRencryptionAlgorithms,
@@ -6443,13 +7288,13 @@ pub struct UESecurityCapabilities {
     pub ie_extensions: Option<UESecurityCapabilitiesIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UETNLABindingReleaseRequest {
     pub protocol_i_es: UETNLABindingReleaseRequestProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct UL_CP_SecurityInformation {
     pub ul_nas_mac: UL_NAS_MAC,
@@ -6458,15 +7303,15 @@ pub struct UL_CP_SecurityInformation {
     pub ie_extensions: Option<UL_CP_SecurityInformationIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "5", sz_ub = "5")]
-pub struct UL_NAS_Count(pub BitVec<u8, Msb0>);
+pub struct UL_NAS_Count(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "16", sz_ub = "16")]
-pub struct UL_NAS_MAC(pub BitVec<u8, Msb0>);
+pub struct UL_NAS_MAC(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct UL_NGU_UP_TNLModifyItem {
     pub ul_ngu_up_tnl_information: UPTransportLayerInformation,
@@ -6475,27 +7320,27 @@ pub struct UL_NGU_UP_TNLModifyItem {
     pub ie_extensions: Option<UL_NGU_UP_TNLModifyItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "4")]
 pub struct UL_NGU_UP_TNLModifyList(pub Vec<UL_NGU_UP_TNLModifyItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct ULForwarding(pub u8);
 impl ULForwarding {
     pub const UL_FORWARDING_PROPOSED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = false)]
 pub enum UPTransportLayerInformation {
     #[asn(key = 0, extended = false)]
     GTPTunnel(GTPTunnel),
     #[asn(key = 1, extended = false)]
-    Choice_Extensions(UPTransportLayerInformationchoice_Extensions),
+    Choice_Extensions(UPTransportLayerInformation_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct UPTransportLayerInformationItem {
     pub ngu_up_tnl_information: UPTransportLayerInformation,
@@ -6503,11 +7348,11 @@ pub struct UPTransportLayerInformationItem {
     pub ie_extensions: Option<UPTransportLayerInformationItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "3")]
 pub struct UPTransportLayerInformationList(pub Vec<UPTransportLayerInformationItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct UPTransportLayerInformationPairItem {
     pub ul_ngu_up_tnl_information: UPTransportLayerInformation,
@@ -6516,15 +7361,15 @@ pub struct UPTransportLayerInformationPairItem {
     pub ie_extensions: Option<UPTransportLayerInformationPairItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "3")]
 pub struct UPTransportLayerInformationPairList(pub Vec<UPTransportLayerInformationPairItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "VisibleString")]
 pub struct URI_address(pub String);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct UnavailableGUAMIItem {
     pub guami: GUAMI,
@@ -6536,7 +7381,7 @@ pub struct UnavailableGUAMIItem {
     pub ie_extensions: Option<UnavailableGUAMIItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6545,7 +7390,7 @@ pub struct UnavailableGUAMIItem {
 )]
 pub struct UnavailableGUAMIList(pub Vec<UnavailableGUAMIItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UnsuccessfulOutcome {
     #[asn(key_field = true)]
@@ -6554,53 +7399,53 @@ pub struct UnsuccessfulOutcome {
     pub value: UnsuccessfulOutcomeValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = true, sz_lb = "8", sz_ub = "8")]
-pub struct UpdateFeedback(pub BitVec<u8, Msb0>);
+pub struct UpdateFeedback(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UplinkNASTransport {
     pub protocol_i_es: UplinkNASTransportProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UplinkNonUEAssociatedNRPPaTransport {
     pub protocol_i_es: UplinkNonUEAssociatedNRPPaTransportProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UplinkRANConfigurationTransfer {
     pub protocol_i_es: UplinkRANConfigurationTransferProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UplinkRANEarlyStatusTransfer {
     pub protocol_i_es: UplinkRANEarlyStatusTransferProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UplinkRANStatusTransfer {
     pub protocol_i_es: UplinkRANStatusTransferProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UplinkRIMInformationTransfer {
     pub protocol_i_es: UplinkRIMInformationTransferProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UplinkUEAssociatedNRPPaTransport {
     pub protocol_i_es: UplinkUEAssociatedNRPPaTransportProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "3", extensible = false)]
 pub enum UserLocationInformation {
     #[asn(key = 0, extended = false)]
@@ -6610,10 +7455,10 @@ pub enum UserLocationInformation {
     #[asn(key = 2, extended = false)]
     UserLocationInformationN3IWF(UserLocationInformationN3IWF),
     #[asn(key = 3, extended = false)]
-    Choice_Extensions(UserLocationInformationchoice_Extensions),
+    Choice_Extensions(UserLocationInformation_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct UserLocationInformationEUTRA {
     pub eutra_cgi: EUTRA_CGI,
@@ -6624,7 +7469,7 @@ pub struct UserLocationInformationEUTRA {
     pub ie_extensions: Option<UserLocationInformationEUTRAIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct UserLocationInformationN3IWF {
     pub ip_address: TransportLayerAddress,
@@ -6633,7 +7478,7 @@ pub struct UserLocationInformationN3IWF {
     pub ie_extensions: Option<UserLocationInformationN3IWFIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct UserLocationInformationNR {
     pub nr_cgi: NR_CGI,
@@ -6644,7 +7489,7 @@ pub struct UserLocationInformationNR {
     pub ie_extensions: Option<UserLocationInformationNRIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct UserLocationInformationTNGF {
     pub tnap_id: TNAP_ID,
@@ -6655,7 +7500,7 @@ pub struct UserLocationInformationTNGF {
     pub ie_extensions: Option<UserLocationInformationTNGFIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct UserLocationInformationTWIF {
     pub twap_id: TWAP_ID,
@@ -6666,7 +7511,7 @@ pub struct UserLocationInformationTWIF {
     pub ie_extensions: Option<UserLocationInformationTWIFIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum UserLocationInformationW_AGF {
     #[asn(key = 0, extended = false)]
@@ -6674,10 +7519,10 @@ pub enum UserLocationInformationW_AGF {
     #[asn(key = 1, extended = false)]
     HFCNode_ID(HFCNode_ID),
     #[asn(key = 2, extended = false)]
-    Choice_Extensions(UserLocationInformationW_AGFchoice_Extensions),
+    Choice_Extensions(UserLocationInformationW_AGF_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct UserPlaneSecurityInformation {
     pub security_result: SecurityResult,
@@ -6686,7 +7531,7 @@ pub struct UserPlaneSecurityInformation {
     pub ie_extensions: Option<UserPlaneSecurityInformationIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct VehicleUE(pub u8);
 impl VehicleUE {
@@ -6694,38 +7539,38 @@ impl VehicleUE {
     pub const NOT_AUTHORIZED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct VolumeTimedReport_Item {
-    pub start_time_stamp: OCTET_STRING_89,
-    pub end_time_stamp: OCTET_STRING_90,
-    pub usage_count_ul: INTEGER_91,
-    pub usage_count_dl: INTEGER_92,
+    pub start_time_stamp: VolumeTimedReport_ItemStartTimeStamp,
+    pub end_time_stamp: VolumeTimedReport_ItemEndTimeStamp,
+    pub usage_count_ul: VolumeTimedReport_ItemUsageCountUL,
+    pub usage_count_dl: VolumeTimedReport_ItemUsageCountDL,
     #[asn(optional_idx = 0)]
     pub ie_extensions: Option<VolumeTimedReport_ItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "2")]
 pub struct VolumeTimedReportList(pub Vec<VolumeTimedReport_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = false)]
 pub enum W_AGF_ID {
     #[asn(key = 0, extended = false)]
-    W_AGF_ID(BIT_STRING_93),
+    W_AGF_ID(W_AGF_ID_w_AGF_ID),
     #[asn(key = 1, extended = false)]
-    Choice_Extensions(W_AGF_IDchoice_Extensions),
+    Choice_Extensions(W_AGF_ID_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct WLANMeasConfig(pub u8);
 impl WLANMeasConfig {
     pub const SETUP: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct WLANMeasConfigNameItem {
     pub wlan_name: WLANName,
@@ -6733,25 +7578,25 @@ pub struct WLANMeasConfigNameItem {
     pub ie_extensions: Option<WLANMeasConfigNameItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "4")]
 pub struct WLANMeasConfigNameList(pub Vec<WLANMeasConfigNameItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 4)]
 pub struct WLANMeasurementConfiguration {
     pub wlan_meas_config: WLANMeasConfig,
     #[asn(optional_idx = 0)]
     pub wlan_meas_config_name_list: Option<WLANMeasConfigNameList>,
     #[asn(optional_idx = 1)]
-    pub wlan_rssi: Option<ENUMERATED_94>,
+    pub wlan_rssi: Option<WLANMeasurementConfigurationWlan_rssi>,
     #[asn(optional_idx = 2)]
-    pub wlan_rtt: Option<ENUMERATED_95>,
+    pub wlan_rtt: Option<WLANMeasurementConfigurationWlan_rtt>,
     #[asn(optional_idx = 3)]
     pub ie_extensions: Option<WLANMeasurementConfigurationIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "OCTET-STRING",
     sz_extensible = false,
@@ -6760,7 +7605,7 @@ pub struct WLANMeasurementConfiguration {
 )]
 pub struct WLANName(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct WUS_Assistance_Information {
     pub paging_probability_information: PagingProbabilityInformation,
@@ -6768,7 +7613,7 @@ pub struct WUS_Assistance_Information {
     pub ie_extensions: Option<WUS_Assistance_InformationIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "OCTET-STRING",
     sz_extensible = false,
@@ -6777,7 +7622,7 @@ pub struct WUS_Assistance_Information {
 )]
 pub struct WarningAreaCoordinates(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "CHOICE", lb = "0", ub = "4", extensible = false)]
 pub enum WarningAreaList {
     #[asn(key = 0, extended = false)]
@@ -6789,10 +7634,10 @@ pub enum WarningAreaList {
     #[asn(key = 3, extended = false)]
     EmergencyAreaIDList(EmergencyAreaIDList),
     #[asn(key = 4, extended = false)]
-    Choice_Extensions(WarningAreaListchoice_Extensions),
+    Choice_Extensions(WarningAreaList_choice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "OCTET-STRING",
     sz_extensible = false,
@@ -6801,7 +7646,7 @@ pub enum WarningAreaList {
 )]
 pub struct WarningMessageContents(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "OCTET-STRING",
     sz_extensible = false,
@@ -6810,23 +7655,23 @@ pub struct WarningMessageContents(pub Vec<u8>);
 )]
 pub struct WarningSecurityInfo(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "2", sz_ub = "2")]
 pub struct WarningType(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct WriteReplaceWarningRequest {
     pub protocol_i_es: WriteReplaceWarningRequestProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct WriteReplaceWarningResponse {
     pub protocol_i_es: WriteReplaceWarningResponseProtocolIEs,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct XnExtTLA_Item {
     #[asn(optional_idx = 0)]
@@ -6837,19 +7682,19 @@ pub struct XnExtTLA_Item {
     pub ie_extensions: Option<XnExtTLA_ItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct XnExtTLAs(pub Vec<XnExtTLA_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct XnGTP_TLAs(pub Vec<TransportLayerAddress>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "2")]
 pub struct XnTLAs(pub Vec<TransportLayerAddress>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct XnTNLConfigurationInfo {
     pub xn_transport_layer_addresses: XnTLAs,
@@ -6859,11 +7704,11 @@ pub struct XnTNLConfigurationInfo {
     pub ie_extensions: Option<XnTNLConfigurationInfoIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AMF_TNLAssociationSetupItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6874,11 +7719,11 @@ pub struct AMF_TNLAssociationSetupItemIE_Extensions(
     pub Vec<AMF_TNLAssociationSetupItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AMF_TNLAssociationToAddItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6889,14 +7734,14 @@ pub struct AMF_TNLAssociationToAddItemIE_Extensions(
     pub Vec<AMF_TNLAssociationToAddItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum AMF_TNLAssociationToRemoveItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 168)]
     Id_TNLAssociationTransportLayerAddressNGRAN(CPTransportLayerInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AMF_TNLAssociationToRemoveItemIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -6905,7 +7750,7 @@ pub struct AMF_TNLAssociationToRemoveItemIE_Extensions_Entry {
     pub extension_value: AMF_TNLAssociationToRemoveItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6916,11 +7761,11 @@ pub struct AMF_TNLAssociationToRemoveItemIE_Extensions(
     pub Vec<AMF_TNLAssociationToRemoveItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AMF_TNLAssociationToUpdateItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6931,7 +7776,7 @@ pub struct AMF_TNLAssociationToUpdateItemIE_Extensions(
     pub Vec<AMF_TNLAssociationToUpdateItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum AMFCPRelocationIndicationProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -6944,7 +7789,7 @@ pub enum AMFCPRelocationIndicationProtocolIEs_EntryValue {
     Id_S_NSSAI(S_NSSAI),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AMFCPRelocationIndicationProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -6953,7 +7798,7 @@ pub struct AMFCPRelocationIndicationProtocolIEs_Entry {
     pub value: AMFCPRelocationIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6964,7 +7809,7 @@ pub struct AMFCPRelocationIndicationProtocolIEs(
     pub Vec<AMFCPRelocationIndicationProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum AMFConfigurationUpdateProtocolIEs_EntryValue {
     #[asn(key = 6)]
@@ -6985,7 +7830,7 @@ pub enum AMFConfigurationUpdateProtocolIEs_EntryValue {
     Id_ServedGUAMIList(ServedGUAMIList),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AMFConfigurationUpdateProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -6994,7 +7839,7 @@ pub struct AMFConfigurationUpdateProtocolIEs_Entry {
     pub value: AMFConfigurationUpdateProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7003,7 +7848,7 @@ pub struct AMFConfigurationUpdateProtocolIEs_Entry {
 )]
 pub struct AMFConfigurationUpdateProtocolIEs(pub Vec<AMFConfigurationUpdateProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum AMFConfigurationUpdateAcknowledgeProtocolIEs_EntryValue {
     #[asn(key = 4)]
@@ -7014,7 +7859,7 @@ pub enum AMFConfigurationUpdateAcknowledgeProtocolIEs_EntryValue {
     Id_CriticalityDiagnostics(CriticalityDiagnostics),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AMFConfigurationUpdateAcknowledgeProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -7023,7 +7868,7 @@ pub struct AMFConfigurationUpdateAcknowledgeProtocolIEs_Entry {
     pub value: AMFConfigurationUpdateAcknowledgeProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7034,7 +7879,7 @@ pub struct AMFConfigurationUpdateAcknowledgeProtocolIEs(
     pub Vec<AMFConfigurationUpdateAcknowledgeProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum AMFConfigurationUpdateFailureProtocolIEs_EntryValue {
     #[asn(key = 15)]
@@ -7045,7 +7890,7 @@ pub enum AMFConfigurationUpdateFailureProtocolIEs_EntryValue {
     Id_TimeToWait(TimeToWait),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AMFConfigurationUpdateFailureProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -7054,7 +7899,7 @@ pub struct AMFConfigurationUpdateFailureProtocolIEs_Entry {
     pub value: AMFConfigurationUpdateFailureProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7065,18 +7910,18 @@ pub struct AMFConfigurationUpdateFailureProtocolIEs(
     pub Vec<AMFConfigurationUpdateFailureProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct AMFPagingTargetchoice_Extensions {}
+pub struct AMFPagingTarget_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum AMFStatusIndicationProtocolIEs_EntryValue {
     #[asn(key = 120)]
     Id_UnavailableGUAMIList(UnavailableGUAMIList),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AMFStatusIndicationProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -7085,7 +7930,7 @@ pub struct AMFStatusIndicationProtocolIEs_Entry {
     pub value: AMFStatusIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7094,14 +7939,14 @@ pub struct AMFStatusIndicationProtocolIEs_Entry {
 )]
 pub struct AMFStatusIndicationProtocolIEs(pub Vec<AMFStatusIndicationProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum AdditionalDLUPTNLInformationForHOItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 183)]
     Id_AdditionalRedundantDL_NGU_UP_TNLInformation(UPTransportLayerInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AdditionalDLUPTNLInformationForHOItemIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -7110,7 +7955,7 @@ pub struct AdditionalDLUPTNLInformationForHOItemIE_Extensions_Entry {
     pub extension_value: AdditionalDLUPTNLInformationForHOItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7121,11 +7966,11 @@ pub struct AdditionalDLUPTNLInformationForHOItemIE_Extensions(
     pub Vec<AdditionalDLUPTNLInformationForHOItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AllocationAndRetentionPriorityIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7136,19 +7981,19 @@ pub struct AllocationAndRetentionPriorityIE_Extensions(
     pub Vec<AllocationAndRetentionPriorityIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
-pub struct ENUMERATED_2(pub u8);
-impl ENUMERATED_2 {
+pub struct Allowed_PNI_NPN_ItemPNI_NPN_restricted(pub u8);
+impl Allowed_PNI_NPN_ItemPNI_NPN_restricted {
     pub const RESTRICTED: u8 = 0u8;
     pub const NOT_RESTRICTED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct Allowed_PNI_NPN_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7157,11 +8002,11 @@ pub struct Allowed_PNI_NPN_ItemIE_Extensions_Entry {}
 )]
 pub struct Allowed_PNI_NPN_ItemIE_Extensions(pub Vec<Allowed_PNI_NPN_ItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AllowedNSSAI_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7170,11 +8015,11 @@ pub struct AllowedNSSAI_ItemIE_Extensions_Entry {}
 )]
 pub struct AllowedNSSAI_ItemIE_Extensions(pub Vec<AllowedNSSAI_ItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AlternativeQoSParaSetItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7185,11 +8030,11 @@ pub struct AlternativeQoSParaSetItemIE_Extensions(
     pub Vec<AlternativeQoSParaSetItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AreaOfInterestIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7198,11 +8043,11 @@ pub struct AreaOfInterestIE_Extensions_Entry {}
 )]
 pub struct AreaOfInterestIE_Extensions(pub Vec<AreaOfInterestIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AreaOfInterestCellItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7211,11 +8056,11 @@ pub struct AreaOfInterestCellItemIE_Extensions_Entry {}
 )]
 pub struct AreaOfInterestCellItemIE_Extensions(pub Vec<AreaOfInterestCellItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AreaOfInterestItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7224,11 +8069,11 @@ pub struct AreaOfInterestItemIE_Extensions_Entry {}
 )]
 pub struct AreaOfInterestItemIE_Extensions(pub Vec<AreaOfInterestItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AreaOfInterestRANNodeItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7239,11 +8084,11 @@ pub struct AreaOfInterestRANNodeItemIE_Extensions(
     pub Vec<AreaOfInterestRANNodeItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AreaOfInterestTAIItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7252,27 +8097,27 @@ pub struct AreaOfInterestTAIItemIE_Extensions_Entry {}
 )]
 pub struct AreaOfInterestTAIItemIE_Extensions(pub Vec<AreaOfInterestTAIItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "NULL")]
-pub struct NULL_3;
+pub struct AreaScopeOfMDT_EUTRA_pLMNWide;
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct AreaScopeOfMDT_EUTRAchoice_Extensions {}
+pub struct AreaScopeOfMDT_EUTRA_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "NULL")]
-pub struct NULL_4;
+pub struct AreaScopeOfMDT_NR_pLMNWide;
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct AreaScopeOfMDT_NRchoice_Extensions {}
+pub struct AreaScopeOfMDT_NR_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AreaScopeOfNeighCellsItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7283,7 +8128,7 @@ pub struct AreaScopeOfNeighCellsItemIE_Extensions(
     pub Vec<AreaScopeOfNeighCellsItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum AssistanceDataForPagingIE_Extensions_EntryExtensionValue {
     #[asn(key = 260)]
@@ -7292,7 +8137,7 @@ pub enum AssistanceDataForPagingIE_Extensions_EntryExtensionValue {
     Id_PagingAssisDataforCEcapabUE(PagingAssisDataforCEcapabUE),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AssistanceDataForPagingIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -7301,7 +8146,7 @@ pub struct AssistanceDataForPagingIE_Extensions_Entry {
     pub extension_value: AssistanceDataForPagingIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7312,11 +8157,11 @@ pub struct AssistanceDataForPagingIE_Extensions(
     pub Vec<AssistanceDataForPagingIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AssistanceDataForRecommendedCellsIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7327,22 +8172,22 @@ pub struct AssistanceDataForRecommendedCellsIE_Extensions(
     pub Vec<AssistanceDataForRecommendedCellsIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
-pub struct ENUMERATED_5(pub u8);
-impl ENUMERATED_5 {
+pub struct AssociatedQosFlowItemQosFlowMappingIndication(pub u8);
+impl AssociatedQosFlowItemQosFlowMappingIndication {
     pub const UL: u8 = 0u8;
     pub const DL: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum AssociatedQosFlowItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 221)]
     Id_CurrentQoSParaSetIndex(AlternativeQoSParaSetIndex),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AssociatedQosFlowItemIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -7351,7 +8196,7 @@ pub struct AssociatedQosFlowItemIE_Extensions_Entry {
     pub extension_value: AssociatedQosFlowItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7360,11 +8205,11 @@ pub struct AssociatedQosFlowItemIE_Extensions_Entry {
 )]
 pub struct AssociatedQosFlowItemIE_Extensions(pub Vec<AssociatedQosFlowItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct BluetoothMeasConfigNameItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7375,18 +8220,18 @@ pub struct BluetoothMeasConfigNameItemIE_Extensions(
     pub Vec<BluetoothMeasConfigNameItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
-pub struct ENUMERATED_6(pub u8);
-impl ENUMERATED_6 {
+pub struct BluetoothMeasurementConfigurationBt_rssi(pub u8);
+impl BluetoothMeasurementConfigurationBt_rssi {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct BluetoothMeasurementConfigurationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7397,15 +8242,15 @@ pub struct BluetoothMeasurementConfigurationIE_Extensions(
     pub Vec<BluetoothMeasurementConfigurationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct BroadcastCancelledAreaListchoice_Extensions {}
+pub struct BroadcastCancelledAreaList_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct BroadcastCompletedAreaListchoice_Extensions {}
+pub struct BroadcastCompletedAreaList_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum BroadcastPLMNItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 271)]
@@ -7414,7 +8259,7 @@ pub enum BroadcastPLMNItemIE_Extensions_EntryExtensionValue {
     Id_NPN_Support(NPN_Support),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct BroadcastPLMNItemIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -7423,7 +8268,7 @@ pub struct BroadcastPLMNItemIE_Extensions_Entry {
     pub extension_value: BroadcastPLMNItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7432,11 +8277,11 @@ pub struct BroadcastPLMNItemIE_Extensions_Entry {
 )]
 pub struct BroadcastPLMNItemIE_Extensions(pub Vec<BroadcastPLMNItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CNAssistedRANTuningIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7445,19 +8290,19 @@ pub struct CNAssistedRANTuningIE_Extensions_Entry {}
 )]
 pub struct CNAssistedRANTuningIE_Extensions(pub Vec<CNAssistedRANTuningIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
-pub struct ENUMERATED_7(pub u8);
-impl ENUMERATED_7 {
+pub struct CNTypeRestrictionsForEquivalentItemCn_Type(pub u8);
+impl CNTypeRestrictionsForEquivalentItemCn_Type {
     pub const EPC_FORBIDDEN: u8 = 0u8;
     pub const FIVE_GC_FORBIDDEN: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CNTypeRestrictionsForEquivalentItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7468,19 +8313,19 @@ pub struct CNTypeRestrictionsForEquivalentItemIE_Extensions(
     pub Vec<CNTypeRestrictionsForEquivalentItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "4095")]
-pub struct INTEGER_8(pub u16);
+pub struct COUNTValueForPDCP_SN12PDCP_SN12(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "1048575")]
-pub struct INTEGER_9(pub u32);
+pub struct COUNTValueForPDCP_SN12HFN_PDCP_SN12(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct COUNTValueForPDCP_SN12IE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7489,19 +8334,19 @@ pub struct COUNTValueForPDCP_SN12IE_Extensions_Entry {}
 )]
 pub struct COUNTValueForPDCP_SN12IE_Extensions(pub Vec<COUNTValueForPDCP_SN12IE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "262143")]
-pub struct INTEGER_10(pub u32);
+pub struct COUNTValueForPDCP_SN18PDCP_SN18(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "16383")]
-pub struct INTEGER_11(pub u16);
+pub struct COUNTValueForPDCP_SN18HFN_PDCP_SN18(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct COUNTValueForPDCP_SN18IE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7510,27 +8355,27 @@ pub struct COUNTValueForPDCP_SN18IE_Extensions_Entry {}
 )]
 pub struct COUNTValueForPDCP_SN18IE_Extensions(pub Vec<COUNTValueForPDCP_SN18IE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
-pub enum CPTransportLayerInformationchoice_ExtensionsValue {
+pub enum CPTransportLayerInformation_choice_ExtensionsValue {
     #[asn(key = 169)]
     Id_EndpointIPAddressAndPort(EndpointIPAddressAndPort),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct CPTransportLayerInformationchoice_Extensions {
+pub struct CPTransportLayerInformation_choice_Extensions {
     #[asn(key_field = true)]
     pub id: ProtocolIE_ID,
     pub criticality: Criticality,
-    pub value: CPTransportLayerInformationchoice_ExtensionsValue,
+    pub value: CPTransportLayerInformation_choice_ExtensionsValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CancelledCellsInEAI_EUTRA_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7541,11 +8386,11 @@ pub struct CancelledCellsInEAI_EUTRA_ItemIE_Extensions(
     pub Vec<CancelledCellsInEAI_EUTRA_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CancelledCellsInEAI_NR_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7556,11 +8401,11 @@ pub struct CancelledCellsInEAI_NR_ItemIE_Extensions(
     pub Vec<CancelledCellsInEAI_NR_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CancelledCellsInTAI_EUTRA_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7571,11 +8416,11 @@ pub struct CancelledCellsInTAI_EUTRA_ItemIE_Extensions(
     pub Vec<CancelledCellsInTAI_EUTRA_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CancelledCellsInTAI_NR_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7586,15 +8431,15 @@ pub struct CancelledCellsInTAI_NR_ItemIE_Extensions(
     pub Vec<CancelledCellsInTAI_NR_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct CandidateCellchoice_Extensions {}
+pub struct CandidateCell_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CandidateCellIDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7603,11 +8448,11 @@ pub struct CandidateCellIDIE_Extensions_Entry {}
 )]
 pub struct CandidateCellIDIE_Extensions(pub Vec<CandidateCellIDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CandidateCellItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7616,19 +8461,19 @@ pub struct CandidateCellItemIE_Extensions_Entry {}
 )]
 pub struct CandidateCellItemIE_Extensions(pub Vec<CandidateCellItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "1007", extensible = true)]
-pub struct INTEGER_12(pub u16);
+pub struct CandidatePCICandidatePCI(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "3279165")]
-pub struct INTEGER_13(pub u32);
+pub struct CandidatePCICandidateNRARFCN(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CandidatePCIIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7637,15 +8482,15 @@ pub struct CandidatePCIIE_Extensions_Entry {}
 )]
 pub struct CandidatePCIIE_Extensions(pub Vec<CandidatePCIIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct Causechoice_Extensions {}
+pub struct Cause_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct Cell_CAGInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7654,11 +8499,11 @@ pub struct Cell_CAGInformationIE_Extensions_Entry {}
 )]
 pub struct Cell_CAGInformationIE_Extensions(pub Vec<Cell_CAGInformationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CellBasedMDT_EUTRAIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7667,11 +8512,11 @@ pub struct CellBasedMDT_EUTRAIE_Extensions_Entry {}
 )]
 pub struct CellBasedMDT_EUTRAIE_Extensions(pub Vec<CellBasedMDT_EUTRAIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CellBasedMDT_NRIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7680,11 +8525,11 @@ pub struct CellBasedMDT_NRIE_Extensions_Entry {}
 )]
 pub struct CellBasedMDT_NRIE_Extensions(pub Vec<CellBasedMDT_NRIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CellIDBroadcastEUTRA_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7695,11 +8540,11 @@ pub struct CellIDBroadcastEUTRA_ItemIE_Extensions(
     pub Vec<CellIDBroadcastEUTRA_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CellIDBroadcastNR_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7708,11 +8553,11 @@ pub struct CellIDBroadcastNR_ItemIE_Extensions_Entry {}
 )]
 pub struct CellIDBroadcastNR_ItemIE_Extensions(pub Vec<CellIDBroadcastNR_ItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CellIDCancelledEUTRA_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7723,11 +8568,11 @@ pub struct CellIDCancelledEUTRA_ItemIE_Extensions(
     pub Vec<CellIDCancelledEUTRA_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CellIDCancelledNR_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7736,11 +8581,11 @@ pub struct CellIDCancelledNR_ItemIE_Extensions_Entry {}
 )]
 pub struct CellIDCancelledNR_ItemIE_Extensions(pub Vec<CellIDCancelledNR_ItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct CellIDListForRestartchoice_Extensions {}
+pub struct CellIDListForRestart_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum CellTrafficTraceProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -7759,7 +8604,7 @@ pub enum CellTrafficTraceProtocolIEs_EntryValue {
     Id_TraceCollectionEntityURI(URI_address),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CellTrafficTraceProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -7768,7 +8613,7 @@ pub struct CellTrafficTraceProtocolIEs_Entry {
     pub value: CellTrafficTraceProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7777,11 +8622,11 @@ pub struct CellTrafficTraceProtocolIEs_Entry {
 )]
 pub struct CellTrafficTraceProtocolIEs(pub Vec<CellTrafficTraceProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CellTypeIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7790,11 +8635,11 @@ pub struct CellTypeIE_Extensions_Entry {}
 )]
 pub struct CellTypeIE_Extensions(pub Vec<CellTypeIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CompletedCellsInEAI_EUTRA_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7805,11 +8650,11 @@ pub struct CompletedCellsInEAI_EUTRA_ItemIE_Extensions(
     pub Vec<CompletedCellsInEAI_EUTRA_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CompletedCellsInEAI_NR_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7820,11 +8665,11 @@ pub struct CompletedCellsInEAI_NR_ItemIE_Extensions(
     pub Vec<CompletedCellsInEAI_NR_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CompletedCellsInTAI_EUTRA_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7835,11 +8680,11 @@ pub struct CompletedCellsInTAI_EUTRA_ItemIE_Extensions(
     pub Vec<CompletedCellsInTAI_EUTRA_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CompletedCellsInTAI_NR_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7850,7 +8695,7 @@ pub struct CompletedCellsInTAI_NR_ItemIE_Extensions(
     pub Vec<CompletedCellsInTAI_NR_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum ConnectionEstablishmentIndicationProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -7879,7 +8724,7 @@ pub enum ConnectionEstablishmentIndicationProtocolIEs_EntryValue {
     Id_UERadioCapabilityID(UERadioCapabilityID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ConnectionEstablishmentIndicationProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -7888,7 +8733,7 @@ pub struct ConnectionEstablishmentIndicationProtocolIEs_Entry {
     pub value: ConnectionEstablishmentIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7899,7 +8744,7 @@ pub struct ConnectionEstablishmentIndicationProtocolIEs(
     pub Vec<ConnectionEstablishmentIndicationProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum CoreNetworkAssistanceInformationForInactiveIE_Extensions_EntryExtensionValue {
     #[asn(key = 280)]
@@ -7910,7 +8755,7 @@ pub enum CoreNetworkAssistanceInformationForInactiveIE_Extensions_EntryExtension
     Id_UERadioCapabilityForPaging(UERadioCapabilityForPaging),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CoreNetworkAssistanceInformationForInactiveIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -7920,7 +8765,7 @@ pub struct CoreNetworkAssistanceInformationForInactiveIE_Extensions_Entry {
         CoreNetworkAssistanceInformationForInactiveIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7931,11 +8776,11 @@ pub struct CoreNetworkAssistanceInformationForInactiveIE_Extensions(
     pub Vec<CoreNetworkAssistanceInformationForInactiveIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CriticalityDiagnosticsIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7944,11 +8789,11 @@ pub struct CriticalityDiagnosticsIE_Extensions_Entry {}
 )]
 pub struct CriticalityDiagnosticsIE_Extensions(pub Vec<CriticalityDiagnosticsIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CriticalityDiagnostics_IE_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7959,18 +8804,18 @@ pub struct CriticalityDiagnostics_IE_ItemIE_Extensions(
     pub Vec<CriticalityDiagnostics_IE_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
-pub struct ENUMERATED_14(pub u8);
-impl ENUMERATED_14 {
+pub struct DAPSRequestInfoDAPSIndicator(pub u8);
+impl DAPSRequestInfoDAPSIndicator {
     pub const DAPS_HO_REQUIRED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DAPSRequestInfoIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7979,19 +8824,19 @@ pub struct DAPSRequestInfoIE_Extensions_Entry {}
 )]
 pub struct DAPSRequestInfoIE_Extensions(pub Vec<DAPSRequestInfoIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
-pub struct ENUMERATED_15(pub u8);
-impl ENUMERATED_15 {
+pub struct DAPSResponseInfoDapsresponseindicator(pub u8);
+impl DAPSResponseInfoDapsresponseindicator {
     pub const DAPS_HO_ACCEPTED: u8 = 0u8;
     pub const DAPS_HO_NOT_ACCEPTED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DAPSResponseInfoIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8000,11 +8845,11 @@ pub struct DAPSResponseInfoIE_Extensions_Entry {}
 )]
 pub struct DAPSResponseInfoIE_Extensions(pub Vec<DAPSResponseInfoIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DAPSResponseInfoItemIE_Extension_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8013,11 +8858,11 @@ pub struct DAPSResponseInfoItemIE_Extension_Entry {}
 )]
 pub struct DAPSResponseInfoItemIE_Extension(pub Vec<DAPSResponseInfoItemIE_Extension_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DL_CP_SecurityInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8028,15 +8873,15 @@ pub struct DL_CP_SecurityInformationIE_Extensions(
     pub Vec<DL_CP_SecurityInformationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct DRBStatusDLchoice_Extensions {}
+pub struct DRBStatusDL_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DRBStatusDL12IE_Extension_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8045,11 +8890,11 @@ pub struct DRBStatusDL12IE_Extension_Entry {}
 )]
 pub struct DRBStatusDL12IE_Extension(pub Vec<DRBStatusDL12IE_Extension_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DRBStatusDL18IE_Extension_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8058,19 +8903,21 @@ pub struct DRBStatusDL18IE_Extension_Entry {}
 )]
 pub struct DRBStatusDL18IE_Extension(pub Vec<DRBStatusDL18IE_Extension_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct DRBStatusULchoice_Extensions {}
+pub struct DRBStatusUL_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "1", sz_ub = "2048")]
-pub struct BIT_STRING_16(pub BitVec<u8, Msb0>);
+pub struct DRBStatusUL12ReceiveStatusOfUL_PDCP_SDUs(
+    pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>,
+);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DRBStatusUL12IE_Extension_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8079,20 +8926,22 @@ pub struct DRBStatusUL12IE_Extension_Entry {}
 )]
 pub struct DRBStatusUL12IE_Extension(pub Vec<DRBStatusUL12IE_Extension_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "BITSTRING",
     sz_extensible = false,
     sz_lb = "1",
     sz_ub = "131072"
 )]
-pub struct BIT_STRING_17(pub BitVec<u8, Msb0>);
+pub struct DRBStatusUL18ReceiveStatusOfUL_PDCP_SDUs(
+    pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>,
+);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DRBStatusUL18IE_Extension_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8101,11 +8950,11 @@ pub struct DRBStatusUL18IE_Extension_Entry {}
 )]
 pub struct DRBStatusUL18IE_Extension(pub Vec<DRBStatusUL18IE_Extension_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DRBsSubjectToEarlyStatusTransfer_ItemIE_Extension_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8116,14 +8965,14 @@ pub struct DRBsSubjectToEarlyStatusTransfer_ItemIE_Extension(
     pub Vec<DRBsSubjectToEarlyStatusTransfer_ItemIE_Extension_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum DRBsSubjectToStatusTransferItemIE_Extension_EntryExtensionValue {
     #[asn(key = 159)]
     Id_OldAssociatedQosFlowList_ULendmarkerexpected(AssociatedQosFlowList),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DRBsSubjectToStatusTransferItemIE_Extension_Entry {
     #[asn(key_field = true)]
@@ -8132,7 +8981,7 @@ pub struct DRBsSubjectToStatusTransferItemIE_Extension_Entry {
     pub extension_value: DRBsSubjectToStatusTransferItemIE_Extension_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8143,14 +8992,14 @@ pub struct DRBsSubjectToStatusTransferItemIE_Extension(
     pub Vec<DRBsSubjectToStatusTransferItemIE_Extension_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum DRBsToQosFlowsMappingItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 266)]
     Id_DAPSRequestInfo(DAPSRequestInfo),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DRBsToQosFlowsMappingItemIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -8159,7 +9008,7 @@ pub struct DRBsToQosFlowsMappingItemIE_Extensions_Entry {
     pub extension_value: DRBsToQosFlowsMappingItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8170,11 +9019,11 @@ pub struct DRBsToQosFlowsMappingItemIE_Extensions(
     pub Vec<DRBsToQosFlowsMappingItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DataForwardingResponseDRBItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8185,11 +9034,11 @@ pub struct DataForwardingResponseDRBItemIE_Extensions(
     pub Vec<DataForwardingResponseDRBItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DataForwardingResponseERABListItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8200,7 +9049,7 @@ pub struct DataForwardingResponseERABListItemIE_Extensions(
     pub Vec<DataForwardingResponseERABListItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum DeactivateTraceProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -8211,7 +9060,7 @@ pub enum DeactivateTraceProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DeactivateTraceProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -8220,7 +9069,7 @@ pub struct DeactivateTraceProtocolIEs_Entry {
     pub value: DeactivateTraceProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8229,7 +9078,7 @@ pub struct DeactivateTraceProtocolIEs_Entry {
 )]
 pub struct DeactivateTraceProtocolIEs(pub Vec<DeactivateTraceProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum DownlinkNASTransportProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -8270,7 +9119,7 @@ pub enum DownlinkNASTransportProtocolIEs_EntryValue {
     Id_UERadioCapabilityID(UERadioCapabilityID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DownlinkNASTransportProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -8279,7 +9128,7 @@ pub struct DownlinkNASTransportProtocolIEs_Entry {
     pub value: DownlinkNASTransportProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8288,7 +9137,7 @@ pub struct DownlinkNASTransportProtocolIEs_Entry {
 )]
 pub struct DownlinkNASTransportProtocolIEs(pub Vec<DownlinkNASTransportProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum DownlinkNonUEAssociatedNRPPaTransportProtocolIEs_EntryValue {
     #[asn(key = 46)]
@@ -8297,7 +9146,7 @@ pub enum DownlinkNonUEAssociatedNRPPaTransportProtocolIEs_EntryValue {
     Id_RoutingID(RoutingID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DownlinkNonUEAssociatedNRPPaTransportProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -8306,7 +9155,7 @@ pub struct DownlinkNonUEAssociatedNRPPaTransportProtocolIEs_Entry {
     pub value: DownlinkNonUEAssociatedNRPPaTransportProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8317,7 +9166,7 @@ pub struct DownlinkNonUEAssociatedNRPPaTransportProtocolIEs(
     pub Vec<DownlinkNonUEAssociatedNRPPaTransportProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum DownlinkRANConfigurationTransferProtocolIEs_EntryValue {
     #[asn(key = 157)]
@@ -8328,7 +9177,7 @@ pub enum DownlinkRANConfigurationTransferProtocolIEs_EntryValue {
     Id_SONConfigurationTransferDL(SONConfigurationTransfer),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DownlinkRANConfigurationTransferProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -8337,7 +9186,7 @@ pub struct DownlinkRANConfigurationTransferProtocolIEs_Entry {
     pub value: DownlinkRANConfigurationTransferProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8348,7 +9197,7 @@ pub struct DownlinkRANConfigurationTransferProtocolIEs(
     pub Vec<DownlinkRANConfigurationTransferProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum DownlinkRANEarlyStatusTransferProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -8359,7 +9208,7 @@ pub enum DownlinkRANEarlyStatusTransferProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DownlinkRANEarlyStatusTransferProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -8368,7 +9217,7 @@ pub struct DownlinkRANEarlyStatusTransferProtocolIEs_Entry {
     pub value: DownlinkRANEarlyStatusTransferProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8379,7 +9228,7 @@ pub struct DownlinkRANEarlyStatusTransferProtocolIEs(
     pub Vec<DownlinkRANEarlyStatusTransferProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum DownlinkRANStatusTransferProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -8390,7 +9239,7 @@ pub enum DownlinkRANStatusTransferProtocolIEs_EntryValue {
     Id_RANStatusTransfer_TransparentContainer(RANStatusTransfer_TransparentContainer),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DownlinkRANStatusTransferProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -8399,7 +9248,7 @@ pub struct DownlinkRANStatusTransferProtocolIEs_Entry {
     pub value: DownlinkRANStatusTransferProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8410,14 +9259,14 @@ pub struct DownlinkRANStatusTransferProtocolIEs(
     pub Vec<DownlinkRANStatusTransferProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum DownlinkRIMInformationTransferProtocolIEs_EntryValue {
     #[asn(key = 175)]
     Id_RIMInformationTransfer(RIMInformationTransfer),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DownlinkRIMInformationTransferProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -8426,7 +9275,7 @@ pub struct DownlinkRIMInformationTransferProtocolIEs_Entry {
     pub value: DownlinkRIMInformationTransferProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8437,7 +9286,7 @@ pub struct DownlinkRIMInformationTransferProtocolIEs(
     pub Vec<DownlinkRIMInformationTransferProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum DownlinkUEAssociatedNRPPaTransportProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -8450,7 +9299,7 @@ pub enum DownlinkUEAssociatedNRPPaTransportProtocolIEs_EntryValue {
     Id_RoutingID(RoutingID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DownlinkUEAssociatedNRPPaTransportProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -8459,7 +9308,7 @@ pub struct DownlinkUEAssociatedNRPPaTransportProtocolIEs_Entry {
     pub value: DownlinkUEAssociatedNRPPaTransportProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8470,7 +9319,7 @@ pub struct DownlinkUEAssociatedNRPPaTransportProtocolIEs(
     pub Vec<DownlinkUEAssociatedNRPPaTransportProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum Dynamic5QIDescriptorIE_Extensions_EntryExtensionValue {
     #[asn(key = 187)]
@@ -8481,7 +9330,7 @@ pub enum Dynamic5QIDescriptorIE_Extensions_EntryExtensionValue {
     Id_ExtendedPacketDelayBudget(ExtendedPacketDelayBudget),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct Dynamic5QIDescriptorIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -8490,7 +9339,7 @@ pub struct Dynamic5QIDescriptorIE_Extensions_Entry {
     pub extension_value: Dynamic5QIDescriptorIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8499,11 +9348,11 @@ pub struct Dynamic5QIDescriptorIE_Extensions_Entry {
 )]
 pub struct Dynamic5QIDescriptorIE_Extensions(pub Vec<Dynamic5QIDescriptorIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABInformationItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8512,31 +9361,31 @@ pub struct E_RABInformationItemIE_Extensions_Entry {}
 )]
 pub struct E_RABInformationItemIE_Extensions(pub Vec<E_RABInformationItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "20", sz_ub = "20")]
-pub struct BIT_STRING_18(pub BitVec<u8, Msb0>);
+pub struct ENB_ID_macroENB_ID(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "28", sz_ub = "28")]
-pub struct BIT_STRING_19(pub BitVec<u8, Msb0>);
+pub struct ENB_ID_homeENB_ID(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "18", sz_ub = "18")]
-pub struct BIT_STRING_20(pub BitVec<u8, Msb0>);
+pub struct ENB_ID_short_macroENB_ID(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "21", sz_ub = "21")]
-pub struct BIT_STRING_21(pub BitVec<u8, Msb0>);
+pub struct ENB_ID_long_macroENB_ID(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct ENB_IDchoice_Extensions {}
+pub struct ENB_ID_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct EPS_TAIIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8545,11 +9394,11 @@ pub struct EPS_TAIIE_Extensions_Entry {}
 )]
 pub struct EPS_TAIIE_Extensions(pub Vec<EPS_TAIIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct EUTRA_CGIIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8558,11 +9407,11 @@ pub struct EUTRA_CGIIE_Extensions_Entry {}
 )]
 pub struct EUTRA_CGIIE_Extensions(pub Vec<EUTRA_CGIIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct EarlyStatusTransfer_TransparentContainerIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8573,11 +9422,11 @@ pub struct EarlyStatusTransfer_TransparentContainerIE_Extensions(
     pub Vec<EarlyStatusTransfer_TransparentContainerIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct EmergencyAreaIDBroadcastEUTRA_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8588,11 +9437,11 @@ pub struct EmergencyAreaIDBroadcastEUTRA_ItemIE_Extensions(
     pub Vec<EmergencyAreaIDBroadcastEUTRA_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct EmergencyAreaIDBroadcastNR_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8603,11 +9452,11 @@ pub struct EmergencyAreaIDBroadcastNR_ItemIE_Extensions(
     pub Vec<EmergencyAreaIDBroadcastNR_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct EmergencyAreaIDCancelledEUTRA_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8618,11 +9467,11 @@ pub struct EmergencyAreaIDCancelledEUTRA_ItemIE_Extensions(
     pub Vec<EmergencyAreaIDCancelledEUTRA_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct EmergencyAreaIDCancelledNR_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8633,11 +9482,11 @@ pub struct EmergencyAreaIDCancelledNR_ItemIE_Extensions(
     pub Vec<EmergencyAreaIDCancelledNR_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct EmergencyFallbackIndicatorIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8648,11 +9497,11 @@ pub struct EmergencyFallbackIndicatorIE_Extensions(
     pub Vec<EmergencyFallbackIndicatorIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct EndpointIPAddressAndPortIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8663,7 +9512,7 @@ pub struct EndpointIPAddressAndPortIE_Extensions(
     pub Vec<EndpointIPAddressAndPortIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum ErrorIndicationProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -8678,7 +9527,7 @@ pub enum ErrorIndicationProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ErrorIndicationProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -8687,7 +9536,7 @@ pub struct ErrorIndicationProtocolIEs_Entry {
     pub value: ErrorIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8696,11 +9545,11 @@ pub struct ErrorIndicationProtocolIEs_Entry {
 )]
 pub struct ErrorIndicationProtocolIEs(pub Vec<ErrorIndicationProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct EventL1LoggedMDTConfigIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8709,22 +9558,22 @@ pub struct EventL1LoggedMDTConfigIE_Extensions_Entry {}
 )]
 pub struct EventL1LoggedMDTConfigIE_Extensions(pub Vec<EventL1LoggedMDTConfigIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
-pub struct ENUMERATED_22(pub u8);
-impl ENUMERATED_22 {
+pub struct EventTrigger_outOfCoverage(pub u8);
+impl EventTrigger_outOfCoverage {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct EventTriggerchoice_Extensions {}
+pub struct EventTrigger_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ExpectedUEActivityBehaviourIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8735,11 +9584,11 @@ pub struct ExpectedUEActivityBehaviourIE_Extensions(
     pub Vec<ExpectedUEActivityBehaviourIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ExpectedUEBehaviourIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8748,15 +9597,15 @@ pub struct ExpectedUEBehaviourIE_Extensions_Entry {}
 )]
 pub struct ExpectedUEBehaviourIE_Extensions(pub Vec<ExpectedUEBehaviourIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "4095")]
-pub struct INTEGER_23(pub u16);
+pub struct ExpectedUEMovingTrajectoryItemTimeStayedInCell(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ExpectedUEMovingTrajectoryItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8767,11 +9616,11 @@ pub struct ExpectedUEMovingTrajectoryItemIE_Extensions(
     pub Vec<ExpectedUEMovingTrajectoryItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct Extended_AMFNameIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8780,11 +9629,11 @@ pub struct Extended_AMFNameIE_Extensions_Entry {}
 )]
 pub struct Extended_AMFNameIE_Extensions(pub Vec<Extended_AMFNameIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct Extended_RANNodeNameIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8793,19 +9642,23 @@ pub struct Extended_RANNodeNameIE_Extensions_Entry {}
 )]
 pub struct Extended_RANNodeNameIE_Extensions(pub Vec<Extended_RANNodeNameIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = true, sz_lb = "8", sz_ub = "8")]
-pub struct BIT_STRING_24(pub BitVec<u8, Msb0>);
+pub struct ExtendedRATRestrictionInformationPrimaryRATRestriction(
+    pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>,
+);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = true, sz_lb = "8", sz_ub = "8")]
-pub struct BIT_STRING_25(pub BitVec<u8, Msb0>);
+pub struct ExtendedRATRestrictionInformationSecondaryRATRestriction(
+    pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>,
+);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ExtendedRATRestrictionInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8816,11 +9669,11 @@ pub struct ExtendedRATRestrictionInformationIE_Extensions(
     pub Vec<ExtendedRATRestrictionInformationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct FailureIndicationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8829,11 +9682,11 @@ pub struct FailureIndicationIE_Extensions_Entry {}
 )]
 pub struct FailureIndicationIE_Extensions(pub Vec<FailureIndicationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct FirstDLCountIE_Extension_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8842,11 +9695,11 @@ pub struct FirstDLCountIE_Extension_Entry {}
 )]
 pub struct FirstDLCountIE_Extension(pub Vec<FirstDLCountIE_Extension_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct FiveG_S_TMSIIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8855,11 +9708,11 @@ pub struct FiveG_S_TMSIIE_Extensions_Entry {}
 )]
 pub struct FiveG_S_TMSIIE_Extensions(pub Vec<FiveG_S_TMSIIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ForbiddenAreaInformation_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8870,11 +9723,11 @@ pub struct ForbiddenAreaInformation_ItemIE_Extensions(
     pub Vec<ForbiddenAreaInformation_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct FromEUTRANtoNGRANIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8883,11 +9736,11 @@ pub struct FromEUTRANtoNGRANIE_Extensions_Entry {}
 )]
 pub struct FromEUTRANtoNGRANIE_Extensions(pub Vec<FromEUTRANtoNGRANIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct FromNGRANtoEUTRANIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8896,14 +9749,14 @@ pub struct FromNGRANtoEUTRANIE_Extensions_Entry {}
 )]
 pub struct FromNGRANtoEUTRANIE_Extensions(pub Vec<FromNGRANtoEUTRANIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum GBR_QosInformationIE_Extensions_EntryExtensionValue {
     #[asn(key = 220)]
     Id_AlternativeQoSParaSetList(AlternativeQoSParaSetList),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct GBR_QosInformationIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -8912,7 +9765,7 @@ pub struct GBR_QosInformationIE_Extensions_Entry {
     pub extension_value: GBR_QosInformationIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8921,19 +9774,19 @@ pub struct GBR_QosInformationIE_Extensions_Entry {
 )]
 pub struct GBR_QosInformationIE_Extensions(pub Vec<GBR_QosInformationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "22", sz_ub = "32")]
-pub struct BIT_STRING_26(pub BitVec<u8, Msb0>);
+pub struct GNB_ID_gNB_ID(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct GNB_IDchoice_Extensions {}
+pub struct GNB_ID_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct GTPTunnelIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8942,11 +9795,11 @@ pub struct GTPTunnelIE_Extensions_Entry {}
 )]
 pub struct GTPTunnelIE_Extensions(pub Vec<GTPTunnelIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct GUAMIIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8955,11 +9808,11 @@ pub struct GUAMIIE_Extensions_Entry {}
 )]
 pub struct GUAMIIE_Extensions(pub Vec<GUAMIIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct GlobalENB_IDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8968,11 +9821,11 @@ pub struct GlobalENB_IDIE_Extensions_Entry {}
 )]
 pub struct GlobalENB_IDIE_Extensions(pub Vec<GlobalENB_IDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct GlobalGNB_IDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8981,11 +9834,11 @@ pub struct GlobalGNB_IDIE_Extensions_Entry {}
 )]
 pub struct GlobalGNB_IDIE_Extensions(pub Vec<GlobalGNB_IDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct GlobalLine_IDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8994,11 +9847,11 @@ pub struct GlobalLine_IDIE_Extensions_Entry {}
 )]
 pub struct GlobalLine_IDIE_Extensions(pub Vec<GlobalLine_IDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct GlobalN3IWF_IDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9007,11 +9860,11 @@ pub struct GlobalN3IWF_IDIE_Extensions_Entry {}
 )]
 pub struct GlobalN3IWF_IDIE_Extensions(pub Vec<GlobalN3IWF_IDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct GlobalNgENB_IDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9020,9 +9873,9 @@ pub struct GlobalNgENB_IDIE_Extensions_Entry {}
 )]
 pub struct GlobalNgENB_IDIE_Extensions(pub Vec<GlobalNgENB_IDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
-pub enum GlobalRANNodeIDchoice_ExtensionsValue {
+pub enum GlobalRANNodeID_choice_ExtensionsValue {
     #[asn(key = 240)]
     Id_GlobalTNGF_ID(GlobalTNGF_ID),
     #[asn(key = 241)]
@@ -9031,20 +9884,20 @@ pub enum GlobalRANNodeIDchoice_ExtensionsValue {
     Id_GlobalW_AGF_ID(GlobalW_AGF_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct GlobalRANNodeIDchoice_Extensions {
+pub struct GlobalRANNodeID_choice_Extensions {
     #[asn(key_field = true)]
     pub id: ProtocolIE_ID,
     pub criticality: Criticality,
-    pub value: GlobalRANNodeIDchoice_ExtensionsValue,
+    pub value: GlobalRANNodeID_choice_ExtensionsValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct GlobalTNGF_IDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9053,11 +9906,11 @@ pub struct GlobalTNGF_IDIE_Extensions_Entry {}
 )]
 pub struct GlobalTNGF_IDIE_Extensions(pub Vec<GlobalTNGF_IDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct GlobalTWIF_IDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9066,11 +9919,11 @@ pub struct GlobalTWIF_IDIE_Extensions_Entry {}
 )]
 pub struct GlobalTWIF_IDIE_Extensions(pub Vec<GlobalTWIF_IDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct GlobalW_AGF_IDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9079,24 +9932,24 @@ pub struct GlobalW_AGF_IDIE_Extensions_Entry {}
 )]
 pub struct GlobalW_AGF_IDIE_Extensions(pub Vec<GlobalW_AGF_IDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
-pub struct ENUMERATED_27(pub u8);
-impl ENUMERATED_27 {
+pub struct HOReportHandoverReportType(pub u8);
+impl HOReportHandoverReportType {
     pub const HO_TOO_EARLY: u8 = 0u8;
     pub const HO_TO_WRONG_CELL: u8 = 1u8;
     pub const INTERSYSTEM_PING_PONG: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "16", sz_ub = "16")]
-pub struct BIT_STRING_28(pub BitVec<u8, Msb0>);
+pub struct HOReportSourcecellC_RNTI(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HOReportIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9105,7 +9958,7 @@ pub struct HOReportIE_Extensions_Entry {}
 )]
 pub struct HOReportIE_Extensions(pub Vec<HOReportIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum HandoverCancelProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -9116,7 +9969,7 @@ pub enum HandoverCancelProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HandoverCancelProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -9125,7 +9978,7 @@ pub struct HandoverCancelProtocolIEs_Entry {
     pub value: HandoverCancelProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9134,7 +9987,7 @@ pub struct HandoverCancelProtocolIEs_Entry {
 )]
 pub struct HandoverCancelProtocolIEs(pub Vec<HandoverCancelProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum HandoverCancelAcknowledgeProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -9145,7 +9998,7 @@ pub enum HandoverCancelAcknowledgeProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HandoverCancelAcknowledgeProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -9154,7 +10007,7 @@ pub struct HandoverCancelAcknowledgeProtocolIEs_Entry {
     pub value: HandoverCancelAcknowledgeProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9165,7 +10018,7 @@ pub struct HandoverCancelAcknowledgeProtocolIEs(
     pub Vec<HandoverCancelAcknowledgeProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum HandoverCommandProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -9186,7 +10039,7 @@ pub enum HandoverCommandProtocolIEs_EntryValue {
     Id_TargetToSource_TransparentContainer(TargetToSource_TransparentContainer),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HandoverCommandProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -9195,7 +10048,7 @@ pub struct HandoverCommandProtocolIEs_Entry {
     pub value: HandoverCommandProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9204,7 +10057,7 @@ pub struct HandoverCommandProtocolIEs_Entry {
 )]
 pub struct HandoverCommandProtocolIEs(pub Vec<HandoverCommandProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum HandoverCommandTransferIE_Extensions_EntryExtensionValue {
     #[asn(key = 152)]
@@ -9217,7 +10070,7 @@ pub enum HandoverCommandTransferIE_Extensions_EntryExtensionValue {
     Id_ULForwardingUP_TNLInformation(UPTransportLayerInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HandoverCommandTransferIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -9226,7 +10079,7 @@ pub struct HandoverCommandTransferIE_Extensions_Entry {
     pub extension_value: HandoverCommandTransferIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9237,7 +10090,7 @@ pub struct HandoverCommandTransferIE_Extensions(
     pub Vec<HandoverCommandTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum HandoverFailureProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -9250,7 +10103,7 @@ pub enum HandoverFailureProtocolIEs_EntryValue {
     Id_TargettoSource_Failure_TransparentContainer(TargettoSource_Failure_TransparentContainer),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HandoverFailureProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -9259,7 +10112,7 @@ pub struct HandoverFailureProtocolIEs_Entry {
     pub value: HandoverFailureProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9268,7 +10121,7 @@ pub struct HandoverFailureProtocolIEs_Entry {
 )]
 pub struct HandoverFailureProtocolIEs(pub Vec<HandoverFailureProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum HandoverNotifyProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -9281,7 +10134,7 @@ pub enum HandoverNotifyProtocolIEs_EntryValue {
     Id_UserLocationInformation(UserLocationInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HandoverNotifyProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -9290,7 +10143,7 @@ pub struct HandoverNotifyProtocolIEs_Entry {
     pub value: HandoverNotifyProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9299,7 +10152,7 @@ pub struct HandoverNotifyProtocolIEs_Entry {
 )]
 pub struct HandoverNotifyProtocolIEs(pub Vec<HandoverNotifyProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum HandoverPreparationFailureProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -9314,7 +10167,7 @@ pub enum HandoverPreparationFailureProtocolIEs_EntryValue {
     Id_TargettoSource_Failure_TransparentContainer(TargettoSource_Failure_TransparentContainer),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HandoverPreparationFailureProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -9323,7 +10176,7 @@ pub struct HandoverPreparationFailureProtocolIEs_Entry {
     pub value: HandoverPreparationFailureProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9334,11 +10187,11 @@ pub struct HandoverPreparationFailureProtocolIEs(
     pub Vec<HandoverPreparationFailureProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HandoverPreparationUnsuccessfulTransferIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9349,7 +10202,7 @@ pub struct HandoverPreparationUnsuccessfulTransferIE_Extensions(
     pub Vec<HandoverPreparationUnsuccessfulTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum HandoverRequestProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -9422,7 +10275,7 @@ pub enum HandoverRequestProtocolIEs_EntryValue {
     Id_UESecurityCapabilities(UESecurityCapabilities),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HandoverRequestProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -9431,7 +10284,7 @@ pub struct HandoverRequestProtocolIEs_Entry {
     pub value: HandoverRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9440,7 +10293,7 @@ pub struct HandoverRequestProtocolIEs_Entry {
 )]
 pub struct HandoverRequestProtocolIEs(pub Vec<HandoverRequestProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum HandoverRequestAcknowledgeProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -9457,7 +10310,7 @@ pub enum HandoverRequestAcknowledgeProtocolIEs_EntryValue {
     Id_TargetToSource_TransparentContainer(TargetToSource_TransparentContainer),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HandoverRequestAcknowledgeProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -9466,7 +10319,7 @@ pub struct HandoverRequestAcknowledgeProtocolIEs_Entry {
     pub value: HandoverRequestAcknowledgeProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9477,7 +10330,7 @@ pub struct HandoverRequestAcknowledgeProtocolIEs(
     pub Vec<HandoverRequestAcknowledgeProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum HandoverRequestAcknowledgeTransferIE_Extensions_EntryExtensionValue {
     #[asn(key = 153)]
@@ -9496,7 +10349,7 @@ pub enum HandoverRequestAcknowledgeTransferIE_Extensions_EntryExtensionValue {
     Id_UsedRSNInformation(RedundantPDUSessionInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HandoverRequestAcknowledgeTransferIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -9505,7 +10358,7 @@ pub struct HandoverRequestAcknowledgeTransferIE_Extensions_Entry {
     pub extension_value: HandoverRequestAcknowledgeTransferIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9516,7 +10369,7 @@ pub struct HandoverRequestAcknowledgeTransferIE_Extensions(
     pub Vec<HandoverRequestAcknowledgeTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum HandoverRequiredProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -9537,7 +10390,7 @@ pub enum HandoverRequiredProtocolIEs_EntryValue {
     Id_TargetID(TargetID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HandoverRequiredProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -9546,7 +10399,7 @@ pub struct HandoverRequiredProtocolIEs_Entry {
     pub value: HandoverRequiredProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9555,11 +10408,11 @@ pub struct HandoverRequiredProtocolIEs_Entry {
 )]
 pub struct HandoverRequiredProtocolIEs(pub Vec<HandoverRequiredProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HandoverRequiredTransferIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9570,11 +10423,11 @@ pub struct HandoverRequiredTransferIE_Extensions(
     pub Vec<HandoverRequiredTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HandoverResourceAllocationUnsuccessfulTransferIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9585,7 +10438,7 @@ pub struct HandoverResourceAllocationUnsuccessfulTransferIE_Extensions(
     pub Vec<HandoverResourceAllocationUnsuccessfulTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum HandoverSuccessProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -9594,7 +10447,7 @@ pub enum HandoverSuccessProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HandoverSuccessProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -9603,7 +10456,7 @@ pub struct HandoverSuccessProtocolIEs_Entry {
     pub value: HandoverSuccessProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9612,11 +10465,11 @@ pub struct HandoverSuccessProtocolIEs_Entry {
 )]
 pub struct HandoverSuccessProtocolIEs(pub Vec<HandoverSuccessProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ImmediateMDTNrIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9625,11 +10478,11 @@ pub struct ImmediateMDTNrIE_Extensions_Entry {}
 )]
 pub struct ImmediateMDTNrIE_Extensions(pub Vec<ImmediateMDTNrIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct InfoOnRecommendedCellsAndRANNodesForPagingIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9640,7 +10493,7 @@ pub struct InfoOnRecommendedCellsAndRANNodesForPagingIE_Extensions(
     pub Vec<InfoOnRecommendedCellsAndRANNodesForPagingIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum InitialContextSetupFailureProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -9655,7 +10508,7 @@ pub enum InitialContextSetupFailureProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct InitialContextSetupFailureProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -9664,7 +10517,7 @@ pub struct InitialContextSetupFailureProtocolIEs_Entry {
     pub value: InitialContextSetupFailureProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9675,7 +10528,7 @@ pub struct InitialContextSetupFailureProtocolIEs(
     pub Vec<InitialContextSetupFailureProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum InitialContextSetupRequestProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -9754,7 +10607,7 @@ pub enum InitialContextSetupRequestProtocolIEs_EntryValue {
     Id_UESecurityCapabilities(UESecurityCapabilities),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct InitialContextSetupRequestProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -9763,7 +10616,7 @@ pub struct InitialContextSetupRequestProtocolIEs_Entry {
     pub value: InitialContextSetupRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9774,7 +10627,7 @@ pub struct InitialContextSetupRequestProtocolIEs(
     pub Vec<InitialContextSetupRequestProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum InitialContextSetupResponseProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -9789,7 +10642,7 @@ pub enum InitialContextSetupResponseProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct InitialContextSetupResponseProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -9798,7 +10651,7 @@ pub struct InitialContextSetupResponseProtocolIEs_Entry {
     pub value: InitialContextSetupResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9809,7 +10662,7 @@ pub struct InitialContextSetupResponseProtocolIEs(
     pub Vec<InitialContextSetupResponseProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum InitialUEMessageProtocolIEs_EntryValue {
     #[asn(key = 3)]
@@ -9846,7 +10699,7 @@ pub enum InitialUEMessageProtocolIEs_EntryValue {
     Id_UserLocationInformation(UserLocationInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct InitialUEMessageProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -9855,7 +10708,7 @@ pub struct InitialUEMessageProtocolIEs_Entry {
     pub value: InitialUEMessageProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9864,7 +10717,7 @@ pub struct InitialUEMessageProtocolIEs_Entry {
 )]
 pub struct InitialUEMessageProtocolIEs(pub Vec<InitialUEMessageProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum InitiatingMessageValue {
     #[asn(key = 64)]
@@ -10001,11 +10854,11 @@ pub enum InitiatingMessageValue {
     Id_WriteReplaceWarning(WriteReplaceWarningRequest),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct InterSystemFailureIndicationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10016,11 +10869,11 @@ pub struct InterSystemFailureIndicationIE_Extensions(
     pub Vec<InterSystemFailureIndicationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct InterSystemHOReportIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10029,15 +10882,15 @@ pub struct InterSystemHOReportIE_Extensions_Entry {}
 )]
 pub struct InterSystemHOReportIE_Extensions(pub Vec<InterSystemHOReportIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct InterSystemHandoverReportTypechoice_Extensions {}
+pub struct InterSystemHandoverReportType_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct IntersystemSONConfigurationTransferIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10048,19 +10901,19 @@ pub struct IntersystemSONConfigurationTransferIE_Extensions(
     pub Vec<IntersystemSONConfigurationTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct IntersystemSONInformationchoice_Extensions {}
+pub struct IntersystemSONInformation_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct IntersystemSONInformationReportchoice_Extensions {}
+pub struct IntersystemSONInformationReport_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct IntersystemSONNGRANnodeIDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10071,15 +10924,15 @@ pub struct IntersystemSONNGRANnodeIDIE_Extensions(
     pub Vec<IntersystemSONNGRANnodeIDIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct IntersystemSONTransferTypechoice_Extensions {}
+pub struct IntersystemSONTransferType_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct IntersystemSONeNBIDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10088,19 +10941,19 @@ pub struct IntersystemSONeNBIDIE_Extensions_Entry {}
 )]
 pub struct IntersystemSONeNBIDIE_Extensions(pub Vec<IntersystemSONeNBIDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
-pub struct ENUMERATED_29(pub u8);
-impl ENUMERATED_29 {
+pub struct IntersystemUnnecessaryHOEarlyIRATHO(pub u8);
+impl IntersystemUnnecessaryHOEarlyIRATHO {
     pub const TRUE: u8 = 0u8;
     pub const FALSE: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct IntersystemUnnecessaryHOIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10111,11 +10964,11 @@ pub struct IntersystemUnnecessaryHOIE_Extensions(
     pub Vec<IntersystemUnnecessaryHOIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LAIIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10124,11 +10977,11 @@ pub struct LAIIE_Extensions_Entry {}
 )]
 pub struct LAIIE_Extensions(pub Vec<LAIIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LTEUESidelinkAggregateMaximumBitrateIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10139,11 +10992,11 @@ pub struct LTEUESidelinkAggregateMaximumBitrateIE_Extensions(
     pub Vec<LTEUESidelinkAggregateMaximumBitrateIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LTEV2XServicesAuthorizedIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10154,15 +11007,15 @@ pub struct LTEV2XServicesAuthorizedIE_Extensions(
     pub Vec<LTEV2XServicesAuthorizedIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct LastVisitedCellInformationchoice_Extensions {}
+pub struct LastVisitedCellInformation_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LastVisitedCellItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10171,11 +11024,11 @@ pub struct LastVisitedCellItemIE_Extensions_Entry {}
 )]
 pub struct LastVisitedCellItemIE_Extensions(pub Vec<LastVisitedCellItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LastVisitedNGRANCellInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10186,7 +11039,7 @@ pub struct LastVisitedNGRANCellInformationIE_Extensions(
     pub Vec<LastVisitedNGRANCellInformationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum LocationReportProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -10201,7 +11054,7 @@ pub enum LocationReportProtocolIEs_EntryValue {
     Id_UserLocationInformation(UserLocationInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LocationReportProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -10210,7 +11063,7 @@ pub struct LocationReportProtocolIEs_Entry {
     pub value: LocationReportProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10219,7 +11072,7 @@ pub struct LocationReportProtocolIEs_Entry {
 )]
 pub struct LocationReportProtocolIEs(pub Vec<LocationReportProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum LocationReportingControlProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -10230,7 +11083,7 @@ pub enum LocationReportingControlProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LocationReportingControlProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -10239,7 +11092,7 @@ pub struct LocationReportingControlProtocolIEs_Entry {
     pub value: LocationReportingControlProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10248,7 +11101,7 @@ pub struct LocationReportingControlProtocolIEs_Entry {
 )]
 pub struct LocationReportingControlProtocolIEs(pub Vec<LocationReportingControlProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum LocationReportingFailureIndicationProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -10259,7 +11112,7 @@ pub enum LocationReportingFailureIndicationProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LocationReportingFailureIndicationProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -10268,7 +11121,7 @@ pub struct LocationReportingFailureIndicationProtocolIEs_Entry {
     pub value: LocationReportingFailureIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10279,14 +11132,14 @@ pub struct LocationReportingFailureIndicationProtocolIEs(
     pub Vec<LocationReportingFailureIndicationProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum LocationReportingRequestTypeIE_Extensions_EntryExtensionValue {
     #[asn(key = 170)]
     Id_LocationReportingAdditionalInfo(LocationReportingAdditionalInfo),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LocationReportingRequestTypeIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -10295,7 +11148,7 @@ pub struct LocationReportingRequestTypeIE_Extensions_Entry {
     pub extension_value: LocationReportingRequestTypeIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10306,11 +11159,11 @@ pub struct LocationReportingRequestTypeIE_Extensions(
     pub Vec<LocationReportingRequestTypeIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LoggedMDTNrIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10319,19 +11172,19 @@ pub struct LoggedMDTNrIE_Extensions_Entry {}
 )]
 pub struct LoggedMDTNrIE_Extensions(pub Vec<LoggedMDTNrIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "NULL")]
-pub struct NULL_30;
+pub struct LoggedMDTTrigger_periodical;
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct LoggedMDTTriggerchoice_Extensions {}
+pub struct LoggedMDTTrigger_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct M1ConfigurationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10340,11 +11193,11 @@ pub struct M1ConfigurationIE_Extensions_Entry {}
 )]
 pub struct M1ConfigurationIE_Extensions(pub Vec<M1ConfigurationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct M1PeriodicReportingIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10353,11 +11206,11 @@ pub struct M1PeriodicReportingIE_Extensions_Entry {}
 )]
 pub struct M1PeriodicReportingIE_Extensions(pub Vec<M1PeriodicReportingIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct M1ThresholdEventA2IE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10366,15 +11219,15 @@ pub struct M1ThresholdEventA2IE_Extensions_Entry {}
 )]
 pub struct M1ThresholdEventA2IE_Extensions(pub Vec<M1ThresholdEventA2IE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct M1ThresholdTypechoice_Extensions {}
+pub struct M1ThresholdType_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct M4ConfigurationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10383,11 +11236,11 @@ pub struct M4ConfigurationIE_Extensions_Entry {}
 )]
 pub struct M4ConfigurationIE_Extensions(pub Vec<M4ConfigurationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct M5ConfigurationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10396,11 +11249,11 @@ pub struct M5ConfigurationIE_Extensions_Entry {}
 )]
 pub struct M5ConfigurationIE_Extensions(pub Vec<M5ConfigurationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct M6ConfigurationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10409,11 +11262,11 @@ pub struct M6ConfigurationIE_Extensions_Entry {}
 )]
 pub struct M6ConfigurationIE_Extensions(pub Vec<M6ConfigurationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct M7ConfigurationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10422,11 +11275,11 @@ pub struct M7ConfigurationIE_Extensions_Entry {}
 )]
 pub struct M7ConfigurationIE_Extensions(pub Vec<M7ConfigurationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MDT_ConfigurationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10435,11 +11288,11 @@ pub struct MDT_ConfigurationIE_Extensions_Entry {}
 )]
 pub struct MDT_ConfigurationIE_Extensions(pub Vec<MDT_ConfigurationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MDT_Configuration_EUTRAIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10450,11 +11303,11 @@ pub struct MDT_Configuration_EUTRAIE_Extensions(
     pub Vec<MDT_Configuration_EUTRAIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MDT_Configuration_NRIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10463,11 +11316,11 @@ pub struct MDT_Configuration_NRIE_Extensions_Entry {}
 )]
 pub struct MDT_Configuration_NRIE_Extensions(pub Vec<MDT_Configuration_NRIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MDT_Location_InfoIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10476,15 +11329,15 @@ pub struct MDT_Location_InfoIE_Extensions_Entry {}
 )]
 pub struct MDT_Location_InfoIE_Extensions(pub Vec<MDT_Location_InfoIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct MDTModeNrchoice_Extensions {}
+pub struct MDTModeNr_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct MeasurementThresholdL1LoggedMDTchoice_Extensions {}
+pub struct MeasurementThresholdL1LoggedMDT_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum MobilityRestrictionListIE_Extensions_EntryExtensionValue {
     #[asn(key = 160)]
@@ -10497,7 +11350,7 @@ pub enum MobilityRestrictionListIE_Extensions_EntryExtensionValue {
     Id_NPN_MobilityInformation(NPN_MobilityInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MobilityRestrictionListIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -10506,7 +11359,7 @@ pub struct MobilityRestrictionListIE_Extensions_Entry {
     pub extension_value: MobilityRestrictionListIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10517,15 +11370,15 @@ pub struct MobilityRestrictionListIE_Extensions(
     pub Vec<MobilityRestrictionListIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "16", sz_ub = "16")]
-pub struct BIT_STRING_31(pub BitVec<u8, Msb0>);
+pub struct N3IWF_ID_n3IWF_ID(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct N3IWF_IDchoice_Extensions {}
+pub struct N3IWF_ID_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum NASNonDeliveryIndicationProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -10538,7 +11391,7 @@ pub enum NASNonDeliveryIndicationProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NASNonDeliveryIndicationProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -10547,7 +11400,7 @@ pub struct NASNonDeliveryIndicationProtocolIEs_Entry {
     pub value: NASNonDeliveryIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10556,11 +11409,11 @@ pub struct NASNonDeliveryIndicationProtocolIEs_Entry {
 )]
 pub struct NASNonDeliveryIndicationProtocolIEs(pub Vec<NASNonDeliveryIndicationProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NB_IoT_Paging_eDRXInfoIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10569,15 +11422,15 @@ pub struct NB_IoT_Paging_eDRXInfoIE_Extensions_Entry {}
 )]
 pub struct NB_IoT_Paging_eDRXInfoIE_Extensions(pub Vec<NB_IoT_Paging_eDRXInfoIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct NGRAN_CGIchoice_Extensions {}
+pub struct NGRAN_CGI_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NGRAN_TNLAssociationToRemoveItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10588,7 +11441,7 @@ pub struct NGRAN_TNLAssociationToRemoveItemIE_Extensions(
     pub Vec<NGRAN_TNLAssociationToRemoveItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum NGResetProtocolIEs_EntryValue {
     #[asn(key = 15)]
@@ -10597,7 +11450,7 @@ pub enum NGResetProtocolIEs_EntryValue {
     Id_ResetType(ResetType),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NGResetProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -10606,7 +11459,7 @@ pub struct NGResetProtocolIEs_Entry {
     pub value: NGResetProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10615,7 +11468,7 @@ pub struct NGResetProtocolIEs_Entry {
 )]
 pub struct NGResetProtocolIEs(pub Vec<NGResetProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum NGResetAcknowledgeProtocolIEs_EntryValue {
     #[asn(key = 19)]
@@ -10624,7 +11477,7 @@ pub enum NGResetAcknowledgeProtocolIEs_EntryValue {
     Id_UE_associatedLogicalNG_connectionList(UE_associatedLogicalNG_connectionList),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NGResetAcknowledgeProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -10633,7 +11486,7 @@ pub struct NGResetAcknowledgeProtocolIEs_Entry {
     pub value: NGResetAcknowledgeProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10642,7 +11495,7 @@ pub struct NGResetAcknowledgeProtocolIEs_Entry {
 )]
 pub struct NGResetAcknowledgeProtocolIEs(pub Vec<NGResetAcknowledgeProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum NGSetupFailureProtocolIEs_EntryValue {
     #[asn(key = 15)]
@@ -10653,7 +11506,7 @@ pub enum NGSetupFailureProtocolIEs_EntryValue {
     Id_TimeToWait(TimeToWait),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NGSetupFailureProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -10662,7 +11515,7 @@ pub struct NGSetupFailureProtocolIEs_Entry {
     pub value: NGSetupFailureProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10671,7 +11524,7 @@ pub struct NGSetupFailureProtocolIEs_Entry {
 )]
 pub struct NGSetupFailureProtocolIEs(pub Vec<NGSetupFailureProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum NGSetupRequestProtocolIEs_EntryValue {
     #[asn(key = 21)]
@@ -10690,7 +11543,7 @@ pub enum NGSetupRequestProtocolIEs_EntryValue {
     Id_UERetentionInformation(UERetentionInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NGSetupRequestProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -10699,7 +11552,7 @@ pub struct NGSetupRequestProtocolIEs_Entry {
     pub value: NGSetupRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10708,7 +11561,7 @@ pub struct NGSetupRequestProtocolIEs_Entry {
 )]
 pub struct NGSetupRequestProtocolIEs(pub Vec<NGSetupRequestProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum NGSetupResponseProtocolIEs_EntryValue {
     #[asn(key = 1)]
@@ -10729,7 +11582,7 @@ pub enum NGSetupResponseProtocolIEs_EntryValue {
     Id_UERetentionInformation(UERetentionInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NGSetupResponseProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -10738,7 +11591,7 @@ pub struct NGSetupResponseProtocolIEs_Entry {
     pub value: NGSetupResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10747,27 +11600,27 @@ pub struct NGSetupResponseProtocolIEs_Entry {
 )]
 pub struct NGSetupResponseProtocolIEs(pub Vec<NGSetupResponseProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct NPN_AccessInformationchoice_Extensions {}
+pub struct NPN_AccessInformation_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct NPN_MobilityInformationchoice_Extensions {}
+pub struct NPN_MobilityInformation_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct NPN_PagingAssistanceInformationchoice_Extensions {}
+pub struct NPN_PagingAssistanceInformation_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct NPN_Supportchoice_Extensions {}
+pub struct NPN_Support_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NR_CGIIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10776,11 +11629,11 @@ pub struct NR_CGIIE_Extensions_Entry {}
 )]
 pub struct NR_CGIIE_Extensions(pub Vec<NR_CGIIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NRFrequencyBandItemIE_Extension_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10789,11 +11642,11 @@ pub struct NRFrequencyBandItemIE_Extension_Entry {}
 )]
 pub struct NRFrequencyBandItemIE_Extension(pub Vec<NRFrequencyBandItemIE_Extension_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NRFrequencyInfoIE_Extension_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10802,11 +11655,11 @@ pub struct NRFrequencyInfoIE_Extension_Entry {}
 )]
 pub struct NRFrequencyInfoIE_Extension(pub Vec<NRFrequencyInfoIE_Extension_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NRUESidelinkAggregateMaximumBitrateIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10817,11 +11670,11 @@ pub struct NRUESidelinkAggregateMaximumBitrateIE_Extensions(
     pub Vec<NRUESidelinkAggregateMaximumBitrateIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NRV2XServicesAuthorizedIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10832,23 +11685,23 @@ pub struct NRV2XServicesAuthorizedIE_Extensions(
     pub Vec<NRV2XServicesAuthorizedIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "20", sz_ub = "20")]
-pub struct BIT_STRING_32(pub BitVec<u8, Msb0>);
+pub struct NgENB_ID_macroNgENB_ID(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "18", sz_ub = "18")]
-pub struct BIT_STRING_33(pub BitVec<u8, Msb0>);
+pub struct NgENB_ID_shortMacroNgENB_ID(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "21", sz_ub = "21")]
-pub struct BIT_STRING_34(pub BitVec<u8, Msb0>);
+pub struct NgENB_ID_longMacroNgENB_ID(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct NgENB_IDchoice_Extensions {}
+pub struct NgENB_ID_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum NonDynamic5QIDescriptorIE_Extensions_EntryExtensionValue {
     #[asn(key = 187)]
@@ -10857,7 +11710,7 @@ pub enum NonDynamic5QIDescriptorIE_Extensions_EntryExtensionValue {
     Id_CNPacketDelayBudgetUL(ExtendedPacketDelayBudget),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NonDynamic5QIDescriptorIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -10866,7 +11719,7 @@ pub struct NonDynamic5QIDescriptorIE_Extensions_Entry {
     pub extension_value: NonDynamic5QIDescriptorIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10877,11 +11730,11 @@ pub struct NonDynamic5QIDescriptorIE_Extensions(
     pub Vec<NonDynamic5QIDescriptorIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct OverloadResponsechoice_Extensions {}
+pub struct OverloadResponse_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum OverloadStartProtocolIEs_EntryValue {
     #[asn(key = 2)]
@@ -10892,7 +11745,7 @@ pub enum OverloadStartProtocolIEs_EntryValue {
     Id_OverloadStartNSSAIList(OverloadStartNSSAIList),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct OverloadStartProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -10901,7 +11754,7 @@ pub struct OverloadStartProtocolIEs_Entry {
     pub value: OverloadStartProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10910,11 +11763,11 @@ pub struct OverloadStartProtocolIEs_Entry {
 )]
 pub struct OverloadStartProtocolIEs(pub Vec<OverloadStartProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct OverloadStartNSSAIItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10923,11 +11776,11 @@ pub struct OverloadStartNSSAIItemIE_Extensions_Entry {}
 )]
 pub struct OverloadStartNSSAIItemIE_Extensions(pub Vec<OverloadStartNSSAIItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct OverloadStopProtocolIEs_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10936,11 +11789,11 @@ pub struct OverloadStopProtocolIEs_Entry {}
 )]
 pub struct OverloadStopProtocolIEs(pub Vec<OverloadStopProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PC5FlowBitRatesIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10949,11 +11802,11 @@ pub struct PC5FlowBitRatesIE_Extensions_Entry {}
 )]
 pub struct PC5FlowBitRatesIE_Extensions(pub Vec<PC5FlowBitRatesIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PC5QoSFlowItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10962,11 +11815,11 @@ pub struct PC5QoSFlowItemIE_Extensions_Entry {}
 )]
 pub struct PC5QoSFlowItemIE_Extensions(pub Vec<PC5QoSFlowItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PC5QoSParametersIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10975,11 +11828,11 @@ pub struct PC5QoSParametersIE_Extensions_Entry {}
 )]
 pub struct PC5QoSParametersIE_Extensions(pub Vec<PC5QoSParametersIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionAggregateMaximumBitRateIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10990,15 +11843,30 @@ pub struct PDUSessionAggregateMaximumBitRateIE_Extensions(
     pub Vec<PDUSessionAggregateMaximumBitRateIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
-pub struct OCTET_STRING_35(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+pub struct PDUSessionResourceAdmittedItemHandoverRequestAcknowledgeTransfer(pub Vec<u8>);
+impl PDUSessionResourceAdmittedItemHandoverRequestAcknowledgeTransfer {
+    pub fn aper_decode_contents(
+        &self,
+    ) -> Result<HandoverRequestAcknowledgeTransfer, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::from_slice_aper(&self.0);
+        <HandoverRequestAcknowledgeTransfer as asn1_codecs::aper::AperCodec>::aper_decode(&mut data)
+    }
+    pub fn aper_encode_contents(
+        contents: &HandoverRequestAcknowledgeTransfer,
+    ) -> Result<Self, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::new_aper();
+        asn1_codecs::aper::AperCodec::aper_encode(contents, &mut data)?;
+        Ok(Self(data.into_bytes()))
+    }
+}
+
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceAdmittedItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11009,15 +11877,35 @@ pub struct PDUSessionResourceAdmittedItemIE_Extensions(
     pub Vec<PDUSessionResourceAdmittedItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
-pub struct OCTET_STRING_36(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+pub struct PDUSessionResourceFailedToModifyItemModCfmPDUSessionResourceModifyIndicationUnsuccessfulTransfer(
+    pub Vec<u8>,
+);
+impl
+    PDUSessionResourceFailedToModifyItemModCfmPDUSessionResourceModifyIndicationUnsuccessfulTransfer
+{
+    pub fn aper_decode_contents(
+        &self,
+    ) -> Result<PDUSessionResourceModifyIndicationUnsuccessfulTransfer, asn1_codecs::PerCodecError>
+    {
+        let mut data = asn1_codecs::PerCodecData::from_slice_aper(&self.0);
+        < PDUSessionResourceModifyIndicationUnsuccessfulTransfer as asn1_codecs :: aper :: AperCodec > :: aper_decode (& mut data)
+    }
+    pub fn aper_encode_contents(
+        contents: &PDUSessionResourceModifyIndicationUnsuccessfulTransfer,
+    ) -> Result<Self, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::new_aper();
+        asn1_codecs::aper::AperCodec::aper_encode(contents, &mut data)?;
+        Ok(Self(data.into_bytes()))
+    }
+}
+
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceFailedToModifyItemModCfmIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11028,15 +11916,34 @@ pub struct PDUSessionResourceFailedToModifyItemModCfmIE_Extensions(
     pub Vec<PDUSessionResourceFailedToModifyItemModCfmIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
-pub struct OCTET_STRING_37(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+pub struct PDUSessionResourceFailedToModifyItemModResPDUSessionResourceModifyUnsuccessfulTransfer(
+    pub Vec<u8>,
+);
+impl PDUSessionResourceFailedToModifyItemModResPDUSessionResourceModifyUnsuccessfulTransfer {
+    pub fn aper_decode_contents(
+        &self,
+    ) -> Result<PDUSessionResourceModifyUnsuccessfulTransfer, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::from_slice_aper(&self.0);
+        <PDUSessionResourceModifyUnsuccessfulTransfer as asn1_codecs::aper::AperCodec>::aper_decode(
+            &mut data,
+        )
+    }
+    pub fn aper_encode_contents(
+        contents: &PDUSessionResourceModifyUnsuccessfulTransfer,
+    ) -> Result<Self, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::new_aper();
+        asn1_codecs::aper::AperCodec::aper_encode(contents, &mut data)?;
+        Ok(Self(data.into_bytes()))
+    }
+}
+
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceFailedToModifyItemModResIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11047,11 +11954,11 @@ pub struct PDUSessionResourceFailedToModifyItemModResIE_Extensions(
     pub Vec<PDUSessionResourceFailedToModifyItemModResIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceFailedToResumeItemRESReqIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11062,11 +11969,11 @@ pub struct PDUSessionResourceFailedToResumeItemRESReqIE_Extensions(
     pub Vec<PDUSessionResourceFailedToResumeItemRESReqIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceFailedToResumeItemRESResIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11077,15 +11984,34 @@ pub struct PDUSessionResourceFailedToResumeItemRESResIE_Extensions(
     pub Vec<PDUSessionResourceFailedToResumeItemRESResIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
-pub struct OCTET_STRING_38(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+pub struct PDUSessionResourceFailedToSetupItemCxtFailPDUSessionResourceSetupUnsuccessfulTransfer(
+    pub Vec<u8>,
+);
+impl PDUSessionResourceFailedToSetupItemCxtFailPDUSessionResourceSetupUnsuccessfulTransfer {
+    pub fn aper_decode_contents(
+        &self,
+    ) -> Result<PDUSessionResourceSetupUnsuccessfulTransfer, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::from_slice_aper(&self.0);
+        <PDUSessionResourceSetupUnsuccessfulTransfer as asn1_codecs::aper::AperCodec>::aper_decode(
+            &mut data,
+        )
+    }
+    pub fn aper_encode_contents(
+        contents: &PDUSessionResourceSetupUnsuccessfulTransfer,
+    ) -> Result<Self, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::new_aper();
+        asn1_codecs::aper::AperCodec::aper_encode(contents, &mut data)?;
+        Ok(Self(data.into_bytes()))
+    }
+}
+
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceFailedToSetupItemCxtFailIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11096,15 +12022,34 @@ pub struct PDUSessionResourceFailedToSetupItemCxtFailIE_Extensions(
     pub Vec<PDUSessionResourceFailedToSetupItemCxtFailIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
-pub struct OCTET_STRING_39(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+pub struct PDUSessionResourceFailedToSetupItemCxtResPDUSessionResourceSetupUnsuccessfulTransfer(
+    pub Vec<u8>,
+);
+impl PDUSessionResourceFailedToSetupItemCxtResPDUSessionResourceSetupUnsuccessfulTransfer {
+    pub fn aper_decode_contents(
+        &self,
+    ) -> Result<PDUSessionResourceSetupUnsuccessfulTransfer, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::from_slice_aper(&self.0);
+        <PDUSessionResourceSetupUnsuccessfulTransfer as asn1_codecs::aper::AperCodec>::aper_decode(
+            &mut data,
+        )
+    }
+    pub fn aper_encode_contents(
+        contents: &PDUSessionResourceSetupUnsuccessfulTransfer,
+    ) -> Result<Self, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::new_aper();
+        asn1_codecs::aper::AperCodec::aper_encode(contents, &mut data)?;
+        Ok(Self(data.into_bytes()))
+    }
+}
+
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceFailedToSetupItemCxtResIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11115,15 +12060,32 @@ pub struct PDUSessionResourceFailedToSetupItemCxtResIE_Extensions(
     pub Vec<PDUSessionResourceFailedToSetupItemCxtResIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
-pub struct OCTET_STRING_40(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+pub struct PDUSessionResourceFailedToSetupItemHOAckHandoverResourceAllocationUnsuccessfulTransfer(
+    pub Vec<u8>,
+);
+impl PDUSessionResourceFailedToSetupItemHOAckHandoverResourceAllocationUnsuccessfulTransfer {
+    pub fn aper_decode_contents(
+        &self,
+    ) -> Result<HandoverResourceAllocationUnsuccessfulTransfer, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::from_slice_aper(&self.0);
+        < HandoverResourceAllocationUnsuccessfulTransfer as asn1_codecs :: aper :: AperCodec > :: aper_decode (& mut data)
+    }
+    pub fn aper_encode_contents(
+        contents: &HandoverResourceAllocationUnsuccessfulTransfer,
+    ) -> Result<Self, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::new_aper();
+        asn1_codecs::aper::AperCodec::aper_encode(contents, &mut data)?;
+        Ok(Self(data.into_bytes()))
+    }
+}
+
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceFailedToSetupItemHOAckIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11134,15 +12096,34 @@ pub struct PDUSessionResourceFailedToSetupItemHOAckIE_Extensions(
     pub Vec<PDUSessionResourceFailedToSetupItemHOAckIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
-pub struct OCTET_STRING_41(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+pub struct PDUSessionResourceFailedToSetupItemPSReqPathSwitchRequestSetupFailedTransfer(
+    pub Vec<u8>,
+);
+impl PDUSessionResourceFailedToSetupItemPSReqPathSwitchRequestSetupFailedTransfer {
+    pub fn aper_decode_contents(
+        &self,
+    ) -> Result<PathSwitchRequestSetupFailedTransfer, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::from_slice_aper(&self.0);
+        <PathSwitchRequestSetupFailedTransfer as asn1_codecs::aper::AperCodec>::aper_decode(
+            &mut data,
+        )
+    }
+    pub fn aper_encode_contents(
+        contents: &PathSwitchRequestSetupFailedTransfer,
+    ) -> Result<Self, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::new_aper();
+        asn1_codecs::aper::AperCodec::aper_encode(contents, &mut data)?;
+        Ok(Self(data.into_bytes()))
+    }
+}
+
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceFailedToSetupItemPSReqIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11153,15 +12134,34 @@ pub struct PDUSessionResourceFailedToSetupItemPSReqIE_Extensions(
     pub Vec<PDUSessionResourceFailedToSetupItemPSReqIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
-pub struct OCTET_STRING_42(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+pub struct PDUSessionResourceFailedToSetupItemSUResPDUSessionResourceSetupUnsuccessfulTransfer(
+    pub Vec<u8>,
+);
+impl PDUSessionResourceFailedToSetupItemSUResPDUSessionResourceSetupUnsuccessfulTransfer {
+    pub fn aper_decode_contents(
+        &self,
+    ) -> Result<PDUSessionResourceSetupUnsuccessfulTransfer, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::from_slice_aper(&self.0);
+        <PDUSessionResourceSetupUnsuccessfulTransfer as asn1_codecs::aper::AperCodec>::aper_decode(
+            &mut data,
+        )
+    }
+    pub fn aper_encode_contents(
+        contents: &PDUSessionResourceSetupUnsuccessfulTransfer,
+    ) -> Result<Self, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::new_aper();
+        asn1_codecs::aper::AperCodec::aper_encode(contents, &mut data)?;
+        Ok(Self(data.into_bytes()))
+    }
+}
+
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceFailedToSetupItemSUResIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11172,15 +12172,30 @@ pub struct PDUSessionResourceFailedToSetupItemSUResIE_Extensions(
     pub Vec<PDUSessionResourceFailedToSetupItemSUResIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
-pub struct OCTET_STRING_43(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+pub struct PDUSessionResourceHandoverItemHandoverCommandTransfer(pub Vec<u8>);
+impl PDUSessionResourceHandoverItemHandoverCommandTransfer {
+    pub fn aper_decode_contents(
+        &self,
+    ) -> Result<HandoverCommandTransfer, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::from_slice_aper(&self.0);
+        <HandoverCommandTransfer as asn1_codecs::aper::AperCodec>::aper_decode(&mut data)
+    }
+    pub fn aper_encode_contents(
+        contents: &HandoverCommandTransfer,
+    ) -> Result<Self, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::new_aper();
+        asn1_codecs::aper::AperCodec::aper_encode(contents, &mut data)?;
+        Ok(Self(data.into_bytes()))
+    }
+}
+
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceHandoverItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11191,11 +12206,11 @@ pub struct PDUSessionResourceHandoverItemIE_Extensions(
     pub Vec<PDUSessionResourceHandoverItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceInformationItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11206,11 +12221,11 @@ pub struct PDUSessionResourceInformationItemIE_Extensions(
     pub Vec<PDUSessionResourceInformationItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum PDUSessionResourceItemCxtRelCplIE_Extensions_EntryExtensionValue {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceItemCxtRelCplIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -11219,7 +12234,7 @@ pub struct PDUSessionResourceItemCxtRelCplIE_Extensions_Entry {
     pub extension_value: PDUSessionResourceItemCxtRelCplIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11230,11 +12245,11 @@ pub struct PDUSessionResourceItemCxtRelCplIE_Extensions(
     pub Vec<PDUSessionResourceItemCxtRelCplIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceItemCxtRelReqIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11245,15 +12260,30 @@ pub struct PDUSessionResourceItemCxtRelReqIE_Extensions(
     pub Vec<PDUSessionResourceItemCxtRelReqIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
-pub struct OCTET_STRING_44(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+pub struct PDUSessionResourceItemHORqdHandoverRequiredTransfer(pub Vec<u8>);
+impl PDUSessionResourceItemHORqdHandoverRequiredTransfer {
+    pub fn aper_decode_contents(
+        &self,
+    ) -> Result<HandoverRequiredTransfer, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::from_slice_aper(&self.0);
+        <HandoverRequiredTransfer as asn1_codecs::aper::AperCodec>::aper_decode(&mut data)
+    }
+    pub fn aper_encode_contents(
+        contents: &HandoverRequiredTransfer,
+    ) -> Result<Self, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::new_aper();
+        asn1_codecs::aper::AperCodec::aper_encode(contents, &mut data)?;
+        Ok(Self(data.into_bytes()))
+    }
+}
+
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceItemHORqdIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11264,7 +12294,7 @@ pub struct PDUSessionResourceItemHORqdIE_Extensions(
     pub Vec<PDUSessionResourceItemHORqdIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum PDUSessionResourceModifyConfirmProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -11279,7 +12309,7 @@ pub enum PDUSessionResourceModifyConfirmProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceModifyConfirmProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -11288,7 +12318,7 @@ pub struct PDUSessionResourceModifyConfirmProtocolIEs_Entry {
     pub value: PDUSessionResourceModifyConfirmProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11299,7 +12329,7 @@ pub struct PDUSessionResourceModifyConfirmProtocolIEs(
     pub Vec<PDUSessionResourceModifyConfirmProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum PDUSessionResourceModifyConfirmTransferIE_Extensions_EntryExtensionValue {
     #[asn(key = 185)]
@@ -11308,7 +12338,7 @@ pub enum PDUSessionResourceModifyConfirmTransferIE_Extensions_EntryExtensionValu
     Id_RedundantUL_NGU_UP_TNLInformation(UPTransportLayerInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceModifyConfirmTransferIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -11317,7 +12347,7 @@ pub struct PDUSessionResourceModifyConfirmTransferIE_Extensions_Entry {
     pub extension_value: PDUSessionResourceModifyConfirmTransferIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11328,7 +12358,7 @@ pub struct PDUSessionResourceModifyConfirmTransferIE_Extensions(
     pub Vec<PDUSessionResourceModifyConfirmTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum PDUSessionResourceModifyIndicationProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -11341,7 +12371,7 @@ pub enum PDUSessionResourceModifyIndicationProtocolIEs_EntryValue {
     Id_UserLocationInformation(UserLocationInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceModifyIndicationProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -11350,7 +12380,7 @@ pub struct PDUSessionResourceModifyIndicationProtocolIEs_Entry {
     pub value: PDUSessionResourceModifyIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11361,7 +12391,7 @@ pub struct PDUSessionResourceModifyIndicationProtocolIEs(
     pub Vec<PDUSessionResourceModifyIndicationProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum PDUSessionResourceModifyIndicationTransferIE_Extensions_EntryExtensionValue {
     #[asn(key = 184)]
@@ -11376,7 +12406,7 @@ pub enum PDUSessionResourceModifyIndicationTransferIE_Extensions_EntryExtensionV
     Id_SecurityResult(SecurityResult),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceModifyIndicationTransferIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -11386,7 +12416,7 @@ pub struct PDUSessionResourceModifyIndicationTransferIE_Extensions_Entry {
         PDUSessionResourceModifyIndicationTransferIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11397,11 +12427,11 @@ pub struct PDUSessionResourceModifyIndicationTransferIE_Extensions(
     pub Vec<PDUSessionResourceModifyIndicationTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceModifyIndicationUnsuccessfulTransferIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11412,15 +12442,32 @@ pub struct PDUSessionResourceModifyIndicationUnsuccessfulTransferIE_Extensions(
     pub Vec<PDUSessionResourceModifyIndicationUnsuccessfulTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
-pub struct OCTET_STRING_45(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+pub struct PDUSessionResourceModifyItemModCfmPDUSessionResourceModifyConfirmTransfer(pub Vec<u8>);
+impl PDUSessionResourceModifyItemModCfmPDUSessionResourceModifyConfirmTransfer {
+    pub fn aper_decode_contents(
+        &self,
+    ) -> Result<PDUSessionResourceModifyConfirmTransfer, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::from_slice_aper(&self.0);
+        <PDUSessionResourceModifyConfirmTransfer as asn1_codecs::aper::AperCodec>::aper_decode(
+            &mut data,
+        )
+    }
+    pub fn aper_encode_contents(
+        contents: &PDUSessionResourceModifyConfirmTransfer,
+    ) -> Result<Self, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::new_aper();
+        asn1_codecs::aper::AperCodec::aper_encode(contents, &mut data)?;
+        Ok(Self(data.into_bytes()))
+    }
+}
+
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceModifyItemModCfmIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11431,15 +12478,34 @@ pub struct PDUSessionResourceModifyItemModCfmIE_Extensions(
     pub Vec<PDUSessionResourceModifyItemModCfmIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
-pub struct OCTET_STRING_46(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+pub struct PDUSessionResourceModifyItemModIndPDUSessionResourceModifyIndicationTransfer(
+    pub Vec<u8>,
+);
+impl PDUSessionResourceModifyItemModIndPDUSessionResourceModifyIndicationTransfer {
+    pub fn aper_decode_contents(
+        &self,
+    ) -> Result<PDUSessionResourceModifyIndicationTransfer, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::from_slice_aper(&self.0);
+        <PDUSessionResourceModifyIndicationTransfer as asn1_codecs::aper::AperCodec>::aper_decode(
+            &mut data,
+        )
+    }
+    pub fn aper_encode_contents(
+        contents: &PDUSessionResourceModifyIndicationTransfer,
+    ) -> Result<Self, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::new_aper();
+        asn1_codecs::aper::AperCodec::aper_encode(contents, &mut data)?;
+        Ok(Self(data.into_bytes()))
+    }
+}
+
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceModifyItemModIndIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11450,18 +12516,35 @@ pub struct PDUSessionResourceModifyItemModIndIE_Extensions(
     pub Vec<PDUSessionResourceModifyItemModIndIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
-pub struct OCTET_STRING_47(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+pub struct PDUSessionResourceModifyItemModReqPDUSessionResourceModifyRequestTransfer(pub Vec<u8>);
+impl PDUSessionResourceModifyItemModReqPDUSessionResourceModifyRequestTransfer {
+    pub fn aper_decode_contents(
+        &self,
+    ) -> Result<PDUSessionResourceModifyRequestTransfer, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::from_slice_aper(&self.0);
+        <PDUSessionResourceModifyRequestTransfer as asn1_codecs::aper::AperCodec>::aper_decode(
+            &mut data,
+        )
+    }
+    pub fn aper_encode_contents(
+        contents: &PDUSessionResourceModifyRequestTransfer,
+    ) -> Result<Self, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::new_aper();
+        asn1_codecs::aper::AperCodec::aper_encode(contents, &mut data)?;
+        Ok(Self(data.into_bytes()))
+    }
+}
+
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum PDUSessionResourceModifyItemModReqIE_Extensions_EntryExtensionValue {
     #[asn(key = 148)]
     Id_S_NSSAI(S_NSSAI),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceModifyItemModReqIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -11470,7 +12553,7 @@ pub struct PDUSessionResourceModifyItemModReqIE_Extensions_Entry {
     pub extension_value: PDUSessionResourceModifyItemModReqIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11481,15 +12564,32 @@ pub struct PDUSessionResourceModifyItemModReqIE_Extensions(
     pub Vec<PDUSessionResourceModifyItemModReqIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
-pub struct OCTET_STRING_48(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+pub struct PDUSessionResourceModifyItemModResPDUSessionResourceModifyResponseTransfer(pub Vec<u8>);
+impl PDUSessionResourceModifyItemModResPDUSessionResourceModifyResponseTransfer {
+    pub fn aper_decode_contents(
+        &self,
+    ) -> Result<PDUSessionResourceModifyResponseTransfer, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::from_slice_aper(&self.0);
+        <PDUSessionResourceModifyResponseTransfer as asn1_codecs::aper::AperCodec>::aper_decode(
+            &mut data,
+        )
+    }
+    pub fn aper_encode_contents(
+        contents: &PDUSessionResourceModifyResponseTransfer,
+    ) -> Result<Self, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::new_aper();
+        asn1_codecs::aper::AperCodec::aper_encode(contents, &mut data)?;
+        Ok(Self(data.into_bytes()))
+    }
+}
+
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceModifyItemModResIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11500,7 +12600,7 @@ pub struct PDUSessionResourceModifyItemModResIE_Extensions(
     pub Vec<PDUSessionResourceModifyItemModResIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum PDUSessionResourceModifyRequestProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -11513,7 +12613,7 @@ pub enum PDUSessionResourceModifyRequestProtocolIEs_EntryValue {
     Id_RANPagingPriority(RANPagingPriority),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceModifyRequestProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -11522,7 +12622,7 @@ pub struct PDUSessionResourceModifyRequestProtocolIEs_Entry {
     pub value: PDUSessionResourceModifyRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11533,7 +12633,7 @@ pub struct PDUSessionResourceModifyRequestProtocolIEs(
     pub Vec<PDUSessionResourceModifyRequestProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum PDUSessionResourceModifyRequestTransferProtocolIEs_EntryValue {
     #[asn(key = 186)]
@@ -11560,7 +12660,7 @@ pub enum PDUSessionResourceModifyRequestTransferProtocolIEs_EntryValue {
     Id_UL_NGU_UP_TNLModifyList(UL_NGU_UP_TNLModifyList),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceModifyRequestTransferProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -11569,7 +12669,7 @@ pub struct PDUSessionResourceModifyRequestTransferProtocolIEs_Entry {
     pub value: PDUSessionResourceModifyRequestTransferProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11580,7 +12680,7 @@ pub struct PDUSessionResourceModifyRequestTransferProtocolIEs(
     pub Vec<PDUSessionResourceModifyRequestTransferProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum PDUSessionResourceModifyResponseProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -11597,7 +12697,7 @@ pub enum PDUSessionResourceModifyResponseProtocolIEs_EntryValue {
     Id_UserLocationInformation(UserLocationInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceModifyResponseProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -11606,7 +12706,7 @@ pub struct PDUSessionResourceModifyResponseProtocolIEs_Entry {
     pub value: PDUSessionResourceModifyResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11617,7 +12717,7 @@ pub struct PDUSessionResourceModifyResponseProtocolIEs(
     pub Vec<PDUSessionResourceModifyResponseProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum PDUSessionResourceModifyResponseTransferIE_Extensions_EntryExtensionValue {
     #[asn(key = 154)]
@@ -11632,7 +12732,7 @@ pub enum PDUSessionResourceModifyResponseTransferIE_Extensions_EntryExtensionVal
     Id_RedundantUL_NGU_UP_TNLInformation(UPTransportLayerInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceModifyResponseTransferIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -11641,7 +12741,7 @@ pub struct PDUSessionResourceModifyResponseTransferIE_Extensions_Entry {
     pub extension_value: PDUSessionResourceModifyResponseTransferIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11652,11 +12752,11 @@ pub struct PDUSessionResourceModifyResponseTransferIE_Extensions(
     pub Vec<PDUSessionResourceModifyResponseTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceModifyUnsuccessfulTransferIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11667,7 +12767,7 @@ pub struct PDUSessionResourceModifyUnsuccessfulTransferIE_Extensions(
     pub Vec<PDUSessionResourceModifyUnsuccessfulTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum PDUSessionResourceNotifyProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -11682,7 +12782,7 @@ pub enum PDUSessionResourceNotifyProtocolIEs_EntryValue {
     Id_UserLocationInformation(UserLocationInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceNotifyProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -11691,7 +12791,7 @@ pub struct PDUSessionResourceNotifyProtocolIEs_Entry {
     pub value: PDUSessionResourceNotifyProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11700,15 +12800,30 @@ pub struct PDUSessionResourceNotifyProtocolIEs_Entry {
 )]
 pub struct PDUSessionResourceNotifyProtocolIEs(pub Vec<PDUSessionResourceNotifyProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
-pub struct OCTET_STRING_49(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+pub struct PDUSessionResourceNotifyItemPDUSessionResourceNotifyTransfer(pub Vec<u8>);
+impl PDUSessionResourceNotifyItemPDUSessionResourceNotifyTransfer {
+    pub fn aper_decode_contents(
+        &self,
+    ) -> Result<PDUSessionResourceNotifyTransfer, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::from_slice_aper(&self.0);
+        <PDUSessionResourceNotifyTransfer as asn1_codecs::aper::AperCodec>::aper_decode(&mut data)
+    }
+    pub fn aper_encode_contents(
+        contents: &PDUSessionResourceNotifyTransfer,
+    ) -> Result<Self, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::new_aper();
+        asn1_codecs::aper::AperCodec::aper_encode(contents, &mut data)?;
+        Ok(Self(data.into_bytes()))
+    }
+}
+
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceNotifyItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11719,14 +12834,14 @@ pub struct PDUSessionResourceNotifyItemIE_Extensions(
     pub Vec<PDUSessionResourceNotifyItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum PDUSessionResourceNotifyReleasedTransferIE_Extensions_EntryExtensionValue {
     #[asn(key = 144)]
     Id_SecondaryRATUsageInformation(SecondaryRATUsageInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceNotifyReleasedTransferIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -11735,7 +12850,7 @@ pub struct PDUSessionResourceNotifyReleasedTransferIE_Extensions_Entry {
     pub extension_value: PDUSessionResourceNotifyReleasedTransferIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11746,7 +12861,7 @@ pub struct PDUSessionResourceNotifyReleasedTransferIE_Extensions(
     pub Vec<PDUSessionResourceNotifyReleasedTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum PDUSessionResourceNotifyTransferIE_Extensions_EntryExtensionValue {
     #[asn(key = 278)]
@@ -11755,7 +12870,7 @@ pub enum PDUSessionResourceNotifyTransferIE_Extensions_EntryExtensionValue {
     Id_SecondaryRATUsageInformation(SecondaryRATUsageInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceNotifyTransferIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -11764,7 +12879,7 @@ pub struct PDUSessionResourceNotifyTransferIE_Extensions_Entry {
     pub extension_value: PDUSessionResourceNotifyTransferIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11775,7 +12890,7 @@ pub struct PDUSessionResourceNotifyTransferIE_Extensions(
     pub Vec<PDUSessionResourceNotifyTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum PDUSessionResourceReleaseCommandProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -11790,7 +12905,7 @@ pub enum PDUSessionResourceReleaseCommandProtocolIEs_EntryValue {
     Id_RANPagingPriority(RANPagingPriority),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceReleaseCommandProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -11799,7 +12914,7 @@ pub struct PDUSessionResourceReleaseCommandProtocolIEs_Entry {
     pub value: PDUSessionResourceReleaseCommandProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11810,11 +12925,11 @@ pub struct PDUSessionResourceReleaseCommandProtocolIEs(
     pub Vec<PDUSessionResourceReleaseCommandProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceReleaseCommandTransferIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11825,7 +12940,7 @@ pub struct PDUSessionResourceReleaseCommandTransferIE_Extensions(
     pub Vec<PDUSessionResourceReleaseCommandTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum PDUSessionResourceReleaseResponseProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -11840,7 +12955,7 @@ pub enum PDUSessionResourceReleaseResponseProtocolIEs_EntryValue {
     Id_UserLocationInformation(UserLocationInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceReleaseResponseProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -11849,7 +12964,7 @@ pub struct PDUSessionResourceReleaseResponseProtocolIEs_Entry {
     pub value: PDUSessionResourceReleaseResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11860,14 +12975,14 @@ pub struct PDUSessionResourceReleaseResponseProtocolIEs(
     pub Vec<PDUSessionResourceReleaseResponseProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum PDUSessionResourceReleaseResponseTransferIE_Extensions_EntryExtensionValue {
     #[asn(key = 144)]
     Id_SecondaryRATUsageInformation(SecondaryRATUsageInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceReleaseResponseTransferIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -11876,7 +12991,7 @@ pub struct PDUSessionResourceReleaseResponseTransferIE_Extensions_Entry {
     pub extension_value: PDUSessionResourceReleaseResponseTransferIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11887,15 +13002,32 @@ pub struct PDUSessionResourceReleaseResponseTransferIE_Extensions(
     pub Vec<PDUSessionResourceReleaseResponseTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
-pub struct OCTET_STRING_50(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+pub struct PDUSessionResourceReleasedItemNotPDUSessionResourceNotifyReleasedTransfer(pub Vec<u8>);
+impl PDUSessionResourceReleasedItemNotPDUSessionResourceNotifyReleasedTransfer {
+    pub fn aper_decode_contents(
+        &self,
+    ) -> Result<PDUSessionResourceNotifyReleasedTransfer, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::from_slice_aper(&self.0);
+        <PDUSessionResourceNotifyReleasedTransfer as asn1_codecs::aper::AperCodec>::aper_decode(
+            &mut data,
+        )
+    }
+    pub fn aper_encode_contents(
+        contents: &PDUSessionResourceNotifyReleasedTransfer,
+    ) -> Result<Self, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::new_aper();
+        asn1_codecs::aper::AperCodec::aper_encode(contents, &mut data)?;
+        Ok(Self(data.into_bytes()))
+    }
+}
+
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceReleasedItemNotIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11906,15 +13038,32 @@ pub struct PDUSessionResourceReleasedItemNotIE_Extensions(
     pub Vec<PDUSessionResourceReleasedItemNotIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
-pub struct OCTET_STRING_51(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+pub struct PDUSessionResourceReleasedItemPSAckPathSwitchRequestUnsuccessfulTransfer(pub Vec<u8>);
+impl PDUSessionResourceReleasedItemPSAckPathSwitchRequestUnsuccessfulTransfer {
+    pub fn aper_decode_contents(
+        &self,
+    ) -> Result<PathSwitchRequestUnsuccessfulTransfer, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::from_slice_aper(&self.0);
+        <PathSwitchRequestUnsuccessfulTransfer as asn1_codecs::aper::AperCodec>::aper_decode(
+            &mut data,
+        )
+    }
+    pub fn aper_encode_contents(
+        contents: &PathSwitchRequestUnsuccessfulTransfer,
+    ) -> Result<Self, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::new_aper();
+        asn1_codecs::aper::AperCodec::aper_encode(contents, &mut data)?;
+        Ok(Self(data.into_bytes()))
+    }
+}
+
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceReleasedItemPSAckIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11925,15 +13074,32 @@ pub struct PDUSessionResourceReleasedItemPSAckIE_Extensions(
     pub Vec<PDUSessionResourceReleasedItemPSAckIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
-pub struct OCTET_STRING_52(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+pub struct PDUSessionResourceReleasedItemPSFailPathSwitchRequestUnsuccessfulTransfer(pub Vec<u8>);
+impl PDUSessionResourceReleasedItemPSFailPathSwitchRequestUnsuccessfulTransfer {
+    pub fn aper_decode_contents(
+        &self,
+    ) -> Result<PathSwitchRequestUnsuccessfulTransfer, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::from_slice_aper(&self.0);
+        <PathSwitchRequestUnsuccessfulTransfer as asn1_codecs::aper::AperCodec>::aper_decode(
+            &mut data,
+        )
+    }
+    pub fn aper_encode_contents(
+        contents: &PathSwitchRequestUnsuccessfulTransfer,
+    ) -> Result<Self, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::new_aper();
+        asn1_codecs::aper::AperCodec::aper_encode(contents, &mut data)?;
+        Ok(Self(data.into_bytes()))
+    }
+}
+
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceReleasedItemPSFailIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11944,15 +13110,34 @@ pub struct PDUSessionResourceReleasedItemPSFailIE_Extensions(
     pub Vec<PDUSessionResourceReleasedItemPSFailIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
-pub struct OCTET_STRING_53(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+pub struct PDUSessionResourceReleasedItemRelResPDUSessionResourceReleaseResponseTransfer(
+    pub Vec<u8>,
+);
+impl PDUSessionResourceReleasedItemRelResPDUSessionResourceReleaseResponseTransfer {
+    pub fn aper_decode_contents(
+        &self,
+    ) -> Result<PDUSessionResourceReleaseResponseTransfer, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::from_slice_aper(&self.0);
+        <PDUSessionResourceReleaseResponseTransfer as asn1_codecs::aper::AperCodec>::aper_decode(
+            &mut data,
+        )
+    }
+    pub fn aper_encode_contents(
+        contents: &PDUSessionResourceReleaseResponseTransfer,
+    ) -> Result<Self, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::new_aper();
+        asn1_codecs::aper::AperCodec::aper_encode(contents, &mut data)?;
+        Ok(Self(data.into_bytes()))
+    }
+}
+
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceReleasedItemRelResIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11963,15 +13148,30 @@ pub struct PDUSessionResourceReleasedItemRelResIE_Extensions(
     pub Vec<PDUSessionResourceReleasedItemRelResIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
-pub struct OCTET_STRING_54(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+pub struct PDUSessionResourceResumeItemRESReqUEContextResumeRequestTransfer(pub Vec<u8>);
+impl PDUSessionResourceResumeItemRESReqUEContextResumeRequestTransfer {
+    pub fn aper_decode_contents(
+        &self,
+    ) -> Result<UEContextResumeRequestTransfer, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::from_slice_aper(&self.0);
+        <UEContextResumeRequestTransfer as asn1_codecs::aper::AperCodec>::aper_decode(&mut data)
+    }
+    pub fn aper_encode_contents(
+        contents: &UEContextResumeRequestTransfer,
+    ) -> Result<Self, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::new_aper();
+        asn1_codecs::aper::AperCodec::aper_encode(contents, &mut data)?;
+        Ok(Self(data.into_bytes()))
+    }
+}
+
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceResumeItemRESReqIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11982,15 +13182,30 @@ pub struct PDUSessionResourceResumeItemRESReqIE_Extensions(
     pub Vec<PDUSessionResourceResumeItemRESReqIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
-pub struct OCTET_STRING_55(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+pub struct PDUSessionResourceResumeItemRESResUEContextResumeResponseTransfer(pub Vec<u8>);
+impl PDUSessionResourceResumeItemRESResUEContextResumeResponseTransfer {
+    pub fn aper_decode_contents(
+        &self,
+    ) -> Result<UEContextResumeResponseTransfer, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::from_slice_aper(&self.0);
+        <UEContextResumeResponseTransfer as asn1_codecs::aper::AperCodec>::aper_decode(&mut data)
+    }
+    pub fn aper_encode_contents(
+        contents: &UEContextResumeResponseTransfer,
+    ) -> Result<Self, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::new_aper();
+        asn1_codecs::aper::AperCodec::aper_encode(contents, &mut data)?;
+        Ok(Self(data.into_bytes()))
+    }
+}
+
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceResumeItemRESResIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12001,15 +13216,32 @@ pub struct PDUSessionResourceResumeItemRESResIE_Extensions(
     pub Vec<PDUSessionResourceResumeItemRESResIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
-pub struct OCTET_STRING_56(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+pub struct PDUSessionResourceSecondaryRATUsageItemSecondaryRATDataUsageReportTransfer(pub Vec<u8>);
+impl PDUSessionResourceSecondaryRATUsageItemSecondaryRATDataUsageReportTransfer {
+    pub fn aper_decode_contents(
+        &self,
+    ) -> Result<SecondaryRATDataUsageReportTransfer, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::from_slice_aper(&self.0);
+        <SecondaryRATDataUsageReportTransfer as asn1_codecs::aper::AperCodec>::aper_decode(
+            &mut data,
+        )
+    }
+    pub fn aper_encode_contents(
+        contents: &SecondaryRATDataUsageReportTransfer,
+    ) -> Result<Self, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::new_aper();
+        asn1_codecs::aper::AperCodec::aper_encode(contents, &mut data)?;
+        Ok(Self(data.into_bytes()))
+    }
+}
+
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceSecondaryRATUsageItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12020,15 +13252,32 @@ pub struct PDUSessionResourceSecondaryRATUsageItemIE_Extensions(
     pub Vec<PDUSessionResourceSecondaryRATUsageItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
-pub struct OCTET_STRING_57(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+pub struct PDUSessionResourceSetupItemCxtReqPDUSessionResourceSetupRequestTransfer(pub Vec<u8>);
+impl PDUSessionResourceSetupItemCxtReqPDUSessionResourceSetupRequestTransfer {
+    pub fn aper_decode_contents(
+        &self,
+    ) -> Result<PDUSessionResourceSetupRequestTransfer, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::from_slice_aper(&self.0);
+        <PDUSessionResourceSetupRequestTransfer as asn1_codecs::aper::AperCodec>::aper_decode(
+            &mut data,
+        )
+    }
+    pub fn aper_encode_contents(
+        contents: &PDUSessionResourceSetupRequestTransfer,
+    ) -> Result<Self, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::new_aper();
+        asn1_codecs::aper::AperCodec::aper_encode(contents, &mut data)?;
+        Ok(Self(data.into_bytes()))
+    }
+}
+
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceSetupItemCxtReqIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12039,15 +13288,32 @@ pub struct PDUSessionResourceSetupItemCxtReqIE_Extensions(
     pub Vec<PDUSessionResourceSetupItemCxtReqIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
-pub struct OCTET_STRING_58(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+pub struct PDUSessionResourceSetupItemCxtResPDUSessionResourceSetupResponseTransfer(pub Vec<u8>);
+impl PDUSessionResourceSetupItemCxtResPDUSessionResourceSetupResponseTransfer {
+    pub fn aper_decode_contents(
+        &self,
+    ) -> Result<PDUSessionResourceSetupResponseTransfer, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::from_slice_aper(&self.0);
+        <PDUSessionResourceSetupResponseTransfer as asn1_codecs::aper::AperCodec>::aper_decode(
+            &mut data,
+        )
+    }
+    pub fn aper_encode_contents(
+        contents: &PDUSessionResourceSetupResponseTransfer,
+    ) -> Result<Self, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::new_aper();
+        asn1_codecs::aper::AperCodec::aper_encode(contents, &mut data)?;
+        Ok(Self(data.into_bytes()))
+    }
+}
+
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceSetupItemCxtResIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12058,15 +13324,32 @@ pub struct PDUSessionResourceSetupItemCxtResIE_Extensions(
     pub Vec<PDUSessionResourceSetupItemCxtResIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
-pub struct OCTET_STRING_59(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+pub struct PDUSessionResourceSetupItemHOReqHandoverRequestTransfer(pub Vec<u8>);
+impl PDUSessionResourceSetupItemHOReqHandoverRequestTransfer {
+    pub fn aper_decode_contents(
+        &self,
+    ) -> Result<PDUSessionResourceSetupRequestTransfer, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::from_slice_aper(&self.0);
+        <PDUSessionResourceSetupRequestTransfer as asn1_codecs::aper::AperCodec>::aper_decode(
+            &mut data,
+        )
+    }
+    pub fn aper_encode_contents(
+        contents: &PDUSessionResourceSetupRequestTransfer,
+    ) -> Result<Self, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::new_aper();
+        asn1_codecs::aper::AperCodec::aper_encode(contents, &mut data)?;
+        Ok(Self(data.into_bytes()))
+    }
+}
+
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceSetupItemHOReqIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12077,15 +13360,32 @@ pub struct PDUSessionResourceSetupItemHOReqIE_Extensions(
     pub Vec<PDUSessionResourceSetupItemHOReqIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
-pub struct OCTET_STRING_60(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+pub struct PDUSessionResourceSetupItemSUReqPDUSessionResourceSetupRequestTransfer(pub Vec<u8>);
+impl PDUSessionResourceSetupItemSUReqPDUSessionResourceSetupRequestTransfer {
+    pub fn aper_decode_contents(
+        &self,
+    ) -> Result<PDUSessionResourceSetupRequestTransfer, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::from_slice_aper(&self.0);
+        <PDUSessionResourceSetupRequestTransfer as asn1_codecs::aper::AperCodec>::aper_decode(
+            &mut data,
+        )
+    }
+    pub fn aper_encode_contents(
+        contents: &PDUSessionResourceSetupRequestTransfer,
+    ) -> Result<Self, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::new_aper();
+        asn1_codecs::aper::AperCodec::aper_encode(contents, &mut data)?;
+        Ok(Self(data.into_bytes()))
+    }
+}
+
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceSetupItemSUReqIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12096,15 +13396,32 @@ pub struct PDUSessionResourceSetupItemSUReqIE_Extensions(
     pub Vec<PDUSessionResourceSetupItemSUReqIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
-pub struct OCTET_STRING_61(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+pub struct PDUSessionResourceSetupItemSUResPDUSessionResourceSetupResponseTransfer(pub Vec<u8>);
+impl PDUSessionResourceSetupItemSUResPDUSessionResourceSetupResponseTransfer {
+    pub fn aper_decode_contents(
+        &self,
+    ) -> Result<PDUSessionResourceSetupResponseTransfer, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::from_slice_aper(&self.0);
+        <PDUSessionResourceSetupResponseTransfer as asn1_codecs::aper::AperCodec>::aper_decode(
+            &mut data,
+        )
+    }
+    pub fn aper_encode_contents(
+        contents: &PDUSessionResourceSetupResponseTransfer,
+    ) -> Result<Self, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::new_aper();
+        asn1_codecs::aper::AperCodec::aper_encode(contents, &mut data)?;
+        Ok(Self(data.into_bytes()))
+    }
+}
+
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceSetupItemSUResIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12115,7 +13432,7 @@ pub struct PDUSessionResourceSetupItemSUResIE_Extensions(
     pub Vec<PDUSessionResourceSetupItemSUResIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum PDUSessionResourceSetupRequestProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -12132,7 +13449,7 @@ pub enum PDUSessionResourceSetupRequestProtocolIEs_EntryValue {
     Id_UEAggregateMaximumBitRate(UEAggregateMaximumBitRate),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceSetupRequestProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -12141,7 +13458,7 @@ pub struct PDUSessionResourceSetupRequestProtocolIEs_Entry {
     pub value: PDUSessionResourceSetupRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12152,7 +13469,7 @@ pub struct PDUSessionResourceSetupRequestProtocolIEs(
     pub Vec<PDUSessionResourceSetupRequestProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum PDUSessionResourceSetupRequestTransferProtocolIEs_EntryValue {
     #[asn(key = 186)]
@@ -12185,7 +13502,7 @@ pub enum PDUSessionResourceSetupRequestTransferProtocolIEs_EntryValue {
     Id_UL_NGU_UP_TNLInformation(UPTransportLayerInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceSetupRequestTransferProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -12194,7 +13511,7 @@ pub struct PDUSessionResourceSetupRequestTransferProtocolIEs_Entry {
     pub value: PDUSessionResourceSetupRequestTransferProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12205,7 +13522,7 @@ pub struct PDUSessionResourceSetupRequestTransferProtocolIEs(
     pub Vec<PDUSessionResourceSetupRequestTransferProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum PDUSessionResourceSetupResponseProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -12220,7 +13537,7 @@ pub enum PDUSessionResourceSetupResponseProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceSetupResponseProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -12229,7 +13546,7 @@ pub struct PDUSessionResourceSetupResponseProtocolIEs_Entry {
     pub value: PDUSessionResourceSetupResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12240,7 +13557,7 @@ pub struct PDUSessionResourceSetupResponseProtocolIEs(
     pub Vec<PDUSessionResourceSetupResponseProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum PDUSessionResourceSetupResponseTransferIE_Extensions_EntryExtensionValue {
     #[asn(key = 184)]
@@ -12253,7 +13570,7 @@ pub enum PDUSessionResourceSetupResponseTransferIE_Extensions_EntryExtensionValu
     Id_UsedRSNInformation(RedundantPDUSessionInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceSetupResponseTransferIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -12262,7 +13579,7 @@ pub struct PDUSessionResourceSetupResponseTransferIE_Extensions_Entry {
     pub extension_value: PDUSessionResourceSetupResponseTransferIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12273,11 +13590,11 @@ pub struct PDUSessionResourceSetupResponseTransferIE_Extensions(
     pub Vec<PDUSessionResourceSetupResponseTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceSetupUnsuccessfulTransferIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12288,15 +13605,30 @@ pub struct PDUSessionResourceSetupUnsuccessfulTransferIE_Extensions(
     pub Vec<PDUSessionResourceSetupUnsuccessfulTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
-pub struct OCTET_STRING_62(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+pub struct PDUSessionResourceSuspendItemSUSReqUEContextSuspendRequestTransfer(pub Vec<u8>);
+impl PDUSessionResourceSuspendItemSUSReqUEContextSuspendRequestTransfer {
+    pub fn aper_decode_contents(
+        &self,
+    ) -> Result<UEContextSuspendRequestTransfer, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::from_slice_aper(&self.0);
+        <UEContextSuspendRequestTransfer as asn1_codecs::aper::AperCodec>::aper_decode(&mut data)
+    }
+    pub fn aper_encode_contents(
+        contents: &UEContextSuspendRequestTransfer,
+    ) -> Result<Self, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::new_aper();
+        asn1_codecs::aper::AperCodec::aper_encode(contents, &mut data)?;
+        Ok(Self(data.into_bytes()))
+    }
+}
+
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceSuspendItemSUSReqIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12307,15 +13639,32 @@ pub struct PDUSessionResourceSuspendItemSUSReqIE_Extensions(
     pub Vec<PDUSessionResourceSuspendItemSUSReqIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
-pub struct OCTET_STRING_63(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+pub struct PDUSessionResourceSwitchedItemPathSwitchRequestAcknowledgeTransfer(pub Vec<u8>);
+impl PDUSessionResourceSwitchedItemPathSwitchRequestAcknowledgeTransfer {
+    pub fn aper_decode_contents(
+        &self,
+    ) -> Result<PathSwitchRequestAcknowledgeTransfer, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::from_slice_aper(&self.0);
+        <PathSwitchRequestAcknowledgeTransfer as asn1_codecs::aper::AperCodec>::aper_decode(
+            &mut data,
+        )
+    }
+    pub fn aper_encode_contents(
+        contents: &PathSwitchRequestAcknowledgeTransfer,
+    ) -> Result<Self, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::new_aper();
+        asn1_codecs::aper::AperCodec::aper_encode(contents, &mut data)?;
+        Ok(Self(data.into_bytes()))
+    }
+}
+
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceSwitchedItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12326,15 +13675,30 @@ pub struct PDUSessionResourceSwitchedItemIE_Extensions(
     pub Vec<PDUSessionResourceSwitchedItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
-pub struct OCTET_STRING_64(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+pub struct PDUSessionResourceToBeSwitchedDLItemPathSwitchRequestTransfer(pub Vec<u8>);
+impl PDUSessionResourceToBeSwitchedDLItemPathSwitchRequestTransfer {
+    pub fn aper_decode_contents(
+        &self,
+    ) -> Result<PathSwitchRequestTransfer, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::from_slice_aper(&self.0);
+        <PathSwitchRequestTransfer as asn1_codecs::aper::AperCodec>::aper_decode(&mut data)
+    }
+    pub fn aper_encode_contents(
+        contents: &PathSwitchRequestTransfer,
+    ) -> Result<Self, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::new_aper();
+        asn1_codecs::aper::AperCodec::aper_encode(contents, &mut data)?;
+        Ok(Self(data.into_bytes()))
+    }
+}
+
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceToBeSwitchedDLItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12345,15 +13709,32 @@ pub struct PDUSessionResourceToBeSwitchedDLItemIE_Extensions(
     pub Vec<PDUSessionResourceToBeSwitchedDLItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
-pub struct OCTET_STRING_65(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+pub struct PDUSessionResourceToReleaseItemHOCmdHandoverPreparationUnsuccessfulTransfer(pub Vec<u8>);
+impl PDUSessionResourceToReleaseItemHOCmdHandoverPreparationUnsuccessfulTransfer {
+    pub fn aper_decode_contents(
+        &self,
+    ) -> Result<HandoverPreparationUnsuccessfulTransfer, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::from_slice_aper(&self.0);
+        <HandoverPreparationUnsuccessfulTransfer as asn1_codecs::aper::AperCodec>::aper_decode(
+            &mut data,
+        )
+    }
+    pub fn aper_encode_contents(
+        contents: &HandoverPreparationUnsuccessfulTransfer,
+    ) -> Result<Self, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::new_aper();
+        asn1_codecs::aper::AperCodec::aper_encode(contents, &mut data)?;
+        Ok(Self(data.into_bytes()))
+    }
+}
+
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceToReleaseItemHOCmdIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12364,15 +13745,34 @@ pub struct PDUSessionResourceToReleaseItemHOCmdIE_Extensions(
     pub Vec<PDUSessionResourceToReleaseItemHOCmdIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING")]
-pub struct OCTET_STRING_66(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+pub struct PDUSessionResourceToReleaseItemRelCmdPDUSessionResourceReleaseCommandTransfer(
+    pub Vec<u8>,
+);
+impl PDUSessionResourceToReleaseItemRelCmdPDUSessionResourceReleaseCommandTransfer {
+    pub fn aper_decode_contents(
+        &self,
+    ) -> Result<PDUSessionResourceReleaseCommandTransfer, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::from_slice_aper(&self.0);
+        <PDUSessionResourceReleaseCommandTransfer as asn1_codecs::aper::AperCodec>::aper_decode(
+            &mut data,
+        )
+    }
+    pub fn aper_encode_contents(
+        contents: &PDUSessionResourceReleaseCommandTransfer,
+    ) -> Result<Self, asn1_codecs::PerCodecError> {
+        let mut data = asn1_codecs::PerCodecData::new_aper();
+        asn1_codecs::aper::AperCodec::aper_encode(contents, &mut data)?;
+        Ok(Self(data.into_bytes()))
+    }
+}
+
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceToReleaseItemRelCmdIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12383,19 +13783,21 @@ pub struct PDUSessionResourceToReleaseItemRelCmdIE_Extensions(
     pub Vec<PDUSessionResourceToReleaseItemRelCmdIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
-pub struct ENUMERATED_67(pub u8);
-impl ENUMERATED_67 {
+pub struct PDUSessionUsageReportRATType(pub u8);
+impl PDUSessionUsageReportRATType {
     pub const NR: u8 = 0u8;
     pub const EUTRA: u8 = 1u8;
+    pub const NR_UNLICENSED: u8 = 2u8;
+    pub const E_UTRA_UNLICENSED: u8 = 3u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionUsageReportIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12404,7 +13806,7 @@ pub struct PDUSessionUsageReportIE_Extensions_Entry {}
 )]
 pub struct PDUSessionUsageReportIE_Extensions(pub Vec<PDUSessionUsageReportIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum PLMNSupportItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 270)]
@@ -12413,7 +13815,7 @@ pub enum PLMNSupportItemIE_Extensions_EntryExtensionValue {
     Id_NPN_Support(NPN_Support),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PLMNSupportItemIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -12422,7 +13824,7 @@ pub struct PLMNSupportItemIE_Extensions_Entry {
     pub extension_value: PLMNSupportItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12431,11 +13833,11 @@ pub struct PLMNSupportItemIE_Extensions_Entry {
 )]
 pub struct PLMNSupportItemIE_Extensions(pub Vec<PLMNSupportItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PNI_NPN_MobilityInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12446,7 +13848,7 @@ pub struct PNI_NPN_MobilityInformationIE_Extensions(
     pub Vec<PNI_NPN_MobilityInformationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum PWSCancelRequestProtocolIEs_EntryValue {
     #[asn(key = 14)]
@@ -12459,7 +13861,7 @@ pub enum PWSCancelRequestProtocolIEs_EntryValue {
     Id_WarningAreaList(WarningAreaList),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PWSCancelRequestProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -12468,7 +13870,7 @@ pub struct PWSCancelRequestProtocolIEs_Entry {
     pub value: PWSCancelRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12477,7 +13879,7 @@ pub struct PWSCancelRequestProtocolIEs_Entry {
 )]
 pub struct PWSCancelRequestProtocolIEs(pub Vec<PWSCancelRequestProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum PWSCancelResponseProtocolIEs_EntryValue {
     #[asn(key = 12)]
@@ -12490,7 +13892,7 @@ pub enum PWSCancelResponseProtocolIEs_EntryValue {
     Id_SerialNumber(SerialNumber),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PWSCancelResponseProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -12499,7 +13901,7 @@ pub struct PWSCancelResponseProtocolIEs_Entry {
     pub value: PWSCancelResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12508,11 +13910,11 @@ pub struct PWSCancelResponseProtocolIEs_Entry {
 )]
 pub struct PWSCancelResponseProtocolIEs(pub Vec<PWSCancelResponseProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct PWSFailedCellIDListchoice_Extensions {}
+pub struct PWSFailedCellIDList_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum PWSFailureIndicationProtocolIEs_EntryValue {
     #[asn(key = 27)]
@@ -12521,7 +13923,7 @@ pub enum PWSFailureIndicationProtocolIEs_EntryValue {
     Id_PWSFailedCellIDList(PWSFailedCellIDList),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PWSFailureIndicationProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -12530,7 +13932,7 @@ pub struct PWSFailureIndicationProtocolIEs_Entry {
     pub value: PWSFailureIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12539,7 +13941,7 @@ pub struct PWSFailureIndicationProtocolIEs_Entry {
 )]
 pub struct PWSFailureIndicationProtocolIEs(pub Vec<PWSFailureIndicationProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum PWSRestartIndicationProtocolIEs_EntryValue {
     #[asn(key = 16)]
@@ -12552,7 +13954,7 @@ pub enum PWSRestartIndicationProtocolIEs_EntryValue {
     Id_TAIListForRestart(TAIListForRestart),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PWSRestartIndicationProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -12561,7 +13963,7 @@ pub struct PWSRestartIndicationProtocolIEs_Entry {
     pub value: PWSRestartIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12570,19 +13972,19 @@ pub struct PWSRestartIndicationProtocolIEs_Entry {
 )]
 pub struct PWSRestartIndicationProtocolIEs(pub Vec<PWSRestartIndicationProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "9", extensible = true)]
-pub struct INTEGER_68(pub u8);
+pub struct PacketErrorRatePERScalar(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "9", extensible = true)]
-pub struct INTEGER_69(pub u8);
+pub struct PacketErrorRatePERExponent(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PacketErrorRateIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12591,7 +13993,7 @@ pub struct PacketErrorRateIE_Extensions_Entry {}
 )]
 pub struct PacketErrorRateIE_Extensions(pub Vec<PacketErrorRateIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum PagingProtocolIEs_EntryValue {
     #[asn(key = 11)]
@@ -12622,7 +14024,7 @@ pub enum PagingProtocolIEs_EntryValue {
     Id_WUS_Assistance_Information(WUS_Assistance_Information),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PagingProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -12631,7 +14033,7 @@ pub struct PagingProtocolIEs_Entry {
     pub value: PagingProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12640,11 +14042,11 @@ pub struct PagingProtocolIEs_Entry {
 )]
 pub struct PagingProtocolIEs(pub Vec<PagingProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PagingAssisDataforCEcapabUEIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12655,11 +14057,11 @@ pub struct PagingAssisDataforCEcapabUEIE_Extensions(
     pub Vec<PagingAssisDataforCEcapabUEIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PagingAttemptInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12670,11 +14072,11 @@ pub struct PagingAttemptInformationIE_Extensions(
     pub Vec<PagingAttemptInformationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PagingeDRXInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12683,7 +14085,7 @@ pub struct PagingeDRXInformationIE_Extensions_Entry {}
 )]
 pub struct PagingeDRXInformationIE_Extensions(pub Vec<PagingeDRXInformationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum PathSwitchRequestProtocolIEs_EntryValue {
     #[asn(key = 57)]
@@ -12702,7 +14104,7 @@ pub enum PathSwitchRequestProtocolIEs_EntryValue {
     Id_UserLocationInformation(UserLocationInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PathSwitchRequestProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -12711,7 +14113,7 @@ pub struct PathSwitchRequestProtocolIEs_Entry {
     pub value: PathSwitchRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12720,7 +14122,7 @@ pub struct PathSwitchRequestProtocolIEs_Entry {
 )]
 pub struct PathSwitchRequestProtocolIEs(pub Vec<PathSwitchRequestProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum PathSwitchRequestAcknowledgeProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -12775,7 +14177,7 @@ pub enum PathSwitchRequestAcknowledgeProtocolIEs_EntryValue {
     Id_UESecurityCapabilities(UESecurityCapabilities),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PathSwitchRequestAcknowledgeProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -12784,7 +14186,7 @@ pub struct PathSwitchRequestAcknowledgeProtocolIEs_Entry {
     pub value: PathSwitchRequestAcknowledgeProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12795,7 +14197,7 @@ pub struct PathSwitchRequestAcknowledgeProtocolIEs(
     pub Vec<PathSwitchRequestAcknowledgeProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum PathSwitchRequestAcknowledgeTransferIE_Extensions_EntryExtensionValue {
     #[asn(key = 154)]
@@ -12808,7 +14210,7 @@ pub enum PathSwitchRequestAcknowledgeTransferIE_Extensions_EntryExtensionValue {
     Id_RedundantUL_NGU_UP_TNLInformation(UPTransportLayerInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PathSwitchRequestAcknowledgeTransferIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -12817,7 +14219,7 @@ pub struct PathSwitchRequestAcknowledgeTransferIE_Extensions_Entry {
     pub extension_value: PathSwitchRequestAcknowledgeTransferIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12828,7 +14230,7 @@ pub struct PathSwitchRequestAcknowledgeTransferIE_Extensions(
     pub Vec<PathSwitchRequestAcknowledgeTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum PathSwitchRequestFailureProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -12841,7 +14243,7 @@ pub enum PathSwitchRequestFailureProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PathSwitchRequestFailureProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -12850,7 +14252,7 @@ pub struct PathSwitchRequestFailureProtocolIEs_Entry {
     pub value: PathSwitchRequestFailureProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12859,11 +14261,11 @@ pub struct PathSwitchRequestFailureProtocolIEs_Entry {
 )]
 pub struct PathSwitchRequestFailureProtocolIEs(pub Vec<PathSwitchRequestFailureProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PathSwitchRequestSetupFailedTransferIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12874,7 +14276,7 @@ pub struct PathSwitchRequestSetupFailedTransferIE_Extensions(
     pub Vec<PathSwitchRequestSetupFailedTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum PathSwitchRequestTransferIE_Extensions_EntryExtensionValue {
     #[asn(key = 155)]
@@ -12891,7 +14293,7 @@ pub enum PathSwitchRequestTransferIE_Extensions_EntryExtensionValue {
     Id_UsedRSNInformation(RedundantPDUSessionInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PathSwitchRequestTransferIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -12900,7 +14302,7 @@ pub struct PathSwitchRequestTransferIE_Extensions_Entry {
     pub extension_value: PathSwitchRequestTransferIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12911,11 +14313,11 @@ pub struct PathSwitchRequestTransferIE_Extensions(
     pub Vec<PathSwitchRequestTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PathSwitchRequestUnsuccessfulTransferIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12926,19 +14328,34 @@ pub struct PathSwitchRequestUnsuccessfulTransferIE_Extensions(
     pub Vec<PathSwitchRequestUnsuccessfulTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "65535")]
-pub struct INTEGER_70(pub u16);
+pub struct PrivateIE_ID_local(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OBJECT-IDENTIFIER")]
-pub struct OBJECT_IDENTIFIER_71;
+pub struct PrivateIE_ID_global(pub Vec<u128>);
+impl std::fmt::Display for PrivateIE_ID_global {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let arcs = self.0.iter().map(|arc| arc.to_string()).collect::<Vec<_>>();
+        write!(f, "{}", arcs.join("."))
+    }
+}
+impl std::str::FromStr for PrivateIE_ID_global {
+    type Err = std::num::ParseIntError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split('.')
+            .map(|arc| arc.parse())
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PrivateMessagePrivateIEs_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12947,23 +14364,25 @@ pub struct PrivateMessagePrivateIEs_Entry {}
 )]
 pub struct PrivateMessagePrivateIEs(pub Vec<PrivateMessagePrivateIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct ProcedureStageChoicechoice_Extensions {}
+pub struct ProcedureStageChoice_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
-pub struct ENUMERATED_72(pub u8);
-impl ENUMERATED_72 {
+pub struct QoSFlowsUsageReport_ItemRATType(pub u8);
+impl QoSFlowsUsageReport_ItemRATType {
     pub const NR: u8 = 0u8;
     pub const EUTRA: u8 = 1u8;
+    pub const NR_UNLICENSED: u8 = 2u8;
+    pub const E_UTRA_UNLICENSED: u8 = 3u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct QoSFlowsUsageReport_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12974,18 +14393,18 @@ pub struct QoSFlowsUsageReport_ItemIE_Extensions(
     pub Vec<QoSFlowsUsageReport_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct QosCharacteristicschoice_Extensions {}
+pub struct QosCharacteristics_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum QosFlowAcceptedItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 221)]
     Id_CurrentQoSParaSetIndex(AlternativeQoSParaSetIndex),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct QosFlowAcceptedItemIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -12994,7 +14413,7 @@ pub struct QosFlowAcceptedItemIE_Extensions_Entry {
     pub extension_value: QosFlowAcceptedItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13003,7 +14422,7 @@ pub struct QosFlowAcceptedItemIE_Extensions_Entry {
 )]
 pub struct QosFlowAcceptedItemIE_Extensions(pub Vec<QosFlowAcceptedItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum QosFlowAddOrModifyRequestItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 194)]
@@ -13012,7 +14431,7 @@ pub enum QosFlowAddOrModifyRequestItemIE_Extensions_EntryExtensionValue {
     Id_TSCTrafficCharacteristics(TSCTrafficCharacteristics),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct QosFlowAddOrModifyRequestItemIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -13021,7 +14440,7 @@ pub struct QosFlowAddOrModifyRequestItemIE_Extensions_Entry {
     pub extension_value: QosFlowAddOrModifyRequestItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13032,14 +14451,14 @@ pub struct QosFlowAddOrModifyRequestItemIE_Extensions(
     pub Vec<QosFlowAddOrModifyRequestItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum QosFlowAddOrModifyResponseItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 221)]
     Id_CurrentQoSParaSetIndex(AlternativeQoSParaSetIndex),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct QosFlowAddOrModifyResponseItemIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -13048,7 +14467,7 @@ pub struct QosFlowAddOrModifyResponseItemIE_Extensions_Entry {
     pub extension_value: QosFlowAddOrModifyResponseItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13059,11 +14478,11 @@ pub struct QosFlowAddOrModifyResponseItemIE_Extensions(
     pub Vec<QosFlowAddOrModifyResponseItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct QosFlowFeedbackItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13072,14 +14491,14 @@ pub struct QosFlowFeedbackItemIE_Extensions_Entry {}
 )]
 pub struct QosFlowFeedbackItemIE_Extensions(pub Vec<QosFlowFeedbackItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum QosFlowInformationItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 163)]
     Id_ULForwarding(ULForwarding),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct QosFlowInformationItemIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -13088,7 +14507,7 @@ pub struct QosFlowInformationItemIE_Extensions_Entry {
     pub extension_value: QosFlowInformationItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13097,14 +14516,14 @@ pub struct QosFlowInformationItemIE_Extensions_Entry {
 )]
 pub struct QosFlowInformationItemIE_Extensions(pub Vec<QosFlowInformationItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum QosFlowItemWithDataForwardingIE_Extensions_EntryExtensionValue {
     #[asn(key = 221)]
     Id_CurrentQoSParaSetIndex(AlternativeQoSParaSetIndex),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct QosFlowItemWithDataForwardingIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -13113,7 +14532,7 @@ pub struct QosFlowItemWithDataForwardingIE_Extensions_Entry {
     pub extension_value: QosFlowItemWithDataForwardingIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13124,7 +14543,7 @@ pub struct QosFlowItemWithDataForwardingIE_Extensions(
     pub Vec<QosFlowItemWithDataForwardingIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum QosFlowLevelQosParametersIE_Extensions_EntryExtensionValue {
     #[asn(key = 276)]
@@ -13133,7 +14552,7 @@ pub enum QosFlowLevelQosParametersIE_Extensions_EntryExtensionValue {
     Id_QosMonitoringRequest(QosMonitoringRequest),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct QosFlowLevelQosParametersIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -13142,7 +14561,7 @@ pub struct QosFlowLevelQosParametersIE_Extensions_Entry {
     pub extension_value: QosFlowLevelQosParametersIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13153,11 +14572,11 @@ pub struct QosFlowLevelQosParametersIE_Extensions(
     pub Vec<QosFlowLevelQosParametersIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct QosFlowModifyConfirmItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13168,14 +14587,14 @@ pub struct QosFlowModifyConfirmItemIE_Extensions(
     pub Vec<QosFlowModifyConfirmItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum QosFlowNotifyItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 221)]
     Id_CurrentQoSParaSetIndex(AlternativeQoSParaSetNotifyIndex),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct QosFlowNotifyItemIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -13184,7 +14603,7 @@ pub struct QosFlowNotifyItemIE_Extensions_Entry {
     pub extension_value: QosFlowNotifyItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13193,7 +14612,7 @@ pub struct QosFlowNotifyItemIE_Extensions_Entry {
 )]
 pub struct QosFlowNotifyItemIE_Extensions(pub Vec<QosFlowNotifyItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum QosFlowParametersItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 279)]
@@ -13204,7 +14623,7 @@ pub enum QosFlowParametersItemIE_Extensions_EntryExtensionValue {
     Id_CNPacketDelayBudgetUL(ExtendedPacketDelayBudget),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct QosFlowParametersItemIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -13213,7 +14632,7 @@ pub struct QosFlowParametersItemIE_Extensions_Entry {
     pub extension_value: QosFlowParametersItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13222,11 +14641,11 @@ pub struct QosFlowParametersItemIE_Extensions_Entry {
 )]
 pub struct QosFlowParametersItemIE_Extensions(pub Vec<QosFlowParametersItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct QosFlowPerTNLInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13237,11 +14656,11 @@ pub struct QosFlowPerTNLInformationIE_Extensions(
     pub Vec<QosFlowPerTNLInformationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct QosFlowPerTNLInformationItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13252,7 +14671,7 @@ pub struct QosFlowPerTNLInformationItemIE_Extensions(
     pub Vec<QosFlowPerTNLInformationItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum QosFlowSetupRequestItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 194)]
@@ -13261,7 +14680,7 @@ pub enum QosFlowSetupRequestItemIE_Extensions_EntryExtensionValue {
     Id_TSCTrafficCharacteristics(TSCTrafficCharacteristics),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct QosFlowSetupRequestItemIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -13270,7 +14689,7 @@ pub struct QosFlowSetupRequestItemIE_Extensions_Entry {
     pub extension_value: QosFlowSetupRequestItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13281,11 +14700,11 @@ pub struct QosFlowSetupRequestItemIE_Extensions(
     pub Vec<QosFlowSetupRequestItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct QosFlowToBeForwardedItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13296,11 +14715,11 @@ pub struct QosFlowToBeForwardedItemIE_Extensions(
     pub Vec<QosFlowToBeForwardedItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct QosFlowWithCauseItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13309,7 +14728,7 @@ pub struct QosFlowWithCauseItemIE_Extensions_Entry {}
 )]
 pub struct QosFlowWithCauseItemIE_Extensions(pub Vec<QosFlowWithCauseItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum RANCPRelocationIndicationProtocolIEs_EntryValue {
     #[asn(key = 25)]
@@ -13324,7 +14743,7 @@ pub enum RANCPRelocationIndicationProtocolIEs_EntryValue {
     Id_UL_CP_SecurityInformation(UL_CP_SecurityInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RANCPRelocationIndicationProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -13333,7 +14752,7 @@ pub struct RANCPRelocationIndicationProtocolIEs_Entry {
     pub value: RANCPRelocationIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13344,7 +14763,7 @@ pub struct RANCPRelocationIndicationProtocolIEs(
     pub Vec<RANCPRelocationIndicationProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum RANConfigurationUpdateProtocolIEs_EntryValue {
     #[asn(key = 21)]
@@ -13363,7 +14782,7 @@ pub enum RANConfigurationUpdateProtocolIEs_EntryValue {
     Id_SupportedTAList(SupportedTAList),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RANConfigurationUpdateProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -13372,7 +14791,7 @@ pub struct RANConfigurationUpdateProtocolIEs_Entry {
     pub value: RANConfigurationUpdateProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13381,14 +14800,14 @@ pub struct RANConfigurationUpdateProtocolIEs_Entry {
 )]
 pub struct RANConfigurationUpdateProtocolIEs(pub Vec<RANConfigurationUpdateProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum RANConfigurationUpdateAcknowledgeProtocolIEs_EntryValue {
     #[asn(key = 19)]
     Id_CriticalityDiagnostics(CriticalityDiagnostics),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RANConfigurationUpdateAcknowledgeProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -13397,7 +14816,7 @@ pub struct RANConfigurationUpdateAcknowledgeProtocolIEs_Entry {
     pub value: RANConfigurationUpdateAcknowledgeProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13408,7 +14827,7 @@ pub struct RANConfigurationUpdateAcknowledgeProtocolIEs(
     pub Vec<RANConfigurationUpdateAcknowledgeProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum RANConfigurationUpdateFailureProtocolIEs_EntryValue {
     #[asn(key = 15)]
@@ -13419,7 +14838,7 @@ pub enum RANConfigurationUpdateFailureProtocolIEs_EntryValue {
     Id_TimeToWait(TimeToWait),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RANConfigurationUpdateFailureProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -13428,7 +14847,7 @@ pub struct RANConfigurationUpdateFailureProtocolIEs_Entry {
     pub value: RANConfigurationUpdateFailureProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13439,11 +14858,11 @@ pub struct RANConfigurationUpdateFailureProtocolIEs(
     pub Vec<RANConfigurationUpdateFailureProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RANStatusTransfer_TransparentContainerIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13454,14 +14873,14 @@ pub struct RANStatusTransfer_TransparentContainerIE_Extensions(
     pub Vec<RANStatusTransfer_TransparentContainerIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum RATRestrictions_ItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 180)]
     Id_ExtendedRATRestrictionInformation(ExtendedRATRestrictionInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RATRestrictions_ItemIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -13470,7 +14889,7 @@ pub struct RATRestrictions_ItemIE_Extensions_Entry {
     pub extension_value: RATRestrictions_ItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13479,19 +14898,19 @@ pub struct RATRestrictions_ItemIE_Extensions_Entry {
 )]
 pub struct RATRestrictions_ItemIE_Extensions(pub Vec<RATRestrictions_ItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
-pub struct ENUMERATED_73(pub u8);
-impl ENUMERATED_73 {
+pub struct RIMInformationRIM_RSDetection(pub u8);
+impl RIMInformationRIM_RSDetection {
     pub const RS_DETECTED: u8 = 0u8;
     pub const RS_DISAPPEARED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RIMInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13500,11 +14919,11 @@ pub struct RIMInformationIE_Extensions_Entry {}
 )]
 pub struct RIMInformationIE_Extensions(pub Vec<RIMInformationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RIMInformationTransferIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13513,7 +14932,7 @@ pub struct RIMInformationTransferIE_Extensions_Entry {}
 )]
 pub struct RIMInformationTransferIE_Extensions(pub Vec<RIMInformationTransferIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum RRCInactiveTransitionReportProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -13526,7 +14945,7 @@ pub enum RRCInactiveTransitionReportProtocolIEs_EntryValue {
     Id_UserLocationInformation(UserLocationInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RRCInactiveTransitionReportProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -13535,7 +14954,7 @@ pub struct RRCInactiveTransitionReportProtocolIEs_Entry {
     pub value: RRCInactiveTransitionReportProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13546,15 +14965,15 @@ pub struct RRCInactiveTransitionReportProtocolIEs(
     pub Vec<RRCInactiveTransitionReportProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "4095")]
-pub struct INTEGER_74(pub u16);
+pub struct RecommendedCellItemTimeStayedInCell(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RecommendedCellItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13563,11 +14982,11 @@ pub struct RecommendedCellItemIE_Extensions_Entry {}
 )]
 pub struct RecommendedCellItemIE_Extensions(pub Vec<RecommendedCellItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RecommendedCellsForPagingIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13578,11 +14997,11 @@ pub struct RecommendedCellsForPagingIE_Extensions(
     pub Vec<RecommendedCellsForPagingIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RecommendedRANNodeItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13591,11 +15010,11 @@ pub struct RecommendedRANNodeItemIE_Extensions_Entry {}
 )]
 pub struct RecommendedRANNodeItemIE_Extensions(pub Vec<RecommendedRANNodeItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RecommendedRANNodesForPagingIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13606,11 +15025,11 @@ pub struct RecommendedRANNodesForPagingIE_Extensions(
     pub Vec<RecommendedRANNodesForPagingIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RedundantPDUSessionInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13621,7 +15040,7 @@ pub struct RedundantPDUSessionInformationIE_Extensions(
     pub Vec<RedundantPDUSessionInformationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum RerouteNASRequestProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -13636,7 +15055,7 @@ pub enum RerouteNASRequestProtocolIEs_EntryValue {
     Id_SourceToTarget_AMFInformationReroute(SourceToTarget_AMFInformationReroute),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RerouteNASRequestProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -13645,7 +15064,7 @@ pub struct RerouteNASRequestProtocolIEs_Entry {
     pub value: RerouteNASRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13654,18 +15073,18 @@ pub struct RerouteNASRequestProtocolIEs_Entry {
 )]
 pub struct RerouteNASRequestProtocolIEs(pub Vec<RerouteNASRequestProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct ResetTypechoice_Extensions {}
+pub struct ResetType_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum RetrieveUEInformationProtocolIEs_EntryValue {
     #[asn(key = 26)]
     Id_FiveG_S_TMSI(FiveG_S_TMSI),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RetrieveUEInformationProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -13674,7 +15093,7 @@ pub struct RetrieveUEInformationProtocolIEs_Entry {
     pub value: RetrieveUEInformationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13683,11 +15102,11 @@ pub struct RetrieveUEInformationProtocolIEs_Entry {
 )]
 pub struct RetrieveUEInformationProtocolIEs(pub Vec<RetrieveUEInformationProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct S_NSSAIIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13696,11 +15115,11 @@ pub struct S_NSSAIIE_Extensions_Entry {}
 )]
 pub struct S_NSSAIIE_Extensions(pub Vec<S_NSSAIIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SNPN_MobilityInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13711,11 +15130,11 @@ pub struct SNPN_MobilityInformationIE_Extensions(
     pub Vec<SNPN_MobilityInformationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SONConfigurationTransferIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13726,27 +15145,27 @@ pub struct SONConfigurationTransferIE_Extensions(
     pub Vec<SONConfigurationTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
-pub enum SONInformationchoice_ExtensionsValue {
+pub enum SONInformation_choice_ExtensionsValue {
     #[asn(key = 252)]
     Id_SONInformationReport(SONInformationReport),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct SONInformationchoice_Extensions {
+pub struct SONInformation_choice_Extensions {
     #[asn(key_field = true)]
     pub id: ProtocolIE_ID,
     pub criticality: Criticality,
-    pub value: SONInformationchoice_ExtensionsValue,
+    pub value: SONInformation_choice_ExtensionsValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SONInformationReplyIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13755,27 +15174,27 @@ pub struct SONInformationReplyIE_Extensions_Entry {}
 )]
 pub struct SONInformationReplyIE_Extensions(pub Vec<SONInformationReplyIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct SONInformationReportchoice_Extensions {}
+pub struct SONInformationReport_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "7", sz_ub = "7")]
-pub struct BIT_STRING_75(pub BitVec<u8, Msb0>);
+pub struct ScheduledCommunicationTimeDayofWeek(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "86399", extensible = true)]
-pub struct INTEGER_76(pub u32);
+pub struct ScheduledCommunicationTimeTimeofDayStart(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "86399", extensible = true)]
-pub struct INTEGER_77(pub u32);
+pub struct ScheduledCommunicationTimeTimeofDayEnd(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ScheduledCommunicationTimeIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13786,7 +15205,7 @@ pub struct ScheduledCommunicationTimeIE_Extensions(
     pub Vec<ScheduledCommunicationTimeIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum SecondaryRATDataUsageReportProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -13801,7 +15220,7 @@ pub enum SecondaryRATDataUsageReportProtocolIEs_EntryValue {
     Id_UserLocationInformation(UserLocationInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SecondaryRATDataUsageReportProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -13810,7 +15229,7 @@ pub struct SecondaryRATDataUsageReportProtocolIEs_Entry {
     pub value: SecondaryRATDataUsageReportProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13821,11 +15240,11 @@ pub struct SecondaryRATDataUsageReportProtocolIEs(
     pub Vec<SecondaryRATDataUsageReportProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SecondaryRATDataUsageReportTransferIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13836,11 +15255,11 @@ pub struct SecondaryRATDataUsageReportTransferIE_Extensions(
     pub Vec<SecondaryRATDataUsageReportTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SecondaryRATUsageInformationIE_Extension_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13851,11 +15270,11 @@ pub struct SecondaryRATUsageInformationIE_Extension(
     pub Vec<SecondaryRATUsageInformationIE_Extension_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SecurityContextIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13864,14 +15283,14 @@ pub struct SecurityContextIE_Extensions_Entry {}
 )]
 pub struct SecurityContextIE_Extensions(pub Vec<SecurityContextIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum SecurityIndicationIE_Extensions_EntryExtensionValue {
     #[asn(key = 151)]
     Id_MaximumIntegrityProtectedDataRate_DL(MaximumIntegrityProtectedDataRate),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SecurityIndicationIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -13880,7 +15299,7 @@ pub struct SecurityIndicationIE_Extensions_Entry {
     pub extension_value: SecurityIndicationIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13889,11 +15308,11 @@ pub struct SecurityIndicationIE_Extensions_Entry {
 )]
 pub struct SecurityIndicationIE_Extensions(pub Vec<SecurityIndicationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SecurityResultIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13902,11 +15321,11 @@ pub struct SecurityResultIE_Extensions_Entry {}
 )]
 pub struct SecurityResultIE_Extensions(pub Vec<SecurityResultIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SensorMeasConfigNameItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13917,11 +15336,11 @@ pub struct SensorMeasConfigNameItemIE_Extensions(
     pub Vec<SensorMeasConfigNameItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SensorMeasurementConfigurationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13932,39 +15351,39 @@ pub struct SensorMeasurementConfigurationIE_Extensions(
     pub Vec<SensorMeasurementConfigurationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
-pub struct ENUMERATED_78(pub u8);
-impl ENUMERATED_78 {
+pub struct SensorNameConfig_uncompensatedBarometricConfig(pub u8);
+impl SensorNameConfig_uncompensatedBarometricConfig {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
-pub struct ENUMERATED_79(pub u8);
-impl ENUMERATED_79 {
+pub struct SensorNameConfig_ueSpeedConfig(pub u8);
+impl SensorNameConfig_ueSpeedConfig {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
-pub struct ENUMERATED_80(pub u8);
-impl ENUMERATED_80 {
+pub struct SensorNameConfig_ueOrientationConfig(pub u8);
+impl SensorNameConfig_ueOrientationConfig {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct SensorNameConfigchoice_Extensions {}
+pub struct SensorNameConfig_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum ServedGUAMIItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 176)]
     Id_GUAMIType(GUAMIType),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ServedGUAMIItemIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -13973,7 +15392,7 @@ pub struct ServedGUAMIItemIE_Extensions_Entry {
     pub extension_value: ServedGUAMIItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13982,11 +15401,11 @@ pub struct ServedGUAMIItemIE_Extensions_Entry {
 )]
 pub struct ServedGUAMIItemIE_Extensions(pub Vec<ServedGUAMIItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ServiceAreaInformation_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13997,11 +15416,11 @@ pub struct ServiceAreaInformation_ItemIE_Extensions(
     pub Vec<ServiceAreaInformation_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SliceOverloadItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14010,11 +15429,11 @@ pub struct SliceOverloadItemIE_Extensions_Entry {}
 )]
 pub struct SliceOverloadItemIE_Extensions(pub Vec<SliceOverloadItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SliceSupportItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14023,7 +15442,7 @@ pub struct SliceSupportItemIE_Extensions_Entry {}
 )]
 pub struct SliceSupportItemIE_Extensions(pub Vec<SliceSupportItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum SourceNGRANNode_ToTargetNGRANNode_TransparentContainerIE_Extensions_EntryExtensionValue {
     #[asn(key = 182)]
@@ -14032,7 +15451,7 @@ pub enum SourceNGRANNode_ToTargetNGRANNode_TransparentContainerIE_Extensions_Ent
     Id_UEHistoryInformationFromTheUE(UEHistoryInformationFromTheUE),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SourceNGRANNode_ToTargetNGRANNode_TransparentContainerIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -14042,7 +15461,7 @@ pub struct SourceNGRANNode_ToTargetNGRANNode_TransparentContainerIE_Extensions_E
         SourceNGRANNode_ToTargetNGRANNode_TransparentContainerIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14053,11 +15472,11 @@ pub struct SourceNGRANNode_ToTargetNGRANNode_TransparentContainerIE_Extensions(
     pub Vec<SourceNGRANNode_ToTargetNGRANNode_TransparentContainerIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SourceRANNodeIDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14066,11 +15485,11 @@ pub struct SourceRANNodeIDIE_Extensions_Entry {}
 )]
 pub struct SourceRANNodeIDIE_Extensions(pub Vec<SourceRANNodeIDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SourceToTarget_AMFInformationRerouteIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14081,7 +15500,7 @@ pub struct SourceToTarget_AMFInformationRerouteIE_Extensions(
     pub Vec<SourceToTarget_AMFInformationRerouteIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum SuccessfulOutcomeValue {
     #[asn(key = 0)]
@@ -14128,7 +15547,7 @@ pub enum SuccessfulOutcomeValue {
     Id_WriteReplaceWarning(WriteReplaceWarningResponse),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum SupportedTAItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 272)]
@@ -14137,7 +15556,7 @@ pub enum SupportedTAItemIE_Extensions_EntryExtensionValue {
     Id_RAT_Information(RAT_Information),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SupportedTAItemIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -14146,7 +15565,7 @@ pub struct SupportedTAItemIE_Extensions_Entry {
     pub extension_value: SupportedTAItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14155,11 +15574,11 @@ pub struct SupportedTAItemIE_Extensions_Entry {
 )]
 pub struct SupportedTAItemIE_Extensions(pub Vec<SupportedTAItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TABasedMDTIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14168,11 +15587,11 @@ pub struct TABasedMDTIE_Extensions_Entry {}
 )]
 pub struct TABasedMDTIE_Extensions(pub Vec<TABasedMDTIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TAIIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14181,11 +15600,11 @@ pub struct TAIIE_Extensions_Entry {}
 )]
 pub struct TAIIE_Extensions(pub Vec<TAIIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TAIBasedMDTIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14194,11 +15613,11 @@ pub struct TAIBasedMDTIE_Extensions_Entry {}
 )]
 pub struct TAIBasedMDTIE_Extensions(pub Vec<TAIBasedMDTIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TAIBroadcastEUTRA_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14207,11 +15626,11 @@ pub struct TAIBroadcastEUTRA_ItemIE_Extensions_Entry {}
 )]
 pub struct TAIBroadcastEUTRA_ItemIE_Extensions(pub Vec<TAIBroadcastEUTRA_ItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TAIBroadcastNR_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14220,11 +15639,11 @@ pub struct TAIBroadcastNR_ItemIE_Extensions_Entry {}
 )]
 pub struct TAIBroadcastNR_ItemIE_Extensions(pub Vec<TAIBroadcastNR_ItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TAICancelledEUTRA_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14233,11 +15652,11 @@ pub struct TAICancelledEUTRA_ItemIE_Extensions_Entry {}
 )]
 pub struct TAICancelledEUTRA_ItemIE_Extensions(pub Vec<TAICancelledEUTRA_ItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TAICancelledNR_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14246,11 +15665,11 @@ pub struct TAICancelledNR_ItemIE_Extensions_Entry {}
 )]
 pub struct TAICancelledNR_ItemIE_Extensions(pub Vec<TAICancelledNR_ItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TAIListForInactiveItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14259,11 +15678,11 @@ pub struct TAIListForInactiveItemIE_Extensions_Entry {}
 )]
 pub struct TAIListForInactiveItemIE_Extensions(pub Vec<TAIListForInactiveItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TAIListForPagingItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14272,19 +15691,19 @@ pub struct TAIListForPagingItemIE_Extensions_Entry {}
 )]
 pub struct TAIListForPagingItemIE_Extensions(pub Vec<TAIListForPagingItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = true, sz_lb = "32", sz_ub = "32")]
-pub struct BIT_STRING_81(pub BitVec<u8, Msb0>);
+pub struct TNGF_ID_tNGF_ID(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct TNGF_IDchoice_Extensions {}
+pub struct TNGF_ID_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TNLAssociationItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14293,11 +15712,11 @@ pub struct TNLAssociationItemIE_Extensions_Entry {}
 )]
 pub struct TNLAssociationItemIE_Extensions(pub Vec<TNLAssociationItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TSCAssistanceInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14308,11 +15727,11 @@ pub struct TSCAssistanceInformationIE_Extensions(
     pub Vec<TSCAssistanceInformationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TSCTrafficCharacteristicsIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14323,35 +15742,35 @@ pub struct TSCTrafficCharacteristicsIE_Extensions(
     pub Vec<TSCTrafficCharacteristicsIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = true, sz_lb = "32", sz_ub = "32")]
-pub struct BIT_STRING_82(pub BitVec<u8, Msb0>);
+pub struct TWIF_ID_tWIF_ID(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct TWIF_IDchoice_Extensions {}
+pub struct TWIF_ID_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
-pub enum TargetIDchoice_ExtensionsValue {
+pub enum TargetID_choice_ExtensionsValue {
     #[asn(key = 178)]
     Id_TargetRNC_ID(TargetRNC_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct TargetIDchoice_Extensions {
+pub struct TargetID_choice_Extensions {
     #[asn(key_field = true)]
     pub id: ProtocolIE_ID,
     pub criticality: Criticality,
-    pub value: TargetIDchoice_ExtensionsValue,
+    pub value: TargetID_choice_ExtensionsValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TargetNGRANNode_ToSourceNGRANNode_FailureTransparentContainerIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14362,14 +15781,14 @@ pub struct TargetNGRANNode_ToSourceNGRANNode_FailureTransparentContainerIE_Exten
     pub Vec<TargetNGRANNode_ToSourceNGRANNode_FailureTransparentContainerIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum TargetNGRANNode_ToSourceNGRANNode_TransparentContainerIE_Extensions_EntryExtensionValue {
     #[asn(key = 267)]
     Id_DAPSResponseInfoList(DAPSResponseInfoList),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TargetNGRANNode_ToSourceNGRANNode_TransparentContainerIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -14379,7 +15798,7 @@ pub struct TargetNGRANNode_ToSourceNGRANNode_TransparentContainerIE_Extensions_E
         TargetNGRANNode_ToSourceNGRANNode_TransparentContainerIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14390,11 +15809,11 @@ pub struct TargetNGRANNode_ToSourceNGRANNode_TransparentContainerIE_Extensions(
     pub Vec<TargetNGRANNode_ToSourceNGRANNode_TransparentContainerIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TargetRANNodeIDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14403,11 +15822,11 @@ pub struct TargetRANNodeIDIE_Extensions_Entry {}
 )]
 pub struct TargetRANNodeIDIE_Extensions(pub Vec<TargetRANNodeIDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TargetRNC_IDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14416,11 +15835,11 @@ pub struct TargetRNC_IDIE_Extensions_Entry {}
 )]
 pub struct TargetRNC_IDIE_Extensions(pub Vec<TargetRNC_IDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TargeteNB_IDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14429,11 +15848,11 @@ pub struct TargeteNB_IDIE_Extensions_Entry {}
 )]
 pub struct TargeteNB_IDIE_Extensions(pub Vec<TargeteNB_IDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TooearlyIntersystemHOIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14442,7 +15861,7 @@ pub struct TooearlyIntersystemHOIE_Extensions_Entry {}
 )]
 pub struct TooearlyIntersystemHOIE_Extensions(pub Vec<TooearlyIntersystemHOIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum TraceActivationIE_Extensions_EntryExtensionValue {
     #[asn(key = 255)]
@@ -14451,7 +15870,7 @@ pub enum TraceActivationIE_Extensions_EntryExtensionValue {
     Id_TraceCollectionEntityURI(URI_address),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TraceActivationIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -14460,7 +15879,7 @@ pub struct TraceActivationIE_Extensions_Entry {
     pub extension_value: TraceActivationIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14469,7 +15888,7 @@ pub struct TraceActivationIE_Extensions_Entry {
 )]
 pub struct TraceActivationIE_Extensions(pub Vec<TraceActivationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum TraceFailureIndicationProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -14482,7 +15901,7 @@ pub enum TraceFailureIndicationProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TraceFailureIndicationProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -14491,7 +15910,7 @@ pub struct TraceFailureIndicationProtocolIEs_Entry {
     pub value: TraceFailureIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14500,7 +15919,7 @@ pub struct TraceFailureIndicationProtocolIEs_Entry {
 )]
 pub struct TraceFailureIndicationProtocolIEs(pub Vec<TraceFailureIndicationProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum TraceStartProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -14511,7 +15930,7 @@ pub enum TraceStartProtocolIEs_EntryValue {
     Id_TraceActivation(TraceActivation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TraceStartProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -14520,7 +15939,7 @@ pub struct TraceStartProtocolIEs_Entry {
     pub value: TraceStartProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14529,49 +15948,49 @@ pub struct TraceStartProtocolIEs_Entry {
 )]
 pub struct TraceStartProtocolIEs(pub Vec<TraceStartProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
-pub struct ENUMERATED_83(pub u8);
-impl ENUMERATED_83 {
+pub struct UE_DifferentiationInfoPeriodicCommunicationIndicator(pub u8);
+impl UE_DifferentiationInfoPeriodicCommunicationIndicator {
     pub const PERIODICALLY: u8 = 0u8;
     pub const ONDEMAND: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "1", ub = "3600", extensible = true)]
-pub struct INTEGER_84(pub u16);
+pub struct UE_DifferentiationInfoPeriodicTime(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
-pub struct ENUMERATED_85(pub u8);
-impl ENUMERATED_85 {
+pub struct UE_DifferentiationInfoStationaryIndication(pub u8);
+impl UE_DifferentiationInfoStationaryIndication {
     pub const STATIONARY: u8 = 0u8;
     pub const MOBILE: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
-pub struct ENUMERATED_86(pub u8);
-impl ENUMERATED_86 {
+pub struct UE_DifferentiationInfoTrafficProfile(pub u8);
+impl UE_DifferentiationInfoTrafficProfile {
     pub const SINGLE_PACKET: u8 = 0u8;
     pub const DUAL_PACKETS: u8 = 1u8;
     pub const MULTIPLE_PACKETS: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
-pub struct ENUMERATED_87(pub u8);
-impl ENUMERATED_87 {
+pub struct UE_DifferentiationInfoBatteryIndication(pub u8);
+impl UE_DifferentiationInfoBatteryIndication {
     pub const BATTERY_POWERED: u8 = 0u8;
     pub const BATTERY_POWERED_NOT_RECHARGEABLE_OR_REPLACEABLE: u8 = 1u8;
     pub const NOT_BATTERY_POWERED: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UE_DifferentiationInfoIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14580,11 +15999,11 @@ pub struct UE_DifferentiationInfoIE_Extensions_Entry {}
 )]
 pub struct UE_DifferentiationInfoIE_Extensions(pub Vec<UE_DifferentiationInfoIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UE_NGAP_ID_pairIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14593,15 +16012,15 @@ pub struct UE_NGAP_ID_pairIE_Extensions_Entry {}
 )]
 pub struct UE_NGAP_ID_pairIE_Extensions(pub Vec<UE_NGAP_ID_pairIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct UE_NGAP_IDschoice_Extensions {}
+pub struct UE_NGAP_IDs_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UE_associatedLogicalNG_connectionItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14612,11 +16031,11 @@ pub struct UE_associatedLogicalNG_connectionItemIE_Extensions(
     pub Vec<UE_associatedLogicalNG_connectionItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEAggregateMaximumBitRateIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14627,7 +16046,7 @@ pub struct UEAggregateMaximumBitRateIE_Extensions(
     pub Vec<UEAggregateMaximumBitRateIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum UEContextModificationFailureProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -14640,7 +16059,7 @@ pub enum UEContextModificationFailureProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextModificationFailureProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -14649,7 +16068,7 @@ pub struct UEContextModificationFailureProtocolIEs_Entry {
     pub value: UEContextModificationFailureProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14660,7 +16079,7 @@ pub struct UEContextModificationFailureProtocolIEs(
     pub Vec<UEContextModificationFailureProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum UEContextModificationRequestProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -14709,7 +16128,7 @@ pub enum UEContextModificationRequestProtocolIEs_EntryValue {
     Id_UESecurityCapabilities(UESecurityCapabilities),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextModificationRequestProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -14718,7 +16137,7 @@ pub struct UEContextModificationRequestProtocolIEs_Entry {
     pub value: UEContextModificationRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14729,7 +16148,7 @@ pub struct UEContextModificationRequestProtocolIEs(
     pub Vec<UEContextModificationRequestProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum UEContextModificationResponseProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -14744,7 +16163,7 @@ pub enum UEContextModificationResponseProtocolIEs_EntryValue {
     Id_UserLocationInformation(UserLocationInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextModificationResponseProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -14753,7 +16172,7 @@ pub struct UEContextModificationResponseProtocolIEs_Entry {
     pub value: UEContextModificationResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14764,7 +16183,7 @@ pub struct UEContextModificationResponseProtocolIEs(
     pub Vec<UEContextModificationResponseProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum UEContextReleaseCommandProtocolIEs_EntryValue {
     #[asn(key = 15)]
@@ -14773,7 +16192,7 @@ pub enum UEContextReleaseCommandProtocolIEs_EntryValue {
     Id_UE_NGAP_IDs(UE_NGAP_IDs),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextReleaseCommandProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -14782,7 +16201,7 @@ pub struct UEContextReleaseCommandProtocolIEs_Entry {
     pub value: UEContextReleaseCommandProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14791,7 +16210,7 @@ pub struct UEContextReleaseCommandProtocolIEs_Entry {
 )]
 pub struct UEContextReleaseCommandProtocolIEs(pub Vec<UEContextReleaseCommandProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum UEContextReleaseCompleteProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -14810,7 +16229,7 @@ pub enum UEContextReleaseCompleteProtocolIEs_EntryValue {
     Id_UserLocationInformation(UserLocationInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextReleaseCompleteProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -14819,7 +16238,7 @@ pub struct UEContextReleaseCompleteProtocolIEs_Entry {
     pub value: UEContextReleaseCompleteProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14828,7 +16247,7 @@ pub struct UEContextReleaseCompleteProtocolIEs_Entry {
 )]
 pub struct UEContextReleaseCompleteProtocolIEs(pub Vec<UEContextReleaseCompleteProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum UEContextReleaseRequestProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -14841,7 +16260,7 @@ pub enum UEContextReleaseRequestProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextReleaseRequestProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -14850,7 +16269,7 @@ pub struct UEContextReleaseRequestProtocolIEs_Entry {
     pub value: UEContextReleaseRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14859,7 +16278,7 @@ pub struct UEContextReleaseRequestProtocolIEs_Entry {
 )]
 pub struct UEContextReleaseRequestProtocolIEs(pub Vec<UEContextReleaseRequestProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum UEContextResumeFailureProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -14872,7 +16291,7 @@ pub enum UEContextResumeFailureProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextResumeFailureProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -14881,7 +16300,7 @@ pub struct UEContextResumeFailureProtocolIEs_Entry {
     pub value: UEContextResumeFailureProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14890,7 +16309,7 @@ pub struct UEContextResumeFailureProtocolIEs_Entry {
 )]
 pub struct UEContextResumeFailureProtocolIEs(pub Vec<UEContextResumeFailureProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum UEContextResumeRequestProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -14911,7 +16330,7 @@ pub enum UEContextResumeRequestProtocolIEs_EntryValue {
     Id_Suspend_Request_Indication(Suspend_Request_Indication),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextResumeRequestProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -14920,7 +16339,7 @@ pub struct UEContextResumeRequestProtocolIEs_Entry {
     pub value: UEContextResumeRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14929,11 +16348,11 @@ pub struct UEContextResumeRequestProtocolIEs_Entry {
 )]
 pub struct UEContextResumeRequestProtocolIEs(pub Vec<UEContextResumeRequestProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextResumeRequestTransferIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14944,7 +16363,7 @@ pub struct UEContextResumeRequestTransferIE_Extensions(
     pub Vec<UEContextResumeRequestTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum UEContextResumeResponseProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -14965,7 +16384,7 @@ pub enum UEContextResumeResponseProtocolIEs_EntryValue {
     Id_Suspend_Response_Indication(Suspend_Response_Indication),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextResumeResponseProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -14974,7 +16393,7 @@ pub struct UEContextResumeResponseProtocolIEs_Entry {
     pub value: UEContextResumeResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14983,11 +16402,11 @@ pub struct UEContextResumeResponseProtocolIEs_Entry {
 )]
 pub struct UEContextResumeResponseProtocolIEs(pub Vec<UEContextResumeResponseProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextResumeResponseTransferIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14998,7 +16417,7 @@ pub struct UEContextResumeResponseTransferIE_Extensions(
     pub Vec<UEContextResumeResponseTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum UEContextSuspendFailureProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -15011,7 +16430,7 @@ pub enum UEContextSuspendFailureProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextSuspendFailureProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -15020,7 +16439,7 @@ pub struct UEContextSuspendFailureProtocolIEs_Entry {
     pub value: UEContextSuspendFailureProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15029,7 +16448,7 @@ pub struct UEContextSuspendFailureProtocolIEs_Entry {
 )]
 pub struct UEContextSuspendFailureProtocolIEs(pub Vec<UEContextSuspendFailureProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum UEContextSuspendRequestProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -15044,7 +16463,7 @@ pub enum UEContextSuspendRequestProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextSuspendRequestProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -15053,7 +16472,7 @@ pub struct UEContextSuspendRequestProtocolIEs_Entry {
     pub value: UEContextSuspendRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15062,11 +16481,11 @@ pub struct UEContextSuspendRequestProtocolIEs_Entry {
 )]
 pub struct UEContextSuspendRequestProtocolIEs(pub Vec<UEContextSuspendRequestProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextSuspendRequestTransferIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15077,7 +16496,7 @@ pub struct UEContextSuspendRequestTransferIE_Extensions(
     pub Vec<UEContextSuspendRequestTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum UEContextSuspendResponseProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -15090,7 +16509,7 @@ pub enum UEContextSuspendResponseProtocolIEs_EntryValue {
     Id_SecurityContext(SecurityContext),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextSuspendResponseProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -15099,7 +16518,7 @@ pub struct UEContextSuspendResponseProtocolIEs_Entry {
     pub value: UEContextSuspendResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15108,19 +16527,19 @@ pub struct UEContextSuspendResponseProtocolIEs_Entry {
 )]
 pub struct UEContextSuspendResponseProtocolIEs(pub Vec<UEContextSuspendResponseProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct UEHistoryInformationFromTheUEchoice_Extensions {}
+pub struct UEHistoryInformationFromTheUE_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "10", sz_ub = "10")]
-pub struct BIT_STRING_88(pub BitVec<u8, Msb0>);
+pub struct UEIdentityIndexValue_indexLength10(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct UEIdentityIndexValuechoice_Extensions {}
+pub struct UEIdentityIndexValue_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum UEInformationTransferProtocolIEs_EntryValue {
     #[asn(key = 0)]
@@ -15137,7 +16556,7 @@ pub enum UEInformationTransferProtocolIEs_EntryValue {
     Id_UERadioCapability(UERadioCapability),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEInformationTransferProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -15146,7 +16565,7 @@ pub struct UEInformationTransferProtocolIEs_Entry {
     pub value: UEInformationTransferProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15155,15 +16574,15 @@ pub struct UEInformationTransferProtocolIEs_Entry {
 )]
 pub struct UEInformationTransferProtocolIEs(pub Vec<UEInformationTransferProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct UEPagingIdentitychoice_Extensions {}
+pub struct UEPagingIdentity_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEPresenceInAreaOfInterestItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15174,11 +16593,11 @@ pub struct UEPresenceInAreaOfInterestItemIE_Extensions(
     pub Vec<UEPresenceInAreaOfInterestItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct UERLFReportContainerchoice_Extensions {}
+pub struct UERLFReportContainer_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum UERadioCapabilityCheckRequestProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -15191,7 +16610,7 @@ pub enum UERadioCapabilityCheckRequestProtocolIEs_EntryValue {
     Id_UERadioCapabilityID(UERadioCapabilityID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UERadioCapabilityCheckRequestProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -15200,7 +16619,7 @@ pub struct UERadioCapabilityCheckRequestProtocolIEs_Entry {
     pub value: UERadioCapabilityCheckRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15211,7 +16630,7 @@ pub struct UERadioCapabilityCheckRequestProtocolIEs(
     pub Vec<UERadioCapabilityCheckRequestProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum UERadioCapabilityCheckResponseProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -15224,7 +16643,7 @@ pub enum UERadioCapabilityCheckResponseProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UERadioCapabilityCheckResponseProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -15233,7 +16652,7 @@ pub struct UERadioCapabilityCheckResponseProtocolIEs_Entry {
     pub value: UERadioCapabilityCheckResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15244,14 +16663,14 @@ pub struct UERadioCapabilityCheckResponseProtocolIEs(
     pub Vec<UERadioCapabilityCheckResponseProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum UERadioCapabilityForPagingIE_Extensions_EntryExtensionValue {
     #[asn(key = 214)]
     Id_UERadioCapabilityForPagingOfNB_IoT(UERadioCapabilityForPagingOfNB_IoT),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UERadioCapabilityForPagingIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -15260,7 +16679,7 @@ pub struct UERadioCapabilityForPagingIE_Extensions_Entry {
     pub extension_value: UERadioCapabilityForPagingIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15271,14 +16690,14 @@ pub struct UERadioCapabilityForPagingIE_Extensions(
     pub Vec<UERadioCapabilityForPagingIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum UERadioCapabilityIDMappingRequestProtocolIEs_EntryValue {
     #[asn(key = 264)]
     Id_UERadioCapabilityID(UERadioCapabilityID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UERadioCapabilityIDMappingRequestProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -15287,7 +16706,7 @@ pub struct UERadioCapabilityIDMappingRequestProtocolIEs_Entry {
     pub value: UERadioCapabilityIDMappingRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15298,7 +16717,7 @@ pub struct UERadioCapabilityIDMappingRequestProtocolIEs(
     pub Vec<UERadioCapabilityIDMappingRequestProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum UERadioCapabilityIDMappingResponseProtocolIEs_EntryValue {
     #[asn(key = 19)]
@@ -15309,7 +16728,7 @@ pub enum UERadioCapabilityIDMappingResponseProtocolIEs_EntryValue {
     Id_UERadioCapabilityID(UERadioCapabilityID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UERadioCapabilityIDMappingResponseProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -15318,7 +16737,7 @@ pub struct UERadioCapabilityIDMappingResponseProtocolIEs_Entry {
     pub value: UERadioCapabilityIDMappingResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15329,7 +16748,7 @@ pub struct UERadioCapabilityIDMappingResponseProtocolIEs(
     pub Vec<UERadioCapabilityIDMappingResponseProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum UERadioCapabilityInfoIndicationProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -15344,7 +16763,7 @@ pub enum UERadioCapabilityInfoIndicationProtocolIEs_EntryValue {
     Id_UERadioCapabilityForPaging(UERadioCapabilityForPaging),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UERadioCapabilityInfoIndicationProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -15353,7 +16772,7 @@ pub struct UERadioCapabilityInfoIndicationProtocolIEs_Entry {
     pub value: UERadioCapabilityInfoIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15364,11 +16783,11 @@ pub struct UERadioCapabilityInfoIndicationProtocolIEs(
     pub Vec<UERadioCapabilityInfoIndicationProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UESecurityCapabilitiesIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15377,7 +16796,7 @@ pub struct UESecurityCapabilitiesIE_Extensions_Entry {}
 )]
 pub struct UESecurityCapabilitiesIE_Extensions(pub Vec<UESecurityCapabilitiesIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum UETNLABindingReleaseRequestProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -15386,7 +16805,7 @@ pub enum UETNLABindingReleaseRequestProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UETNLABindingReleaseRequestProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -15395,7 +16814,7 @@ pub struct UETNLABindingReleaseRequestProtocolIEs_Entry {
     pub value: UETNLABindingReleaseRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15406,11 +16825,11 @@ pub struct UETNLABindingReleaseRequestProtocolIEs(
     pub Vec<UETNLABindingReleaseRequestProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UL_CP_SecurityInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15421,7 +16840,7 @@ pub struct UL_CP_SecurityInformationIE_Extensions(
     pub Vec<UL_CP_SecurityInformationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum UL_NGU_UP_TNLModifyItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 192)]
@@ -15430,7 +16849,7 @@ pub enum UL_NGU_UP_TNLModifyItemIE_Extensions_EntryExtensionValue {
     Id_RedundantUL_NGU_UP_TNLInformation(UPTransportLayerInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UL_NGU_UP_TNLModifyItemIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -15439,7 +16858,7 @@ pub struct UL_NGU_UP_TNLModifyItemIE_Extensions_Entry {
     pub extension_value: UL_NGU_UP_TNLModifyItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15450,15 +16869,15 @@ pub struct UL_NGU_UP_TNLModifyItemIE_Extensions(
     pub Vec<UL_NGU_UP_TNLModifyItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct UPTransportLayerInformationchoice_Extensions {}
+pub struct UPTransportLayerInformation_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UPTransportLayerInformationItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15469,11 +16888,11 @@ pub struct UPTransportLayerInformationItemIE_Extensions(
     pub Vec<UPTransportLayerInformationItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UPTransportLayerInformationPairItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15484,11 +16903,11 @@ pub struct UPTransportLayerInformationPairItemIE_Extensions(
     pub Vec<UPTransportLayerInformationPairItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UnavailableGUAMIItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15497,7 +16916,7 @@ pub struct UnavailableGUAMIItemIE_Extensions_Entry {}
 )]
 pub struct UnavailableGUAMIItemIE_Extensions(pub Vec<UnavailableGUAMIItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum UnsuccessfulOutcomeValue {
     #[asn(key = 0)]
@@ -15522,7 +16941,7 @@ pub enum UnsuccessfulOutcomeValue {
     Id_UEContextSuspend(UEContextSuspendFailure),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum UplinkNASTransportProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -15535,7 +16954,7 @@ pub enum UplinkNASTransportProtocolIEs_EntryValue {
     Id_UserLocationInformation(UserLocationInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UplinkNASTransportProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -15544,7 +16963,7 @@ pub struct UplinkNASTransportProtocolIEs_Entry {
     pub value: UplinkNASTransportProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15553,7 +16972,7 @@ pub struct UplinkNASTransportProtocolIEs_Entry {
 )]
 pub struct UplinkNASTransportProtocolIEs(pub Vec<UplinkNASTransportProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum UplinkNonUEAssociatedNRPPaTransportProtocolIEs_EntryValue {
     #[asn(key = 46)]
@@ -15562,7 +16981,7 @@ pub enum UplinkNonUEAssociatedNRPPaTransportProtocolIEs_EntryValue {
     Id_RoutingID(RoutingID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UplinkNonUEAssociatedNRPPaTransportProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -15571,7 +16990,7 @@ pub struct UplinkNonUEAssociatedNRPPaTransportProtocolIEs_Entry {
     pub value: UplinkNonUEAssociatedNRPPaTransportProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15582,7 +17001,7 @@ pub struct UplinkNonUEAssociatedNRPPaTransportProtocolIEs(
     pub Vec<UplinkNonUEAssociatedNRPPaTransportProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum UplinkRANConfigurationTransferProtocolIEs_EntryValue {
     #[asn(key = 158)]
@@ -15593,7 +17012,7 @@ pub enum UplinkRANConfigurationTransferProtocolIEs_EntryValue {
     Id_SONConfigurationTransferUL(SONConfigurationTransfer),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UplinkRANConfigurationTransferProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -15602,7 +17021,7 @@ pub struct UplinkRANConfigurationTransferProtocolIEs_Entry {
     pub value: UplinkRANConfigurationTransferProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15613,7 +17032,7 @@ pub struct UplinkRANConfigurationTransferProtocolIEs(
     pub Vec<UplinkRANConfigurationTransferProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum UplinkRANEarlyStatusTransferProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -15624,7 +17043,7 @@ pub enum UplinkRANEarlyStatusTransferProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UplinkRANEarlyStatusTransferProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -15633,7 +17052,7 @@ pub struct UplinkRANEarlyStatusTransferProtocolIEs_Entry {
     pub value: UplinkRANEarlyStatusTransferProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15644,7 +17063,7 @@ pub struct UplinkRANEarlyStatusTransferProtocolIEs(
     pub Vec<UplinkRANEarlyStatusTransferProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum UplinkRANStatusTransferProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -15655,7 +17074,7 @@ pub enum UplinkRANStatusTransferProtocolIEs_EntryValue {
     Id_RANStatusTransfer_TransparentContainer(RANStatusTransfer_TransparentContainer),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UplinkRANStatusTransferProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -15664,7 +17083,7 @@ pub struct UplinkRANStatusTransferProtocolIEs_Entry {
     pub value: UplinkRANStatusTransferProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15673,14 +17092,14 @@ pub struct UplinkRANStatusTransferProtocolIEs_Entry {
 )]
 pub struct UplinkRANStatusTransferProtocolIEs(pub Vec<UplinkRANStatusTransferProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum UplinkRIMInformationTransferProtocolIEs_EntryValue {
     #[asn(key = 175)]
     Id_RIMInformationTransfer(RIMInformationTransfer),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UplinkRIMInformationTransferProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -15689,7 +17108,7 @@ pub struct UplinkRIMInformationTransferProtocolIEs_Entry {
     pub value: UplinkRIMInformationTransferProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15700,7 +17119,7 @@ pub struct UplinkRIMInformationTransferProtocolIEs(
     pub Vec<UplinkRIMInformationTransferProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum UplinkUEAssociatedNRPPaTransportProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -15713,7 +17132,7 @@ pub enum UplinkUEAssociatedNRPPaTransportProtocolIEs_EntryValue {
     Id_RoutingID(RoutingID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UplinkUEAssociatedNRPPaTransportProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -15722,7 +17141,7 @@ pub struct UplinkUEAssociatedNRPPaTransportProtocolIEs_Entry {
     pub value: UplinkUEAssociatedNRPPaTransportProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15733,9 +17152,9 @@ pub struct UplinkUEAssociatedNRPPaTransportProtocolIEs(
     pub Vec<UplinkUEAssociatedNRPPaTransportProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
-pub enum UserLocationInformationchoice_ExtensionsValue {
+pub enum UserLocationInformation_choice_ExtensionsValue {
     #[asn(key = 244)]
     Id_UserLocationInformationTNGF(UserLocationInformationTNGF),
     #[asn(key = 248)]
@@ -15744,23 +17163,23 @@ pub enum UserLocationInformationchoice_ExtensionsValue {
     Id_UserLocationInformationW_AGF(UserLocationInformationW_AGF),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct UserLocationInformationchoice_Extensions {
+pub struct UserLocationInformation_choice_Extensions {
     #[asn(key_field = true)]
     pub id: ProtocolIE_ID,
     pub criticality: Criticality,
-    pub value: UserLocationInformationchoice_ExtensionsValue,
+    pub value: UserLocationInformation_choice_ExtensionsValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum UserLocationInformationEUTRAIE_Extensions_EntryExtensionValue {
     #[asn(key = 149)]
     Id_PSCellInformation(NGRAN_CGI),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UserLocationInformationEUTRAIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -15769,7 +17188,7 @@ pub struct UserLocationInformationEUTRAIE_Extensions_Entry {
     pub extension_value: UserLocationInformationEUTRAIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15780,11 +17199,11 @@ pub struct UserLocationInformationEUTRAIE_Extensions(
     pub Vec<UserLocationInformationEUTRAIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UserLocationInformationN3IWFIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15795,7 +17214,7 @@ pub struct UserLocationInformationN3IWFIE_Extensions(
     pub Vec<UserLocationInformationN3IWFIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum UserLocationInformationNRIE_Extensions_EntryExtensionValue {
     #[asn(key = 263)]
@@ -15804,7 +17223,7 @@ pub enum UserLocationInformationNRIE_Extensions_EntryExtensionValue {
     Id_PSCellInformation(NGRAN_CGI),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UserLocationInformationNRIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -15813,7 +17232,7 @@ pub struct UserLocationInformationNRIE_Extensions_Entry {
     pub extension_value: UserLocationInformationNRIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15824,11 +17243,11 @@ pub struct UserLocationInformationNRIE_Extensions(
     pub Vec<UserLocationInformationNRIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UserLocationInformationTNGFIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15839,11 +17258,11 @@ pub struct UserLocationInformationTNGFIE_Extensions(
     pub Vec<UserLocationInformationTNGFIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UserLocationInformationTWIFIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15854,27 +17273,27 @@ pub struct UserLocationInformationTWIFIE_Extensions(
     pub Vec<UserLocationInformationTWIFIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
-pub enum UserLocationInformationW_AGFchoice_ExtensionsValue {
+pub enum UserLocationInformationW_AGF_choice_ExtensionsValue {
     #[asn(key = 275)]
     Id_GlobalCable_ID(GlobalCable_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct UserLocationInformationW_AGFchoice_Extensions {
+pub struct UserLocationInformationW_AGF_choice_Extensions {
     #[asn(key_field = true)]
     pub id: ProtocolIE_ID,
     pub criticality: Criticality,
-    pub value: UserLocationInformationW_AGFchoice_ExtensionsValue,
+    pub value: UserLocationInformationW_AGF_choice_ExtensionsValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UserPlaneSecurityInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15885,27 +17304,27 @@ pub struct UserPlaneSecurityInformationIE_Extensions(
     pub Vec<UserPlaneSecurityInformationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "4", sz_ub = "4")]
-pub struct OCTET_STRING_89(pub Vec<u8>);
+pub struct VolumeTimedReport_ItemStartTimeStamp(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "4", sz_ub = "4")]
-pub struct OCTET_STRING_90(pub Vec<u8>);
+pub struct VolumeTimedReport_ItemEndTimeStamp(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "18446744073709551615")]
-pub struct INTEGER_91(pub u64);
+pub struct VolumeTimedReport_ItemUsageCountUL(pub u64);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "INTEGER", lb = "0", ub = "18446744073709551615")]
-pub struct INTEGER_92(pub u64);
+pub struct VolumeTimedReport_ItemUsageCountDL(pub u64);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct VolumeTimedReport_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15914,19 +17333,19 @@ pub struct VolumeTimedReport_ItemIE_Extensions_Entry {}
 )]
 pub struct VolumeTimedReport_ItemIE_Extensions(pub Vec<VolumeTimedReport_ItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "BITSTRING", sz_extensible = true, sz_lb = "16", sz_ub = "16")]
-pub struct BIT_STRING_93(pub BitVec<u8, Msb0>);
+pub struct W_AGF_ID_w_AGF_ID(pub bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct W_AGF_IDchoice_Extensions {}
+pub struct W_AGF_ID_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct WLANMeasConfigNameItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15935,25 +17354,25 @@ pub struct WLANMeasConfigNameItemIE_Extensions_Entry {}
 )]
 pub struct WLANMeasConfigNameItemIE_Extensions(pub Vec<WLANMeasConfigNameItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
-pub struct ENUMERATED_94(pub u8);
-impl ENUMERATED_94 {
+pub struct WLANMeasurementConfigurationWlan_rssi(pub u8);
+impl WLANMeasurementConfigurationWlan_rssi {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
-pub struct ENUMERATED_95(pub u8);
-impl ENUMERATED_95 {
+pub struct WLANMeasurementConfigurationWlan_rtt(pub u8);
+impl WLANMeasurementConfigurationWlan_rtt {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct WLANMeasurementConfigurationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15964,11 +17383,11 @@ pub struct WLANMeasurementConfigurationIE_Extensions(
     pub Vec<WLANMeasurementConfigurationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct WUS_Assistance_InformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15979,11 +17398,11 @@ pub struct WUS_Assistance_InformationIE_Extensions(
     pub Vec<WUS_Assistance_InformationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
-pub struct WarningAreaListchoice_Extensions {}
+pub struct WarningAreaList_choice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum WriteReplaceWarningRequestProtocolIEs_EntryValue {
     #[asn(key = 17)]
@@ -16010,7 +17429,7 @@ pub enum WriteReplaceWarningRequestProtocolIEs_EntryValue {
     Id_WarningType(WarningType),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct WriteReplaceWarningRequestProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -16019,7 +17438,7 @@ pub struct WriteReplaceWarningRequestProtocolIEs_Entry {
     pub value: WriteReplaceWarningRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -16030,7 +17449,7 @@ pub struct WriteReplaceWarningRequestProtocolIEs(
     pub Vec<WriteReplaceWarningRequestProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum WriteReplaceWarningResponseProtocolIEs_EntryValue {
     #[asn(key = 13)]
@@ -16043,7 +17462,7 @@ pub enum WriteReplaceWarningResponseProtocolIEs_EntryValue {
     Id_SerialNumber(SerialNumber),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct WriteReplaceWarningResponseProtocolIEs_Entry {
     #[asn(key_field = true)]
@@ -16052,7 +17471,7 @@ pub struct WriteReplaceWarningResponseProtocolIEs_Entry {
     pub value: WriteReplaceWarningResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -16063,14 +17482,14 @@ pub struct WriteReplaceWarningResponseProtocolIEs(
     pub Vec<WriteReplaceWarningResponseProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "OPEN")]
 pub enum XnExtTLA_ItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 173)]
     Id_SCTP_TLAs(SCTP_TLAs),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct XnExtTLA_ItemIE_Extensions_Entry {
     #[asn(key_field = true)]
@@ -16079,7 +17498,7 @@ pub struct XnExtTLA_ItemIE_Extensions_Entry {
     pub extension_value: XnExtTLA_ItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -16088,11 +17507,11 @@ pub struct XnExtTLA_ItemIE_Extensions_Entry {
 )]
 pub struct XnExtTLA_ItemIE_Extensions(pub Vec<XnExtTLA_ItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct XnTNLConfigurationInfoIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(asn1_codecs_derive :: AperCodec, Debug)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
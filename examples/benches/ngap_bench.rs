@@ -1,6 +1,6 @@
 use criterion::{criterion_group, criterion_main, Criterion};
 
-use asn1_codecs::aper::{AperCodec, AperCodecData};
+use asn1_codecs::{aper::AperCodec, PerCodecData};
 mod ngap;
 use ngap::NGAP_PDU;
 
@@ -11,8 +11,8 @@ pub fn ngap_decode_bench(c: &mut Criterion) {
 
     c.bench_function("NGAP_decode", |b| {
         b.iter(|| {
-            let mut codec_data = AperCodecData::from_slice(&ngap_data);
-            let _ = NGAP_PDU::decode(&mut codec_data).unwrap();
+            let mut codec_data = PerCodecData::from_slice_aper(&ngap_data);
+            let _ = NGAP_PDU::aper_decode(&mut codec_data).unwrap();
         });
     });
 }
@@ -29,7 +29,7 @@ fn get_specs_files(
                 .and_then(|f| f.to_str())
                 // This is a `map` on the option - converts Option<&str>, Option<bool>,
                 // Leaving `None` as it is
-                .map(|s| s.starts_with(prefix))
+                .map(|s| s.to_ascii_uppercase().starts_with(prefix))
                 // If it's None, it's falsey, filter out
                 .unwrap_or_default()
         })
@@ -42,14 +42,22 @@ fn get_specs_files(
 }
 
 fn main() -> std::io::Result<()> {
-    let specs = vec!["ranap", "s1ap", "ngap", "e2ap", "supl"];
-    let modules = vec!["ranap.rs", "s1ap.rs", "ngap.rs", "e2ap.rs", "supl.rs"];
+    let specs = vec!["ranap", "s1ap", "ngap", "e2ap", "supl", "example"];
+    let modules = vec![
+        "ranap.rs",
+        "s1ap.rs",
+        "ngap.rs",
+        "e2ap.rs",
+        "supl.rs",
+        "example.rs",
+    ];
     let mut codecs_map = HashMap::new();
     codecs_map.insert("ranap.rs", vec![Codec::Aper]);
     codecs_map.insert("s1ap.rs", vec![Codec::Aper]);
     codecs_map.insert("ngap.rs", vec![Codec::Aper]);
     codecs_map.insert("e2ap.rs", vec![Codec::Aper]);
     codecs_map.insert("supl.rs", vec![Codec::Uper]);
+    codecs_map.insert("example.rs", vec![Codec::Aper, Codec::Uper]);
 
     for (spec, module) in std::iter::zip(specs, modules) {
         let specs_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap())
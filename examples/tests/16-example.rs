@@ -1,59 +1,10 @@
 #![allow(dead_code, unreachable_patterns, non_camel_case_types)]
 
-#[derive(asn1_codecs_derive :: AperCodec, asn1_codecs_derive :: UperCodec, Debug)]
-#[asn(type = "SEQUENCE", extensible = false)]
-pub struct ChildInformation {
-    pub name: Name,
-    pub date_of_birth: Date,
+mod example {
+    include!(concat!(env!("OUT_DIR"), "/example.rs"));
 }
 
-#[derive(asn1_codecs_derive :: AperCodec, asn1_codecs_derive :: UperCodec, Debug)]
-#[asn(type = "VisibleString")]
-pub struct Date(pub String);
-
-#[derive(asn1_codecs_derive :: AperCodec, asn1_codecs_derive :: UperCodec, Debug)]
-#[asn(type = "INTEGER")]
-pub struct EmployeeNumber(pub i64);
-
-#[derive(asn1_codecs_derive :: AperCodec, asn1_codecs_derive :: UperCodec, Debug)]
-#[asn(type = "SEQUENCE", extensible = false)]
-pub struct Name {
-    pub given_name: CharacterString_2,
-    pub initial: CharacterString_3,
-    pub family_name: CharacterString_4,
-}
-
-#[derive(asn1_codecs_derive :: AperCodec, asn1_codecs_derive :: UperCodec, Debug)]
-#[asn(type = "SEQUENCE", extensible = false, optional_fields = 1)]
-pub struct PersonnelRecord {
-    pub name: Name,
-    pub number: EmployeeNumber,
-    pub title: CharacterString_5,
-    pub date_of_hire: Date,
-    pub name_of_spouse: Name,
-    #[asn(optional_idx = 0)]
-    pub children: Option<PersonnelRecordChildren>,
-}
-
-#[derive(asn1_codecs_derive :: AperCodec, asn1_codecs_derive :: UperCodec, Debug)]
-#[asn(type = "VisibleString")]
-pub struct CharacterString_2(pub String);
-
-#[derive(asn1_codecs_derive :: AperCodec, asn1_codecs_derive :: UperCodec, Debug)]
-#[asn(type = "VisibleString")]
-pub struct CharacterString_3(pub String);
-
-#[derive(asn1_codecs_derive :: AperCodec, asn1_codecs_derive :: UperCodec, Debug)]
-#[asn(type = "VisibleString")]
-pub struct CharacterString_4(pub String);
-
-#[derive(asn1_codecs_derive :: AperCodec, asn1_codecs_derive :: UperCodec, Debug)]
-#[asn(type = "VisibleString")]
-pub struct CharacterString_5(pub String);
-
-#[derive(asn1_codecs_derive :: AperCodec, asn1_codecs_derive :: UperCodec, Debug)]
-#[asn(type = "SEQUENCE-OF")]
-pub struct PersonnelRecordChildren(pub Vec<ChildInformation>);
+use example::{EmployeeNumber, PersonnelRecord, PersonnelRecordTitle};
 
 fn main() {
     use asn1_codecs::{aper::AperCodec, uper::UperCodec, PerCodecData};
@@ -63,6 +14,21 @@ fn main() {
 
     let personnel_record = PersonnelRecord::aper_decode(&mut codec_data);
     eprintln!("personnel_record: {:#?}", personnel_record);
+    let personnel_record = personnel_record.unwrap();
+    assert_eq!(personnel_record.number, EmployeeNumber(51));
+    assert_eq!(
+        personnel_record.title,
+        PersonnelRecordTitle("Director".to_string())
+    );
+
+    // The components of the `SET` are generated (and encoded) in the canonical order of their
+    // tags, which is not the textual order.
+    let mut codec_data = PerCodecData::new_aper();
+    personnel_record.aper_encode(&mut codec_data).unwrap();
+    assert_eq!(
+        hex::encode_upper(codec_data.into_bytes()),
+        example_bytes_aper
+    );
 
     let example_bytes_uper = "824ADFA3700D005A7B74F4D0026611134F2CB8FA6FE410C5CB762C1CB16E09370F2F20350169EDD3D340102D2C3B386801A80B4F6E9E9A0218B96ADD8B162C4169F5E787700C20595BF765E610C5CB572C1BB16E";
     let example_data_uper = hex::decode(example_bytes_uper).unwrap();